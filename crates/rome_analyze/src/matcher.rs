@@ -199,8 +199,8 @@ mod tests {
     use super::MatchQueryParams;
     use crate::{
         signals::DiagnosticSignal, Analyzer, AnalyzerContext, AnalyzerSignal, ControlFlow,
-        MetadataRegistry, Never, Phases, QueryMatcher, RuleKey, ServiceBag, SignalEntry,
-        SyntaxVisitor,
+        MetadataRegistry, Never, Phases, QueryMatcher, RuleKey, RuleMetadata, ServiceBag,
+        SignalEntry, SyntaxVisitor,
     };
     use crate::{AnalyzerOptions, SuppressionKind};
     use rome_diagnostics::{category, DiagnosticExt};
@@ -361,7 +361,7 @@ mod tests {
         }
 
         let mut metadata = MetadataRegistry::default();
-        metadata.insert_rule("group", "rule");
+        metadata.insert_rule("group", RuleMetadata::new("0.0.0", "rule", ""));
 
         let mut analyzer = Analyzer::new(
             &metadata,
@@ -9,11 +9,7 @@ use crate::{
 use rome_diagnostics::Error;
 use rome_rowan::{AstNode, Language, RawSyntaxKind, SyntaxKind, SyntaxNode};
 use rustc_hash::{FxHashMap, FxHashSet};
-use std::{
-    any::TypeId,
-    borrow,
-    collections::{BTreeMap, BTreeSet},
-};
+use std::{any::TypeId, borrow, collections::BTreeMap};
 
 /// Defines all the phases that the [RuleRegistry] supports.
 #[repr(usize)]
@@ -59,26 +55,48 @@ pub trait RegistryVisitor<L: Language> {
 /// alphabetically
 #[derive(Debug, Default)]
 pub struct MetadataRegistry {
-    inner: BTreeSet<MetadataKey>,
+    inner: BTreeMap<MetadataKey, RuleMetadata>,
 }
 
 impl MetadataRegistry {
     /// Return a unique identifier for a rule group if it's known by this registry
     pub fn find_group(&self, group: &str) -> Option<GroupKey> {
-        let key = self.inner.get(group)?;
+        let (key, _) = self.inner.get_key_value(group)?;
         Some(key.into_group_key())
     }
 
     /// Return a unique identifier for a rule if it's known by this registry
     pub fn find_rule(&self, group: &str, rule: &str) -> Option<RuleKey> {
-        let key = self.inner.get(&(group, rule))?;
+        let (key, _) = self.inner.get_key_value(&(group, rule))?;
         Some(key.into_rule_key())
     }
 
-    pub(crate) fn insert_rule(&mut self, group: &'static str, rule: &'static str) {
-        self.inner.insert(MetadataKey {
-            inner: (group, rule),
-        });
+    /// Return the metadata of a rule if it's known by this registry
+    pub fn find_rule_metadata(&self, group: &str, rule: &str) -> Option<RegistryRuleMetadata> {
+        let (key, metadata) = self.inner.get_key_value(&(group, rule))?;
+        Some(RegistryRuleMetadata {
+            group: key.inner.0,
+            rule: *metadata,
+        })
+    }
+
+    /// Return an iterator over the metadata of all the rules in this registry
+    pub fn iter_rules(&self) -> impl Iterator<Item = RegistryRuleMetadata> + '_ {
+        self.inner
+            .iter()
+            .map(|(key, metadata)| RegistryRuleMetadata {
+                group: key.inner.0,
+                rule: *metadata,
+            })
+    }
+
+    pub(crate) fn insert_rule(&mut self, group: &'static str, metadata: RuleMetadata) {
+        self.inner.insert(
+            MetadataKey {
+                inner: (group, metadata.name),
+            },
+            metadata,
+        );
     }
 }
 
@@ -89,7 +107,7 @@ impl<L: Language> RegistryVisitor<L> for MetadataRegistry {
         R::Query: Queryable<Language = L>,
         <R::Query as Queryable>::Output: Clone,
     {
-        self.insert_rule(<R::Group as RuleGroup>::NAME, R::METADATA.name);
+        self.insert_rule(<R::Group as RuleGroup>::NAME, R::METADATA);
     }
 }
 
@@ -335,6 +353,7 @@ impl borrow::Borrow<str> for MetadataKey {
 }
 
 /// Metadata entry for a rule and its group in the registry
+#[derive(Debug, Clone, Copy)]
 pub struct RegistryRuleMetadata {
     pub group: &'static str,
     pub rule: RuleMetadata,
@@ -400,6 +419,13 @@ impl<L: Language + Default> RegistryRule<L> {
                 }
             }
 
+            let _span = tracing::trace_span!(
+                "rule",
+                group = <R::Group as RuleGroup>::NAME,
+                name = R::METADATA.name
+            )
+            .entered();
+
             // SAFETY: The rule should never get executed in the first place
             // if the query doesn't match
             let query_result = params.query.downcast_ref().unwrap();
@@ -17,6 +17,7 @@ use rome_rowan::{AstNode, BatchMutation, BatchMutationExt, Language, TextRange};
 use std::fmt::Debug;
 
 /// Static metadata containing information about a rule
+#[derive(Debug, Clone, Copy)]
 pub struct RuleMetadata {
     /// It marks if a rule is deprecated, and if so a reason has to be provided.
     pub deprecated: Option<&'static str>,
@@ -28,6 +29,17 @@ pub struct RuleMetadata {
     pub docs: &'static str,
     /// Whether a rule is recommended or not
     pub recommended: bool,
+    /// The kind of fix the rule can emit, if any
+    pub fix_kind: Option<FixKind>,
+}
+
+/// The kind of fix a rule can emit
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FixKind {
+    /// The fix doesn't change the semantics of the code, it's applied with `--apply`
+    Safe,
+    /// The fix may change the semantics of the code, it's applied with `--apply-unsafe`
+    Unsafe,
 }
 
 impl RuleMetadata {
@@ -38,6 +50,7 @@ impl RuleMetadata {
             name,
             docs,
             recommended: false,
+            fix_kind: None,
         }
     }
 
@@ -50,6 +63,11 @@ impl RuleMetadata {
         self.deprecated = Some(deprecated);
         self
     }
+
+    pub const fn fix_kind(mut self, fix_kind: FixKind) -> Self {
+        self.fix_kind = Some(fix_kind);
+        self
+    }
 }
 
 pub trait RuleMeta {
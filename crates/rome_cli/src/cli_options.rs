@@ -1,4 +1,5 @@
-use bpaf::Bpaf;
+use bpaf::{construct, long, Bpaf, Parser};
+use std::path::PathBuf;
 use std::str::FromStr;
 
 /// Global options applied to all commands
@@ -44,6 +45,56 @@ pub struct CliOptions {
     /// Reports information using the JSON format
     #[bpaf(long("json"), switch, hide_usage)]
     pub json: bool,
+
+    /// Set the format of the report: "json" prints a JSON summary, "sarif" prints the diagnostics in the SARIF 2.1.0 format, "junit" in the JUnit XML format, "checkstyle" in the Checkstyle XML format, "gitlab" in the GitLab Code Quality format and "github" as GitHub Actions annotations
+    #[bpaf(
+        long("reporter"),
+        argument("json|sarif|junit|checkstyle|gitlab|github"),
+        optional
+    )]
+    pub reporter: Option<ReporterArg>,
+
+    /// Write the report to a file instead of printing it. It requires the option --reporter
+    #[bpaf(long("reporter-file"), argument("PATH"), optional)]
+    pub reporter_file: Option<PathBuf>,
+
+    #[bpaf(external(changed), optional)]
+    pub changed: Option<String>,
+
+    /// Process only the files that are staged in git
+    #[bpaf(long("staged"), switch)]
+    pub staged: bool,
+
+    /// Process all the files, even the files whose result is cached because they didn't change since the last run
+    #[bpaf(long("no-cache"), switch)]
+    pub no_cache: bool,
+
+    /// Ignore the diagnostics recorded in this baseline file, and report only the new ones
+    #[bpaf(long("baseline"), argument("PATH"), optional)]
+    pub baseline: Option<PathBuf>,
+
+    /// Record the diagnostics in this baseline file, instead of reporting them
+    #[bpaf(long("write-baseline"), argument("PATH"), optional)]
+    pub write_baseline: Option<PathBuf>,
+
+    /// Print the slowest files, and the time spent in each lint rule and in each phase of the analysis
+    #[bpaf(long("timings"), switch)]
+    pub timings: bool,
+}
+
+/// Parses `--changed=BASE`, and `--changed` alone that compares with `HEAD`.
+///
+/// The base must be attached to the option, otherwise the paths that follow the option would be
+/// taken as the base
+fn changed() -> impl Parser<String> {
+    let base = long("changed")
+        .help("Process only the files that changed since BASE according to git, including the untracked files")
+        .argument::<String>("BASE")
+        .adjacent();
+    let head = long("changed")
+        .help("Process only the files that changed since HEAD according to git, including the untracked files")
+        .req_flag(String::from("HEAD"));
+    construct!([base, head])
 }
 
 #[derive(Debug, Clone)]
@@ -65,3 +116,31 @@ impl FromStr for ColorsArg {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy)]
+pub enum ReporterArg {
+    Json,
+    Sarif,
+    Junit,
+    Checkstyle,
+    Gitlab,
+    Github,
+}
+
+impl FromStr for ReporterArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            "junit" => Ok(Self::Junit),
+            "checkstyle" => Ok(Self::Checkstyle),
+            "gitlab" => Ok(Self::Gitlab),
+            "github" => Ok(Self::Github),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --reporter argument"
+            )),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::cli_options::CliOptions;
 use crate::configuration::{load_configuration, LoadedConfiguration};
+use crate::execute::ReportMode;
 use crate::vcs::store_path_to_ignore_from_vcs;
 use crate::{execute_mode, CliDiagnostic, CliSession, Execution, TraversalMode};
 use rome_service::configuration::organize_imports::OrganizeImports;
@@ -12,6 +13,7 @@ use std::path::PathBuf;
 pub(crate) struct CheckCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
+    pub(crate) watch: bool,
     pub(crate) cli_options: CliOptions,
     pub(crate) configuration: Option<Configuration>,
     pub(crate) paths: Vec<OsString>,
@@ -29,6 +31,7 @@ pub(crate) fn check(
     let CheckCommandPayload {
         apply,
         apply_unsafe,
+        watch,
         cli_options,
         configuration,
         paths,
@@ -94,6 +97,12 @@ pub(crate) fn check(
     )?;
 
     let stdin = if let Some(stdin_file_path) = stdin_file_path {
+        if watch {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--stdin-file-path",
+                "--watch",
+            ));
+        }
         let console = &mut session.app.console;
         let input_code = console.read();
         if let Some(input_code) = input_code {
@@ -107,20 +116,23 @@ pub(crate) fn check(
         None
     };
 
+    let execution = Execution::with_report(
+        TraversalMode::Check {
+            fix_file_mode,
+            stdin,
+        },
+        ReportMode::from(cli_options.reporter),
+    )
+    .with_configuration(&fs_configuration)
+    .with_watch(watch);
+
     session
         .app
         .workspace
         .update_settings(UpdateSettingsParams {
             configuration: fs_configuration,
+            directory: None,
         })?;
 
-    execute_mode(
-        Execution::new(TraversalMode::Check {
-            fix_file_mode,
-            stdin,
-        }),
-        session,
-        &cli_options,
-        paths,
-    )
+    execute_mode(execution, session, &cli_options, paths)
 }
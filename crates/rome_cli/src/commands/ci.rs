@@ -1,5 +1,6 @@
 use crate::cli_options::CliOptions;
 use crate::configuration::LoadedConfiguration;
+use crate::execute::ReportMode;
 use crate::vcs::store_path_to_ignore_from_vcs;
 use crate::{
     configuration::load_configuration, execute_mode, CliDiagnostic, CliSession, Execution,
@@ -81,15 +82,21 @@ pub(crate) fn ci(mut session: CliSession, payload: CiCommandPayload) -> Result<(
         &payload.cli_options,
     )?;
 
+    // inside a GitHub Actions workflow, the diagnostics are shown as annotations of the pull request
+    let report_mode = match payload.cli_options.reporter {
+        None if session.environment.github_actions => ReportMode::Github,
+        reporter => ReportMode::from(reporter),
+    };
+    let execution =
+        Execution::with_report(TraversalMode::CI, report_mode).with_configuration(&configuration);
+
     session
         .app
         .workspace
-        .update_settings(UpdateSettingsParams { configuration })?;
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            directory: None,
+        })?;
 
-    execute_mode(
-        Execution::new(TraversalMode::CI),
-        session,
-        &payload.cli_options,
-        payload.paths,
-    )
+    execute_mode(execution, session, &payload.cli_options, payload.paths)
 }
@@ -0,0 +1,170 @@
+use rome_analyze::{FixKind, MetadataRegistry, RegistryRuleMetadata};
+use rome_console::fmt::{Display, Formatter};
+use rome_console::{markup, ConsoleExt};
+use rome_js_analyze::options::PossibleOptions;
+use std::io;
+
+use crate::{CliDiagnostic, CliSession};
+
+/// What `rome explain` prints: a single rule, or all the rules of a group
+enum Explanation {
+    Rule(RegistryRuleMetadata),
+    Group {
+        name: &'static str,
+        rules: Vec<RegistryRuleMetadata>,
+    },
+}
+
+/// Handler for the `explain` command
+pub(crate) fn explain(session: CliSession, name: String) -> Result<(), CliDiagnostic> {
+    let registries = [rome_js_analyze::metadata(), rome_json_analyze::metadata()];
+    let explanation = find_explanation(&registries, &name)
+        .ok_or_else(|| CliDiagnostic::unknown_rule(name.as_str()))?;
+
+    match explanation {
+        Explanation::Rule(metadata) => {
+            session.app.console.log(markup! {
+                <Emphasis>"lint/"{metadata.group}"/"{metadata.rule.name}</Emphasis>"\n"
+                {Documentation(metadata.rule.docs)}
+            });
+
+            // The JSON of the default options is aligned with the other values
+            let options = PossibleOptions::for_rule(metadata.rule.name)
+                .and_then(|options| serde_json::to_string_pretty(&options).ok())
+                .map(|options| options.replace('\n', &format!("\n{:VALUE_COLUMN$}", "")));
+
+            session.app.console.log(markup! {
+                {KeyValuePair("Recommended", &if is_recommended(&metadata) { "yes" } else { "no" })}
+                {KeyValuePair("Fix", &DisplayFixKind(metadata.rule.fix_kind))}
+                {KeyValuePair("Default options", &options.as_deref().unwrap_or("none"))}
+                {KeyValuePair("Since", &metadata.rule.version)}
+            });
+
+            if let Some(reason) = metadata.rule.deprecated {
+                session.app.console.log(markup! {
+                    <Warn>"This rule is deprecated: "{reason}</Warn>
+                });
+            }
+        }
+        Explanation::Group { name, rules } => {
+            session.app.console.log(markup! {
+                <Emphasis>"lint/"{name}</Emphasis>"\n"
+            });
+
+            for metadata in rules {
+                let summary = metadata.rule.docs.lines().next().unwrap_or_default().trim();
+                if is_recommended(&metadata) {
+                    session.app.console.log(markup! {
+                        "  "<Emphasis>{metadata.rule.name}</Emphasis>" "<Success>"(recommended)"</Success>"\n    "{summary}
+                    });
+                } else {
+                    session.app.console.log(markup! {
+                        "  "<Emphasis>{metadata.rule.name}</Emphasis>"\n    "{summary}
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds the rule or the group called `name`. The name of a rule can be prefixed by its group
+/// (`suspicious/noDebugger`), or be its category (`lint/suspicious/noDebugger`)
+fn find_explanation(registries: &[&MetadataRegistry], name: &str) -> Option<Explanation> {
+    let name = name.strip_prefix("lint/").unwrap_or(name);
+
+    if let Some((group, rule)) = name.split_once('/') {
+        return registries
+            .iter()
+            .find_map(|registry| registry.find_rule_metadata(group, rule))
+            .map(Explanation::Rule);
+    }
+
+    let rule = registries
+        .iter()
+        .flat_map(|registry| registry.iter_rules())
+        .find(|metadata| metadata.rule.name == name);
+    if let Some(metadata) = rule {
+        return Some(Explanation::Rule(metadata));
+    }
+
+    let mut rules: Vec<_> = registries
+        .iter()
+        .flat_map(|registry| registry.iter_rules())
+        .filter(|metadata| metadata.group == name)
+        .collect();
+    let group = rules.first()?.group;
+    rules.sort_by_key(|metadata| metadata.rule.name);
+
+    Some(Explanation::Group { name: group, rules })
+}
+
+/// The rules of the nursery are never recommended, whatever their metadata says
+fn is_recommended(metadata: &RegistryRuleMetadata) -> bool {
+    metadata.group != "nursery" && metadata.rule.recommended
+}
+
+/// Prints the documentation of a rule: the headings are emphasized, and the code blocks of the
+/// examples are indented, without their fences
+struct Documentation(&'static str);
+
+impl Display for Documentation {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let mut in_code_block = false;
+
+        for line in self.0.trim_end().lines() {
+            let line = line.strip_prefix(' ').unwrap_or(line);
+
+            if line.starts_with("```") {
+                in_code_block = !in_code_block;
+                continue;
+            }
+
+            if in_code_block {
+                fmt.write_markup(markup! { "    "{line}"\n" })?;
+            } else if let Some(heading) = line.strip_prefix('#') {
+                let heading = heading.trim_start_matches('#').trim();
+                fmt.write_markup(markup! { <Emphasis>{heading}</Emphasis>"\n" })?;
+            } else {
+                fmt.write_markup(markup! { {line}"\n" })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct DisplayFixKind(Option<FixKind>);
+
+impl Display for DisplayFixKind {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        match self.0 {
+            Some(FixKind::Safe) => fmt.write_markup(markup! { "safe, applied with --apply" }),
+            Some(FixKind::Unsafe) => {
+                fmt.write_markup(markup! { "unsafe, applied with --apply-unsafe" })
+            }
+            None => fmt.write_str("none"),
+        }
+    }
+}
+
+/// The column where the values of [KeyValuePair] start
+const VALUE_COLUMN: usize = 17;
+
+struct KeyValuePair<'a>(&'a str, &'a dyn Display);
+
+impl Display for KeyValuePair<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let KeyValuePair(key, value) = self;
+        fmt.write_markup(markup! { <Emphasis>{key}":"</Emphasis> })?;
+
+        let padding_width = VALUE_COLUMN.saturating_sub(key.len() + 1);
+        for _ in 0..padding_width {
+            fmt.write_str(" ")?;
+        }
+
+        value.fmt(fmt)?;
+        fmt.write_str("\n")
+    }
+}
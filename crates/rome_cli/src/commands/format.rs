@@ -56,10 +56,6 @@ pub(crate) fn format(
         vcs_base_path,
         &cli_options,
     )?;
-    session
-        .app
-        .workspace
-        .update_settings(UpdateSettingsParams { configuration })?;
 
     let stdin = if let Some(stdin_file_path) = stdin_file_path {
         let console = &mut session.app.console;
@@ -75,22 +71,28 @@ pub(crate) fn format(
         None
     };
 
-    let execution = if cli_options.json {
-        Execution::with_report(
-            TraversalMode::Format {
-                ignore_errors: cli_options.skip_errors,
-                write,
-                stdin,
-            },
-            ReportMode::Json,
-        )
+    let report_mode = if cli_options.json {
+        ReportMode::Json
     } else {
-        Execution::new(TraversalMode::Format {
+        ReportMode::from(cli_options.reporter)
+    };
+    let execution = Execution::with_report(
+        TraversalMode::Format {
             ignore_errors: cli_options.skip_errors,
             write,
             stdin,
-        })
-    };
+        },
+        report_mode,
+    )
+    .with_configuration(&configuration);
+
+    session
+        .app
+        .workspace
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            directory: None,
+        })?;
 
     execute_mode(execution, session, &cli_options, paths)
 }
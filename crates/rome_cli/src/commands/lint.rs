@@ -1,8 +1,9 @@
 use crate::cli_options::CliOptions;
 use crate::configuration::{load_configuration, LoadedConfiguration};
+use crate::execute::ReportMode;
 use crate::vcs::store_path_to_ignore_from_vcs;
 use crate::{execute_mode, CliDiagnostic, CliSession, Execution, TraversalMode};
-use rome_service::workspace::{FixFileMode, UpdateSettingsParams};
+use rome_service::workspace::{FixFileMode, RuleSelector, UpdateSettingsParams};
 use rome_service::{Configuration, MergeWith};
 use std::ffi::OsString;
 use std::path::PathBuf;
@@ -10,6 +11,8 @@ use std::path::PathBuf;
 pub(crate) struct LintCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
+    pub(crate) only: Vec<RuleSelector>,
+    pub(crate) skip: Vec<RuleSelector>,
     pub(crate) cli_options: CliOptions,
     pub(crate) configuration: Option<Configuration>,
     pub(crate) paths: Vec<OsString>,
@@ -24,6 +27,8 @@ pub(crate) fn lint(
     let LintCommandPayload {
         apply,
         apply_unsafe,
+        only,
+        skip,
         cli_options,
         configuration,
         paths,
@@ -75,20 +80,24 @@ pub(crate) fn lint(
         None
     };
 
+    let execution = Execution::with_report(
+        TraversalMode::Lint {
+            fix_file_mode,
+            stdin,
+            only,
+            skip,
+        },
+        ReportMode::from(cli_options.reporter),
+    )
+    .with_configuration(&fs_configuration);
+
     session
         .app
         .workspace
         .update_settings(UpdateSettingsParams {
             configuration: fs_configuration,
+            directory: None,
         })?;
 
-    execute_mode(
-        Execution::new(TraversalMode::Lint {
-            fix_file_mode,
-            stdin,
-        }),
-        session,
-        &cli_options,
-        paths,
-    )
+    execute_mode(execution, session, &cli_options, paths)
 }
@@ -4,11 +4,13 @@ use crate::diagnostics::MigrationDiagnostic;
 use crate::execute::{execute_mode, Execution, TraversalMode};
 use crate::{CliDiagnostic, CliSession};
 
-/// Handler for the "check" command of the Rome CLI
+/// Handler for the "migrate" command of the Rome CLI
 pub(crate) fn migrate(
     mut session: CliSession,
     cli_options: CliOptions,
     write: bool,
+    eslint: bool,
+    prettier: bool,
 ) -> Result<(), CliDiagnostic> {
     let LoadedConfiguration {
         configuration: _,
@@ -22,6 +24,8 @@ pub(crate) fn migrate(
             Execution::new(TraversalMode::Migrate {
                 write,
                 configuration_path: path.join(config_name),
+                eslint,
+                prettier,
             }),
             session,
             &cli_options,
@@ -6,12 +6,14 @@ use rome_service::configuration::{
     configuration, files_configuration, formatter_configuration, javascript::javascript_formatter,
     vcs::vcs_configuration, FilesConfiguration, FormatterConfiguration, JavascriptFormatter,
 };
+use rome_service::workspace::RuleSelector;
 use rome_service::Configuration;
 use std::ffi::OsString;
 
 pub(crate) mod check;
 pub(crate) mod ci;
 pub(crate) mod daemon;
+pub(crate) mod explain;
 pub(crate) mod format;
 pub(crate) mod init;
 pub(crate) mod lint;
@@ -47,6 +49,9 @@ pub enum RomeCommand {
         /// Apply safe fixes and unsafe fixes, formatting and import sorting
         #[bpaf(long("apply-unsafe"), switch)]
         apply_unsafe: bool,
+        /// Keep running, and check the files again when they change
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
         /// Allow to enable or disable the formatter check.
         #[bpaf(
             long("formatter-enabled"),
@@ -86,6 +91,12 @@ pub enum RomeCommand {
         /// Apply safe fixes and unsafe fixes, formatting and import sorting
         #[bpaf(long("apply-unsafe"), switch)]
         apply_unsafe: bool,
+        /// Run only this rule, or the rules of this group, in place of the rules enabled in the configuration, e.g. --only=suspicious/noConsoleLog. It can be passed many times
+        #[bpaf(long("only"), argument("GROUP/RULE"), many)]
+        only: Vec<RuleSelector>,
+        /// Don't run this rule, or the rules of this group, e.g. --skip=style. It can be passed many times
+        #[bpaf(long("skip"), argument("GROUP/RULE"), many)]
+        skip: Vec<RuleSelector>,
         #[bpaf(external, hide_usage, optional)]
         configuration: Option<Configuration>,
         #[bpaf(external, hide_usage)]
@@ -158,12 +169,29 @@ pub enum RomeCommand {
     LspProxy(#[bpaf(external(cli_options))] CliOptions),
     /// It updates the configuration when there are breaking changes
     #[bpaf(command)]
-    Migrate(
-        #[bpaf(external(cli_options), hide_usage)] CliOptions,
+    Migrate {
+        #[bpaf(external(cli_options), hide_usage)]
+        cli_options: CliOptions,
         /// Writes the new configuration file to disk
         #[bpaf(long("write"), switch)]
-        bool,
-    ),
+        write: bool,
+        /// Migrates the settings of the ESLint configuration file (.eslintrc.json) into the configuration
+        #[bpaf(long("eslint"), switch)]
+        eslint: bool,
+        /// Migrates the settings of the Prettier configuration file (.prettierrc) into the configuration
+        #[bpaf(long("prettier"), switch)]
+        prettier: bool,
+    },
+
+    /// Prints the documentation of a rule, with its examples, its options and its fix
+    #[bpaf(command)]
+    Explain {
+        #[bpaf(external(cli_options), hide_usage)]
+        cli_options: CliOptions,
+        /// The name of a rule, with or without its group (`suspicious/noDebugger`), its category (`lint/suspicious/noDebugger`) or the name of a group
+        #[bpaf(positional("NAME"))]
+        name: String,
+    },
 
     #[bpaf(command("__run_server"), hide)]
     RunServer {
@@ -187,7 +215,8 @@ impl RomeCommand {
             RomeCommand::Format { cli_options, .. } => cli_options.colors.as_ref(),
             RomeCommand::Init => None,
             RomeCommand::LspProxy(cli_options) => cli_options.colors.as_ref(),
-            RomeCommand::Migrate(cli_options, _) => cli_options.colors.as_ref(),
+            RomeCommand::Migrate { cli_options, .. } => cli_options.colors.as_ref(),
+            RomeCommand::Explain { cli_options, .. } => cli_options.colors.as_ref(),
             RomeCommand::RunServer { .. } => None,
             RomeCommand::PrintSocket => None,
         }
@@ -205,7 +234,8 @@ impl RomeCommand {
             RomeCommand::Format { cli_options, .. } => cli_options.use_server,
             RomeCommand::Init => false,
             RomeCommand::LspProxy(cli_options) => cli_options.use_server,
-            RomeCommand::Migrate(cli_options, _) => cli_options.use_server,
+            RomeCommand::Migrate { cli_options, .. } => cli_options.use_server,
+            RomeCommand::Explain { cli_options, .. } => cli_options.use_server,
             RomeCommand::RunServer { .. } => false,
             RomeCommand::PrintSocket => false,
         }
@@ -227,7 +257,8 @@ impl RomeCommand {
             RomeCommand::Ci { cli_options, .. } => cli_options.verbose,
             RomeCommand::Init => false,
             RomeCommand::LspProxy(cli_options) => cli_options.verbose,
-            RomeCommand::Migrate(cli_options, _) => cli_options.verbose,
+            RomeCommand::Migrate { cli_options, .. } => cli_options.verbose,
+            RomeCommand::Explain { cli_options, .. } => cli_options.verbose,
             RomeCommand::RunServer { .. } => false,
             RomeCommand::PrintSocket => false,
         }
@@ -57,6 +57,12 @@ pub enum CliDiagnostic {
     MigrateError(MigrationDiagnostic),
     /// When the VCS folder couldn't be found
     NoVcsFolderFound(NoVcsFolderFound),
+    /// When the VCS couldn't list the changed files
+    VcsCommandFailed(VcsCommandFailed),
+    /// Returned when the baseline file passed to `--baseline` can't be read
+    InvalidBaseline(InvalidBaseline),
+    /// Returned by `rome explain` when the name isn't a rule or a group
+    UnknownRule(UnknownRule),
 }
 
 #[derive(Debug, Diagnostic)]
@@ -268,6 +274,48 @@ pub struct NoVcsFolderFound {
 )]
 pub struct DisabledVcs {}
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "internalError/fs",
+    severity = Error,
+    message(
+		description = "Rome couldn't list the changed files, the command {command} failed: {reason}",
+		message("Rome couldn't list the changed files, the command "<Emphasis>{self.command}</Emphasis>" failed: "{self.reason}),
+	)
+)]
+pub struct VcsCommandFailed {
+    pub command: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+		description = "Rome couldn't read the baseline file: {reason}",
+		message("Rome couldn't read the baseline file: "{self.reason}),
+	)
+)]
+pub struct InvalidBaseline {
+    #[location(resource)]
+    pub path: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+		description = "Rome doesn't know the rule or the group {name}",
+		message("Rome doesn't know the rule or the group "<Emphasis>{self.name}</Emphasis>),
+	)
+)]
+pub struct UnknownRule {
+    pub name: String,
+}
+
 /// Advices for the [CliDiagnostic]
 #[derive(Debug, Default)]
 struct CliAdvice {
@@ -436,6 +484,19 @@ impl CliDiagnostic {
         })
     }
 
+    /// Emitted by `rome explain` when the name isn't a rule or a group
+    pub fn unknown_rule(name: impl Into<String>) -> Self {
+        Self::UnknownRule(UnknownRule { name: name.into() })
+    }
+
+    /// Emitted when the baseline file can't be read, or isn't a baseline
+    pub fn invalid_baseline(path: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidBaseline(InvalidBaseline {
+            path: path.into(),
+            reason: reason.into(),
+        })
+    }
+
     /// Emitted when an argument value is greater than the allowed value
     pub fn overflown_argument(argument: impl Into<String>, maximum: u16) -> Self {
         Self::OverflowNumberArgument(OverflowNumberArgument {
@@ -472,6 +533,9 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheckApply(diagnostic) => diagnostic.category(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.category(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.category(),
+            CliDiagnostic::VcsCommandFailed(diagnostic) => diagnostic.category(),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.category(),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.category(),
         }
     }
 
@@ -494,6 +558,9 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheckApply(diagnostic) => diagnostic.tags(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.tags(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.tags(),
+            CliDiagnostic::VcsCommandFailed(diagnostic) => diagnostic.tags(),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.tags(),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.tags(),
         }
     }
 
@@ -516,6 +583,9 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheckApply(diagnostic) => diagnostic.severity(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.severity(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.severity(),
+            CliDiagnostic::VcsCommandFailed(diagnostic) => diagnostic.severity(),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.severity(),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.severity(),
         }
     }
 
@@ -538,6 +608,9 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheckApply(diagnostic) => diagnostic.location(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.location(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.location(),
+            CliDiagnostic::VcsCommandFailed(diagnostic) => diagnostic.location(),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.location(),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.location(),
         }
     }
 
@@ -560,6 +633,9 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheckApply(diagnostic) => diagnostic.message(fmt),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.message(fmt),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.message(fmt),
+            CliDiagnostic::VcsCommandFailed(diagnostic) => diagnostic.message(fmt),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.message(fmt),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.message(fmt),
         }
     }
 
@@ -582,6 +658,9 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheckApply(diagnostic) => diagnostic.description(fmt),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.description(fmt),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.description(fmt),
+            CliDiagnostic::VcsCommandFailed(diagnostic) => diagnostic.description(fmt),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.description(fmt),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.description(fmt),
         }
     }
 
@@ -604,6 +683,9 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheckApply(diagnostic) => diagnostic.advices(visitor),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.advices(visitor),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.advices(visitor),
+            CliDiagnostic::VcsCommandFailed(diagnostic) => diagnostic.advices(visitor),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.advices(visitor),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.advices(visitor),
         }
     }
 
@@ -630,6 +712,9 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheckApply(diagnostic) => diagnostic.verbose_advices(visitor),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.verbose_advices(visitor),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.verbose_advices(visitor),
+            CliDiagnostic::VcsCommandFailed(diagnostic) => diagnostic.verbose_advices(visitor),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.verbose_advices(visitor),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.verbose_advices(visitor),
         }
     }
 
@@ -652,6 +737,9 @@ impl Diagnostic for CliDiagnostic {
             CliDiagnostic::FileCheckApply(diagnostic) => diagnostic.source(),
             CliDiagnostic::MigrateError(diagnostic) => diagnostic.source(),
             CliDiagnostic::NoVcsFolderFound(diagnostic) => diagnostic.source(),
+            CliDiagnostic::VcsCommandFailed(diagnostic) => diagnostic.source(),
+            CliDiagnostic::InvalidBaseline(diagnostic) => diagnostic.source(),
+            CliDiagnostic::UnknownRule(diagnostic) => diagnostic.source(),
         }
     }
 }
@@ -0,0 +1,167 @@
+use crate::reports::ReportedDiagnostic;
+use crate::CliDiagnostic;
+use rome_diagnostics::{Error, PrintDescription};
+use rome_fs::{FileSystem, FileSystemExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// The diagnostics that existed when a rule was enabled, and that the traversal doesn't report.
+///
+/// Each diagnostic is identified by its path, its category and a fingerprint of its message and
+/// of its source code, so that a diagnostic is still recognized after the lines above it change.
+#[derive(Debug)]
+pub(crate) struct Baseline {
+    /// The path of the baseline file
+    path: PathBuf,
+    mode: BaselineMode,
+    /// The number of occurrences of each diagnostic. With `--baseline`, an occurrence is removed
+    /// every time it's suppressed, so that a copy of a recorded diagnostic is still reported
+    entries: BTreeMap<BaselineEntry, usize>,
+    /// The number of diagnostics that weren't reported
+    suppressed: usize,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum BaselineMode {
+    /// `--baseline`: the diagnostics of the file aren't reported
+    Suppress,
+    /// `--write-baseline`: all the diagnostics are recorded in the file, instead of being reported
+    Write,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct BaselineFile {
+    diagnostics: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd)]
+struct BaselineEntry {
+    path: String,
+    category: String,
+    fingerprint: String,
+}
+
+impl BaselineEntry {
+    fn new(diagnostic: &Error, path: &str, source_code: Option<&str>) -> Self {
+        let path = path.replace('\\', "/");
+        let message = PrintDescription(diagnostic).to_string();
+        let fingerprint = ReportedDiagnostic::fingerprint(diagnostic, &path, source_code, &message);
+
+        Self {
+            path,
+            category: diagnostic
+                .category()
+                .map_or_else(String::new, |category| category.name().to_string()),
+            fingerprint,
+        }
+    }
+}
+
+impl Baseline {
+    /// Creates the baseline requested with `--baseline` or `--write-baseline`, the file of
+    /// `--baseline` is read from the file system
+    pub(crate) fn from_options(
+        fs: &dyn FileSystem,
+        baseline: Option<&Path>,
+        write_baseline: Option<&Path>,
+    ) -> Result<Option<Self>, CliDiagnostic> {
+        match (baseline, write_baseline) {
+            (Some(_), Some(_)) => Err(CliDiagnostic::incompatible_arguments(
+                "--baseline",
+                "--write-baseline",
+            )),
+            (Some(path), None) => Self::load(fs, path).map(Some),
+            (None, Some(path)) => Ok(Some(Self {
+                path: path.to_path_buf(),
+                mode: BaselineMode::Write,
+                entries: BTreeMap::new(),
+                suppressed: 0,
+            })),
+            (None, None) => Ok(None),
+        }
+    }
+
+    fn load(fs: &dyn FileSystem, path: &Path) -> Result<Self, CliDiagnostic> {
+        let invalid_baseline =
+            |reason: String| CliDiagnostic::invalid_baseline(path.display().to_string(), reason);
+
+        let mut content = String::new();
+        fs.open(path)
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .map_err(|error| invalid_baseline(error.to_string()))?;
+        let file: BaselineFile =
+            serde_json::from_str(&content).map_err(|error| invalid_baseline(error.to_string()))?;
+
+        let mut entries = BTreeMap::new();
+        for entry in file.diagnostics {
+            *entries.entry(entry).or_default() += 1;
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            mode: BaselineMode::Suppress,
+            entries,
+            suppressed: 0,
+        })
+    }
+
+    pub(crate) fn mode(&self) -> &BaselineMode {
+        &self.mode
+    }
+
+    /// The number of diagnostics that weren't reported
+    pub(crate) fn suppressed(&self) -> usize {
+        self.suppressed
+    }
+
+    /// Returns `true` if `diagnostic` shouldn't be reported: it's recorded when the baseline is
+    /// written, or it's one of the diagnostics of the baseline.
+    ///
+    /// `path` and `source_code` are the path and the content of the file of the diagnostic, they
+    /// aren't always attached to the diagnostic yet
+    pub(crate) fn suppress(
+        &mut self,
+        diagnostic: &Error,
+        path: &str,
+        source_code: Option<&str>,
+    ) -> bool {
+        let entry = BaselineEntry::new(diagnostic, path, source_code);
+        let is_suppressed = match self.mode {
+            BaselineMode::Write => {
+                *self.entries.entry(entry).or_default() += 1;
+                true
+            }
+            BaselineMode::Suppress => match self.entries.get_mut(&entry) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    true
+                }
+                _ => false,
+            },
+        };
+
+        if is_suppressed {
+            self.suppressed += 1;
+        }
+        is_suppressed
+    }
+
+    /// Writes the recorded diagnostics to the baseline file, sorted so that the file can be
+    /// reviewed when it's updated
+    pub(crate) fn save(&self, fs: &dyn FileSystem) -> Result<(), CliDiagnostic> {
+        let file = BaselineFile {
+            diagnostics: self
+                .entries
+                .iter()
+                .flat_map(|(entry, count)| std::iter::repeat(entry.clone()).take(*count))
+                .collect(),
+        };
+        let content = serde_json::to_string_pretty(&file)
+            .map_err(|error| CliDiagnostic::io_error(error.into()))?;
+
+        fs.create(&self.path)
+            .and_then(|mut file| file.set_content(content.as_bytes()))
+            .map_err(CliDiagnostic::io_error)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::reports::fingerprint;
+use crate::VERSION;
+use rome_fs::{FileSystem, FileSystemExt};
+use rome_service::Configuration;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// Remembers the files that didn't emit any diagnostic, so that the next traversals can skip
+/// them while their content doesn't change.
+///
+/// The cache of a project is stored in a single file of the cache directory. It's discarded
+/// when it was written by another version of Rome, or with another configuration.
+#[derive(Debug)]
+pub(crate) struct ResultCache {
+    /// The path of the cache file
+    path: PathBuf,
+    /// The traversal mode the results belong to, the commands don't emit the same diagnostics
+    mode: String,
+    content: Mutex<CacheContent>,
+    /// Whether the content changed since it was loaded
+    has_changed: AtomicBool,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct CacheContent {
+    /// The version of Rome that wrote the cache
+    version: String,
+    /// The hash of the configuration the files were processed with
+    configuration: String,
+    /// For each traversal mode, the path of the files without diagnostics and the hash of
+    /// their content
+    files: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl ResultCache {
+    /// Hashes the resolved configuration: a change in `rome.json`, in the configuration files it
+    /// extends, or in the command line arguments invalidates the cache
+    pub(crate) fn configuration_hash(configuration: &Configuration) -> String {
+        let configuration = serde_json::to_string(configuration).unwrap_or_default();
+        fingerprint(&[&configuration])
+    }
+
+    /// Reads the cache of the working directory from `directory`. A missing or unreadable cache
+    /// is replaced with an empty one
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        directory: &Path,
+        mode: String,
+        configuration_hash: &str,
+    ) -> Self {
+        let working_directory = fs.working_directory().unwrap_or_default();
+        let path = directory.join(format!(
+            "{}.json",
+            fingerprint(&[&working_directory.display().to_string()])
+        ));
+
+        let content = fs
+            .open(&path)
+            .and_then(|mut file| {
+                let mut content = String::new();
+                file.read_to_string(&mut content)?;
+                Ok(content)
+            })
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheContent>(&content).ok())
+            .filter(|content| {
+                content.version == VERSION && content.configuration == configuration_hash
+            });
+
+        Self {
+            path,
+            mode,
+            has_changed: AtomicBool::new(content.is_none()),
+            content: Mutex::new(content.unwrap_or_else(|| CacheContent {
+                version: VERSION.to_string(),
+                configuration: configuration_hash.to_string(),
+                files: BTreeMap::new(),
+            })),
+        }
+    }
+
+    /// Returns `true` if the file at `path` didn't emit any diagnostic the last time it was
+    /// processed with this `content`, and with the nested configuration of this
+    /// `configuration_hash` when its directory has one
+    pub(crate) fn is_clean(
+        &self,
+        path: &Path,
+        content: &str,
+        configuration_hash: Option<&str>,
+    ) -> bool {
+        let content_hash = content_hash(content, configuration_hash);
+        let cache = self.content.lock().unwrap();
+        cache
+            .files
+            .get(&self.mode)
+            .and_then(|files| files.get(&path.display().to_string()))
+            .map_or(false, |hash| *hash == content_hash)
+    }
+
+    /// Stores the result of the file at `path`: it's remembered only if the file is `clean`
+    pub(crate) fn update(
+        &self,
+        path: &Path,
+        content: &str,
+        configuration_hash: Option<&str>,
+        clean: bool,
+    ) {
+        let path = path.display().to_string();
+        let mut cache = self.content.lock().unwrap();
+        let files = cache.files.entry(self.mode.clone()).or_default();
+        let has_changed = if clean {
+            let content_hash = content_hash(content, configuration_hash);
+            files.insert(path, content_hash.clone()) != Some(content_hash)
+        } else {
+            files.remove(&path).is_some()
+        };
+        if has_changed {
+            self.has_changed.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Writes the cache to the cache directory, if it changed
+    pub(crate) fn save(&self, fs: &dyn FileSystem) -> io::Result<()> {
+        if !self.has_changed.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        if let Some(directory) = self.path.parent() {
+            fs.create_directory_all(directory)?;
+        }
+        let content = serde_json::to_string(&*self.content.lock().unwrap())?;
+        fs.create(&self.path)?.set_content(content.as_bytes())
+    }
+}
+
+fn content_hash(content: &str, configuration_hash: Option<&str>) -> String {
+    match configuration_hash {
+        Some(configuration_hash) => fingerprint(&[content, configuration_hash]),
+        None => fingerprint(&[content]),
+    }
+}
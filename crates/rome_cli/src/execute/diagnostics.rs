@@ -1,5 +1,9 @@
+use rome_console::fmt::Display;
+use rome_console::markup;
 use rome_diagnostics::adapters::{IoError, StdError};
-use rome_diagnostics::{Advices, Category, Diagnostic, DiagnosticExt, Error, Severity, Visit};
+use rome_diagnostics::{
+    Advices, Category, Diagnostic, DiagnosticExt, Error, LogCategory, Severity, Visit,
+};
 use rome_text_edit::TextEdit;
 use std::io;
 
@@ -66,6 +70,49 @@ pub(crate) struct MigrateDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+	category = "migrate",
+	severity = Warning,
+	message = "Some settings can't be migrated."
+)]
+pub(crate) struct UnsupportedSettingsDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) settings: UnsupportedSettingsAdvice,
+}
+
+#[derive(Debug)]
+pub(crate) struct UnsupportedSettingsAdvice {
+    pub(crate) settings: Vec<String>,
+}
+
+impl Advices for UnsupportedSettingsAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! { "These settings don't have an equivalent in Rome:" },
+        )?;
+        let list: Vec<_> = self
+            .settings
+            .iter()
+            .map(|setting| setting as &dyn Display)
+            .collect();
+        visitor.record_list(&list)
+    }
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "jsonSchema", severity = Warning)]
+pub(crate) struct CantLoadJsonSchemaDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[message]
+    #[description]
+    pub(crate) message: String,
+}
+
 #[derive(Debug)]
 pub(crate) struct ContentDiffAdvice {
     pub(crate) old: String,
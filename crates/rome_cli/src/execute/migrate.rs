@@ -1,25 +1,55 @@
-use crate::execute::diagnostics::{ContentDiffAdvice, MigrateDiffDiagnostic};
+mod eslint;
+mod merge;
+mod prettier;
+
+use crate::diagnostics::MigrationDiagnostic;
+use crate::execute::diagnostics::{
+    ContentDiffAdvice, MigrateDiffDiagnostic, UnsupportedSettingsAdvice,
+    UnsupportedSettingsDiagnostic,
+};
 use crate::{CliDiagnostic, CliSession};
 use rome_console::{markup, ConsoleExt};
 use rome_diagnostics::{category, PrintDiagnostic};
-use rome_fs::OpenOptions;
+use rome_fs::{FileSystem, OpenOptions};
+use rome_json_formatter::context::JsonFormatOptions;
 use rome_json_parser::JsonParserOptions;
-use rome_json_syntax::JsonRoot;
+use rome_json_syntax::{AnyJsonValue, JsonRoot};
 use rome_migrate::{migrate_configuration, ControlFlow};
-use rome_rowan::AstNode;
+use rome_rowan::{AstNode, AstSeparatedList};
+use rome_service::configuration::Configuration;
 use rome_service::workspace::FixAction;
 use std::borrow::Cow;
-use std::path::PathBuf;
-
-pub(crate) fn run(
-    session: CliSession,
-    write: bool,
-    configuration_path: PathBuf,
-    verbose: bool,
-) -> Result<(), CliDiagnostic> {
+use std::path::{Path, PathBuf};
+
+pub(crate) struct MigrateOptions {
+    pub(crate) write: bool,
+    pub(crate) configuration_path: PathBuf,
+    /// Migrates the settings of the ESLint configuration file
+    pub(crate) eslint: bool,
+    /// Migrates the settings of the Prettier configuration file
+    pub(crate) prettier: bool,
+    pub(crate) verbose: bool,
+}
+
+/// The result of the migration of the configuration file of another tool
+pub(crate) struct ForeignMigration {
+    /// The settings that have an equivalent in Rome
+    pub(crate) configuration: Configuration,
+    /// The settings that don't have an equivalent in Rome
+    pub(crate) unsupported_settings: Vec<String>,
+}
+
+pub(crate) fn run(session: CliSession, options: MigrateOptions) -> Result<(), CliDiagnostic> {
+    let MigrateOptions {
+        write,
+        configuration_path,
+        eslint,
+        prettier,
+        verbose,
+    } = options;
     let fs = &*session.app.fs;
     let open_options = if write {
-        OpenOptions::default().write(true)
+        OpenOptions::default().read(true).write(true)
     } else {
         OpenOptions::default().read(true)
     };
@@ -71,6 +101,62 @@ pub(crate) fn run(
         }
     }
     let console = &mut *session.app.console;
+
+    let mut foreign_migrations = Vec::new();
+    let configuration_directory = configuration_path.parent().unwrap_or(Path::new(""));
+    if eslint {
+        let (path, content) = read_foreign_configuration(
+            fs,
+            configuration_directory,
+            eslint::CONFIGURATION_FILES,
+            "ESLint",
+        )?;
+        let migration = parse_foreign_configuration(&path, &content)
+            .and_then(|value| eslint::migrate(&value))
+            .ok_or_else(|| invalid_foreign_configuration(&path))?;
+        foreign_migrations.push((path, migration));
+    }
+    if prettier {
+        let (path, content) = read_foreign_configuration(
+            fs,
+            configuration_directory,
+            prettier::CONFIGURATION_FILES,
+            "Prettier",
+        )?;
+        let migration = parse_foreign_configuration(&path, &content)
+            .and_then(|value| prettier::migrate(&value))
+            .ok_or_else(|| invalid_foreign_configuration(&path))?;
+        foreign_migrations.push((path, migration));
+    }
+
+    if !foreign_migrations.is_empty() {
+        for (path, migration) in foreign_migrations {
+            tree = merge::merge_configuration(&tree, &migration.configuration)
+                .ok_or_else(|| CliDiagnostic::check_error(category!("migrate")))?;
+
+            if !migration.unsupported_settings.is_empty() {
+                let diagnostic = UnsupportedSettingsDiagnostic {
+                    file_name: path.display().to_string(),
+                    settings: UnsupportedSettingsAdvice {
+                        settings: migration.unsupported_settings,
+                    },
+                };
+                console.error(markup! {
+                    {if verbose { PrintDiagnostic::verbose(&diagnostic) } else { PrintDiagnostic::simple(&diagnostic) }}
+                });
+            }
+        }
+
+        // The new settings don't have any layout
+        let formatted =
+            rome_json_formatter::format_node(JsonFormatOptions::default(), tree.syntax())
+                .map_err(|_| CliDiagnostic::check_error(category!("migrate")))?
+                .print()
+                .map_err(|_| CliDiagnostic::check_error(category!("migrate")))?;
+        tree =
+            rome_json_parser::parse_json(formatted.as_code(), JsonParserOptions::default()).tree();
+    }
+
     let new_configuration_content = tree.to_string();
 
     if configuration_content != new_configuration_content {
@@ -101,3 +187,87 @@ pub(crate) fn run(
     }
     Ok(())
 }
+
+/// Reads the first configuration file of `file_names` that exists in `directory`
+fn read_foreign_configuration(
+    fs: &dyn FileSystem,
+    directory: &Path,
+    file_names: &[&str],
+    tool: &str,
+) -> Result<(PathBuf, String), CliDiagnostic> {
+    for file_name in file_names {
+        let path = directory.join(file_name);
+        if let Ok(mut file) = fs.open_with_options(&path, OpenOptions::default().read(true)) {
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+            return Ok((path, content));
+        }
+    }
+
+    Err(CliDiagnostic::MigrateError(MigrationDiagnostic {
+        reason: format!(
+            "Rome couldn't find the {tool} configuration file, it looked for: {}",
+            file_names.join(", ")
+        ),
+    }))
+}
+
+/// Parses the configuration file of another tool. Comments and trailing commas are allowed.
+fn parse_foreign_configuration(path: &Path, content: &str) -> Option<AnyJsonValue> {
+    let options = JsonParserOptions::default()
+        .with_allow_comments()
+        .with_allow_trailing_commas();
+    let parsed = rome_json_parser::parse_json(content, options);
+    if parsed.has_errors() {
+        tracing::debug!("Can't parse {}", path.display());
+        return None;
+    }
+
+    parsed.tree().value().ok()
+}
+
+fn invalid_foreign_configuration(path: &Path) -> CliDiagnostic {
+    CliDiagnostic::MigrateError(MigrationDiagnostic {
+        reason: format!(
+            "Rome couldn't migrate {}, only JSON files with an object are supported",
+            path.display()
+        ),
+    })
+}
+
+/// Returns the members of `value` and their name, if it's an object
+fn members(value: &AnyJsonValue) -> Option<Vec<(String, AnyJsonValue)>> {
+    value
+        .as_json_object_value()?
+        .json_member_list()
+        .iter()
+        .map(|member| {
+            let member = member.ok()?;
+            let name = member.name().ok()?.inner_string_text().ok()?;
+            Some((name.text().to_string(), member.value().ok()?))
+        })
+        .collect()
+}
+
+/// Returns the strings of `value`, if it's a string or an array of strings
+fn string_values(value: &AnyJsonValue) -> Option<Vec<String>> {
+    let string_value = |value: &AnyJsonValue| {
+        Some(
+            value
+                .as_json_string_value()?
+                .inner_string_text()
+                .ok()?
+                .text()
+                .to_string(),
+        )
+    };
+
+    match value {
+        AnyJsonValue::JsonArrayValue(array) => array
+            .elements()
+            .iter()
+            .map(|element| string_value(&element.ok()?))
+            .collect(),
+        _ => Some(vec![string_value(value)?]),
+    }
+}
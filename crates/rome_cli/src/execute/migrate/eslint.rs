@@ -0,0 +1,326 @@
+use crate::execute::migrate::{members, string_values, ForeignMigration};
+use indexmap::IndexSet;
+use rome_deserialize::StringSet;
+use rome_js_analyze::options::{PossibleOptions, RestrictedGlobalsOptions};
+use rome_json_syntax::AnyJsonValue;
+use rome_rowan::AstSeparatedList;
+use rome_service::configuration::linter::{RulePlainConfiguration, RuleWithOptions};
+use rome_service::configuration::{
+    Configuration, JavascriptConfiguration, LinterConfiguration, RuleConfiguration, Rules,
+};
+
+/// The ESLint configuration files that can be migrated, in order of precedence
+pub(crate) const CONFIGURATION_FILES: &[&str] = &[".eslintrc.json", ".eslintrc"];
+
+/// The ESLint rules that have an equivalent in Rome: the name of the ESLint rule,
+/// the group and the name of the Rome rule
+#[rustfmt::skip]
+const RULES: &[(&str, &str, &str)] = &[
+    // ESLint
+    ("constructor-super", "correctness", "noInvalidConstructorSuper"),
+    ("curly", "style", "useBlockStatements"),
+    ("default-case-last", "suspicious", "useDefaultSwitchClauseLast"),
+    ("default-param-last", "style", "useDefaultParameterLast"),
+    ("dot-notation", "complexity", "useLiteralKeys"),
+    ("eqeqeq", "suspicious", "noDoubleEquals"),
+    ("for-direction", "correctness", "useValidForDirection"),
+    ("no-async-promise-executor", "suspicious", "noAsyncPromiseExecutor"),
+    ("no-case-declarations", "correctness", "noSwitchDeclarations"),
+    ("no-class-assign", "suspicious", "noClassAssign"),
+    ("no-compare-neg-zero", "suspicious", "noCompareNegZero"),
+    ("no-cond-assign", "suspicious", "noAssignInExpressions"),
+    ("no-confusing-arrow", "nursery", "noConfusingArrow"),
+    ("no-const-assign", "correctness", "noConstAssign"),
+    ("no-constant-condition", "nursery", "noConstantCondition"),
+    ("no-constructor-return", "correctness", "noConstructorReturn"),
+    ("no-control-regex", "nursery", "noControlCharactersInRegex"),
+    ("no-debugger", "suspicious", "noDebugger"),
+    ("no-dupe-args", "suspicious", "noDuplicateParameters"),
+    ("no-dupe-class-members", "suspicious", "noDuplicateClassMembers"),
+    ("no-dupe-keys", "suspicious", "noDuplicateObjectKeys"),
+    ("no-duplicate-case", "suspicious", "noDuplicateCase"),
+    ("no-empty-pattern", "correctness", "noEmptyPattern"),
+    ("no-ex-assign", "suspicious", "noCatchAssign"),
+    ("no-extra-boolean-cast", "complexity", "noExtraBooleanCast"),
+    ("no-extra-label", "complexity", "noUselessLabel"),
+    ("no-fallthrough", "nursery", "noFallthroughSwitchClause"),
+    ("no-func-assign", "suspicious", "noFunctionAssign"),
+    ("no-import-assign", "suspicious", "noImportAssign"),
+    ("no-inner-declarations", "correctness", "noInnerDeclarations"),
+    ("no-label-var", "suspicious", "noLabelVar"),
+    ("no-loss-of-precision", "correctness", "noPrecisionLoss"),
+    ("no-negated-condition", "style", "noNegationElse"),
+    ("no-new-symbol", "correctness", "noNewSymbol"),
+    ("no-nonoctal-decimal-escape", "nursery", "noNonoctalDecimalEscape"),
+    ("no-obj-calls", "correctness", "noGlobalObjectCalls"),
+    ("no-param-reassign", "style", "noParameterAssign"),
+    ("no-prototype-builtins", "suspicious", "noPrototypeBuiltins"),
+    ("no-redeclare", "suspicious", "noRedeclare"),
+    ("no-regex-spaces", "complexity", "noMultipleSpacesInRegularExpressionLiterals"),
+    ("no-restricted-globals", "style", "noRestrictedGlobals"),
+    ("no-self-assign", "nursery", "noSelfAssign"),
+    ("no-self-compare", "suspicious", "noSelfCompare"),
+    ("no-sequences", "style", "noCommaOperator"),
+    ("no-setter-return", "correctness", "noSetterReturn"),
+    ("no-shadow-restricted-names", "suspicious", "noShadowRestrictedNames"),
+    ("no-sparse-arrays", "suspicious", "noSparseArray"),
+    ("no-this-before-super", "correctness", "noUnreachableSuper"),
+    ("no-undef", "correctness", "noUndeclaredVariables"),
+    ("no-unreachable", "correctness", "noUnreachable"),
+    ("no-unsafe-finally", "correctness", "noUnsafeFinally"),
+    ("no-unsafe-negation", "suspicious", "noUnsafeNegation"),
+    ("no-unsafe-optional-chaining", "correctness", "noUnsafeOptionalChaining"),
+    ("no-unused-labels", "correctness", "noUnusedLabels"),
+    ("no-unused-vars", "correctness", "noUnusedVariables"),
+    ("no-useless-catch", "complexity", "noUselessCatch"),
+    ("no-useless-constructor", "complexity", "noUselessConstructor"),
+    ("no-useless-rename", "complexity", "noUselessRename"),
+    ("no-var", "style", "noVar"),
+    ("no-void", "nursery", "noVoid"),
+    ("no-with", "complexity", "noWith"),
+    ("prefer-arrow-callback", "nursery", "useArrowFunction"),
+    ("prefer-const", "style", "useConst"),
+    ("prefer-exponentiation-operator", "style", "useExponentiationOperator"),
+    ("prefer-numeric-literals", "style", "useNumericLiterals"),
+    ("prefer-rest-params", "style", "noArguments"),
+    ("prefer-template", "style", "useTemplate"),
+    ("require-yield", "correctness", "useYield"),
+    ("use-isnan", "correctness", "useIsNan"),
+    ("valid-typeof", "suspicious", "useValidTypeof"),
+    // typescript-eslint
+    ("@typescript-eslint/array-type", "style", "useShorthandArrayType"),
+    ("@typescript-eslint/ban-types", "nursery", "noBannedTypes"),
+    ("@typescript-eslint/default-param-last", "style", "useDefaultParameterLast"),
+    ("@typescript-eslint/no-dupe-class-members", "suspicious", "noDuplicateClassMembers"),
+    ("@typescript-eslint/no-empty-interface", "suspicious", "noEmptyInterface"),
+    ("@typescript-eslint/no-explicit-any", "suspicious", "noExplicitAny"),
+    ("@typescript-eslint/no-extra-non-null-assertion", "suspicious", "noExtraNonNullAssertion"),
+    ("@typescript-eslint/no-extraneous-class", "nursery", "noStaticOnlyClass"),
+    ("@typescript-eslint/no-inferrable-types", "style", "noInferrableTypes"),
+    ("@typescript-eslint/no-namespace", "style", "noNamespace"),
+    ("@typescript-eslint/no-non-null-assertion", "style", "noNonNullAssertion"),
+    ("@typescript-eslint/no-redeclare", "suspicious", "noRedeclare"),
+    ("@typescript-eslint/no-unsafe-declaration-merging", "nursery", "noUnsafeDeclarationMerging"),
+    ("@typescript-eslint/no-unused-vars", "correctness", "noUnusedVariables"),
+    ("@typescript-eslint/no-useless-constructor", "complexity", "noUselessConstructor"),
+    ("@typescript-eslint/no-useless-empty-export", "nursery", "noUselessEmptyExport"),
+    ("@typescript-eslint/parameter-properties", "style", "noParameterProperties"),
+    ("@typescript-eslint/prefer-enum-initializers", "style", "useEnumInitializers"),
+    ("@typescript-eslint/prefer-literal-enum-member", "nursery", "useLiteralEnumMembers"),
+    ("@typescript-eslint/prefer-namespace-keyword", "suspicious", "useNamespaceKeyword"),
+    ("@typescript-eslint/prefer-optional-chain", "complexity", "useOptionalChain"),
+    // eslint-plugin-react
+    ("react/button-has-type", "a11y", "useButtonType"),
+    ("react/jsx-fragments", "style", "useFragmentSyntax"),
+    ("react/jsx-no-comment-textnodes", "suspicious", "noCommentText"),
+    ("react/jsx-no-duplicate-props", "suspicious", "noDuplicateJsxProps"),
+    ("react/jsx-no-target-blank", "a11y", "noBlankTarget"),
+    ("react/jsx-no-useless-fragment", "complexity", "noUselessFragments"),
+    ("react/no-array-index-key", "suspicious", "noArrayIndexKey"),
+    ("react/no-children-prop", "correctness", "noChildrenProp"),
+    ("react/no-danger", "security", "noDangerouslySetInnerHtml"),
+    ("react/no-danger-with-children", "security", "noDangerouslySetInnerHtmlWithChildren"),
+    ("react/no-render-return-value", "correctness", "noRenderReturnValue"),
+    ("react/self-closing-comp", "style", "useSelfClosingElements"),
+    ("react/void-dom-elements-no-children", "correctness", "noVoidElementsWithChildren"),
+    // eslint-plugin-react-hooks
+    ("react-hooks/exhaustive-deps", "nursery", "useExhaustiveDependencies"),
+    ("react-hooks/rules-of-hooks", "nursery", "useHookAtTopLevel"),
+    // eslint-plugin-jsx-a11y
+    ("jsx-a11y/alt-text", "a11y", "useAltText"),
+    ("jsx-a11y/anchor-has-content", "a11y", "useAnchorContent"),
+    ("jsx-a11y/anchor-is-valid", "a11y", "useValidAnchor"),
+    ("jsx-a11y/aria-props", "a11y", "useValidAriaProps"),
+    ("jsx-a11y/aria-proptypes", "nursery", "useAriaPropTypes"),
+    ("jsx-a11y/aria-unsupported-elements", "nursery", "noAriaUnsupportedElements"),
+    ("jsx-a11y/click-events-have-key-events", "a11y", "useKeyWithClickEvents"),
+    ("jsx-a11y/heading-has-content", "a11y", "useHeadingContent"),
+    ("jsx-a11y/html-has-lang", "a11y", "useHtmlLang"),
+    ("jsx-a11y/iframe-has-title", "a11y", "useIframeTitle"),
+    ("jsx-a11y/img-redundant-alt", "a11y", "noRedundantAlt"),
+    ("jsx-a11y/lang", "a11y", "useValidLang"),
+    ("jsx-a11y/media-has-caption", "a11y", "useMediaCaption"),
+    ("jsx-a11y/mouse-events-have-key-events", "a11y", "useKeyWithMouseEvents"),
+    ("jsx-a11y/no-access-key", "a11y", "noAccessKey"),
+    ("jsx-a11y/no-autofocus", "a11y", "noAutofocus"),
+    ("jsx-a11y/no-distracting-elements", "a11y", "noDistractingElements"),
+    ("jsx-a11y/no-noninteractive-element-to-interactive-role", "a11y", "noNoninteractiveElementToInteractiveRole"),
+    ("jsx-a11y/no-noninteractive-tabindex", "nursery", "noNoninteractiveTabindex"),
+    ("jsx-a11y/no-redundant-roles", "nursery", "noRedundantRoles"),
+    ("jsx-a11y/role-has-required-aria-props", "a11y", "useAriaPropsForRole"),
+    ("jsx-a11y/scope", "a11y", "noHeaderScope"),
+    ("jsx-a11y/tabindex-no-positive", "a11y", "noPositiveTabindex"),
+];
+
+/// The environments of ESLint whose globals are already known by Rome
+const KNOWN_ENVIRONMENTS: &[&str] = &[
+    "browser",
+    "commonjs",
+    "node",
+    "serviceworker",
+    "shared-node-browser",
+    "worker",
+];
+
+/// Migrates an ESLint configuration file
+pub(crate) fn migrate(eslint_configuration: &AnyJsonValue) -> Option<ForeignMigration> {
+    let mut rules = Rules {
+        recommended: None,
+        ..Rules::default()
+    };
+    let mut globals = IndexSet::new();
+    let mut ignore = IndexSet::new();
+    let mut unsupported_settings = Vec::new();
+
+    for (name, value) in members(eslint_configuration)? {
+        match name.as_str() {
+            "rules" => match members(&value) {
+                Some(members) => {
+                    for (rule_name, value) in members {
+                        migrate_rule(&rule_name, &value, &mut rules, &mut unsupported_settings);
+                    }
+                }
+                None => unsupported_settings.push(name),
+            },
+            "globals" => match members(&value) {
+                Some(members) => {
+                    for (global, value) in members {
+                        // `"off"` disables a global
+                        let is_enabled = value
+                            .as_json_string_value()
+                            .and_then(|value| value.inner_string_text().ok())
+                            .map_or(true, |value| value.text() != "off");
+                        if is_enabled {
+                            globals.insert(global);
+                        }
+                    }
+                }
+                None => unsupported_settings.push(name),
+            },
+            "env" => match members(&value) {
+                Some(members) => {
+                    for (environment, _) in members {
+                        let is_known = KNOWN_ENVIRONMENTS.contains(&environment.as_str())
+                            || environment.starts_with("es");
+                        if !is_known {
+                            unsupported_settings.push(format!("env.{environment}"));
+                        }
+                    }
+                }
+                None => unsupported_settings.push(name),
+            },
+            "ignorePatterns" => match string_values(&value) {
+                Some(patterns) => ignore.extend(patterns),
+                None => unsupported_settings.push(name),
+            },
+            // Rome doesn't merge the configuration files of the parent directories
+            "root" | "$schema" => {}
+            _ => unsupported_settings.push(name),
+        }
+    }
+
+    let javascript = (!globals.is_empty()).then(|| JavascriptConfiguration {
+        globals: Some(StringSet::new(globals)),
+        ..JavascriptConfiguration::default()
+    });
+
+    Some(ForeignMigration {
+        configuration: Configuration {
+            linter: Some(LinterConfiguration {
+                enabled: None,
+                rules: Some(rules),
+                ignore: (!ignore.is_empty()).then(|| StringSet::new(ignore)),
+            }),
+            javascript,
+            organize_imports: None,
+            ..Configuration::default()
+        },
+        unsupported_settings,
+    })
+}
+
+/// Migrates the configuration of the ESLint rule `name`, like `"error"` or `["error", options]`
+fn migrate_rule(
+    name: &str,
+    value: &AnyJsonValue,
+    rules: &mut Rules,
+    unsupported_settings: &mut Vec<String>,
+) {
+    let Some((_, group, rule_name)) = RULES.iter().find(|(eslint_name, ..)| *eslint_name == name)
+    else {
+        unsupported_settings.push(format!("rules.{name}"));
+        return;
+    };
+
+    let (severity, options) = match value {
+        AnyJsonValue::JsonArrayValue(array) => {
+            let mut elements = array.elements().iter().flatten();
+            let severity = elements.next();
+            (severity, elements.collect())
+        }
+        _ => (Some(value.clone()), Vec::new()),
+    };
+    let Some(level) = severity.as_ref().and_then(rule_level) else {
+        unsupported_settings.push(format!("rules.{name}"));
+        return;
+    };
+
+    let configuration = if options.is_empty() {
+        RuleConfiguration::Plain(level)
+    } else if let Some(options) = rule_options(name, &options) {
+        RuleConfiguration::WithOptions(RuleWithOptions {
+            level,
+            options: Some(options),
+        })
+    } else {
+        unsupported_settings.push(format!("rules.{name} (options)"));
+        RuleConfiguration::Plain(level)
+    };
+
+    let is_known_rule = rules.set_rule_configuration(group, rule_name, configuration);
+    debug_assert!(is_known_rule, "the rule {group}/{rule_name} doesn't exist");
+}
+
+/// Maps the severity of an ESLint rule: `"off"`, `"warn"`, `"error"` or their numeric values
+fn rule_level(severity: &AnyJsonValue) -> Option<RulePlainConfiguration> {
+    let level = match severity {
+        AnyJsonValue::JsonStringValue(value) => value.inner_string_text().ok()?.text().to_string(),
+        AnyJsonValue::JsonNumberValue(value) => {
+            value.value_token().ok()?.text_trimmed().to_string()
+        }
+        _ => return None,
+    };
+
+    match level.as_str() {
+        "off" | "0" => Some(RulePlainConfiguration::Off),
+        "warn" | "1" => Some(RulePlainConfiguration::Warn),
+        "error" | "2" => Some(RulePlainConfiguration::Error),
+        _ => None,
+    }
+}
+
+/// Maps the options of the ESLint rule `name` to the options of the Rome rule, if they are supported
+fn rule_options(name: &str, options: &[AnyJsonValue]) -> Option<PossibleOptions> {
+    match name {
+        // The globals are strings, or objects with a name and a message
+        "no-restricted-globals" => {
+            let denied_globals = options
+                .iter()
+                .map(|option| match option {
+                    AnyJsonValue::JsonObjectValue(_) => members(option)?
+                        .into_iter()
+                        .find(|(key, _)| key == "name")
+                        .and_then(|(_, name)| string_values(&name)?.pop()),
+                    _ => string_values(option)?.pop(),
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            Some(PossibleOptions::RestrictedGlobals(
+                RestrictedGlobalsOptions {
+                    denied_globals: Some(denied_globals),
+                },
+            ))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,107 @@
+use rome_json_factory::make;
+use rome_json_parser::{parse_json, JsonParserOptions};
+use rome_json_syntax::{
+    AnyJsonValue, JsonMember, JsonObjectValue, JsonRoot, JsonSyntaxKind, JsonSyntaxToken, T,
+};
+use rome_rowan::AstSeparatedList;
+use rome_service::configuration::Configuration;
+use serde_json::{Map, Value};
+
+/// Merges the settings of `configuration` into the configuration file `root`.
+///
+/// The settings of `configuration` override the existing ones, while the other
+/// members of the file are kept in place. The new members don't have any trivia, so
+/// the new file should be formatted.
+pub(crate) fn merge_configuration(
+    root: &JsonRoot,
+    configuration: &Configuration,
+) -> Option<JsonRoot> {
+    let Value::Object(settings) = serde_json::to_value(configuration).ok()? else {
+        return None;
+    };
+    let value = root.value().ok()?;
+    let object = merge_object(value.as_json_object_value()?, &settings)?;
+
+    Some(root.clone().with_value(AnyJsonValue::from(object)))
+}
+
+fn merge_object(
+    object: &JsonObjectValue,
+    settings: &Map<String, Value>,
+) -> Option<JsonObjectValue> {
+    let list = object.json_member_list();
+    let mut members: Vec<JsonMember> = list.iter().collect::<Result<_, _>>().ok()?;
+
+    for (key, setting) in settings {
+        if is_empty(setting) {
+            continue;
+        }
+
+        let position = members.iter().position(|member| {
+            member
+                .name()
+                .and_then(|name| name.inner_string_text())
+                .map_or(false, |name| name.text() == key)
+        });
+
+        match position {
+            Some(position) => {
+                let member = members[position].clone();
+                let value = member.value().ok()?;
+                let new_value = match (value.as_json_object_value(), setting) {
+                    (Some(object), Value::Object(settings)) => {
+                        AnyJsonValue::from(merge_object(object, settings)?)
+                    }
+                    _ => json_value(setting)?,
+                };
+                members[position] = member.with_value(new_value);
+            }
+            None => {
+                let name = JsonSyntaxToken::new_detached(
+                    JsonSyntaxKind::JSON_STRING_LITERAL,
+                    &serde_json::to_string(key).ok()?,
+                    [],
+                    [],
+                );
+                members.push(make::json_member(
+                    make::json_member_name(name),
+                    JsonSyntaxToken::new_detached(T![:], ":", [], []),
+                    json_value(setting)?,
+                ));
+            }
+        }
+    }
+
+    let separators: Vec<_> = list
+        .separators()
+        .flatten()
+        .chain(std::iter::repeat_with(|| {
+            JsonSyntaxToken::new_detached(T![,], ",", [], [])
+        }))
+        .take(members.len().saturating_sub(1))
+        .collect();
+
+    Some(
+        object
+            .clone()
+            .with_json_member_list(Some(make::json_member_list(members, separators))),
+    )
+}
+
+/// Returns `true` if `value` doesn't contain any setting
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Object(map) => map.values().all(is_empty),
+        _ => false,
+    }
+}
+
+/// Creates the JSON node of `value`
+fn json_value(value: &Value) -> Option<AnyJsonValue> {
+    let source = serde_json::to_string(value).ok()?;
+    parse_json(&source, JsonParserOptions::default())
+        .tree()
+        .value()
+        .ok()
+}
@@ -0,0 +1,148 @@
+use crate::execute::migrate::{members, ForeignMigration};
+use rome_formatter::LineWidth;
+use rome_js_formatter::context::trailing_comma::TrailingComma;
+use rome_js_formatter::context::{ArrowParentheses, QuoteProperties, QuoteStyle, Semicolons};
+use rome_json_syntax::AnyJsonValue;
+use rome_service::configuration::{
+    Configuration, FormatterConfiguration, JavascriptConfiguration, JavascriptFormatter,
+    PlainIndentStyle,
+};
+
+/// The Prettier configuration files that can be migrated, in order of precedence
+pub(crate) const CONFIGURATION_FILES: &[&str] = &[".prettierrc", ".prettierrc.json"];
+
+/// A value of the Prettier configuration
+enum PrettierValue {
+    Boolean(bool),
+    Number(u16),
+    String(String),
+}
+
+impl PrettierValue {
+    fn from_json(value: &AnyJsonValue) -> Option<Self> {
+        let value = match value {
+            AnyJsonValue::JsonBooleanValue(value) => {
+                Self::Boolean(value.value_token().ok()?.text_trimmed() == "true")
+            }
+            AnyJsonValue::JsonNumberValue(value) => {
+                Self::Number(value.value_token().ok()?.text_trimmed().parse().ok()?)
+            }
+            AnyJsonValue::JsonStringValue(value) => {
+                Self::String(value.inner_string_text().ok()?.text().to_string())
+            }
+            _ => return None,
+        };
+
+        Some(value)
+    }
+}
+
+/// Migrates a Prettier configuration file
+pub(crate) fn migrate(prettier_configuration: &AnyJsonValue) -> Option<ForeignMigration> {
+    let mut formatter = FormatterConfiguration {
+        enabled: None,
+        format_with_errors: None,
+        // Prettier indents with spaces by default, whereas Rome indents with tabs
+        indent_style: Some(PlainIndentStyle::Space),
+        indent_size: None,
+        line_width: None,
+        ignore: None,
+    };
+    let mut javascript_formatter = JavascriptFormatter::default();
+    let mut unsupported_settings = Vec::new();
+
+    for (name, value) in members(prettier_configuration)? {
+        let is_supported = match (name.as_str(), PrettierValue::from_json(&value)) {
+            ("printWidth", Some(PrettierValue::Number(width))) => LineWidth::try_from(width)
+                .map(|width| formatter.line_width = Some(width))
+                .is_ok(),
+            ("tabWidth", Some(PrettierValue::Number(width))) => u8::try_from(width)
+                .map(|width| formatter.indent_size = Some(width))
+                .is_ok(),
+            ("useTabs", Some(PrettierValue::Boolean(use_tabs))) => {
+                formatter.indent_style = Some(if use_tabs {
+                    PlainIndentStyle::Tab
+                } else {
+                    PlainIndentStyle::Space
+                });
+                true
+            }
+            ("semi", Some(PrettierValue::Boolean(semi))) => {
+                javascript_formatter.semicolons = Some(if semi {
+                    Semicolons::Always
+                } else {
+                    Semicolons::AsNeeded
+                });
+                true
+            }
+            ("singleQuote", Some(PrettierValue::Boolean(single_quote))) => {
+                javascript_formatter.quote_style = Some(quote_style(single_quote));
+                true
+            }
+            ("jsxSingleQuote", Some(PrettierValue::Boolean(single_quote))) => {
+                javascript_formatter.jsx_quote_style = Some(quote_style(single_quote));
+                true
+            }
+            ("quoteProps", Some(PrettierValue::String(quote_props))) => {
+                let quote_properties = match quote_props.as_str() {
+                    "as-needed" => Some(QuoteProperties::AsNeeded),
+                    "preserve" => Some(QuoteProperties::Preserve),
+                    _ => None,
+                };
+                javascript_formatter.quote_properties = quote_properties;
+                quote_properties.is_some()
+            }
+            ("trailingComma", Some(PrettierValue::String(trailing_comma))) => {
+                let trailing_comma = match trailing_comma.as_str() {
+                    "all" => Some(TrailingComma::All),
+                    "es5" => Some(TrailingComma::Es5),
+                    "none" => Some(TrailingComma::None),
+                    _ => None,
+                };
+                javascript_formatter.trailing_comma = trailing_comma;
+                trailing_comma.is_some()
+            }
+            ("arrowParens", Some(PrettierValue::String(arrow_parens))) => {
+                let arrow_parentheses = match arrow_parens.as_str() {
+                    "always" => Some(ArrowParentheses::Always),
+                    "avoid" => Some(ArrowParentheses::AsNeeded),
+                    _ => None,
+                };
+                javascript_formatter.arrow_parentheses = arrow_parentheses;
+                arrow_parentheses.is_some()
+            }
+            // Rome always formats the code like these values
+            ("bracketSpacing", Some(PrettierValue::Boolean(true)))
+            | ("bracketSameLine", Some(PrettierValue::Boolean(false))) => true,
+            ("endOfLine", Some(PrettierValue::String(end_of_line))) => end_of_line == "lf",
+            ("$schema", _) => true,
+            _ => false,
+        };
+
+        if !is_supported {
+            unsupported_settings.push(name);
+        }
+    }
+
+    Some(ForeignMigration {
+        configuration: Configuration {
+            formatter: Some(formatter),
+            javascript: Some(JavascriptConfiguration {
+                formatter: Some(javascript_formatter),
+                ..JavascriptConfiguration::default()
+            }),
+            linter: None,
+            organize_imports: None,
+            ..Configuration::default()
+        },
+        unsupported_settings,
+    })
+}
+
+fn quote_style(single_quote: bool) -> QuoteStyle {
+    if single_quote {
+        QuoteStyle::Single
+    } else {
+        QuoteStyle::Double
+    }
+}
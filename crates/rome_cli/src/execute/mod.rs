@@ -1,15 +1,29 @@
+mod baseline;
+mod cache;
 mod diagnostics;
 mod migrate;
 mod process_file;
 mod std_in;
 mod traverse;
+mod watch;
 
-use crate::cli_options::CliOptions;
+use crate::cli_options::{CliOptions, ReporterArg};
+use crate::execute::cache::ResultCache;
 use crate::execute::traverse::traverse;
+pub use crate::execute::watch::{FileWatcher, PollWatcher};
+use crate::reports::checkstyle::CheckstyleReport;
+use crate::reports::github::GithubReport;
+use crate::reports::gitlab::GitlabReport;
+use crate::reports::junit::JunitReport;
+use crate::reports::sarif::SarifReport;
+use crate::reports::Reporter;
+use crate::vcs::ChangedFiles;
+use crate::Report;
 use crate::{CliDiagnostic, CliSession};
 use rome_diagnostics::{category, Category, MAXIMUM_DISPLAYABLE_DIAGNOSTICS};
 use rome_fs::RomePath;
-use rome_service::workspace::{FeatureName, FixFileMode};
+use rome_service::workspace::{FeatureName, FixFileMode, RuleSelector};
+use rome_service::Configuration;
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
@@ -24,6 +38,17 @@ pub(crate) struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u16,
+
+    /// A hash of the configuration used to process the files. The results of the files are
+    /// cached only when it's known
+    configuration_hash: Option<String>,
+
+    /// The configuration of the root, the configuration files found in the subdirectories are
+    /// merged onto it
+    configuration: Option<Configuration>,
+
+    /// Whether the files are processed again when they change
+    watch: bool,
 }
 
 impl Execution {
@@ -60,6 +85,10 @@ pub(crate) enum TraversalMode {
         /// 1. The virtual path to the file
         /// 2. The content of the file
         stdin: Option<(PathBuf, String)>,
+        /// The rules passed to `--only`, they run in place of the rules enabled in the configuration
+        only: Vec<RuleSelector>,
+        /// The rules passed to `--skip`, they don't run
+        skip: Vec<RuleSelector>,
     },
     /// This mode is enabled when running the command `rome ci`
     CI,
@@ -78,6 +107,10 @@ pub(crate) enum TraversalMode {
     Migrate {
         write: bool,
         configuration_path: PathBuf,
+        /// Migrates the settings of the ESLint configuration file
+        eslint: bool,
+        /// Migrates the settings of the Prettier configuration file
+        prettier: bool,
     },
 }
 
@@ -101,6 +134,46 @@ pub(crate) enum ReportMode {
     Terminal,
     /// Reports information in JSON format
     Json,
+    /// Reports the diagnostics in the SARIF format
+    Sarif,
+    /// Reports the diagnostics in the JUnit XML format
+    Junit,
+    /// Reports the diagnostics in the Checkstyle XML format
+    Checkstyle,
+    /// Reports the diagnostics in the GitLab Code Quality format
+    Gitlab,
+    /// Reports the diagnostics as GitHub Actions annotations
+    Github,
+}
+
+impl ReportMode {
+    /// Creates the [Reporter] that collects the diagnostics, it's [None] when the
+    /// diagnostics are printed to the console
+    pub(crate) fn create_reporter(self) -> Option<Box<dyn Reporter>> {
+        match self {
+            ReportMode::Terminal => None,
+            ReportMode::Json => Some(Box::<Report>::default()),
+            ReportMode::Sarif => Some(Box::<SarifReport>::default()),
+            ReportMode::Junit => Some(Box::<JunitReport>::default()),
+            ReportMode::Checkstyle => Some(Box::<CheckstyleReport>::default()),
+            ReportMode::Gitlab => Some(Box::<GitlabReport>::default()),
+            ReportMode::Github => Some(Box::<GithubReport>::default()),
+        }
+    }
+}
+
+impl From<Option<ReporterArg>> for ReportMode {
+    fn from(reporter: Option<ReporterArg>) -> Self {
+        match reporter {
+            None => Self::Terminal,
+            Some(ReporterArg::Json) => Self::Json,
+            Some(ReporterArg::Sarif) => Self::Sarif,
+            Some(ReporterArg::Junit) => Self::Junit,
+            Some(ReporterArg::Checkstyle) => Self::Checkstyle,
+            Some(ReporterArg::Gitlab) => Self::Gitlab,
+            Some(ReporterArg::Github) => Self::Github,
+        }
+    }
 }
 
 impl Execution {
@@ -109,6 +182,9 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            configuration_hash: None,
+            configuration: None,
+            watch: false,
         }
     }
 
@@ -118,14 +194,43 @@ impl Execution {
             traversal_mode,
             report_mode,
             max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            configuration_hash: None,
+            configuration: None,
+            watch: false,
         }
     }
 
+    /// Sets the configuration used to process the files, the results of the files are cached
+    /// for this configuration
+    pub(crate) fn with_configuration(mut self, configuration: &Configuration) -> Self {
+        self.configuration_hash = Some(ResultCache::configuration_hash(configuration));
+        self.configuration = Some(configuration.clone());
+        self
+    }
+
+    pub(crate) fn configuration_hash(&self) -> Option<&str> {
+        self.configuration_hash.as_deref()
+    }
+
+    pub(crate) fn configuration(&self) -> Option<&Configuration> {
+        self.configuration.as_ref()
+    }
+
+    /// Keeps processing the files when they change, instead of exiting after the first traversal
+    pub(crate) fn with_watch(mut self, watch: bool) -> Self {
+        self.watch = watch;
+        self
+    }
+
     /// Tells if the reporting is happening straight to terminal
     pub(crate) fn should_report_to_terminal(&self) -> bool {
         matches!(self.report_mode, ReportMode::Terminal)
     }
 
+    pub(crate) const fn report_mode(&self) -> ReportMode {
+        self.report_mode
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
@@ -145,6 +250,22 @@ impl Execution {
         }
     }
 
+    /// The rules that run in place of the rules enabled in the configuration, when they aren't empty
+    pub(crate) fn only_rules(&self) -> &[RuleSelector] {
+        match &self.traversal_mode {
+            TraversalMode::Lint { only, .. } => only,
+            _ => &[],
+        }
+    }
+
+    /// The rules that don't run, even when they're enabled in the configuration
+    pub(crate) fn skipped_rules(&self) -> &[RuleSelector] {
+        match &self.traversal_mode {
+            TraversalMode::Lint { skip, .. } => skip,
+            _ => &[],
+        }
+    }
+
     pub(crate) fn as_diagnostic_category(&self) -> &'static Category {
         match self.traversal_mode {
             TraversalMode::Check { .. } => category!("check"),
@@ -216,7 +337,7 @@ impl Execution {
 /// or handles the stdin file.
 pub(crate) fn execute_mode(
     mut mode: Execution,
-    session: CliSession,
+    mut session: CliSession,
     cli_options: &CliOptions,
     paths: Vec<OsString>,
 ) -> Result<(), CliDiagnostic> {
@@ -229,6 +350,13 @@ pub(crate) fn execute_mode(
 
     mode.max_diagnostics = cli_options.max_diagnostics;
 
+    if cli_options.reporter_file.is_some() && mode.should_report_to_terminal() {
+        return Err(CliDiagnostic::missing_argument(
+            "--reporter",
+            format!("{}", mode.traversal_mode),
+        ));
+    }
+
     // don't do any traversal if there's some content coming from stdin
     if let Some((path, content)) = mode.as_stdin_file() {
         let rome_path = RomePath::new(path);
@@ -236,10 +364,32 @@ pub(crate) fn execute_mode(
     } else if let TraversalMode::Migrate {
         write,
         configuration_path,
+        eslint,
+        prettier,
     } = mode.traversal_mode
     {
-        migrate::run(session, write, configuration_path, cli_options.verbose)
+        migrate::run(
+            session,
+            migrate::MigrateOptions {
+                write,
+                configuration_path,
+                eslint,
+                prettier,
+                verbose: cli_options.verbose,
+            },
+        )
     } else {
-        traverse(mode, session, cli_options, paths)
+        let changed_files = ChangedFiles::from_options(&session, cli_options)?;
+        if mode.watch {
+            watch::run(&mode, session, cli_options, paths, changed_files)
+        } else {
+            traverse(
+                &mode,
+                &mut session,
+                cli_options,
+                paths,
+                changed_files.as_ref(),
+            )
+        }
     }
 }
@@ -4,6 +4,7 @@ mod lint;
 mod organize_imports;
 mod workspace_file;
 
+use crate::execute::cache::ResultCache;
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::process_file::check::check_file;
 use crate::execute::process_file::format::format;
@@ -12,8 +13,9 @@ use crate::execute::traverse::TraversalOptions;
 use crate::execute::TraversalMode;
 use crate::CliDiagnostic;
 use rome_diagnostics::{category, DiagnosticExt, Error};
-use rome_fs::RomePath;
+use rome_fs::{FileSystemExt, RomePath};
 use rome_service::workspace::{FeatureName, FeaturesBuilder, SupportKind, SupportsFeatureParams};
+use std::cell::Cell;
 use std::marker::PhantomData;
 use std::ops::Deref;
 use std::path::Path;
@@ -85,6 +87,8 @@ pub(crate) type FileResult = Result<FileStatus, Message>;
 /// compiler constraints set by the lifetimes of the [TraversalOptions]
 pub(crate) struct SharedTraversalOptions<'ctx, 'app> {
     inner: &'app TraversalOptions<'ctx, 'app>,
+    /// Whether a message was sent to the display thread while processing the file
+    has_messages: Cell<bool>,
     _p: PhantomData<&'app ()>,
 }
 
@@ -92,9 +96,16 @@ impl<'ctx, 'app> SharedTraversalOptions<'ctx, 'app> {
     fn new(t: &'app TraversalOptions<'ctx, 'app>) -> Self {
         Self {
             _p: PhantomData::default(),
+            has_messages: Cell::new(false),
             inner: t,
         }
     }
+
+    /// Send a message to the display thread
+    pub(crate) fn push_message(&self, msg: impl Into<Message>) {
+        self.has_messages.set(true);
+        self.inner.push_message(msg);
+    }
 }
 
 impl<'ctx, 'app> Deref for SharedTraversalOptions<'ctx, 'app> {
@@ -111,7 +122,7 @@ impl<'ctx, 'app> Deref for SharedTraversalOptions<'ctx, 'app> {
 /// content of the file and emit a diff or write the new content to the disk if
 /// write mode is enabled
 pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
-    tracing::trace_span!("process_file", path = ?path).in_scope(move || {
+    tracing::trace_span!("process_file", path = %path.display()).in_scope(move || {
         let rome_path = RomePath::new(path);
         let file_features = ctx
             .workspace
@@ -210,9 +221,27 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
             };
         }
 
+        // The content is only read to look up the cache, the file is read again when it's processed
+        // The files of a directory with a nested configuration file are cached with the hash of
+        // this configuration, the hash of the root configuration is checked when the cache loads
+        let cached_content = ctx.cache.and_then(|cache| {
+            let content = read_content(ctx, path)?;
+            let configuration_hash = ctx
+                .nested_configurations
+                .and_then(|nested| nested.get(path.parent()?))
+                .map(|configuration| ResultCache::configuration_hash(&configuration));
+            Some((cache, content, configuration_hash))
+        });
+        if let Some((cache, content, configuration_hash)) = &cached_content {
+            if cache.is_clean(path, content, configuration_hash.as_deref()) {
+                ctx.increment_processed();
+                return Ok(FileStatus::Success);
+            }
+        }
+
         let shared_context = &SharedTraversalOptions::new(ctx);
         ctx.increment_processed();
-        match ctx.execution.traversal_mode {
+        let result = match ctx.execution.traversal_mode {
             TraversalMode::Lint { .. } => {
                 // the unsupported case should be handled already at this point
                 lint(shared_context, path)
@@ -228,6 +257,22 @@ pub(crate) fn process_file(ctx: &TraversalOptions, path: &Path) -> FileResult {
             TraversalMode::Migrate { .. } => {
                 unreachable!("The migration should not be called for this file")
             }
+        };
+
+        if let Some((cache, content, configuration_hash)) = &cached_content {
+            let is_clean =
+                matches!(result, Ok(FileStatus::Success)) && !shared_context.has_messages.get();
+            cache.update(path, content, configuration_hash.as_deref(), is_clean);
         }
+
+        result
     })
 }
+
+/// Reads the content of the file at `path`, the errors are reported when the file is processed
+fn read_content(ctx: &TraversalOptions, path: &Path) -> Option<String> {
+    let mut file = ctx.fs.open(path).ok()?;
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    Some(content)
+}
@@ -22,7 +22,12 @@ pub(crate) fn format_with_guard<'ctx>(
     let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
     let diagnostics_result = workspace_file
         .guard()
-        .pull_diagnostics(RuleCategories::SYNTAX, max_diagnostics.into())
+        .pull_diagnostics(
+            RuleCategories::SYNTAX,
+            max_diagnostics.into(),
+            vec![],
+            vec![],
+        )
         .with_file_path_and_code(
             workspace_file.path.display().to_string(),
             category!("format"),
@@ -1,9 +1,10 @@
-use crate::execute::diagnostics::ResultExt;
+use crate::execute::diagnostics::{CantLoadJsonSchemaDiagnostic, ResultExt};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
 use crate::CliDiagnostic;
-use rome_diagnostics::{category, Error};
-use rome_service::workspace::RuleCategories;
+use rome_diagnostics::{category, Error, PrintDescription};
+use rome_fs::FileSystemExt;
+use rome_service::workspace::{RegisterJsonSchemaParams, ResolveJsonSchemaResult, RuleCategories};
 use std::path::Path;
 use std::sync::atomic::Ordering;
 
@@ -23,7 +24,12 @@ pub(crate) fn lint_with_guard<'ctx>(
     if let Some(fix_mode) = ctx.execution.as_fix_file_mode() {
         let fixed = workspace_file
             .guard()
-            .fix_file(*fix_mode, false)
+            .fix_file(
+                *fix_mode,
+                false,
+                ctx.execution.only_rules().to_vec(),
+                ctx.execution.skipped_rules().to_vec(),
+            )
             .with_file_path_and_code(
                 workspace_file.path.display().to_string(),
                 category!("lint"),
@@ -40,10 +46,17 @@ pub(crate) fn lint_with_guard<'ctx>(
         errors = fixed.errors;
     }
 
+    register_json_schema(ctx, workspace_file);
+
     let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
     let pull_diagnostics_result = workspace_file
         .guard()
-        .pull_diagnostics(RuleCategories::LINT, max_diagnostics.into())
+        .pull_diagnostics(
+            RuleCategories::LINT,
+            max_diagnostics.into(),
+            ctx.execution.only_rules().to_vec(),
+            ctx.execution.skipped_rules().to_vec(),
+        )
         .with_file_path_and_code(workspace_file.path.display().to_string(), category!("lint"))?;
 
     let no_diagnostics = pull_diagnostics_result.diagnostics.is_empty()
@@ -74,3 +87,49 @@ pub(crate) fn lint_with_guard<'ctx>(
         Ok(FileStatus::Success)
     }
 }
+
+/// Reads the JSON Schema used to validate the file from the file system and registers it
+/// in the workspace, unless it was already read by another file
+fn register_json_schema(ctx: &SharedTraversalOptions, workspace_file: &WorkspaceFile) {
+    let Ok(ResolveJsonSchemaResult {
+        schema_path: Some(schema_path),
+        is_registered: false,
+    }) = workspace_file.guard().resolve_json_schema() else {
+        return;
+    };
+
+    let is_new = ctx
+        .json_schemas
+        .lock()
+        .unwrap()
+        .insert(schema_path.to_path_buf());
+    if !is_new {
+        return;
+    }
+
+    let content = ctx.fs.open(&schema_path).and_then(|mut file| {
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+        Ok(content)
+    });
+    let result = match content {
+        Ok(content) => ctx
+            .workspace
+            .register_json_schema(RegisterJsonSchemaParams {
+                path: schema_path.clone(),
+                content,
+            })
+            .map_err(|error| PrintDescription(&error).to_string()),
+        Err(error) => Err(error.to_string()),
+    };
+
+    if let Err(reason) = result {
+        ctx.push_message(Error::from(CantLoadJsonSchemaDiagnostic {
+            file_name: workspace_file.path.display().to_string(),
+            message: format!(
+                "Rome couldn't load the JSON Schema {}: {reason}",
+                schema_path.display()
+            ),
+        }));
+    }
+}
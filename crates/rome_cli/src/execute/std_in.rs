@@ -74,6 +74,8 @@ pub(crate) fn run<'a>(
                     path: rome_path.clone(),
                     should_format: mode.is_check()
                         && file_features.supports_for(&FeatureName::Format),
+                    only: mode.only_rules().to_vec(),
+                    skip: mode.skipped_rules().to_vec(),
                 })?;
                 if fix_file_result.code != new_content {
                     version += 1;
@@ -107,6 +109,8 @@ pub(crate) fn run<'a>(
                 categories: RuleCategories::LINT | RuleCategories::SYNTAX,
                 path: rome_path.clone(),
                 max_diagnostics: mode.max_diagnostics.into(),
+                only: mode.only_rules().to_vec(),
+                skip: mode.skipped_rules().to_vec(),
             })?;
             diagnostics.extend(result.diagnostics);
         }
@@ -1,12 +1,17 @@
+use super::baseline::{Baseline, BaselineMode};
+use super::cache::ResultCache;
 use super::process_file::{process_file, DiffKind, FileStatus, Message};
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     CIFormatDiffDiagnostic, CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice,
     FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic, PanicDiagnostic,
 };
+use crate::reports::{Reporter, TraversalSummary};
+use crate::timings::{self, TimingsReport};
+use crate::vcs::ChangedFiles;
 use crate::{
-    CliDiagnostic, CliSession, Execution, FormatterReportFileDetail, FormatterReportSummary,
-    Report, ReportDiagnostic, ReportDiff, ReportErrorKind, ReportKind, TraversalMode,
+    CliDiagnostic, CliSession, Execution, FormatterReportFileDetail, ReportDiff, ReportKind,
+    TraversalMode,
 };
 use crossbeam::{
     channel::{unbounded, Receiver, Sender},
@@ -14,11 +19,12 @@ use crossbeam::{
 };
 use rome_console::{fmt, markup, Console, ConsoleExt};
 use rome_diagnostics::{
-    adapters::StdError, category, DiagnosticExt, Error, PrintDescription, PrintDiagnostic,
-    Resource, Severity,
+    adapters::StdError, category, Diagnostic, DiagnosticExt, Error, PrintDiagnostic, Resource,
+    Severity,
 };
-use rome_fs::{FileSystem, PathInterner, RomePath};
+use rome_fs::{FileSystem, FileSystemExt, PathInterner, RomePath};
 use rome_fs::{TraversalContext, TraversalScope};
+use rome_service::configuration::nested::NestedConfigurations;
 use rome_service::workspace::{FeaturesBuilder, IsPathIgnoredParams};
 use rome_service::{
     workspace::{FeatureName, SupportsFeatureParams},
@@ -32,7 +38,7 @@ use std::{
     path::{Path, PathBuf},
     sync::{
         atomic::{AtomicU16, AtomicUsize, Ordering},
-        Once,
+        Mutex, Once,
     },
     thread,
     time::{Duration, Instant},
@@ -54,21 +60,75 @@ impl fmt::Display for CheckResult {
     }
 }
 
-///
+/// Processes the files of `inputs`. When `changed_files` is passed, only these files are processed,
+/// and they're processed even without `inputs`
 pub(crate) fn traverse(
-    execution: Execution,
-    session: CliSession,
+    execution: &Execution,
+    session: &mut CliSession,
     cli_options: &CliOptions,
     inputs: Vec<OsString>,
+    changed_files: Option<&ChangedFiles>,
 ) -> Result<(), CliDiagnostic> {
     init_thread_pool();
-    if inputs.is_empty() && execution.as_stdin_file().is_none() {
-        return Err(CliDiagnostic::missing_argument(
-            "<INPUT>",
-            format!("{}", execution.traversal_mode),
-        ));
+    let inputs = match changed_files {
+        // Without inputs, only the changed files are traversed
+        Some(changed_files) if inputs.is_empty() => changed_files
+            .paths()
+            .into_iter()
+            .map(PathBuf::into_os_string)
+            .collect(),
+        _ => {
+            if inputs.is_empty() && execution.as_stdin_file().is_none() {
+                return Err(CliDiagnostic::missing_argument(
+                    "<INPUT>",
+                    format!("{}", execution.traversal_mode),
+                ));
+            }
+            inputs
+        }
+    };
+
+    if cli_options.timings {
+        // The rules and the phases run in the daemon, where they can't be measured
+        if cli_options.use_server {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--timings",
+                "--use-server",
+            ));
+        }
+        timings::start();
     }
 
+    // The files are only skipped when they're checked, the commands that write the files
+    // process all of them. The results of a subset of the rules aren't cached, and all the
+    // files are processed when they're timed
+    let cache = match (
+        &session.environment.cache_directory,
+        execution.configuration_hash(),
+    ) {
+        (Some(directory), Some(configuration_hash))
+            if !cli_options.no_cache
+                && !execution.requires_write_access()
+                && execution.only_rules().is_empty()
+                && execution.skipped_rules().is_empty()
+                && !cli_options.timings =>
+        {
+            Some(ResultCache::load(
+                &*session.app.fs,
+                directory,
+                execution.traversal_mode().to_string(),
+                configuration_hash,
+            ))
+        }
+        _ => None,
+    };
+
+    let mut baseline = Baseline::from_options(
+        &*session.app.fs,
+        cli_options.baseline.as_deref(),
+        cli_options.write_baseline.as_deref(),
+    )?;
+
     let (interner, recv_files) = PathInterner::new();
     let (send_msgs, recv_msgs) = unbounded();
     let (sender_reports, recv_reports) = unbounded();
@@ -80,19 +140,26 @@ pub(crate) fn traverse(
     let workspace = &*session.app.workspace;
     let console = &mut *session.app.console;
 
+    let nested_configurations = execution.configuration().map(|configuration| {
+        NestedConfigurations::new(
+            configuration.clone(),
+            fs.working_directory().unwrap_or_default(),
+        )
+    });
+
     let max_diagnostics = execution.get_max_diagnostics();
     let remaining_diagnostics = AtomicU16::new(max_diagnostics);
 
     let mut errors: usize = 0;
     let mut warnings: usize = 0;
-    let mut report = Report::default();
+    let mut reporter = execution.report_mode().create_reporter();
 
     let duration = thread::scope(|s| {
         thread::Builder::new()
             .name(String::from("rome::console"))
             .spawn_scoped(s, || {
                 process_messages(ProcessMessagesOptions {
-                    execution: &execution,
+                    execution,
                     console,
                     recv_reports,
                     recv_files,
@@ -100,7 +167,8 @@ pub(crate) fn traverse(
                     max_diagnostics,
                     remaining_diagnostics: &remaining_diagnostics,
                     errors: &mut errors,
-                    report: &mut report,
+                    reporter: &mut reporter,
+                    baseline: &mut baseline,
                     verbose: cli_options.verbose,
                     warnings: &mut warnings,
                 });
@@ -115,19 +183,35 @@ pub(crate) fn traverse(
             &TraversalOptions {
                 fs,
                 workspace,
-                execution: &execution,
+                execution,
                 interner,
                 processed: &processed,
                 skipped: &skipped,
                 messages: send_msgs,
                 sender_reports,
                 remaining_diagnostics: &remaining_diagnostics,
+                json_schemas: Mutex::default(),
+                changed_files,
+                cache: cache.as_ref(),
+                nested_configurations: nested_configurations.as_ref(),
             },
         )
     });
 
     let count = processed.load(Ordering::Relaxed);
     let skipped = skipped.load(Ordering::Relaxed);
+    let timings = cli_options.timings.then(timings::finish);
+
+    if let Some(cache) = &cache {
+        // A cache that can't be written only makes the next run slower
+        let _ = cache.save(fs);
+    }
+
+    if let Some(baseline) = &baseline {
+        if *baseline.mode() == BaselineMode::Write {
+            baseline.save(fs)?;
+        }
+    }
 
     if execution.should_report_to_terminal() {
         match execution.traversal_mode() {
@@ -178,30 +262,45 @@ pub(crate) fn traverse(
                 });
             }
         }
-    } else {
-        if let TraversalMode::Format { write, .. } = execution.traversal_mode() {
-            let mut summary = FormatterReportSummary::default();
-            if *write {
-                summary.set_files_written(count);
-            } else {
-                summary.set_files_compared(count);
-            }
-            report.set_formatter_summary(summary);
+    } else if let Some(reporter) = reporter {
+        let summary = TraversalSummary { count };
+        let report = reporter.finish(execution, &summary)?;
+        match &cli_options.reporter_file {
+            Some(path) => fs
+                .create(path)
+                .and_then(|mut file| file.set_content(report.as_bytes()))
+                .map_err(CliDiagnostic::io_error)?,
+            None => console.log(markup! {
+                {report}
+            }),
         }
+    }
 
-        let to_print = report.as_serialized_reports()?;
+    if skipped > 0 && execution.should_report_to_terminal() {
         console.log(markup! {
-            {to_print}
+            <Warn>"Skipped "{skipped}" file(s)"</Warn>
         });
-        return Ok(());
     }
 
-    if skipped > 0 {
+    if let (Some(timings), true) = (&timings, execution.should_report_to_terminal()) {
         console.log(markup! {
-            <Warn>"Skipped "{skipped}" file(s)"</Warn>
+            {TimingsReport(timings)}
         });
     }
 
+    if let (Some(baseline), true) = (&baseline, execution.should_report_to_terminal()) {
+        let suppressed = baseline.suppressed();
+        match baseline.mode() {
+            BaselineMode::Write => console.log(markup! {
+                <Info>"Recorded "{suppressed}" diagnostic(s) in the baseline"</Info>
+            }),
+            BaselineMode::Suppress if suppressed > 0 => console.log(markup! {
+                <Info>"Ignored "{suppressed}" diagnostic(s) recorded in the baseline"</Info>
+            }),
+            BaselineMode::Suppress => {}
+        }
+    }
+
     let should_exit_on_warnings = warnings > 0 && cli_options.error_on_warnings;
     // Processing emitted error diagnostics, exit with a non-zero code
     if count.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
@@ -273,9 +372,11 @@ struct ProcessMessagesOptions<'ctx> {
     /// Mutable reference to a boolean flag tracking whether the console thread
     /// printed any warnings-level message
     warnings: &'ctx mut usize,
-    /// Mutable handle to a [Report] instance the console thread should write
-    /// stats into
-    report: &'ctx mut Report,
+    /// Mutable handle to the [Reporter] the console thread should write the diagnostics
+    /// and the stats into, it's [None] when the diagnostics are printed to the console
+    reporter: &'ctx mut Option<Box<dyn Reporter>>,
+    /// The diagnostics that aren't reported, with `--baseline` or `--write-baseline`
+    baseline: &'ctx mut Option<Baseline>,
     /// Whether the console thread should print diagnostics in verbose mode
     verbose: bool,
 }
@@ -292,12 +393,15 @@ fn process_messages(options: ProcessMessagesOptions) {
         max_diagnostics,
         remaining_diagnostics,
         errors,
-        report,
+        reporter,
+        baseline,
         verbose,
         warnings,
     } = options;
 
     let mut paths: HashSet<String> = HashSet::new();
+    // The files whose errors are all recorded in the baseline
+    let mut files_in_baseline: HashSet<String> = HashSet::new();
     let mut printed_diagnostics: u16 = 0;
     let mut not_printed_diagnostics = 0;
     let mut total_skipped_suggested_fixes = 0;
@@ -317,7 +421,9 @@ fn process_messages(options: ProcessMessagesOptions) {
             recv(recv_reports) -> stat => {
                 match stat {
                     Ok(stat) => {
-                        report.push_detail_report(stat);
+                        if let Some(reporter) = reporter.as_mut() {
+                            reporter.report_detail(stat);
+                        }
                     }
                     Err(_) => {
                         is_report_open = false;
@@ -335,6 +441,11 @@ fn process_messages(options: ProcessMessagesOptions) {
             }
 
             Message::ApplyError(error) => {
+                if let Some(Resource::File(path)) = error.location().resource {
+                    if files_in_baseline.contains(path) {
+                        continue;
+                    }
+                }
                 *errors += 1;
                 let should_print = printed_diagnostics < max_diagnostics;
                 if should_print {
@@ -353,9 +464,6 @@ fn process_messages(options: ProcessMessagesOptions) {
 
             Message::Error(mut err) => {
                 let location = err.location();
-                if err.severity() == Severity::Warning {
-                    *warnings += 1;
-                }
                 if let Some(Resource::File(file_path)) = location.resource.as_ref() {
                     // Retrieves the file name from the file ID cache, if it's a miss
                     // flush entries from the interner channel until it's found
@@ -382,6 +490,22 @@ fn process_messages(options: ProcessMessagesOptions) {
                     }
                 }
 
+                if let Some(baseline) = baseline.as_mut() {
+                    let location = err.location();
+                    let path = match location.resource {
+                        Some(Resource::File(path)) => path,
+                        _ => "<unknown>",
+                    };
+                    let source_code = location.source_code.map(|source_code| source_code.text);
+                    if baseline.suppress(&err, path, source_code) {
+                        continue;
+                    }
+                }
+
+                if err.severity() == Severity::Warning {
+                    *warnings += 1;
+                }
+
                 let should_print = printed_diagnostics < max_diagnostics;
                 if should_print {
                     printed_diagnostics += 1;
@@ -393,29 +517,10 @@ fn process_messages(options: ProcessMessagesOptions) {
                     not_printed_diagnostics += 1;
                 }
 
-                if mode.should_report_to_terminal() {
-                    if should_print {
-                        diagnostics_to_print.push(err);
-                    }
-                } else {
-                    let location = err.location();
-                    let path = match &location.resource {
-                        Some(Resource::File(file)) => Some(*file),
-                        _ => None,
-                    };
-
-                    let file_name = path.unwrap_or("<unknown>");
-                    let title = PrintDescription(&err).to_string();
-                    let code = err.category().and_then(|code| code.name().parse().ok());
-
-                    report.push_detail_report(ReportKind::Error(
-                        file_name.to_string(),
-                        ReportErrorKind::Diagnostic(ReportDiagnostic {
-                            code,
-                            title,
-                            severity: err.severity(),
-                        }),
-                    ));
+                if let Some(reporter) = reporter.as_mut() {
+                    reporter.report_diagnostic(err);
+                } else if should_print {
+                    diagnostics_to_print.push(err);
                 }
             }
 
@@ -427,6 +532,27 @@ fn process_messages(options: ProcessMessagesOptions) {
             } => {
                 not_printed_diagnostics += skipped_diagnostics;
 
+                let diagnostics = match baseline.as_mut() {
+                    Some(baseline) => {
+                        let count = diagnostics.len();
+                        let diagnostics: Vec<_> = diagnostics
+                            .into_iter()
+                            .filter(|diag| !baseline.suppress(diag, &name, Some(&content)))
+                            .collect();
+                        // The error emitted for the whole file is ignored when all its errors are in the baseline
+                        if diagnostics.len() < count
+                            && skipped_diagnostics == 0
+                            && diagnostics
+                                .iter()
+                                .all(|diag| diag.severity() != Severity::Error)
+                        {
+                            files_in_baseline.insert(name.clone());
+                        }
+                        diagnostics
+                    }
+                    None => diagnostics,
+                };
+
                 // is CI mode we want to print all the diagnostics
                 if mode.is_ci() {
                     for diag in diagnostics {
@@ -435,7 +561,11 @@ fn process_messages(options: ProcessMessagesOptions) {
                         }
 
                         let diag = diag.with_file_path(&name).with_file_source_code(&content);
-                        diagnostics_to_print.push(diag);
+                        if let Some(reporter) = reporter.as_mut() {
+                            reporter.report_diagnostic(diag);
+                        } else {
+                            diagnostics_to_print.push(diag);
+                        }
                     }
                 } else {
                     for diag in diagnostics {
@@ -458,21 +588,13 @@ fn process_messages(options: ProcessMessagesOptions) {
                             not_printed_diagnostics += 1;
                         }
 
-                        if mode.should_report_to_terminal() {
-                            if should_print {
-                                let diag =
-                                    diag.with_file_path(&name).with_file_source_code(&content);
-                                diagnostics_to_print.push(diag)
-                            }
-                        } else {
-                            report.push_detail_report(ReportKind::Error(
-                                name.to_string(),
-                                ReportErrorKind::Diagnostic(ReportDiagnostic {
-                                    code: diag.category().and_then(|code| code.name().parse().ok()),
-                                    title: String::from("test here"),
-                                    severity,
-                                }),
-                            ));
+                        if let Some(reporter) = reporter.as_mut() {
+                            reporter.report_diagnostic(
+                                diag.with_file_path(&name).with_file_source_code(&content),
+                            );
+                        } else if should_print {
+                            let diag = diag.with_file_path(&name).with_file_source_code(&content);
+                            diagnostics_to_print.push(diag)
                         }
                     }
                 }
@@ -499,64 +621,32 @@ fn process_messages(options: ProcessMessagesOptions) {
                     not_printed_diagnostics += 1;
                 }
 
-                if mode.should_report_to_terminal() {
-                    if should_print {
-                        if mode.is_ci() {
-                            match diff_kind {
-                                DiffKind::Format => {
-                                    let diag = CIFormatDiffDiagnostic {
-                                        file_name: file_name.clone(),
-                                        diff: ContentDiffAdvice {
-                                            old: old.clone(),
-                                            new: new.clone(),
-                                        },
-                                    };
-                                    diagnostics_to_print.push(Error::from(diag))
-                                }
-                                DiffKind::OrganizeImports => {
-                                    let diag = CIOrganizeImportsDiffDiagnostic {
-                                        file_name: file_name.clone(),
-                                        diff: ContentDiffAdvice {
-                                            old: old.clone(),
-                                            new: new.clone(),
-                                        },
-                                    };
-                                    diagnostics_to_print.push(Error::from(diag))
-                                }
-                            };
-                        } else {
-                            match diff_kind {
-                                DiffKind::Format => {
-                                    let diag = FormatDiffDiagnostic {
-                                        file_name: file_name.clone(),
-                                        diff: ContentDiffAdvice {
-                                            old: old.clone(),
-                                            new: new.clone(),
-                                        },
-                                    };
-                                    diagnostics_to_print.push(Error::from(diag))
-                                }
-                                DiffKind::OrganizeImports => {
-                                    let diag = OrganizeImportsDiffDiagnostic {
-                                        file_name: file_name.clone(),
-                                        diff: ContentDiffAdvice {
-                                            old: old.clone(),
-                                            new: new.clone(),
-                                        },
-                                    };
-                                    diagnostics_to_print.push(Error::from(diag))
-                                }
-                            };
-                        }
-                    }
-                } else {
-                    report.push_detail_report(ReportKind::Error(
+                if let Some(reporter) = reporter.as_mut() {
+                    // The source code is needed to compute the position of the changes
+                    let diag = diff_diagnostic(
+                        mode,
+                        diff_kind,
                         file_name,
-                        ReportErrorKind::Diff(ReportDiff {
+                        ContentDiffAdvice {
+                            old: old.clone(),
+                            new: new.clone(),
+                        },
+                    )
+                    .with_file_source_code(old.clone());
+                    reporter.report_diff(
+                        diag,
+                        ReportDiff {
                             before: old,
                             after: new,
                             severity: Severity::Error,
-                        }),
+                        },
+                    );
+                } else if should_print {
+                    diagnostics_to_print.push(diff_diagnostic(
+                        mode,
+                        diff_kind,
+                        file_name,
+                        ContentDiffAdvice { old, new },
                     ));
                 }
             }
@@ -569,6 +659,10 @@ fn process_messages(options: ProcessMessagesOptions) {
         });
     }
 
+    if !mode.should_report_to_terminal() {
+        return;
+    }
+
     if mode.is_check() && total_skipped_suggested_fixes > 0 {
         console.log(markup! {
             <Warn>"Skipped "{total_skipped_suggested_fixes}" suggested fixes.\n"</Warn>
@@ -584,6 +678,26 @@ fn process_messages(options: ProcessMessagesOptions) {
     }
 }
 
+/// Creates the diagnostic that reports the difference between the content of a file
+/// and the output of the formatter or of the import sorting
+fn diff_diagnostic(
+    mode: &Execution,
+    diff_kind: DiffKind,
+    file_name: String,
+    diff: ContentDiffAdvice,
+) -> Error {
+    match (mode.is_ci(), diff_kind) {
+        (true, DiffKind::Format) => Error::from(CIFormatDiffDiagnostic { file_name, diff }),
+        (true, DiffKind::OrganizeImports) => {
+            Error::from(CIOrganizeImportsDiffDiagnostic { file_name, diff })
+        }
+        (false, DiffKind::Format) => Error::from(FormatDiffDiagnostic { file_name, diff }),
+        (false, DiffKind::OrganizeImports) => {
+            Error::from(OrganizeImportsDiffDiagnostic { file_name, diff })
+        }
+    }
+}
+
 /// Context object shared between directory traversal tasks
 pub(crate) struct TraversalOptions<'ctx, 'app> {
     /// Shared instance of [FileSystem]
@@ -605,6 +719,16 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     /// The approximate number of diagnostics the console will print before
     /// folding the rest into the "skipped diagnostics" counter
     pub(crate) remaining_diagnostics: &'ctx AtomicU16,
+    /// The paths of the JSON Schemas that were already read from the file system
+    pub(crate) json_schemas: Mutex<HashSet<PathBuf>>,
+    /// When `--changed` or `--staged` is passed, the files outside of this list are skipped
+    changed_files: Option<&'ctx ChangedFiles>,
+    /// The files that didn't emit diagnostics the last time they were processed, it's [None]
+    /// when the cache is disabled
+    pub(crate) cache: Option<&'ctx ResultCache>,
+    /// The configuration files found in the subdirectories, it's [None] when the files are
+    /// processed without a configuration
+    pub(crate) nested_configurations: Option<&'ctx NestedConfigurations>,
 }
 
 impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
@@ -623,6 +747,23 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
             .ok();
     }
 
+    /// Registers the settings of the configuration files found in the directory of `rome_path`
+    /// and in its ancestors
+    fn register_nested_configurations(&self, rome_path: &RomePath) {
+        let Some(nested_configurations) = self.nested_configurations else {
+            return;
+        };
+        let directory = if rome_path.is_dir() {
+            rome_path.as_path()
+        } else {
+            rome_path.parent().unwrap_or(Path::new(""))
+        };
+
+        for diagnostic in nested_configurations.register(self.fs, self.workspace, directory) {
+            self.push_diagnostic(diagnostic);
+        }
+    }
+
     pub(crate) fn miss_handler_err(&self, err: WorkspaceError, rome_path: &RomePath) {
         self.push_diagnostic(
             StdError::from(err)
@@ -642,6 +783,19 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
     }
 
     fn can_handle(&self, rome_path: &RomePath) -> bool {
+        if let Some(changed_files) = self.changed_files {
+            let is_changed = if rome_path.is_dir() {
+                changed_files.contains_directory(rome_path)
+            } else {
+                changed_files.contains_file(rome_path)
+            };
+            if !is_changed {
+                return false;
+            }
+        }
+
+        self.register_nested_configurations(rome_path);
+
         if rome_path.is_dir() {
             let can_handle = !self
                 .workspace
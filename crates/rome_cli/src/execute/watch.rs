@@ -0,0 +1,171 @@
+use crate::cli_options::CliOptions;
+use crate::execute::traverse::traverse;
+use crate::vcs::ChangedFiles;
+use crate::{CliDiagnostic, CliSession, Execution};
+use rome_console::{markup, ConsoleExt};
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Notifies the watch mode of the files that changed
+pub trait FileWatcher {
+    /// Blocks until some files inside `roots` are created or modified, and returns their paths.
+    /// The watch mode stops when it returns [None]
+    fn wait_for_changes(&mut self, roots: &[PathBuf]) -> Option<Vec<PathBuf>>;
+}
+
+/// Implementation of [FileWatcher] that compares the modification time and the size of the files
+/// of the operating system at regular intervals
+#[derive(Debug)]
+pub struct PollWatcher {
+    interval: Duration,
+    /// The state of the files the last time they were compared, it's [None] before the first wait
+    files: Option<HashMap<PathBuf, FileState>>,
+}
+
+impl Default for PollWatcher {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_millis(500),
+            files: None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct FileState {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+impl FileWatcher for PollWatcher {
+    fn wait_for_changes(&mut self, roots: &[PathBuf]) -> Option<Vec<PathBuf>> {
+        let mut previous = match self.files.take() {
+            Some(files) => files,
+            None => scan(roots),
+        };
+
+        loop {
+            thread::sleep(self.interval);
+            let current = scan(roots);
+            let mut changes: Vec<_> = current
+                .iter()
+                .filter(|(path, state)| previous.get(*path) != Some(state))
+                .map(|(path, _)| path.clone())
+                .collect();
+            previous = current;
+
+            if !changes.is_empty() {
+                self.files = Some(previous);
+                changes.sort();
+                return Some(changes);
+            }
+        }
+    }
+}
+
+/// Returns the state of the files inside `roots`
+fn scan(roots: &[PathBuf]) -> HashMap<PathBuf, FileState> {
+    let mut files = HashMap::new();
+    for root in roots {
+        scan_path(root, &mut files);
+    }
+    files
+}
+
+fn scan_path(path: &Path, files: &mut HashMap<PathBuf, FileState>) {
+    // The symbolic links aren't followed, they could create cycles
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return;
+    };
+
+    if metadata.is_dir() {
+        // These directories are large, and Rome ignores them anyway
+        if matches!(
+            path.file_name().and_then(|name| name.to_str()),
+            Some(".git" | "node_modules")
+        ) {
+            return;
+        }
+        let Ok(entries) = fs::read_dir(path) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            scan_path(&entry.path(), files);
+        }
+    } else if metadata.is_file() {
+        files.insert(
+            path.to_path_buf(),
+            FileState {
+                modified: metadata.modified().ok(),
+                len: metadata.len(),
+            },
+        );
+    }
+}
+
+/// Processes the files of `inputs`, then processes again the files that change until the
+/// [FileWatcher] of the session stops
+pub(crate) fn run(
+    execution: &Execution,
+    mut session: CliSession,
+    cli_options: &CliOptions,
+    inputs: Vec<OsString>,
+    changed_files: Option<ChangedFiles>,
+) -> Result<(), CliDiagnostic> {
+    let roots: Vec<PathBuf> = match &changed_files {
+        Some(changed_files) if inputs.is_empty() => changed_files.paths(),
+        _ => inputs.iter().map(PathBuf::from).collect(),
+    };
+
+    let result = traverse(
+        execution,
+        &mut session,
+        cli_options,
+        inputs,
+        changed_files.as_ref(),
+    );
+    ignore_check_errors(result)?;
+
+    loop {
+        if execution.should_report_to_terminal() {
+            session.app.console.log(markup! {
+                <Dim>"Watching for changes..."</Dim>
+            });
+        }
+
+        let Some(changes) = session.watcher.wait_for_changes(&roots) else {
+            return Ok(());
+        };
+
+        let changed_files = ChangedFiles::new(session.app.fs.working_directory(), changes);
+        let inputs = roots
+            .iter()
+            .map(|root| root.clone().into_os_string())
+            .collect();
+        let result = traverse(
+            execution,
+            &mut session,
+            cli_options,
+            inputs,
+            Some(&changed_files),
+        );
+        match result {
+            // The changed files can be ignored, or not supported
+            Err(CliDiagnostic::NoFilesWereProcessed(_)) => {}
+            result => ignore_check_errors(result)?,
+        }
+    }
+}
+
+/// The diagnostics of the files were already printed by the traversal, the watch mode keeps
+/// running until the files are fixed. The other errors are returned
+fn ignore_check_errors(result: Result<(), CliDiagnostic>) -> Result<(), CliDiagnostic> {
+    match result {
+        Err(CliDiagnostic::CheckError(_)) => Ok(()),
+        result => result,
+    }
+}
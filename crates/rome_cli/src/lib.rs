@@ -10,6 +10,7 @@ use rome_console::{ColorMode, Console};
 use rome_fs::OsFileSystem;
 use rome_service::{App, DynRef, Workspace, WorkspaceRef};
 use std::env;
+use std::path::PathBuf;
 
 mod cli_options;
 mod commands;
@@ -20,6 +21,7 @@ mod metrics;
 mod panic;
 mod reports;
 mod service;
+mod timings;
 mod vcs;
 
 use crate::cli_options::ColorsArg;
@@ -30,12 +32,14 @@ use crate::commands::lint::LintCommandPayload;
 pub use crate::commands::{rome_command, RomeCommand};
 pub use diagnostics::CliDiagnostic;
 pub(crate) use execute::{execute_mode, Execution, TraversalMode};
+pub use execute::{FileWatcher, PollWatcher};
 pub use panic::setup_panic_handler;
 pub use reports::{
     formatter::{FormatterReport, FormatterReportFileDetail, FormatterReportSummary},
     Report, ReportDiagnostic, ReportDiff, ReportErrorKind, ReportKind,
 };
 pub use service::{open_transport, SocketTransport};
+pub use vcs::{GitClient, VcsClient};
 
 pub(crate) const VERSION: &str = match option_env!("ROME_VERSION") {
     Some(version) => version,
@@ -46,6 +50,35 @@ pub(crate) const VERSION: &str = match option_env!("ROME_VERSION") {
 pub struct CliSession<'app> {
     /// Instance of [App] used by this run of the CLI
     pub app: App<'app>,
+    /// Information about the environment the CLI runs in
+    pub environment: CliEnvironment,
+    /// Lists the files selected by `--changed` and `--staged`
+    pub vcs: Box<dyn VcsClient>,
+    /// Notifies the watch mode of the files that changed
+    pub watcher: Box<dyn FileWatcher>,
+}
+
+/// Information read from the environment variables of the process
+#[derive(Debug, Default)]
+pub struct CliEnvironment {
+    /// Whether the CLI runs inside a GitHub Actions workflow
+    pub github_actions: bool,
+    /// The directory where the results of the unchanged files are cached, the cache is
+    /// disabled when it's [None]
+    pub cache_directory: Option<PathBuf>,
+}
+
+impl CliEnvironment {
+    /// Reads the environment variables of the current process
+    pub fn from_env() -> Self {
+        Self {
+            github_actions: env::var_os("GITHUB_ACTIONS").map_or(false, |value| value == "true"),
+            cache_directory: Some(match env::var_os("ROME_CACHE_DIR") {
+                Some(directory) => PathBuf::from(directory),
+                None => env::temp_dir().join("rome-cache"),
+            }),
+        }
+    }
 }
 
 impl<'app> CliSession<'app> {
@@ -59,6 +92,9 @@ impl<'app> CliSession<'app> {
                 console,
                 WorkspaceRef::Borrowed(workspace),
             ),
+            environment: CliEnvironment::from_env(),
+            vcs: Box::new(GitClient),
+            watcher: Box::<PollWatcher>::default(),
         })
     }
 
@@ -84,11 +120,13 @@ impl<'app> CliSession<'app> {
                 linter_enabled,
                 organize_imports_enabled,
                 formatter_enabled,
+                watch,
             } => commands::check::check(
                 self,
                 CheckCommandPayload {
                     apply_unsafe,
                     apply,
+                    watch,
                     cli_options,
                     configuration: rome_configuration,
                     paths,
@@ -101,6 +139,8 @@ impl<'app> CliSession<'app> {
             RomeCommand::Lint {
                 apply,
                 apply_unsafe,
+                only,
+                skip,
                 cli_options,
                 configuration: rome_configuration,
                 paths,
@@ -110,6 +150,8 @@ impl<'app> CliSession<'app> {
                 LintCommandPayload {
                     apply_unsafe,
                     apply,
+                    only,
+                    skip,
                     cli_options,
                     configuration: rome_configuration,
                     paths,
@@ -158,9 +200,13 @@ impl<'app> CliSession<'app> {
             ),
             RomeCommand::Init => commands::init::init(self),
             RomeCommand::LspProxy(_) => commands::daemon::lsp_proxy(),
-            RomeCommand::Migrate(cli_options, write) => {
-                commands::migrate::migrate(self, cli_options, write)
-            }
+            RomeCommand::Migrate {
+                cli_options,
+                write,
+                eslint,
+                prettier,
+            } => commands::migrate::migrate(self, cli_options, write, eslint, prettier),
+            RomeCommand::Explain { name, .. } => commands::explain::explain(self, name),
             RomeCommand::RunServer { stop_on_disconnect } => {
                 commands::daemon::run_server(stop_on_disconnect)
             }
@@ -147,7 +147,7 @@ impl<I: Timepoint> Timings<I> {
     }
 }
 
-fn read_span<'ctx, S>(ctx: &'ctx Context<'_, S>, id: &span::Id) -> SpanRef<'ctx, S>
+pub(crate) fn read_span<'ctx, S>(ctx: &'ctx Context<'_, S>, id: &span::Id) -> SpanRef<'ctx, S>
 where
     S: Subscriber + for<'a> LookupSpan<'a>,
 {
@@ -0,0 +1,61 @@
+//! Serialization of diagnostics in the Checkstyle XML format.
+
+use crate::reports::{escape_xml, LineIndexes, ReportedDiagnostic, Reporter, TraversalSummary};
+use crate::Execution;
+use rome_diagnostics::{Error, Severity};
+use rome_service::WorkspaceError;
+use std::fmt::Write;
+
+#[derive(Debug, Default)]
+pub(crate) struct CheckstyleReport {
+    diagnostics: Vec<ReportedDiagnostic>,
+    line_indexes: LineIndexes,
+}
+
+impl Reporter for CheckstyleReport {
+    fn report_diagnostic(&mut self, diagnostic: Error) {
+        self.diagnostics
+            .push(ReportedDiagnostic::new(&diagnostic, &mut self.line_indexes));
+    }
+
+    fn finish(
+        mut self: Box<Self>,
+        _execution: &Execution,
+        _summary: &TraversalSummary,
+    ) -> Result<String, WorkspaceError> {
+        ReportedDiagnostic::sort(&mut self.diagnostics);
+
+        let mut output = String::new();
+        // Writing into a String never fails
+        let _ = writeln!(output, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = writeln!(output, r#"<checkstyle version="4.3">"#);
+
+        for file in ReportedDiagnostic::group_by_path(&self.diagnostics) {
+            let _ = writeln!(output, r#"  <file name="{}">"#, escape_xml(&file[0].path));
+            for diagnostic in file {
+                let _ = write!(output, "    <error");
+                if let Some((line, column)) = diagnostic.start {
+                    let _ = write!(output, r#" line="{line}" column="{column}""#);
+                }
+                let severity = match diagnostic.severity {
+                    Severity::Hint | Severity::Information => "info",
+                    Severity::Warning => "warning",
+                    Severity::Error | Severity::Fatal => "error",
+                };
+                let _ = write!(
+                    output,
+                    r#" severity="{severity}" message="{}""#,
+                    escape_xml(&diagnostic.message)
+                );
+                if let Some(category) = diagnostic.category {
+                    let _ = write!(output, r#" source="{}""#, escape_xml(category.name()));
+                }
+                let _ = writeln!(output, " />");
+            }
+            let _ = writeln!(output, "  </file>");
+        }
+
+        let _ = write!(output, "</checkstyle>");
+        Ok(output)
+    }
+}
@@ -0,0 +1,74 @@
+//! Serialization of diagnostics as [GitHub Actions workflow commands], that GitHub shows as
+//! annotations on the files of a pull request.
+//!
+//! [GitHub Actions workflow commands]: https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-an-error-message
+
+use crate::reports::{LineIndexes, ReportedDiagnostic, Reporter, TraversalSummary};
+use crate::Execution;
+use rome_diagnostics::{Error, Severity};
+use rome_service::WorkspaceError;
+use std::fmt::Write;
+
+#[derive(Debug, Default)]
+pub(crate) struct GithubReport {
+    diagnostics: Vec<ReportedDiagnostic>,
+    line_indexes: LineIndexes,
+}
+
+impl Reporter for GithubReport {
+    fn report_diagnostic(&mut self, diagnostic: Error) {
+        self.diagnostics
+            .push(ReportedDiagnostic::new(&diagnostic, &mut self.line_indexes));
+    }
+
+    fn finish(
+        mut self: Box<Self>,
+        _execution: &Execution,
+        _summary: &TraversalSummary,
+    ) -> Result<String, WorkspaceError> {
+        ReportedDiagnostic::sort(&mut self.diagnostics);
+
+        let mut output = String::new();
+        for diagnostic in &self.diagnostics {
+            let command = match diagnostic.severity {
+                Severity::Hint | Severity::Information => "notice",
+                Severity::Warning => "warning",
+                Severity::Error | Severity::Fatal => "error",
+            };
+            // Writing into a String never fails
+            let _ = write!(
+                output,
+                "::{command} file={}",
+                escape_property(&diagnostic.path)
+            );
+            if let (Some((line, column)), Some((end_line, end_column))) =
+                (diagnostic.start, diagnostic.end)
+            {
+                let _ = write!(
+                    output,
+                    ",line={line},endLine={end_line},col={column},endColumn={end_column}"
+                );
+            }
+            if let Some(category) = diagnostic.category {
+                let _ = write!(output, ",title={}", escape_property(category.name()));
+            }
+            let _ = writeln!(output, "::{}", escape_data(&diagnostic.message));
+        }
+
+        // The console adds the last line break
+        output.pop();
+        Ok(output)
+    }
+}
+
+/// Escapes the message of a workflow command
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes the value of a property of a workflow command, where `:` and `,` are separators
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
@@ -0,0 +1,92 @@
+//! Serialization of diagnostics in the [Code Quality] format of GitLab.
+//!
+//! [Code Quality]: https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool
+
+use crate::reports::{fingerprint, LineIndexes, ReportedDiagnostic, Reporter, TraversalSummary};
+use crate::Execution;
+use rome_diagnostics::{Error, Severity};
+use rome_service::WorkspaceError;
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub(crate) struct GitlabReport {
+    diagnostics: Vec<ReportedDiagnostic>,
+    line_indexes: LineIndexes,
+}
+
+impl Reporter for GitlabReport {
+    fn report_diagnostic(&mut self, diagnostic: Error) {
+        self.diagnostics
+            .push(ReportedDiagnostic::new(&diagnostic, &mut self.line_indexes));
+    }
+
+    fn finish(
+        mut self: Box<Self>,
+        _execution: &Execution,
+        _summary: &TraversalSummary,
+    ) -> Result<String, WorkspaceError> {
+        ReportedDiagnostic::sort(&mut self.diagnostics);
+
+        // GitLab requires the fingerprints to be unique, the fingerprints of identical
+        // diagnostics also hash the number of times they were already seen
+        let mut occurrences: HashMap<&str, usize> = HashMap::new();
+        let issues: Vec<_> = self
+            .diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let occurrence = occurrences
+                    .entry(diagnostic.fingerprint.as_str())
+                    .or_default();
+                let fingerprint = if *occurrence == 0 {
+                    diagnostic.fingerprint.clone()
+                } else {
+                    fingerprint(&[&diagnostic.fingerprint, &occurrence.to_string()])
+                };
+                *occurrence += 1;
+
+                GitlabIssue {
+                    description: &diagnostic.message,
+                    check_name: diagnostic.category.map(|category| category.name()),
+                    fingerprint,
+                    severity: match diagnostic.severity {
+                        Severity::Hint | Severity::Information => "info",
+                        Severity::Warning => "minor",
+                        Severity::Error => "major",
+                        Severity::Fatal => "critical",
+                    },
+                    location: GitlabLocation {
+                        path: &diagnostic.path,
+                        lines: GitlabLines {
+                            begin: diagnostic.start.map_or(1, |(line, _)| line),
+                        },
+                    },
+                }
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&issues)
+            .map_err(|err| WorkspaceError::report_not_serializable(err.to_string()))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct GitlabIssue<'a> {
+    description: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    check_name: Option<&'static str>,
+    fingerprint: String,
+    severity: &'static str,
+    location: GitlabLocation<'a>,
+}
+
+#[derive(Debug, Serialize)]
+struct GitlabLocation<'a> {
+    path: &'a str,
+    lines: GitlabLines,
+}
+
+#[derive(Debug, Serialize)]
+struct GitlabLines {
+    begin: usize,
+}
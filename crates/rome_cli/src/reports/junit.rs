@@ -0,0 +1,79 @@
+//! Serialization of diagnostics in the JUnit XML format, understood by most CI systems.
+//!
+//! Each file that has diagnostics is a test suite, and each diagnostic is a failed test case.
+
+use crate::reports::{
+    escape_xml, severity_name, LineIndexes, ReportedDiagnostic, Reporter, TraversalSummary,
+};
+use crate::Execution;
+use rome_diagnostics::Error;
+use rome_service::WorkspaceError;
+use std::fmt::Write;
+
+#[derive(Debug, Default)]
+pub(crate) struct JunitReport {
+    diagnostics: Vec<ReportedDiagnostic>,
+    line_indexes: LineIndexes,
+}
+
+impl Reporter for JunitReport {
+    fn report_diagnostic(&mut self, diagnostic: Error) {
+        self.diagnostics
+            .push(ReportedDiagnostic::new(&diagnostic, &mut self.line_indexes));
+    }
+
+    fn finish(
+        mut self: Box<Self>,
+        _execution: &Execution,
+        _summary: &TraversalSummary,
+    ) -> Result<String, WorkspaceError> {
+        ReportedDiagnostic::sort(&mut self.diagnostics);
+
+        let mut output = String::new();
+        let count = self.diagnostics.len();
+        // Writing into a String never fails
+        let _ = writeln!(output, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = writeln!(
+            output,
+            r#"<testsuites name="Rome" tests="{count}" failures="{count}">"#
+        );
+
+        for file in ReportedDiagnostic::group_by_path(&self.diagnostics) {
+            let path = escape_xml(&file[0].path);
+            let _ = writeln!(
+                output,
+                r#"  <testsuite name="{path}" tests="{count}" failures="{count}">"#,
+                count = file.len()
+            );
+            for diagnostic in file {
+                let category = diagnostic.category.map_or("", |category| category.name());
+                let position = match diagnostic.start {
+                    Some((line, column)) => format!("{line}:{column}"),
+                    None => String::new(),
+                };
+                let name = if position.is_empty() {
+                    category.to_string()
+                } else {
+                    format!("{category} ({position})")
+                };
+                let message = escape_xml(&diagnostic.message);
+                let _ = writeln!(
+                    output,
+                    r#"    <testcase name="{name}" classname="{path}">"#,
+                    name = escape_xml(&name)
+                );
+                let _ = writeln!(
+                    output,
+                    r#"      <failure message="{message}" type="{severity}">{path}{separator}{position} {message}</failure>"#,
+                    severity = severity_name(diagnostic.severity),
+                    separator = if position.is_empty() { "" } else { ":" },
+                );
+                let _ = writeln!(output, "    </testcase>");
+            }
+            let _ = writeln!(output, "  </testsuite>");
+        }
+
+        let _ = write!(output, "</testsuites>");
+        Ok(output)
+    }
+}
@@ -1,11 +1,57 @@
+pub mod checkstyle;
 pub mod formatter;
+pub mod github;
+pub mod gitlab;
+pub mod junit;
+pub mod sarif;
 
 use crate::reports::formatter::{FormatterReportFileDetail, FormatterReportSummary};
+use crate::{Execution, TraversalMode};
 use formatter::FormatterReport;
-use rome_diagnostics::{Category, Severity};
+use rome_diagnostics::{Category, Error, LineIndexBuf, PrintDescription, Resource, Severity};
 use rome_service::WorkspaceError;
+use rome_text_size::{TextRange, TextSize};
 use serde::Serialize;
 use std::collections::HashMap;
+use std::fmt::Write;
+
+/// A reporter collects the diagnostics emitted during a traversal, and serializes them in a
+/// format that is understood by other tools.
+///
+/// The reporter is used in place of the console, when a report mode other than
+/// [ReportMode::Terminal](crate::execute::ReportMode::Terminal) is requested.
+pub(crate) trait Reporter: Send {
+    /// Tracks a diagnostic. The path and the source code of the file are attached to the
+    /// diagnostic, when they are known
+    fn report_diagnostic(&mut self, diagnostic: Error);
+
+    /// Tracks the difference between the content of a file and the output of the formatter,
+    /// or of the import sorting. `diagnostic` describes the difference, and its source code
+    /// is the content of the file
+    fn report_diff(&mut self, diagnostic: Error, diff: ReportDiff) {
+        let _ = diff;
+        self.report_diagnostic(diagnostic);
+    }
+
+    /// Tracks additional information sent by the workers, like the formatted content of a file
+    fn report_detail(&mut self, detail: ReportKind) {
+        let _ = detail;
+    }
+
+    /// Serializes the report, once all the files were processed
+    fn finish(
+        self: Box<Self>,
+        execution: &Execution,
+        summary: &TraversalSummary,
+    ) -> Result<String, WorkspaceError>;
+}
+
+/// Information about the traversal, given to the [Reporter] once all the files were processed
+#[derive(Debug)]
+pub(crate) struct TraversalSummary {
+    /// The number of processed files
+    pub(crate) count: usize,
+}
 
 #[derive(Debug, Default, Serialize)]
 pub struct Report {
@@ -64,6 +110,13 @@ pub enum ReportKind {
 }
 
 impl Report {
+    fn file_name(diagnostic: &Error) -> String {
+        match diagnostic.location().resource {
+            Some(Resource::File(file)) => file.to_string(),
+            _ => String::from("<unknown>"),
+        }
+    }
+
     /// Creates or updates a stat
     pub fn push_detail_report(&mut self, stat: ReportKind) {
         match stat {
@@ -90,3 +143,220 @@ impl Report {
             .map_err(|err| WorkspaceError::report_not_serializable(err.to_string()))
     }
 }
+
+impl Reporter for Report {
+    fn report_diagnostic(&mut self, diagnostic: Error) {
+        self.push_error(
+            Self::file_name(&diagnostic),
+            ReportErrorKind::Diagnostic(ReportDiagnostic {
+                severity: diagnostic.severity(),
+                code: diagnostic.category(),
+                title: PrintDescription(&diagnostic).to_string(),
+            }),
+        );
+    }
+
+    fn report_diff(&mut self, diagnostic: Error, diff: ReportDiff) {
+        self.push_error(Self::file_name(&diagnostic), ReportErrorKind::Diff(diff));
+    }
+
+    fn report_detail(&mut self, detail: ReportKind) {
+        self.push_detail_report(detail);
+    }
+
+    fn finish(
+        mut self: Box<Self>,
+        execution: &Execution,
+        summary: &TraversalSummary,
+    ) -> Result<String, WorkspaceError> {
+        if let TraversalMode::Format { write, .. } = execution.traversal_mode() {
+            let mut formatter_summary = FormatterReportSummary::default();
+            if *write {
+                formatter_summary.set_files_written(summary.count);
+            } else {
+                formatter_summary.set_files_compared(summary.count);
+            }
+            self.set_formatter_summary(formatter_summary);
+        }
+
+        self.as_serialized_reports()
+    }
+}
+
+/// The line index of each file that has diagnostics, computed the first time a diagnostic
+/// of the file is reported
+#[derive(Debug, Default)]
+pub(crate) struct LineIndexes(HashMap<String, LineIndexBuf>);
+
+impl LineIndexes {
+    pub(crate) fn get(&mut self, path: &str, text: &str) -> &LineIndexBuf {
+        self.0
+            .entry(path.to_string())
+            .or_insert_with(|| LineIndexBuf::from_source_text(text))
+    }
+}
+
+/// Returns the one-based line and column of `offset`, the column is counted in Unicode code points
+pub(crate) fn line_column(
+    offset: TextSize,
+    text: &str,
+    line_index: &LineIndexBuf,
+) -> (usize, usize) {
+    let offset = offset.min(TextSize::of(text));
+    let line = line_index
+        .partition_point(|line_start| *line_start <= offset)
+        .max(1);
+    let line_start = line_index[line - 1];
+    let column = text[TextRange::new(line_start, offset)].chars().count() + 1;
+
+    (line, column)
+}
+
+/// The information of a diagnostic that the reporters listing the problems of each file need
+#[derive(Debug)]
+pub(crate) struct ReportedDiagnostic {
+    /// The path of the file, with forward slashes
+    pub(crate) path: String,
+    /// The one-based line and column where the diagnostic starts, when its source code is known
+    pub(crate) start: Option<(usize, usize)>,
+    /// The one-based line and column where the diagnostic ends, the column is exclusive
+    pub(crate) end: Option<(usize, usize)>,
+    pub(crate) severity: Severity,
+    pub(crate) category: Option<&'static Category>,
+    pub(crate) message: String,
+    /// A hash of the path, the category, the message and the source code of the diagnostic,
+    /// that doesn't change when the diagnostic moves inside the file
+    pub(crate) fingerprint: String,
+}
+
+impl ReportedDiagnostic {
+    pub(crate) fn new(diagnostic: &Error, line_indexes: &mut LineIndexes) -> Self {
+        let location = diagnostic.location();
+        let path = match location.resource {
+            Some(Resource::File(path)) => path.replace('\\', "/"),
+            _ => String::from("<unknown>"),
+        };
+        let text = location.source_code.map(|source_code| source_code.text);
+        let (start, end) = match (location.span, text) {
+            (Some(span), Some(text)) => {
+                let line_index = line_indexes.get(&path, text);
+                (
+                    Some(line_column(span.start(), text, line_index)),
+                    Some(line_column(span.end(), text, line_index)),
+                )
+            }
+            _ => (None, None),
+        };
+
+        let category = diagnostic.category();
+        let message = PrintDescription(diagnostic).to_string();
+        let fingerprint = Self::fingerprint(diagnostic, &path, text, &message);
+
+        Self {
+            path,
+            start,
+            end,
+            severity: diagnostic.severity(),
+            category,
+            message,
+            fingerprint,
+        }
+    }
+
+    /// Hashes the path, the category, the message and the source code of `diagnostic`.
+    /// `source_code` is the content of the file, the span of the diagnostic is a range of it
+    pub(crate) fn fingerprint(
+        diagnostic: &Error,
+        path: &str,
+        source_code: Option<&str>,
+        message: &str,
+    ) -> String {
+        let code = match (diagnostic.location().span, source_code) {
+            (Some(span), Some(text)) if span.end() <= TextSize::of(text) => &text[span],
+            _ => "",
+        };
+
+        fingerprint(&[
+            path,
+            diagnostic.category().map_or("", |category| category.name()),
+            message,
+            code.trim(),
+        ])
+    }
+
+    /// Sorts the diagnostics by file and position, because the files are processed in parallel
+    pub(crate) fn sort(diagnostics: &mut [Self]) {
+        diagnostics.sort_by(|left, right| {
+            left.path
+                .cmp(&right.path)
+                .then_with(|| left.start.cmp(&right.start))
+        });
+    }
+
+    /// Splits sorted diagnostics into the diagnostics of each file
+    pub(crate) fn group_by_path(diagnostics: &[Self]) -> Vec<&[Self]> {
+        let mut files = vec![];
+        let mut start = 0;
+        for index in 1..=diagnostics.len() {
+            if index == diagnostics.len() || diagnostics[index].path != diagnostics[start].path {
+                files.push(&diagnostics[start..index]);
+                start = index;
+            }
+        }
+        files
+    }
+}
+
+/// Returns the name of a severity, as it is serialized
+pub(crate) fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Hint => "hint",
+        Severity::Information => "information",
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+        Severity::Fatal => "fatal",
+    }
+}
+
+/// Hashes `parts` with the 64 bits FNV-1a function, whose output is the same on every platform
+/// and with every version of Rust
+pub(crate) fn fingerprint(parts: &[&str]) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut hash = OFFSET_BASIS;
+    for (index, part) in parts.iter().enumerate() {
+        if index > 0 {
+            // Separates the parts, so that moving a character from a part to the next one changes the hash
+            hash = (hash ^ 0xff).wrapping_mul(PRIME);
+        }
+        for byte in part.bytes() {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(PRIME);
+        }
+    }
+
+    format!("{hash:016x}")
+}
+
+/// Escapes the characters that have a meaning in XML text and attribute values
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            '\t' => escaped.push_str("&#9;"),
+            // The other control characters aren't allowed in XML 1.0
+            char if char.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", char as u32);
+            }
+            char => escaped.push(char),
+        }
+    }
+    escaped
+}
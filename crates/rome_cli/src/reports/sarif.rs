@@ -0,0 +1,389 @@
+//! Serialization of diagnostics in the [SARIF 2.1.0] format, consumed by code scanning tools.
+//!
+//! [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use crate::reports::{line_column, LineIndexes, Reporter, TraversalSummary};
+use crate::{Execution, VERSION};
+use rome_console::fmt::{Display, Formatter};
+use rome_console::{markup, MarkupBuf};
+use rome_diagnostics::{
+    Advices, Category, Error, LineIndexBuf, LogCategory, PrintDescription, Resource, Severity,
+    Visit,
+};
+use rome_service::WorkspaceError;
+use rome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use rome_text_size::{TextRange, TextSize};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Collects the diagnostics emitted during a traversal, and serializes them as a SARIF log
+#[derive(Debug, Default)]
+pub struct SarifReport {
+    results: Vec<SarifResult>,
+    line_indexes: LineIndexes,
+}
+
+impl Reporter for SarifReport {
+    /// Tracks a diagnostic.
+    ///
+    /// The location ranges and the fixes are only computed when the source code of the file
+    /// is attached to the diagnostic.
+    fn report_diagnostic(&mut self, diagnostic: Error) {
+        let location = diagnostic.location();
+        let path = match location.resource {
+            Some(Resource::File(path)) => path.replace('\\', "/"),
+            _ => String::from("<unknown>"),
+        };
+        let source_code = location.source_code.map(|source_code| source_code.text);
+        let line_index = source_code.map(|text| self.line_indexes.get(&path, text));
+
+        let region = match (location.span, source_code, line_index) {
+            (Some(span), Some(text), Some(line_index)) => {
+                Some(SarifRegion::from_range(span, text, line_index))
+            }
+            _ => None,
+        };
+
+        let message = PrintDescription(&diagnostic).to_string();
+        let mut fixes = vec![];
+        if let (Some(text), Some(line_index)) = (source_code, line_index) {
+            let mut visitor = FixesVisitor {
+                path: &path,
+                text,
+                line_index,
+                message: &message,
+                description: None,
+                fixes: &mut fixes,
+            };
+            // The visitor doesn't emit any IO error
+            let _ = diagnostic.advices(&mut visitor);
+        }
+
+        self.results.push(SarifResult {
+            rule_id: diagnostic.category(),
+            rule_index: None,
+            level: SarifLevel::from(diagnostic.severity()),
+            message: SarifMessage { text: message },
+            locations: vec![SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation { uri: path },
+                    region,
+                },
+            }],
+            fixes,
+        });
+    }
+
+    /// Serializes the diagnostics in a SARIF log.
+    ///
+    /// The results are sorted by file and position, because the files are processed in parallel
+    fn finish(
+        mut self: Box<Self>,
+        _execution: &Execution,
+        _summary: &TraversalSummary,
+    ) -> Result<String, WorkspaceError> {
+        self.results.sort_by(|left, right| {
+            let left = &left.locations[0].physical_location;
+            let right = &right.locations[0].physical_location;
+            let position =
+                |region: &Option<SarifRegion>| region.as_ref().map(|region| region.start());
+
+            left.artifact_location
+                .uri
+                .cmp(&right.artifact_location.uri)
+                .then_with(|| position(&left.region).cmp(&position(&right.region)))
+        });
+
+        let rules: BTreeMap<&'static str, &'static Category> = self
+            .results
+            .iter()
+            .filter_map(|result| result.rule_id)
+            .map(|category| (category.name(), category))
+            .collect();
+        let rules: Vec<_> = rules
+            .into_values()
+            .map(|category| SarifRule {
+                id: category,
+                help_uri: category.link(),
+            })
+            .collect();
+
+        for result in &mut self.results {
+            result.rule_index = result
+                .rule_id
+                .and_then(|category| rules.iter().position(|rule| rule.id == category));
+        }
+
+        let log = SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: [SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "Rome",
+                        version: VERSION,
+                        information_uri: "https://rome.tools",
+                        rules,
+                    },
+                },
+                column_kind: "unicodeCodePoints",
+                results: self.results,
+            }],
+        };
+
+        serde_json::to_string_pretty(&log)
+            .map_err(|err| WorkspaceError::report_not_serializable(err.to_string()))
+    }
+}
+
+/// Advice visitor that turns the code suggestions of a diagnostic into SARIF fixes
+struct FixesVisitor<'a> {
+    path: &'a str,
+    /// The source code the diffs apply to
+    text: &'a str,
+    line_index: &'a LineIndexBuf,
+    /// The message of the diagnostic, it describes the code suggestions that aren't preceded by a log
+    message: &'a str,
+    /// The last log that was recorded, code suggestions are described by the log that precedes them
+    description: Option<String>,
+    fixes: &'a mut Vec<SarifFix>,
+}
+
+impl Visit for FixesVisitor<'_> {
+    fn record_log(&mut self, _category: LogCategory, text: &dyn Display) -> io::Result<()> {
+        self.description = Some(markup_to_string(text));
+        Ok(())
+    }
+
+    fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
+        let mut replacements = vec![];
+        // The deleted range and the inserted text of the change being built
+        let mut change: Option<(TextRange, String)> = None;
+        let mut position = TextSize::from(0);
+
+        for op in diff {
+            match op {
+                CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                    let (deleted, _) =
+                        change.get_or_insert_with(|| (TextRange::empty(position), String::new()));
+                    *deleted = deleted.cover_offset(position + range.len());
+                    position += range.len();
+                }
+                CompressedOp::DiffOp(DiffOp::Insert { range }) => {
+                    let (_, inserted) =
+                        change.get_or_insert_with(|| (TextRange::empty(position), String::new()));
+                    inserted.push_str(diff.get_text(*range));
+                }
+                CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                    replacements.extend(change.take().map(|change| self.replacement(change)));
+                    position += range.len();
+                }
+                CompressedOp::EqualLines { line_count } => {
+                    replacements.extend(change.take().map(|change| self.replacement(change)));
+                    let line_break_count = line_count.get() as usize + 1;
+                    for line in self.text[usize::from(position)..]
+                        .split_inclusive('\n')
+                        .take(line_break_count)
+                    {
+                        position += TextSize::of(line);
+                    }
+                }
+            }
+        }
+        replacements.extend(change.take().map(|change| self.replacement(change)));
+
+        if !replacements.is_empty() {
+            self.fixes.push(SarifFix {
+                description: SarifMessage {
+                    text: self
+                        .description
+                        .take()
+                        .unwrap_or_else(|| self.message.to_string()),
+                },
+                artifact_changes: vec![SarifArtifactChange {
+                    artifact_location: SarifArtifactLocation {
+                        uri: self.path.to_string(),
+                    },
+                    replacements,
+                }],
+            });
+        }
+
+        Ok(())
+    }
+
+    fn record_group(&mut self, _title: &dyn Display, advice: &dyn Advices) -> io::Result<()> {
+        advice.record(self)
+    }
+}
+
+impl FixesVisitor<'_> {
+    fn replacement(&self, (deleted, inserted): (TextRange, String)) -> SarifReplacement {
+        SarifReplacement {
+            deleted_region: SarifRegion::from_range(deleted, self.text, self.line_index),
+            inserted_content: (!inserted.is_empty())
+                .then_some(SarifArtifactContent { text: inserted }),
+        }
+    }
+}
+
+fn markup_to_string(text: &dyn Display) -> String {
+    let mut buffer = MarkupBuf::default();
+    // Writing into a MarkupBuf never fails
+    let _ = Formatter::new(&mut buffer).write_markup(markup! { {text} });
+    buffer.0.into_iter().map(|node| node.content).collect()
+}
+
+#[derive(Debug, Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun; 1],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: &'static Category,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'static str>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    /// The category of the diagnostic
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<&'static Category>,
+    /// The position of the rule in the rules of the driver, computed once all the results are collected
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: SarifLevel,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum SarifLevel {
+    Note,
+    Warning,
+    Error,
+}
+
+impl From<Severity> for SarifLevel {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Hint | Severity::Information => Self::Note,
+            Severity::Warning => Self::Warning,
+            Severity::Error | Severity::Fatal => Self::Error,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+/// A range of text, with one-based lines and columns. The end column is exclusive.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl SarifRegion {
+    fn from_range(range: TextRange, text: &str, line_index: &LineIndexBuf) -> Self {
+        let (start_line, start_column) = line_column(range.start(), text, line_index);
+        let (end_line, end_column) = line_column(range.end(), text, line_index);
+        Self {
+            start_line,
+            start_column,
+            end_line,
+            end_column,
+        }
+    }
+
+    fn start(&self) -> (usize, usize) {
+        (self.start_line, self.start_column)
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    description: SarifMessage,
+    artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inserted_content: Option<SarifArtifactContent>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactContent {
+    text: String,
+}
@@ -0,0 +1,290 @@
+use std::{
+    collections::HashMap,
+    fmt, io,
+    sync::{Mutex, Once},
+    time::{Duration, Instant},
+};
+
+use rome_console::{fmt::Formatter, markup};
+use tracing::{
+    field::{Field, Visit},
+    span,
+    subscriber::Interest,
+    Metadata, Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer};
+
+use crate::metrics::read_span;
+
+/// Implementation of a tracing [Layer] that measures the time spent processing each file, running
+/// each lint rule and in each phase of the analysis, for the `--timings` option
+struct TimingsLayer;
+
+lazy_static::lazy_static! {
+    /// The timings of the current traversal, `None` when `--timings` isn't passed
+    static ref TIMINGS: Mutex<Option<Timings>> = Mutex::default();
+}
+
+/// The spans measured by the [TimingsLayer], all the other spans are disabled
+#[derive(Debug, Clone)]
+enum SpanKind {
+    /// `process_file`, the whole processing of a file
+    File(String),
+    /// `rule`, a single run of a lint rule
+    Rule(String),
+    /// `parse`, `semantic_model`, `control_flow` and `format_node`
+    Phase(&'static str),
+}
+
+impl SpanKind {
+    /// Returns `true` if the layer measures the spans created by this callsite
+    fn is_measured(metadata: &Metadata) -> bool {
+        matches!(
+            metadata.name(),
+            "process_file" | "rule" | "parse" | "semantic_model" | "control_flow" | "format_node"
+        )
+    }
+
+    fn from_attributes(attributes: &span::Attributes) -> Option<Self> {
+        let mut fields = SpanFields::default();
+        attributes.record(&mut fields);
+
+        match attributes.metadata().name() {
+            "process_file" => Some(Self::File(fields.path?)),
+            "rule" => Some(Self::Rule(format!("{}/{}", fields.group?, fields.name?))),
+            "parse" => Some(Self::Phase("parse")),
+            "semantic_model" => Some(Self::Phase("semantic model")),
+            "control_flow" => Some(Self::Phase("control flow")),
+            "format_node" => Some(Self::Phase("format")),
+            _ => None,
+        }
+    }
+}
+
+/// The fields of a span that identify its file or its rule
+#[derive(Default)]
+struct SpanFields {
+    path: Option<String>,
+    group: Option<String>,
+    name: Option<String>,
+}
+
+impl Visit for SpanFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "path" => self.path = Some(value.to_string()),
+            "group" => self.group = Some(value.to_string()),
+            "name" => self.name = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record_str(field, &format!("{value:?}"));
+    }
+}
+
+/// Extension data attached to the measured spans
+///
+/// The time of a file includes the time of all its spans, while the time of a rule or of a phase
+/// doesn't include the time of the spans it contains
+struct SpanTimings {
+    kind: SpanKind,
+    /// The time during which this span was the innermost measured span
+    own: Duration,
+    /// The time between the first time this span was entered and the last time it was exited
+    total: Duration,
+    /// When the span was last entered, or resumed after a child span exited
+    resumed: Option<Instant>,
+    /// When the span was entered, if it's entered
+    entered: Option<Instant>,
+}
+
+impl SpanTimings {
+    fn pause(&mut self, now: Instant) {
+        if let Some(resumed) = self.resumed.take() {
+            self.own += now - resumed;
+        }
+    }
+}
+
+/// The time accumulated for each file, rule and phase
+#[derive(Debug, Default)]
+pub(crate) struct Timings {
+    files: HashMap<String, Duration>,
+    rules: HashMap<String, Duration>,
+    phases: HashMap<&'static str, Duration>,
+}
+
+impl Timings {
+    fn record(&mut self, span: SpanTimings) {
+        match span.kind {
+            SpanKind::File(path) => *self.files.entry(path).or_default() += span.total,
+            SpanKind::Rule(rule) => *self.rules.entry(rule).or_default() += span.own,
+            SpanKind::Phase(phase) => *self.phases.entry(phase).or_default() += span.own,
+        }
+    }
+
+    /// Returns the files sorted from the slowest one
+    pub(crate) fn slowest_files(&self) -> Vec<(&str, Duration)> {
+        sorted_by_duration(&self.files)
+    }
+
+    /// Returns the rules sorted from the slowest one
+    pub(crate) fn slowest_rules(&self) -> Vec<(&str, Duration)> {
+        sorted_by_duration(&self.rules)
+    }
+
+    /// Returns the phases sorted from the slowest one
+    pub(crate) fn slowest_phases(&self) -> Vec<(&str, Duration)> {
+        let mut phases: Vec<_> = self
+            .phases
+            .iter()
+            .map(|(phase, duration)| (*phase, *duration))
+            .collect();
+        phases.sort_by(|(a_name, a), (b_name, b)| b.cmp(a).then(a_name.cmp(b_name)));
+        phases
+    }
+}
+
+fn sorted_by_duration(durations: &HashMap<String, Duration>) -> Vec<(&str, Duration)> {
+    let mut durations: Vec<_> = durations
+        .iter()
+        .map(|(name, duration)| (name.as_str(), *duration))
+        .collect();
+    durations.sort_by(|(a_name, a), (b_name, b)| b.cmp(a).then(a_name.cmp(b_name)));
+    durations
+}
+
+impl<S> Layer<S> for TimingsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    /// Only express interest in the measured spans, so that the others aren't created
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        if metadata.is_span() && SpanKind::is_measured(metadata) {
+            Interest::always()
+        } else {
+            Interest::never()
+        }
+    }
+
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let Some(kind) = SpanKind::from_attributes(attrs) else {
+            return;
+        };
+
+        let span = read_span(&ctx, id);
+        span.extensions_mut().insert(SpanTimings {
+            kind,
+            own: Duration::ZERO,
+            total: Duration::ZERO,
+            resumed: None,
+            entered: None,
+        });
+    }
+
+    /// When a span is entered, the time stops counting for its parent
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        let span = read_span(&ctx, id);
+        let now = Instant::now();
+
+        if let Some(parent) = span.parent() {
+            let mut extensions = parent.extensions_mut();
+            if let Some(timings) = extensions.get_mut::<SpanTimings>() {
+                timings.pause(now);
+            }
+        }
+
+        let mut extensions = span.extensions_mut();
+        if let Some(timings) = extensions.get_mut::<SpanTimings>() {
+            timings.resumed = Some(now);
+            timings.entered = Some(now);
+        }
+    }
+
+    /// When a span is exited, the time counts for its parent again
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        let span = read_span(&ctx, id);
+        let now = Instant::now();
+
+        let mut extensions = span.extensions_mut();
+        if let Some(timings) = extensions.get_mut::<SpanTimings>() {
+            timings.pause(now);
+            if let Some(entered) = timings.entered.take() {
+                timings.total += now - entered;
+            }
+        }
+
+        if let Some(parent) = span.parent() {
+            let mut extensions = parent.extensions_mut();
+            if let Some(timings) = extensions.get_mut::<SpanTimings>() {
+                timings.resumed = Some(now);
+            }
+        }
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        let span = read_span(&ctx, &id);
+        let Some(timings) = span.extensions_mut().remove::<SpanTimings>() else {
+            return;
+        };
+
+        if let Some(report) = TIMINGS.lock().unwrap().as_mut() {
+            report.record(timings);
+        }
+    }
+}
+
+/// Starts measuring the time spent in the files, the rules and the phases
+///
+/// The layer is installed as the global subscriber the first time, the spans are only recorded
+/// between [start] and [finish]
+pub(crate) fn start() {
+    static INIT_ONCE: Once = Once::new();
+    INIT_ONCE.call_once(|| {
+        // Another global subscriber can't be replaced, the timings are then empty
+        let _ = tracing_subscriber::registry().with(TimingsLayer).try_init();
+    });
+
+    *TIMINGS.lock().unwrap() = Some(Timings::default());
+}
+
+/// Stops measuring the time, and returns the timings recorded since [start]
+pub(crate) fn finish() -> Timings {
+    TIMINGS.lock().unwrap().take().unwrap_or_default()
+}
+
+/// The number of files and of rules printed by [TimingsReport]
+const MAX_ENTRIES: usize = 10;
+
+/// Prints the slowest files, the slowest rules and the time spent in each phase
+pub(crate) struct TimingsReport<'a>(pub(crate) &'a Timings);
+
+impl rome_console::fmt::Display for TimingsReport<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let sections = [
+            ("Slowest files", self.0.slowest_files()),
+            ("Slowest rules", self.0.slowest_rules()),
+            ("Time per phase", self.0.slowest_phases()),
+        ];
+
+        for (index, (title, entries)) in sections.iter().enumerate() {
+            if index > 0 {
+                fmt.write_str("\n")?;
+            }
+            fmt.write_markup(markup! { <Emphasis>{title}":"</Emphasis>"\n" })?;
+
+            let entries = &entries[..entries.len().min(MAX_ENTRIES)];
+            let width = entries.iter().map(|(name, _)| name.len()).max();
+            for (name, duration) in entries {
+                let padding = width.unwrap_or_default() - name.len() + 2;
+                fmt.write_markup(markup! { "  "{name} })?;
+                fmt.write_str(&" ".repeat(padding))?;
+                fmt.write_markup(markup! { {duration}"\n" })?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::cli_options::CliOptions;
-use crate::diagnostics::{DisabledVcs, NoVcsFolderFound};
+use crate::diagnostics::{DisabledVcs, NoVcsFolderFound, VcsCommandFailed};
 use crate::{CliDiagnostic, CliSession};
 use rome_console::{markup, ConsoleExt};
 use rome_deserialize::StringSet;
@@ -7,7 +7,9 @@ use rome_diagnostics::PrintDiagnostic;
 use rome_service::configuration::vcs::{VcsClientKind, VcsConfiguration};
 use rome_service::configuration::FilesConfiguration;
 use rome_service::{Configuration, WorkspaceError};
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
 
 /// This function will check if the configuration is set to use the VCS integration and try to
 /// read the ignored files.
@@ -98,3 +100,148 @@ pub(crate) fn read_vcs_ignore_file(
 
     Ok(vec![])
 }
+
+/// Asks the version control system which files were modified, in order to process only them
+pub trait VcsClient {
+    /// Returns the files that changed since `base`, including the untracked files, relative
+    /// to the working directory
+    fn changed_files(&self, base: &str) -> Result<Vec<PathBuf>, CliDiagnostic>;
+
+    /// Returns the files that are staged for the next commit, relative to the working directory
+    fn staged_files(&self) -> Result<Vec<PathBuf>, CliDiagnostic>;
+}
+
+/// Implementation of [VcsClient] that runs the `git` executable in the working directory
+#[derive(Debug, Default)]
+pub struct GitClient;
+
+impl GitClient {
+    /// Runs `git` with `args`, and returns its output
+    fn run(&self, args: &[&str]) -> Result<String, CliDiagnostic> {
+        let command = format!("git {}", args.join(" "));
+        let output = Command::new("git").args(args).output().map_err(|error| {
+            CliDiagnostic::VcsCommandFailed(VcsCommandFailed {
+                command: command.clone(),
+                reason: error.to_string(),
+            })
+        })?;
+
+        if !output.status.success() {
+            return Err(CliDiagnostic::VcsCommandFailed(VcsCommandFailed {
+                command,
+                reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            }));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Runs `git` with `args`, and returns the paths it printed, separated by NUL characters
+    fn run_paths(&self, args: &[&str]) -> Result<Vec<PathBuf>, CliDiagnostic> {
+        let output = self.run(args)?;
+        Ok(output
+            .split('\0')
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .collect())
+    }
+}
+
+impl VcsClient for GitClient {
+    fn changed_files(&self, base: &str) -> Result<Vec<PathBuf>, CliDiagnostic> {
+        // Compare with the commit the current branch forked from, so that the changes
+        // made on `base` in the meantime are excluded
+        let merge_base = self.run(&["merge-base", base, "HEAD"])?;
+        let mut files = self.run_paths(&[
+            "diff",
+            "--name-only",
+            "--relative",
+            "--diff-filter=d",
+            "-z",
+            merge_base.trim(),
+        ])?;
+        files.extend(self.run_paths(&["ls-files", "--others", "--exclude-standard", "-z"])?);
+        Ok(files)
+    }
+
+    fn staged_files(&self) -> Result<Vec<PathBuf>, CliDiagnostic> {
+        self.run_paths(&[
+            "diff",
+            "--name-only",
+            "--relative",
+            "--diff-filter=d",
+            "--cached",
+            "-z",
+        ])
+    }
+}
+
+/// The files selected by the `--changed` or `--staged` options, the traversal only processes them
+#[derive(Debug)]
+pub(crate) struct ChangedFiles {
+    files: HashSet<PathBuf>,
+    working_directory: Option<PathBuf>,
+}
+
+impl ChangedFiles {
+    /// Asks the [VcsClient] of the session for the files selected by the options, it returns [None]
+    /// when none of `--changed` and `--staged` is passed
+    pub(crate) fn from_options(
+        session: &CliSession,
+        cli_options: &CliOptions,
+    ) -> Result<Option<Self>, CliDiagnostic> {
+        let files = match (&cli_options.changed, cli_options.staged) {
+            (Some(_), true) => {
+                return Err(CliDiagnostic::incompatible_arguments(
+                    "--changed",
+                    "--staged",
+                ))
+            }
+            (Some(base), false) => session.vcs.changed_files(base)?,
+            (None, true) => session.vcs.staged_files()?,
+            (None, false) => return Ok(None),
+        };
+
+        Ok(Some(Self::new(session.app.fs.working_directory(), files)))
+    }
+
+    /// Creates the list of `files`, whose paths are relative to `working_directory` or absolute
+    pub(crate) fn new(working_directory: Option<PathBuf>, files: Vec<PathBuf>) -> Self {
+        Self {
+            files: files
+                .iter()
+                .map(|path| normalize_path(path, working_directory.as_deref()))
+                .collect(),
+            working_directory,
+        }
+    }
+
+    /// The paths of the files, sorted
+    pub(crate) fn paths(&self) -> Vec<PathBuf> {
+        let mut paths: Vec<_> = self.files.iter().cloned().collect();
+        paths.sort();
+        paths
+    }
+
+    /// Returns `true` if `path` is one of the files
+    pub(crate) fn contains_file(&self, path: &Path) -> bool {
+        self.files
+            .contains(&normalize_path(path, self.working_directory.as_deref()))
+    }
+
+    /// Returns `true` if one of the files is inside the directory at `path`
+    pub(crate) fn contains_directory(&self, path: &Path) -> bool {
+        let path = normalize_path(path, self.working_directory.as_deref());
+        self.files.iter().any(|file| file.starts_with(&path))
+    }
+}
+
+/// Makes `path` relative to the working directory, and removes its `.` components
+fn normalize_path(path: &Path, working_directory: Option<&Path>) -> PathBuf {
+    let path = working_directory
+        .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+        .unwrap_or(path);
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect()
+}
@@ -0,0 +1,160 @@
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use crate::{run_cli, LINT_ERROR};
+use bpaf::Args;
+use rome_console::BufferConsole;
+use rome_fs::MemoryFileSystem;
+use rome_service::DynRef;
+use std::path::Path;
+
+#[test]
+fn write_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--write-baseline"),
+                ("baseline.json"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_reports_only_new_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from(
+            [
+                ("lint"),
+                ("--write-baseline"),
+                ("baseline.json"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The recorded diagnostics move to the next lines, and are copied once
+    fs.insert(
+        file_path.into(),
+        format!("statement();\n{LINT_ERROR}{LINT_ERROR}").as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--baseline"),
+                ("baseline.json"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_reports_only_new_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn missing_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--baseline"),
+                ("baseline.json"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "missing_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_and_write_baseline() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--baseline"),
+                ("old.json"),
+                ("--write-baseline"),
+                ("new.json"),
+                ("file.js"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_and_write_baseline",
+        fs,
+        console,
+        result,
+    ));
+}
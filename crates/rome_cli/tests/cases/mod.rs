@@ -1,4 +1,11 @@
 //! Add here test cases that are not related directly to a command, but to specific
 //! case that affects many commands
 
+mod baseline;
 mod config_extends;
+mod nested_configuration;
+mod overrides;
+mod result_cache;
+mod timings;
+mod vcs_changed_files;
+mod watch;
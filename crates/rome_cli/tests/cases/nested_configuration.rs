@@ -0,0 +1,146 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use bpaf::Args;
+use rome_console::BufferConsole;
+use rome_fs::{FileSystemExt, MemoryFileSystem};
+use rome_service::DynRef;
+use std::path::Path;
+
+const UNFORMATTED: &str = "const value = { first: 'a string', second: 'another string' };\n";
+
+fn assert_file_contents(fs: &MemoryFileSystem, file_path: &Path, expected: &str) {
+    let mut file = fs.open(file_path).expect("the file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, expected);
+}
+
+#[test]
+fn nested_configuration_is_merged_onto_the_root_one() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("rome.json").into(),
+        r#"{ "formatter": { "indentStyle": "space", "indentSize": 4 } }"#,
+    );
+    fs.insert(
+        Path::new("packages/narrow/rome.json").into(),
+        r#"{ "formatter": { "lineWidth": 40 } }"#,
+    );
+
+    let root_file = Path::new("src/file.js");
+    fs.insert(root_file.into(), UNFORMATTED);
+    let nested_file = Path::new("packages/narrow/src/file.js");
+    fs.insert(nested_file.into(), UNFORMATTED);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                root_file.as_os_str().to_str().unwrap(),
+                nested_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        root_file,
+        "const value = { first: \"a string\", second: \"another string\" };\n",
+    );
+    assert_file_contents(
+        &fs,
+        nested_file,
+        "const value = {\n    first: \"a string\",\n    second: \"another string\",\n};\n",
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_configuration_is_merged_onto_the_root_one",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nearest_nested_configuration_applies_to_the_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("rome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#,
+    );
+    fs.insert(
+        Path::new("packages/legacy/rome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#,
+    );
+    fs.insert(
+        Path::new("packages/legacy/tests/rome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } } }"#,
+    );
+
+    fs.insert(Path::new("packages/legacy/file.js").into(), "debugger;\n");
+    fs.insert(
+        Path::new("packages/legacy/src/file.js").into(),
+        "debugger;\n",
+    );
+    fs.insert(
+        Path::new("packages/legacy/tests/file.js").into(),
+        "debugger;\n",
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("packages")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nearest_nested_configuration_applies_to_the_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_configuration_with_errors() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("packages/app/rome.json").into(),
+        r#"{ "formatter": { "lineWidht": 40 } }"#,
+    );
+    let file_path = Path::new("packages/app/file.js");
+    fs.insert(file_path.into(), "statement();\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_configuration_with_errors",
+        fs,
+        console,
+        result,
+    ));
+}
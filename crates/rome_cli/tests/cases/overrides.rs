@@ -0,0 +1,211 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use bpaf::Args;
+use rome_console::BufferConsole;
+use rome_fs::{FileSystemExt, MemoryFileSystem};
+use rome_service::DynRef;
+use std::path::Path;
+
+const UNFORMATTED_LINE: &str =
+    "value.isEqual(firstArgument, secondArgument, thirdArgument, fourthArgument, fifthArgument);\n";
+const FORMATTED_LINE: &str = "value.isEqual(\n\tfirstArgument,\n\tsecondArgument,\n\tthirdArgument,\n\tfourthArgument,\n\tfifthArgument,\n);\n";
+
+fn assert_file_contents(fs: &MemoryFileSystem, file_path: &Path, expected: &str) {
+    let mut file = fs.open(file_path).expect("the file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, expected);
+}
+
+#[test]
+fn overrides_relax_a_rule_in_the_test_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("rome.json").into(),
+        r#"{
+  "overrides": [
+    {
+      "include": ["*.test.ts"],
+      "linter": {
+        "rules": {
+          "suspicious": {
+            "noExplicitAny": "off"
+          }
+        }
+      }
+    }
+  ]
+}"#,
+    );
+
+    let source_file = Path::new("src/file.ts");
+    fs.insert(source_file.into(), "export const value: any = 1;\n");
+    let test_file = Path::new("src/file.test.ts");
+    fs.insert(test_file.into(), "export const value: any = 1;\n");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                source_file.as_os_str().to_str().unwrap(),
+                test_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "overrides_relax_a_rule_in_the_test_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn overrides_change_the_line_width_of_the_generated_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("rome.json").into(),
+        r#"{
+  "overrides": [
+    { "include": ["generated/**"], "formatter": { "lineWidth": 40 } },
+    { "include": ["generated/**"], "formatter": { "lineWidth": 120 } }
+  ]
+}"#,
+    );
+
+    let source_file = Path::new("src/file.js");
+    fs.insert(source_file.into(), UNFORMATTED_LINE);
+    let generated_file = Path::new("generated/file.js");
+    fs.insert(generated_file.into(), UNFORMATTED_LINE);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                source_file.as_os_str().to_str().unwrap(),
+                generated_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, source_file, FORMATTED_LINE);
+    assert_file_contents(&fs, generated_file, UNFORMATTED_LINE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "overrides_change_the_line_width_of_the_generated_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn overrides_dont_apply_to_the_ignored_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("rome.json").into(),
+        r#"{
+  "overrides": [
+    {
+      "include": ["src/**"],
+      "ignore": ["src/vendor/**"],
+      "formatter": { "indentStyle": "space", "indentSize": 4 },
+      "javascript": { "formatter": { "trailingComma": "none" } }
+    }
+  ]
+}"#,
+    );
+
+    let source_file = Path::new("src/file.js");
+    fs.insert(source_file.into(), UNFORMATTED_LINE);
+    let vendor_file = Path::new("src/vendor/file.js");
+    fs.insert(vendor_file.into(), UNFORMATTED_LINE);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                source_file.as_os_str().to_str().unwrap(),
+                vendor_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        source_file,
+        "value.isEqual(\n    firstArgument,\n    secondArgument,\n    thirdArgument,\n    fourthArgument,\n    fifthArgument\n);\n",
+    );
+    assert_file_contents(&fs, vendor_file, FORMATTED_LINE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "overrides_dont_apply_to_the_ignored_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn overrides_disable_the_formatter() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("rome.json").into(),
+        r#"{
+  "overrides": [{ "include": ["generated/**"], "formatter": { "enabled": false } }]
+}"#,
+    );
+
+    let generated_file = Path::new("generated/file.js");
+    fs.insert(generated_file.into(), UNFORMATTED_LINE);
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), generated_file.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, generated_file, UNFORMATTED_LINE);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "overrides_disable_the_formatter",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,189 @@
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use crate::{run_cli_with_session_options, SessionOptions, FORMATTED, LINT_ERROR};
+use bpaf::Args;
+use rome_cli::CliEnvironment;
+use rome_console::BufferConsole;
+use rome_fs::{FileSystemExt, MemoryFileSystem};
+use rome_service::DynRef;
+use std::path::{Path, PathBuf};
+
+fn with_cache() -> SessionOptions {
+    SessionOptions {
+        environment: CliEnvironment {
+            cache_directory: Some(PathBuf::from("cache")),
+            ..CliEnvironment::default()
+        },
+        ..SessionOptions::default()
+    }
+}
+
+/// The memory file system doesn't have a working directory, the name of the cache file is
+/// the hash of an empty path
+const CACHE_PATH: &str = "cache/cbf29ce484222325.json";
+
+fn read_cache(fs: &MemoryFileSystem) -> Option<String> {
+    let mut content = String::new();
+    fs.open(Path::new(CACHE_PATH))
+        .ok()?
+        .read_to_string(&mut content)
+        .ok()?;
+    Some(content)
+}
+
+#[test]
+fn caches_files_without_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let clean = Path::new("clean.js");
+    fs.insert(clean.into(), FORMATTED.as_bytes());
+    let lint = Path::new("lint.js");
+    fs.insert(lint.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli_with_session_options(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                clean.as_os_str().to_str().unwrap(),
+                lint.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+        with_cache(),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let cache = read_cache(&fs).expect("the cache wasn't written");
+    assert!(cache.contains("clean.js"));
+    assert!(!cache.contains("lint.js"));
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "caches_files_without_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn invalidates_cache_when_configuration_changes() {
+    let mut fs = MemoryFileSystem::default();
+
+    let clean = Path::new("clean.js");
+    fs.insert(clean.into(), FORMATTED.as_bytes());
+
+    let result = run_cli_with_session_options(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from([("check"), clean.as_os_str().to_str().unwrap()].as_slice()),
+        with_cache(),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    let first_cache = read_cache(&fs).expect("the cache wasn't written");
+
+    fs.insert(
+        Path::new("rome.json").into(),
+        r#"{ "formatter": { "lineWidth": 120 } }"#.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli_with_session_options(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), clean.as_os_str().to_str().unwrap()].as_slice()),
+        with_cache(),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    let second_cache = read_cache(&fs).expect("the cache wasn't written");
+
+    assert_ne!(first_cache, second_cache);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "invalidates_cache_when_configuration_changes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn no_cache_doesnt_write_the_cache() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let clean = Path::new("clean.js");
+    fs.insert(clean.into(), FORMATTED.as_bytes());
+
+    let result = run_cli_with_session_options(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--no-cache"),
+                clean.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+        with_cache(),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(read_cache(&fs).is_none());
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "no_cache_doesnt_write_the_cache",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn invalidates_cache_when_a_nested_configuration_changes() {
+    let mut fs = MemoryFileSystem::default();
+
+    let nested_configuration = Path::new("packages/app/rome.json");
+    fs.insert(
+        nested_configuration.into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#.as_bytes(),
+    );
+    let file_path = Path::new("packages/app/file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli_with_session_options(
+        DynRef::Borrowed(&mut fs),
+        &mut BufferConsole::default(),
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+        with_cache(),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.insert(
+        nested_configuration.into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#.as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli_with_session_options(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+        with_cache(),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "invalidates_cache_when_a_nested_configuration_changes",
+        fs,
+        console,
+        result,
+    ));
+}
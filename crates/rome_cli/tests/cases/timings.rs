@@ -0,0 +1,95 @@
+use crate::snap_test::{assert_cli_snapshot, markup_to_string, SnapshotPayload};
+use crate::{run_cli, FORMATTED};
+use bpaf::Args;
+use rome_console::{markup, BufferConsole};
+use rome_fs::MemoryFileSystem;
+use rome_service::DynRef;
+use std::path::Path;
+
+/// Returns the report printed by `--timings`. The durations change at each run, so the tests
+/// only check its entries
+fn timings_report(console: &BufferConsole) -> String {
+    console
+        .out_buffer
+        .iter()
+        .map(|message| markup_to_string(markup! {{message.content}}))
+        .find(|message| message.starts_with("Slowest files:"))
+        .expect("the timings weren't printed")
+}
+
+#[test]
+fn timings_report_files_rules_and_phases() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("file.js").into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--only=suspicious/noDebugger"),
+                ("--timings"),
+                ("file.js"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let report = timings_report(&console);
+    assert!(report.contains("file.js"), "{report}");
+    assert!(report.contains("Slowest rules:"), "{report}");
+    assert!(report.contains("suspicious/noDebugger"), "{report}");
+    assert!(report.contains("Time per phase:"), "{report}");
+    assert!(report.contains("parse"), "{report}");
+}
+
+#[test]
+fn timings_report_analysis_and_format_phases() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("file.js").into(), FORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--timings"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let report = timings_report(&console);
+    assert!(report.contains("file.js"), "{report}");
+    assert!(report.contains("semantic model"), "{report}");
+    assert!(report.contains("control flow"), "{report}");
+    assert!(report.contains("format"), "{report}");
+}
+
+#[test]
+fn timings_with_use_server() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("file.js").into(), "let a = 1;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--timings"), ("--use-server"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "timings_with_use_server",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,173 @@
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use crate::{run_cli, run_cli_with_session_options, MemoryVcsClient, SessionOptions, LINT_ERROR};
+use bpaf::Args;
+use rome_console::BufferConsole;
+use rome_fs::MemoryFileSystem;
+use rome_service::DynRef;
+use std::path::{Path, PathBuf};
+
+#[test]
+fn check_changed_files_since_head() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("changed.js").into(), LINT_ERROR.as_bytes());
+    fs.insert(Path::new("unchanged.js").into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli_with_session_options(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--changed")].as_slice()),
+        SessionOptions {
+            vcs: MemoryVcsClient {
+                changed_files: vec![("HEAD", PathBuf::from("changed.js"))],
+                ..MemoryVcsClient::default()
+            },
+            ..SessionOptions::default()
+        },
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_changed_files_since_head",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_changed_files_since_base() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("changed.js").into(), LINT_ERROR.as_bytes());
+    fs.insert(Path::new("unchanged.js").into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli_with_session_options(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--changed=main")].as_slice()),
+        SessionOptions {
+            vcs: MemoryVcsClient {
+                changed_files: vec![
+                    ("main", PathBuf::from("./changed.js")),
+                    ("HEAD", PathBuf::from("unchanged.js")),
+                ],
+                ..MemoryVcsClient::default()
+            },
+            ..SessionOptions::default()
+        },
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_changed_files_since_base",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_staged_files_among_inputs() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let staged = Path::new("staged.js");
+    fs.insert(staged.into(), LINT_ERROR.as_bytes());
+    let unstaged = Path::new("unstaged.js");
+    fs.insert(unstaged.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli_with_session_options(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--staged"),
+                staged.as_os_str().to_str().unwrap(),
+                unstaged.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+        SessionOptions {
+            vcs: MemoryVcsClient {
+                staged_files: vec![PathBuf::from("staged.js")],
+                ..MemoryVcsClient::default()
+            },
+            ..SessionOptions::default()
+        },
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_staged_files_among_inputs",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn no_staged_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(Path::new("unstaged.js").into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--staged")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "no_staged_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn changed_and_staged_are_incompatible() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--changed"),
+                ("--staged"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "changed_and_staged_are_incompatible",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,103 @@
+use crate::snap_test::{assert_cli_snapshot, SnapshotPayload};
+use crate::{
+    run_cli, run_cli_with_session_options, MemoryFileWatcher, SessionOptions, FORMATTED, LINT_ERROR,
+};
+use bpaf::Args;
+use rome_console::BufferConsole;
+use rome_fs::MemoryFileSystem;
+use rome_service::DynRef;
+use std::path::{Path, PathBuf};
+
+#[test]
+fn check_again_the_changed_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let clean = Path::new("clean.js");
+    fs.insert(clean.into(), FORMATTED.as_bytes());
+    let lint = Path::new("lint.js");
+    fs.insert(lint.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli_with_session_options(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                ("--watch"),
+                clean.as_os_str().to_str().unwrap(),
+                lint.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+        SessionOptions {
+            watcher: MemoryFileWatcher {
+                changes: vec![vec![PathBuf::from("lint.js")]],
+            },
+            ..SessionOptions::default()
+        },
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_again_the_changed_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn ignores_the_changes_of_unsupported_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let clean = Path::new("clean.js");
+    fs.insert(clean.into(), FORMATTED.as_bytes());
+
+    let result = run_cli_with_session_options(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--watch"), clean.as_os_str().to_str().unwrap()].as_slice()),
+        SessionOptions {
+            watcher: MemoryFileWatcher {
+                changes: vec![vec![PathBuf::from("README.md")]],
+            },
+            ..SessionOptions::default()
+        },
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "ignores_the_changes_of_unsupported_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn watch_with_stdin_file_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--watch"), ("--stdin-file-path"), ("file.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "watch_with_stdin_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
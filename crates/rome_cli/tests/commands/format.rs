@@ -1951,3 +1951,85 @@ fn treat_known_json_files_as_jsonc_files() {
         result,
     ));
 }
+
+#[test]
+fn format_json_trailing_commas() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_json = r#"{
+  "json": {
+    "formatter": { "trailingCommas": "all" },
+    "parser": { "allowTrailingCommas": true }
+  }
+}"#;
+    let code = r#"{
+	"compilerOptions": { "strict": true, "lib": ["dom", "es2022",], },
+	"include": ["src/**/*.ts", "tests/**/*.ts", "scripts/**/*.ts", "benchmarks/**/*.ts"]
+}"#;
+    fs.insert(Path::new("rome.json").into(), config_json.as_bytes());
+    let tsconfig = Path::new("tsconfig.json");
+    fs.insert(tsconfig.into(), code.as_bytes());
+    let json = Path::new("file.json");
+    fs.insert(json.into(), code.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                tsconfig.as_os_str().to_str().unwrap(),
+                json.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_json_trailing_commas",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_css_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let code = r#"
+.header,#app>.title{color:red;margin:0 auto}
+@media screen and (min-width:800px){a{display:none}}
+	"#;
+    let file_path = Path::new("file.css");
+    fs.insert(file_path.into(), code.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                "--write",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_css_files",
+        fs,
+        console,
+        result,
+    ));
+}
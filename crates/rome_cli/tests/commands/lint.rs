@@ -2299,6 +2299,50 @@ fn check_json_files() {
     ));
 }
 
+#[test]
+fn check_css_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path1 = Path::new("test.css");
+    fs.insert(
+        file_path1.into(),
+        r#"a { color: red; width: 10pixels; color: blue; }"#.as_bytes(),
+    );
+
+    let configuration = Path::new("rome.json");
+    fs.insert(
+        configuration.into(),
+        r#"{
+	"linter": {
+		"rules": {
+			"nursery": {
+				"noDuplicateCssProperties": "error",
+				"noUnknownCssUnits": "error"
+			}
+		}
+	}
+	 }"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path1.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_css_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn doesnt_error_if_no_files_were_processed() {
     let mut console = BufferConsole::default();
@@ -2426,3 +2470,271 @@ A = 0;
         result,
     ));
 }
+
+const SERVER_SCHEMA: &str = r#"{
+	"type": "object",
+	"properties": {
+		"name": { "type": "string" },
+		"port": { "type": "integer" }
+	},
+	"required": ["name"],
+	"additionalProperties": false
+}"#;
+
+#[test]
+fn validate_json_against_configured_schema() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("schemas/server.schema.json").into(),
+        SERVER_SCHEMA.as_bytes(),
+    );
+
+    let file_path = Path::new("prod.server.json");
+    fs.insert(
+        file_path.into(),
+        r#"{ "name": "prod", "port": "443", "host": "localhost" }"#.as_bytes(),
+    );
+
+    let configuration = Path::new("rome.json");
+    fs.insert(
+        configuration.into(),
+        r#"{
+	"json": {
+		"schemas": [
+			{ "include": ["*.server.json"], "schema": "./schemas/server.schema.json" }
+		]
+	}
+}"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "validate_json_against_configured_schema",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn validate_json_against_schema_reference() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("schemas/server.schema.json").into(),
+        SERVER_SCHEMA.as_bytes(),
+    );
+
+    let file_path = Path::new("config/dev.json");
+    fs.insert(
+        file_path.into(),
+        r#"{ "$schema": "../schemas/server.schema.json", "port": 80 }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "validate_json_against_schema_reference",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn warns_when_json_schema_is_missing() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("config.json");
+    fs.insert(
+        file_path.into(),
+        r#"{ "$schema": "./missing.schema.json" }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "warns_when_json_schema_is_missing",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_only_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--only=style/useWhile"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_only_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_only_rule_not_enabled_in_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "void 0;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--only=nursery/noVoid"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_only_rule_not_enabled_in_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_skip_group() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--skip=nursery"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_skip_group",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn apply_only_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply-unsafe"),
+                ("--only=style/useWhile"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "apply_only_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_only_unknown_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), ("--only=style/noUnknownRule"), ("check.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_only_unknown_rule",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -85,3 +85,270 @@ fn missing_configuration_file() {
         result,
     ));
 }
+
+#[test]
+fn migrate_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{
+  "linter": {
+    "rules": {
+      "ruleSet": "recommended",
+      "correctness": {
+        "noDebugger": "off"
+      },
+      "nursery": {
+        "noConstAssign": "error"
+      }
+    }
+  }
+}"#;
+
+    let configuration_path = Path::new("rome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs.open(configuration_path).expect("file to open");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, configuration);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_rules_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{
+  "linter": {
+    "rules": {
+      "ruleSet": "all",
+      "a11y": {
+        "useBlankTarget": "warn"
+      },
+      "nursery": {
+        "noDupeKeys": "error",
+        "useFlatMap": "off"
+      }
+    }
+  }
+}"#;
+
+    let configuration_path = Path::new("rome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs.open(configuration_path).expect("file to open");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(
+        content,
+        r#"{
+  "linter": {
+    "rules": {
+      "all": true,
+      "a11y": {
+        "noBlankTarget": "warn"
+      },
+      "complexity": {
+        "useFlatMap": "off"
+      },
+      "suspicious": {
+        "noDuplicateObjectKeys": "error"
+      }
+    }
+  }
+}"#
+    );
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_rules_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_eslint() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "linter": { "enabled": true } }"#;
+    let eslint_configuration = r#"{
+    "root": true,
+    "env": { "browser": true, "jest": true },
+    "plugins": ["react"],
+    "globals": { "jQuery": "readonly", "$": "off" },
+    "ignorePatterns": ["dist/**"],
+    "rules": {
+        // Comments are allowed
+        "no-debugger": "error",
+        "eqeqeq": ["warn", "always"],
+        "no-restricted-globals": ["error", "event", { "name": "fdescribe", "message": "Use describe" }],
+        "no-alert": "error",
+        "@typescript-eslint/no-explicit-any": 0
+    }
+}"#;
+
+    let configuration_path = Path::new("rome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+    fs.insert(
+        Path::new(".eslintrc.json").into(),
+        eslint_configuration.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--eslint", "--write"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs.open(configuration_path).expect("file to open");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(
+        content,
+        r#"{
+	"linter": {
+		"enabled": true,
+		"ignore": ["dist/**"],
+		"rules": {
+			"style": {
+				"noRestrictedGlobals": {
+					"level": "error",
+					"options": { "deniedGlobals": ["event", "fdescribe"] }
+				}
+			},
+			"suspicious": {
+				"noDebugger": "error",
+				"noDoubleEquals": "warn",
+				"noExplicitAny": "off"
+			}
+		}
+	},
+	"javascript": { "globals": ["jQuery"] }
+}
+"#
+    );
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_eslint",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_prettier() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{ "formatter": { "enabled": true, "lineWidth": 120 } }"#;
+    let prettier_configuration = r#"{
+    "printWidth": 100,
+    "singleQuote": true,
+    "semi": false,
+    "trailingComma": "es5",
+    "bracketSpacing": true,
+    "endOfLine": "crlf",
+    "overrides": []
+}"#;
+
+    let configuration_path = Path::new("rome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+    fs.insert(
+        Path::new(".prettierrc").into(),
+        prettier_configuration.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--prettier"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs.open(configuration_path).expect("file to open");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, configuration);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_prettier",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn migrate_prettier_missing_file() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration_path = Path::new("rome.json");
+    fs.insert(configuration_path.into(), "{}".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("migrate"), "--prettier"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "migrate_prettier_missing_file",
+        fs,
+        console,
+        result,
+    ));
+}
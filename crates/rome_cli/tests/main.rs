@@ -8,9 +8,9 @@ mod snap_test;
 use snap_test::assert_cli_snapshot;
 
 use bpaf::ParseFailure;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use rome_cli::{rome_command, CliDiagnostic, CliSession};
+use rome_cli::{rome_command, CliDiagnostic, CliEnvironment, CliSession, FileWatcher, VcsClient};
 use rome_console::{markup, BufferConsole, Console, ConsoleExt};
 use rome_fs::{FileSystem, MemoryFileSystem};
 use rome_service::{App, DynRef};
@@ -391,12 +391,414 @@ mod reporter_json {
     }
 }
 
+mod reporter_sarif {
+    use super::*;
+    use crate::snap_test::SnapshotPayload;
+    use bpaf::Args;
+
+    #[test]
+    fn reports_check_diagnostics() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("check.js");
+        fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(
+                [
+                    "check",
+                    "--reporter=sarif",
+                    file_path.as_os_str().to_str().unwrap(),
+                ]
+                .as_slice(),
+            ),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_eq!(console.out_buffer.len(), 1);
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "reports_check_diagnostics",
+            fs,
+            console,
+            result,
+        ));
+    }
+
+    #[test]
+    fn reports_ci_diagnostics() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("ci.js");
+        fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(
+                [
+                    "ci",
+                    "--reporter=sarif",
+                    file_path.as_os_str().to_str().unwrap(),
+                ]
+                .as_slice(),
+            ),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_eq!(console.out_buffer.len(), 1);
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "reports_ci_diagnostics",
+            fs,
+            console,
+            result,
+        ));
+    }
+}
+
+mod reporter_junit {
+    use super::*;
+    use crate::snap_test::SnapshotPayload;
+    use bpaf::Args;
+
+    #[test]
+    fn reports_check_diagnostics() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("check.js");
+        fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(
+                [
+                    "check",
+                    "--reporter=junit",
+                    file_path.as_os_str().to_str().unwrap(),
+                ]
+                .as_slice(),
+            ),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_eq!(console.out_buffer.len(), 1);
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "reports_check_diagnostics",
+            fs,
+            console,
+            result,
+        ));
+    }
+
+    #[test]
+    fn writes_report_to_file() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("ci.js");
+        fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(
+                [
+                    "ci",
+                    "--reporter=junit",
+                    "--reporter-file=report.xml",
+                    file_path.as_os_str().to_str().unwrap(),
+                ]
+                .as_slice(),
+            ),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert!(console.out_buffer.is_empty());
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "writes_report_to_file",
+            fs,
+            console,
+            result,
+        ));
+    }
+
+    #[test]
+    fn reporter_file_requires_reporter() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("ci.js");
+        fs.insert(file_path.into(), FORMATTED.as_bytes());
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(
+                [
+                    "ci",
+                    "--reporter-file=report.xml",
+                    file_path.as_os_str().to_str().unwrap(),
+                ]
+                .as_slice(),
+            ),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "reporter_file_requires_reporter",
+            fs,
+            console,
+            result,
+        ));
+    }
+}
+
+mod reporter_checkstyle {
+    use super::*;
+    use crate::snap_test::SnapshotPayload;
+    use bpaf::Args;
+
+    #[test]
+    fn reports_check_diagnostics() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("check.js");
+        fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(
+                [
+                    "check",
+                    "--reporter=checkstyle",
+                    file_path.as_os_str().to_str().unwrap(),
+                ]
+                .as_slice(),
+            ),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_eq!(console.out_buffer.len(), 1);
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "reports_check_diagnostics",
+            fs,
+            console,
+            result,
+        ));
+    }
+}
+
+mod reporter_gitlab {
+    use super::*;
+    use crate::snap_test::SnapshotPayload;
+    use bpaf::Args;
+
+    #[test]
+    fn reports_check_diagnostics() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("check.js");
+        fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(
+                [
+                    "check",
+                    "--reporter=gitlab",
+                    file_path.as_os_str().to_str().unwrap(),
+                ]
+                .as_slice(),
+            ),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_eq!(console.out_buffer.len(), 1);
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "reports_check_diagnostics",
+            fs,
+            console,
+            result,
+        ));
+    }
+}
+
+mod reporter_github {
+    use super::*;
+    use crate::snap_test::SnapshotPayload;
+    use bpaf::Args;
+
+    #[test]
+    fn reports_check_diagnostics() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("check.js");
+        fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(
+                [
+                    "check",
+                    "--reporter=github",
+                    file_path.as_os_str().to_str().unwrap(),
+                ]
+                .as_slice(),
+            ),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_eq!(console.out_buffer.len(), 1);
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "reports_check_diagnostics",
+            fs,
+            console,
+            result,
+        ));
+    }
+
+    #[test]
+    fn ci_reports_annotations_in_github_actions() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let lint_path = Path::new("lint.js");
+        fs.insert(lint_path.into(), LINT_ERROR.as_bytes());
+
+        let format_path = Path::new("format.js");
+        fs.insert(format_path.into(), UNFORMATTED.as_bytes());
+
+        let result = run_cli_with_session_options(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(
+                [
+                    "ci",
+                    lint_path.as_os_str().to_str().unwrap(),
+                    format_path.as_os_str().to_str().unwrap(),
+                ]
+                .as_slice(),
+            ),
+            SessionOptions {
+                environment: CliEnvironment {
+                    github_actions: true,
+                    ..CliEnvironment::default()
+                },
+                ..SessionOptions::default()
+            },
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_eq!(console.out_buffer.len(), 1);
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "ci_reports_annotations_in_github_actions",
+            fs,
+            console,
+            result,
+        ));
+    }
+
+    #[test]
+    fn ci_reporter_overrides_github_actions() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("ci.js");
+        fs.insert(file_path.into(), LINT_ERROR.as_bytes());
+
+        let result = run_cli_with_session_options(
+            DynRef::Borrowed(&mut fs),
+            &mut console,
+            Args::from(
+                [
+                    "ci",
+                    "--reporter=checkstyle",
+                    file_path.as_os_str().to_str().unwrap(),
+                ]
+                .as_slice(),
+            ),
+            SessionOptions {
+                environment: CliEnvironment {
+                    github_actions: true,
+                    ..CliEnvironment::default()
+                },
+                ..SessionOptions::default()
+            },
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "ci_reporter_overrides_github_actions",
+            fs,
+            console,
+            result,
+        ));
+    }
+}
+
 /// Create an [App] instance using the provided [FileSystem] and [Console]
 /// instance, and using an in-process "remote" instance of the workspace
 pub(crate) fn run_cli<'app>(
     fs: DynRef<'app, dyn FileSystem>,
     console: &'app mut dyn Console,
     args: bpaf::Args,
+) -> Result<(), CliDiagnostic> {
+    run_cli_with_session_options(fs, console, args, SessionOptions::default())
+}
+
+/// The parts of the [CliSession] that depend on the process, replaced with fakes in the tests
+#[derive(Default)]
+pub(crate) struct SessionOptions {
+    pub(crate) environment: CliEnvironment,
+    pub(crate) vcs: MemoryVcsClient,
+    pub(crate) watcher: MemoryFileWatcher,
+}
+
+/// Same as [run_cli], with the given [SessionOptions]
+pub(crate) fn run_cli_with_session_options<'app>(
+    fs: DynRef<'app, dyn FileSystem>,
+    console: &'app mut dyn Console,
+    args: bpaf::Args,
+    options: SessionOptions,
 ) -> Result<(), CliDiagnostic> {
     use rome_cli::SocketTransport;
     use rome_lsp::ServerFactory;
@@ -421,7 +823,12 @@ pub(crate) fn run_cli<'app>(
     let workspace = workspace::client(transport).unwrap();
     let app = App::new(fs, console, WorkspaceRef::Owned(workspace));
 
-    let mut session = CliSession { app };
+    let mut session = CliSession {
+        app,
+        environment: options.environment,
+        vcs: Box::new(options.vcs),
+        watcher: Box::new(options.watcher),
+    };
     let command = rome_command().run_inner(args);
     match command {
         Ok(command) => session.run(command),
@@ -436,3 +843,41 @@ pub(crate) fn run_cli<'app>(
         }
     }
 }
+
+/// A [VcsClient] that returns the files it was created with, instead of asking git
+#[derive(Debug, Default)]
+pub(crate) struct MemoryVcsClient {
+    pub(crate) changed_files: Vec<(&'static str, PathBuf)>,
+    pub(crate) staged_files: Vec<PathBuf>,
+}
+
+impl VcsClient for MemoryVcsClient {
+    fn changed_files(&self, base: &str) -> Result<Vec<PathBuf>, CliDiagnostic> {
+        Ok(self
+            .changed_files
+            .iter()
+            .filter(|(file_base, _)| *file_base == base)
+            .map(|(_, path)| path.clone())
+            .collect())
+    }
+
+    fn staged_files(&self) -> Result<Vec<PathBuf>, CliDiagnostic> {
+        Ok(self.staged_files.clone())
+    }
+}
+
+/// A [FileWatcher] that returns the changes it was created with, one by one
+#[derive(Debug, Default)]
+pub(crate) struct MemoryFileWatcher {
+    pub(crate) changes: Vec<Vec<PathBuf>>,
+}
+
+impl FileWatcher for MemoryFileWatcher {
+    fn wait_for_changes(&mut self, _roots: &[PathBuf]) -> Option<Vec<PathBuf>> {
+        if self.changes.is_empty() {
+            None
+        } else {
+            Some(self.changes.remove(0))
+        }
+    }
+}
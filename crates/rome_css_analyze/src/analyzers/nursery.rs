@@ -0,0 +1,16 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use rome_analyze::declare_group;
+
+pub(crate) mod no_duplicate_css_properties;
+pub(crate) mod no_unknown_css_units;
+
+declare_group! {
+    pub (crate) Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: no_duplicate_css_properties :: NoDuplicateCssProperties ,
+            self :: no_unknown_css_units :: NoUnknownCssUnits ,
+        ]
+     }
+}
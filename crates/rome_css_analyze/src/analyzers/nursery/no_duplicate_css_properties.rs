@@ -0,0 +1,219 @@
+use rome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_css_syntax::{
+    AnyCssBlockItem, AnyCssDeclarationName, CssBlock, CssComponentValueList, CssDeclaration,
+    CssIdentifier, CssSimpleFunction, CssUrlFunction, CssVarFunction,
+};
+use rome_rowan::{AstNode, TextRange};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap};
+
+declare_rule! {
+    /// Disallow duplicate properties inside a declaration block.
+    ///
+    /// Only the last declaration of a property takes effect, the previous ones are overridden.
+    ///
+    /// A property that is declared again directly after itself is allowed when one of the two values
+    /// uses a vendor-prefixed keyword or a function that the other value doesn't use,
+    /// because it's a common way to provide a fallback for browsers that don't support the second value.
+    ///
+    /// Property names are compared case-insensitively, with the exception of custom properties.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   color: red;
+    ///   background: blue;
+    ///   color: green;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   color: red;
+    ///   COLOR: red;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   color: red;
+    ///   color: blue;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a {
+    ///   color: red;
+    ///   background: blue;
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// a {
+    ///   display: -webkit-box;
+    ///   display: flex;
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// a {
+    ///   width: 100px;
+    ///   width: calc(100% - 2rem);
+    /// }
+    /// ```
+    pub(crate) NoDuplicateCssProperties {
+        version: "next",
+        name: "noDuplicateCssProperties",
+        recommended: true,
+    }
+}
+
+pub(crate) struct DuplicatedProperty {
+    /// The range of the name of the first declaration of the property
+    first_declaration: TextRange,
+    /// The declaration that declares the property again
+    duplicate: CssDeclaration,
+}
+
+impl Rule for NoDuplicateCssProperties {
+    type Query = Ast<CssBlock>;
+    type State = DuplicatedProperty;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+
+        let mut declared = HashMap::<String, TextRange>::new();
+        let mut previous: Option<(String, BTreeSet<String>)> = None;
+        let mut signals = Vec::new();
+
+        for item in node.items() {
+            let declaration = match item {
+                AnyCssBlockItem::CssDeclaration(declaration) => declaration,
+                AnyCssBlockItem::CssEmptyDeclaration(_) => continue,
+                _ => {
+                    previous = None;
+                    continue;
+                }
+            };
+
+            let Ok(name) = declaration.name() else {
+                previous = None;
+                continue;
+            };
+            let Some(text) = property_name(&name) else {
+                previous = None;
+                continue;
+            };
+            let features = fallback_features(&declaration.value());
+
+            let is_fallback =
+                previous
+                    .as_ref()
+                    .map_or(false, |(previous_name, previous_features)| {
+                        *previous_name == text && *previous_features != features
+                    });
+
+            match declared.entry(text.clone()) {
+                Entry::Occupied(entry) => {
+                    if !is_fallback {
+                        signals.push(DuplicatedProperty {
+                            first_declaration: *entry.get(),
+                            duplicate: declaration,
+                        });
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(name.range());
+                }
+            }
+
+            previous = Some((text, features));
+        }
+
+        signals
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let DuplicatedProperty {
+            first_declaration,
+            duplicate,
+        } = state;
+        let name = duplicate.name().ok()?;
+
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.range(),
+                markup! {
+                    "The property "<Emphasis>{{name.syntax().text_trimmed().to_string()}}</Emphasis>" is already declared in this block."
+                },
+            )
+            .detail(
+                first_declaration,
+                markup! {
+                    "The property was first declared here:"
+                },
+            )
+            .note(markup! {
+                "Only the last declaration of a property takes effect, the previous ones are overridden."
+            }),
+        )
+    }
+}
+
+/// Returns the name used to compare two properties: custom properties are case-sensitive,
+/// all other properties aren't.
+fn property_name(name: &AnyCssDeclarationName) -> Option<String> {
+    match name {
+        AnyCssDeclarationName::CssIdentifier(identifier) => Some(
+            identifier
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                .to_ascii_lowercase(),
+        ),
+        AnyCssDeclarationName::CssCustomProperty(property) => {
+            Some(property.value_token().ok()?.text_trimmed().to_string())
+        }
+    }
+}
+
+/// Returns the vendor-prefixed keywords and the names of the functions used by a value.
+///
+/// Two consecutive declarations of a property whose values have different features
+/// are considered a fallback, such as `display: -webkit-box; display: flex;`.
+fn fallback_features(value: &CssComponentValueList) -> BTreeSet<String> {
+    value
+        .syntax()
+        .descendants()
+        .filter_map(|node| {
+            if let Some(function) = CssSimpleFunction::cast_ref(&node) {
+                let name = function.name().ok()?.value_token().ok()?;
+                Some(format!("{}()", name.text_trimmed().to_ascii_lowercase()))
+            } else if CssVarFunction::can_cast(node.kind()) {
+                Some(String::from("var()"))
+            } else if CssUrlFunction::can_cast(node.kind()) {
+                Some(String::from("url()"))
+            } else {
+                let identifier = CssIdentifier::cast(node)?;
+                let text = identifier.value_token().ok()?;
+                let text = text.text_trimmed().to_ascii_lowercase();
+                is_vendor_prefixed(&text).then_some(text)
+            }
+        })
+        .collect()
+}
+
+fn is_vendor_prefixed(text: &str) -> bool {
+    ["-webkit-", "-moz-", "-ms-", "-o-"]
+        .iter()
+        .any(|prefix| text.starts_with(prefix))
+}
@@ -0,0 +1,100 @@
+use rome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_css_syntax::{CssDimension, CssPseudoValueParameters};
+use rome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow units that aren't defined by the CSS specifications.
+    ///
+    /// Unit names are compared case-insensitively.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   width: 10pixels;
+    /// }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   transition: opacity 1sec;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a {
+    ///   width: 10px;
+    ///   height: 100vh;
+    ///   transform: rotate(45deg);
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// li:nth-child(2n + 1) {}
+    /// ```
+    pub(crate) NoUnknownCssUnits {
+        version: "next",
+        name: "noUnknownCssUnits",
+        recommended: true,
+    }
+}
+
+/// Units defined by the CSS specifications, sorted alphabetically.
+const KNOWN_UNITS: [&str; 62] = [
+    "cap", "ch", "cm", "cqb", "cqh", "cqi", "cqmax", "cqmin", "cqw", "deg", "dpcm", "dpi", "dppx",
+    "dvb", "dvh", "dvi", "dvmax", "dvmin", "dvw", "em", "ex", "fr", "grad", "hz", "ic", "in",
+    "khz", "lh", "lvb", "lvh", "lvi", "lvmax", "lvmin", "lvw", "mm", "ms", "pc", "pt", "px", "q",
+    "rad", "rcap", "rch", "rem", "rex", "ric", "rlh", "s", "svb", "svh", "svi", "svmax", "svmin",
+    "svw", "turn", "vb", "vh", "vi", "vmax", "vmin", "vw", "x",
+];
+
+impl Rule for NoUnknownCssUnits {
+    type Query = Ast<CssDimension>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+
+        // The `n` of `:nth-child(2n + 1)` isn't a unit
+        if node
+            .syntax()
+            .ancestors()
+            .any(|ancestor| CssPseudoValueParameters::can_cast(ancestor.kind()))
+        {
+            return None;
+        }
+
+        let unit = node.unit().ok()?.value_token().ok()?;
+        let unit = unit.text_trimmed().to_ascii_lowercase();
+
+        if KNOWN_UNITS.binary_search(&unit.as_str()).is_ok() {
+            None
+        } else {
+            Some(())
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let unit = ctx.query().unit().ok()?;
+
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                unit.range(),
+                markup! {
+                    "Unknown unit "<Emphasis>{{unit.syntax().text_trimmed().to_string()}}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "See "<Hyperlink href="https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Values_and_Units">"MDN web docs"</Hyperlink>" for the list of valid units."
+            }),
+        )
+    }
+}
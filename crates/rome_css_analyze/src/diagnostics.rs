@@ -0,0 +1,5 @@
+use rome_diagnostics::Diagnostic;
+
+#[derive(Clone, Debug, PartialEq, Eq, Diagnostic)]
+#[diagnostic(category = "suppressions/parse")]
+pub struct SuppressionDiagnostic {}
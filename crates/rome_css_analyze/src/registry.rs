@@ -0,0 +1,7 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use rome_analyze::RegistryVisitor;
+use rome_css_syntax::CssLanguage;
+pub fn visit_registry<V: RegistryVisitor<CssLanguage>>(registry: &mut V) {
+    registry.record_category::<crate::analyzers::Analyzers>();
+}
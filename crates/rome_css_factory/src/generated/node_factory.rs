@@ -7,17 +7,88 @@ use rome_css_syntax::{
     *,
 };
 use rome_rowan::AstNode;
-pub fn css_any_function(css_simple_function: CssSimpleFunction) -> CssAnyFunction {
-    CssAnyFunction::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ANY_FUNCTION,
-        [Some(SyntaxElement::Node(css_simple_function.into_syntax()))],
+pub fn css_at_charset(
+    at_token: SyntaxToken,
+    charset_token: SyntaxToken,
+    encoding: CssString,
+    semicolon_token: SyntaxToken,
+) -> CssAtCharset {
+    CssAtCharset::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_AT_CHARSET,
+        [
+            Some(SyntaxElement::Token(at_token)),
+            Some(SyntaxElement::Token(charset_token)),
+            Some(SyntaxElement::Node(encoding.into_syntax())),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
+    ))
+}
+pub fn css_at_generic(
+    at_token: SyntaxToken,
+    name: CssIdentifier,
+    prelude: CssComponentValueList,
+) -> CssAtGenericBuilder {
+    CssAtGenericBuilder {
+        at_token,
+        name,
+        prelude,
+        block: None,
+        semicolon_token: None,
+    }
+}
+pub struct CssAtGenericBuilder {
+    at_token: SyntaxToken,
+    name: CssIdentifier,
+    prelude: CssComponentValueList,
+    block: Option<CssBlock>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl CssAtGenericBuilder {
+    pub fn with_block(mut self, block: CssBlock) -> Self {
+        self.block = Some(block);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> CssAtGeneric {
+        CssAtGeneric::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_AT_GENERIC,
+            [
+                Some(SyntaxElement::Token(self.at_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Node(self.prelude.into_syntax())),
+                self.block
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn css_at_import(
+    at_token: SyntaxToken,
+    import_token: SyntaxToken,
+    url: AnyCssImportUrl,
+    media: CssAtMediaQueryList,
+    semicolon_token: SyntaxToken,
+) -> CssAtImport {
+    CssAtImport::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_AT_IMPORT,
+        [
+            Some(SyntaxElement::Token(at_token)),
+            Some(SyntaxElement::Token(import_token)),
+            Some(SyntaxElement::Node(url.into_syntax())),
+            Some(SyntaxElement::Node(media.into_syntax())),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
     ))
 }
 pub fn css_at_keyframes(
     at_token: SyntaxToken,
     keyframes_token: SyntaxToken,
-    name: CssIdentifier,
-    css_string: CssString,
+    name: AnyCssKeyframesName,
     body: CssAtKeyframesBody,
 ) -> CssAtKeyframes {
     CssAtKeyframes::unwrap_cast(SyntaxNode::new_detached(
@@ -26,7 +97,6 @@ pub fn css_at_keyframes(
             Some(SyntaxElement::Token(at_token)),
             Some(SyntaxElement::Token(keyframes_token)),
             Some(SyntaxElement::Node(name.into_syntax())),
-            Some(SyntaxElement::Node(css_string.into_syntax())),
             Some(SyntaxElement::Node(body.into_syntax())),
         ],
     ))
@@ -49,9 +119,7 @@ pub fn css_at_media(
     at_token: SyntaxToken,
     media_token: SyntaxToken,
     query_list: CssAtMediaQueryList,
-    l_curly_token: SyntaxToken,
-    body: AnyCssRule,
-    r_curly_token: SyntaxToken,
+    block: CssBlock,
 ) -> CssAtMedia {
     CssAtMedia::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_AT_MEDIA,
@@ -59,67 +127,54 @@ pub fn css_at_media(
             Some(SyntaxElement::Token(at_token)),
             Some(SyntaxElement::Token(media_token)),
             Some(SyntaxElement::Node(query_list.into_syntax())),
-            Some(SyntaxElement::Token(l_curly_token)),
-            Some(SyntaxElement::Node(body.into_syntax())),
-            Some(SyntaxElement::Token(r_curly_token)),
+            Some(SyntaxElement::Node(block.into_syntax())),
         ],
     ))
 }
 pub fn css_at_media_query(
-    condition_token: SyntaxToken,
-    or_token: SyntaxToken,
     ty: AnyCssAtMediaQueryType,
+    consequent_list: CssAtMediaQueryConsequentList,
 ) -> CssAtMediaQueryBuilder {
     CssAtMediaQueryBuilder {
-        condition_token,
-        or_token,
         ty,
-        only_token: None,
-        consequent: None,
+        consequent_list,
+        condition_token: None,
     }
 }
 pub struct CssAtMediaQueryBuilder {
-    condition_token: SyntaxToken,
-    or_token: SyntaxToken,
     ty: AnyCssAtMediaQueryType,
-    only_token: Option<SyntaxToken>,
-    consequent: Option<CssAtMediaQueryConsequent>,
+    consequent_list: CssAtMediaQueryConsequentList,
+    condition_token: Option<SyntaxToken>,
 }
 impl CssAtMediaQueryBuilder {
-    pub fn with_only_token(mut self, only_token: SyntaxToken) -> Self {
-        self.only_token = Some(only_token);
-        self
-    }
-    pub fn with_consequent(mut self, consequent: CssAtMediaQueryConsequent) -> Self {
-        self.consequent = Some(consequent);
+    pub fn with_condition_token(mut self, condition_token: SyntaxToken) -> Self {
+        self.condition_token = Some(condition_token);
         self
     }
     pub fn build(self) -> CssAtMediaQuery {
         CssAtMediaQuery::unwrap_cast(SyntaxNode::new_detached(
             CssSyntaxKind::CSS_AT_MEDIA_QUERY,
             [
-                Some(SyntaxElement::Token(self.condition_token)),
-                Some(SyntaxElement::Token(self.or_token)),
-                self.only_token.map(|token| SyntaxElement::Token(token)),
+                self.condition_token
+                    .map(|token| SyntaxElement::Token(token)),
                 Some(SyntaxElement::Node(self.ty.into_syntax())),
-                self.consequent
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.consequent_list.into_syntax())),
             ],
         ))
     }
 }
 pub fn css_at_media_query_consequent(
-    and_token: SyntaxToken,
+    operator_token: SyntaxToken,
     ty: AnyCssAtMediaQueryType,
 ) -> CssAtMediaQueryConsequentBuilder {
     CssAtMediaQueryConsequentBuilder {
-        and_token,
+        operator_token,
         ty,
         condition_token: None,
     }
 }
 pub struct CssAtMediaQueryConsequentBuilder {
-    and_token: SyntaxToken,
+    operator_token: SyntaxToken,
     ty: AnyCssAtMediaQueryType,
     condition_token: Option<SyntaxToken>,
 }
@@ -132,7 +187,7 @@ impl CssAtMediaQueryConsequentBuilder {
         CssAtMediaQueryConsequent::unwrap_cast(SyntaxNode::new_detached(
             CssSyntaxKind::CSS_AT_MEDIA_QUERY_CONSEQUENT,
             [
-                Some(SyntaxElement::Token(self.and_token)),
+                Some(SyntaxElement::Token(self.operator_token)),
                 self.condition_token
                     .map(|token| SyntaxElement::Token(token)),
                 Some(SyntaxElement::Node(self.ty.into_syntax())),
@@ -154,12 +209,10 @@ pub fn css_at_media_query_feature(
         ],
     ))
 }
-pub fn css_at_media_query_feature_boolean(
-    css_identifier: CssIdentifier,
-) -> CssAtMediaQueryFeatureBoolean {
+pub fn css_at_media_query_feature_boolean(name: CssIdentifier) -> CssAtMediaQueryFeatureBoolean {
     CssAtMediaQueryFeatureBoolean::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_AT_MEDIA_QUERY_FEATURE_BOOLEAN,
-        [Some(SyntaxElement::Node(css_identifier.into_syntax()))],
+        [Some(SyntaxElement::Node(name.into_syntax()))],
     ))
 }
 pub fn css_at_media_query_feature_compare(
@@ -194,8 +247,8 @@ pub fn css_at_media_query_feature_range(
     first_value: AnyCssValue,
     first_range: CssAtMediaQueryRange,
     name: CssIdentifier,
-    second_value: AnyCssValue,
     second_range: CssAtMediaQueryRange,
+    second_value: AnyCssValue,
 ) -> CssAtMediaQueryFeatureRange {
     CssAtMediaQueryFeatureRange::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_AT_MEDIA_QUERY_FEATURE_RANGE,
@@ -203,156 +256,127 @@ pub fn css_at_media_query_feature_range(
             Some(SyntaxElement::Node(first_value.into_syntax())),
             Some(SyntaxElement::Node(first_range.into_syntax())),
             Some(SyntaxElement::Node(name.into_syntax())),
-            Some(SyntaxElement::Node(second_value.into_syntax())),
             Some(SyntaxElement::Node(second_range.into_syntax())),
+            Some(SyntaxElement::Node(second_value.into_syntax())),
         ],
     ))
 }
-pub fn css_at_media_query_range(
-    r_angle_token: SyntaxToken,
-    l_angle_token: SyntaxToken,
-    greater_than_equal_token: SyntaxToken,
-    less_than_equal_token: SyntaxToken,
-) -> CssAtMediaQueryRange {
-    CssAtMediaQueryRange::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_AT_MEDIA_QUERY_RANGE,
+pub fn css_at_media_query_feature_reverse_compare(
+    value: AnyCssValue,
+    range: CssAtMediaQueryRange,
+    name: CssIdentifier,
+) -> CssAtMediaQueryFeatureReverseCompare {
+    CssAtMediaQueryFeatureReverseCompare::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_AT_MEDIA_QUERY_FEATURE_REVERSE_COMPARE,
         [
-            Some(SyntaxElement::Token(r_angle_token)),
-            Some(SyntaxElement::Token(l_angle_token)),
-            Some(SyntaxElement::Token(greater_than_equal_token)),
-            Some(SyntaxElement::Token(less_than_equal_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Node(range.into_syntax())),
+            Some(SyntaxElement::Node(name.into_syntax())),
         ],
     ))
 }
-pub fn css_attribute(
-    l_brack_token: SyntaxToken,
-    attribute_name: CssAttributeName,
-    r_brack_token: SyntaxToken,
-) -> CssAttributeBuilder {
-    CssAttributeBuilder {
-        l_brack_token,
-        attribute_name,
-        r_brack_token,
-        attribute_meta: None,
+pub fn css_at_media_query_range(operator_token: SyntaxToken) -> CssAtMediaQueryRange {
+    CssAtMediaQueryRange::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_AT_MEDIA_QUERY_RANGE,
+        [Some(SyntaxElement::Token(operator_token))],
+    ))
+}
+pub fn css_attribute_matcher(
+    operator_token: SyntaxToken,
+    value: AnyCssAttributeMatcherValue,
+) -> CssAttributeMatcherBuilder {
+    CssAttributeMatcherBuilder {
+        operator_token,
+        value,
+        modifier: None,
     }
 }
-pub struct CssAttributeBuilder {
-    l_brack_token: SyntaxToken,
-    attribute_name: CssAttributeName,
-    r_brack_token: SyntaxToken,
-    attribute_meta: Option<CssAttributeMeta>,
+pub struct CssAttributeMatcherBuilder {
+    operator_token: SyntaxToken,
+    value: AnyCssAttributeMatcherValue,
+    modifier: Option<CssIdentifier>,
 }
-impl CssAttributeBuilder {
-    pub fn with_attribute_meta(mut self, attribute_meta: CssAttributeMeta) -> Self {
-        self.attribute_meta = Some(attribute_meta);
+impl CssAttributeMatcherBuilder {
+    pub fn with_modifier(mut self, modifier: CssIdentifier) -> Self {
+        self.modifier = Some(modifier);
         self
     }
-    pub fn build(self) -> CssAttribute {
-        CssAttribute::unwrap_cast(SyntaxNode::new_detached(
-            CssSyntaxKind::CSS_ATTRIBUTE,
+    pub fn build(self) -> CssAttributeMatcher {
+        CssAttributeMatcher::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_ATTRIBUTE_MATCHER,
             [
-                Some(SyntaxElement::Token(self.l_brack_token)),
-                Some(SyntaxElement::Node(self.attribute_name.into_syntax())),
-                self.attribute_meta
+                Some(SyntaxElement::Token(self.operator_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.modifier
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
-                Some(SyntaxElement::Token(self.r_brack_token)),
             ],
         ))
     }
 }
-pub fn css_attribute_matcher(
-    matcher_type_token: SyntaxToken,
-    exactly_or_hyphen_token: SyntaxToken,
-    prefix_token: SyntaxToken,
-    suffix_token: SyntaxToken,
-    times_assign_token: SyntaxToken,
-    eq_token: SyntaxToken,
-    matcher_name: CssString,
-    css_identifier: CssIdentifier,
-) -> CssAttributeMatcher {
-    CssAttributeMatcher::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ATTRIBUTE_MATCHER,
-        [
-            Some(SyntaxElement::Token(matcher_type_token)),
-            Some(SyntaxElement::Token(exactly_or_hyphen_token)),
-            Some(SyntaxElement::Token(prefix_token)),
-            Some(SyntaxElement::Token(suffix_token)),
-            Some(SyntaxElement::Token(times_assign_token)),
-            Some(SyntaxElement::Token(eq_token)),
-            Some(SyntaxElement::Node(matcher_name.into_syntax())),
-            Some(SyntaxElement::Node(css_identifier.into_syntax())),
-        ],
-    ))
-}
-pub fn css_attribute_meta() -> CssAttributeMetaBuilder {
-    CssAttributeMetaBuilder {
-        attribute_matcher: None,
-        attribute_modifier: None,
+pub fn css_attribute_selector_pattern(
+    l_brack_token: SyntaxToken,
+    name: CssIdentifier,
+    r_brack_token: SyntaxToken,
+) -> CssAttributeSelectorPatternBuilder {
+    CssAttributeSelectorPatternBuilder {
+        l_brack_token,
+        name,
+        r_brack_token,
+        matcher: None,
     }
 }
-pub struct CssAttributeMetaBuilder {
-    attribute_matcher: Option<CssAttributeMatcher>,
-    attribute_modifier: Option<CssAttributeModifier>,
+pub struct CssAttributeSelectorPatternBuilder {
+    l_brack_token: SyntaxToken,
+    name: CssIdentifier,
+    r_brack_token: SyntaxToken,
+    matcher: Option<CssAttributeMatcher>,
 }
-impl CssAttributeMetaBuilder {
-    pub fn with_attribute_matcher(mut self, attribute_matcher: CssAttributeMatcher) -> Self {
-        self.attribute_matcher = Some(attribute_matcher);
+impl CssAttributeSelectorPatternBuilder {
+    pub fn with_matcher(mut self, matcher: CssAttributeMatcher) -> Self {
+        self.matcher = Some(matcher);
         self
     }
-    pub fn with_attribute_modifier(mut self, attribute_modifier: CssAttributeModifier) -> Self {
-        self.attribute_modifier = Some(attribute_modifier);
-        self
-    }
-    pub fn build(self) -> CssAttributeMeta {
-        CssAttributeMeta::unwrap_cast(SyntaxNode::new_detached(
-            CssSyntaxKind::CSS_ATTRIBUTE_META,
+    pub fn build(self) -> CssAttributeSelectorPattern {
+        CssAttributeSelectorPattern::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_ATTRIBUTE_SELECTOR_PATTERN,
             [
-                self.attribute_matcher
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
-                self.attribute_modifier
+                Some(SyntaxElement::Token(self.l_brack_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.matcher
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_brack_token)),
             ],
         ))
     }
 }
-pub fn css_attribute_modifier(i_token: SyntaxToken) -> CssAttributeModifier {
-    CssAttributeModifier::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ATTRIBUTE_MODIFIER,
-        [Some(SyntaxElement::Token(i_token))],
-    ))
-}
-pub fn css_attribute_name(css_string: CssString) -> CssAttributeName {
-    CssAttributeName::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ATTRIBUTE_NAME,
-        [Some(SyntaxElement::Node(css_string.into_syntax()))],
-    ))
-}
-pub fn css_attribute_selector_pattern(
-    name: CssIdentifier,
-    attribute_list: CssAttributeList,
-) -> CssAttributeSelectorPattern {
-    CssAttributeSelectorPattern::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ATTRIBUTE_SELECTOR_PATTERN,
-        [
-            Some(SyntaxElement::Node(name.into_syntax())),
-            Some(SyntaxElement::Node(attribute_list.into_syntax())),
-        ],
-    ))
-}
 pub fn css_block(
     l_curly_token: SyntaxToken,
-    declaration_list: CssDeclarationList,
+    items: CssBlockItemList,
     r_curly_token: SyntaxToken,
 ) -> CssBlock {
     CssBlock::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_BLOCK,
         [
             Some(SyntaxElement::Token(l_curly_token)),
-            Some(SyntaxElement::Node(declaration_list.into_syntax())),
+            Some(SyntaxElement::Node(items.into_syntax())),
             Some(SyntaxElement::Token(r_curly_token)),
         ],
     ))
 }
+pub fn css_bracketed_value(
+    l_brack_token: SyntaxToken,
+    values: CssComponentValueList,
+    r_brack_token: SyntaxToken,
+) -> CssBracketedValue {
+    CssBracketedValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_BRACKETED_VALUE,
+        [
+            Some(SyntaxElement::Token(l_brack_token)),
+            Some(SyntaxElement::Node(values.into_syntax())),
+            Some(SyntaxElement::Token(r_brack_token)),
+        ],
+    ))
+}
 pub fn css_class_selector_pattern(
     dot_token: SyntaxToken,
     name: CssIdentifier,
@@ -365,26 +389,44 @@ pub fn css_class_selector_pattern(
         ],
     ))
 }
-pub fn css_combinator_selector_pattern(
-    left: AnyCssSelectorPattern,
-    combinator_token: SyntaxToken,
-    plus_token: SyntaxToken,
-    bitwise_not_token: SyntaxToken,
-    css_space_literal_token: SyntaxToken,
-    right: AnyCssSelectorPattern,
-) -> CssCombinatorSelectorPattern {
-    CssCombinatorSelectorPattern::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_COMBINATOR_SELECTOR_PATTERN,
+pub fn css_color(hash_token: SyntaxToken, value_token: SyntaxToken) -> CssColor {
+    CssColor::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COLOR,
         [
-            Some(SyntaxElement::Node(left.into_syntax())),
-            Some(SyntaxElement::Token(combinator_token)),
-            Some(SyntaxElement::Token(plus_token)),
-            Some(SyntaxElement::Token(bitwise_not_token)),
-            Some(SyntaxElement::Token(css_space_literal_token)),
-            Some(SyntaxElement::Node(right.into_syntax())),
+            Some(SyntaxElement::Token(hash_token)),
+            Some(SyntaxElement::Token(value_token)),
         ],
     ))
 }
+pub fn css_complex_selector(left: AnyCssSelector, right: CssSelector) -> CssComplexSelectorBuilder {
+    CssComplexSelectorBuilder {
+        left,
+        right,
+        combinator_token: None,
+    }
+}
+pub struct CssComplexSelectorBuilder {
+    left: AnyCssSelector,
+    right: CssSelector,
+    combinator_token: Option<SyntaxToken>,
+}
+impl CssComplexSelectorBuilder {
+    pub fn with_combinator_token(mut self, combinator_token: SyntaxToken) -> Self {
+        self.combinator_token = Some(combinator_token);
+        self
+    }
+    pub fn build(self) -> CssComplexSelector {
+        CssComplexSelector::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_COMPLEX_SELECTOR,
+            [
+                Some(SyntaxElement::Node(self.left.into_syntax())),
+                self.combinator_token
+                    .map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.right.into_syntax())),
+            ],
+        ))
+    }
+}
 pub fn css_custom_property(value_token: SyntaxToken) -> CssCustomProperty {
     CssCustomProperty::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_CUSTOM_PROPERTY,
@@ -392,41 +434,45 @@ pub fn css_custom_property(value_token: SyntaxToken) -> CssCustomProperty {
     ))
 }
 pub fn css_declaration(
-    name: CssIdentifier,
-    css_custom_property: CssCustomProperty,
+    name: AnyCssDeclarationName,
     colon_token: SyntaxToken,
-    value: AnyCssValue,
+    value: CssComponentValueList,
 ) -> CssDeclarationBuilder {
     CssDeclarationBuilder {
         name,
-        css_custom_property,
         colon_token,
         value,
         important: None,
+        semicolon_token: None,
     }
 }
 pub struct CssDeclarationBuilder {
-    name: CssIdentifier,
-    css_custom_property: CssCustomProperty,
+    name: AnyCssDeclarationName,
     colon_token: SyntaxToken,
-    value: AnyCssValue,
+    value: CssComponentValueList,
     important: Option<CssDeclarationImportant>,
+    semicolon_token: Option<SyntaxToken>,
 }
 impl CssDeclarationBuilder {
     pub fn with_important(mut self, important: CssDeclarationImportant) -> Self {
         self.important = Some(important);
         self
     }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
     pub fn build(self) -> CssDeclaration {
         CssDeclaration::unwrap_cast(SyntaxNode::new_detached(
             CssSyntaxKind::CSS_DECLARATION,
             [
                 Some(SyntaxElement::Node(self.name.into_syntax())),
-                Some(SyntaxElement::Node(self.css_custom_property.into_syntax())),
                 Some(SyntaxElement::Token(self.colon_token)),
                 Some(SyntaxElement::Node(self.value.into_syntax())),
                 self.important
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
             ],
         ))
     }
@@ -452,6 +498,18 @@ pub fn css_dimension(value: CssNumber, unit: CssIdentifier) -> CssDimension {
         ],
     ))
 }
+pub fn css_empty_declaration(semicolon_token: SyntaxToken) -> CssEmptyDeclaration {
+    CssEmptyDeclaration::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_EMPTY_DECLARATION,
+        [Some(SyntaxElement::Token(semicolon_token))],
+    ))
+}
+pub fn css_generic_delimiter(value_token: SyntaxToken) -> CssGenericDelimiter {
+    CssGenericDelimiter::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_GENERIC_DELIMITER,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
 pub fn css_id_selector_pattern(
     hash_token: SyntaxToken,
     name: CssIdentifier,
@@ -472,32 +530,26 @@ pub fn css_identifier(value_token: SyntaxToken) -> CssIdentifier {
 }
 pub fn css_keyframes_block(
     selectors: CssKeyframesSelectorList,
-    l_curly_token: SyntaxToken,
-    declarations: CssDeclarationList,
-    r_curly_token: SyntaxToken,
+    block: CssBlock,
 ) -> CssKeyframesBlock {
     CssKeyframesBlock::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_KEYFRAMES_BLOCK,
         [
             Some(SyntaxElement::Node(selectors.into_syntax())),
-            Some(SyntaxElement::Token(l_curly_token)),
-            Some(SyntaxElement::Node(declarations.into_syntax())),
-            Some(SyntaxElement::Token(r_curly_token)),
+            Some(SyntaxElement::Node(block.into_syntax())),
         ],
     ))
 }
-pub fn css_keyframes_selector(
-    from_token: SyntaxToken,
-    to_token: SyntaxToken,
-    css_percentage: CssPercentage,
-) -> CssKeyframesSelector {
+pub fn css_keyframes_selector(selector_token: SyntaxToken) -> CssKeyframesSelector {
     CssKeyframesSelector::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_KEYFRAMES_SELECTOR,
-        [
-            Some(SyntaxElement::Token(from_token)),
-            Some(SyntaxElement::Token(to_token)),
-            Some(SyntaxElement::Node(css_percentage.into_syntax())),
-        ],
+        [Some(SyntaxElement::Token(selector_token))],
+    ))
+}
+pub fn css_nesting_selector_pattern(amp_token: SyntaxToken) -> CssNestingSelectorPattern {
+    CssNestingSelectorPattern::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_NESTING_SELECTOR_PATTERN,
+        [Some(SyntaxElement::Token(amp_token))],
     ))
 }
 pub fn css_number(value_token: SyntaxToken) -> CssNumber {
@@ -506,10 +558,24 @@ pub fn css_number(value_token: SyntaxToken) -> CssNumber {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn css_parameter(any_css_value: AnyCssValue) -> CssParameter {
+pub fn css_parameter(values: CssComponentValueList) -> CssParameter {
     CssParameter::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_PARAMETER,
-        [Some(SyntaxElement::Node(any_css_value.into_syntax()))],
+        [Some(SyntaxElement::Node(values.into_syntax()))],
+    ))
+}
+pub fn css_parenthesized_value(
+    l_paren_token: SyntaxToken,
+    values: CssComponentValueList,
+    r_paren_token: SyntaxToken,
+) -> CssParenthesizedValue {
+    CssParenthesizedValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_PARENTHESIZED_VALUE,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(values.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
     ))
 }
 pub fn css_percentage(value: CssNumber, reminder_token: SyntaxToken) -> CssPercentage {
@@ -534,10 +600,10 @@ pub fn css_pseudo_class_selector_pattern(
 pub struct CssPseudoClassSelectorPatternBuilder {
     colon_token: SyntaxToken,
     name: CssIdentifier,
-    parameters: Option<CssPseudoClassSelectorPatternParameters>,
+    parameters: Option<AnyCssPseudoParameters>,
 }
 impl CssPseudoClassSelectorPatternBuilder {
-    pub fn with_parameters(mut self, parameters: CssPseudoClassSelectorPatternParameters) -> Self {
+    pub fn with_parameters(mut self, parameters: AnyCssPseudoParameters) -> Self {
         self.parameters = Some(parameters);
         self
     }
@@ -553,29 +619,92 @@ impl CssPseudoClassSelectorPatternBuilder {
         ))
     }
 }
-pub fn css_pseudo_class_selector_pattern_parameters(
+pub fn css_pseudo_element_selector_pattern(
+    double_colon_token: SyntaxToken,
+    name: CssIdentifier,
+) -> CssPseudoElementSelectorPatternBuilder {
+    CssPseudoElementSelectorPatternBuilder {
+        double_colon_token,
+        name,
+        parameters: None,
+    }
+}
+pub struct CssPseudoElementSelectorPatternBuilder {
+    double_colon_token: SyntaxToken,
+    name: CssIdentifier,
+    parameters: Option<AnyCssPseudoParameters>,
+}
+impl CssPseudoElementSelectorPatternBuilder {
+    pub fn with_parameters(mut self, parameters: AnyCssPseudoParameters) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+    pub fn build(self) -> CssPseudoElementSelectorPattern {
+        CssPseudoElementSelectorPattern::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN,
+            [
+                Some(SyntaxElement::Token(self.double_colon_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.parameters
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn css_pseudo_selector_parameters(
+    l_paren_token: SyntaxToken,
+    selectors: CssSelectorList,
+    r_paren_token: SyntaxToken,
+) -> CssPseudoSelectorParameters {
+    CssPseudoSelectorParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_PSEUDO_SELECTOR_PARAMETERS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(selectors.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn css_pseudo_value_parameters(
     l_paren_token: SyntaxToken,
-    parameter: AnyCssValue,
+    values: CssComponentValueList,
     r_paren_token: SyntaxToken,
-) -> CssPseudoClassSelectorPatternParameters {
-    CssPseudoClassSelectorPatternParameters::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS,
+) -> CssPseudoValueParameters {
+    CssPseudoValueParameters::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_PSEUDO_VALUE_PARAMETERS,
         [
             Some(SyntaxElement::Token(l_paren_token)),
-            Some(SyntaxElement::Node(parameter.into_syntax())),
+            Some(SyntaxElement::Node(values.into_syntax())),
             Some(SyntaxElement::Token(r_paren_token)),
         ],
     ))
 }
-pub fn css_ratio(numerator: CssNumber, denominator: CssNumber) -> CssRatio {
+pub fn css_ratio(
+    numerator: CssNumber,
+    slash_token: SyntaxToken,
+    denominator: CssNumber,
+) -> CssRatio {
     CssRatio::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_RATIO,
         [
             Some(SyntaxElement::Node(numerator.into_syntax())),
+            Some(SyntaxElement::Token(slash_token)),
             Some(SyntaxElement::Node(denominator.into_syntax())),
         ],
     ))
 }
+pub fn css_relative_selector(
+    combinator_token: SyntaxToken,
+    selector: AnyCssSelector,
+) -> CssRelativeSelector {
+    CssRelativeSelector::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_RELATIVE_SELECTOR,
+        [
+            Some(SyntaxElement::Token(combinator_token)),
+            Some(SyntaxElement::Node(selector.into_syntax())),
+        ],
+    ))
+}
 pub fn css_root(rules: CssRuleList, eof_token: SyntaxToken) -> CssRoot {
     CssRoot::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_ROOT,
@@ -634,6 +763,48 @@ pub fn css_universal_selector_pattern(star_token: SyntaxToken) -> CssUniversalSe
         [Some(SyntaxElement::Token(star_token))],
     ))
 }
+pub fn css_url_function(
+    url_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    r_paren_token: SyntaxToken,
+) -> CssUrlFunctionBuilder {
+    CssUrlFunctionBuilder {
+        url_token,
+        l_paren_token,
+        r_paren_token,
+        value: None,
+    }
+}
+pub struct CssUrlFunctionBuilder {
+    url_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    r_paren_token: SyntaxToken,
+    value: Option<AnyCssUrlValue>,
+}
+impl CssUrlFunctionBuilder {
+    pub fn with_value(mut self, value: AnyCssUrlValue) -> Self {
+        self.value = Some(value);
+        self
+    }
+    pub fn build(self) -> CssUrlFunction {
+        CssUrlFunction::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::CSS_URL_FUNCTION,
+            [
+                Some(SyntaxElement::Token(self.url_token)),
+                Some(SyntaxElement::Token(self.l_paren_token)),
+                self.value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_paren_token)),
+            ],
+        ))
+    }
+}
+pub fn css_url_value_raw(value_token: SyntaxToken) -> CssUrlValueRaw {
+    CssUrlValueRaw::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_URL_VALUE_RAW,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
 pub fn css_var_function(
     var_token: SyntaxToken,
     l_paren_token: SyntaxToken,
@@ -676,7 +847,7 @@ impl CssVarFunctionBuilder {
 }
 pub fn css_var_function_value(
     comma_token: SyntaxToken,
-    value: CssIdentifier,
+    value: CssComponentValueList,
 ) -> CssVarFunctionValue {
     CssVarFunctionValue::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_VAR_FUNCTION_VALUE,
@@ -710,6 +881,18 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn css_at_media_query_consequent_list<I>(items: I) -> CssAtMediaQueryConsequentList
+where
+    I: IntoIterator<Item = CssAtMediaQueryConsequent>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssAtMediaQueryConsequentList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn css_at_media_query_list<I, S>(items: I, separators: S) -> CssAtMediaQueryList
 where
     I: IntoIterator<Item = CssAtMediaQuery>,
@@ -731,25 +914,25 @@ where
         }),
     ))
 }
-pub fn css_attribute_list<I>(items: I) -> CssAttributeList
+pub fn css_block_item_list<I>(items: I) -> CssBlockItemList
 where
-    I: IntoIterator<Item = CssAttribute>,
+    I: IntoIterator<Item = AnyCssBlockItem>,
     I::IntoIter: ExactSizeIterator,
 {
-    CssAttributeList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_ATTRIBUTE_LIST,
+    CssBlockItemList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_BLOCK_ITEM_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn css_declaration_list<I>(items: I) -> CssDeclarationList
+pub fn css_component_value_list<I>(items: I) -> CssComponentValueList
 where
-    I: IntoIterator<Item = CssDeclaration>,
+    I: IntoIterator<Item = AnyCssValue>,
     I::IntoIter: ExactSizeIterator,
 {
-    CssDeclarationList::unwrap_cast(SyntaxNode::new_detached(
-        CssSyntaxKind::CSS_DECLARATION_LIST,
+    CssComponentValueList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_COMPONENT_VALUE_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
@@ -757,7 +940,7 @@ where
 }
 pub fn css_keyframes_selector_list<I, S>(items: I, separators: S) -> CssKeyframesSelectorList
 where
-    I: IntoIterator<Item = CssKeyframesSelector>,
+    I: IntoIterator<Item = AnyCssKeyframesSelector>,
     I::IntoIter: ExactSizeIterator,
     S: IntoIterator<Item = CssSyntaxToken>,
     S::IntoIter: ExactSizeIterator,
@@ -776,16 +959,25 @@ where
         }),
     ))
 }
-pub fn css_parameter_list<I>(items: I) -> CssParameterList
+pub fn css_parameter_list<I, S>(items: I, separators: S) -> CssParameterList
 where
     I: IntoIterator<Item = CssParameter>,
     I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = CssSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
 {
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
     CssParameterList::unwrap_cast(SyntaxNode::new_detached(
         CssSyntaxKind::CSS_PARAMETER_LIST,
-        items
-            .into_iter()
-            .map(|item| Some(item.into_syntax().into())),
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
     ))
 }
 pub fn css_rule_list<I>(items: I) -> CssRuleList
@@ -802,7 +994,7 @@ where
 }
 pub fn css_selector_list<I, S>(items: I, separators: S) -> CssSelectorList
 where
-    I: IntoIterator<Item = CssSelector>,
+    I: IntoIterator<Item = AnyCssSelector>,
     I::IntoIter: ExactSizeIterator,
     S: IntoIterator<Item = CssSyntaxToken>,
     S::IntoIter: ExactSizeIterator,
@@ -828,3 +1020,33 @@ where
 {
     CssBogus::unwrap_cast(SyntaxNode::new_detached(CssSyntaxKind::CSS_BOGUS, slots))
 }
+pub fn css_bogus_rule<I>(slots: I) -> CssBogusRule
+where
+    I: IntoIterator<Item = Option<SyntaxElement>>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssBogusRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_BOGUS_RULE,
+        slots,
+    ))
+}
+pub fn css_bogus_selector<I>(slots: I) -> CssBogusSelector
+where
+    I: IntoIterator<Item = Option<SyntaxElement>>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssBogusSelector::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_BOGUS_SELECTOR,
+        slots,
+    ))
+}
+pub fn css_bogus_value<I>(slots: I) -> CssBogusValue
+where
+    I: IntoIterator<Item = Option<SyntaxElement>>,
+    I::IntoIter: ExactSizeIterator,
+{
+    CssBogusValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::CSS_BOGUS_VALUE,
+        slots,
+    ))
+}
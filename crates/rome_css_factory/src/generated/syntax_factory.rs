@@ -12,13 +12,36 @@ impl SyntaxFactory for CssSyntaxFactory {
         children: ParsedChildren<Self::Kind>,
     ) -> RawSyntaxNode<Self::Kind> {
         match kind {
-            CSS_BOGUS => RawSyntaxNode::new(kind, children.into_iter().map(Some)),
-            CSS_ANY_FUNCTION => {
+            CSS_BOGUS | CSS_BOGUS_RULE | CSS_BOGUS_SELECTOR | CSS_BOGUS_VALUE => {
+                RawSyntaxNode::new(kind, children.into_iter().map(Some))
+            }
+            CSS_AT_CHARSET => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssSimpleFunction::can_cast(element.kind()) {
+                    if element.kind() == T ! [@] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![charset] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssString::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [;] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -26,13 +49,13 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_ANY_FUNCTION.to_bogus(),
+                        CSS_AT_CHARSET.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_ANY_FUNCTION, children)
+                slots.into_node(CSS_AT_CHARSET, children)
             }
-            CSS_AT_KEYFRAMES => {
+            CSS_AT_GENERIC => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<5usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
@@ -44,28 +67,28 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![keyframes] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if CssComponentValueList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssString::can_cast(element.kind()) {
+                    if CssBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssAtKeyframesBody::can_cast(element.kind()) {
+                    if element.kind() == T ! [;] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -73,32 +96,46 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_AT_KEYFRAMES.to_bogus(),
+                        CSS_AT_GENERIC.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_AT_KEYFRAMES, children)
+                slots.into_node(CSS_AT_GENERIC, children)
             }
-            CSS_AT_KEYFRAMES_BODY => {
+            CSS_AT_IMPORT => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<5usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['{'] {
+                    if element.kind() == T ! [@] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssAtKeyframesItemList::can_cast(element.kind()) {
+                    if element.kind() == T![import] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['}'] {
+                    if AnyCssImportUrl::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssAtMediaQueryList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [;] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -106,15 +143,15 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_AT_KEYFRAMES_BODY.to_bogus(),
+                        CSS_AT_IMPORT.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_AT_KEYFRAMES_BODY, children)
+                slots.into_node(CSS_AT_IMPORT, children)
             }
-            CSS_AT_MEDIA => {
+            CSS_AT_KEYFRAMES => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if element.kind() == T ! [@] {
@@ -124,19 +161,38 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![media] {
+                    if element.kind() == T![keyframes] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssAtMediaQueryList::can_cast(element.kind()) {
+                    if AnyCssKeyframesName::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssAtKeyframesBody::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_AT_KEYFRAMES.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_AT_KEYFRAMES, children)
+            }
+            CSS_AT_KEYFRAMES_BODY => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if element.kind() == T!['{'] {
                         slots.mark_present();
@@ -145,7 +201,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssRule::can_cast(element.kind()) {
+                    if CssAtKeyframesItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -160,32 +216,58 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_AT_MEDIA.to_bogus(),
+                        CSS_AT_KEYFRAMES_BODY.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_AT_MEDIA, children)
+                slots.into_node(CSS_AT_KEYFRAMES_BODY, children)
             }
-            CSS_AT_MEDIA_QUERY => {
+            CSS_AT_MEDIA => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<5usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![not] {
+                    if element.kind() == T ! [@] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![or] {
+                    if element.kind() == T![media] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![only] {
+                    if CssAtMediaQueryList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssBlock::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_AT_MEDIA.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_AT_MEDIA, children)
+            }
+            CSS_AT_MEDIA_QUERY => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if matches!(element.kind(), T![not] | T![only]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -199,7 +281,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssAtMediaQueryConsequent::can_cast(element.kind()) {
+                    if CssAtMediaQueryConsequentList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -218,7 +300,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![and] {
+                    if matches!(element.kind(), T![and] | T![or]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -390,14 +472,14 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssValue::can_cast(element.kind()) {
+                    if CssAtMediaQueryRange::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssAtMediaQueryRange::can_cast(element.kind()) {
+                    if AnyCssValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -411,33 +493,26 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.into_node(CSS_AT_MEDIA_QUERY_FEATURE_RANGE, children)
             }
-            CSS_AT_MEDIA_QUERY_RANGE => {
+            CSS_AT_MEDIA_QUERY_FEATURE_REVERSE_COMPARE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [>] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T ! [<] {
+                    if AnyCssValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [>=] {
+                    if CssAtMediaQueryRange::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [<=] {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -445,39 +520,21 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_AT_MEDIA_QUERY_RANGE.to_bogus(),
+                        CSS_AT_MEDIA_QUERY_FEATURE_REVERSE_COMPARE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_AT_MEDIA_QUERY_RANGE, children)
+                slots.into_node(CSS_AT_MEDIA_QUERY_FEATURE_REVERSE_COMPARE, children)
             }
-            CSS_ATTRIBUTE => {
+            CSS_AT_MEDIA_QUERY_RANGE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['['] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if CssAttributeName::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if CssAttributeMeta::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T![']'] {
+                    if matches!(
+                        element.kind(),
+                        T ! [>] | T ! [<] | T ! [>=] | T ! [<=] | T ! [=]
+                    ) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -485,60 +542,28 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_ATTRIBUTE.to_bogus(),
+                        CSS_AT_MEDIA_QUERY_RANGE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_ATTRIBUTE, children)
+                slots.into_node(CSS_AT_MEDIA_QUERY_RANGE, children)
             }
             CSS_ATTRIBUTE_MATCHER => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<8usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [~=] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T ! [|=] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T ! [^=] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T!["$="] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T ! [*=] {
+                    if matches!(
+                        element.kind(),
+                        T ! [~=] | T ! [|=] | T ! [^=] | T!["$="] | T ! [*=] | T ! [=]
+                    ) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [=] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if CssString::can_cast(element.kind()) {
+                    if AnyCssAttributeMatcherValue::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -559,38 +584,33 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.into_node(CSS_ATTRIBUTE_MATCHER, children)
             }
-            CSS_ATTRIBUTE_META => {
+            CSS_ATTRIBUTE_SELECTOR_PATTERN => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssAttributeMatcher::can_cast(element.kind()) {
+                    if element.kind() == T!['['] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssAttributeModifier::can_cast(element.kind()) {
+                    if CssIdentifier::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_ATTRIBUTE_META.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
+                if let Some(element) = &current_element {
+                    if CssAttributeMatcher::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
                 }
-                slots.into_node(CSS_ATTRIBUTE_META, children)
-            }
-            CSS_ATTRIBUTE_MODIFIER => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
+                slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![i] {
+                    if element.kind() == T![']'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -598,44 +618,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_ATTRIBUTE_MODIFIER.to_bogus(),
+                        CSS_ATTRIBUTE_SELECTOR_PATTERN.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_ATTRIBUTE_MODIFIER, children)
+                slots.into_node(CSS_ATTRIBUTE_SELECTOR_PATTERN, children)
             }
-            CSS_ATTRIBUTE_NAME => {
+            CSS_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssString::can_cast(element.kind()) {
+                    if element.kind() == T!['{'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        CSS_ATTRIBUTE_NAME.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
-                }
-                slots.into_node(CSS_ATTRIBUTE_NAME, children)
-            }
-            CSS_ATTRIBUTE_SELECTOR_PATTERN => {
-                let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
-                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if CssBlockItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssAttributeList::can_cast(element.kind()) {
+                    if element.kind() == T!['}'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -643,32 +651,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_ATTRIBUTE_SELECTOR_PATTERN.to_bogus(),
+                        CSS_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_ATTRIBUTE_SELECTOR_PATTERN, children)
+                slots.into_node(CSS_BLOCK, children)
             }
-            CSS_BLOCK => {
+            CSS_BRACKETED_VALUE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['{'] {
+                    if element.kind() == T!['['] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssDeclarationList::can_cast(element.kind()) {
+                    if CssComponentValueList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['}'] {
+                    if element.kind() == T![']'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -676,11 +684,11 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_BLOCK.to_bogus(),
+                        CSS_BRACKETED_VALUE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_BLOCK, children)
+                slots.into_node(CSS_BRACKETED_VALUE, children)
             }
             CSS_CLASS_SELECTOR_PATTERN => {
                 let mut elements = (&children).into_iter();
@@ -708,47 +716,52 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.into_node(CSS_CLASS_SELECTOR_PATTERN, children)
             }
-            CSS_COMBINATOR_SELECTOR_PATTERN => {
+            CSS_COLOR => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssSelectorPattern::can_cast(element.kind()) {
+                    if element.kind() == T ! [#] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [>] {
+                    if element.kind() == CSS_COLOR_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T ! [+] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_COLOR.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
                 }
-                slots.next_slot();
+                slots.into_node(CSS_COLOR, children)
+            }
+            CSS_COMPLEX_SELECTOR => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [~] {
+                    if AnyCssSelector::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == CSS_SPACE_LITERAL {
+                    if matches!(element.kind(), T ! [>] | T ! [+] | T ! [~]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssSelectorPattern::can_cast(element.kind()) {
+                    if CssSelector::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -756,18 +769,18 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_COMBINATOR_SELECTOR_PATTERN.to_bogus(),
+                        CSS_COMPLEX_SELECTOR.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_COMBINATOR_SELECTOR_PATTERN, children)
+                slots.into_node(CSS_COMPLEX_SELECTOR, children)
             }
             CSS_CUSTOM_PROPERTY => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == CSS_CUSTOM_PROPERTY {
+                    if element.kind() == CSS_CUSTOM_PROPERTY_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -786,35 +799,35 @@ impl SyntaxFactory for CssSyntaxFactory {
                 let mut slots: RawNodeSlots<5usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if AnyCssDeclarationName::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssCustomProperty::can_cast(element.kind()) {
+                    if element.kind() == T ! [:] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T ! [:] {
+                    if CssComponentValueList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssValue::can_cast(element.kind()) {
+                    if CssDeclarationImportant::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssDeclarationImportant::can_cast(element.kind()) {
+                    if element.kind() == T ! [;] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -880,6 +893,57 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.into_node(CSS_DIMENSION, children)
             }
+            CSS_EMPTY_DECLARATION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [;] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_EMPTY_DECLARATION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_EMPTY_DECLARATION, children)
+            }
+            CSS_GENERIC_DELIMITER => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if matches!(
+                        element.kind(),
+                        T ! [,]
+                            | T ! [/]
+                            | T ! [:]
+                            | T ! [=]
+                            | T ! [+]
+                            | T ! [-]
+                            | T ! [*]
+                            | T ! [>]
+                            | T ! [<]
+                            | T ! [>=]
+                            | T ! [<=]
+                    ) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_GENERIC_DELIMITER.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_GENERIC_DELIMITER, children)
+            }
             CSS_ID_SELECTOR_PATTERN => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
@@ -927,7 +991,7 @@ impl SyntaxFactory for CssSyntaxFactory {
             }
             CSS_KEYFRAMES_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if CssKeyframesSelectorList::can_cast(element.kind()) {
@@ -937,21 +1001,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['{'] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if CssDeclarationList::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T!['}'] {
+                    if CssBlock::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -967,24 +1017,29 @@ impl SyntaxFactory for CssSyntaxFactory {
             }
             CSS_KEYFRAMES_SELECTOR => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![from] {
+                    if matches!(element.kind(), T![from] | T![to]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == T![to] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_KEYFRAMES_SELECTOR.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
                 }
-                slots.next_slot();
+                slots.into_node(CSS_KEYFRAMES_SELECTOR, children)
+            }
+            CSS_NESTING_SELECTOR_PATTERN => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if CssPercentage::can_cast(element.kind()) {
+                    if element.kind() == T ! [&] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -992,11 +1047,11 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_KEYFRAMES_SELECTOR.to_bogus(),
+                        CSS_NESTING_SELECTOR_PATTERN.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_KEYFRAMES_SELECTOR, children)
+                slots.into_node(CSS_NESTING_SELECTOR_PATTERN, children)
             }
             CSS_NUMBER => {
                 let mut elements = (&children).into_iter();
@@ -1022,7 +1077,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyCssValue::can_cast(element.kind()) {
+                    if CssComponentValueList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1036,6 +1091,39 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.into_node(CSS_PARAMETER, children)
             }
+            CSS_PARENTHESIZED_VALUE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssComponentValueList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_PARENTHESIZED_VALUE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_PARENTHESIZED_VALUE, children)
+            }
             CSS_PERCENTAGE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
@@ -1081,7 +1169,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssPseudoClassSelectorPatternParameters::can_cast(element.kind()) {
+                    if AnyCssPseudoParameters::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1095,7 +1183,40 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.into_node(CSS_PSEUDO_CLASS_SELECTOR_PATTERN, children)
             }
-            CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS => {
+            CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [::] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssIdentifier::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssPseudoParameters::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN, children)
+            }
+            CSS_PSEUDO_SELECTOR_PARAMETERS => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
@@ -1107,7 +1228,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if AnyCssValue::can_cast(element.kind()) {
+                    if CssSelectorList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1122,15 +1243,48 @@ impl SyntaxFactory for CssSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS.to_bogus(),
+                        CSS_PSEUDO_SELECTOR_PARAMETERS.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS, children)
+                slots.into_node(CSS_PSEUDO_SELECTOR_PARAMETERS, children)
+            }
+            CSS_PSEUDO_VALUE_PARAMETERS => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if CssComponentValueList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_PSEUDO_VALUE_PARAMETERS.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_PSEUDO_VALUE_PARAMETERS, children)
             }
             CSS_RATIO => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if CssNumber::can_cast(element.kind()) {
@@ -1139,6 +1293,13 @@ impl SyntaxFactory for CssSyntaxFactory {
                     }
                 }
                 slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [/] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
                 if let Some(element) = &current_element {
                     if CssNumber::can_cast(element.kind()) {
                         slots.mark_present();
@@ -1154,6 +1315,32 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.into_node(CSS_RATIO, children)
             }
+            CSS_RELATIVE_SELECTOR => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if matches!(element.kind(), T ! [>] | T ! [+] | T ! [~]) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssSelector::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_RELATIVE_SELECTOR.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_RELATIVE_SELECTOR, children)
+            }
             CSS_ROOT => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
@@ -1316,6 +1503,65 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.into_node(CSS_UNIVERSAL_SELECTOR_PATTERN, children)
             }
+            CSS_URL_FUNCTION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![url] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if AnyCssUrlValue::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_URL_FUNCTION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_URL_FUNCTION, children)
+            }
+            CSS_URL_VALUE_RAW => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == CSS_URL_VALUE_RAW_LITERAL {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        CSS_URL_VALUE_RAW.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(CSS_URL_VALUE_RAW, children)
+            }
             CSS_VAR_FUNCTION => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<5usize> = RawNodeSlots::default();
@@ -1375,7 +1621,7 @@ impl SyntaxFactory for CssSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if CssIdentifier::can_cast(element.kind()) {
+                    if CssComponentValueList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -1395,6 +1641,9 @@ impl SyntaxFactory for CssSyntaxFactory {
             CSS_AT_KEYFRAMES_ITEM_LIST => {
                 Self::make_node_list_syntax(kind, children, CssKeyframesBlock::can_cast)
             }
+            CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST => {
+                Self::make_node_list_syntax(kind, children, CssAtMediaQueryConsequent::can_cast)
+            }
             CSS_AT_MEDIA_QUERY_LIST => Self::make_separated_list_syntax(
                 kind,
                 children,
@@ -1402,27 +1651,31 @@ impl SyntaxFactory for CssSyntaxFactory {
                 T ! [,],
                 false,
             ),
-            CSS_ATTRIBUTE_LIST => {
-                Self::make_node_list_syntax(kind, children, CssAttribute::can_cast)
+            CSS_BLOCK_ITEM_LIST => {
+                Self::make_node_list_syntax(kind, children, AnyCssBlockItem::can_cast)
             }
-            CSS_DECLARATION_LIST => {
-                Self::make_node_list_syntax(kind, children, CssDeclaration::can_cast)
+            CSS_COMPONENT_VALUE_LIST => {
+                Self::make_node_list_syntax(kind, children, AnyCssValue::can_cast)
             }
             CSS_KEYFRAMES_SELECTOR_LIST => Self::make_separated_list_syntax(
                 kind,
                 children,
-                CssKeyframesSelector::can_cast,
+                AnyCssKeyframesSelector::can_cast,
+                T ! [,],
+                false,
+            ),
+            CSS_PARAMETER_LIST => Self::make_separated_list_syntax(
+                kind,
+                children,
+                CssParameter::can_cast,
                 T ! [,],
                 false,
             ),
-            CSS_PARAMETER_LIST => {
-                Self::make_node_list_syntax(kind, children, CssParameter::can_cast)
-            }
             CSS_RULE_LIST => Self::make_node_list_syntax(kind, children, AnyCssRule::can_cast),
             CSS_SELECTOR_LIST => Self::make_separated_list_syntax(
                 kind,
                 children,
-                CssSelector::can_cast,
+                AnyCssSelector::can_cast,
                 T ! [,],
                 false,
             ),
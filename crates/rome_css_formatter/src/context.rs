@@ -0,0 +1,94 @@
+use crate::CssCommentStyle;
+use rome_formatter::prelude::*;
+use rome_formatter::{
+    CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineWidth, TransformSourceMap,
+};
+
+use crate::comments::{CssComments, FormatCssLeadingComment};
+use rome_css_syntax::CssLanguage;
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct CssFormatContext {
+    options: CssFormatOptions,
+    /// The comments of the nodes and tokens in the program.
+    comments: Rc<CssComments>,
+    source_map: Option<TransformSourceMap>,
+}
+
+impl CssFormatContext {
+    pub fn new(options: CssFormatOptions, comments: CssComments) -> Self {
+        Self {
+            options,
+            comments: Rc::new(comments),
+            source_map: None,
+        }
+    }
+
+    pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
+        self.source_map = source_map;
+        self
+    }
+}
+
+impl FormatContext for CssFormatContext {
+    type Options = CssFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_map(&self) -> Option<&TransformSourceMap> {
+        None
+    }
+}
+
+impl CstFormatContext for CssFormatContext {
+    type Language = CssLanguage;
+    type Style = CssCommentStyle;
+    type CommentRule = FormatCssLeadingComment;
+
+    fn comments(&self) -> &CssComments {
+        &self.comments
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CssFormatOptions {
+    indent_style: IndentStyle,
+    line_width: LineWidth,
+}
+
+impl CssFormatOptions {
+    pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
+        self.indent_style = indent_style;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+}
+
+impl FormatOptions for CssFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        self.indent_style
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::from(self)
+    }
+}
+
+impl fmt::Display for CssFormatOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Indent style: {}", self.indent_style)?;
+        writeln!(f, "Line width: {}", self.line_width.value())
+    }
+}
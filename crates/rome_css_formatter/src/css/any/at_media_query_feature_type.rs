@@ -0,0 +1,28 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use rome_css_syntax::AnyCssAtMediaQueryFeatureType;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssAtMediaQueryFeatureType;
+impl FormatRule<AnyCssAtMediaQueryFeatureType> for FormatAnyCssAtMediaQueryFeatureType {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssAtMediaQueryFeatureType, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(node) => {
+                node.format().fmt(f)
+            }
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(node) => {
+                node.format().fmt(f)
+            }
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(node) => {
+                node.format().fmt(f)
+            }
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureReverseCompare(node) => {
+                node.format().fmt(f)
+            }
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(node) => {
+                node.format().fmt(f)
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use rome_css_syntax::AnyCssAtMediaQueryType;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssAtMediaQueryType;
+impl FormatRule<AnyCssAtMediaQueryType> for FormatAnyCssAtMediaQueryType {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssAtMediaQueryType, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssAtMediaQueryType::CssIdentifier(node) => node.format().fmt(f),
+            AnyCssAtMediaQueryType::CssAtMediaQueryFeature(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use rome_css_syntax::AnyCssAtRule;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssAtRule;
+impl FormatRule<AnyCssAtRule> for FormatAnyCssAtRule {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssAtRule::CssAtCharset(node) => node.format().fmt(f),
+            AnyCssAtRule::CssAtImport(node) => node.format().fmt(f),
+            AnyCssAtRule::CssAtKeyframes(node) => node.format().fmt(f),
+            AnyCssAtRule::CssAtMedia(node) => node.format().fmt(f),
+            AnyCssAtRule::CssAtGeneric(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use rome_css_syntax::AnyCssAttributeMatcherValue;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssAttributeMatcherValue;
+impl FormatRule<AnyCssAttributeMatcherValue> for FormatAnyCssAttributeMatcherValue {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssAttributeMatcherValue, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssAttributeMatcherValue::CssString(node) => node.format().fmt(f),
+            AnyCssAttributeMatcherValue::CssIdentifier(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use rome_css_syntax::AnyCssBlockItem;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssBlockItem;
+impl FormatRule<AnyCssBlockItem> for FormatAnyCssBlockItem {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssBlockItem, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssBlockItem::CssDeclaration(node) => node.format().fmt(f),
+            AnyCssBlockItem::CssEmptyDeclaration(node) => node.format().fmt(f),
+            AnyCssBlockItem::CssRule(node) => node.format().fmt(f),
+            AnyCssBlockItem::AnyCssAtRule(node) => node.format().fmt(f),
+            AnyCssBlockItem::CssBogusRule(node) => node.format().fmt(f),
+        }
+    }
+}
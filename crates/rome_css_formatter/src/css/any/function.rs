@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use rome_css_syntax::AnyCssFunction;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssFunction;
+impl FormatRule<AnyCssFunction> for FormatAnyCssFunction {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssFunction, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssFunction::CssSimpleFunction(node) => node.format().fmt(f),
+            AnyCssFunction::CssVarFunction(node) => node.format().fmt(f),
+            AnyCssFunction::CssUrlFunction(node) => node.format().fmt(f),
+        }
+    }
+}
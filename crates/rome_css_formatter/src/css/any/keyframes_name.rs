@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use rome_css_syntax::AnyCssKeyframesName;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssKeyframesName;
+impl FormatRule<AnyCssKeyframesName> for FormatAnyCssKeyframesName {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssKeyframesName, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssKeyframesName::CssIdentifier(node) => node.format().fmt(f),
+            AnyCssKeyframesName::CssString(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod at_media_query_feature_type;
+pub(crate) mod at_media_query_type;
+pub(crate) mod at_rule;
+pub(crate) mod attribute_matcher_value;
+pub(crate) mod block_item;
+pub(crate) mod declaration_name;
+pub(crate) mod function;
+pub(crate) mod import_url;
+pub(crate) mod keyframes_name;
+pub(crate) mod keyframes_selector;
+pub(crate) mod pseudo_parameters;
+pub(crate) mod rule;
+pub(crate) mod selector;
+pub(crate) mod selector_pattern;
+pub(crate) mod url_value;
+pub(crate) mod value;
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use rome_css_syntax::AnyCssPseudoParameters;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssPseudoParameters;
+impl FormatRule<AnyCssPseudoParameters> for FormatAnyCssPseudoParameters {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssPseudoParameters, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssPseudoParameters::CssPseudoSelectorParameters(node) => node.format().fmt(f),
+            AnyCssPseudoParameters::CssPseudoValueParameters(node) => node.format().fmt(f),
+        }
+    }
+}
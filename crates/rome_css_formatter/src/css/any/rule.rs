@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use rome_css_syntax::AnyCssRule;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssRule;
+impl FormatRule<AnyCssRule> for FormatAnyCssRule {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssRule, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssRule::CssRule(node) => node.format().fmt(f),
+            AnyCssRule::AnyCssAtRule(node) => node.format().fmt(f),
+            AnyCssRule::CssBogusRule(node) => node.format().fmt(f),
+        }
+    }
+}
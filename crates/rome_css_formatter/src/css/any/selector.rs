@@ -0,0 +1,17 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use rome_css_syntax::AnyCssSelector;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssSelector;
+impl FormatRule<AnyCssSelector> for FormatAnyCssSelector {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssSelector, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssSelector::CssSelector(node) => node.format().fmt(f),
+            AnyCssSelector::CssComplexSelector(node) => node.format().fmt(f),
+            AnyCssSelector::CssRelativeSelector(node) => node.format().fmt(f),
+            AnyCssSelector::CssBogusSelector(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use rome_css_syntax::AnyCssSelectorPattern;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyCssSelectorPattern;
+impl FormatRule<AnyCssSelectorPattern> for FormatAnyCssSelectorPattern {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &AnyCssSelectorPattern, f: &mut CssFormatter) -> FormatResult<()> {
+        match node {
+            AnyCssSelectorPattern::CssIdSelectorPattern(node) => node.format().fmt(f),
+            AnyCssSelectorPattern::CssClassSelectorPattern(node) => node.format().fmt(f),
+            AnyCssSelectorPattern::CssUniversalSelectorPattern(node) => node.format().fmt(f),
+            AnyCssSelectorPattern::CssAttributeSelectorPattern(node) => node.format().fmt(f),
+            AnyCssSelectorPattern::CssPseudoClassSelectorPattern(node) => node.format().fmt(f),
+            AnyCssSelectorPattern::CssPseudoElementSelectorPattern(node) => node.format().fmt(f),
+            AnyCssSelectorPattern::CssTypeSelectorPattern(node) => node.format().fmt(f),
+            AnyCssSelectorPattern::CssNestingSelectorPattern(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtCharset, CssAtCharsetFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtCharset;
+
+impl FormatNodeRule<CssAtCharset> for FormatCssAtCharset {
+    fn fmt_fields(&self, node: &CssAtCharset, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAtCharsetFields {
+            at_token,
+            charset_token,
+            encoding,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                at_token.format(),
+                charset_token.format(),
+                space(),
+                encoding.format(),
+                semicolon_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtGeneric, CssAtGenericFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtGeneric;
+
+impl FormatNodeRule<CssAtGeneric> for FormatCssAtGeneric {
+    fn fmt_fields(&self, node: &CssAtGeneric, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAtGenericFields {
+            at_token,
+            name,
+            prelude,
+            block,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(f, [at_token.format(), name.format()])?;
+
+        if !prelude.is_empty() {
+            write!(f, [space(), prelude.format()])?;
+        }
+
+        if let Some(block) = block {
+            write!(f, [space(), block.format()])?;
+        }
+
+        write!(f, [semicolon_token.format()])
+    }
+}
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtImport, CssAtImportFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtImport;
+
+impl FormatNodeRule<CssAtImport> for FormatCssAtImport {
+    fn fmt_fields(&self, node: &CssAtImport, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAtImportFields {
+            at_token,
+            import_token,
+            url,
+            media,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                at_token.format(),
+                import_token.format(),
+                space(),
+                url.format()
+            ]
+        )?;
+
+        if !media.is_empty() {
+            write!(f, [space(), group(&media.format())])?;
+        }
+
+        write!(f, [semicolon_token.format()])
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMedia, CssAtMediaFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtMedia;
+
+impl FormatNodeRule<CssAtMedia> for FormatCssAtMedia {
+    fn fmt_fields(&self, node: &CssAtMedia, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAtMediaFields {
+            at_token,
+            media_token,
+            query_list,
+            block,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                at_token.format(),
+                media_token.format(),
+                space(),
+                group(&query_list.format()),
+                space(),
+                block.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMediaQueryConsequent, CssAtMediaQueryConsequentFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtMediaQueryConsequent;
+
+impl FormatNodeRule<CssAtMediaQueryConsequent> for FormatCssAtMediaQueryConsequent {
+    fn fmt_fields(
+        &self,
+        node: &CssAtMediaQueryConsequent,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssAtMediaQueryConsequentFields {
+            operator,
+            condition_token,
+            ty,
+        } = node.as_fields();
+
+        write!(f, [operator.format(), space()])?;
+
+        if let Some(condition) = condition_token {
+            write!(f, [condition.format(), space()])?;
+        }
+
+        write!(f, [ty.format()])
+    }
+}
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMediaQueryFeatureBoolean, CssAtMediaQueryFeatureBooleanFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtMediaQueryFeatureBoolean;
+
+impl FormatNodeRule<CssAtMediaQueryFeatureBoolean> for FormatCssAtMediaQueryFeatureBoolean {
+    fn fmt_fields(
+        &self,
+        node: &CssAtMediaQueryFeatureBoolean,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssAtMediaQueryFeatureBooleanFields { name } = node.as_fields();
+
+        write!(f, [name.format()])
+    }
+}
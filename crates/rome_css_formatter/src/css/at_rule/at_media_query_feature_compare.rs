@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMediaQueryFeatureCompare, CssAtMediaQueryFeatureCompareFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtMediaQueryFeatureCompare;
+
+impl FormatNodeRule<CssAtMediaQueryFeatureCompare> for FormatCssAtMediaQueryFeatureCompare {
+    fn fmt_fields(
+        &self,
+        node: &CssAtMediaQueryFeatureCompare,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssAtMediaQueryFeatureCompareFields { name, range, value } = node.as_fields();
+
+        write!(
+            f,
+            [
+                name.format(),
+                space(),
+                range.format(),
+                space(),
+                value.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMediaQueryFeaturePlain, CssAtMediaQueryFeaturePlainFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtMediaQueryFeaturePlain;
+
+impl FormatNodeRule<CssAtMediaQueryFeaturePlain> for FormatCssAtMediaQueryFeaturePlain {
+    fn fmt_fields(
+        &self,
+        node: &CssAtMediaQueryFeaturePlain,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssAtMediaQueryFeaturePlainFields {
+            name,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [name.format(), colon_token.format(), space(), value.format()]
+        )
+    }
+}
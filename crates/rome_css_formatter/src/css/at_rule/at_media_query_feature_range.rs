@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMediaQueryFeatureRange, CssAtMediaQueryFeatureRangeFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtMediaQueryFeatureRange;
+
+impl FormatNodeRule<CssAtMediaQueryFeatureRange> for FormatCssAtMediaQueryFeatureRange {
+    fn fmt_fields(
+        &self,
+        node: &CssAtMediaQueryFeatureRange,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssAtMediaQueryFeatureRangeFields {
+            first_value,
+            first_range,
+            name,
+            second_range,
+            second_value,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                first_value.format(),
+                space(),
+                first_range.format(),
+                space(),
+                name.format(),
+                space(),
+                second_range.format(),
+                space(),
+                second_value.format()
+            ]
+        )
+    }
+}
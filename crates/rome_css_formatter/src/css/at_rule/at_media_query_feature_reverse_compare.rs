@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use rome_css_syntax::{
+    CssAtMediaQueryFeatureReverseCompare, CssAtMediaQueryFeatureReverseCompareFields,
+};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtMediaQueryFeatureReverseCompare;
+
+impl FormatNodeRule<CssAtMediaQueryFeatureReverseCompare>
+    for FormatCssAtMediaQueryFeatureReverseCompare
+{
+    fn fmt_fields(
+        &self,
+        node: &CssAtMediaQueryFeatureReverseCompare,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssAtMediaQueryFeatureReverseCompareFields { value, range, name } = node.as_fields();
+
+        write!(
+            f,
+            [
+                value.format(),
+                space(),
+                range.format(),
+                space(),
+                name.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssAtMediaQueryRange, CssAtMediaQueryRangeFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtMediaQueryRange;
+
+impl FormatNodeRule<CssAtMediaQueryRange> for FormatCssAtMediaQueryRange {
+    fn fmt_fields(&self, node: &CssAtMediaQueryRange, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssAtMediaQueryRangeFields { operator } = node.as_fields();
+
+        write!(f, [operator.format()])
+    }
+}
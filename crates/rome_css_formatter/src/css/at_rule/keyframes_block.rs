@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssKeyframesBlock, CssKeyframesBlockFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssKeyframesBlock;
+
+impl FormatNodeRule<CssKeyframesBlock> for FormatCssKeyframesBlock {
+    fn fmt_fields(&self, node: &CssKeyframesBlock, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssKeyframesBlockFields { selectors, block } = node.as_fields();
+
+        write!(f, [group(&selectors.format()), space(), block.format()])
+    }
+}
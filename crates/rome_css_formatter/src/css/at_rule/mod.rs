@@ -0,0 +1,19 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod at_charset;
+pub(crate) mod at_generic;
+pub(crate) mod at_import;
+pub(crate) mod at_keyframes;
+pub(crate) mod at_keyframes_body;
+pub(crate) mod at_media;
+pub(crate) mod at_media_query;
+pub(crate) mod at_media_query_consequent;
+pub(crate) mod at_media_query_feature;
+pub(crate) mod at_media_query_feature_boolean;
+pub(crate) mod at_media_query_feature_compare;
+pub(crate) mod at_media_query_feature_plain;
+pub(crate) mod at_media_query_feature_range;
+pub(crate) mod at_media_query_feature_reverse_compare;
+pub(crate) mod at_media_query_range;
+pub(crate) mod keyframes_block;
+pub(crate) mod keyframes_selector;
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssBlock, CssBlockFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssBlock;
+
+impl FormatNodeRule<CssBlock> for FormatCssBlock {
+    fn fmt_fields(&self, node: &CssBlock, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssBlockFields {
+            l_curly_token,
+            items,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(f, [l_curly_token.format()])?;
+
+        if items.is_empty() {
+            write!(
+                f,
+                [format_dangling_comments(node.syntax()).with_block_indent()]
+            )?;
+        } else {
+            write!(f, [block_indent(&items.format())])?;
+        }
+
+        write!(f, [r_curly_token.format()])
+    }
+
+    fn fmt_dangling_comments(&self, _: &CssBlock, _: &mut CssFormatter) -> FormatResult<()> {
+        // Formatted inside of `fmt_fields`
+        Ok(())
+    }
+}
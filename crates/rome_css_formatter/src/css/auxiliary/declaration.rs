@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssDeclaration, CssDeclarationFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssDeclaration;
+
+impl FormatNodeRule<CssDeclaration> for FormatCssDeclaration {
+    fn fmt_fields(&self, node: &CssDeclaration, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssDeclarationFields {
+            name,
+            colon_token,
+            value,
+            important,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(f, [name.format(), colon_token.format()])?;
+
+        if !value.is_empty() {
+            write!(f, [space(), value.format()])?;
+        }
+
+        if let Some(important) = important {
+            write!(f, [space(), important.format()])?;
+        }
+
+        match semicolon_token {
+            Some(semicolon) => write!(f, [semicolon.format()]),
+            None => write!(f, [text(";")]),
+        }
+    }
+}
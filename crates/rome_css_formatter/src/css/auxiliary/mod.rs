@@ -0,0 +1,8 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod block;
+pub(crate) mod declaration;
+pub(crate) mod declaration_important;
+pub(crate) mod empty_declaration;
+pub(crate) mod root;
+pub(crate) mod rule;
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssRoot, CssRootFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssRoot;
+
+impl FormatNodeRule<CssRoot> for FormatCssRoot {
+    fn fmt_fields(&self, node: &CssRoot, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssRootFields { rules, eof_token } = node.as_fields();
+
+        write!(f, [rules.format(), format_removed(&eof_token?)])?;
+
+        if !rules.is_empty() {
+            write!(f, [hard_line_break()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+use crate::FormatBogusNodeRule;
+use rome_css_syntax::CssBogus;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssBogus;
+impl FormatBogusNodeRule<CssBogus> for FormatCssBogus {}
@@ -0,0 +1,5 @@
+use crate::FormatBogusNodeRule;
+use rome_css_syntax::CssBogusRule;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssBogusRule;
+impl FormatBogusNodeRule<CssBogusRule> for FormatCssBogusRule {}
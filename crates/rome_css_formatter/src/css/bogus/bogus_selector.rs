@@ -0,0 +1,5 @@
+use crate::FormatBogusNodeRule;
+use rome_css_syntax::CssBogusSelector;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssBogusSelector;
+impl FormatBogusNodeRule<CssBogusSelector> for FormatCssBogusSelector {}
@@ -0,0 +1,5 @@
+use crate::FormatBogusNodeRule;
+use rome_css_syntax::CssBogusValue;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssBogusValue;
+impl FormatBogusNodeRule<CssBogusValue> for FormatCssBogusValue {}
@@ -0,0 +1,7 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+#[allow(clippy::module_inception)]
+pub(crate) mod bogus;
+pub(crate) mod bogus_rule;
+pub(crate) mod bogus_selector;
+pub(crate) mod bogus_value;
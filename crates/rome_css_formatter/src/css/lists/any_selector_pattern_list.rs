@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use rome_css_syntax::CssAnySelectorPatternList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAnySelectorPatternList;
+
+impl FormatRule<CssAnySelectorPatternList> for FormatCssAnySelectorPatternList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAnySelectorPatternList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.join().entries(node.iter().formatted()).finish()
+    }
+}
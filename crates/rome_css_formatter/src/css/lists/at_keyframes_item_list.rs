@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use rome_css_syntax::CssAtKeyframesItemList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtKeyframesItemList;
+
+impl FormatRule<CssAtKeyframesItemList> for FormatCssAtKeyframesItemList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAtKeyframesItemList, f: &mut CssFormatter) -> FormatResult<()> {
+        let mut join = f.join_nodes_with_hardline();
+
+        for item in node {
+            join.entry(item.syntax(), &item.format());
+        }
+
+        join.finish()
+    }
+}
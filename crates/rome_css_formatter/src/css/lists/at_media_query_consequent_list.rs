@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use rome_css_syntax::CssAtMediaQueryConsequentList;
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssAtMediaQueryConsequentList;
+
+impl FormatRule<CssAtMediaQueryConsequentList> for FormatCssAtMediaQueryConsequentList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssAtMediaQueryConsequentList, f: &mut CssFormatter) -> FormatResult<()> {
+        for consequent in node {
+            write!(f, [space(), consequent.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use rome_css_syntax::{AnyCssBlockItem, CssBlockItemList};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssBlockItemList;
+
+impl FormatRule<CssBlockItemList> for FormatCssBlockItemList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssBlockItemList, f: &mut CssFormatter) -> FormatResult<()> {
+        let mut join = f.join_nodes_with_hardline();
+
+        for item in node {
+            match item {
+                AnyCssBlockItem::CssEmptyDeclaration(empty) => {
+                    join.entry_no_separator(&empty.format());
+                }
+                _ => {
+                    join.entry(item.syntax(), &format_or_verbatim(item.format()));
+                }
+            }
+        }
+
+        join.finish()
+    }
+}
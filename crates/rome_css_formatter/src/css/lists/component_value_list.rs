@@ -0,0 +1,40 @@
+use crate::prelude::*;
+use rome_css_syntax::{AnyCssValue, CssComponentValueList, T};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssComponentValueList;
+
+impl FormatRule<CssComponentValueList> for FormatCssComponentValueList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssComponentValueList, f: &mut CssFormatter) -> FormatResult<()> {
+        let mut previous: Option<AnyCssValue> = None;
+
+        for value in node {
+            if let Some(previous) = &previous {
+                // Values are whitespace sensitive: `12px/1.5` isn't the same as `12px / 1.5`
+                // in every context. Only separate values that were separated in the source,
+                // but always put a space after a comma and never before one.
+                let is_separated = previous.syntax().text_trimmed_range().end()
+                    != value.syntax().text_trimmed_range().start();
+
+                if !is_comma(&value) && (is_separated || is_comma(previous)) {
+                    write!(f, [space()])?;
+                }
+            }
+
+            write!(f, [value.format()])?;
+            previous = Some(value);
+        }
+
+        Ok(())
+    }
+}
+
+fn is_comma(value: &AnyCssValue) -> bool {
+    matches!(
+        value,
+        AnyCssValue::CssGenericDelimiter(delimiter)
+            if delimiter.value().map_or(false, |token| token.kind() == T![,])
+    )
+}
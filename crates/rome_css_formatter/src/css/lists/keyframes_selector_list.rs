@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use crate::separated::FormatAstSeparatedListExtension;
+use rome_css_syntax::CssKeyframesSelectorList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssKeyframesSelectorList;
+
+impl FormatRule<CssKeyframesSelectorList> for FormatCssKeyframesSelectorList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssKeyframesSelectorList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.join_with(&soft_line_break_or_space())
+            .entries(node.format_separated(","))
+            .finish()
+    }
+}
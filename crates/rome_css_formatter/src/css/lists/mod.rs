@@ -0,0 +1,12 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod any_selector_pattern_list;
+pub(crate) mod at_keyframes_item_list;
+pub(crate) mod at_media_query_consequent_list;
+pub(crate) mod at_media_query_list;
+pub(crate) mod block_item_list;
+pub(crate) mod component_value_list;
+pub(crate) mod keyframes_selector_list;
+pub(crate) mod parameter_list;
+pub(crate) mod rule_list;
+pub(crate) mod selector_list;
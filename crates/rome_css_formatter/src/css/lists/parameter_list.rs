@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use crate::separated::FormatAstSeparatedListExtension;
+use rome_css_syntax::CssParameterList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssParameterList;
+
+impl FormatRule<CssParameterList> for FormatCssParameterList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssParameterList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.join_with(&space())
+            .entries(node.format_separated(","))
+            .finish()
+    }
+}
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use rome_css_syntax::CssRuleList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssRuleList;
+
+impl FormatRule<CssRuleList> for FormatCssRuleList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &CssRuleList, f: &mut CssFormatter) -> FormatResult<()> {
+        let mut join = f.join_nodes_with_hardline();
+
+        for item in node {
+            join.entry(item.syntax(), &format_or_verbatim(item.format()));
+        }
+
+        join.finish()
+    }
+}
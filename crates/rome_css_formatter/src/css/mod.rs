@@ -0,0 +1,9 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod any;
+pub(crate) mod at_rule;
+pub(crate) mod auxiliary;
+pub(crate) mod bogus;
+pub(crate) mod lists;
+pub(crate) mod selectors;
+pub(crate) mod value;
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssClassSelectorPattern, CssClassSelectorPatternFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssClassSelectorPattern;
+
+impl FormatNodeRule<CssClassSelectorPattern> for FormatCssClassSelectorPattern {
+    fn fmt_fields(&self, node: &CssClassSelectorPattern, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssClassSelectorPatternFields { dot_token, name } = node.as_fields();
+
+        write!(f, [dot_token.format(), name.format()])
+    }
+}
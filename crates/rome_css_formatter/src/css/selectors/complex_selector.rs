@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssComplexSelector, CssComplexSelectorFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssComplexSelector;
+
+impl FormatNodeRule<CssComplexSelector> for FormatCssComplexSelector {
+    fn fmt_fields(&self, node: &CssComplexSelector, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssComplexSelectorFields {
+            left,
+            combinator,
+            right,
+        } = node.as_fields();
+
+        write!(f, [left.format(), space()])?;
+
+        if let Some(combinator) = combinator {
+            write!(f, [combinator.format(), space()])?;
+        }
+
+        write!(f, [right.format()])
+    }
+}
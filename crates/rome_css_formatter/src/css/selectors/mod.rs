@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod attribute_matcher;
+pub(crate) mod attribute_selector_pattern;
+pub(crate) mod class_selector_pattern;
+pub(crate) mod complex_selector;
+pub(crate) mod id_selector_pattern;
+pub(crate) mod nesting_selector_pattern;
+pub(crate) mod pseudo_class_selector_pattern;
+pub(crate) mod pseudo_element_selector_pattern;
+pub(crate) mod pseudo_selector_parameters;
+pub(crate) mod pseudo_value_parameters;
+pub(crate) mod relative_selector;
+pub(crate) mod selector;
+pub(crate) mod type_selector_pattern;
+pub(crate) mod universal_selector_pattern;
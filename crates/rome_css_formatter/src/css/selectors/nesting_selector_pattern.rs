@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssNestingSelectorPattern, CssNestingSelectorPatternFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssNestingSelectorPattern;
+
+impl FormatNodeRule<CssNestingSelectorPattern> for FormatCssNestingSelectorPattern {
+    fn fmt_fields(
+        &self,
+        node: &CssNestingSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssNestingSelectorPatternFields { amp_token } = node.as_fields();
+
+        write!(f, [amp_token.format()])
+    }
+}
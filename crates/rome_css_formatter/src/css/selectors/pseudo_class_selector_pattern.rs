@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssPseudoClassSelectorPattern, CssPseudoClassSelectorPatternFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssPseudoClassSelectorPattern;
+
+impl FormatNodeRule<CssPseudoClassSelectorPattern> for FormatCssPseudoClassSelectorPattern {
+    fn fmt_fields(
+        &self,
+        node: &CssPseudoClassSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssPseudoClassSelectorPatternFields {
+            colon_token,
+            name,
+            parameters,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [colon_token.format(), name.format(), parameters.format()]
+        )
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssPseudoSelectorParameters, CssPseudoSelectorParametersFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssPseudoSelectorParameters;
+
+impl FormatNodeRule<CssPseudoSelectorParameters> for FormatCssPseudoSelectorParameters {
+    fn fmt_fields(
+        &self,
+        node: &CssPseudoSelectorParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssPseudoSelectorParametersFields {
+            l_paren_token,
+            selectors,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_paren_token.format(),
+                group(&selectors.format()),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssPseudoValueParameters, CssPseudoValueParametersFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssPseudoValueParameters;
+
+impl FormatNodeRule<CssPseudoValueParameters> for FormatCssPseudoValueParameters {
+    fn fmt_fields(
+        &self,
+        node: &CssPseudoValueParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssPseudoValueParametersFields {
+            l_paren_token,
+            values,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_paren_token.format(),
+                values.format(),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
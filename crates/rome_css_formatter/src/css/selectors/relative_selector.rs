@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssRelativeSelector, CssRelativeSelectorFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssRelativeSelector;
+
+impl FormatNodeRule<CssRelativeSelector> for FormatCssRelativeSelector {
+    fn fmt_fields(&self, node: &CssRelativeSelector, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssRelativeSelectorFields {
+            combinator,
+            selector,
+        } = node.as_fields();
+
+        write!(f, [combinator.format(), space(), selector.format()])
+    }
+}
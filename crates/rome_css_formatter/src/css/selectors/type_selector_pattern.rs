@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssTypeSelectorPattern, CssTypeSelectorPatternFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssTypeSelectorPattern;
+
+impl FormatNodeRule<CssTypeSelectorPattern> for FormatCssTypeSelectorPattern {
+    fn fmt_fields(&self, node: &CssTypeSelectorPattern, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssTypeSelectorPatternFields { ident } = node.as_fields();
+
+        write!(f, [ident.format()])
+    }
+}
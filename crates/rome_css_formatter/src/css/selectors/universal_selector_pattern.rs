@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssUniversalSelectorPattern, CssUniversalSelectorPatternFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssUniversalSelectorPattern;
+
+impl FormatNodeRule<CssUniversalSelectorPattern> for FormatCssUniversalSelectorPattern {
+    fn fmt_fields(
+        &self,
+        node: &CssUniversalSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        let CssUniversalSelectorPatternFields { star_token } = node.as_fields();
+
+        write!(f, [star_token.format()])
+    }
+}
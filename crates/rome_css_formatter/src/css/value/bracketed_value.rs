@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssBracketedValue, CssBracketedValueFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssBracketedValue;
+
+impl FormatNodeRule<CssBracketedValue> for FormatCssBracketedValue {
+    fn fmt_fields(&self, node: &CssBracketedValue, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssBracketedValueFields {
+            l_brack_token,
+            values,
+            r_brack_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_brack_token.format(),
+                values.format(),
+                r_brack_token.format()
+            ]
+        )
+    }
+}
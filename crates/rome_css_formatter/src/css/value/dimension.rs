@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssDimension, CssDimensionFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssDimension;
+
+impl FormatNodeRule<CssDimension> for FormatCssDimension {
+    fn fmt_fields(&self, node: &CssDimension, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssDimensionFields { value, unit } = node.as_fields();
+
+        write!(f, [value.format(), unit.format()])
+    }
+}
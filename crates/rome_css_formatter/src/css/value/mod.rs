@@ -0,0 +1,19 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod bracketed_value;
+pub(crate) mod color;
+pub(crate) mod custom_property;
+pub(crate) mod dimension;
+pub(crate) mod generic_delimiter;
+pub(crate) mod identifier;
+pub(crate) mod number;
+pub(crate) mod parameter;
+pub(crate) mod parenthesized_value;
+pub(crate) mod percentage;
+pub(crate) mod ratio;
+pub(crate) mod simple_function;
+pub(crate) mod string;
+pub(crate) mod url_function;
+pub(crate) mod url_value_raw;
+pub(crate) mod var_function;
+pub(crate) mod var_function_value;
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssParameter, CssParameterFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssParameter;
+
+impl FormatNodeRule<CssParameter> for FormatCssParameter {
+    fn fmt_fields(&self, node: &CssParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssParameterFields { values } = node.as_fields();
+
+        write!(f, [values.format()])
+    }
+}
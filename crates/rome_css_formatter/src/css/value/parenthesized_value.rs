@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssParenthesizedValue, CssParenthesizedValueFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssParenthesizedValue;
+
+impl FormatNodeRule<CssParenthesizedValue> for FormatCssParenthesizedValue {
+    fn fmt_fields(&self, node: &CssParenthesizedValue, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssParenthesizedValueFields {
+            l_paren_token,
+            values,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_paren_token.format(),
+                values.format(),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssRatio, CssRatioFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssRatio;
+
+impl FormatNodeRule<CssRatio> for FormatCssRatio {
+    fn fmt_fields(&self, node: &CssRatio, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssRatioFields {
+            numerator,
+            slash_token,
+            denominator,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                numerator.format(),
+                space(),
+                slash_token.format(),
+                space(),
+                denominator.format()
+            ]
+        )
+    }
+}
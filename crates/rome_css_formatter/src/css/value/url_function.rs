@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssUrlFunction, CssUrlFunctionFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssUrlFunction;
+
+impl FormatNodeRule<CssUrlFunction> for FormatCssUrlFunction {
+    fn fmt_fields(&self, node: &CssUrlFunction, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssUrlFunctionFields {
+            url_token,
+            l_paren_token,
+            value,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                url_token.format(),
+                l_paren_token.format(),
+                value.format(),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
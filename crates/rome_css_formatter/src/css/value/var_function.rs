@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssVarFunction, CssVarFunctionFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssVarFunction;
+
+impl FormatNodeRule<CssVarFunction> for FormatCssVarFunction {
+    fn fmt_fields(&self, node: &CssVarFunction, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssVarFunctionFields {
+            var_token,
+            l_paren_token,
+            property,
+            value,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                var_token.format(),
+                l_paren_token.format(),
+                property.format(),
+                value.format(),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use rome_css_syntax::{CssVarFunctionValue, CssVarFunctionValueFields};
+use rome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatCssVarFunctionValue;
+
+impl FormatNodeRule<CssVarFunctionValue> for FormatCssVarFunctionValue {
+    fn fmt_fields(&self, node: &CssVarFunctionValue, f: &mut CssFormatter) -> FormatResult<()> {
+        let CssVarFunctionValueFields { comma_token, value } = node.as_fields();
+
+        write!(f, [comma_token.format()])?;
+
+        if !value.is_empty() {
+            write!(f, [space(), value.format()])?;
+        }
+
+        Ok(())
+    }
+}
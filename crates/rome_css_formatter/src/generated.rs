@@ -0,0 +1,2560 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::{
+    AsFormat, CssFormatContext, CssFormatter, FormatBogusNodeRule, FormatNodeRule, IntoFormat,
+};
+use rome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<rome_css_syntax::CssRoot> for crate::css::auxiliary::root::FormatCssRoot {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssRoot, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssRoot>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssRoot {
+    type Format<'a> =
+        FormatRefWithRule<'a, rome_css_syntax::CssRoot, crate::css::auxiliary::root::FormatCssRoot>;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, crate::css::auxiliary::root::FormatCssRoot::default())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssRoot {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssRoot, crate::css::auxiliary::root::FormatCssRoot>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::auxiliary::root::FormatCssRoot::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssRule> for crate::css::auxiliary::rule::FormatCssRule {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssRule, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssRule {
+    type Format<'a> =
+        FormatRefWithRule<'a, rome_css_syntax::CssRule, crate::css::auxiliary::rule::FormatCssRule>;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, crate::css::auxiliary::rule::FormatCssRule::default())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssRule {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssRule, crate::css::auxiliary::rule::FormatCssRule>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::auxiliary::rule::FormatCssRule::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssBlock> for crate::css::auxiliary::block::FormatCssBlock {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssBlock, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssBlock,
+        crate::css::auxiliary::block::FormatCssBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::block::FormatCssBlock::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssBlock {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssBlock,
+        crate::css::auxiliary::block::FormatCssBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::block::FormatCssBlock::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssSelector>
+    for crate::css::selectors::selector::FormatCssSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssSelector, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssSelector>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssSelector,
+        crate::css::selectors::selector::FormatCssSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::selector::FormatCssSelector::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssSelector {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssSelector,
+        crate::css::selectors::selector::FormatCssSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::selector::FormatCssSelector::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssComplexSelector>
+    for crate::css::selectors::complex_selector::FormatCssComplexSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssComplexSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssComplexSelector>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssComplexSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssComplexSelector,
+        crate::css::selectors::complex_selector::FormatCssComplexSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::complex_selector::FormatCssComplexSelector::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssComplexSelector {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssComplexSelector,
+        crate::css::selectors::complex_selector::FormatCssComplexSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::complex_selector::FormatCssComplexSelector::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssRelativeSelector>
+    for crate::css::selectors::relative_selector::FormatCssRelativeSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssRelativeSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssRelativeSelector>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssRelativeSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssRelativeSelector,
+        crate::css::selectors::relative_selector::FormatCssRelativeSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::relative_selector::FormatCssRelativeSelector::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssRelativeSelector {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssRelativeSelector,
+        crate::css::selectors::relative_selector::FormatCssRelativeSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::relative_selector::FormatCssRelativeSelector::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssIdSelectorPattern>
+    for crate::css::selectors::id_selector_pattern::FormatCssIdSelectorPattern
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssIdSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssIdSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssIdSelectorPattern {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssIdSelectorPattern,
+        crate::css::selectors::id_selector_pattern::FormatCssIdSelectorPattern,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::id_selector_pattern::FormatCssIdSelectorPattern::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssIdSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssIdSelectorPattern,
+        crate::css::selectors::id_selector_pattern::FormatCssIdSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::id_selector_pattern::FormatCssIdSelectorPattern::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssClassSelectorPattern>
+    for crate::css::selectors::class_selector_pattern::FormatCssClassSelectorPattern
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssClassSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssClassSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssClassSelectorPattern {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssClassSelectorPattern,
+        crate::css::selectors::class_selector_pattern::FormatCssClassSelectorPattern,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::class_selector_pattern::FormatCssClassSelectorPattern::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssClassSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssClassSelectorPattern,
+        crate::css::selectors::class_selector_pattern::FormatCssClassSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::class_selector_pattern::FormatCssClassSelectorPattern::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssUniversalSelectorPattern>
+    for crate::css::selectors::universal_selector_pattern::FormatCssUniversalSelectorPattern
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssUniversalSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssUniversalSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssUniversalSelectorPattern {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssUniversalSelectorPattern,
+        crate::css::selectors::universal_selector_pattern::FormatCssUniversalSelectorPattern,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: universal_selector_pattern :: FormatCssUniversalSelectorPattern :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssUniversalSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssUniversalSelectorPattern,
+        crate::css::selectors::universal_selector_pattern::FormatCssUniversalSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: universal_selector_pattern :: FormatCssUniversalSelectorPattern :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssAttributeSelectorPattern>
+    for crate::css::selectors::attribute_selector_pattern::FormatCssAttributeSelectorPattern
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAttributeSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAttributeSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAttributeSelectorPattern {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAttributeSelectorPattern,
+        crate::css::selectors::attribute_selector_pattern::FormatCssAttributeSelectorPattern,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: attribute_selector_pattern :: FormatCssAttributeSelectorPattern :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAttributeSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAttributeSelectorPattern,
+        crate::css::selectors::attribute_selector_pattern::FormatCssAttributeSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: attribute_selector_pattern :: FormatCssAttributeSelectorPattern :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssPseudoClassSelectorPattern>
+    for crate::css::selectors::pseudo_class_selector_pattern::FormatCssPseudoClassSelectorPattern
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssPseudoClassSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssPseudoClassSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssPseudoClassSelectorPattern {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssPseudoClassSelectorPattern,
+        crate::css::selectors::pseudo_class_selector_pattern::FormatCssPseudoClassSelectorPattern,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_class_selector_pattern :: FormatCssPseudoClassSelectorPattern :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssPseudoClassSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssPseudoClassSelectorPattern,
+        crate::css::selectors::pseudo_class_selector_pattern::FormatCssPseudoClassSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_class_selector_pattern :: FormatCssPseudoClassSelectorPattern :: default ())
+    }
+}
+impl FormatRule < rome_css_syntax :: CssPseudoElementSelectorPattern > for crate :: css :: selectors :: pseudo_element_selector_pattern :: FormatCssPseudoElementSelectorPattern { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & rome_css_syntax :: CssPseudoElementSelectorPattern , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < rome_css_syntax :: CssPseudoElementSelectorPattern > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssPseudoElementSelectorPattern {
+    type Format < 'a > = FormatRefWithRule < 'a , rome_css_syntax :: CssPseudoElementSelectorPattern , crate :: css :: selectors :: pseudo_element_selector_pattern :: FormatCssPseudoElementSelectorPattern > ;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_element_selector_pattern :: FormatCssPseudoElementSelectorPattern :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssPseudoElementSelectorPattern {
+    type Format = FormatOwnedWithRule < rome_css_syntax :: CssPseudoElementSelectorPattern , crate :: css :: selectors :: pseudo_element_selector_pattern :: FormatCssPseudoElementSelectorPattern > ;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_element_selector_pattern :: FormatCssPseudoElementSelectorPattern :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssTypeSelectorPattern>
+    for crate::css::selectors::type_selector_pattern::FormatCssTypeSelectorPattern
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssTypeSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssTypeSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssTypeSelectorPattern {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssTypeSelectorPattern,
+        crate::css::selectors::type_selector_pattern::FormatCssTypeSelectorPattern,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::type_selector_pattern::FormatCssTypeSelectorPattern::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssTypeSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssTypeSelectorPattern,
+        crate::css::selectors::type_selector_pattern::FormatCssTypeSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::type_selector_pattern::FormatCssTypeSelectorPattern::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssNestingSelectorPattern>
+    for crate::css::selectors::nesting_selector_pattern::FormatCssNestingSelectorPattern
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssNestingSelectorPattern,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssNestingSelectorPattern>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssNestingSelectorPattern {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssNestingSelectorPattern,
+        crate::css::selectors::nesting_selector_pattern::FormatCssNestingSelectorPattern,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: nesting_selector_pattern :: FormatCssNestingSelectorPattern :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssNestingSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssNestingSelectorPattern,
+        crate::css::selectors::nesting_selector_pattern::FormatCssNestingSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: nesting_selector_pattern :: FormatCssNestingSelectorPattern :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssIdentifier>
+    for crate::css::value::identifier::FormatCssIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssIdentifier, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssIdentifier,
+        crate::css::value::identifier::FormatCssIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::identifier::FormatCssIdentifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssIdentifier {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssIdentifier,
+        crate::css::value::identifier::FormatCssIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::identifier::FormatCssIdentifier::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAttributeMatcher>
+    for crate::css::selectors::attribute_matcher::FormatCssAttributeMatcher
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAttributeMatcher,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAttributeMatcher>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAttributeMatcher {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAttributeMatcher,
+        crate::css::selectors::attribute_matcher::FormatCssAttributeMatcher,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::attribute_matcher::FormatCssAttributeMatcher::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAttributeMatcher {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAttributeMatcher,
+        crate::css::selectors::attribute_matcher::FormatCssAttributeMatcher,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::attribute_matcher::FormatCssAttributeMatcher::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssString> for crate::css::value::string::FormatCssString {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssString, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssString>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssString {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssString,
+        crate::css::value::string::FormatCssString,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, crate::css::value::string::FormatCssString::default())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssString {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssString, crate::css::value::string::FormatCssString>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::value::string::FormatCssString::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssPseudoSelectorParameters>
+    for crate::css::selectors::pseudo_selector_parameters::FormatCssPseudoSelectorParameters
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssPseudoSelectorParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssPseudoSelectorParameters>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssPseudoSelectorParameters {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssPseudoSelectorParameters,
+        crate::css::selectors::pseudo_selector_parameters::FormatCssPseudoSelectorParameters,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: selectors :: pseudo_selector_parameters :: FormatCssPseudoSelectorParameters :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssPseudoSelectorParameters {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssPseudoSelectorParameters,
+        crate::css::selectors::pseudo_selector_parameters::FormatCssPseudoSelectorParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: selectors :: pseudo_selector_parameters :: FormatCssPseudoSelectorParameters :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssPseudoValueParameters>
+    for crate::css::selectors::pseudo_value_parameters::FormatCssPseudoValueParameters
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssPseudoValueParameters,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssPseudoValueParameters>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssPseudoValueParameters {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssPseudoValueParameters,
+        crate::css::selectors::pseudo_value_parameters::FormatCssPseudoValueParameters,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::selectors::pseudo_value_parameters::FormatCssPseudoValueParameters::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssPseudoValueParameters {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssPseudoValueParameters,
+        crate::css::selectors::pseudo_value_parameters::FormatCssPseudoValueParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::selectors::pseudo_value_parameters::FormatCssPseudoValueParameters::default(
+            ),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssDeclaration>
+    for crate::css::auxiliary::declaration::FormatCssDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssDeclaration,
+        crate::css::auxiliary::declaration::FormatCssDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::declaration::FormatCssDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssDeclaration {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssDeclaration,
+        crate::css::auxiliary::declaration::FormatCssDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::declaration::FormatCssDeclaration::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssEmptyDeclaration>
+    for crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssEmptyDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssEmptyDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssEmptyDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssEmptyDeclaration,
+        crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssEmptyDeclaration {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssEmptyDeclaration,
+        crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::empty_declaration::FormatCssEmptyDeclaration::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssDeclarationImportant>
+    for crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssDeclarationImportant,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssDeclarationImportant>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssDeclarationImportant {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssDeclarationImportant,
+        crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssDeclarationImportant {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssDeclarationImportant,
+        crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::auxiliary::declaration_important::FormatCssDeclarationImportant::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssCustomProperty>
+    for crate::css::value::custom_property::FormatCssCustomProperty
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssCustomProperty,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssCustomProperty>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssCustomProperty {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssCustomProperty,
+        crate::css::value::custom_property::FormatCssCustomProperty,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::custom_property::FormatCssCustomProperty::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssCustomProperty {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssCustomProperty,
+        crate::css::value::custom_property::FormatCssCustomProperty,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::custom_property::FormatCssCustomProperty::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtCharset>
+    for crate::css::at_rule::at_charset::FormatCssAtCharset
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssAtCharset, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtCharset>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtCharset {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtCharset,
+        crate::css::at_rule::at_charset::FormatCssAtCharset,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rule::at_charset::FormatCssAtCharset::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtCharset {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtCharset,
+        crate::css::at_rule::at_charset::FormatCssAtCharset,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rule::at_charset::FormatCssAtCharset::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtImport>
+    for crate::css::at_rule::at_import::FormatCssAtImport
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssAtImport, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtImport>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtImport {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtImport,
+        crate::css::at_rule::at_import::FormatCssAtImport,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rule::at_import::FormatCssAtImport::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtImport {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtImport,
+        crate::css::at_rule::at_import::FormatCssAtImport,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rule::at_import::FormatCssAtImport::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtKeyframes>
+    for crate::css::at_rule::at_keyframes::FormatCssAtKeyframes
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtKeyframes,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtKeyframes>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtKeyframes {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtKeyframes,
+        crate::css::at_rule::at_keyframes::FormatCssAtKeyframes,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rule::at_keyframes::FormatCssAtKeyframes::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtKeyframes {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtKeyframes,
+        crate::css::at_rule::at_keyframes::FormatCssAtKeyframes,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rule::at_keyframes::FormatCssAtKeyframes::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMedia> for crate::css::at_rule::at_media::FormatCssAtMedia {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssAtMedia, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMedia>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtMedia {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMedia,
+        crate::css::at_rule::at_media::FormatCssAtMedia,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rule::at_media::FormatCssAtMedia::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtMedia {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMedia,
+        crate::css::at_rule::at_media::FormatCssAtMedia,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rule::at_media::FormatCssAtMedia::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtGeneric>
+    for crate::css::at_rule::at_generic::FormatCssAtGeneric
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssAtGeneric, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtGeneric>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtGeneric {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtGeneric,
+        crate::css::at_rule::at_generic::FormatCssAtGeneric,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rule::at_generic::FormatCssAtGeneric::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtGeneric {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtGeneric,
+        crate::css::at_rule::at_generic::FormatCssAtGeneric,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rule::at_generic::FormatCssAtGeneric::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssUrlFunction>
+    for crate::css::value::url_function::FormatCssUrlFunction
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssUrlFunction,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssUrlFunction>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssUrlFunction {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssUrlFunction,
+        crate::css::value::url_function::FormatCssUrlFunction,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::url_function::FormatCssUrlFunction::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssUrlFunction {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssUrlFunction,
+        crate::css::value::url_function::FormatCssUrlFunction,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::url_function::FormatCssUrlFunction::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtKeyframesBody>
+    for crate::css::at_rule::at_keyframes_body::FormatCssAtKeyframesBody
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtKeyframesBody,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtKeyframesBody>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtKeyframesBody {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtKeyframesBody,
+        crate::css::at_rule::at_keyframes_body::FormatCssAtKeyframesBody,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rule::at_keyframes_body::FormatCssAtKeyframesBody::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtKeyframesBody {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtKeyframesBody,
+        crate::css::at_rule::at_keyframes_body::FormatCssAtKeyframesBody,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rule::at_keyframes_body::FormatCssAtKeyframesBody::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssKeyframesBlock>
+    for crate::css::at_rule::keyframes_block::FormatCssKeyframesBlock
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssKeyframesBlock,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssKeyframesBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssKeyframesBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssKeyframesBlock,
+        crate::css::at_rule::keyframes_block::FormatCssKeyframesBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rule::keyframes_block::FormatCssKeyframesBlock::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssKeyframesBlock {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssKeyframesBlock,
+        crate::css::at_rule::keyframes_block::FormatCssKeyframesBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rule::keyframes_block::FormatCssKeyframesBlock::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssKeyframesSelector>
+    for crate::css::at_rule::keyframes_selector::FormatCssKeyframesSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssKeyframesSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssKeyframesSelector>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssKeyframesSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssKeyframesSelector,
+        crate::css::at_rule::keyframes_selector::FormatCssKeyframesSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rule::keyframes_selector::FormatCssKeyframesSelector::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssKeyframesSelector {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssKeyframesSelector,
+        crate::css::at_rule::keyframes_selector::FormatCssKeyframesSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rule::keyframes_selector::FormatCssKeyframesSelector::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssPercentage>
+    for crate::css::value::percentage::FormatCssPercentage
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssPercentage, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssPercentage>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssPercentage {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssPercentage,
+        crate::css::value::percentage::FormatCssPercentage,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::percentage::FormatCssPercentage::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssPercentage {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssPercentage,
+        crate::css::value::percentage::FormatCssPercentage,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::percentage::FormatCssPercentage::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQuery>
+    for crate::css::at_rule::at_media_query::FormatCssAtMediaQuery
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQuery,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQuery>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQuery {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQuery,
+        crate::css::at_rule::at_media_query::FormatCssAtMediaQuery,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rule::at_media_query::FormatCssAtMediaQuery::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQuery {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQuery,
+        crate::css::at_rule::at_media_query::FormatCssAtMediaQuery,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rule::at_media_query::FormatCssAtMediaQuery::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryConsequent>
+    for crate::css::at_rule::at_media_query_consequent::FormatCssAtMediaQueryConsequent
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryConsequent,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryConsequent>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryConsequent {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryConsequent,
+        crate::css::at_rule::at_media_query_consequent::FormatCssAtMediaQueryConsequent,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: at_rule :: at_media_query_consequent :: FormatCssAtMediaQueryConsequent :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryConsequent {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryConsequent,
+        crate::css::at_rule::at_media_query_consequent::FormatCssAtMediaQueryConsequent,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: at_rule :: at_media_query_consequent :: FormatCssAtMediaQueryConsequent :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryFeature>
+    for crate::css::at_rule::at_media_query_feature::FormatCssAtMediaQueryFeature
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryFeature,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryFeature>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeature {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryFeature,
+        crate::css::at_rule::at_media_query_feature::FormatCssAtMediaQueryFeature,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rule::at_media_query_feature::FormatCssAtMediaQueryFeature::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeature {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryFeature,
+        crate::css::at_rule::at_media_query_feature::FormatCssAtMediaQueryFeature,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rule::at_media_query_feature::FormatCssAtMediaQueryFeature::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryFeaturePlain>
+    for crate::css::at_rule::at_media_query_feature_plain::FormatCssAtMediaQueryFeaturePlain
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryFeaturePlain,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryFeaturePlain>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeaturePlain {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryFeaturePlain,
+        crate::css::at_rule::at_media_query_feature_plain::FormatCssAtMediaQueryFeaturePlain,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: at_rule :: at_media_query_feature_plain :: FormatCssAtMediaQueryFeaturePlain :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeaturePlain {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryFeaturePlain,
+        crate::css::at_rule::at_media_query_feature_plain::FormatCssAtMediaQueryFeaturePlain,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: at_rule :: at_media_query_feature_plain :: FormatCssAtMediaQueryFeaturePlain :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryFeatureBoolean>
+    for crate::css::at_rule::at_media_query_feature_boolean::FormatCssAtMediaQueryFeatureBoolean
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryFeatureBoolean,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryFeatureBoolean>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeatureBoolean {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryFeatureBoolean,
+        crate::css::at_rule::at_media_query_feature_boolean::FormatCssAtMediaQueryFeatureBoolean,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: at_rule :: at_media_query_feature_boolean :: FormatCssAtMediaQueryFeatureBoolean :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeatureBoolean {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryFeatureBoolean,
+        crate::css::at_rule::at_media_query_feature_boolean::FormatCssAtMediaQueryFeatureBoolean,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: at_rule :: at_media_query_feature_boolean :: FormatCssAtMediaQueryFeatureBoolean :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryFeatureCompare>
+    for crate::css::at_rule::at_media_query_feature_compare::FormatCssAtMediaQueryFeatureCompare
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryFeatureCompare,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryFeatureCompare>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeatureCompare {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryFeatureCompare,
+        crate::css::at_rule::at_media_query_feature_compare::FormatCssAtMediaQueryFeatureCompare,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: at_rule :: at_media_query_feature_compare :: FormatCssAtMediaQueryFeatureCompare :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeatureCompare {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryFeatureCompare,
+        crate::css::at_rule::at_media_query_feature_compare::FormatCssAtMediaQueryFeatureCompare,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: at_rule :: at_media_query_feature_compare :: FormatCssAtMediaQueryFeatureCompare :: default ())
+    }
+}
+impl FormatRule < rome_css_syntax :: CssAtMediaQueryFeatureReverseCompare > for crate :: css :: at_rule :: at_media_query_feature_reverse_compare :: FormatCssAtMediaQueryFeatureReverseCompare { type Context = CssFormatContext ; # [inline (always)] fn fmt (& self , node : & rome_css_syntax :: CssAtMediaQueryFeatureReverseCompare , f : & mut CssFormatter) -> FormatResult < () > { FormatNodeRule :: < rome_css_syntax :: CssAtMediaQueryFeatureReverseCompare > :: fmt (self , node , f) } }
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeatureReverseCompare {
+    type Format < 'a > = FormatRefWithRule < 'a , rome_css_syntax :: CssAtMediaQueryFeatureReverseCompare , crate :: css :: at_rule :: at_media_query_feature_reverse_compare :: FormatCssAtMediaQueryFeatureReverseCompare > ;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: at_rule :: at_media_query_feature_reverse_compare :: FormatCssAtMediaQueryFeatureReverseCompare :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeatureReverseCompare {
+    type Format = FormatOwnedWithRule < rome_css_syntax :: CssAtMediaQueryFeatureReverseCompare , crate :: css :: at_rule :: at_media_query_feature_reverse_compare :: FormatCssAtMediaQueryFeatureReverseCompare > ;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: at_rule :: at_media_query_feature_reverse_compare :: FormatCssAtMediaQueryFeatureReverseCompare :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryFeatureRange>
+    for crate::css::at_rule::at_media_query_feature_range::FormatCssAtMediaQueryFeatureRange
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryFeatureRange,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryFeatureRange>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeatureRange {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryFeatureRange,
+        crate::css::at_rule::at_media_query_feature_range::FormatCssAtMediaQueryFeatureRange,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: at_rule :: at_media_query_feature_range :: FormatCssAtMediaQueryFeatureRange :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryFeatureRange {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryFeatureRange,
+        crate::css::at_rule::at_media_query_feature_range::FormatCssAtMediaQueryFeatureRange,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: at_rule :: at_media_query_feature_range :: FormatCssAtMediaQueryFeatureRange :: default ())
+    }
+}
+impl FormatRule<rome_css_syntax::CssAtMediaQueryRange>
+    for crate::css::at_rule::at_media_query_range::FormatCssAtMediaQueryRange
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssAtMediaQueryRange,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssAtMediaQueryRange>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryRange {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryRange,
+        crate::css::at_rule::at_media_query_range::FormatCssAtMediaQueryRange,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::at_rule::at_media_query_range::FormatCssAtMediaQueryRange::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryRange {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryRange,
+        crate::css::at_rule::at_media_query_range::FormatCssAtMediaQueryRange,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::at_rule::at_media_query_range::FormatCssAtMediaQueryRange::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssNumber> for crate::css::value::number::FormatCssNumber {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssNumber, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssNumber>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssNumber {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssNumber,
+        crate::css::value::number::FormatCssNumber,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, crate::css::value::number::FormatCssNumber::default())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssNumber {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssNumber, crate::css::value::number::FormatCssNumber>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::value::number::FormatCssNumber::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssDimension>
+    for crate::css::value::dimension::FormatCssDimension
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssDimension, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssDimension>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssDimension {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssDimension,
+        crate::css::value::dimension::FormatCssDimension,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::dimension::FormatCssDimension::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssDimension {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssDimension,
+        crate::css::value::dimension::FormatCssDimension,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::dimension::FormatCssDimension::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssRatio> for crate::css::value::ratio::FormatCssRatio {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssRatio, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssRatio>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssRatio {
+    type Format<'a> =
+        FormatRefWithRule<'a, rome_css_syntax::CssRatio, crate::css::value::ratio::FormatCssRatio>;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, crate::css::value::ratio::FormatCssRatio::default())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssRatio {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssRatio, crate::css::value::ratio::FormatCssRatio>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::value::ratio::FormatCssRatio::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssColor> for crate::css::value::color::FormatCssColor {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssColor, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssColor>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssColor {
+    type Format<'a> =
+        FormatRefWithRule<'a, rome_css_syntax::CssColor, crate::css::value::color::FormatCssColor>;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, crate::css::value::color::FormatCssColor::default())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssColor {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssColor, crate::css::value::color::FormatCssColor>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::value::color::FormatCssColor::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssParenthesizedValue>
+    for crate::css::value::parenthesized_value::FormatCssParenthesizedValue
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssParenthesizedValue,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssParenthesizedValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssParenthesizedValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssParenthesizedValue,
+        crate::css::value::parenthesized_value::FormatCssParenthesizedValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::parenthesized_value::FormatCssParenthesizedValue::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssParenthesizedValue {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssParenthesizedValue,
+        crate::css::value::parenthesized_value::FormatCssParenthesizedValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::parenthesized_value::FormatCssParenthesizedValue::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssBracketedValue>
+    for crate::css::value::bracketed_value::FormatCssBracketedValue
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssBracketedValue,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssBracketedValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssBracketedValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssBracketedValue,
+        crate::css::value::bracketed_value::FormatCssBracketedValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::bracketed_value::FormatCssBracketedValue::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssBracketedValue {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssBracketedValue,
+        crate::css::value::bracketed_value::FormatCssBracketedValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::bracketed_value::FormatCssBracketedValue::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssGenericDelimiter>
+    for crate::css::value::generic_delimiter::FormatCssGenericDelimiter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssGenericDelimiter,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssGenericDelimiter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssGenericDelimiter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssGenericDelimiter,
+        crate::css::value::generic_delimiter::FormatCssGenericDelimiter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::generic_delimiter::FormatCssGenericDelimiter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssGenericDelimiter {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssGenericDelimiter,
+        crate::css::value::generic_delimiter::FormatCssGenericDelimiter,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::generic_delimiter::FormatCssGenericDelimiter::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssSimpleFunction>
+    for crate::css::value::simple_function::FormatCssSimpleFunction
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssSimpleFunction,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssSimpleFunction>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssSimpleFunction {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssSimpleFunction,
+        crate::css::value::simple_function::FormatCssSimpleFunction,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::simple_function::FormatCssSimpleFunction::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssSimpleFunction {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssSimpleFunction,
+        crate::css::value::simple_function::FormatCssSimpleFunction,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::simple_function::FormatCssSimpleFunction::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssVarFunction>
+    for crate::css::value::var_function::FormatCssVarFunction
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssVarFunction,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssVarFunction>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssVarFunction {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssVarFunction,
+        crate::css::value::var_function::FormatCssVarFunction,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::var_function::FormatCssVarFunction::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssVarFunction {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssVarFunction,
+        crate::css::value::var_function::FormatCssVarFunction,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::var_function::FormatCssVarFunction::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssParameter>
+    for crate::css::value::parameter::FormatCssParameter
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssParameter, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssParameter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssParameter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssParameter,
+        crate::css::value::parameter::FormatCssParameter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::parameter::FormatCssParameter::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssParameter {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssParameter,
+        crate::css::value::parameter::FormatCssParameter,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::parameter::FormatCssParameter::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssVarFunctionValue>
+    for crate::css::value::var_function_value::FormatCssVarFunctionValue
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssVarFunctionValue,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssVarFunctionValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssVarFunctionValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssVarFunctionValue,
+        crate::css::value::var_function_value::FormatCssVarFunctionValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::var_function_value::FormatCssVarFunctionValue::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssVarFunctionValue {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssVarFunctionValue,
+        crate::css::value::var_function_value::FormatCssVarFunctionValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::var_function_value::FormatCssVarFunctionValue::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssUrlValueRaw>
+    for crate::css::value::url_value_raw::FormatCssUrlValueRaw
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssUrlValueRaw,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<rome_css_syntax::CssUrlValueRaw>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssUrlValueRaw {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssUrlValueRaw,
+        crate::css::value::url_value_raw::FormatCssUrlValueRaw,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::value::url_value_raw::FormatCssUrlValueRaw::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssUrlValueRaw {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssUrlValueRaw,
+        crate::css::value::url_value_raw::FormatCssUrlValueRaw,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::value::url_value_raw::FormatCssUrlValueRaw::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAnySelectorPatternList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAnySelectorPatternList,
+        crate::css::lists::any_selector_pattern_list::FormatCssAnySelectorPatternList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::any_selector_pattern_list::FormatCssAnySelectorPatternList::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAnySelectorPatternList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAnySelectorPatternList,
+        crate::css::lists::any_selector_pattern_list::FormatCssAnySelectorPatternList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::any_selector_pattern_list::FormatCssAnySelectorPatternList::default(
+            ),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtKeyframesItemList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtKeyframesItemList,
+        crate::css::lists::at_keyframes_item_list::FormatCssAtKeyframesItemList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::at_keyframes_item_list::FormatCssAtKeyframesItemList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtKeyframesItemList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtKeyframesItemList,
+        crate::css::lists::at_keyframes_item_list::FormatCssAtKeyframesItemList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::at_keyframes_item_list::FormatCssAtKeyframesItemList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryConsequentList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryConsequentList,
+        crate::css::lists::at_media_query_consequent_list::FormatCssAtMediaQueryConsequentList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: lists :: at_media_query_consequent_list :: FormatCssAtMediaQueryConsequentList :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryConsequentList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryConsequentList,
+        crate::css::lists::at_media_query_consequent_list::FormatCssAtMediaQueryConsequentList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: lists :: at_media_query_consequent_list :: FormatCssAtMediaQueryConsequentList :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssAtMediaQueryList,
+        crate::css::lists::at_media_query_list::FormatCssAtMediaQueryList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::at_media_query_list::FormatCssAtMediaQueryList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssAtMediaQueryList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssAtMediaQueryList,
+        crate::css::lists::at_media_query_list::FormatCssAtMediaQueryList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::at_media_query_list::FormatCssAtMediaQueryList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssBlockItemList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssBlockItemList,
+        crate::css::lists::block_item_list::FormatCssBlockItemList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::block_item_list::FormatCssBlockItemList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssBlockItemList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssBlockItemList,
+        crate::css::lists::block_item_list::FormatCssBlockItemList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::block_item_list::FormatCssBlockItemList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssComponentValueList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssComponentValueList,
+        crate::css::lists::component_value_list::FormatCssComponentValueList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::component_value_list::FormatCssComponentValueList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssComponentValueList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssComponentValueList,
+        crate::css::lists::component_value_list::FormatCssComponentValueList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::component_value_list::FormatCssComponentValueList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssKeyframesSelectorList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssKeyframesSelectorList,
+        crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssKeyframesSelectorList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssKeyframesSelectorList,
+        crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::keyframes_selector_list::FormatCssKeyframesSelectorList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssParameterList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssParameterList,
+        crate::css::lists::parameter_list::FormatCssParameterList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::parameter_list::FormatCssParameterList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssParameterList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssParameterList,
+        crate::css::lists::parameter_list::FormatCssParameterList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::parameter_list::FormatCssParameterList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssRuleList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssRuleList,
+        crate::css::lists::rule_list::FormatCssRuleList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::rule_list::FormatCssRuleList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssRuleList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssRuleList,
+        crate::css::lists::rule_list::FormatCssRuleList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::rule_list::FormatCssRuleList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssSelectorList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssSelectorList,
+        crate::css::lists::selector_list::FormatCssSelectorList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::lists::selector_list::FormatCssSelectorList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssSelectorList {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssSelectorList,
+        crate::css::lists::selector_list::FormatCssSelectorList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::lists::selector_list::FormatCssSelectorList::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssBogus> for crate::css::bogus::bogus::FormatCssBogus {
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssBogus, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatBogusNodeRule::<rome_css_syntax::CssBogus>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssBogus {
+    type Format<'a> =
+        FormatRefWithRule<'a, rome_css_syntax::CssBogus, crate::css::bogus::bogus::FormatCssBogus>;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, crate::css::bogus::bogus::FormatCssBogus::default())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssBogus {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::CssBogus, crate::css::bogus::bogus::FormatCssBogus>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::bogus::bogus::FormatCssBogus::default())
+    }
+}
+impl FormatRule<rome_css_syntax::CssBogusRule>
+    for crate::css::bogus::bogus_rule::FormatCssBogusRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssBogusRule, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatBogusNodeRule::<rome_css_syntax::CssBogusRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssBogusRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssBogusRule,
+        crate::css::bogus::bogus_rule::FormatCssBogusRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::bogus::bogus_rule::FormatCssBogusRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssBogusRule {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssBogusRule,
+        crate::css::bogus::bogus_rule::FormatCssBogusRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::bogus::bogus_rule::FormatCssBogusRule::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssBogusSelector>
+    for crate::css::bogus::bogus_selector::FormatCssBogusSelector
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &rome_css_syntax::CssBogusSelector,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<rome_css_syntax::CssBogusSelector>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssBogusSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssBogusSelector,
+        crate::css::bogus::bogus_selector::FormatCssBogusSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::bogus::bogus_selector::FormatCssBogusSelector::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssBogusSelector {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssBogusSelector,
+        crate::css::bogus::bogus_selector::FormatCssBogusSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::bogus::bogus_selector::FormatCssBogusSelector::default(),
+        )
+    }
+}
+impl FormatRule<rome_css_syntax::CssBogusValue>
+    for crate::css::bogus::bogus_value::FormatCssBogusValue
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &rome_css_syntax::CssBogusValue, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatBogusNodeRule::<rome_css_syntax::CssBogusValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::CssBogusValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::CssBogusValue,
+        crate::css::bogus::bogus_value::FormatCssBogusValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::bogus::bogus_value::FormatCssBogusValue::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::CssBogusValue {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::CssBogusValue,
+        crate::css::bogus::bogus_value::FormatCssBogusValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::bogus::bogus_value::FormatCssBogusValue::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssRule {
+    type Format<'a> =
+        FormatRefWithRule<'a, rome_css_syntax::AnyCssRule, crate::css::any::rule::FormatAnyCssRule>;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, crate::css::any::rule::FormatAnyCssRule::default())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssRule {
+    type Format =
+        FormatOwnedWithRule<rome_css_syntax::AnyCssRule, crate::css::any::rule::FormatAnyCssRule>;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::any::rule::FormatAnyCssRule::default())
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssAtRule,
+        crate::css::any::at_rule::FormatAnyCssAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::at_rule::FormatAnyCssAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssAtRule {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssAtRule,
+        crate::css::any::at_rule::FormatAnyCssAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::at_rule::FormatAnyCssAtRule::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssSelector,
+        crate::css::any::selector::FormatAnyCssSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::selector::FormatAnyCssSelector::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssSelector {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssSelector,
+        crate::css::any::selector::FormatAnyCssSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::selector::FormatAnyCssSelector::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssSelectorPattern {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssSelectorPattern,
+        crate::css::any::selector_pattern::FormatAnyCssSelectorPattern,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::selector_pattern::FormatAnyCssSelectorPattern::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssSelectorPattern {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssSelectorPattern,
+        crate::css::any::selector_pattern::FormatAnyCssSelectorPattern,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::selector_pattern::FormatAnyCssSelectorPattern::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssAttributeMatcherValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssAttributeMatcherValue,
+        crate::css::any::attribute_matcher_value::FormatAnyCssAttributeMatcherValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::attribute_matcher_value::FormatAnyCssAttributeMatcherValue::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssAttributeMatcherValue {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssAttributeMatcherValue,
+        crate::css::any::attribute_matcher_value::FormatAnyCssAttributeMatcherValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::attribute_matcher_value::FormatAnyCssAttributeMatcherValue::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssPseudoParameters {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssPseudoParameters,
+        crate::css::any::pseudo_parameters::FormatAnyCssPseudoParameters,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::pseudo_parameters::FormatAnyCssPseudoParameters::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssPseudoParameters {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssPseudoParameters,
+        crate::css::any::pseudo_parameters::FormatAnyCssPseudoParameters,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::pseudo_parameters::FormatAnyCssPseudoParameters::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssBlockItem {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssBlockItem,
+        crate::css::any::block_item::FormatAnyCssBlockItem,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::block_item::FormatAnyCssBlockItem::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssBlockItem {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssBlockItem,
+        crate::css::any::block_item::FormatAnyCssBlockItem,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::block_item::FormatAnyCssBlockItem::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssDeclarationName {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssDeclarationName,
+        crate::css::any::declaration_name::FormatAnyCssDeclarationName,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::declaration_name::FormatAnyCssDeclarationName::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssDeclarationName {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssDeclarationName,
+        crate::css::any::declaration_name::FormatAnyCssDeclarationName,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::declaration_name::FormatAnyCssDeclarationName::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssImportUrl {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssImportUrl,
+        crate::css::any::import_url::FormatAnyCssImportUrl,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::import_url::FormatAnyCssImportUrl::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssImportUrl {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssImportUrl,
+        crate::css::any::import_url::FormatAnyCssImportUrl,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::import_url::FormatAnyCssImportUrl::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssKeyframesName {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssKeyframesName,
+        crate::css::any::keyframes_name::FormatAnyCssKeyframesName,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::keyframes_name::FormatAnyCssKeyframesName::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssKeyframesName {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssKeyframesName,
+        crate::css::any::keyframes_name::FormatAnyCssKeyframesName,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::keyframes_name::FormatAnyCssKeyframesName::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssKeyframesSelector {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssKeyframesSelector,
+        crate::css::any::keyframes_selector::FormatAnyCssKeyframesSelector,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::keyframes_selector::FormatAnyCssKeyframesSelector::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssKeyframesSelector {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssKeyframesSelector,
+        crate::css::any::keyframes_selector::FormatAnyCssKeyframesSelector,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::keyframes_selector::FormatAnyCssKeyframesSelector::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssAtMediaQueryType {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssAtMediaQueryType,
+        crate::css::any::at_media_query_type::FormatAnyCssAtMediaQueryType,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::at_media_query_type::FormatAnyCssAtMediaQueryType::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssAtMediaQueryType {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssAtMediaQueryType,
+        crate::css::any::at_media_query_type::FormatAnyCssAtMediaQueryType,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::at_media_query_type::FormatAnyCssAtMediaQueryType::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssAtMediaQueryFeatureType {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssAtMediaQueryFeatureType,
+        crate::css::any::at_media_query_feature_type::FormatAnyCssAtMediaQueryFeatureType,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: css :: any :: at_media_query_feature_type :: FormatAnyCssAtMediaQueryFeatureType :: default ())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssAtMediaQueryFeatureType {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssAtMediaQueryFeatureType,
+        crate::css::any::at_media_query_feature_type::FormatAnyCssAtMediaQueryFeatureType,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: css :: any :: at_media_query_feature_type :: FormatAnyCssAtMediaQueryFeatureType :: default ())
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssValue,
+        crate::css::any::value::FormatAnyCssValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, crate::css::any::value::FormatAnyCssValue::default())
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssValue {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssValue,
+        crate::css::any::value::FormatAnyCssValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::css::any::value::FormatAnyCssValue::default())
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssFunction {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssFunction,
+        crate::css::any::function::FormatAnyCssFunction,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::function::FormatAnyCssFunction::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssFunction {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssFunction,
+        crate::css::any::function::FormatAnyCssFunction,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::function::FormatAnyCssFunction::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for rome_css_syntax::AnyCssUrlValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        rome_css_syntax::AnyCssUrlValue,
+        crate::css::any::url_value::FormatAnyCssUrlValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::css::any::url_value::FormatAnyCssUrlValue::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for rome_css_syntax::AnyCssUrlValue {
+    type Format = FormatOwnedWithRule<
+        rome_css_syntax::AnyCssUrlValue,
+        crate::css::any::url_value::FormatAnyCssUrlValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::css::any::url_value::FormatAnyCssUrlValue::default(),
+        )
+    }
+}
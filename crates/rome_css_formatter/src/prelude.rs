@@ -0,0 +1,10 @@
+//! This module provides important and useful traits to help to format tokens and nodes
+//! when implementing the [crate::FormatNodeRule] trait.
+
+#[allow(unused_imports)]
+pub(crate) use crate::{
+    AsFormat, CssFormatContext, CssFormatter, FormatNodeRule, FormattedIterExt as _, IntoFormat,
+};
+pub(crate) use rome_formatter::prelude::*;
+#[allow(unused_imports)]
+pub(crate) use rome_rowan::{AstNode as _, AstNodeList as _, AstSeparatedList as _};
@@ -0,0 +1,107 @@
+use rome_css_formatter::context::{CssFormatContext, CssFormatOptions};
+use rome_css_formatter::{format_node, format_range, CssFormatLanguage};
+use rome_css_parser::{parse_css, CssParserOptions};
+use rome_css_syntax::{CssFileSource, CssLanguage};
+use rome_formatter::{FormatContext, FormatResult, Formatted, IndentStyle, LineWidth, Printed};
+use rome_formatter_test::TestFormatLanguage;
+use rome_parser::AnyParse;
+use rome_rowan::{FileSource, SyntaxNode, TextRange};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default)]
+pub struct CssTestFormatLanguage {
+    source_type: CssFileSource,
+}
+
+impl TestFormatLanguage for CssTestFormatLanguage {
+    type SyntaxLanguage = CssLanguage;
+    type Options = CssFormatOptions;
+    type Context = CssFormatContext;
+    type FormatLanguage = CssFormatLanguage;
+
+    fn parse(&self, text: &str) -> AnyParse {
+        let parse = parse_css(text, CssParserOptions::default());
+
+        AnyParse::new(
+            parse.syntax().as_send().unwrap(),
+            parse.into_diagnostics(),
+            self.source_type.as_any_file_source(),
+        )
+    }
+
+    fn deserialize_format_options(
+        &self,
+        options: &str,
+    ) -> Vec<<Self::Context as FormatContext>::Options> {
+        let test_options: TestOptions = serde_json::from_str(options).unwrap();
+
+        test_options
+            .cases
+            .into_iter()
+            .map(|case| case.into())
+            .collect()
+    }
+
+    fn format_node(
+        &self,
+        options: Self::Options,
+        node: &SyntaxNode<Self::SyntaxLanguage>,
+    ) -> FormatResult<Formatted<Self::Context>> {
+        format_node(options, node)
+    }
+
+    fn format_range(
+        &self,
+        options: Self::Options,
+        node: &SyntaxNode<Self::SyntaxLanguage>,
+        range: TextRange,
+    ) -> FormatResult<Printed> {
+        format_range(options, node, range)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum CssSerializableIndentStyle {
+    /// Tab
+    Tab,
+    /// Space, with its quantity
+    Space(u8),
+}
+
+impl From<CssSerializableIndentStyle> for IndentStyle {
+    fn from(test: CssSerializableIndentStyle) -> Self {
+        match test {
+            CssSerializableIndentStyle::Tab => IndentStyle::Tab,
+            CssSerializableIndentStyle::Space(spaces) => IndentStyle::Space(spaces),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct CssSerializableFormatOptions {
+    /// The indent style.
+    pub indent_style: Option<CssSerializableIndentStyle>,
+
+    /// What's the max width of a line. Defaults to 80.
+    pub line_width: Option<u16>,
+}
+
+impl From<CssSerializableFormatOptions> for CssFormatOptions {
+    fn from(test: CssSerializableFormatOptions) -> Self {
+        CssFormatOptions::default()
+            .with_indent_style(
+                test.indent_style
+                    .map_or_else(|| IndentStyle::Tab, |value| value.into()),
+            )
+            .with_line_width(
+                test.line_width
+                    .and_then(|width| LineWidth::try_from(width).ok())
+                    .unwrap_or_default(),
+            )
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct TestOptions {
+    cases: Vec<CssSerializableFormatOptions>,
+}
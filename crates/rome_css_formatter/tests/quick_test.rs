@@ -0,0 +1,40 @@
+use rome_css_formatter::context::CssFormatOptions;
+use rome_css_formatter::format_node;
+use rome_css_parser::{parse_css, CssParserOptions};
+use rome_formatter_test::check_reformat::CheckReformat;
+
+mod language {
+    include!("language.rs");
+}
+
+#[ignore]
+#[test]
+// use this test check if your snippet prints as you wish, without using a snapshot
+fn quick_test() {
+    let src = r#"
+/* comment */
+a,b{color:red}
+"#;
+    let parse = parse_css(src, CssParserOptions::default());
+    let options = CssFormatOptions::default();
+    let result = format_node(options.clone(), &parse.syntax())
+        .unwrap()
+        .print()
+        .unwrap();
+
+    let root = &parse.syntax();
+    let language = language::CssTestFormatLanguage::default();
+
+    let check_reformat =
+        CheckReformat::new(root, result.as_code(), "quick_test", &language, options);
+    check_reformat.check_reformat();
+
+    assert_eq!(
+        result.as_code(),
+        r#"/* comment */
+a, b {
+	color: red;
+}
+"#
+    );
+}
@@ -4,7 +4,7 @@ mod tests;
 
 use crate::CssParserOptions;
 use rome_css_syntax::{CssSyntaxKind, CssSyntaxKind::*, TextLen, TextRange, TextSize, T};
-use rome_js_unicode_table::{is_id_continue, is_id_start, lookup_byte, Dispatch::*};
+use rome_js_unicode_table::{is_id_continue, is_id_start, lookup_byte, Dispatch, Dispatch::*};
 use rome_parser::diagnostic::ParseDiagnostic;
 use std::char::REPLACEMENT_CHARACTER;
 use std::iter::FusedIterator;
@@ -14,6 +14,24 @@ pub struct Token {
     range: TextRange,
 }
 
+/// Context in which the lexer should lex the next token
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum CssLexContext {
+    /// Default context for no particular context
+    #[default]
+    Regular,
+
+    /// Applies to the value of a hex color after a `#`: `#fff`, `#0000ff`
+    ///
+    /// The lexer consumes all name code points as a single [CSS_COLOR_LITERAL] token.
+    Color,
+
+    /// Applies to the content of a `url(` function
+    ///
+    /// The lexer consumes unquoted urls as a single [CSS_URL_VALUE_RAW_LITERAL] token.
+    Url,
+}
+
 impl Token {
     pub fn kind(&self) -> CssSyntaxKind {
         self.kind
@@ -63,16 +81,48 @@ impl<'src> Lexer<'src> {
         self.diagnostics
     }
 
+    /// Returns a lexer that starts at the same position as this lexer but doesn't track diagnostics.
+    ///
+    /// Useful to look ahead of the current token without modifying the lexer's state.
+    pub(crate) fn lookahead(&self) -> Lexer<'src> {
+        Self {
+            source: self.source,
+            position: self.position,
+            diagnostics: vec![],
+            config: self.config,
+        }
+    }
+
+    /// Returns the byte offset of the next token.
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Rewinds the lexer to the given position and drops any diagnostic that was emitted after it.
+    pub(crate) fn rewind(&mut self, position: usize, diagnostics_len: usize) {
+        self.position = position;
+        self.diagnostics.truncate(diagnostics_len);
+    }
+
+    /// Returns the number of diagnostics emitted so far.
+    pub(crate) fn diagnostics_len(&self) -> usize {
+        self.diagnostics.len()
+    }
+
     /// Lexes the next token.
     ///
     /// ## Return
     /// Returns its kind.
-    pub(crate) fn next_token(&mut self) -> Option<Token> {
+    pub(crate) fn next_token(&mut self, context: CssLexContext) -> Option<Token> {
         let start = self.text_position();
 
         match self.current_byte() {
             Some(current) => {
-                let kind = self.lex_token(current);
+                let kind = match context {
+                    CssLexContext::Regular => self.lex_token(current),
+                    CssLexContext::Color => self.lex_color(current),
+                    CssLexContext::Url => self.lex_url(current),
+                };
 
                 debug_assert!(start < self.text_position(), "Lexer did not progress");
                 Some(Token {
@@ -101,6 +151,16 @@ impl<'src> Lexer<'src> {
         tok
     }
 
+    /// Bumps the current byte and returns `eq_tok` if the next byte is a `=`, otherwise returns `tok`.
+    fn eat_byte_or_eq(&mut self, tok: CssSyntaxKind, eq_tok: CssSyntaxKind) -> CssSyntaxKind {
+        if self.peek_byte() == Some(b'=') {
+            self.advance(2);
+            eq_tok
+        } else {
+            self.eat_byte(tok)
+        }
+    }
+
     /// Consume just one newline/line break.
     ///
     /// ## Safety
@@ -310,7 +370,7 @@ impl<'src> Lexer<'src> {
             QOT => self.lex_string_literal(current),
             SLH => self.lex_slash(),
 
-            DIG => self.lex_number(current),
+            DIG | ZER => self.lex_number(current),
 
             MIN => {
                 if self.is_number_start() {
@@ -329,7 +389,7 @@ impl<'src> Lexer<'src> {
                         self.advance(2);
                         self.lex_identifier();
 
-                        return CSS_CUSTOM_PROPERTY;
+                        return CSS_CUSTOM_PROPERTY_LITERAL;
                     }
                 }
 
@@ -369,13 +429,40 @@ impl<'src> Lexer<'src> {
                     return CDO;
                 }
 
-                self.eat_byte(T![<])
+                self.eat_byte_or_eq(T![<], T![<=])
+            }
+
+            // `$=` has to be checked before identifiers because `$` is an identifier start in the
+            // unicode table shared with the JavaScript lexer.
+            IDT if current == b'$' => {
+                if self.peek_byte() == Some(b'=') {
+                    self.advance(2);
+                    T!["$="]
+                } else {
+                    self.eat_unexpected_character()
+                }
             }
 
             IDT | UNI | BSL if self.is_ident_start() => self.lex_identifier(),
 
-            MUL => self.eat_byte(T![*]),
-            COL => self.eat_byte(T![:]),
+            MUL => self.eat_byte_or_eq(T![*], T![*=]),
+            TLD => self.eat_byte_or_eq(T![~], T![~=]),
+            PIP => self.eat_byte_or_eq(T![|], T![|=]),
+            CRT => self.eat_byte_or_eq(T![^], T![^=]),
+            MOR => self.eat_byte_or_eq(T![>], T![>=]),
+            EQL => self.eat_byte(T![=]),
+            EXL => self.eat_byte(T![!]),
+            PRC => self.eat_byte(T![%]),
+            Dispatch::AMP => self.eat_byte(T![&]),
+            SEM => self.eat_byte(T![;]),
+            COL => {
+                if self.peek_byte() == Some(b':') {
+                    self.advance(2);
+                    T![::]
+                } else {
+                    self.eat_byte(T![:])
+                }
+            }
             AT_ => self.eat_byte(T![@]),
             HAS => self.eat_byte(T![#]),
             PNO => self.eat_byte(T!['(']),
@@ -585,6 +672,9 @@ impl<'src> Lexer<'src> {
             b"from" => FROM_KW,
             b"to" => TO_KW,
             b"var" => VAR_KW,
+            b"url" => URL_KW,
+            b"charset" => CHARSET_KW,
+            b"import" => IMPORT_KW,
             _ => IDENT,
         }
     }
@@ -623,6 +713,8 @@ impl<'src> Lexer<'src> {
                 }
                 // SAFETY: We know that the current byte is a number and we can use cast.
                 DIG | ZER if !is_first => current as char,
+                // U+002D HYPHEN-MINUS (-) is a name code point
+                MIN if !is_first => '-',
                 // U+005C REVERSE SOLIDUS (\)
                 // If the first and second code points are a valid escape, continue consume.
                 // Otherwise, break.
@@ -642,6 +734,62 @@ impl<'src> Lexer<'src> {
         idx
     }
 
+    /// Lexes the value of a hex color, the part after the `#`.
+    fn lex_color(&mut self, current: u8) -> CssSyntaxKind {
+        if !self.is_name_code_point(current) {
+            return self.lex_token(current);
+        }
+
+        while let Some(current) = self.current_byte() {
+            if !self.is_name_code_point(current) {
+                break;
+            }
+
+            self.advance_byte_or_char(current);
+        }
+
+        CSS_COLOR_LITERAL
+    }
+
+    /// Lexes the content of a `url(` function.
+    ///
+    /// Quoted urls are lexed as regular strings. Unquoted urls span up to the next whitespace or `)`.
+    fn lex_url(&mut self, current: u8) -> CssSyntaxKind {
+        match lookup_byte(current) {
+            WHS | QOT | PNC => self.lex_token(current),
+            _ => {
+                while let Some(current) = self.current_byte() {
+                    match lookup_byte(current) {
+                        WHS | PNC => break,
+                        QOT | PNO => {
+                            let start = self.text_position();
+                            self.advance(1);
+                            self.diagnostics.push(
+                                ParseDiagnostic::new(
+                                    "Unexpected character in an unquoted url",
+                                    start..self.text_position(),
+                                )
+                                .hint("Wrap the url in quotes."),
+                            );
+                        }
+                        _ => self.advance_byte_or_char(current),
+                    }
+                }
+
+                CSS_URL_VALUE_RAW_LITERAL
+            }
+        }
+    }
+
+    /// Returns `true` if the byte is the start of a name code point: a letter, digit, `_`, `-` or a non ASCII char.
+    fn is_name_code_point(&self, current: u8) -> bool {
+        match lookup_byte(current) {
+            IDT => current != b'$',
+            DIG | ZER | MIN | UNI => true,
+            _ => false,
+        }
+    }
+
     /// Lexes a comment.
     fn lex_slash(&mut self) -> CssSyntaxKind {
         let start = self.text_position();
@@ -698,7 +846,7 @@ impl<'src> Lexer<'src> {
 
                 COMMENT
             }
-            _ => self.eat_unexpected_character(),
+            _ => self.eat_byte(T![/]),
         }
     }
 
@@ -792,7 +940,7 @@ impl Iterator for Lexer<'_> {
     type Item = Token;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.next_token()
+        self.next_token(CssLexContext::Regular)
     }
 }
 
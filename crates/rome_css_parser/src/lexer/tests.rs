@@ -356,19 +356,19 @@ fn identifier() {
 
     assert_lex! {
         "--property",
-        CSS_CUSTOM_PROPERTY:10,
+        CSS_CUSTOM_PROPERTY_LITERAL:10,
         EOF:0
     }
 
     assert_lex! {
         r#"--prop\eerty"#,
-        CSS_CUSTOM_PROPERTY:12,
+        CSS_CUSTOM_PROPERTY_LITERAL:12,
         EOF:0
     }
 
     assert_lex! {
         r#"--\pprop\eerty"#,
-        CSS_CUSTOM_PROPERTY:14,
+        CSS_CUSTOM_PROPERTY_LITERAL:14,
         EOF:0
     }
 }
@@ -1,10 +1,10 @@
-use crate::token_source::CssTokenSource;
+use crate::token_source::{CssTokenSource, TokenSourceCheckpoint};
 use rome_css_syntax::CssSyntaxKind;
 use rome_parser::diagnostic::merge_diagnostics;
 use rome_parser::event::Event;
 use rome_parser::prelude::*;
 use rome_parser::token_source::Trivia;
-use rome_parser::ParserContext;
+use rome_parser::{ParserContext, ParserContextCheckpoint};
 
 pub(crate) struct CssParser<'source> {
     context: ParserContext<CssSyntaxKind>,
@@ -31,6 +31,22 @@ impl<'source> CssParser<'source> {
         }
     }
 
+    /// Creates a checkpoint to which the parser can be rewound.
+    pub fn checkpoint(&self) -> CssParserCheckpoint {
+        CssParserCheckpoint {
+            context: self.context.checkpoint(),
+            source: self.source.checkpoint(),
+        }
+    }
+
+    /// Rewinds the parser, including the events, diagnostics and the token source, to a previous checkpoint.
+    pub fn rewind(&mut self, checkpoint: CssParserCheckpoint) {
+        let CssParserCheckpoint { context, source } = checkpoint;
+
+        self.context.rewind(context);
+        self.source.rewind(source);
+    }
+
     pub fn finish(self) -> (Vec<Event<CssSyntaxKind>>, Vec<ParseDiagnostic>, Vec<Trivia>) {
         let (trivia, lexer_diagnostics) = self.source.finish();
         let (events, parse_diagnostics) = self.context.finish();
@@ -61,3 +77,8 @@ impl<'source> Parser for CssParser<'source> {
         &mut self.source
     }
 }
+
+pub(crate) struct CssParserCheckpoint {
+    context: ParserContextCheckpoint,
+    source: TokenSourceCheckpoint,
+}
@@ -0,0 +1,142 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{
+    expected_block, expected_keyframes_name, expected_keyframes_selector,
+};
+use crate::syntax::{parse_block, parse_regular_identifier, parse_string};
+use rome_css_syntax::CssSyntaxKind::*;
+use rome_css_syntax::{CssSyntaxKind, T};
+use rome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use rome_parser::parse_recovery::{ParseRecovery, RecoveryResult};
+use rome_parser::prelude::ParsedSyntax;
+use rome_parser::prelude::ParsedSyntax::{Absent, Present};
+use rome_parser::{token_set, Parser, TokenSet};
+
+const KEYFRAMES_SELECTOR_START: TokenSet<CssSyntaxKind> =
+    token_set![T![from], T![to], CSS_NUMBER_LITERAL];
+
+/// Parses a `@keyframes` rule: `@keyframes fade { from { opacity: 0; } to { opacity: 1; } }`
+pub(crate) fn parse_keyframes(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+
+    p.bump(T![@]);
+    p.bump(T![keyframes]);
+
+    if p.at(CSS_STRING_LITERAL) {
+        parse_string(p).ok();
+    } else {
+        parse_regular_identifier(p).or_add_diagnostic(p, expected_keyframes_name);
+    }
+
+    parse_keyframes_body(p).or_add_diagnostic(p, expected_block);
+
+    Present(m.complete(p, CSS_AT_KEYFRAMES))
+}
+
+fn parse_keyframes_body(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['{']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['{']);
+
+    KeyframesItemList.parse_list(p);
+
+    p.expect(T!['}']);
+
+    Present(m.complete(p, CSS_AT_KEYFRAMES_BODY))
+}
+
+struct KeyframesItemList;
+
+impl ParseNodeList for KeyframesItemList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+
+    const LIST_KIND: CssSyntaxKind = CSS_AT_KEYFRAMES_ITEM_LIST;
+
+    fn parse_element(&mut self, p: &mut CssParser) -> ParsedSyntax {
+        parse_keyframes_block(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut CssParser) -> bool {
+        p.at(T!['}'])
+    }
+
+    fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
+        parsed_element.or_recover(
+            p,
+            &ParseRecovery::new(
+                CSS_BOGUS,
+                KEYFRAMES_SELECTOR_START.union(token_set![T!['}']]),
+            ),
+            expected_keyframes_selector,
+        )
+    }
+}
+
+/// Parses a single keyframe: `from, 50% { opacity: 0; }`
+fn parse_keyframes_block(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at_ts(KEYFRAMES_SELECTOR_START) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    KeyframesSelectorList.parse_list(p);
+    parse_block(p).or_add_diagnostic(p, expected_block);
+
+    Present(m.complete(p, CSS_KEYFRAMES_BLOCK))
+}
+
+struct KeyframesSelectorList;
+
+impl ParseSeparatedList for KeyframesSelectorList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+
+    const LIST_KIND: CssSyntaxKind = CSS_KEYFRAMES_SELECTOR_LIST;
+
+    fn parse_element(&mut self, p: &mut CssParser) -> ParsedSyntax {
+        parse_keyframes_selector(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut CssParser) -> bool {
+        p.at(T!['{'])
+    }
+
+    fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
+        parsed_element.or_recover(
+            p,
+            &ParseRecovery::new(CSS_BOGUS, token_set![T![,], T!['{'], T!['}']]),
+            expected_keyframes_selector,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> CssSyntaxKind {
+        T![,]
+    }
+}
+
+/// Parses `from`, `to` or a percentage: `50%`
+fn parse_keyframes_selector(p: &mut CssParser) -> ParsedSyntax {
+    match p.cur() {
+        T![from] | T![to] => {
+            let m = p.start();
+            p.bump_any();
+            Present(m.complete(p, CSS_KEYFRAMES_SELECTOR))
+        }
+        CSS_NUMBER_LITERAL => {
+            let m = p.start();
+
+            let number = p.start();
+            p.bump(CSS_NUMBER_LITERAL);
+            number.complete(p, CSS_NUMBER);
+
+            p.expect(T![%]);
+
+            Present(m.complete(p, CSS_PERCENTAGE))
+        }
+        _ => Absent,
+    }
+}
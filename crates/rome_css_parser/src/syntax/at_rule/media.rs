@@ -0,0 +1,192 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{expected_block, expected_media_query, expected_value};
+use crate::syntax::value::{parse_any_value, parse_numeric_value_with_ratio};
+use crate::syntax::{is_at_identifier, parse_block, parse_regular_identifier};
+use rome_css_syntax::CssSyntaxKind::*;
+use rome_css_syntax::{CssSyntaxKind, T};
+use rome_parser::parse_lists::ParseSeparatedList;
+use rome_parser::parse_recovery::{ParseRecovery, RecoveryResult};
+use rome_parser::prelude::ParsedSyntax;
+use rome_parser::prelude::ParsedSyntax::{Absent, Present};
+use rome_parser::{token_set, Parser, TokenSet};
+
+const MEDIA_QUERY_RANGES: TokenSet<CssSyntaxKind> = token_set![T![>], T![<], T![>=], T![<=], T![=]];
+
+const MEDIA_FEATURE_RECOVERY_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![')'], T!['{'], T!['}'], T![;]];
+
+/// Parses a `@media` rule: `@media screen and (min-width: 800px) {}`
+pub(crate) fn parse_media(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+
+    p.bump(T![@]);
+    p.bump(T![media]);
+
+    MediaQueryList::new(T!['{']).parse_list(p);
+
+    parse_block(p).or_add_diagnostic(p, expected_block);
+
+    Present(m.complete(p, CSS_AT_MEDIA))
+}
+
+/// The comma separated media queries of a `@media` or `@import` rule.
+pub(crate) struct MediaQueryList {
+    end_kind: CssSyntaxKind,
+}
+
+impl MediaQueryList {
+    pub(crate) fn new(end_kind: CssSyntaxKind) -> Self {
+        Self { end_kind }
+    }
+}
+
+impl ParseSeparatedList for MediaQueryList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+
+    const LIST_KIND: CssSyntaxKind = CSS_AT_MEDIA_QUERY_LIST;
+
+    fn parse_element(&mut self, p: &mut CssParser) -> ParsedSyntax {
+        parse_media_query(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut CssParser) -> bool {
+        p.at(self.end_kind)
+    }
+
+    fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
+        parsed_element.or_recover(
+            p,
+            &ParseRecovery::new(
+                CSS_BOGUS,
+                token_set![T![,], T!['{'], T!['}'], T![;]].union(token_set![self.end_kind]),
+            ),
+            expected_media_query,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> CssSyntaxKind {
+        T![,]
+    }
+}
+
+#[inline]
+fn is_at_media_query_type(p: &mut CssParser) -> bool {
+    p.at(T!['(']) || is_at_identifier(p)
+}
+
+/// Parses a single media query: `only screen and (color)`
+fn parse_media_query(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_media_query_type(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    // `not` and `only` are only conditions if they are followed by the media type: `not print`
+    if p.at_ts(token_set![T![not], T![only]]) && (p.nth_at(1, T!['(']) || p.nth_at(1, IDENT)) {
+        p.bump_any();
+    }
+
+    parse_media_query_type(p).or_add_diagnostic(p, expected_media_query);
+
+    let consequents = p.start();
+    while p.at_ts(token_set![T![and], T![or]]) {
+        let consequent = p.start();
+        p.bump_any();
+        p.eat(T![not]);
+        parse_media_query_type(p).or_add_diagnostic(p, expected_media_query);
+        consequent.complete(p, CSS_AT_MEDIA_QUERY_CONSEQUENT);
+    }
+    consequents.complete(p, CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST);
+
+    Present(m.complete(p, CSS_AT_MEDIA_QUERY))
+}
+
+/// Parses a media type (`screen`) or a media feature (`(min-width: 800px)`)
+fn parse_media_query_type(p: &mut CssParser) -> ParsedSyntax {
+    if p.at(T!['(']) {
+        parse_media_feature(p)
+    } else {
+        parse_regular_identifier(p)
+    }
+}
+
+fn parse_media_feature(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+    p.bump(T!['(']);
+
+    parse_media_feature_type(p)
+        .or_recover(
+            p,
+            &ParseRecovery::new(CSS_BOGUS, MEDIA_FEATURE_RECOVERY_SET),
+            |p, range| p.err_builder("Expected a media feature", range),
+        )
+        .ok();
+
+    p.expect(T![')']);
+
+    Present(m.complete(p, CSS_AT_MEDIA_QUERY_FEATURE))
+}
+
+fn parse_media_feature_type(p: &mut CssParser) -> ParsedSyntax {
+    if is_at_identifier(p) {
+        let m = p.start();
+        parse_regular_identifier(p).ok();
+
+        let kind = if p.eat(T![:]) {
+            parse_media_value(p).or_add_diagnostic(p, expected_value);
+            CSS_AT_MEDIA_QUERY_FEATURE_PLAIN
+        } else if p.at_ts(MEDIA_QUERY_RANGES) {
+            parse_media_query_range(p);
+            parse_media_value(p).or_add_diagnostic(p, expected_value);
+            CSS_AT_MEDIA_QUERY_FEATURE_COMPARE
+        } else {
+            CSS_AT_MEDIA_QUERY_FEATURE_BOOLEAN
+        };
+
+        Present(m.complete(p, kind))
+    } else {
+        let m = match parse_media_value(p) {
+            Present(value) => value.precede(p),
+            Absent => return Absent,
+        };
+
+        if p.at_ts(MEDIA_QUERY_RANGES) {
+            parse_media_query_range(p);
+        } else {
+            p.error(p.err_builder("Expected a range operator", p.cur_range()));
+        }
+
+        parse_regular_identifier(p).or_add_diagnostic(p, |p, range| {
+            p.err_builder("Expected the name of the media feature", range)
+        });
+
+        let kind = if p.at_ts(MEDIA_QUERY_RANGES) {
+            parse_media_query_range(p);
+            parse_media_value(p).or_add_diagnostic(p, expected_value);
+            CSS_AT_MEDIA_QUERY_FEATURE_RANGE
+        } else {
+            CSS_AT_MEDIA_QUERY_FEATURE_REVERSE_COMPARE
+        };
+
+        Present(m.complete(p, kind))
+    }
+}
+
+fn parse_media_query_range(p: &mut CssParser) {
+    let m = p.start();
+    p.bump_any();
+    m.complete(p, CSS_AT_MEDIA_QUERY_RANGE);
+}
+
+/// Parses the value of a media feature where a ratio is allowed: `(aspect-ratio: 16/9)`
+fn parse_media_value(p: &mut CssParser) -> ParsedSyntax {
+    if p.at(CSS_NUMBER_LITERAL) {
+        parse_numeric_value_with_ratio(p, true)
+    } else if p.at_ts(MEDIA_FEATURE_RECOVERY_SET.union(MEDIA_QUERY_RANGES)) {
+        Absent
+    } else {
+        parse_any_value(p)
+    }
+}
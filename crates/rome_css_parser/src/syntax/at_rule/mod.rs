@@ -0,0 +1,109 @@
+mod keyframes;
+mod media;
+
+use crate::parser::CssParser;
+use crate::syntax::at_rule::keyframes::parse_keyframes;
+use crate::syntax::at_rule::media::{parse_media, MediaQueryList};
+use crate::syntax::parse_block;
+use crate::syntax::parse_error::{expected_identifier, expected_string, expected_url};
+use crate::syntax::value::{parse_url_function, ComponentValueList};
+use crate::syntax::{parse_regular_identifier, parse_string};
+use rome_css_syntax::CssSyntaxKind::*;
+use rome_css_syntax::{CssSyntaxKind, T};
+use rome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use rome_parser::parse_recovery::ParseRecovery;
+use rome_parser::prelude::ParsedSyntax;
+use rome_parser::prelude::ParsedSyntax::{Absent, Present};
+use rome_parser::{token_set, Parser, TokenSet};
+
+/// Tokens that end the prelude of an at-rule
+const AT_RULE_PRELUDE_END: TokenSet<CssSyntaxKind> = token_set![T![;], T!['{'], T!['}']];
+
+#[inline]
+pub(crate) fn is_at_at_rule(p: &mut CssParser) -> bool {
+    p.at(T![@])
+}
+
+/// Parses an at-rule: `@media screen {}`, `@import "theme.css";`
+pub(crate) fn parse_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_at_rule(p) {
+        return Absent;
+    }
+
+    match p.nth(1) {
+        T![charset] => parse_charset(p),
+        T![import] => parse_import(p),
+        T![media] => parse_media(p),
+        T![keyframes] => parse_keyframes(p),
+        _ => parse_generic(p),
+    }
+}
+
+/// Parses a `@charset` rule: `@charset "utf-8";`
+fn parse_charset(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+
+    p.bump(T![@]);
+    p.bump(T![charset]);
+
+    parse_string(p)
+        .or_recover(
+            p,
+            &ParseRecovery::new(CSS_BOGUS, AT_RULE_PRELUDE_END).enable_recovery_on_line_break(),
+            expected_string,
+        )
+        .ok();
+
+    p.expect(T![;]);
+
+    Present(m.complete(p, CSS_AT_CHARSET))
+}
+
+/// Parses an `@import` rule: `@import url("theme.css") screen;`
+fn parse_import(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+
+    p.bump(T![@]);
+    p.bump(T![import]);
+
+    if p.at(CSS_STRING_LITERAL) {
+        parse_string(p).ok();
+    } else {
+        parse_url_function(p)
+            .or_recover(
+                p,
+                &ParseRecovery::new(CSS_BOGUS, AT_RULE_PRELUDE_END).enable_recovery_on_line_break(),
+                expected_url,
+            )
+            .ok();
+    }
+
+    MediaQueryList::new(T![;]).parse_list(p);
+
+    p.expect(T![;]);
+
+    Present(m.complete(p, CSS_AT_IMPORT))
+}
+
+/// Parses any at-rule that has no dedicated syntax: `@font-face {}`, `@layer base;`
+fn parse_generic(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+
+    p.bump(T![@]);
+
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+
+    ComponentValueList::new(AT_RULE_PRELUDE_END).parse_list(p);
+
+    if parse_block(p).is_absent() && !p.eat(T![;]) && !p.at(T!['}']) && !p.at(EOF) {
+        p.error(p.err_builder(
+            format!(
+                "Expected a block or a `;` at the end of the at-rule but instead found `{}`",
+                p.cur_text()
+            ),
+            p.cur_range(),
+        ));
+    }
+
+    Present(m.complete(p, CSS_AT_GENERIC))
+}
@@ -0,0 +1,284 @@
+mod at_rule;
+mod parse_error;
+mod selector;
+mod value;
+
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::at_rule::{is_at_at_rule, parse_at_rule};
+use crate::syntax::parse_error::{expected_block, expected_declaration_item, expected_rule};
+use crate::syntax::selector::{is_at_selector, SelectorList};
+use crate::syntax::value::ComponentValueList;
+use rome_css_syntax::CssSyntaxKind::*;
+use rome_css_syntax::{CssSyntaxKind, T};
+use rome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use rome_parser::parse_recovery::{ParseRecovery, RecoveryResult};
+use rome_parser::prelude::ParsedSyntax;
+use rome_parser::prelude::ParsedSyntax::{Absent, Present};
+use rome_parser::{token_set, Parser, TokenSet};
+
+/// The keywords emitted by the lexer. Any of them is a valid identifier outside of the position
+/// where it has a special meaning.
+const KEYWORDS: TokenSet<CssSyntaxKind> = token_set![
+    T![media],
+    T![keyframes],
+    T![not],
+    T![and],
+    T![only],
+    T![or],
+    T![i],
+    T![important],
+    T![from],
+    T![to],
+    T![var],
+    T![url],
+    T![charset],
+    T![import]
+];
+
+const IDENTIFIER_SET: TokenSet<CssSyntaxKind> = KEYWORDS.union(token_set![IDENT]);
+
+const SELECTOR_START: TokenSet<CssSyntaxKind> = IDENTIFIER_SET.union(token_set![
+    T![#],
+    T![.],
+    T![*],
+    T!['['],
+    T![:],
+    T![::],
+    T![&],
+    T![>],
+    T![+],
+    T![~]
+]);
+
+const RULE_RECOVERY_SET: TokenSet<CssSyntaxKind> = SELECTOR_START.union(token_set![T![@]]);
+
+const BLOCK_ITEM_RECOVERY_SET: TokenSet<CssSyntaxKind> =
+    RULE_RECOVERY_SET.union(token_set![T![;], T!['}'], CSS_CUSTOM_PROPERTY_LITERAL]);
+
+/// Tokens that end the value of a declaration
+const DECLARATION_VALUE_END: TokenSet<CssSyntaxKind> = token_set![T![;], T!['}'], T!['{'], T![!]];
+
+pub(crate) fn parse_root(p: &mut CssParser) {
+    let m = p.start();
+
+    RuleList.parse_list(p);
+
+    m.complete(p, CSS_ROOT);
+}
+
+/// The list of rules at the top level of a stylesheet
+struct RuleList;
+
+impl ParseNodeList for RuleList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+
+    const LIST_KIND: CssSyntaxKind = CSS_RULE_LIST;
+
+    fn parse_element(&mut self, p: &mut CssParser) -> ParsedSyntax {
+        if is_at_at_rule(p) {
+            parse_at_rule(p)
+        } else {
+            parse_rule(p)
+        }
+    }
+
+    fn is_at_list_end(&self, _p: &mut CssParser) -> bool {
+        // The root list only ends at the end of the file
+        false
+    }
+
+    fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
+        parsed_element.or_recover(
+            p,
+            &ParseRecovery::new(CSS_BOGUS_RULE, RULE_RECOVERY_SET),
+            expected_rule,
+        )
+    }
+}
+
+/// Parses a style rule: `.header, .footer { color: red; }`
+pub(crate) fn parse_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_selector(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    SelectorList::new(T!['{']).parse_list(p);
+    parse_block(p).or_add_diagnostic(p, expected_block);
+
+    Present(m.complete(p, CSS_RULE))
+}
+
+/// Parses a block containing declarations, nested rules and nested at-rules: `{ color: red; &:hover {} }`
+pub(crate) fn parse_block(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['{']) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(T!['{']);
+
+    BlockItemList.parse_list(p);
+
+    p.expect(T!['}']);
+
+    Present(m.complete(p, CSS_BLOCK))
+}
+
+struct BlockItemList;
+
+impl ParseNodeList for BlockItemList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+
+    const LIST_KIND: CssSyntaxKind = CSS_BLOCK_ITEM_LIST;
+
+    fn parse_element(&mut self, p: &mut CssParser) -> ParsedSyntax {
+        parse_block_item(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut CssParser) -> bool {
+        p.at(T!['}'])
+    }
+
+    fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
+        parsed_element.or_recover(
+            p,
+            &ParseRecovery::new(CSS_BOGUS_RULE, BLOCK_ITEM_RECOVERY_SET),
+            expected_declaration_item,
+        )
+    }
+}
+
+fn parse_block_item(p: &mut CssParser) -> ParsedSyntax {
+    match p.cur() {
+        T![;] => {
+            let m = p.start();
+            p.bump(T![;]);
+            Present(m.complete(p, CSS_EMPTY_DECLARATION))
+        }
+        CSS_CUSTOM_PROPERTY_LITERAL => parse_declaration(p),
+        _ if is_at_at_rule(p) => parse_at_rule(p),
+        _ if is_at_identifier(p) && p.nth_at(1, T![:]) && !is_at_nested_rule(p) => {
+            parse_declaration(p)
+        }
+        _ => parse_rule(p),
+    }
+}
+
+/// Returns `true` if the parser is at a nested rule rather than at a declaration.
+///
+/// Both `color: red;` and `a:hover {}` start with an identifier followed by a colon. The
+/// item is a nested rule if a `{` comes before the end of the declaration (`;` or `}`).
+fn is_at_nested_rule(p: &mut CssParser) -> bool {
+    let checkpoint = p.checkpoint();
+    let mut is_rule = false;
+
+    loop {
+        match p.cur() {
+            T!['{'] => {
+                is_rule = true;
+                break;
+            }
+            T![;] | T!['}'] | EOF => break,
+            // Unquoted urls can contain `;` or `{` that aren't tokens on their own.
+            T![url] if p.nth_at(1, T!['(']) => {
+                p.bump(T![url]);
+                p.bump_with_context(T!['('], CssLexContext::Url);
+            }
+            _ => p.bump_any(),
+        }
+    }
+
+    p.rewind(checkpoint);
+    is_rule
+}
+
+/// Parses a declaration: `color: red !important;`
+fn parse_declaration(p: &mut CssParser) -> ParsedSyntax {
+    let is_custom_property = p.at(CSS_CUSTOM_PROPERTY_LITERAL);
+
+    let m = p.start();
+
+    if is_custom_property {
+        parse_custom_property(p).ok();
+    } else {
+        parse_regular_identifier(p).ok();
+    }
+
+    p.expect(T![:]);
+
+    let mut value = ComponentValueList::new(DECLARATION_VALUE_END);
+    value.parse_list(p);
+
+    // Custom properties can have an empty value: `--empty: ;`
+    if value.is_empty() && !is_custom_property {
+        p.error(p.err_builder("Expected a value for the declaration", p.cur_range()));
+    }
+
+    if p.at(T![!]) {
+        let important = p.start();
+        p.bump(T![!]);
+        p.expect(T![important]);
+        important.complete(p, CSS_DECLARATION_IMPORTANT);
+    }
+
+    if !p.eat(T![;]) && !p.at(T!['}']) && !p.at(EOF) {
+        p.error(p.err_builder(
+            format!(
+                "Expected a `;` or `}}` at the end of the declaration but instead found `{}`",
+                p.cur_text()
+            ),
+            p.cur_range(),
+        ));
+    }
+
+    Present(m.complete(p, CSS_DECLARATION))
+}
+
+/// Returns `true` if the parser is at any identifier, including the keywords that are
+/// only reserved in specific positions.
+#[inline]
+pub(crate) fn is_at_identifier(p: &CssParser) -> bool {
+    p.at_ts(IDENTIFIER_SET)
+}
+
+/// Parses any identifier as a [CSS_IDENTIFIER], remapping keywords to [IDENT].
+pub(crate) fn parse_regular_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump_remap(IDENT);
+    Present(m.complete(p, CSS_IDENTIFIER))
+}
+
+pub(crate) fn parse_string(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(CSS_STRING_LITERAL) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(CSS_STRING_LITERAL);
+    Present(m.complete(p, CSS_STRING))
+}
+
+pub(crate) fn parse_custom_property(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(CSS_CUSTOM_PROPERTY_LITERAL) {
+        return Absent;
+    }
+
+    let m = p.start();
+    p.bump(CSS_CUSTOM_PROPERTY_LITERAL);
+    Present(m.complete(p, CSS_CUSTOM_PROPERTY))
+}
+
+/// Returns `true` if the current token directly follows the previous token, without any whitespace or comment in between.
+#[inline]
+pub(crate) fn is_adjacent_to_previous_token(p: &CssParser) -> bool {
+    p.last_end() == Some(p.cur_range().start())
+}
@@ -0,0 +1,50 @@
+//! Provides factory function to create common diagnostics for the CSS syntax
+
+use crate::parser::CssParser;
+use rome_parser::diagnostic::{expected_any, expected_node, ToDiagnostic};
+use rome_parser::prelude::ParseDiagnostic;
+use rome_rowan::TextRange;
+
+pub(crate) fn expected_rule(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["rule", "at-rule"], range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_block(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("block", range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_declaration_item(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["declaration", "rule", "at-rule"], range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_selector(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("selector", range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_identifier(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("identifier", range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_value(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("value", range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_string(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("string", range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_url(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["string", "url()"], range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_media_query(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["media type", "media feature"], range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_keyframes_name(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["identifier", "string"], range).into_diagnostic(p)
+}
+
+pub(crate) fn expected_keyframes_selector(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["from", "to", "percentage"], range).into_diagnostic(p)
+}
@@ -0,0 +1,285 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{expected_identifier, expected_selector};
+use crate::syntax::value::ComponentValueList;
+use crate::syntax::{
+    is_adjacent_to_previous_token, is_at_identifier, parse_regular_identifier, parse_string,
+};
+use rome_css_syntax::CssSyntaxKind::*;
+use rome_css_syntax::{CssSyntaxKind, T};
+use rome_parser::diagnostic::{expected_any, ToDiagnostic};
+use rome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use rome_parser::parse_recovery::{ParseRecovery, RecoveryResult};
+use rome_parser::prelude::ParsedSyntax;
+use rome_parser::prelude::ParsedSyntax::{Absent, Present};
+use rome_parser::{token_set, Parser, TokenSet};
+
+const COMBINATORS: TokenSet<CssSyntaxKind> = token_set![T![>], T![+], T![~]];
+
+const SIMPLE_SELECTOR_START: TokenSet<CssSyntaxKind> =
+    token_set![T![#], T![.], T![*], T!['['], T![:], T![::], T![&]];
+
+/// Tokens that end a selector list, even if the expected closing token is missing
+const SELECTOR_LIST_END: TokenSet<CssSyntaxKind> =
+    token_set![T!['{'], T!['}'], T![')'], T![;], T![@]];
+
+const ATTRIBUTE_MATCHERS: TokenSet<CssSyntaxKind> =
+    token_set![T![~=], T![|=], T![^=], T!["$="], T![*=], T![=]];
+
+/// Pseudo classes and pseudo elements that accept a selector list as their argument
+const SELECTOR_PSEUDO_FUNCTIONS: [&str; 9] = [
+    "not",
+    "is",
+    "where",
+    "has",
+    "matches",
+    "host",
+    "host-context",
+    "slotted",
+    "cue",
+];
+
+/// Returns `true` if the parser is at the start of a selector, including the relative
+/// selectors allowed in nested rules: `> a {}`
+pub(crate) fn is_at_selector(p: &mut CssParser) -> bool {
+    is_at_simple_selector(p) || p.at_ts(COMBINATORS)
+}
+
+#[inline]
+fn is_at_simple_selector(p: &CssParser) -> bool {
+    p.at_ts(SIMPLE_SELECTOR_START) || is_at_identifier(p)
+}
+
+/// The comma separated list of selectors of a rule (`a, b {}`) or of a pseudo class (`:is(a, b)`)
+pub(crate) struct SelectorList {
+    end_kind: CssSyntaxKind,
+}
+
+impl SelectorList {
+    pub(crate) fn new(end_kind: CssSyntaxKind) -> Self {
+        Self { end_kind }
+    }
+}
+
+impl ParseSeparatedList for SelectorList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+
+    const LIST_KIND: CssSyntaxKind = CSS_SELECTOR_LIST;
+
+    fn parse_element(&mut self, p: &mut CssParser) -> ParsedSyntax {
+        parse_selector(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut CssParser) -> bool {
+        p.at(self.end_kind) || p.at_ts(SELECTOR_LIST_END)
+    }
+
+    fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
+        parsed_element.or_recover(
+            p,
+            &ParseRecovery::new(
+                CSS_BOGUS_SELECTOR,
+                SELECTOR_LIST_END.union(token_set![T![,], self.end_kind]),
+            ),
+            expected_selector,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> CssSyntaxKind {
+        T![,]
+    }
+}
+
+/// Parses a complex selector: `div > a.link:hover`
+fn parse_selector(p: &mut CssParser) -> ParsedSyntax {
+    let mut left = if p.at_ts(COMBINATORS) {
+        let m = p.start();
+        p.bump_any();
+        parse_selector(p).or_add_diagnostic(p, expected_selector);
+        return Present(m.complete(p, CSS_RELATIVE_SELECTOR));
+    } else {
+        match parse_compound_selector(p) {
+            Present(selector) => selector,
+            Absent => return Absent,
+        }
+    };
+
+    loop {
+        if p.at_ts(COMBINATORS) {
+            let m = left.precede(p);
+            p.bump_any();
+            parse_compound_selector(p).or_add_diagnostic(p, expected_selector);
+            left = m.complete(p, CSS_COMPLEX_SELECTOR);
+        } else if is_at_simple_selector(p) && !is_adjacent_to_previous_token(p) {
+            // Descendant combinator: `div a`
+            let m = left.precede(p);
+            parse_compound_selector(p).ok();
+            left = m.complete(p, CSS_COMPLEX_SELECTOR);
+        } else {
+            break;
+        }
+    }
+
+    Present(left)
+}
+
+/// Parses a compound selector: a sequence of simple selectors without any whitespace in between. `a.link:hover`
+fn parse_compound_selector(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_simple_selector(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    SelectorPatternList { is_first: true }.parse_list(p);
+    Present(m.complete(p, CSS_SELECTOR))
+}
+
+/// The simple selectors of a compound selector
+struct SelectorPatternList {
+    is_first: bool,
+}
+
+impl ParseNodeList for SelectorPatternList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+
+    const LIST_KIND: CssSyntaxKind = CSS_ANY_SELECTOR_PATTERN_LIST;
+
+    fn parse_element(&mut self, p: &mut CssParser) -> ParsedSyntax {
+        self.is_first = false;
+        parse_selector_pattern(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut CssParser) -> bool {
+        // Any whitespace after the first simple selector ends the compound selector
+        !is_at_simple_selector(p) || (!self.is_first && !is_adjacent_to_previous_token(p))
+    }
+
+    fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
+        parsed_element.or_recover(
+            p,
+            &ParseRecovery::new(CSS_BOGUS_SELECTOR, SIMPLE_SELECTOR_START),
+            expected_selector,
+        )
+    }
+}
+
+fn parse_selector_pattern(p: &mut CssParser) -> ParsedSyntax {
+    match p.cur() {
+        T![#] => parse_id_selector(p),
+        T![.] => parse_class_selector(p),
+        T![*] => parse_token_selector(p, T![*], CSS_UNIVERSAL_SELECTOR_PATTERN),
+        T![&] => parse_token_selector(p, T![&], CSS_NESTING_SELECTOR_PATTERN),
+        T!['['] => parse_attribute_selector(p),
+        T![:] => parse_pseudo_selector(p, T![:], CSS_PSEUDO_CLASS_SELECTOR_PATTERN),
+        T![::] => parse_pseudo_selector(p, T![::], CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN),
+        _ if is_at_identifier(p) => {
+            let m = p.start();
+            parse_regular_identifier(p).ok();
+            Present(m.complete(p, CSS_TYPE_SELECTOR_PATTERN))
+        }
+        _ => Absent,
+    }
+}
+
+fn parse_token_selector(
+    p: &mut CssParser,
+    token: CssSyntaxKind,
+    kind: CssSyntaxKind,
+) -> ParsedSyntax {
+    let m = p.start();
+    p.bump(token);
+    Present(m.complete(p, kind))
+}
+
+/// Parses an id selector: `#app`
+fn parse_id_selector(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+    p.bump(T![#]);
+    parse_selector_identifier(p);
+    Present(m.complete(p, CSS_ID_SELECTOR_PATTERN))
+}
+
+/// Parses a class selector: `.app`
+fn parse_class_selector(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+    p.bump(T![.]);
+    parse_selector_identifier(p);
+    Present(m.complete(p, CSS_CLASS_SELECTOR_PATTERN))
+}
+
+/// Parses the name of an id, class or pseudo selector. The name must directly follow the
+/// `#`, `.` or `:` token.
+fn parse_selector_identifier(p: &mut CssParser) {
+    if is_at_identifier(p) && !is_adjacent_to_previous_token(p) {
+        p.error(p.err_builder(
+            "Unexpected whitespace in the selector",
+            p.last_end().unwrap_or_default()..p.cur_range().start(),
+        ));
+    }
+
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+}
+
+/// Parses an attribute selector: `[title="title" i]`
+fn parse_attribute_selector(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+    p.bump(T!['[']);
+
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+
+    if p.at_ts(ATTRIBUTE_MATCHERS) {
+        let matcher = p.start();
+        p.bump_any();
+
+        match p.cur() {
+            CSS_STRING_LITERAL => {
+                parse_string(p).ok();
+            }
+            _ => {
+                parse_regular_identifier(p).or_add_diagnostic(p, |p, range| {
+                    expected_any(&["string", "identifier"], range).into_diagnostic(p)
+                });
+            }
+        }
+
+        // Case sensitivity modifier: `i` or `s`
+        parse_regular_identifier(p).ok();
+
+        matcher.complete(p, CSS_ATTRIBUTE_MATCHER);
+    }
+
+    p.expect(T![']']);
+
+    Present(m.complete(p, CSS_ATTRIBUTE_SELECTOR_PATTERN))
+}
+
+/// Parses a pseudo class (`:hover`, `:not(.a)`) or pseudo element (`::before`, `::part(label)`)
+fn parse_pseudo_selector(
+    p: &mut CssParser,
+    token: CssSyntaxKind,
+    kind: CssSyntaxKind,
+) -> ParsedSyntax {
+    let m = p.start();
+    p.bump(token);
+
+    let name = p.cur_text().to_ascii_lowercase();
+    parse_selector_identifier(p);
+
+    if p.at(T!['(']) && is_adjacent_to_previous_token(p) {
+        let parameters = p.start();
+        p.bump(T!['(']);
+
+        if SELECTOR_PSEUDO_FUNCTIONS.contains(&name.as_str()) {
+            SelectorList::new(T![')']).parse_list(p);
+            p.expect(T![')']);
+            parameters.complete(p, CSS_PSEUDO_SELECTOR_PARAMETERS);
+        } else {
+            ComponentValueList::new(token_set![T![')'], T!['{'], T!['}'], T![;]]).parse_list(p);
+            p.expect(T![')']);
+            parameters.complete(p, CSS_PSEUDO_VALUE_PARAMETERS);
+        }
+    }
+
+    Present(m.complete(p, kind))
+}
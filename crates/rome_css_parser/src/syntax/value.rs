@@ -0,0 +1,302 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::parse_error::expected_value;
+use crate::syntax::{
+    is_adjacent_to_previous_token, is_at_identifier, parse_custom_property,
+    parse_regular_identifier, parse_string,
+};
+use rome_css_syntax::CssSyntaxKind::*;
+use rome_css_syntax::{CssSyntaxKind, T};
+use rome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use rome_parser::parse_recovery::{ParseRecovery, RecoveryResult};
+use rome_parser::prelude::ParsedSyntax::{Absent, Present};
+use rome_parser::prelude::{CompletedMarker, ParsedSyntax};
+use rome_parser::{token_set, Parser, TokenSet};
+
+/// Tokens that are parsed as a [CSS_GENERIC_DELIMITER]
+const GENERIC_DELIMITERS: TokenSet<CssSyntaxKind> = token_set![
+    T![,],
+    T![/],
+    T![:],
+    T![=],
+    T![+],
+    T![-],
+    T![*],
+    T![>],
+    T![<],
+    T![>=],
+    T![<=]
+];
+
+const VALUE_START: TokenSet<CssSyntaxKind> = GENERIC_DELIMITERS.union(token_set![
+    CSS_STRING_LITERAL,
+    CSS_NUMBER_LITERAL,
+    CSS_CUSTOM_PROPERTY_LITERAL,
+    T![#],
+    T!['('],
+    T!['['],
+    IDENT,
+    T![media],
+    T![keyframes],
+    T![not],
+    T![and],
+    T![only],
+    T![or],
+    T![i],
+    T![important],
+    T![from],
+    T![to],
+    T![var],
+    T![url],
+    T![charset],
+    T![import]
+]);
+
+/// Tokens that always end a nested list of values, even if the closing token is missing.
+const NESTED_VALUE_END: TokenSet<CssSyntaxKind> = token_set![T!['{'], T!['}'], T![;]];
+
+/// A whitespace separated list of component values: `1px solid red`
+pub(crate) struct ComponentValueList {
+    /// The tokens that end the list
+    end_set: TokenSet<CssSyntaxKind>,
+    len: usize,
+}
+
+impl ComponentValueList {
+    pub(crate) fn new(end_set: TokenSet<CssSyntaxKind>) -> Self {
+        Self { end_set, len: 0 }
+    }
+
+    /// Returns `true` if the list doesn't contain any value
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl ParseNodeList for ComponentValueList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+
+    const LIST_KIND: CssSyntaxKind = CSS_COMPONENT_VALUE_LIST;
+
+    fn parse_element(&mut self, p: &mut CssParser) -> ParsedSyntax {
+        parse_any_value(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut CssParser) -> bool {
+        p.at_ts(self.end_set)
+    }
+
+    fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
+        let result = parsed_element.or_recover(
+            p,
+            &ParseRecovery::new(CSS_BOGUS_VALUE, self.end_set.union(VALUE_START)),
+            expected_value,
+        );
+
+        if result.is_ok() {
+            self.len += 1;
+        }
+
+        result
+    }
+}
+
+/// Parses a single component value
+pub(crate) fn parse_any_value(p: &mut CssParser) -> ParsedSyntax {
+    match p.cur() {
+        CSS_STRING_LITERAL => parse_string(p),
+        CSS_NUMBER_LITERAL => parse_numeric_value(p),
+        CSS_CUSTOM_PROPERTY_LITERAL => parse_custom_property(p),
+        T![#] => parse_color(p),
+        T!['('] => parse_parenthesized_value(p),
+        T!['['] => parse_bracketed_value(p),
+        T![var] if is_at_function(p) => parse_var_function(p),
+        T![url] if is_at_function(p) => parse_url_function(p),
+        _ if is_at_identifier(p) => {
+            if is_at_function(p) {
+                parse_simple_function(p)
+            } else {
+                parse_regular_identifier(p)
+            }
+        }
+        _ if p.at_ts(GENERIC_DELIMITERS) => {
+            let m = p.start();
+            p.bump_any();
+            Present(m.complete(p, CSS_GENERIC_DELIMITER))
+        }
+        _ => Absent,
+    }
+}
+
+/// Parses a number, a percentage (`50%`), a dimension (`10px`) or a ratio (`16/9`).
+///
+/// Ratios are only parsed if `allow_ratio` is `true` because `/` is a regular delimiter in most
+/// property values: `font: 12px/1.5 Helvetica`.
+pub(crate) fn parse_numeric_value_with_ratio(p: &mut CssParser, allow_ratio: bool) -> ParsedSyntax {
+    if !p.at(CSS_NUMBER_LITERAL) {
+        return Absent;
+    }
+
+    let number = parse_number(p);
+
+    if p.at(T![%]) && is_adjacent_to_previous_token(p) {
+        let m = number.precede(p);
+        p.bump(T![%]);
+        Present(m.complete(p, CSS_PERCENTAGE))
+    } else if is_at_identifier(p) && is_adjacent_to_previous_token(p) {
+        let m = number.precede(p);
+        parse_regular_identifier(p).ok();
+        Present(m.complete(p, CSS_DIMENSION))
+    } else if allow_ratio && p.at(T![/]) && p.nth_at(1, CSS_NUMBER_LITERAL) {
+        let m = number.precede(p);
+        p.bump(T![/]);
+        parse_number(p);
+        Present(m.complete(p, CSS_RATIO))
+    } else {
+        Present(number)
+    }
+}
+
+fn parse_numeric_value(p: &mut CssParser) -> ParsedSyntax {
+    parse_numeric_value_with_ratio(p, false)
+}
+
+fn parse_number(p: &mut CssParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(CSS_NUMBER_LITERAL);
+    m.complete(p, CSS_NUMBER)
+}
+
+/// Parses a hex color: `#fff`
+fn parse_color(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+    p.bump_with_context(T![#], CssLexContext::Color);
+
+    if !p.eat(CSS_COLOR_LITERAL) {
+        p.error(p.err_builder("Expected a color value after `#`", p.cur_range()));
+    }
+
+    Present(m.complete(p, CSS_COLOR))
+}
+
+fn parse_parenthesized_value(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+    p.bump(T!['(']);
+    ComponentValueList::new(NESTED_VALUE_END.union(token_set![T![')']])).parse_list(p);
+    p.expect(T![')']);
+    Present(m.complete(p, CSS_PARENTHESIZED_VALUE))
+}
+
+fn parse_bracketed_value(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+    p.bump(T!['[']);
+    ComponentValueList::new(NESTED_VALUE_END.union(token_set![T![']'], T![')']])).parse_list(p);
+    p.expect(T![']']);
+    Present(m.complete(p, CSS_BRACKETED_VALUE))
+}
+
+/// Returns `true` if the parser is at the name of a function: an identifier directly followed by `(`
+#[inline]
+fn is_at_function(p: &mut CssParser) -> bool {
+    is_at_identifier(p)
+        && p.nth_at(1, T!['('])
+        && p.source().nth_range(1).start() == p.cur_range().end()
+}
+
+/// Parses a function: `rgba(0, 0, 0, 0.5)`
+fn parse_simple_function(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+
+    parse_regular_identifier(p).ok();
+    p.bump(T!['(']);
+    ParameterList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, CSS_SIMPLE_FUNCTION))
+}
+
+struct ParameterList;
+
+impl ParseSeparatedList for ParameterList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+
+    const LIST_KIND: CssSyntaxKind = CSS_PARAMETER_LIST;
+
+    fn parse_element(&mut self, p: &mut CssParser) -> ParsedSyntax {
+        let m = p.start();
+        ComponentValueList::new(NESTED_VALUE_END.union(token_set![T![,], T![')']])).parse_list(p);
+        Present(m.complete(p, CSS_PARAMETER))
+    }
+
+    fn is_at_list_end(&self, p: &mut CssParser) -> bool {
+        p.at_ts(NESTED_VALUE_END.union(token_set![T![')']]))
+    }
+
+    fn recover(&mut self, p: &mut CssParser, parsed_element: ParsedSyntax) -> RecoveryResult {
+        parsed_element.or_recover(
+            p,
+            &ParseRecovery::new(
+                CSS_BOGUS_VALUE,
+                NESTED_VALUE_END.union(token_set![T![,], T![')']]),
+            ),
+            expected_value,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> CssSyntaxKind {
+        T![,]
+    }
+}
+
+/// Parses a `var()` function: `var(--main-color, red)`
+fn parse_var_function(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+
+    p.bump(T![var]);
+    p.bump(T!['(']);
+
+    parse_custom_property(p).or_add_diagnostic(p, |p, range| {
+        p.err_builder("Expected a custom property", range)
+    });
+
+    if p.at(T![,]) {
+        let fallback = p.start();
+        p.bump(T![,]);
+        ComponentValueList::new(NESTED_VALUE_END.union(token_set![T![')']])).parse_list(p);
+        fallback.complete(p, CSS_VAR_FUNCTION_VALUE);
+    }
+
+    p.expect(T![')']);
+
+    Present(m.complete(p, CSS_VAR_FUNCTION))
+}
+
+/// Parses a `url()` function: `url(image.png)` or `url("image.png")`
+pub(crate) fn parse_url_function(p: &mut CssParser) -> ParsedSyntax {
+    if !(p.at(T![url]) && is_at_function(p)) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![url]);
+    p.bump_with_context(T!['('], CssLexContext::Url);
+
+    match p.cur() {
+        CSS_STRING_LITERAL => {
+            parse_string(p).ok();
+        }
+        CSS_URL_VALUE_RAW_LITERAL => {
+            let value = p.start();
+            p.bump(CSS_URL_VALUE_RAW_LITERAL);
+            value.complete(p, CSS_URL_VALUE_RAW);
+        }
+        _ => {}
+    }
+
+    p.expect(T![')']);
+
+    Present(m.complete(p, CSS_URL_FUNCTION))
+}
@@ -1,10 +1,10 @@
-use crate::lexer::{Lexer, Token};
+use crate::lexer::{CssLexContext, Lexer, Token};
 use crate::CssParserOptions;
 use rome_css_syntax::CssSyntaxKind::{EOF, TOMBSTONE};
-use rome_css_syntax::{CssSyntaxKind, TextRange};
+use rome_css_syntax::{CssSyntaxKind, TextRange, TextSize};
 use rome_parser::diagnostic::ParseDiagnostic;
 use rome_parser::prelude::TokenSource;
-use rome_parser::token_source::Trivia;
+use rome_parser::token_source::{BumpWithContext, NthToken, Trivia};
 use rome_rowan::TriviaPieceKind;
 
 pub(crate) struct CssTokenSource<'source> {
@@ -29,15 +29,15 @@ impl<'source> CssTokenSource<'source> {
             config,
         };
 
-        source.next_non_trivia_token(true);
+        source.next_non_trivia_token(CssLexContext::Regular, true);
         source
     }
 
-    fn next_non_trivia_token(&mut self, first_token: bool) {
+    fn next_non_trivia_token(&mut self, context: CssLexContext, first_token: bool) {
         let mut trailing = !first_token;
         self.preceding_line_break = false;
 
-        while let Some(token) = self.lexer.next_token() {
+        while let Some(token) = self.lexer.next_token(context) {
             let trivia_kind = TriviaPieceKind::try_from(token.kind());
 
             match trivia_kind {
@@ -48,7 +48,8 @@ impl<'source> CssTokenSource<'source> {
                 }
                 Ok(trivia_kind)
                     if trivia_kind.is_single_line_comment()
-                        && !self.config.allow_single_line_comments =>
+                        && !self.config.allow_single_line_comments
+                        && self.lexer.source()[token.range()].starts_with("//") =>
                 {
                     self.set_current_token(token);
 
@@ -72,6 +73,76 @@ impl<'source> CssTokenSource<'source> {
         self.current = token.kind();
         self.current_range = token.range()
     }
+
+    /// Creates a checkpoint to which the token source can be rewound.
+    pub fn checkpoint(&self) -> TokenSourceCheckpoint {
+        TokenSourceCheckpoint {
+            current: self.current,
+            current_range: self.current_range,
+            preceding_line_break: self.preceding_line_break,
+            lexer_position: self.lexer.position(),
+            lexer_diagnostics_len: self.lexer.diagnostics_len(),
+            trivia_len: self.trivia.len(),
+        }
+    }
+
+    /// Restores the token source to a previous state
+    pub fn rewind(&mut self, checkpoint: TokenSourceCheckpoint) {
+        assert!(self.trivia.len() >= checkpoint.trivia_len);
+
+        self.current = checkpoint.current;
+        self.current_range = checkpoint.current_range;
+        self.preceding_line_break = checkpoint.preceding_line_break;
+        self.trivia.truncate(checkpoint.trivia_len);
+        self.lexer
+            .rewind(checkpoint.lexer_position, checkpoint.lexer_diagnostics_len);
+    }
+
+    /// Returns the range of the `n`th non-trivia token or an empty range at the end of the file.
+    pub fn nth_range(&self, n: usize) -> TextRange {
+        if n == 0 {
+            self.current_range
+        } else {
+            self.lookahead(n).map_or_else(
+                || TextRange::empty(TextSize::of(self.lexer.source())),
+                |(token, _)| token.range(),
+            )
+        }
+    }
+
+    /// Lexes the `n`th non-trivia token after the current token.
+    ///
+    /// The lookahead lexes the tokens in the regular context without affecting the state of the token source.
+    fn lookahead(&self, n: usize) -> Option<(Token, bool)> {
+        debug_assert!(n > 0);
+
+        let mut lexer = self.lexer.lookahead();
+        let mut remaining = n;
+        let mut has_preceding_line_break = false;
+
+        while let Some(token) = lexer.next_token(CssLexContext::Regular) {
+            match TriviaPieceKind::try_from(token.kind()) {
+                Ok(trivia_kind)
+                    if !trivia_kind.is_single_line_comment()
+                        || self.config.allow_single_line_comments
+                        || !self.lexer.source()[token.range()].starts_with("//") =>
+                {
+                    has_preceding_line_break |= trivia_kind.is_newline();
+                }
+                _ => {
+                    remaining -= 1;
+
+                    if remaining == 0 {
+                        return Some((token, has_preceding_line_break));
+                    }
+
+                    has_preceding_line_break = false;
+                }
+            }
+        }
+
+        None
+    }
 }
 
 impl<'source> TokenSource for CssTokenSource<'source> {
@@ -94,12 +165,28 @@ impl<'source> TokenSource for CssTokenSource<'source> {
     }
 
     fn bump(&mut self) {
+        self.bump_with_context(CssLexContext::Regular)
+    }
+
+    fn skip_as_trivia(&mut self) {
+        self.skip_as_trivia_with_context(CssLexContext::Regular)
+    }
+
+    fn finish(self) -> (Vec<Trivia>, Vec<ParseDiagnostic>) {
+        (self.trivia, self.lexer.finish())
+    }
+}
+
+impl<'source> BumpWithContext for CssTokenSource<'source> {
+    type Context = CssLexContext;
+
+    fn bump_with_context(&mut self, context: Self::Context) {
         if self.current != EOF {
-            self.next_non_trivia_token(false)
+            self.next_non_trivia_token(context, false)
         }
     }
 
-    fn skip_as_trivia(&mut self) {
+    fn skip_as_trivia_with_context(&mut self, context: Self::Context) {
         if self.current() != EOF {
             self.trivia.push(Trivia::new(
                 TriviaPieceKind::Skipped,
@@ -107,11 +194,40 @@ impl<'source> TokenSource for CssTokenSource<'source> {
                 false,
             ));
 
-            self.next_non_trivia_token(false)
+            self.next_non_trivia_token(context, false)
         }
     }
+}
 
-    fn finish(self) -> (Vec<Trivia>, Vec<ParseDiagnostic>) {
-        (self.trivia, self.lexer.finish())
+impl<'source> NthToken for CssTokenSource<'source> {
+    /// Gets the kind of the nth non-trivia token
+    fn nth(&mut self, n: usize) -> CssSyntaxKind {
+        if n == 0 {
+            self.current()
+        } else {
+            self.lookahead(n).map_or(EOF, |(token, _)| token.kind())
+        }
+    }
+
+    /// Returns true if the nth non-trivia token is preceded by a line break
+    fn has_nth_preceding_line_break(&mut self, n: usize) -> bool {
+        if n == 0 {
+            self.has_preceding_line_break()
+        } else {
+            self.lookahead(n)
+                .map_or(false, |(_, has_preceding_line_break)| {
+                    has_preceding_line_break
+                })
+        }
     }
 }
+
+#[derive(Debug)]
+pub(crate) struct TokenSourceCheckpoint {
+    current: CssSyntaxKind,
+    current_range: TextRange,
+    preceding_line_break: bool,
+    lexer_position: usize,
+    lexer_diagnostics_len: usize,
+    trivia_len: usize,
+}
@@ -6,3 +6,8 @@ mod ok {
     //! Tests that must pass according to the CSS specification
     tests_macros::gen_tests! {"tests/css_test_suite/ok/*.css", crate::spec_test::run, "ok"}
 }
+
+mod error {
+    //! Tests that must fail according to the CSS specification
+    tests_macros::gen_tests! {"tests/css_test_suite/error/*.css", crate::spec_test::run, "error"}
+}
@@ -0,0 +1,59 @@
+use crate::CssLanguage;
+use rome_rowan::{FileSource, FileSourceError};
+use std::path::Path;
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct CssFileSource {
+    #[allow(dead_code)]
+    variant: CssVariant,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+enum CssVariant {
+    #[default]
+    Standard,
+}
+
+impl CssFileSource {
+    pub fn css() -> Self {
+        Self {
+            variant: CssVariant::Standard,
+        }
+    }
+}
+
+impl<'a> FileSource<'a, CssLanguage> for CssFileSource {}
+
+impl TryFrom<&Path> for CssFileSource {
+    type Error = FileSourceError;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| FileSourceError::MissingFileName(path.into()))?
+            .to_str()
+            .ok_or_else(|| FileSourceError::MissingFileName(path.into()))?;
+
+        let extension = path
+            .extension()
+            .ok_or_else(|| FileSourceError::MissingFileExtension(path.into()))?
+            .to_str()
+            .ok_or_else(|| FileSourceError::MissingFileExtension(path.into()))?;
+
+        compute_source_type_from_path_or_extension(file_name, extension)
+    }
+}
+
+/// It deduce the [CssFileSource] from the file name and its extension
+fn compute_source_type_from_path_or_extension(
+    file_name: &str,
+    extension: &str,
+) -> Result<CssFileSource, FileSourceError> {
+    match extension {
+        "css" => Ok(CssFileSource::css()),
+        _ => Err(FileSourceError::UnknownExtension(
+            file_name.into(),
+            extension.into(),
+        )),
+    }
+}
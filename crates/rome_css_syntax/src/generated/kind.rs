@@ -31,6 +31,7 @@ pub enum CssSyntaxKind {
     PERCENT,
     DOT,
     COLON,
+    COLON2,
     EQ,
     BANG,
     NEQ,
@@ -201,10 +202,14 @@ pub enum CssSyntaxKind {
     FROM_KW,
     TO_KW,
     VAR_KW,
+    URL_KW,
+    CHARSET_KW,
+    IMPORT_KW,
     CSS_STRING_LITERAL,
     CSS_NUMBER_LITERAL,
-    CSS_CUSTOM_PROPERTY,
-    CSS_SPACE_LITERAL,
+    CSS_CUSTOM_PROPERTY_LITERAL,
+    CSS_COLOR_LITERAL,
+    CSS_URL_VALUE_RAW_LITERAL,
     ERROR_TOKEN,
     IDENT,
     NEWLINE,
@@ -213,57 +218,72 @@ pub enum CssSyntaxKind {
     MULTILINE_COMMENT,
     CSS_ROOT,
     CSS_RULE_LIST,
-    CSS_ID_SELECTOR_PATTERN,
     CSS_RULE,
     CSS_SELECTOR_LIST,
     CSS_SELECTOR,
-    CSS_ANY_FUNCTION,
+    CSS_ANY_SELECTOR_PATTERN_LIST,
+    CSS_COMPLEX_SELECTOR,
+    CSS_RELATIVE_SELECTOR,
+    CSS_ID_SELECTOR_PATTERN,
+    CSS_CLASS_SELECTOR_PATTERN,
+    CSS_UNIVERSAL_SELECTOR_PATTERN,
+    CSS_NESTING_SELECTOR_PATTERN,
+    CSS_ATTRIBUTE_SELECTOR_PATTERN,
+    CSS_ATTRIBUTE_MATCHER,
+    CSS_PSEUDO_CLASS_SELECTOR_PATTERN,
+    CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN,
+    CSS_PSEUDO_SELECTOR_PARAMETERS,
+    CSS_PSEUDO_VALUE_PARAMETERS,
+    CSS_TYPE_SELECTOR_PATTERN,
+    CSS_BLOCK,
+    CSS_BLOCK_ITEM_LIST,
+    CSS_DECLARATION,
+    CSS_DECLARATION_IMPORTANT,
+    CSS_EMPTY_DECLARATION,
+    CSS_AT_CHARSET,
+    CSS_AT_IMPORT,
     CSS_AT_KEYFRAMES,
     CSS_AT_KEYFRAMES_BODY,
+    CSS_AT_KEYFRAMES_ITEM_LIST,
+    CSS_KEYFRAMES_BLOCK,
+    CSS_KEYFRAMES_SELECTOR_LIST,
+    CSS_KEYFRAMES_SELECTOR,
     CSS_AT_MEDIA,
+    CSS_AT_MEDIA_QUERY_LIST,
     CSS_AT_MEDIA_QUERY,
+    CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST,
     CSS_AT_MEDIA_QUERY_CONSEQUENT,
     CSS_AT_MEDIA_QUERY_FEATURE,
+    CSS_AT_MEDIA_QUERY_FEATURE_PLAIN,
     CSS_AT_MEDIA_QUERY_FEATURE_BOOLEAN,
     CSS_AT_MEDIA_QUERY_FEATURE_COMPARE,
-    CSS_AT_MEDIA_QUERY_FEATURE_PLAIN,
+    CSS_AT_MEDIA_QUERY_FEATURE_REVERSE_COMPARE,
     CSS_AT_MEDIA_QUERY_FEATURE_RANGE,
     CSS_AT_MEDIA_QUERY_RANGE,
-    CSS_ATTRIBUTE,
-    CSS_ATTRIBUTE_MATCHER,
-    CSS_ATTRIBUTE_META,
-    CSS_ATTRIBUTE_MODIFIER,
-    CSS_ATTRIBUTE_NAME,
-    CSS_ATTRIBUTE_SELECTOR_PATTERN,
-    CSS_BLOCK,
-    CSS_CLASS_SELECTOR_PATTERN,
-    CSS_COMBINATOR_SELECTOR_PATTERN,
-    CSS_DECLARATION,
+    CSS_AT_GENERIC,
+    CSS_COMPONENT_VALUE_LIST,
     CSS_DIMENSION,
-    CSS_IDENTIFIER,
-    CSS_KEYFRAMES_BLOCK,
-    CSS_KEYFRAMES_SELECTOR,
-    CSS_NUMBER,
-    CSS_PARAMETER,
-    CSS_PERCENTAGE,
-    CSS_PSEUDO_CLASS_SELECTOR_PATTERN,
-    CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS,
     CSS_RATIO,
+    CSS_PERCENTAGE,
+    CSS_COLOR,
+    CSS_PARENTHESIZED_VALUE,
+    CSS_BRACKETED_VALUE,
+    CSS_GENERIC_DELIMITER,
     CSS_SIMPLE_FUNCTION,
-    CSS_STRING,
-    CSS_TYPE_SELECTOR_PATTERN,
-    CSS_UNIVERSAL_SELECTOR_PATTERN,
+    CSS_PARAMETER_LIST,
+    CSS_PARAMETER,
     CSS_VAR_FUNCTION,
     CSS_VAR_FUNCTION_VALUE,
-    CSS_ANY_SELECTOR_PATTERN_LIST,
-    CSS_AT_KEYFRAMES_ITEM_LIST,
-    CSS_AT_MEDIA_QUERY_LIST,
-    CSS_ATTRIBUTE_LIST,
-    CSS_DECLARATION_LIST,
-    CSS_KEYFRAMES_SELECTOR_LIST,
-    CSS_PARAMETER_LIST,
-    CSS_DECLARATION_IMPORTANT,
+    CSS_URL_FUNCTION,
+    CSS_URL_VALUE_RAW,
+    CSS_CUSTOM_PROPERTY,
+    CSS_IDENTIFIER,
+    CSS_STRING,
+    CSS_NUMBER,
     CSS_BOGUS,
+    CSS_BOGUS_RULE,
+    CSS_BOGUS_SELECTOR,
+    CSS_BOGUS_VALUE,
     #[doc(hidden)]
     __LAST,
 }
@@ -273,17 +293,19 @@ impl CssSyntaxKind {
         match self {
             SEMICOLON | COMMA | L_PAREN | R_PAREN | L_CURLY | R_CURLY | L_BRACK | R_BRACK
             | L_ANGLE | R_ANGLE | TILDE | HASH | AMP | PIPE | PLUS | STAR | SLASH | CARET
-            | PERCENT | DOT | COLON | EQ | BANG | NEQ | MINUS | LTEQ | GTEQ | PLUSEQ | PIPEEQ
-            | AMPEQ | CARETEQ | SLASHEQ | STAREQ | PERCENTEQ | AT | DOLLAR_EQ | TILDE_EQ | CDC
-            | CDO => true,
+            | PERCENT | DOT | COLON | COLON2 | EQ | BANG | NEQ | MINUS | LTEQ | GTEQ | PLUSEQ
+            | PIPEEQ | AMPEQ | CARETEQ | SLASHEQ | STAREQ | PERCENTEQ | AT | DOLLAR_EQ
+            | TILDE_EQ | CDC | CDO => true,
             _ => false,
         }
     }
     pub const fn is_literal(self) -> bool {
         match self {
-            CSS_STRING_LITERAL | CSS_NUMBER_LITERAL | CSS_CUSTOM_PROPERTY | CSS_SPACE_LITERAL => {
-                true
-            }
+            CSS_STRING_LITERAL
+            | CSS_NUMBER_LITERAL
+            | CSS_CUSTOM_PROPERTY_LITERAL
+            | CSS_COLOR_LITERAL
+            | CSS_URL_VALUE_RAW_LITERAL => true,
             _ => false,
         }
     }
@@ -292,11 +314,12 @@ impl CssSyntaxKind {
             CSS_RULE_LIST
             | CSS_SELECTOR_LIST
             | CSS_ANY_SELECTOR_PATTERN_LIST
+            | CSS_BLOCK_ITEM_LIST
             | CSS_AT_KEYFRAMES_ITEM_LIST
-            | CSS_AT_MEDIA_QUERY_LIST
-            | CSS_ATTRIBUTE_LIST
-            | CSS_DECLARATION_LIST
             | CSS_KEYFRAMES_SELECTOR_LIST
+            | CSS_AT_MEDIA_QUERY_LIST
+            | CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST
+            | CSS_COMPONENT_VALUE_LIST
             | CSS_PARAMETER_LIST => true,
             _ => false,
         }
@@ -455,6 +478,9 @@ impl CssSyntaxKind {
             "from" => FROM_KW,
             "to" => TO_KW,
             "var" => VAR_KW,
+            "url" => URL_KW,
+            "charset" => CHARSET_KW,
+            "import" => IMPORT_KW,
             _ => return None,
         };
         Some(kw)
@@ -482,6 +508,7 @@ impl CssSyntaxKind {
             PERCENT => "%",
             DOT => ".",
             COLON => ":",
+            COLON2 => "::",
             EQ => "=",
             BANG => "!",
             NEQ => "!=",
@@ -652,6 +679,9 @@ impl CssSyntaxKind {
             FROM_KW => "from",
             TO_KW => "to",
             VAR_KW => "var",
+            URL_KW => "url",
+            CHARSET_KW => "charset",
+            IMPORT_KW => "import",
             CSS_STRING_LITERAL => "string literal",
             _ => return None,
         };
@@ -660,4 +690,4 @@ impl CssSyntaxKind {
 }
 #[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
 #[macro_export]
-macro_rules ! T { [;] => { $ crate :: CssSyntaxKind :: SEMICOLON } ; [,] => { $ crate :: CssSyntaxKind :: COMMA } ; ['('] => { $ crate :: CssSyntaxKind :: L_PAREN } ; [')'] => { $ crate :: CssSyntaxKind :: R_PAREN } ; ['{'] => { $ crate :: CssSyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: CssSyntaxKind :: R_CURLY } ; ['['] => { $ crate :: CssSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: CssSyntaxKind :: R_BRACK } ; [<] => { $ crate :: CssSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: CssSyntaxKind :: R_ANGLE } ; [~] => { $ crate :: CssSyntaxKind :: TILDE } ; [#] => { $ crate :: CssSyntaxKind :: HASH } ; [&] => { $ crate :: CssSyntaxKind :: AMP } ; [|] => { $ crate :: CssSyntaxKind :: PIPE } ; [+] => { $ crate :: CssSyntaxKind :: PLUS } ; [*] => { $ crate :: CssSyntaxKind :: STAR } ; [/] => { $ crate :: CssSyntaxKind :: SLASH } ; [^] => { $ crate :: CssSyntaxKind :: CARET } ; [%] => { $ crate :: CssSyntaxKind :: PERCENT } ; [.] => { $ crate :: CssSyntaxKind :: DOT } ; [:] => { $ crate :: CssSyntaxKind :: COLON } ; [=] => { $ crate :: CssSyntaxKind :: EQ } ; [!] => { $ crate :: CssSyntaxKind :: BANG } ; [!=] => { $ crate :: CssSyntaxKind :: NEQ } ; [-] => { $ crate :: CssSyntaxKind :: MINUS } ; [<=] => { $ crate :: CssSyntaxKind :: LTEQ } ; [>=] => { $ crate :: CssSyntaxKind :: GTEQ } ; [+=] => { $ crate :: CssSyntaxKind :: PLUSEQ } ; [|=] => { $ crate :: CssSyntaxKind :: PIPEEQ } ; [&=] => { $ crate :: CssSyntaxKind :: AMPEQ } ; [^=] => { $ crate :: CssSyntaxKind :: CARETEQ } ; [/=] => { $ crate :: CssSyntaxKind :: SLASHEQ } ; [*=] => { $ crate :: CssSyntaxKind :: STAREQ } ; [%=] => { $ crate :: CssSyntaxKind :: PERCENTEQ } ; [@] => { $ crate :: CssSyntaxKind :: AT } ; ["$="] => { $ crate :: CssSyntaxKind :: DOLLAR_EQ } ; [~=] => { $ crate :: CssSyntaxKind :: TILDE_EQ } ; [-->] => { $ crate :: CssSyntaxKind :: CDC } ; [<!--] => { $ crate :: CssSyntaxKind :: CDO } ; [aliceblue] => { $ crate :: CssSyntaxKind :: ALICEBLUE_KW } ; [antiquewhite] => { $ crate :: CssSyntaxKind :: ANTIQUEWHITE_KW } ; [aqua] => { $ crate :: CssSyntaxKind :: AQUA_KW } ; [aquamarine] => { $ crate :: CssSyntaxKind :: AQUAMARINE_KW } ; [azure] => { $ crate :: CssSyntaxKind :: AZURE_KW } ; [beige] => { $ crate :: CssSyntaxKind :: BEIGE_KW } ; [bisque] => { $ crate :: CssSyntaxKind :: BISQUE_KW } ; [black] => { $ crate :: CssSyntaxKind :: BLACK_KW } ; [blanchedalmond] => { $ crate :: CssSyntaxKind :: BLANCHEDALMOND_KW } ; [blue] => { $ crate :: CssSyntaxKind :: BLUE_KW } ; [blueviolet] => { $ crate :: CssSyntaxKind :: BLUEVIOLET_KW } ; [brown] => { $ crate :: CssSyntaxKind :: BROWN_KW } ; [burlywood] => { $ crate :: CssSyntaxKind :: BURLYWOOD_KW } ; [cadetblue] => { $ crate :: CssSyntaxKind :: CADETBLUE_KW } ; [chartreuse] => { $ crate :: CssSyntaxKind :: CHARTREUSE_KW } ; [chocolate] => { $ crate :: CssSyntaxKind :: CHOCOLATE_KW } ; [coral] => { $ crate :: CssSyntaxKind :: CORAL_KW } ; [cornflowerblue] => { $ crate :: CssSyntaxKind :: CORNFLOWERBLUE_KW } ; [cornsilk] => { $ crate :: CssSyntaxKind :: CORNSILK_KW } ; [crimson] => { $ crate :: CssSyntaxKind :: CRIMSON_KW } ; [cyan] => { $ crate :: CssSyntaxKind :: CYAN_KW } ; [darkblue] => { $ crate :: CssSyntaxKind :: DARKBLUE_KW } ; [darkcyan] => { $ crate :: CssSyntaxKind :: DARKCYAN_KW } ; [darkgoldenrod] => { $ crate :: CssSyntaxKind :: DARKGOLDENROD_KW } ; [darkgray] => { $ crate :: CssSyntaxKind :: DARKGRAY_KW } ; [darkgreen] => { $ crate :: CssSyntaxKind :: DARKGREEN_KW } ; [darkkhaki] => { $ crate :: CssSyntaxKind :: DARKKHAKI_KW } ; [darkmagenta] => { $ crate :: CssSyntaxKind :: DARKMAGENTA_KW } ; [darkolivegreen] => { $ crate :: CssSyntaxKind :: DARKOLIVEGREEN_KW } ; [darkorange] => { $ crate :: CssSyntaxKind :: DARKORANGE_KW } ; [darkorchid] => { $ crate :: CssSyntaxKind :: DARKORCHID_KW } ; [darkred] => { $ crate :: CssSyntaxKind :: DARKRED_KW } ; [darksalmon] => { $ crate :: CssSyntaxKind :: DARKSALMON_KW } ; [darkseagreen] => { $ crate :: CssSyntaxKind :: DARKSEAGREEN_KW } ; [darkslateblue] => { $ crate :: CssSyntaxKind :: DARKSLATEBLUE_KW } ; [darkslategray] => { $ crate :: CssSyntaxKind :: DARKSLATEGRAY_KW } ; [darkturquoise] => { $ crate :: CssSyntaxKind :: DARKTURQUOISE_KW } ; [darkviolet] => { $ crate :: CssSyntaxKind :: DARKVIOLET_KW } ; [deeppink] => { $ crate :: CssSyntaxKind :: DEEPPINK_KW } ; [deepskyblue] => { $ crate :: CssSyntaxKind :: DEEPSKYBLUE_KW } ; [dimgray] => { $ crate :: CssSyntaxKind :: DIMGRAY_KW } ; [dodgerblue] => { $ crate :: CssSyntaxKind :: DODGERBLUE_KW } ; [firebrick] => { $ crate :: CssSyntaxKind :: FIREBRICK_KW } ; [floralwhite] => { $ crate :: CssSyntaxKind :: FLORALWHITE_KW } ; [forestgreen] => { $ crate :: CssSyntaxKind :: FORESTGREEN_KW } ; [fuchsia] => { $ crate :: CssSyntaxKind :: FUCHSIA_KW } ; [gainsboro] => { $ crate :: CssSyntaxKind :: GAINSBORO_KW } ; [ghostwhite] => { $ crate :: CssSyntaxKind :: GHOSTWHITE_KW } ; [gold] => { $ crate :: CssSyntaxKind :: GOLD_KW } ; [goldenrod] => { $ crate :: CssSyntaxKind :: GOLDENROD_KW } ; [gray] => { $ crate :: CssSyntaxKind :: GRAY_KW } ; [green] => { $ crate :: CssSyntaxKind :: GREEN_KW } ; [greenyellow] => { $ crate :: CssSyntaxKind :: GREENYELLOW_KW } ; [honeydew] => { $ crate :: CssSyntaxKind :: HONEYDEW_KW } ; [hotpink] => { $ crate :: CssSyntaxKind :: HOTPINK_KW } ; [indianred] => { $ crate :: CssSyntaxKind :: INDIANRED_KW } ; [indigo] => { $ crate :: CssSyntaxKind :: INDIGO_KW } ; [ivory] => { $ crate :: CssSyntaxKind :: IVORY_KW } ; [khaki] => { $ crate :: CssSyntaxKind :: KHAKI_KW } ; [lavender] => { $ crate :: CssSyntaxKind :: LAVENDER_KW } ; [lavenderblush] => { $ crate :: CssSyntaxKind :: LAVENDERBLUSH_KW } ; [lawngreen] => { $ crate :: CssSyntaxKind :: LAWNGREEN_KW } ; [lemonchiffon] => { $ crate :: CssSyntaxKind :: LEMONCHIFFON_KW } ; [lightblue] => { $ crate :: CssSyntaxKind :: LIGHTBLUE_KW } ; [lightcoral] => { $ crate :: CssSyntaxKind :: LIGHTCORAL_KW } ; [lightcyan] => { $ crate :: CssSyntaxKind :: LIGHTCYAN_KW } ; [lightgoldenrodyellow] => { $ crate :: CssSyntaxKind :: LIGHTGOLDENRODYELLOW_KW } ; [lightgreen] => { $ crate :: CssSyntaxKind :: LIGHTGREEN_KW } ; [lightgrey] => { $ crate :: CssSyntaxKind :: LIGHTGREY_KW } ; [lightpink] => { $ crate :: CssSyntaxKind :: LIGHTPINK_KW } ; [lightsalmon] => { $ crate :: CssSyntaxKind :: LIGHTSALMON_KW } ; [lightseagreen] => { $ crate :: CssSyntaxKind :: LIGHTSEAGREEN_KW } ; [lightskyblue] => { $ crate :: CssSyntaxKind :: LIGHTSKYBLUE_KW } ; [lightslategray] => { $ crate :: CssSyntaxKind :: LIGHTSLATEGRAY_KW } ; [lightsteelblue] => { $ crate :: CssSyntaxKind :: LIGHTSTEELBLUE_KW } ; [lightyellow] => { $ crate :: CssSyntaxKind :: LIGHTYELLOW_KW } ; [lime] => { $ crate :: CssSyntaxKind :: LIME_KW } ; [limegreen] => { $ crate :: CssSyntaxKind :: LIMEGREEN_KW } ; [linen] => { $ crate :: CssSyntaxKind :: LINEN_KW } ; [magenta] => { $ crate :: CssSyntaxKind :: MAGENTA_KW } ; [maroon] => { $ crate :: CssSyntaxKind :: MAROON_KW } ; [mediumaquamarine] => { $ crate :: CssSyntaxKind :: MEDIUMAQUAMARINE_KW } ; [mediumblue] => { $ crate :: CssSyntaxKind :: MEDIUMBLUE_KW } ; [mediumorchid] => { $ crate :: CssSyntaxKind :: MEDIUMORCHID_KW } ; [mediumpurple] => { $ crate :: CssSyntaxKind :: MEDIUMPURPLE_KW } ; [mediumseagreen] => { $ crate :: CssSyntaxKind :: MEDIUMSEAGREEN_KW } ; [mediumslateblue] => { $ crate :: CssSyntaxKind :: MEDIUMSLATEBLUE_KW } ; [mediumspringgreen] => { $ crate :: CssSyntaxKind :: MEDIUMSPRINGGREEN_KW } ; [mediumturquoise] => { $ crate :: CssSyntaxKind :: MEDIUMTURQUOISE_KW } ; [mediumvioletred] => { $ crate :: CssSyntaxKind :: MEDIUMVIOLETRED_KW } ; [midnightblue] => { $ crate :: CssSyntaxKind :: MIDNIGHTBLUE_KW } ; [mintcream] => { $ crate :: CssSyntaxKind :: MINTCREAM_KW } ; [mistyrose] => { $ crate :: CssSyntaxKind :: MISTYROSE_KW } ; [moccasin] => { $ crate :: CssSyntaxKind :: MOCCASIN_KW } ; [navajowhite] => { $ crate :: CssSyntaxKind :: NAVAJOWHITE_KW } ; [navy] => { $ crate :: CssSyntaxKind :: NAVY_KW } ; [navyblue] => { $ crate :: CssSyntaxKind :: NAVYBLUE_KW } ; [oldlace] => { $ crate :: CssSyntaxKind :: OLDLACE_KW } ; [olive] => { $ crate :: CssSyntaxKind :: OLIVE_KW } ; [olivedrab] => { $ crate :: CssSyntaxKind :: OLIVEDRAB_KW } ; [orange] => { $ crate :: CssSyntaxKind :: ORANGE_KW } ; [orangered] => { $ crate :: CssSyntaxKind :: ORANGERED_KW } ; [orchid] => { $ crate :: CssSyntaxKind :: ORCHID_KW } ; [palegoldenrod] => { $ crate :: CssSyntaxKind :: PALEGOLDENROD_KW } ; [palegreen] => { $ crate :: CssSyntaxKind :: PALEGREEN_KW } ; [paleturquoise] => { $ crate :: CssSyntaxKind :: PALETURQUOISE_KW } ; [palevioletred] => { $ crate :: CssSyntaxKind :: PALEVIOLETRED_KW } ; [papayawhip] => { $ crate :: CssSyntaxKind :: PAPAYAWHIP_KW } ; [peachpuff] => { $ crate :: CssSyntaxKind :: PEACHPUFF_KW } ; [peru] => { $ crate :: CssSyntaxKind :: PERU_KW } ; [pink] => { $ crate :: CssSyntaxKind :: PINK_KW } ; [plum] => { $ crate :: CssSyntaxKind :: PLUM_KW } ; [powderblue] => { $ crate :: CssSyntaxKind :: POWDERBLUE_KW } ; [purple] => { $ crate :: CssSyntaxKind :: PURPLE_KW } ; [red] => { $ crate :: CssSyntaxKind :: RED_KW } ; [rosybrown] => { $ crate :: CssSyntaxKind :: ROSYBROWN_KW } ; [royalblue] => { $ crate :: CssSyntaxKind :: ROYALBLUE_KW } ; [saddlebrown] => { $ crate :: CssSyntaxKind :: SADDLEBROWN_KW } ; [salmon] => { $ crate :: CssSyntaxKind :: SALMON_KW } ; [sandybrown] => { $ crate :: CssSyntaxKind :: SANDYBROWN_KW } ; [seagreen] => { $ crate :: CssSyntaxKind :: SEAGREEN_KW } ; [seashell] => { $ crate :: CssSyntaxKind :: SEASHELL_KW } ; [sienna] => { $ crate :: CssSyntaxKind :: SIENNA_KW } ; [silver] => { $ crate :: CssSyntaxKind :: SILVER_KW } ; [skyblue] => { $ crate :: CssSyntaxKind :: SKYBLUE_KW } ; [slateblue] => { $ crate :: CssSyntaxKind :: SLATEBLUE_KW } ; [slategray] => { $ crate :: CssSyntaxKind :: SLATEGRAY_KW } ; [snow] => { $ crate :: CssSyntaxKind :: SNOW_KW } ; [springgreen] => { $ crate :: CssSyntaxKind :: SPRINGGREEN_KW } ; [steelblue] => { $ crate :: CssSyntaxKind :: STEELBLUE_KW } ; [tan] => { $ crate :: CssSyntaxKind :: TAN_KW } ; [teal] => { $ crate :: CssSyntaxKind :: TEAL_KW } ; [thistle] => { $ crate :: CssSyntaxKind :: THISTLE_KW } ; [tomato] => { $ crate :: CssSyntaxKind :: TOMATO_KW } ; [turquoise] => { $ crate :: CssSyntaxKind :: TURQUOISE_KW } ; [violet] => { $ crate :: CssSyntaxKind :: VIOLET_KW } ; [wheat] => { $ crate :: CssSyntaxKind :: WHEAT_KW } ; [white] => { $ crate :: CssSyntaxKind :: WHITE_KW } ; [whitesmoke] => { $ crate :: CssSyntaxKind :: WHITESMOKE_KW } ; [yellow] => { $ crate :: CssSyntaxKind :: YELLOW_KW } ; [yellowgreen] => { $ crate :: CssSyntaxKind :: YELLOWGREEN_KW } ; [media] => { $ crate :: CssSyntaxKind :: MEDIA_KW } ; [keyframes] => { $ crate :: CssSyntaxKind :: KEYFRAMES_KW } ; [not] => { $ crate :: CssSyntaxKind :: NOT_KW } ; [and] => { $ crate :: CssSyntaxKind :: AND_KW } ; [only] => { $ crate :: CssSyntaxKind :: ONLY_KW } ; [or] => { $ crate :: CssSyntaxKind :: OR_KW } ; [i] => { $ crate :: CssSyntaxKind :: I_KW } ; [important] => { $ crate :: CssSyntaxKind :: IMPORTANT_KW } ; [from] => { $ crate :: CssSyntaxKind :: FROM_KW } ; [to] => { $ crate :: CssSyntaxKind :: TO_KW } ; [var] => { $ crate :: CssSyntaxKind :: VAR_KW } ; [ident] => { $ crate :: CssSyntaxKind :: IDENT } ; [EOF] => { $ crate :: CssSyntaxKind :: EOF } ; [#] => { $ crate :: CssSyntaxKind :: HASH } ; }
+macro_rules ! T { [;] => { $ crate :: CssSyntaxKind :: SEMICOLON } ; [,] => { $ crate :: CssSyntaxKind :: COMMA } ; ['('] => { $ crate :: CssSyntaxKind :: L_PAREN } ; [')'] => { $ crate :: CssSyntaxKind :: R_PAREN } ; ['{'] => { $ crate :: CssSyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: CssSyntaxKind :: R_CURLY } ; ['['] => { $ crate :: CssSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: CssSyntaxKind :: R_BRACK } ; [<] => { $ crate :: CssSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: CssSyntaxKind :: R_ANGLE } ; [~] => { $ crate :: CssSyntaxKind :: TILDE } ; [#] => { $ crate :: CssSyntaxKind :: HASH } ; [&] => { $ crate :: CssSyntaxKind :: AMP } ; [|] => { $ crate :: CssSyntaxKind :: PIPE } ; [+] => { $ crate :: CssSyntaxKind :: PLUS } ; [*] => { $ crate :: CssSyntaxKind :: STAR } ; [/] => { $ crate :: CssSyntaxKind :: SLASH } ; [^] => { $ crate :: CssSyntaxKind :: CARET } ; [%] => { $ crate :: CssSyntaxKind :: PERCENT } ; [.] => { $ crate :: CssSyntaxKind :: DOT } ; [:] => { $ crate :: CssSyntaxKind :: COLON } ; [::] => { $ crate :: CssSyntaxKind :: COLON2 } ; [=] => { $ crate :: CssSyntaxKind :: EQ } ; [!] => { $ crate :: CssSyntaxKind :: BANG } ; [!=] => { $ crate :: CssSyntaxKind :: NEQ } ; [-] => { $ crate :: CssSyntaxKind :: MINUS } ; [<=] => { $ crate :: CssSyntaxKind :: LTEQ } ; [>=] => { $ crate :: CssSyntaxKind :: GTEQ } ; [+=] => { $ crate :: CssSyntaxKind :: PLUSEQ } ; [|=] => { $ crate :: CssSyntaxKind :: PIPEEQ } ; [&=] => { $ crate :: CssSyntaxKind :: AMPEQ } ; [^=] => { $ crate :: CssSyntaxKind :: CARETEQ } ; [/=] => { $ crate :: CssSyntaxKind :: SLASHEQ } ; [*=] => { $ crate :: CssSyntaxKind :: STAREQ } ; [%=] => { $ crate :: CssSyntaxKind :: PERCENTEQ } ; [@] => { $ crate :: CssSyntaxKind :: AT } ; ["$="] => { $ crate :: CssSyntaxKind :: DOLLAR_EQ } ; [~=] => { $ crate :: CssSyntaxKind :: TILDE_EQ } ; [-->] => { $ crate :: CssSyntaxKind :: CDC } ; [<!--] => { $ crate :: CssSyntaxKind :: CDO } ; [aliceblue] => { $ crate :: CssSyntaxKind :: ALICEBLUE_KW } ; [antiquewhite] => { $ crate :: CssSyntaxKind :: ANTIQUEWHITE_KW } ; [aqua] => { $ crate :: CssSyntaxKind :: AQUA_KW } ; [aquamarine] => { $ crate :: CssSyntaxKind :: AQUAMARINE_KW } ; [azure] => { $ crate :: CssSyntaxKind :: AZURE_KW } ; [beige] => { $ crate :: CssSyntaxKind :: BEIGE_KW } ; [bisque] => { $ crate :: CssSyntaxKind :: BISQUE_KW } ; [black] => { $ crate :: CssSyntaxKind :: BLACK_KW } ; [blanchedalmond] => { $ crate :: CssSyntaxKind :: BLANCHEDALMOND_KW } ; [blue] => { $ crate :: CssSyntaxKind :: BLUE_KW } ; [blueviolet] => { $ crate :: CssSyntaxKind :: BLUEVIOLET_KW } ; [brown] => { $ crate :: CssSyntaxKind :: BROWN_KW } ; [burlywood] => { $ crate :: CssSyntaxKind :: BURLYWOOD_KW } ; [cadetblue] => { $ crate :: CssSyntaxKind :: CADETBLUE_KW } ; [chartreuse] => { $ crate :: CssSyntaxKind :: CHARTREUSE_KW } ; [chocolate] => { $ crate :: CssSyntaxKind :: CHOCOLATE_KW } ; [coral] => { $ crate :: CssSyntaxKind :: CORAL_KW } ; [cornflowerblue] => { $ crate :: CssSyntaxKind :: CORNFLOWERBLUE_KW } ; [cornsilk] => { $ crate :: CssSyntaxKind :: CORNSILK_KW } ; [crimson] => { $ crate :: CssSyntaxKind :: CRIMSON_KW } ; [cyan] => { $ crate :: CssSyntaxKind :: CYAN_KW } ; [darkblue] => { $ crate :: CssSyntaxKind :: DARKBLUE_KW } ; [darkcyan] => { $ crate :: CssSyntaxKind :: DARKCYAN_KW } ; [darkgoldenrod] => { $ crate :: CssSyntaxKind :: DARKGOLDENROD_KW } ; [darkgray] => { $ crate :: CssSyntaxKind :: DARKGRAY_KW } ; [darkgreen] => { $ crate :: CssSyntaxKind :: DARKGREEN_KW } ; [darkkhaki] => { $ crate :: CssSyntaxKind :: DARKKHAKI_KW } ; [darkmagenta] => { $ crate :: CssSyntaxKind :: DARKMAGENTA_KW } ; [darkolivegreen] => { $ crate :: CssSyntaxKind :: DARKOLIVEGREEN_KW } ; [darkorange] => { $ crate :: CssSyntaxKind :: DARKORANGE_KW } ; [darkorchid] => { $ crate :: CssSyntaxKind :: DARKORCHID_KW } ; [darkred] => { $ crate :: CssSyntaxKind :: DARKRED_KW } ; [darksalmon] => { $ crate :: CssSyntaxKind :: DARKSALMON_KW } ; [darkseagreen] => { $ crate :: CssSyntaxKind :: DARKSEAGREEN_KW } ; [darkslateblue] => { $ crate :: CssSyntaxKind :: DARKSLATEBLUE_KW } ; [darkslategray] => { $ crate :: CssSyntaxKind :: DARKSLATEGRAY_KW } ; [darkturquoise] => { $ crate :: CssSyntaxKind :: DARKTURQUOISE_KW } ; [darkviolet] => { $ crate :: CssSyntaxKind :: DARKVIOLET_KW } ; [deeppink] => { $ crate :: CssSyntaxKind :: DEEPPINK_KW } ; [deepskyblue] => { $ crate :: CssSyntaxKind :: DEEPSKYBLUE_KW } ; [dimgray] => { $ crate :: CssSyntaxKind :: DIMGRAY_KW } ; [dodgerblue] => { $ crate :: CssSyntaxKind :: DODGERBLUE_KW } ; [firebrick] => { $ crate :: CssSyntaxKind :: FIREBRICK_KW } ; [floralwhite] => { $ crate :: CssSyntaxKind :: FLORALWHITE_KW } ; [forestgreen] => { $ crate :: CssSyntaxKind :: FORESTGREEN_KW } ; [fuchsia] => { $ crate :: CssSyntaxKind :: FUCHSIA_KW } ; [gainsboro] => { $ crate :: CssSyntaxKind :: GAINSBORO_KW } ; [ghostwhite] => { $ crate :: CssSyntaxKind :: GHOSTWHITE_KW } ; [gold] => { $ crate :: CssSyntaxKind :: GOLD_KW } ; [goldenrod] => { $ crate :: CssSyntaxKind :: GOLDENROD_KW } ; [gray] => { $ crate :: CssSyntaxKind :: GRAY_KW } ; [green] => { $ crate :: CssSyntaxKind :: GREEN_KW } ; [greenyellow] => { $ crate :: CssSyntaxKind :: GREENYELLOW_KW } ; [honeydew] => { $ crate :: CssSyntaxKind :: HONEYDEW_KW } ; [hotpink] => { $ crate :: CssSyntaxKind :: HOTPINK_KW } ; [indianred] => { $ crate :: CssSyntaxKind :: INDIANRED_KW } ; [indigo] => { $ crate :: CssSyntaxKind :: INDIGO_KW } ; [ivory] => { $ crate :: CssSyntaxKind :: IVORY_KW } ; [khaki] => { $ crate :: CssSyntaxKind :: KHAKI_KW } ; [lavender] => { $ crate :: CssSyntaxKind :: LAVENDER_KW } ; [lavenderblush] => { $ crate :: CssSyntaxKind :: LAVENDERBLUSH_KW } ; [lawngreen] => { $ crate :: CssSyntaxKind :: LAWNGREEN_KW } ; [lemonchiffon] => { $ crate :: CssSyntaxKind :: LEMONCHIFFON_KW } ; [lightblue] => { $ crate :: CssSyntaxKind :: LIGHTBLUE_KW } ; [lightcoral] => { $ crate :: CssSyntaxKind :: LIGHTCORAL_KW } ; [lightcyan] => { $ crate :: CssSyntaxKind :: LIGHTCYAN_KW } ; [lightgoldenrodyellow] => { $ crate :: CssSyntaxKind :: LIGHTGOLDENRODYELLOW_KW } ; [lightgreen] => { $ crate :: CssSyntaxKind :: LIGHTGREEN_KW } ; [lightgrey] => { $ crate :: CssSyntaxKind :: LIGHTGREY_KW } ; [lightpink] => { $ crate :: CssSyntaxKind :: LIGHTPINK_KW } ; [lightsalmon] => { $ crate :: CssSyntaxKind :: LIGHTSALMON_KW } ; [lightseagreen] => { $ crate :: CssSyntaxKind :: LIGHTSEAGREEN_KW } ; [lightskyblue] => { $ crate :: CssSyntaxKind :: LIGHTSKYBLUE_KW } ; [lightslategray] => { $ crate :: CssSyntaxKind :: LIGHTSLATEGRAY_KW } ; [lightsteelblue] => { $ crate :: CssSyntaxKind :: LIGHTSTEELBLUE_KW } ; [lightyellow] => { $ crate :: CssSyntaxKind :: LIGHTYELLOW_KW } ; [lime] => { $ crate :: CssSyntaxKind :: LIME_KW } ; [limegreen] => { $ crate :: CssSyntaxKind :: LIMEGREEN_KW } ; [linen] => { $ crate :: CssSyntaxKind :: LINEN_KW } ; [magenta] => { $ crate :: CssSyntaxKind :: MAGENTA_KW } ; [maroon] => { $ crate :: CssSyntaxKind :: MAROON_KW } ; [mediumaquamarine] => { $ crate :: CssSyntaxKind :: MEDIUMAQUAMARINE_KW } ; [mediumblue] => { $ crate :: CssSyntaxKind :: MEDIUMBLUE_KW } ; [mediumorchid] => { $ crate :: CssSyntaxKind :: MEDIUMORCHID_KW } ; [mediumpurple] => { $ crate :: CssSyntaxKind :: MEDIUMPURPLE_KW } ; [mediumseagreen] => { $ crate :: CssSyntaxKind :: MEDIUMSEAGREEN_KW } ; [mediumslateblue] => { $ crate :: CssSyntaxKind :: MEDIUMSLATEBLUE_KW } ; [mediumspringgreen] => { $ crate :: CssSyntaxKind :: MEDIUMSPRINGGREEN_KW } ; [mediumturquoise] => { $ crate :: CssSyntaxKind :: MEDIUMTURQUOISE_KW } ; [mediumvioletred] => { $ crate :: CssSyntaxKind :: MEDIUMVIOLETRED_KW } ; [midnightblue] => { $ crate :: CssSyntaxKind :: MIDNIGHTBLUE_KW } ; [mintcream] => { $ crate :: CssSyntaxKind :: MINTCREAM_KW } ; [mistyrose] => { $ crate :: CssSyntaxKind :: MISTYROSE_KW } ; [moccasin] => { $ crate :: CssSyntaxKind :: MOCCASIN_KW } ; [navajowhite] => { $ crate :: CssSyntaxKind :: NAVAJOWHITE_KW } ; [navy] => { $ crate :: CssSyntaxKind :: NAVY_KW } ; [navyblue] => { $ crate :: CssSyntaxKind :: NAVYBLUE_KW } ; [oldlace] => { $ crate :: CssSyntaxKind :: OLDLACE_KW } ; [olive] => { $ crate :: CssSyntaxKind :: OLIVE_KW } ; [olivedrab] => { $ crate :: CssSyntaxKind :: OLIVEDRAB_KW } ; [orange] => { $ crate :: CssSyntaxKind :: ORANGE_KW } ; [orangered] => { $ crate :: CssSyntaxKind :: ORANGERED_KW } ; [orchid] => { $ crate :: CssSyntaxKind :: ORCHID_KW } ; [palegoldenrod] => { $ crate :: CssSyntaxKind :: PALEGOLDENROD_KW } ; [palegreen] => { $ crate :: CssSyntaxKind :: PALEGREEN_KW } ; [paleturquoise] => { $ crate :: CssSyntaxKind :: PALETURQUOISE_KW } ; [palevioletred] => { $ crate :: CssSyntaxKind :: PALEVIOLETRED_KW } ; [papayawhip] => { $ crate :: CssSyntaxKind :: PAPAYAWHIP_KW } ; [peachpuff] => { $ crate :: CssSyntaxKind :: PEACHPUFF_KW } ; [peru] => { $ crate :: CssSyntaxKind :: PERU_KW } ; [pink] => { $ crate :: CssSyntaxKind :: PINK_KW } ; [plum] => { $ crate :: CssSyntaxKind :: PLUM_KW } ; [powderblue] => { $ crate :: CssSyntaxKind :: POWDERBLUE_KW } ; [purple] => { $ crate :: CssSyntaxKind :: PURPLE_KW } ; [red] => { $ crate :: CssSyntaxKind :: RED_KW } ; [rosybrown] => { $ crate :: CssSyntaxKind :: ROSYBROWN_KW } ; [royalblue] => { $ crate :: CssSyntaxKind :: ROYALBLUE_KW } ; [saddlebrown] => { $ crate :: CssSyntaxKind :: SADDLEBROWN_KW } ; [salmon] => { $ crate :: CssSyntaxKind :: SALMON_KW } ; [sandybrown] => { $ crate :: CssSyntaxKind :: SANDYBROWN_KW } ; [seagreen] => { $ crate :: CssSyntaxKind :: SEAGREEN_KW } ; [seashell] => { $ crate :: CssSyntaxKind :: SEASHELL_KW } ; [sienna] => { $ crate :: CssSyntaxKind :: SIENNA_KW } ; [silver] => { $ crate :: CssSyntaxKind :: SILVER_KW } ; [skyblue] => { $ crate :: CssSyntaxKind :: SKYBLUE_KW } ; [slateblue] => { $ crate :: CssSyntaxKind :: SLATEBLUE_KW } ; [slategray] => { $ crate :: CssSyntaxKind :: SLATEGRAY_KW } ; [snow] => { $ crate :: CssSyntaxKind :: SNOW_KW } ; [springgreen] => { $ crate :: CssSyntaxKind :: SPRINGGREEN_KW } ; [steelblue] => { $ crate :: CssSyntaxKind :: STEELBLUE_KW } ; [tan] => { $ crate :: CssSyntaxKind :: TAN_KW } ; [teal] => { $ crate :: CssSyntaxKind :: TEAL_KW } ; [thistle] => { $ crate :: CssSyntaxKind :: THISTLE_KW } ; [tomato] => { $ crate :: CssSyntaxKind :: TOMATO_KW } ; [turquoise] => { $ crate :: CssSyntaxKind :: TURQUOISE_KW } ; [violet] => { $ crate :: CssSyntaxKind :: VIOLET_KW } ; [wheat] => { $ crate :: CssSyntaxKind :: WHEAT_KW } ; [white] => { $ crate :: CssSyntaxKind :: WHITE_KW } ; [whitesmoke] => { $ crate :: CssSyntaxKind :: WHITESMOKE_KW } ; [yellow] => { $ crate :: CssSyntaxKind :: YELLOW_KW } ; [yellowgreen] => { $ crate :: CssSyntaxKind :: YELLOWGREEN_KW } ; [media] => { $ crate :: CssSyntaxKind :: MEDIA_KW } ; [keyframes] => { $ crate :: CssSyntaxKind :: KEYFRAMES_KW } ; [not] => { $ crate :: CssSyntaxKind :: NOT_KW } ; [and] => { $ crate :: CssSyntaxKind :: AND_KW } ; [only] => { $ crate :: CssSyntaxKind :: ONLY_KW } ; [or] => { $ crate :: CssSyntaxKind :: OR_KW } ; [i] => { $ crate :: CssSyntaxKind :: I_KW } ; [important] => { $ crate :: CssSyntaxKind :: IMPORTANT_KW } ; [from] => { $ crate :: CssSyntaxKind :: FROM_KW } ; [to] => { $ crate :: CssSyntaxKind :: TO_KW } ; [var] => { $ crate :: CssSyntaxKind :: VAR_KW } ; [url] => { $ crate :: CssSyntaxKind :: URL_KW } ; [charset] => { $ crate :: CssSyntaxKind :: CHARSET_KW } ; [import] => { $ crate :: CssSyntaxKind :: IMPORT_KW } ; [ident] => { $ crate :: CssSyntaxKind :: IDENT } ; [EOF] => { $ crate :: CssSyntaxKind :: EOF } ; [#] => { $ crate :: CssSyntaxKind :: HASH } ; }
@@ -16,8 +16,16 @@ macro_rules! map_syntax_node {
     ($ node : expr , $ pattern : pat => $ body : expr) => {
         match $node {
             node => match $crate::CssSyntaxNode::kind(&node) {
-                $crate::CssSyntaxKind::CSS_ANY_FUNCTION => {
-                    let $pattern = unsafe { $crate::CssAnyFunction::new_unchecked(node) };
+                $crate::CssSyntaxKind::CSS_AT_CHARSET => {
+                    let $pattern = unsafe { $crate::CssAtCharset::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_AT_GENERIC => {
+                    let $pattern = unsafe { $crate::CssAtGeneric::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_AT_IMPORT => {
+                    let $pattern = unsafe { $crate::CssAtImport::new_unchecked(node) };
                     $body
                 }
                 $crate::CssSyntaxKind::CSS_AT_KEYFRAMES => {
@@ -65,30 +73,20 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssAtMediaQueryFeatureRange::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_AT_MEDIA_QUERY_RANGE => {
-                    let $pattern = unsafe { $crate::CssAtMediaQueryRange::new_unchecked(node) };
+                $crate::CssSyntaxKind::CSS_AT_MEDIA_QUERY_FEATURE_REVERSE_COMPARE => {
+                    let $pattern = unsafe {
+                        $crate::CssAtMediaQueryFeatureReverseCompare::new_unchecked(node)
+                    };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_ATTRIBUTE => {
-                    let $pattern = unsafe { $crate::CssAttribute::new_unchecked(node) };
+                $crate::CssSyntaxKind::CSS_AT_MEDIA_QUERY_RANGE => {
+                    let $pattern = unsafe { $crate::CssAtMediaQueryRange::new_unchecked(node) };
                     $body
                 }
                 $crate::CssSyntaxKind::CSS_ATTRIBUTE_MATCHER => {
                     let $pattern = unsafe { $crate::CssAttributeMatcher::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_ATTRIBUTE_META => {
-                    let $pattern = unsafe { $crate::CssAttributeMeta::new_unchecked(node) };
-                    $body
-                }
-                $crate::CssSyntaxKind::CSS_ATTRIBUTE_MODIFIER => {
-                    let $pattern = unsafe { $crate::CssAttributeModifier::new_unchecked(node) };
-                    $body
-                }
-                $crate::CssSyntaxKind::CSS_ATTRIBUTE_NAME => {
-                    let $pattern = unsafe { $crate::CssAttributeName::new_unchecked(node) };
-                    $body
-                }
                 $crate::CssSyntaxKind::CSS_ATTRIBUTE_SELECTOR_PATTERN => {
                     let $pattern =
                         unsafe { $crate::CssAttributeSelectorPattern::new_unchecked(node) };
@@ -98,13 +96,20 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssBlock::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_BRACKETED_VALUE => {
+                    let $pattern = unsafe { $crate::CssBracketedValue::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_CLASS_SELECTOR_PATTERN => {
                     let $pattern = unsafe { $crate::CssClassSelectorPattern::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_COMBINATOR_SELECTOR_PATTERN => {
-                    let $pattern =
-                        unsafe { $crate::CssCombinatorSelectorPattern::new_unchecked(node) };
+                $crate::CssSyntaxKind::CSS_COLOR => {
+                    let $pattern = unsafe { $crate::CssColor::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_COMPLEX_SELECTOR => {
+                    let $pattern = unsafe { $crate::CssComplexSelector::new_unchecked(node) };
                     $body
                 }
                 $crate::CssSyntaxKind::CSS_CUSTOM_PROPERTY => {
@@ -123,6 +128,14 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssDimension::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_EMPTY_DECLARATION => {
+                    let $pattern = unsafe { $crate::CssEmptyDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_GENERIC_DELIMITER => {
+                    let $pattern = unsafe { $crate::CssGenericDelimiter::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_ID_SELECTOR_PATTERN => {
                     let $pattern = unsafe { $crate::CssIdSelectorPattern::new_unchecked(node) };
                     $body
@@ -139,6 +152,11 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssKeyframesSelector::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_NESTING_SELECTOR_PATTERN => {
+                    let $pattern =
+                        unsafe { $crate::CssNestingSelectorPattern::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_NUMBER => {
                     let $pattern = unsafe { $crate::CssNumber::new_unchecked(node) };
                     $body
@@ -147,6 +165,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssParameter::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_PARENTHESIZED_VALUE => {
+                    let $pattern = unsafe { $crate::CssParenthesizedValue::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_PERCENTAGE => {
                     let $pattern = unsafe { $crate::CssPercentage::new_unchecked(node) };
                     $body
@@ -156,16 +178,28 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssPseudoClassSelectorPattern::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS => {
-                    let $pattern = unsafe {
-                        $crate::CssPseudoClassSelectorPatternParameters::new_unchecked(node)
-                    };
+                $crate::CssSyntaxKind::CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN => {
+                    let $pattern =
+                        unsafe { $crate::CssPseudoElementSelectorPattern::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_PSEUDO_SELECTOR_PARAMETERS => {
+                    let $pattern =
+                        unsafe { $crate::CssPseudoSelectorParameters::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_PSEUDO_VALUE_PARAMETERS => {
+                    let $pattern = unsafe { $crate::CssPseudoValueParameters::new_unchecked(node) };
                     $body
                 }
                 $crate::CssSyntaxKind::CSS_RATIO => {
                     let $pattern = unsafe { $crate::CssRatio::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_RELATIVE_SELECTOR => {
+                    let $pattern = unsafe { $crate::CssRelativeSelector::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_ROOT => {
                     let $pattern = unsafe { $crate::CssRoot::new_unchecked(node) };
                     $body
@@ -195,6 +229,14 @@ macro_rules! map_syntax_node {
                         unsafe { $crate::CssUniversalSelectorPattern::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_URL_FUNCTION => {
+                    let $pattern = unsafe { $crate::CssUrlFunction::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_URL_VALUE_RAW => {
+                    let $pattern = unsafe { $crate::CssUrlValueRaw::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_VAR_FUNCTION => {
                     let $pattern = unsafe { $crate::CssVarFunction::new_unchecked(node) };
                     $body
@@ -207,6 +249,18 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssBogus::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_BOGUS_RULE => {
+                    let $pattern = unsafe { $crate::CssBogusRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_BOGUS_SELECTOR => {
+                    let $pattern = unsafe { $crate::CssBogusSelector::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::CSS_BOGUS_VALUE => {
+                    let $pattern = unsafe { $crate::CssBogusValue::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_ANY_SELECTOR_PATTERN_LIST => {
                     let $pattern =
                         unsafe { $crate::CssAnySelectorPatternList::new_unchecked(node) };
@@ -216,16 +270,21 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssAtKeyframesItemList::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST => {
+                    let $pattern =
+                        unsafe { $crate::CssAtMediaQueryConsequentList::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_AT_MEDIA_QUERY_LIST => {
                     let $pattern = unsafe { $crate::CssAtMediaQueryList::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_ATTRIBUTE_LIST => {
-                    let $pattern = unsafe { $crate::CssAttributeList::new_unchecked(node) };
+                $crate::CssSyntaxKind::CSS_BLOCK_ITEM_LIST => {
+                    let $pattern = unsafe { $crate::CssBlockItemList::new_unchecked(node) };
                     $body
                 }
-                $crate::CssSyntaxKind::CSS_DECLARATION_LIST => {
-                    let $pattern = unsafe { $crate::CssDeclarationList::new_unchecked(node) };
+                $crate::CssSyntaxKind::CSS_COMPONENT_VALUE_LIST => {
+                    let $pattern = unsafe { $crate::CssComponentValueList::new_unchecked(node) };
                     $body
                 }
                 $crate::CssSyntaxKind::CSS_KEYFRAMES_SELECTOR_LIST => {
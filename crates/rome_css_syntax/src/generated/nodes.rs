@@ -20,10 +20,10 @@ use serde::ser::SerializeSeq;
 use serde::{Serialize, Serializer};
 use std::fmt::{Debug, Formatter};
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssAnyFunction {
+pub struct CssAtCharset {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssAnyFunction {
+impl CssAtCharset {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -31,17 +31,128 @@ impl CssAnyFunction {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssAnyFunctionFields {
-        CssAnyFunctionFields {
-            css_simple_function: self.css_simple_function(),
+    pub fn as_fields(&self) -> CssAtCharsetFields {
+        CssAtCharsetFields {
+            at_token: self.at_token(),
+            charset_token: self.charset_token(),
+            encoding: self.encoding(),
+            semicolon_token: self.semicolon_token(),
         }
     }
-    pub fn css_simple_function(&self) -> SyntaxResult<CssSimpleFunction> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn at_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn charset_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn encoding(&self) -> SyntaxResult<CssString> {
+        support::required_node(&self.syntax, 2usize)
+    }
+    pub fn semicolon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssAtCharset {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssAtCharsetFields {
+    pub at_token: SyntaxResult<SyntaxToken>,
+    pub charset_token: SyntaxResult<SyntaxToken>,
+    pub encoding: SyntaxResult<CssString>,
+    pub semicolon_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssAtGeneric {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssAtGeneric {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssAtGenericFields {
+        CssAtGenericFields {
+            at_token: self.at_token(),
+            name: self.name(),
+            prelude: self.prelude(),
+            block: self.block(),
+            semicolon_token: self.semicolon_token(),
+        }
+    }
+    pub fn at_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn prelude(&self) -> CssComponentValueList { support::list(&self.syntax, 2usize) }
+    pub fn block(&self) -> Option<CssBlock> { support::node(&self.syntax, 3usize) }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, 4usize) }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssAtGeneric {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssAtGenericFields {
+    pub at_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<CssIdentifier>,
+    pub prelude: CssComponentValueList,
+    pub block: Option<CssBlock>,
+    pub semicolon_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssAtImport {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssAtImport {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssAtImportFields {
+        CssAtImportFields {
+            at_token: self.at_token(),
+            import_token: self.import_token(),
+            url: self.url(),
+            media: self.media(),
+            semicolon_token: self.semicolon_token(),
+        }
+    }
+    pub fn at_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn import_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn url(&self) -> SyntaxResult<AnyCssImportUrl> {
+        support::required_node(&self.syntax, 2usize)
+    }
+    pub fn media(&self) -> CssAtMediaQueryList { support::list(&self.syntax, 3usize) }
+    pub fn semicolon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 4usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssAnyFunction {
+impl Serialize for CssAtImport {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -50,8 +161,12 @@ impl Serialize for CssAnyFunction {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssAnyFunctionFields {
-    pub css_simple_function: SyntaxResult<CssSimpleFunction>,
+pub struct CssAtImportFields {
+    pub at_token: SyntaxResult<SyntaxToken>,
+    pub import_token: SyntaxResult<SyntaxToken>,
+    pub url: SyntaxResult<AnyCssImportUrl>,
+    pub media: CssAtMediaQueryList,
+    pub semicolon_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAtKeyframes {
@@ -70,7 +185,6 @@ impl CssAtKeyframes {
             at_token: self.at_token(),
             keyframes_token: self.keyframes_token(),
             name: self.name(),
-            css_string: self.css_string(),
             body: self.body(),
         }
     }
@@ -80,14 +194,11 @@ impl CssAtKeyframes {
     pub fn keyframes_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 1usize)
     }
-    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+    pub fn name(&self) -> SyntaxResult<AnyCssKeyframesName> {
         support::required_node(&self.syntax, 2usize)
     }
-    pub fn css_string(&self) -> SyntaxResult<CssString> {
-        support::required_node(&self.syntax, 3usize)
-    }
     pub fn body(&self) -> SyntaxResult<CssAtKeyframesBody> {
-        support::required_node(&self.syntax, 4usize)
+        support::required_node(&self.syntax, 3usize)
     }
 }
 #[cfg(feature = "serde")]
@@ -103,8 +214,7 @@ impl Serialize for CssAtKeyframes {
 pub struct CssAtKeyframesFields {
     pub at_token: SyntaxResult<SyntaxToken>,
     pub keyframes_token: SyntaxResult<SyntaxToken>,
-    pub name: SyntaxResult<CssIdentifier>,
-    pub css_string: SyntaxResult<CssString>,
+    pub name: SyntaxResult<AnyCssKeyframesName>,
     pub body: SyntaxResult<CssAtKeyframesBody>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -166,9 +276,7 @@ impl CssAtMedia {
             at_token: self.at_token(),
             media_token: self.media_token(),
             query_list: self.query_list(),
-            l_curly_token: self.l_curly_token(),
-            body: self.body(),
-            r_curly_token: self.r_curly_token(),
+            block: self.block(),
         }
     }
     pub fn at_token(&self) -> SyntaxResult<SyntaxToken> {
@@ -178,13 +286,7 @@ impl CssAtMedia {
         support::required_token(&self.syntax, 1usize)
     }
     pub fn query_list(&self) -> CssAtMediaQueryList { support::list(&self.syntax, 2usize) }
-    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
-    }
-    pub fn body(&self) -> SyntaxResult<AnyCssRule> { support::required_node(&self.syntax, 4usize) }
-    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 5usize)
-    }
+    pub fn block(&self) -> SyntaxResult<CssBlock> { support::required_node(&self.syntax, 3usize) }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssAtMedia {
@@ -200,9 +302,7 @@ pub struct CssAtMediaFields {
     pub at_token: SyntaxResult<SyntaxToken>,
     pub media_token: SyntaxResult<SyntaxToken>,
     pub query_list: CssAtMediaQueryList,
-    pub l_curly_token: SyntaxResult<SyntaxToken>,
-    pub body: SyntaxResult<AnyCssRule>,
-    pub r_curly_token: SyntaxResult<SyntaxToken>,
+    pub block: SyntaxResult<CssBlock>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAtMediaQuery {
@@ -218,25 +318,17 @@ impl CssAtMediaQuery {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssAtMediaQueryFields {
         CssAtMediaQueryFields {
-            condition_token: self.condition_token(),
-            or_token: self.or_token(),
-            only_token: self.only_token(),
+            condition: self.condition(),
             ty: self.ty(),
-            consequent: self.consequent(),
+            consequent_list: self.consequent_list(),
         }
     }
-    pub fn condition_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-    pub fn or_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn only_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, 2usize) }
+    pub fn condition(&self) -> Option<SyntaxToken> { support::token(&self.syntax, 0usize) }
     pub fn ty(&self) -> SyntaxResult<AnyCssAtMediaQueryType> {
-        support::required_node(&self.syntax, 3usize)
+        support::required_node(&self.syntax, 1usize)
     }
-    pub fn consequent(&self) -> Option<CssAtMediaQueryConsequent> {
-        support::node(&self.syntax, 4usize)
+    pub fn consequent_list(&self) -> CssAtMediaQueryConsequentList {
+        support::list(&self.syntax, 2usize)
     }
 }
 #[cfg(feature = "serde")]
@@ -250,11 +342,9 @@ impl Serialize for CssAtMediaQuery {
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CssAtMediaQueryFields {
-    pub condition_token: SyntaxResult<SyntaxToken>,
-    pub or_token: SyntaxResult<SyntaxToken>,
-    pub only_token: Option<SyntaxToken>,
+    pub condition: Option<SyntaxToken>,
     pub ty: SyntaxResult<AnyCssAtMediaQueryType>,
-    pub consequent: Option<CssAtMediaQueryConsequent>,
+    pub consequent_list: CssAtMediaQueryConsequentList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAtMediaQueryConsequent {
@@ -270,12 +360,12 @@ impl CssAtMediaQueryConsequent {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssAtMediaQueryConsequentFields {
         CssAtMediaQueryConsequentFields {
-            and_token: self.and_token(),
+            operator: self.operator(),
             condition_token: self.condition_token(),
             ty: self.ty(),
         }
     }
-    pub fn and_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn operator(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
     pub fn condition_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, 1usize) }
@@ -294,7 +384,7 @@ impl Serialize for CssAtMediaQueryConsequent {
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CssAtMediaQueryConsequentFields {
-    pub and_token: SyntaxResult<SyntaxToken>,
+    pub operator: SyntaxResult<SyntaxToken>,
     pub condition_token: Option<SyntaxToken>,
     pub ty: SyntaxResult<AnyCssAtMediaQueryType>,
 }
@@ -355,11 +445,9 @@ impl CssAtMediaQueryFeatureBoolean {
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssAtMediaQueryFeatureBooleanFields {
-        CssAtMediaQueryFeatureBooleanFields {
-            css_identifier: self.css_identifier(),
-        }
+        CssAtMediaQueryFeatureBooleanFields { name: self.name() }
     }
-    pub fn css_identifier(&self) -> SyntaxResult<CssIdentifier> {
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
         support::required_node(&self.syntax, 0usize)
     }
 }
@@ -374,7 +462,7 @@ impl Serialize for CssAtMediaQueryFeatureBoolean {
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CssAtMediaQueryFeatureBooleanFields {
-    pub css_identifier: SyntaxResult<CssIdentifier>,
+    pub name: SyntaxResult<CssIdentifier>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAtMediaQueryFeatureCompare {
@@ -481,8 +569,8 @@ impl CssAtMediaQueryFeatureRange {
             first_value: self.first_value(),
             first_range: self.first_range(),
             name: self.name(),
-            second_value: self.second_value(),
             second_range: self.second_range(),
+            second_value: self.second_value(),
         }
     }
     pub fn first_value(&self) -> SyntaxResult<AnyCssValue> {
@@ -494,10 +582,10 @@ impl CssAtMediaQueryFeatureRange {
     pub fn name(&self) -> SyntaxResult<CssIdentifier> {
         support::required_node(&self.syntax, 2usize)
     }
-    pub fn second_value(&self) -> SyntaxResult<AnyCssValue> {
+    pub fn second_range(&self) -> SyntaxResult<CssAtMediaQueryRange> {
         support::required_node(&self.syntax, 3usize)
     }
-    pub fn second_range(&self) -> SyntaxResult<CssAtMediaQueryRange> {
+    pub fn second_value(&self) -> SyntaxResult<AnyCssValue> {
         support::required_node(&self.syntax, 4usize)
     }
 }
@@ -515,14 +603,14 @@ pub struct CssAtMediaQueryFeatureRangeFields {
     pub first_value: SyntaxResult<AnyCssValue>,
     pub first_range: SyntaxResult<CssAtMediaQueryRange>,
     pub name: SyntaxResult<CssIdentifier>,
-    pub second_value: SyntaxResult<AnyCssValue>,
     pub second_range: SyntaxResult<CssAtMediaQueryRange>,
+    pub second_value: SyntaxResult<AnyCssValue>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssAtMediaQueryRange {
+pub struct CssAtMediaQueryFeatureReverseCompare {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssAtMediaQueryRange {
+impl CssAtMediaQueryFeatureReverseCompare {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -530,29 +618,25 @@ impl CssAtMediaQueryRange {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssAtMediaQueryRangeFields {
-        CssAtMediaQueryRangeFields {
-            r_angle_token: self.r_angle_token(),
-            l_angle_token: self.l_angle_token(),
-            greater_than_equal_token: self.greater_than_equal_token(),
-            less_than_equal_token: self.less_than_equal_token(),
+    pub fn as_fields(&self) -> CssAtMediaQueryFeatureReverseCompareFields {
+        CssAtMediaQueryFeatureReverseCompareFields {
+            value: self.value(),
+            range: self.range(),
+            name: self.name(),
         }
     }
-    pub fn r_angle_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-    pub fn l_angle_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
+    pub fn value(&self) -> SyntaxResult<AnyCssValue> {
+        support::required_node(&self.syntax, 0usize)
     }
-    pub fn greater_than_equal_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
+    pub fn range(&self) -> SyntaxResult<CssAtMediaQueryRange> {
+        support::required_node(&self.syntax, 1usize)
     }
-    pub fn less_than_equal_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 2usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssAtMediaQueryRange {
+impl Serialize for CssAtMediaQueryFeatureReverseCompare {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -561,17 +645,16 @@ impl Serialize for CssAtMediaQueryRange {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssAtMediaQueryRangeFields {
-    pub r_angle_token: SyntaxResult<SyntaxToken>,
-    pub l_angle_token: SyntaxResult<SyntaxToken>,
-    pub greater_than_equal_token: SyntaxResult<SyntaxToken>,
-    pub less_than_equal_token: SyntaxResult<SyntaxToken>,
+pub struct CssAtMediaQueryFeatureReverseCompareFields {
+    pub value: SyntaxResult<AnyCssValue>,
+    pub range: SyntaxResult<CssAtMediaQueryRange>,
+    pub name: SyntaxResult<CssIdentifier>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssAttribute {
+pub struct CssAtMediaQueryRange {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssAttribute {
+impl CssAtMediaQueryRange {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -579,27 +662,17 @@ impl CssAttribute {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssAttributeFields {
-        CssAttributeFields {
-            l_brack_token: self.l_brack_token(),
-            attribute_name: self.attribute_name(),
-            attribute_meta: self.attribute_meta(),
-            r_brack_token: self.r_brack_token(),
+    pub fn as_fields(&self) -> CssAtMediaQueryRangeFields {
+        CssAtMediaQueryRangeFields {
+            operator: self.operator(),
         }
     }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn operator(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn attribute_name(&self) -> SyntaxResult<CssAttributeName> {
-        support::required_node(&self.syntax, 1usize)
-    }
-    pub fn attribute_meta(&self) -> Option<CssAttributeMeta> { support::node(&self.syntax, 2usize) }
-    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
-    }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssAttribute {
+impl Serialize for CssAtMediaQueryRange {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -608,11 +681,8 @@ impl Serialize for CssAttribute {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssAttributeFields {
-    pub l_brack_token: SyntaxResult<SyntaxToken>,
-    pub attribute_name: SyntaxResult<CssAttributeName>,
-    pub attribute_meta: Option<CssAttributeMeta>,
-    pub r_brack_token: SyntaxResult<SyntaxToken>,
+pub struct CssAtMediaQueryRangeFields {
+    pub operator: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssAttributeMatcher {
@@ -628,40 +698,18 @@ impl CssAttributeMatcher {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssAttributeMatcherFields {
         CssAttributeMatcherFields {
-            matcher_type_token: self.matcher_type_token(),
-            exactly_or_hyphen_token: self.exactly_or_hyphen_token(),
-            prefix_token: self.prefix_token(),
-            suffix_token: self.suffix_token(),
-            times_assign_token: self.times_assign_token(),
-            eq_token: self.eq_token(),
-            matcher_name: self.matcher_name(),
-            css_identifier: self.css_identifier(),
+            operator: self.operator(),
+            value: self.value(),
+            modifier: self.modifier(),
         }
     }
-    pub fn matcher_type_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn operator(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn exactly_or_hyphen_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn prefix_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
-    }
-    pub fn suffix_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
-    }
-    pub fn times_assign_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 4usize)
-    }
-    pub fn eq_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 5usize)
-    }
-    pub fn matcher_name(&self) -> SyntaxResult<CssString> {
-        support::required_node(&self.syntax, 6usize)
-    }
-    pub fn css_identifier(&self) -> SyntaxResult<CssIdentifier> {
-        support::required_node(&self.syntax, 7usize)
+    pub fn value(&self) -> SyntaxResult<AnyCssAttributeMatcherValue> {
+        support::required_node(&self.syntax, 1usize)
     }
+    pub fn modifier(&self) -> Option<CssIdentifier> { support::node(&self.syntax, 2usize) }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssAttributeMatcher {
@@ -674,20 +722,15 @@ impl Serialize for CssAttributeMatcher {
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CssAttributeMatcherFields {
-    pub matcher_type_token: SyntaxResult<SyntaxToken>,
-    pub exactly_or_hyphen_token: SyntaxResult<SyntaxToken>,
-    pub prefix_token: SyntaxResult<SyntaxToken>,
-    pub suffix_token: SyntaxResult<SyntaxToken>,
-    pub times_assign_token: SyntaxResult<SyntaxToken>,
-    pub eq_token: SyntaxResult<SyntaxToken>,
-    pub matcher_name: SyntaxResult<CssString>,
-    pub css_identifier: SyntaxResult<CssIdentifier>,
+    pub operator: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<AnyCssAttributeMatcherValue>,
+    pub modifier: Option<CssIdentifier>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssAttributeMeta {
+pub struct CssAttributeSelectorPattern {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssAttributeMeta {
+impl CssAttributeSelectorPattern {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -695,21 +738,27 @@ impl CssAttributeMeta {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssAttributeMetaFields {
-        CssAttributeMetaFields {
-            attribute_matcher: self.attribute_matcher(),
-            attribute_modifier: self.attribute_modifier(),
+    pub fn as_fields(&self) -> CssAttributeSelectorPatternFields {
+        CssAttributeSelectorPatternFields {
+            l_brack_token: self.l_brack_token(),
+            name: self.name(),
+            matcher: self.matcher(),
+            r_brack_token: self.r_brack_token(),
         }
     }
-    pub fn attribute_matcher(&self) -> Option<CssAttributeMatcher> {
-        support::node(&self.syntax, 0usize)
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 1usize)
     }
-    pub fn attribute_modifier(&self) -> Option<CssAttributeModifier> {
-        support::node(&self.syntax, 1usize)
+    pub fn matcher(&self) -> Option<CssAttributeMatcher> { support::node(&self.syntax, 2usize) }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssAttributeMeta {
+impl Serialize for CssAttributeSelectorPattern {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -718,15 +767,17 @@ impl Serialize for CssAttributeMeta {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssAttributeMetaFields {
-    pub attribute_matcher: Option<CssAttributeMatcher>,
-    pub attribute_modifier: Option<CssAttributeModifier>,
+pub struct CssAttributeSelectorPatternFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<CssIdentifier>,
+    pub matcher: Option<CssAttributeMatcher>,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssAttributeModifier {
+pub struct CssBlock {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssAttributeModifier {
+impl CssBlock {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -734,17 +785,23 @@ impl CssAttributeModifier {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssAttributeModifierFields {
-        CssAttributeModifierFields {
-            i_token: self.i_token(),
+    pub fn as_fields(&self) -> CssBlockFields {
+        CssBlockFields {
+            l_curly_token: self.l_curly_token(),
+            items: self.items(),
+            r_curly_token: self.r_curly_token(),
         }
     }
-    pub fn i_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
+    pub fn items(&self) -> CssBlockItemList { support::list(&self.syntax, 1usize) }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssAttributeModifier {
+impl Serialize for CssBlock {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -753,14 +810,16 @@ impl Serialize for CssAttributeModifier {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssAttributeModifierFields {
-    pub i_token: SyntaxResult<SyntaxToken>,
+pub struct CssBlockFields {
+    pub l_curly_token: SyntaxResult<SyntaxToken>,
+    pub items: CssBlockItemList,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssAttributeName {
+pub struct CssBracketedValue {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssAttributeName {
+impl CssBracketedValue {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -768,17 +827,23 @@ impl CssAttributeName {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssAttributeNameFields {
-        CssAttributeNameFields {
-            css_string: self.css_string(),
+    pub fn as_fields(&self) -> CssBracketedValueFields {
+        CssBracketedValueFields {
+            l_brack_token: self.l_brack_token(),
+            values: self.values(),
+            r_brack_token: self.r_brack_token(),
         }
     }
-    pub fn css_string(&self) -> SyntaxResult<CssString> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn values(&self) -> CssComponentValueList { support::list(&self.syntax, 1usize) }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssAttributeName {
+impl Serialize for CssBracketedValue {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -787,14 +852,16 @@ impl Serialize for CssAttributeName {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssAttributeNameFields {
-    pub css_string: SyntaxResult<CssString>,
+pub struct CssBracketedValueFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub values: CssComponentValueList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssAttributeSelectorPattern {
+pub struct CssClassSelectorPattern {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssAttributeSelectorPattern {
+impl CssClassSelectorPattern {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -802,19 +869,21 @@ impl CssAttributeSelectorPattern {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssAttributeSelectorPatternFields {
-        CssAttributeSelectorPatternFields {
+    pub fn as_fields(&self) -> CssClassSelectorPatternFields {
+        CssClassSelectorPatternFields {
+            dot_token: self.dot_token(),
             name: self.name(),
-            attribute_list: self.attribute_list(),
         }
     }
+    pub fn dot_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
     pub fn name(&self) -> SyntaxResult<CssIdentifier> {
-        support::required_node(&self.syntax, 0usize)
+        support::required_node(&self.syntax, 1usize)
     }
-    pub fn attribute_list(&self) -> CssAttributeList { support::list(&self.syntax, 1usize) }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssAttributeSelectorPattern {
+impl Serialize for CssClassSelectorPattern {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -823,15 +892,15 @@ impl Serialize for CssAttributeSelectorPattern {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssAttributeSelectorPatternFields {
+pub struct CssClassSelectorPatternFields {
+    pub dot_token: SyntaxResult<SyntaxToken>,
     pub name: SyntaxResult<CssIdentifier>,
-    pub attribute_list: CssAttributeList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssBlock {
+pub struct CssColor {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssBlock {
+impl CssColor {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -839,23 +908,21 @@ impl CssBlock {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssBlockFields {
-        CssBlockFields {
-            l_curly_token: self.l_curly_token(),
-            declaration_list: self.declaration_list(),
-            r_curly_token: self.r_curly_token(),
+    pub fn as_fields(&self) -> CssColorFields {
+        CssColorFields {
+            hash_token: self.hash_token(),
+            value_token: self.value_token(),
         }
     }
-    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn hash_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn declaration_list(&self) -> CssDeclarationList { support::list(&self.syntax, 1usize) }
-    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssBlock {
+impl Serialize for CssColor {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -864,16 +931,15 @@ impl Serialize for CssBlock {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssBlockFields {
-    pub l_curly_token: SyntaxResult<SyntaxToken>,
-    pub declaration_list: CssDeclarationList,
-    pub r_curly_token: SyntaxResult<SyntaxToken>,
+pub struct CssColorFields {
+    pub hash_token: SyntaxResult<SyntaxToken>,
+    pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssClassSelectorPattern {
+pub struct CssComplexSelector {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssClassSelectorPattern {
+impl CssComplexSelector {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -881,76 +947,23 @@ impl CssClassSelectorPattern {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssClassSelectorPatternFields {
-        CssClassSelectorPatternFields {
-            dot_token: self.dot_token(),
-            name: self.name(),
-        }
-    }
-    pub fn dot_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
-        support::required_node(&self.syntax, 1usize)
-    }
-}
-#[cfg(feature = "serde")]
-impl Serialize for CssClassSelectorPattern {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssClassSelectorPatternFields {
-    pub dot_token: SyntaxResult<SyntaxToken>,
-    pub name: SyntaxResult<CssIdentifier>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssCombinatorSelectorPattern {
-    pub(crate) syntax: SyntaxNode,
-}
-impl CssCombinatorSelectorPattern {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssCombinatorSelectorPatternFields {
-        CssCombinatorSelectorPatternFields {
+    pub fn as_fields(&self) -> CssComplexSelectorFields {
+        CssComplexSelectorFields {
             left: self.left(),
-            combinator_token: self.combinator_token(),
-            plus_token: self.plus_token(),
-            bitwise_not_token: self.bitwise_not_token(),
-            css_space_literal_token: self.css_space_literal_token(),
+            combinator: self.combinator(),
             right: self.right(),
         }
     }
-    pub fn left(&self) -> SyntaxResult<AnyCssSelectorPattern> {
+    pub fn left(&self) -> SyntaxResult<AnyCssSelector> {
         support::required_node(&self.syntax, 0usize)
     }
-    pub fn combinator_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn plus_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
-    }
-    pub fn bitwise_not_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
-    }
-    pub fn css_space_literal_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 4usize)
-    }
-    pub fn right(&self) -> SyntaxResult<AnyCssSelectorPattern> {
-        support::required_node(&self.syntax, 5usize)
+    pub fn combinator(&self) -> Option<SyntaxToken> { support::token(&self.syntax, 1usize) }
+    pub fn right(&self) -> SyntaxResult<CssSelector> {
+        support::required_node(&self.syntax, 2usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssCombinatorSelectorPattern {
+impl Serialize for CssComplexSelector {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -959,13 +972,10 @@ impl Serialize for CssCombinatorSelectorPattern {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssCombinatorSelectorPatternFields {
-    pub left: SyntaxResult<AnyCssSelectorPattern>,
-    pub combinator_token: SyntaxResult<SyntaxToken>,
-    pub plus_token: SyntaxResult<SyntaxToken>,
-    pub bitwise_not_token: SyntaxResult<SyntaxToken>,
-    pub css_space_literal_token: SyntaxResult<SyntaxToken>,
-    pub right: SyntaxResult<AnyCssSelectorPattern>,
+pub struct CssComplexSelectorFields {
+    pub left: SyntaxResult<AnyCssSelector>,
+    pub combinator: Option<SyntaxToken>,
+    pub right: SyntaxResult<CssSelector>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssCustomProperty {
@@ -1016,27 +1026,23 @@ impl CssDeclaration {
     pub fn as_fields(&self) -> CssDeclarationFields {
         CssDeclarationFields {
             name: self.name(),
-            css_custom_property: self.css_custom_property(),
             colon_token: self.colon_token(),
             value: self.value(),
             important: self.important(),
+            semicolon_token: self.semicolon_token(),
         }
     }
-    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+    pub fn name(&self) -> SyntaxResult<AnyCssDeclarationName> {
         support::required_node(&self.syntax, 0usize)
     }
-    pub fn css_custom_property(&self) -> SyntaxResult<CssCustomProperty> {
-        support::required_node(&self.syntax, 1usize)
-    }
     pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
-    }
-    pub fn value(&self) -> SyntaxResult<AnyCssValue> {
-        support::required_node(&self.syntax, 3usize)
+        support::required_token(&self.syntax, 1usize)
     }
+    pub fn value(&self) -> CssComponentValueList { support::list(&self.syntax, 2usize) }
     pub fn important(&self) -> Option<CssDeclarationImportant> {
-        support::node(&self.syntax, 4usize)
+        support::node(&self.syntax, 3usize)
     }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, 4usize) }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssDeclaration {
@@ -1049,11 +1055,11 @@ impl Serialize for CssDeclaration {
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CssDeclarationFields {
-    pub name: SyntaxResult<CssIdentifier>,
-    pub css_custom_property: SyntaxResult<CssCustomProperty>,
+    pub name: SyntaxResult<AnyCssDeclarationName>,
     pub colon_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<AnyCssValue>,
+    pub value: CssComponentValueList,
     pub important: Option<CssDeclarationImportant>,
+    pub semicolon_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssDeclarationImportant {
@@ -1132,6 +1138,74 @@ pub struct CssDimensionFields {
     pub unit: SyntaxResult<CssIdentifier>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssEmptyDeclaration {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssEmptyDeclaration {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssEmptyDeclarationFields {
+        CssEmptyDeclarationFields {
+            semicolon_token: self.semicolon_token(),
+        }
+    }
+    pub fn semicolon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssEmptyDeclaration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssEmptyDeclarationFields {
+    pub semicolon_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssGenericDelimiter {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssGenericDelimiter {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssGenericDelimiterFields {
+        CssGenericDelimiterFields {
+            value: self.value(),
+        }
+    }
+    pub fn value(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssGenericDelimiter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssGenericDelimiterFields {
+    pub value: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssIdSelectorPattern {
     pub(crate) syntax: SyntaxNode,
 }
@@ -1219,19 +1293,11 @@ impl CssKeyframesBlock {
     pub fn as_fields(&self) -> CssKeyframesBlockFields {
         CssKeyframesBlockFields {
             selectors: self.selectors(),
-            l_curly_token: self.l_curly_token(),
-            declarations: self.declarations(),
-            r_curly_token: self.r_curly_token(),
+            block: self.block(),
         }
     }
     pub fn selectors(&self) -> CssKeyframesSelectorList { support::list(&self.syntax, 0usize) }
-    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn declarations(&self) -> CssDeclarationList { support::list(&self.syntax, 2usize) }
-    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
-    }
+    pub fn block(&self) -> SyntaxResult<CssBlock> { support::required_node(&self.syntax, 1usize) }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssKeyframesBlock {
@@ -1245,9 +1311,7 @@ impl Serialize for CssKeyframesBlock {
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CssKeyframesBlockFields {
     pub selectors: CssKeyframesSelectorList,
-    pub l_curly_token: SyntaxResult<SyntaxToken>,
-    pub declarations: CssDeclarationList,
-    pub r_curly_token: SyntaxResult<SyntaxToken>,
+    pub block: SyntaxResult<CssBlock>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssKeyframesSelector {
@@ -1263,20 +1327,12 @@ impl CssKeyframesSelector {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssKeyframesSelectorFields {
         CssKeyframesSelectorFields {
-            from_token: self.from_token(),
-            to_token: self.to_token(),
-            css_percentage: self.css_percentage(),
+            selector: self.selector(),
         }
     }
-    pub fn from_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn selector(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn to_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn css_percentage(&self) -> SyntaxResult<CssPercentage> {
-        support::required_node(&self.syntax, 2usize)
-    }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssKeyframesSelector {
@@ -1289,9 +1345,41 @@ impl Serialize for CssKeyframesSelector {
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CssKeyframesSelectorFields {
-    pub from_token: SyntaxResult<SyntaxToken>,
-    pub to_token: SyntaxResult<SyntaxToken>,
-    pub css_percentage: SyntaxResult<CssPercentage>,
+    pub selector: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssNestingSelectorPattern {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssNestingSelectorPattern {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssNestingSelectorPatternFields {
+        CssNestingSelectorPatternFields {
+            amp_token: self.amp_token(),
+        }
+    }
+    pub fn amp_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssNestingSelectorPattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssNestingSelectorPatternFields {
+    pub amp_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssNumber {
@@ -1341,12 +1429,10 @@ impl CssParameter {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
     pub fn as_fields(&self) -> CssParameterFields {
         CssParameterFields {
-            any_css_value: self.any_css_value(),
+            values: self.values(),
         }
     }
-    pub fn any_css_value(&self) -> SyntaxResult<AnyCssValue> {
-        support::required_node(&self.syntax, 0usize)
-    }
+    pub fn values(&self) -> CssComponentValueList { support::list(&self.syntax, 0usize) }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssParameter {
@@ -1359,7 +1445,49 @@ impl Serialize for CssParameter {
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CssParameterFields {
-    pub any_css_value: SyntaxResult<AnyCssValue>,
+    pub values: CssComponentValueList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssParenthesizedValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssParenthesizedValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssParenthesizedValueFields {
+        CssParenthesizedValueFields {
+            l_paren_token: self.l_paren_token(),
+            values: self.values(),
+            r_paren_token: self.r_paren_token(),
+        }
+    }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn values(&self) -> CssComponentValueList { support::list(&self.syntax, 1usize) }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssParenthesizedValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssParenthesizedValueFields {
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub values: CssComponentValueList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssPercentage {
@@ -1423,7 +1551,7 @@ impl CssPseudoClassSelectorPattern {
     pub fn name(&self) -> SyntaxResult<CssIdentifier> {
         support::required_node(&self.syntax, 1usize)
     }
-    pub fn parameters(&self) -> Option<CssPseudoClassSelectorPatternParameters> {
+    pub fn parameters(&self) -> Option<AnyCssPseudoParameters> {
         support::node(&self.syntax, 2usize)
     }
 }
@@ -1440,13 +1568,13 @@ impl Serialize for CssPseudoClassSelectorPattern {
 pub struct CssPseudoClassSelectorPatternFields {
     pub colon_token: SyntaxResult<SyntaxToken>,
     pub name: SyntaxResult<CssIdentifier>,
-    pub parameters: Option<CssPseudoClassSelectorPatternParameters>,
+    pub parameters: Option<AnyCssPseudoParameters>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssPseudoClassSelectorPatternParameters {
+pub struct CssPseudoElementSelectorPattern {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssPseudoClassSelectorPatternParameters {
+impl CssPseudoElementSelectorPattern {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1454,25 +1582,25 @@ impl CssPseudoClassSelectorPatternParameters {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssPseudoClassSelectorPatternParametersFields {
-        CssPseudoClassSelectorPatternParametersFields {
-            l_paren_token: self.l_paren_token(),
-            parameter: self.parameter(),
-            r_paren_token: self.r_paren_token(),
+    pub fn as_fields(&self) -> CssPseudoElementSelectorPatternFields {
+        CssPseudoElementSelectorPatternFields {
+            double_colon_token: self.double_colon_token(),
+            name: self.name(),
+            parameters: self.parameters(),
         }
     }
-    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn double_colon_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn parameter(&self) -> SyntaxResult<AnyCssValue> {
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
         support::required_node(&self.syntax, 1usize)
     }
-    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
+    pub fn parameters(&self) -> Option<AnyCssPseudoParameters> {
+        support::node(&self.syntax, 2usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssPseudoClassSelectorPatternParameters {
+impl Serialize for CssPseudoElementSelectorPattern {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1481,16 +1609,16 @@ impl Serialize for CssPseudoClassSelectorPatternParameters {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssPseudoClassSelectorPatternParametersFields {
-    pub l_paren_token: SyntaxResult<SyntaxToken>,
-    pub parameter: SyntaxResult<AnyCssValue>,
-    pub r_paren_token: SyntaxResult<SyntaxToken>,
+pub struct CssPseudoElementSelectorPatternFields {
+    pub double_colon_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<CssIdentifier>,
+    pub parameters: Option<AnyCssPseudoParameters>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssRatio {
+pub struct CssPseudoSelectorParameters {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssRatio {
+impl CssPseudoSelectorParameters {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1498,21 +1626,23 @@ impl CssRatio {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssRatioFields {
-        CssRatioFields {
-            numerator: self.numerator(),
-            denominator: self.denominator(),
+    pub fn as_fields(&self) -> CssPseudoSelectorParametersFields {
+        CssPseudoSelectorParametersFields {
+            l_paren_token: self.l_paren_token(),
+            selectors: self.selectors(),
+            r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn numerator(&self) -> SyntaxResult<CssNumber> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn denominator(&self) -> SyntaxResult<CssNumber> {
-        support::required_node(&self.syntax, 1usize)
+    pub fn selectors(&self) -> CssSelectorList { support::list(&self.syntax, 1usize) }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssRatio {
+impl Serialize for CssPseudoSelectorParameters {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1521,15 +1651,16 @@ impl Serialize for CssRatio {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssRatioFields {
-    pub numerator: SyntaxResult<CssNumber>,
-    pub denominator: SyntaxResult<CssNumber>,
+pub struct CssPseudoSelectorParametersFields {
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub selectors: CssSelectorList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssRoot {
+pub struct CssPseudoValueParameters {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssRoot {
+impl CssPseudoValueParameters {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1537,19 +1668,23 @@ impl CssRoot {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssRootFields {
-        CssRootFields {
-            rules: self.rules(),
-            eof_token: self.eof_token(),
+    pub fn as_fields(&self) -> CssPseudoValueParametersFields {
+        CssPseudoValueParametersFields {
+            l_paren_token: self.l_paren_token(),
+            values: self.values(),
+            r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn rules(&self) -> CssRuleList { support::list(&self.syntax, 0usize) }
-    pub fn eof_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn values(&self) -> CssComponentValueList { support::list(&self.syntax, 1usize) }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssRoot {
+impl Serialize for CssPseudoValueParameters {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1558,15 +1693,16 @@ impl Serialize for CssRoot {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct CssRootFields {
-    pub rules: CssRuleList,
-    pub eof_token: SyntaxResult<SyntaxToken>,
+pub struct CssPseudoValueParametersFields {
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub values: CssComponentValueList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct CssRule {
+pub struct CssRatio {
     pub(crate) syntax: SyntaxNode,
 }
-impl CssRule {
+impl CssRatio {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1574,19 +1710,139 @@ impl CssRule {
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
-    pub fn as_fields(&self) -> CssRuleFields {
-        CssRuleFields {
-            prelude: self.prelude(),
-            block: self.block(),
+    pub fn as_fields(&self) -> CssRatioFields {
+        CssRatioFields {
+            numerator: self.numerator(),
+            slash_token: self.slash_token(),
+            denominator: self.denominator(),
         }
     }
-    pub fn prelude(&self) -> CssSelectorList { support::list(&self.syntax, 0usize) }
-    pub fn block(&self) -> SyntaxResult<CssBlock> { support::required_node(&self.syntax, 1usize) }
-}
-#[cfg(feature = "serde")]
-impl Serialize for CssRule {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
+    pub fn numerator(&self) -> SyntaxResult<CssNumber> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn slash_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn denominator(&self) -> SyntaxResult<CssNumber> {
+        support::required_node(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssRatio {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssRatioFields {
+    pub numerator: SyntaxResult<CssNumber>,
+    pub slash_token: SyntaxResult<SyntaxToken>,
+    pub denominator: SyntaxResult<CssNumber>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssRelativeSelector {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssRelativeSelector {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssRelativeSelectorFields {
+        CssRelativeSelectorFields {
+            combinator: self.combinator(),
+            selector: self.selector(),
+        }
+    }
+    pub fn combinator(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn selector(&self) -> SyntaxResult<AnyCssSelector> {
+        support::required_node(&self.syntax, 1usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssRelativeSelector {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssRelativeSelectorFields {
+    pub combinator: SyntaxResult<SyntaxToken>,
+    pub selector: SyntaxResult<AnyCssSelector>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssRoot {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssRoot {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssRootFields {
+        CssRootFields {
+            rules: self.rules(),
+            eof_token: self.eof_token(),
+        }
+    }
+    pub fn rules(&self) -> CssRuleList { support::list(&self.syntax, 0usize) }
+    pub fn eof_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssRoot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssRootFields {
+    pub rules: CssRuleList,
+    pub eof_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssRule {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssRuleFields {
+        CssRuleFields {
+            prelude: self.prelude(),
+            block: self.block(),
+        }
+    }
+    pub fn prelude(&self) -> CssSelectorList { support::list(&self.syntax, 0usize) }
+    pub fn block(&self) -> SyntaxResult<CssBlock> { support::required_node(&self.syntax, 1usize) }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
         S: Serializer,
     {
         self.as_fields().serialize(serializer)
@@ -1779,6 +2035,87 @@ pub struct CssUniversalSelectorPatternFields {
     pub star_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssUrlFunction {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssUrlFunction {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssUrlFunctionFields {
+        CssUrlFunctionFields {
+            url_token: self.url_token(),
+            l_paren_token: self.l_paren_token(),
+            value: self.value(),
+            r_paren_token: self.r_paren_token(),
+        }
+    }
+    pub fn url_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn value(&self) -> Option<AnyCssUrlValue> { support::node(&self.syntax, 2usize) }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssUrlFunction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssUrlFunctionFields {
+    pub url_token: SyntaxResult<SyntaxToken>,
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub value: Option<AnyCssUrlValue>,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CssUrlValueRaw {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CssUrlValueRaw {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn as_fields(&self) -> CssUrlValueRawFields {
+        CssUrlValueRawFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssUrlValueRaw {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssUrlValueRawFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CssVarFunction {
     pub(crate) syntax: SyntaxNode,
 }
@@ -1851,9 +2188,7 @@ impl CssVarFunctionValue {
     pub fn comma_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn value(&self) -> SyntaxResult<CssIdentifier> {
-        support::required_node(&self.syntax, 1usize)
-    }
+    pub fn value(&self) -> CssComponentValueList { support::list(&self.syntax, 1usize) }
 }
 #[cfg(feature = "serde")]
 impl Serialize for CssVarFunctionValue {
@@ -1867,7 +2202,7 @@ impl Serialize for CssVarFunctionValue {
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CssVarFunctionValueFields {
     pub comma_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<CssIdentifier>,
+    pub value: CssComponentValueList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
@@ -1876,6 +2211,7 @@ pub enum AnyCssAtMediaQueryFeatureType {
     CssAtMediaQueryFeatureCompare(CssAtMediaQueryFeatureCompare),
     CssAtMediaQueryFeaturePlain(CssAtMediaQueryFeaturePlain),
     CssAtMediaQueryFeatureRange(CssAtMediaQueryFeatureRange),
+    CssAtMediaQueryFeatureReverseCompare(CssAtMediaQueryFeatureReverseCompare),
 }
 impl AnyCssAtMediaQueryFeatureType {
     pub fn as_css_at_media_query_feature_boolean(&self) -> Option<&CssAtMediaQueryFeatureBoolean> {
@@ -1902,6 +2238,14 @@ impl AnyCssAtMediaQueryFeatureType {
             _ => None,
         }
     }
+    pub fn as_css_at_media_query_feature_reverse_compare(
+        &self,
+    ) -> Option<&CssAtMediaQueryFeatureReverseCompare> {
+        match &self {
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureReverseCompare(item) => Some(item),
+            _ => None,
+        }
+    }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
@@ -1926,10 +2270,31 @@ impl AnyCssAtMediaQueryType {
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum AnyCssAtRule {
+    CssAtCharset(CssAtCharset),
+    CssAtGeneric(CssAtGeneric),
+    CssAtImport(CssAtImport),
     CssAtKeyframes(CssAtKeyframes),
     CssAtMedia(CssAtMedia),
 }
 impl AnyCssAtRule {
+    pub fn as_css_at_charset(&self) -> Option<&CssAtCharset> {
+        match &self {
+            AnyCssAtRule::CssAtCharset(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_at_generic(&self) -> Option<&CssAtGeneric> {
+        match &self {
+            AnyCssAtRule::CssAtGeneric(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_at_import(&self) -> Option<&CssAtImport> {
+        match &self {
+            AnyCssAtRule::CssAtImport(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_css_at_keyframes(&self) -> Option<&CssAtKeyframes> {
         match &self {
             AnyCssAtRule::CssAtKeyframes(item) => Some(item),
@@ -1945,112 +2310,400 @@ impl AnyCssAtRule {
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssRule {
-    AnyCssAtRule(AnyCssAtRule),
-    CssRule(CssRule),
+pub enum AnyCssAttributeMatcherValue {
+    CssIdentifier(CssIdentifier),
+    CssString(CssString),
 }
-impl AnyCssRule {
-    pub fn as_any_css_at_rule(&self) -> Option<&AnyCssAtRule> {
+impl AnyCssAttributeMatcherValue {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
         match &self {
-            AnyCssRule::AnyCssAtRule(item) => Some(item),
+            AnyCssAttributeMatcherValue::CssIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_rule(&self) -> Option<&CssRule> {
+    pub fn as_css_string(&self) -> Option<&CssString> {
         match &self {
-            AnyCssRule::CssRule(item) => Some(item),
+            AnyCssAttributeMatcherValue::CssString(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssSelectorPattern {
-    CssAttributeSelectorPattern(CssAttributeSelectorPattern),
-    CssClassSelectorPattern(CssClassSelectorPattern),
-    CssCombinatorSelectorPattern(CssCombinatorSelectorPattern),
-    CssIdSelectorPattern(CssIdSelectorPattern),
-    CssPseudoClassSelectorPattern(CssPseudoClassSelectorPattern),
-    CssTypeSelectorPattern(CssTypeSelectorPattern),
-    CssUniversalSelectorPattern(CssUniversalSelectorPattern),
+pub enum AnyCssBlockItem {
+    AnyCssAtRule(AnyCssAtRule),
+    CssBogusRule(CssBogusRule),
+    CssDeclaration(CssDeclaration),
+    CssEmptyDeclaration(CssEmptyDeclaration),
+    CssRule(CssRule),
 }
-impl AnyCssSelectorPattern {
-    pub fn as_css_attribute_selector_pattern(&self) -> Option<&CssAttributeSelectorPattern> {
+impl AnyCssBlockItem {
+    pub fn as_any_css_at_rule(&self) -> Option<&AnyCssAtRule> {
         match &self {
-            AnyCssSelectorPattern::CssAttributeSelectorPattern(item) => Some(item),
+            AnyCssBlockItem::AnyCssAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_class_selector_pattern(&self) -> Option<&CssClassSelectorPattern> {
+    pub fn as_css_bogus_rule(&self) -> Option<&CssBogusRule> {
         match &self {
-            AnyCssSelectorPattern::CssClassSelectorPattern(item) => Some(item),
+            AnyCssBlockItem::CssBogusRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_combinator_selector_pattern(&self) -> Option<&CssCombinatorSelectorPattern> {
+    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
         match &self {
-            AnyCssSelectorPattern::CssCombinatorSelectorPattern(item) => Some(item),
+            AnyCssBlockItem::CssDeclaration(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_id_selector_pattern(&self) -> Option<&CssIdSelectorPattern> {
+    pub fn as_css_empty_declaration(&self) -> Option<&CssEmptyDeclaration> {
         match &self {
-            AnyCssSelectorPattern::CssIdSelectorPattern(item) => Some(item),
+            AnyCssBlockItem::CssEmptyDeclaration(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_selector_pattern(&self) -> Option<&CssPseudoClassSelectorPattern> {
+    pub fn as_css_rule(&self) -> Option<&CssRule> {
         match &self {
-            AnyCssSelectorPattern::CssPseudoClassSelectorPattern(item) => Some(item),
+            AnyCssBlockItem::CssRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_type_selector_pattern(&self) -> Option<&CssTypeSelectorPattern> {
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssDeclarationName {
+    CssCustomProperty(CssCustomProperty),
+    CssIdentifier(CssIdentifier),
+}
+impl AnyCssDeclarationName {
+    pub fn as_css_custom_property(&self) -> Option<&CssCustomProperty> {
         match &self {
-            AnyCssSelectorPattern::CssTypeSelectorPattern(item) => Some(item),
+            AnyCssDeclarationName::CssCustomProperty(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_universal_selector_pattern(&self) -> Option<&CssUniversalSelectorPattern> {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
         match &self {
-            AnyCssSelectorPattern::CssUniversalSelectorPattern(item) => Some(item),
+            AnyCssDeclarationName::CssIdentifier(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssValue {
-    CssAnyFunction(CssAnyFunction),
-    CssCustomProperty(CssCustomProperty),
-    CssDimension(CssDimension),
-    CssIdentifier(CssIdentifier),
-    CssNumber(CssNumber),
-    CssRatio(CssRatio),
-    CssString(CssString),
+pub enum AnyCssFunction {
+    CssSimpleFunction(CssSimpleFunction),
+    CssUrlFunction(CssUrlFunction),
+    CssVarFunction(CssVarFunction),
 }
-impl AnyCssValue {
-    pub fn as_css_any_function(&self) -> Option<&CssAnyFunction> {
-        match &self {
-            AnyCssValue::CssAnyFunction(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_custom_property(&self) -> Option<&CssCustomProperty> {
+impl AnyCssFunction {
+    pub fn as_css_simple_function(&self) -> Option<&CssSimpleFunction> {
         match &self {
-            AnyCssValue::CssCustomProperty(item) => Some(item),
+            AnyCssFunction::CssSimpleFunction(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_dimension(&self) -> Option<&CssDimension> {
+    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
         match &self {
-            AnyCssValue::CssDimension(item) => Some(item),
+            AnyCssFunction::CssUrlFunction(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+    pub fn as_css_var_function(&self) -> Option<&CssVarFunction> {
         match &self {
-            AnyCssValue::CssIdentifier(item) => Some(item),
+            AnyCssFunction::CssVarFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssImportUrl {
+    CssString(CssString),
+    CssUrlFunction(CssUrlFunction),
+}
+impl AnyCssImportUrl {
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssImportUrl::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
+        match &self {
+            AnyCssImportUrl::CssUrlFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssKeyframesName {
+    CssIdentifier(CssIdentifier),
+    CssString(CssString),
+}
+impl AnyCssKeyframesName {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyCssKeyframesName::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssKeyframesName::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssKeyframesSelector {
+    CssKeyframesSelector(CssKeyframesSelector),
+    CssPercentage(CssPercentage),
+}
+impl AnyCssKeyframesSelector {
+    pub fn as_css_keyframes_selector(&self) -> Option<&CssKeyframesSelector> {
+        match &self {
+            AnyCssKeyframesSelector::CssKeyframesSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_percentage(&self) -> Option<&CssPercentage> {
+        match &self {
+            AnyCssKeyframesSelector::CssPercentage(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssPseudoParameters {
+    CssPseudoSelectorParameters(CssPseudoSelectorParameters),
+    CssPseudoValueParameters(CssPseudoValueParameters),
+}
+impl AnyCssPseudoParameters {
+    pub fn as_css_pseudo_selector_parameters(&self) -> Option<&CssPseudoSelectorParameters> {
+        match &self {
+            AnyCssPseudoParameters::CssPseudoSelectorParameters(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_value_parameters(&self) -> Option<&CssPseudoValueParameters> {
+        match &self {
+            AnyCssPseudoParameters::CssPseudoValueParameters(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssRule {
+    AnyCssAtRule(AnyCssAtRule),
+    CssBogusRule(CssBogusRule),
+    CssRule(CssRule),
+}
+impl AnyCssRule {
+    pub fn as_any_css_at_rule(&self) -> Option<&AnyCssAtRule> {
+        match &self {
+            AnyCssRule::AnyCssAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_bogus_rule(&self) -> Option<&CssBogusRule> {
+        match &self {
+            AnyCssRule::CssBogusRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_rule(&self) -> Option<&CssRule> {
+        match &self {
+            AnyCssRule::CssRule(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssComplexSelector(CssComplexSelector),
+    CssRelativeSelector(CssRelativeSelector),
+    CssSelector(CssSelector),
+}
+impl AnyCssSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+        match &self {
+            AnyCssSelector::CssBogusSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_complex_selector(&self) -> Option<&CssComplexSelector> {
+        match &self {
+            AnyCssSelector::CssComplexSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_relative_selector(&self) -> Option<&CssRelativeSelector> {
+        match &self {
+            AnyCssSelector::CssRelativeSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_selector(&self) -> Option<&CssSelector> {
+        match &self {
+            AnyCssSelector::CssSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssSelectorPattern {
+    CssAttributeSelectorPattern(CssAttributeSelectorPattern),
+    CssClassSelectorPattern(CssClassSelectorPattern),
+    CssIdSelectorPattern(CssIdSelectorPattern),
+    CssNestingSelectorPattern(CssNestingSelectorPattern),
+    CssPseudoClassSelectorPattern(CssPseudoClassSelectorPattern),
+    CssPseudoElementSelectorPattern(CssPseudoElementSelectorPattern),
+    CssTypeSelectorPattern(CssTypeSelectorPattern),
+    CssUniversalSelectorPattern(CssUniversalSelectorPattern),
+}
+impl AnyCssSelectorPattern {
+    pub fn as_css_attribute_selector_pattern(&self) -> Option<&CssAttributeSelectorPattern> {
+        match &self {
+            AnyCssSelectorPattern::CssAttributeSelectorPattern(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_class_selector_pattern(&self) -> Option<&CssClassSelectorPattern> {
+        match &self {
+            AnyCssSelectorPattern::CssClassSelectorPattern(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_id_selector_pattern(&self) -> Option<&CssIdSelectorPattern> {
+        match &self {
+            AnyCssSelectorPattern::CssIdSelectorPattern(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_nesting_selector_pattern(&self) -> Option<&CssNestingSelectorPattern> {
+        match &self {
+            AnyCssSelectorPattern::CssNestingSelectorPattern(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_class_selector_pattern(&self) -> Option<&CssPseudoClassSelectorPattern> {
+        match &self {
+            AnyCssSelectorPattern::CssPseudoClassSelectorPattern(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_element_selector_pattern(
+        &self,
+    ) -> Option<&CssPseudoElementSelectorPattern> {
+        match &self {
+            AnyCssSelectorPattern::CssPseudoElementSelectorPattern(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_type_selector_pattern(&self) -> Option<&CssTypeSelectorPattern> {
+        match &self {
+            AnyCssSelectorPattern::CssTypeSelectorPattern(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_universal_selector_pattern(&self) -> Option<&CssUniversalSelectorPattern> {
+        match &self {
+            AnyCssSelectorPattern::CssUniversalSelectorPattern(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssUrlValue {
+    CssString(CssString),
+    CssUrlValueRaw(CssUrlValueRaw),
+}
+impl AnyCssUrlValue {
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssUrlValue::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_url_value_raw(&self) -> Option<&CssUrlValueRaw> {
+        match &self {
+            AnyCssUrlValue::CssUrlValueRaw(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssValue {
+    AnyCssFunction(AnyCssFunction),
+    CssBogusValue(CssBogusValue),
+    CssBracketedValue(CssBracketedValue),
+    CssColor(CssColor),
+    CssCustomProperty(CssCustomProperty),
+    CssDimension(CssDimension),
+    CssGenericDelimiter(CssGenericDelimiter),
+    CssIdentifier(CssIdentifier),
+    CssNumber(CssNumber),
+    CssParenthesizedValue(CssParenthesizedValue),
+    CssPercentage(CssPercentage),
+    CssRatio(CssRatio),
+    CssString(CssString),
+}
+impl AnyCssValue {
+    pub fn as_any_css_function(&self) -> Option<&AnyCssFunction> {
+        match &self {
+            AnyCssValue::AnyCssFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_bogus_value(&self) -> Option<&CssBogusValue> {
+        match &self {
+            AnyCssValue::CssBogusValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_bracketed_value(&self) -> Option<&CssBracketedValue> {
+        match &self {
+            AnyCssValue::CssBracketedValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_color(&self) -> Option<&CssColor> {
+        match &self {
+            AnyCssValue::CssColor(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_custom_property(&self) -> Option<&CssCustomProperty> {
+        match &self {
+            AnyCssValue::CssCustomProperty(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_dimension(&self) -> Option<&CssDimension> {
+        match &self {
+            AnyCssValue::CssDimension(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_generic_delimiter(&self) -> Option<&CssGenericDelimiter> {
+        match &self {
+            AnyCssValue::CssGenericDelimiter(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyCssValue::CssIdentifier(item) => Some(item),
             _ => None,
         }
     }
@@ -2060,6 +2713,18 @@ impl AnyCssValue {
             _ => None,
         }
     }
+    pub fn as_css_parenthesized_value(&self) -> Option<&CssParenthesizedValue> {
+        match &self {
+            AnyCssValue::CssParenthesizedValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_percentage(&self) -> Option<&CssPercentage> {
+        match &self {
+            AnyCssValue::CssPercentage(item) => Some(item),
+            _ => None,
+        }
+    }
     pub fn as_css_ratio(&self) -> Option<&CssRatio> {
         match &self {
             AnyCssValue::CssRatio(item) => Some(item),
@@ -2073,11 +2738,11 @@ impl AnyCssValue {
         }
     }
 }
-impl AstNode for CssAnyFunction {
+impl AstNode for CssAtCharset {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ANY_FUNCTION as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ANY_FUNCTION }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_CHARSET as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_CHARSET }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2088,27 +2753,33 @@ impl AstNode for CssAnyFunction {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssAnyFunction {
+impl std::fmt::Debug for CssAtCharset {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAnyFunction")
+        f.debug_struct("CssAtCharset")
+            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
+            .field(
+                "charset_token",
+                &support::DebugSyntaxResult(self.charset_token()),
+            )
+            .field("encoding", &support::DebugSyntaxResult(self.encoding()))
             .field(
-                "css_simple_function",
-                &support::DebugSyntaxResult(self.css_simple_function()),
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
             )
             .finish()
     }
 }
-impl From<CssAnyFunction> for SyntaxNode {
-    fn from(n: CssAnyFunction) -> SyntaxNode { n.syntax }
+impl From<CssAtCharset> for SyntaxNode {
+    fn from(n: CssAtCharset) -> SyntaxNode { n.syntax }
 }
-impl From<CssAnyFunction> for SyntaxElement {
-    fn from(n: CssAnyFunction) -> SyntaxElement { n.syntax.into() }
+impl From<CssAtCharset> for SyntaxElement {
+    fn from(n: CssAtCharset) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssAtKeyframes {
+impl AstNode for CssAtGeneric {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_KEYFRAMES as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_KEYFRAMES }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_GENERIC as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_GENERIC }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2119,31 +2790,31 @@ impl AstNode for CssAtKeyframes {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssAtKeyframes {
+impl std::fmt::Debug for CssAtGeneric {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAtKeyframes")
+        f.debug_struct("CssAtGeneric")
             .field("at_token", &support::DebugSyntaxResult(self.at_token()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("prelude", &self.prelude())
+            .field("block", &support::DebugOptionalElement(self.block()))
             .field(
-                "keyframes_token",
-                &support::DebugSyntaxResult(self.keyframes_token()),
+                "semicolon_token",
+                &support::DebugOptionalElement(self.semicolon_token()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("css_string", &support::DebugSyntaxResult(self.css_string()))
-            .field("body", &support::DebugSyntaxResult(self.body()))
             .finish()
     }
 }
-impl From<CssAtKeyframes> for SyntaxNode {
-    fn from(n: CssAtKeyframes) -> SyntaxNode { n.syntax }
+impl From<CssAtGeneric> for SyntaxNode {
+    fn from(n: CssAtGeneric) -> SyntaxNode { n.syntax }
 }
-impl From<CssAtKeyframes> for SyntaxElement {
-    fn from(n: CssAtKeyframes) -> SyntaxElement { n.syntax.into() }
+impl From<CssAtGeneric> for SyntaxElement {
+    fn from(n: CssAtGeneric) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssAtKeyframesBody {
+impl AstNode for CssAtImport {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_KEYFRAMES_BODY as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_KEYFRAMES_BODY }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_IMPORT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_IMPORT }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2154,23 +2825,95 @@ impl AstNode for CssAtKeyframesBody {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssAtKeyframesBody {
+impl std::fmt::Debug for CssAtImport {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAtKeyframesBody")
+        f.debug_struct("CssAtImport")
+            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
             .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
+                "import_token",
+                &support::DebugSyntaxResult(self.import_token()),
             )
-            .field("items", &self.items())
+            .field("url", &support::DebugSyntaxResult(self.url()))
+            .field("media", &self.media())
             .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
             )
             .finish()
     }
 }
-impl From<CssAtKeyframesBody> for SyntaxNode {
-    fn from(n: CssAtKeyframesBody) -> SyntaxNode { n.syntax }
+impl From<CssAtImport> for SyntaxNode {
+    fn from(n: CssAtImport) -> SyntaxNode { n.syntax }
+}
+impl From<CssAtImport> for SyntaxElement {
+    fn from(n: CssAtImport) -> SyntaxElement { n.syntax.into() }
+}
+impl AstNode for CssAtKeyframes {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_KEYFRAMES as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_KEYFRAMES }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssAtKeyframes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssAtKeyframes")
+            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
+            .field(
+                "keyframes_token",
+                &support::DebugSyntaxResult(self.keyframes_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("body", &support::DebugSyntaxResult(self.body()))
+            .finish()
+    }
+}
+impl From<CssAtKeyframes> for SyntaxNode {
+    fn from(n: CssAtKeyframes) -> SyntaxNode { n.syntax }
+}
+impl From<CssAtKeyframes> for SyntaxElement {
+    fn from(n: CssAtKeyframes) -> SyntaxElement { n.syntax.into() }
+}
+impl AstNode for CssAtKeyframesBody {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_KEYFRAMES_BODY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_KEYFRAMES_BODY }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssAtKeyframesBody {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssAtKeyframesBody")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssAtKeyframesBody> for SyntaxNode {
+    fn from(n: CssAtKeyframesBody) -> SyntaxNode { n.syntax }
 }
 impl From<CssAtKeyframesBody> for SyntaxElement {
     fn from(n: CssAtKeyframesBody) -> SyntaxElement { n.syntax.into() }
@@ -2199,15 +2942,7 @@ impl std::fmt::Debug for CssAtMedia {
                 &support::DebugSyntaxResult(self.media_token()),
             )
             .field("query_list", &self.query_list())
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("body", &support::DebugSyntaxResult(self.body()))
-            .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
-            )
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
@@ -2236,19 +2971,11 @@ impl std::fmt::Debug for CssAtMediaQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssAtMediaQuery")
             .field(
-                "condition_token",
-                &support::DebugSyntaxResult(self.condition_token()),
-            )
-            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
-            .field(
-                "only_token",
-                &support::DebugOptionalElement(self.only_token()),
+                "condition",
+                &support::DebugOptionalElement(self.condition()),
             )
             .field("ty", &support::DebugSyntaxResult(self.ty()))
-            .field(
-                "consequent",
-                &support::DebugOptionalElement(self.consequent()),
-            )
+            .field("consequent_list", &self.consequent_list())
             .finish()
     }
 }
@@ -2276,7 +3003,7 @@ impl AstNode for CssAtMediaQueryConsequent {
 impl std::fmt::Debug for CssAtMediaQueryConsequent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssAtMediaQueryConsequent")
-            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
+            .field("operator", &support::DebugSyntaxResult(self.operator()))
             .field(
                 "condition_token",
                 &support::DebugOptionalElement(self.condition_token()),
@@ -2345,10 +3072,7 @@ impl AstNode for CssAtMediaQueryFeatureBoolean {
 impl std::fmt::Debug for CssAtMediaQueryFeatureBoolean {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssAtMediaQueryFeatureBoolean")
-            .field(
-                "css_identifier",
-                &support::DebugSyntaxResult(self.css_identifier()),
-            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
@@ -2448,14 +3172,14 @@ impl std::fmt::Debug for CssAtMediaQueryFeatureRange {
                 &support::DebugSyntaxResult(self.first_range()),
             )
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "second_value",
-                &support::DebugSyntaxResult(self.second_value()),
-            )
             .field(
                 "second_range",
                 &support::DebugSyntaxResult(self.second_range()),
             )
+            .field(
+                "second_value",
+                &support::DebugSyntaxResult(self.second_value()),
+            )
             .finish()
     }
 }
@@ -2465,11 +3189,12 @@ impl From<CssAtMediaQueryFeatureRange> for SyntaxNode {
 impl From<CssAtMediaQueryFeatureRange> for SyntaxElement {
     fn from(n: CssAtMediaQueryFeatureRange) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssAtMediaQueryRange {
+impl AstNode for CssAtMediaQueryFeatureReverseCompare {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_MEDIA_QUERY_RANGE as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_MEDIA_QUERY_RANGE }
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_AT_MEDIA_QUERY_FEATURE_REVERSE_COMPARE as u16,
+    ));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_MEDIA_QUERY_FEATURE_REVERSE_COMPARE }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2480,39 +3205,26 @@ impl AstNode for CssAtMediaQueryRange {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssAtMediaQueryRange {
+impl std::fmt::Debug for CssAtMediaQueryFeatureReverseCompare {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAtMediaQueryRange")
-            .field(
-                "r_angle_token",
-                &support::DebugSyntaxResult(self.r_angle_token()),
-            )
-            .field(
-                "l_angle_token",
-                &support::DebugSyntaxResult(self.l_angle_token()),
-            )
-            .field(
-                "greater_than_equal_token",
-                &support::DebugSyntaxResult(self.greater_than_equal_token()),
-            )
-            .field(
-                "less_than_equal_token",
-                &support::DebugSyntaxResult(self.less_than_equal_token()),
-            )
+        f.debug_struct("CssAtMediaQueryFeatureReverseCompare")
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("range", &support::DebugSyntaxResult(self.range()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssAtMediaQueryRange> for SyntaxNode {
-    fn from(n: CssAtMediaQueryRange) -> SyntaxNode { n.syntax }
+impl From<CssAtMediaQueryFeatureReverseCompare> for SyntaxNode {
+    fn from(n: CssAtMediaQueryFeatureReverseCompare) -> SyntaxNode { n.syntax }
 }
-impl From<CssAtMediaQueryRange> for SyntaxElement {
-    fn from(n: CssAtMediaQueryRange) -> SyntaxElement { n.syntax.into() }
+impl From<CssAtMediaQueryFeatureReverseCompare> for SyntaxElement {
+    fn from(n: CssAtMediaQueryFeatureReverseCompare) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssAttribute {
+impl AstNode for CssAtMediaQueryRange {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ATTRIBUTE }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_MEDIA_QUERY_RANGE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_MEDIA_QUERY_RANGE }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2523,33 +3235,18 @@ impl AstNode for CssAttribute {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssAttribute {
+impl std::fmt::Debug for CssAtMediaQueryRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttribute")
-            .field(
-                "l_brack_token",
-                &support::DebugSyntaxResult(self.l_brack_token()),
-            )
-            .field(
-                "attribute_name",
-                &support::DebugSyntaxResult(self.attribute_name()),
-            )
-            .field(
-                "attribute_meta",
-                &support::DebugOptionalElement(self.attribute_meta()),
-            )
-            .field(
-                "r_brack_token",
-                &support::DebugSyntaxResult(self.r_brack_token()),
-            )
+        f.debug_struct("CssAtMediaQueryRange")
+            .field("operator", &support::DebugSyntaxResult(self.operator()))
             .finish()
     }
 }
-impl From<CssAttribute> for SyntaxNode {
-    fn from(n: CssAttribute) -> SyntaxNode { n.syntax }
+impl From<CssAtMediaQueryRange> for SyntaxNode {
+    fn from(n: CssAtMediaQueryRange) -> SyntaxNode { n.syntax }
 }
-impl From<CssAttribute> for SyntaxElement {
-    fn from(n: CssAttribute) -> SyntaxElement { n.syntax.into() }
+impl From<CssAtMediaQueryRange> for SyntaxElement {
+    fn from(n: CssAtMediaQueryRange) -> SyntaxElement { n.syntax.into() }
 }
 impl AstNode for CssAttributeMatcher {
     type Language = Language;
@@ -2569,35 +3266,9 @@ impl AstNode for CssAttributeMatcher {
 impl std::fmt::Debug for CssAttributeMatcher {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssAttributeMatcher")
-            .field(
-                "matcher_type_token",
-                &support::DebugSyntaxResult(self.matcher_type_token()),
-            )
-            .field(
-                "exactly_or_hyphen_token",
-                &support::DebugSyntaxResult(self.exactly_or_hyphen_token()),
-            )
-            .field(
-                "prefix_token",
-                &support::DebugSyntaxResult(self.prefix_token()),
-            )
-            .field(
-                "suffix_token",
-                &support::DebugSyntaxResult(self.suffix_token()),
-            )
-            .field(
-                "times_assign_token",
-                &support::DebugSyntaxResult(self.times_assign_token()),
-            )
-            .field("eq_token", &support::DebugSyntaxResult(self.eq_token()))
-            .field(
-                "matcher_name",
-                &support::DebugSyntaxResult(self.matcher_name()),
-            )
-            .field(
-                "css_identifier",
-                &support::DebugSyntaxResult(self.css_identifier()),
-            )
+            .field("operator", &support::DebugSyntaxResult(self.operator()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("modifier", &support::DebugOptionalElement(self.modifier()))
             .finish()
     }
 }
@@ -2607,11 +3278,11 @@ impl From<CssAttributeMatcher> for SyntaxNode {
 impl From<CssAttributeMatcher> for SyntaxElement {
     fn from(n: CssAttributeMatcher) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssAttributeMeta {
+impl AstNode for CssAttributeSelectorPattern {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_META as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ATTRIBUTE_META }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_SELECTOR_PATTERN as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ATTRIBUTE_SELECTOR_PATTERN }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2622,31 +3293,33 @@ impl AstNode for CssAttributeMeta {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssAttributeMeta {
+impl std::fmt::Debug for CssAttributeSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeMeta")
+        f.debug_struct("CssAttributeSelectorPattern")
             .field(
-                "attribute_matcher",
-                &support::DebugOptionalElement(self.attribute_matcher()),
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
             )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("matcher", &support::DebugOptionalElement(self.matcher()))
             .field(
-                "attribute_modifier",
-                &support::DebugOptionalElement(self.attribute_modifier()),
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
             )
             .finish()
     }
 }
-impl From<CssAttributeMeta> for SyntaxNode {
-    fn from(n: CssAttributeMeta) -> SyntaxNode { n.syntax }
+impl From<CssAttributeSelectorPattern> for SyntaxNode {
+    fn from(n: CssAttributeSelectorPattern) -> SyntaxNode { n.syntax }
 }
-impl From<CssAttributeMeta> for SyntaxElement {
-    fn from(n: CssAttributeMeta) -> SyntaxElement { n.syntax.into() }
+impl From<CssAttributeSelectorPattern> for SyntaxElement {
+    fn from(n: CssAttributeSelectorPattern) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssAttributeModifier {
+impl AstNode for CssBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_MODIFIER as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ATTRIBUTE_MODIFIER }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_BLOCK }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2657,24 +3330,32 @@ impl AstNode for CssAttributeModifier {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssAttributeModifier {
+impl std::fmt::Debug for CssBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeModifier")
-            .field("i_token", &support::DebugSyntaxResult(self.i_token()))
+        f.debug_struct("CssBlock")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
             .finish()
     }
 }
-impl From<CssAttributeModifier> for SyntaxNode {
-    fn from(n: CssAttributeModifier) -> SyntaxNode { n.syntax }
+impl From<CssBlock> for SyntaxNode {
+    fn from(n: CssBlock) -> SyntaxNode { n.syntax }
 }
-impl From<CssAttributeModifier> for SyntaxElement {
-    fn from(n: CssAttributeModifier) -> SyntaxElement { n.syntax.into() }
+impl From<CssBlock> for SyntaxElement {
+    fn from(n: CssBlock) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssAttributeName {
+impl AstNode for CssBracketedValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_NAME as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ATTRIBUTE_NAME }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BRACKETED_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_BRACKETED_VALUE }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2685,24 +3366,32 @@ impl AstNode for CssAttributeName {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssAttributeName {
+impl std::fmt::Debug for CssBracketedValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeName")
-            .field("css_string", &support::DebugSyntaxResult(self.css_string()))
+        f.debug_struct("CssBracketedValue")
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
+            .field("values", &self.values())
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
             .finish()
     }
 }
-impl From<CssAttributeName> for SyntaxNode {
-    fn from(n: CssAttributeName) -> SyntaxNode { n.syntax }
+impl From<CssBracketedValue> for SyntaxNode {
+    fn from(n: CssBracketedValue) -> SyntaxNode { n.syntax }
 }
-impl From<CssAttributeName> for SyntaxElement {
-    fn from(n: CssAttributeName) -> SyntaxElement { n.syntax.into() }
+impl From<CssBracketedValue> for SyntaxElement {
+    fn from(n: CssBracketedValue) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssAttributeSelectorPattern {
+impl AstNode for CssClassSelectorPattern {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_SELECTOR_PATTERN as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ATTRIBUTE_SELECTOR_PATTERN }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CLASS_SELECTOR_PATTERN as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_CLASS_SELECTOR_PATTERN }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2713,25 +3402,25 @@ impl AstNode for CssAttributeSelectorPattern {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssAttributeSelectorPattern {
+impl std::fmt::Debug for CssClassSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeSelectorPattern")
+        f.debug_struct("CssClassSelectorPattern")
+            .field("dot_token", &support::DebugSyntaxResult(self.dot_token()))
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("attribute_list", &self.attribute_list())
             .finish()
     }
 }
-impl From<CssAttributeSelectorPattern> for SyntaxNode {
-    fn from(n: CssAttributeSelectorPattern) -> SyntaxNode { n.syntax }
+impl From<CssClassSelectorPattern> for SyntaxNode {
+    fn from(n: CssClassSelectorPattern) -> SyntaxNode { n.syntax }
 }
-impl From<CssAttributeSelectorPattern> for SyntaxElement {
-    fn from(n: CssAttributeSelectorPattern) -> SyntaxElement { n.syntax.into() }
+impl From<CssClassSelectorPattern> for SyntaxElement {
+    fn from(n: CssClassSelectorPattern) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssBlock {
+impl AstNode for CssColor {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BLOCK as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_BLOCK }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COLOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_COLOR }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2742,32 +3431,28 @@ impl AstNode for CssBlock {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssBlock {
+impl std::fmt::Debug for CssColor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBlock")
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("declaration_list", &self.declaration_list())
+        f.debug_struct("CssColor")
+            .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
             .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
             .finish()
     }
 }
-impl From<CssBlock> for SyntaxNode {
-    fn from(n: CssBlock) -> SyntaxNode { n.syntax }
+impl From<CssColor> for SyntaxNode {
+    fn from(n: CssColor) -> SyntaxNode { n.syntax }
 }
-impl From<CssBlock> for SyntaxElement {
-    fn from(n: CssBlock) -> SyntaxElement { n.syntax.into() }
+impl From<CssColor> for SyntaxElement {
+    fn from(n: CssColor) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssClassSelectorPattern {
+impl AstNode for CssComplexSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CLASS_SELECTOR_PATTERN as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_CLASS_SELECTOR_PATTERN }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPLEX_SELECTOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_COMPLEX_SELECTOR }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2778,25 +3463,29 @@ impl AstNode for CssClassSelectorPattern {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssClassSelectorPattern {
+impl std::fmt::Debug for CssComplexSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssClassSelectorPattern")
-            .field("dot_token", &support::DebugSyntaxResult(self.dot_token()))
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssComplexSelector")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field(
+                "combinator",
+                &support::DebugOptionalElement(self.combinator()),
+            )
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssClassSelectorPattern> for SyntaxNode {
-    fn from(n: CssClassSelectorPattern) -> SyntaxNode { n.syntax }
+impl From<CssComplexSelector> for SyntaxNode {
+    fn from(n: CssComplexSelector) -> SyntaxNode { n.syntax }
 }
-impl From<CssClassSelectorPattern> for SyntaxElement {
-    fn from(n: CssClassSelectorPattern) -> SyntaxElement { n.syntax.into() }
+impl From<CssComplexSelector> for SyntaxElement {
+    fn from(n: CssComplexSelector) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssCombinatorSelectorPattern {
+impl AstNode for CssCustomProperty {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMBINATOR_SELECTOR_PATTERN as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_COMBINATOR_SELECTOR_PATTERN }
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CUSTOM_PROPERTY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_CUSTOM_PROPERTY }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -2807,54 +3496,12 @@ impl AstNode for CssCombinatorSelectorPattern {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssCombinatorSelectorPattern {
+impl std::fmt::Debug for CssCustomProperty {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssCombinatorSelectorPattern")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field(
-                "combinator_token",
-                &support::DebugSyntaxResult(self.combinator_token()),
-            )
-            .field("plus_token", &support::DebugSyntaxResult(self.plus_token()))
+        f.debug_struct("CssCustomProperty")
             .field(
-                "bitwise_not_token",
-                &support::DebugSyntaxResult(self.bitwise_not_token()),
-            )
-            .field(
-                "css_space_literal_token",
-                &support::DebugSyntaxResult(self.css_space_literal_token()),
-            )
-            .field("right", &support::DebugSyntaxResult(self.right()))
-            .finish()
-    }
-}
-impl From<CssCombinatorSelectorPattern> for SyntaxNode {
-    fn from(n: CssCombinatorSelectorPattern) -> SyntaxNode { n.syntax }
-}
-impl From<CssCombinatorSelectorPattern> for SyntaxElement {
-    fn from(n: CssCombinatorSelectorPattern) -> SyntaxElement { n.syntax.into() }
-}
-impl AstNode for CssCustomProperty {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CUSTOM_PROPERTY as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_CUSTOM_PROPERTY }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
-    }
-    fn syntax(&self) -> &SyntaxNode { &self.syntax }
-    fn into_syntax(self) -> SyntaxNode { self.syntax }
-}
-impl std::fmt::Debug for CssCustomProperty {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssCustomProperty")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
             .finish()
     }
@@ -2884,19 +3531,19 @@ impl std::fmt::Debug for CssDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssDeclaration")
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "css_custom_property",
-                &support::DebugSyntaxResult(self.css_custom_property()),
-            )
             .field(
                 "colon_token",
                 &support::DebugSyntaxResult(self.colon_token()),
             )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("value", &self.value())
             .field(
                 "important",
                 &support::DebugOptionalElement(self.important()),
             )
+            .field(
+                "semicolon_token",
+                &support::DebugOptionalElement(self.semicolon_token()),
+            )
             .finish()
     }
 }
@@ -2967,6 +3614,65 @@ impl From<CssDimension> for SyntaxNode {
 impl From<CssDimension> for SyntaxElement {
     fn from(n: CssDimension) -> SyntaxElement { n.syntax.into() }
 }
+impl AstNode for CssEmptyDeclaration {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_EMPTY_DECLARATION as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_EMPTY_DECLARATION }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssEmptyDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssEmptyDeclaration")
+            .field(
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssEmptyDeclaration> for SyntaxNode {
+    fn from(n: CssEmptyDeclaration) -> SyntaxNode { n.syntax }
+}
+impl From<CssEmptyDeclaration> for SyntaxElement {
+    fn from(n: CssEmptyDeclaration) -> SyntaxElement { n.syntax.into() }
+}
+impl AstNode for CssGenericDelimiter {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_GENERIC_DELIMITER as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_GENERIC_DELIMITER }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssGenericDelimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssGenericDelimiter")
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .finish()
+    }
+}
+impl From<CssGenericDelimiter> for SyntaxNode {
+    fn from(n: CssGenericDelimiter) -> SyntaxNode { n.syntax }
+}
+impl From<CssGenericDelimiter> for SyntaxElement {
+    fn from(n: CssGenericDelimiter) -> SyntaxElement { n.syntax.into() }
+}
 impl AstNode for CssIdSelectorPattern {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -3046,15 +3752,7 @@ impl std::fmt::Debug for CssKeyframesBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssKeyframesBlock")
             .field("selectors", &self.selectors())
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("declarations", &self.declarations())
-            .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
-            )
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
@@ -3082,12 +3780,7 @@ impl AstNode for CssKeyframesSelector {
 impl std::fmt::Debug for CssKeyframesSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssKeyframesSelector")
-            .field("from_token", &support::DebugSyntaxResult(self.from_token()))
-            .field("to_token", &support::DebugSyntaxResult(self.to_token()))
-            .field(
-                "css_percentage",
-                &support::DebugSyntaxResult(self.css_percentage()),
-            )
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .finish()
     }
 }
@@ -3097,6 +3790,34 @@ impl From<CssKeyframesSelector> for SyntaxNode {
 impl From<CssKeyframesSelector> for SyntaxElement {
     fn from(n: CssKeyframesSelector) -> SyntaxElement { n.syntax.into() }
 }
+impl AstNode for CssNestingSelectorPattern {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NESTING_SELECTOR_PATTERN as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_NESTING_SELECTOR_PATTERN }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssNestingSelectorPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssNestingSelectorPattern")
+            .field("amp_token", &support::DebugSyntaxResult(self.amp_token()))
+            .finish()
+    }
+}
+impl From<CssNestingSelectorPattern> for SyntaxNode {
+    fn from(n: CssNestingSelectorPattern) -> SyntaxNode { n.syntax }
+}
+impl From<CssNestingSelectorPattern> for SyntaxElement {
+    fn from(n: CssNestingSelectorPattern) -> SyntaxElement { n.syntax.into() }
+}
 impl AstNode for CssNumber {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -3146,10 +3867,7 @@ impl AstNode for CssParameter {
 impl std::fmt::Debug for CssParameter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssParameter")
-            .field(
-                "any_css_value",
-                &support::DebugSyntaxResult(self.any_css_value()),
-            )
+            .field("values", &self.values())
             .finish()
     }
 }
@@ -3159,6 +3877,42 @@ impl From<CssParameter> for SyntaxNode {
 impl From<CssParameter> for SyntaxElement {
     fn from(n: CssParameter) -> SyntaxElement { n.syntax.into() }
 }
+impl AstNode for CssParenthesizedValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARENTHESIZED_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PARENTHESIZED_VALUE }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssParenthesizedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssParenthesizedValue")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("values", &self.values())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssParenthesizedValue> for SyntaxNode {
+    fn from(n: CssParenthesizedValue) -> SyntaxNode { n.syntax }
+}
+impl From<CssParenthesizedValue> for SyntaxElement {
+    fn from(n: CssParenthesizedValue) -> SyntaxElement { n.syntax.into() }
+}
 impl AstNode for CssPercentage {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -3227,12 +3981,83 @@ impl From<CssPseudoClassSelectorPattern> for SyntaxNode {
 impl From<CssPseudoClassSelectorPattern> for SyntaxElement {
     fn from(n: CssPseudoClassSelectorPattern) -> SyntaxElement { n.syntax.into() }
 }
-impl AstNode for CssPseudoClassSelectorPatternParameters {
+impl AstNode for CssPseudoElementSelectorPattern {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS as u16,
-    ));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS }
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssPseudoElementSelectorPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssPseudoElementSelectorPattern")
+            .field(
+                "double_colon_token",
+                &support::DebugSyntaxResult(self.double_colon_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "parameters",
+                &support::DebugOptionalElement(self.parameters()),
+            )
+            .finish()
+    }
+}
+impl From<CssPseudoElementSelectorPattern> for SyntaxNode {
+    fn from(n: CssPseudoElementSelectorPattern) -> SyntaxNode { n.syntax }
+}
+impl From<CssPseudoElementSelectorPattern> for SyntaxElement {
+    fn from(n: CssPseudoElementSelectorPattern) -> SyntaxElement { n.syntax.into() }
+}
+impl AstNode for CssPseudoSelectorParameters {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_SELECTOR_PARAMETERS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PSEUDO_SELECTOR_PARAMETERS }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssPseudoSelectorParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssPseudoSelectorParameters")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("selectors", &self.selectors())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssPseudoSelectorParameters> for SyntaxNode {
+    fn from(n: CssPseudoSelectorParameters) -> SyntaxNode { n.syntax }
+}
+impl From<CssPseudoSelectorParameters> for SyntaxElement {
+    fn from(n: CssPseudoSelectorParameters) -> SyntaxElement { n.syntax.into() }
+}
+impl AstNode for CssPseudoValueParameters {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_VALUE_PARAMETERS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_PSEUDO_VALUE_PARAMETERS }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
@@ -3243,14 +4068,14 @@ impl AstNode for CssPseudoClassSelectorPatternParameters {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssPseudoClassSelectorPatternParameters {
+impl std::fmt::Debug for CssPseudoValueParameters {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassSelectorPatternParameters")
+        f.debug_struct("CssPseudoValueParameters")
             .field(
                 "l_paren_token",
                 &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("parameter", &support::DebugSyntaxResult(self.parameter()))
+            .field("values", &self.values())
             .field(
                 "r_paren_token",
                 &support::DebugSyntaxResult(self.r_paren_token()),
@@ -3258,11 +4083,11 @@ impl std::fmt::Debug for CssPseudoClassSelectorPatternParameters {
             .finish()
     }
 }
-impl From<CssPseudoClassSelectorPatternParameters> for SyntaxNode {
-    fn from(n: CssPseudoClassSelectorPatternParameters) -> SyntaxNode { n.syntax }
+impl From<CssPseudoValueParameters> for SyntaxNode {
+    fn from(n: CssPseudoValueParameters) -> SyntaxNode { n.syntax }
 }
-impl From<CssPseudoClassSelectorPatternParameters> for SyntaxElement {
-    fn from(n: CssPseudoClassSelectorPatternParameters) -> SyntaxElement { n.syntax.into() }
+impl From<CssPseudoValueParameters> for SyntaxElement {
+    fn from(n: CssPseudoValueParameters) -> SyntaxElement { n.syntax.into() }
 }
 impl AstNode for CssRatio {
     type Language = Language;
@@ -3283,6 +4108,10 @@ impl std::fmt::Debug for CssRatio {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CssRatio")
             .field("numerator", &support::DebugSyntaxResult(self.numerator()))
+            .field(
+                "slash_token",
+                &support::DebugSyntaxResult(self.slash_token()),
+            )
             .field(
                 "denominator",
                 &support::DebugSyntaxResult(self.denominator()),
@@ -3296,6 +4125,35 @@ impl From<CssRatio> for SyntaxNode {
 impl From<CssRatio> for SyntaxElement {
     fn from(n: CssRatio) -> SyntaxElement { n.syntax.into() }
 }
+impl AstNode for CssRelativeSelector {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RELATIVE_SELECTOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_RELATIVE_SELECTOR }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssRelativeSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssRelativeSelector")
+            .field("combinator", &support::DebugSyntaxResult(self.combinator()))
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
+            .finish()
+    }
+}
+impl From<CssRelativeSelector> for SyntaxNode {
+    fn from(n: CssRelativeSelector) -> SyntaxNode { n.syntax }
+}
+impl From<CssRelativeSelector> for SyntaxElement {
+    fn from(n: CssRelativeSelector) -> SyntaxElement { n.syntax.into() }
+}
 impl AstNode for CssRoot {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -3506,6 +4364,74 @@ impl From<CssUniversalSelectorPattern> for SyntaxNode {
 impl From<CssUniversalSelectorPattern> for SyntaxElement {
     fn from(n: CssUniversalSelectorPattern) -> SyntaxElement { n.syntax.into() }
 }
+impl AstNode for CssUrlFunction {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_FUNCTION as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_URL_FUNCTION }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssUrlFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssUrlFunction")
+            .field("url_token", &support::DebugSyntaxResult(self.url_token()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("value", &support::DebugOptionalElement(self.value()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssUrlFunction> for SyntaxNode {
+    fn from(n: CssUrlFunction) -> SyntaxNode { n.syntax }
+}
+impl From<CssUrlFunction> for SyntaxElement {
+    fn from(n: CssUrlFunction) -> SyntaxElement { n.syntax.into() }
+}
+impl AstNode for CssUrlValueRaw {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_VALUE_RAW as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_URL_VALUE_RAW }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssUrlValueRaw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssUrlValueRaw")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssUrlValueRaw> for SyntaxNode {
+    fn from(n: CssUrlValueRaw) -> SyntaxNode { n.syntax }
+}
+impl From<CssUrlValueRaw> for SyntaxElement {
+    fn from(n: CssUrlValueRaw) -> SyntaxElement { n.syntax.into() }
+}
 impl AstNode for CssVarFunction {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
@@ -3559,265 +4485,838 @@ impl AstNode for CssVarFunctionValue {
     fn syntax(&self) -> &SyntaxNode { &self.syntax }
     fn into_syntax(self) -> SyntaxNode { self.syntax }
 }
-impl std::fmt::Debug for CssVarFunctionValue {
+impl std::fmt::Debug for CssVarFunctionValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssVarFunctionValue")
+            .field(
+                "comma_token",
+                &support::DebugSyntaxResult(self.comma_token()),
+            )
+            .field("value", &self.value())
+            .finish()
+    }
+}
+impl From<CssVarFunctionValue> for SyntaxNode {
+    fn from(n: CssVarFunctionValue) -> SyntaxNode { n.syntax }
+}
+impl From<CssVarFunctionValue> for SyntaxElement {
+    fn from(n: CssVarFunctionValue) -> SyntaxElement { n.syntax.into() }
+}
+impl From<CssAtMediaQueryFeatureBoolean> for AnyCssAtMediaQueryFeatureType {
+    fn from(node: CssAtMediaQueryFeatureBoolean) -> AnyCssAtMediaQueryFeatureType {
+        AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(node)
+    }
+}
+impl From<CssAtMediaQueryFeatureCompare> for AnyCssAtMediaQueryFeatureType {
+    fn from(node: CssAtMediaQueryFeatureCompare) -> AnyCssAtMediaQueryFeatureType {
+        AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(node)
+    }
+}
+impl From<CssAtMediaQueryFeaturePlain> for AnyCssAtMediaQueryFeatureType {
+    fn from(node: CssAtMediaQueryFeaturePlain) -> AnyCssAtMediaQueryFeatureType {
+        AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(node)
+    }
+}
+impl From<CssAtMediaQueryFeatureRange> for AnyCssAtMediaQueryFeatureType {
+    fn from(node: CssAtMediaQueryFeatureRange) -> AnyCssAtMediaQueryFeatureType {
+        AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(node)
+    }
+}
+impl From<CssAtMediaQueryFeatureReverseCompare> for AnyCssAtMediaQueryFeatureType {
+    fn from(node: CssAtMediaQueryFeatureReverseCompare) -> AnyCssAtMediaQueryFeatureType {
+        AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureReverseCompare(node)
+    }
+}
+impl AstNode for AnyCssAtMediaQueryFeatureType {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = CssAtMediaQueryFeatureBoolean::KIND_SET
+        .union(CssAtMediaQueryFeatureCompare::KIND_SET)
+        .union(CssAtMediaQueryFeaturePlain::KIND_SET)
+        .union(CssAtMediaQueryFeatureRange::KIND_SET)
+        .union(CssAtMediaQueryFeatureReverseCompare::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            CSS_AT_MEDIA_QUERY_FEATURE_BOOLEAN
+                | CSS_AT_MEDIA_QUERY_FEATURE_COMPARE
+                | CSS_AT_MEDIA_QUERY_FEATURE_PLAIN
+                | CSS_AT_MEDIA_QUERY_FEATURE_RANGE
+                | CSS_AT_MEDIA_QUERY_FEATURE_REVERSE_COMPARE
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_AT_MEDIA_QUERY_FEATURE_BOOLEAN => {
+                AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(
+                    CssAtMediaQueryFeatureBoolean { syntax },
+                )
+            }
+            CSS_AT_MEDIA_QUERY_FEATURE_COMPARE => {
+                AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(
+                    CssAtMediaQueryFeatureCompare { syntax },
+                )
+            }
+            CSS_AT_MEDIA_QUERY_FEATURE_PLAIN => {
+                AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(
+                    CssAtMediaQueryFeaturePlain { syntax },
+                )
+            }
+            CSS_AT_MEDIA_QUERY_FEATURE_RANGE => {
+                AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(
+                    CssAtMediaQueryFeatureRange { syntax },
+                )
+            }
+            CSS_AT_MEDIA_QUERY_FEATURE_REVERSE_COMPARE => {
+                AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureReverseCompare(
+                    CssAtMediaQueryFeatureReverseCompare { syntax },
+                )
+            }
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(it) => &it.syntax,
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(it) => &it.syntax,
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(it) => &it.syntax,
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(it) => &it.syntax,
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureReverseCompare(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(it) => it.syntax,
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(it) => it.syntax,
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(it) => it.syntax,
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(it) => it.syntax,
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureReverseCompare(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyCssAtMediaQueryFeatureType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(it) => {
+                std::fmt::Debug::fmt(it, f)
+            }
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(it) => {
+                std::fmt::Debug::fmt(it, f)
+            }
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(it) => {
+                std::fmt::Debug::fmt(it, f)
+            }
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(it) => {
+                std::fmt::Debug::fmt(it, f)
+            }
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureReverseCompare(it) => {
+                std::fmt::Debug::fmt(it, f)
+            }
+        }
+    }
+}
+impl From<AnyCssAtMediaQueryFeatureType> for SyntaxNode {
+    fn from(n: AnyCssAtMediaQueryFeatureType) -> SyntaxNode {
+        match n {
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(it) => it.into(),
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(it) => it.into(),
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(it) => it.into(),
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(it) => it.into(),
+            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureReverseCompare(it) => it.into(),
+        }
+    }
+}
+impl From<AnyCssAtMediaQueryFeatureType> for SyntaxElement {
+    fn from(n: AnyCssAtMediaQueryFeatureType) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssAtMediaQueryFeature> for AnyCssAtMediaQueryType {
+    fn from(node: CssAtMediaQueryFeature) -> AnyCssAtMediaQueryType {
+        AnyCssAtMediaQueryType::CssAtMediaQueryFeature(node)
+    }
+}
+impl From<CssIdentifier> for AnyCssAtMediaQueryType {
+    fn from(node: CssIdentifier) -> AnyCssAtMediaQueryType {
+        AnyCssAtMediaQueryType::CssIdentifier(node)
+    }
+}
+impl AstNode for AnyCssAtMediaQueryType {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        CssAtMediaQueryFeature::KIND_SET.union(CssIdentifier::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CSS_AT_MEDIA_QUERY_FEATURE | CSS_IDENTIFIER)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_AT_MEDIA_QUERY_FEATURE => {
+                AnyCssAtMediaQueryType::CssAtMediaQueryFeature(CssAtMediaQueryFeature { syntax })
+            }
+            CSS_IDENTIFIER => AnyCssAtMediaQueryType::CssIdentifier(CssIdentifier { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyCssAtMediaQueryType::CssAtMediaQueryFeature(it) => &it.syntax,
+            AnyCssAtMediaQueryType::CssIdentifier(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyCssAtMediaQueryType::CssAtMediaQueryFeature(it) => it.syntax,
+            AnyCssAtMediaQueryType::CssIdentifier(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyCssAtMediaQueryType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyCssAtMediaQueryType::CssAtMediaQueryFeature(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtMediaQueryType::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyCssAtMediaQueryType> for SyntaxNode {
+    fn from(n: AnyCssAtMediaQueryType) -> SyntaxNode {
+        match n {
+            AnyCssAtMediaQueryType::CssAtMediaQueryFeature(it) => it.into(),
+            AnyCssAtMediaQueryType::CssIdentifier(it) => it.into(),
+        }
+    }
+}
+impl From<AnyCssAtMediaQueryType> for SyntaxElement {
+    fn from(n: AnyCssAtMediaQueryType) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssAtCharset> for AnyCssAtRule {
+    fn from(node: CssAtCharset) -> AnyCssAtRule { AnyCssAtRule::CssAtCharset(node) }
+}
+impl From<CssAtGeneric> for AnyCssAtRule {
+    fn from(node: CssAtGeneric) -> AnyCssAtRule { AnyCssAtRule::CssAtGeneric(node) }
+}
+impl From<CssAtImport> for AnyCssAtRule {
+    fn from(node: CssAtImport) -> AnyCssAtRule { AnyCssAtRule::CssAtImport(node) }
+}
+impl From<CssAtKeyframes> for AnyCssAtRule {
+    fn from(node: CssAtKeyframes) -> AnyCssAtRule { AnyCssAtRule::CssAtKeyframes(node) }
+}
+impl From<CssAtMedia> for AnyCssAtRule {
+    fn from(node: CssAtMedia) -> AnyCssAtRule { AnyCssAtRule::CssAtMedia(node) }
+}
+impl AstNode for AnyCssAtRule {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = CssAtCharset::KIND_SET
+        .union(CssAtGeneric::KIND_SET)
+        .union(CssAtImport::KIND_SET)
+        .union(CssAtKeyframes::KIND_SET)
+        .union(CssAtMedia::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            CSS_AT_CHARSET | CSS_AT_GENERIC | CSS_AT_IMPORT | CSS_AT_KEYFRAMES | CSS_AT_MEDIA
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_AT_CHARSET => AnyCssAtRule::CssAtCharset(CssAtCharset { syntax }),
+            CSS_AT_GENERIC => AnyCssAtRule::CssAtGeneric(CssAtGeneric { syntax }),
+            CSS_AT_IMPORT => AnyCssAtRule::CssAtImport(CssAtImport { syntax }),
+            CSS_AT_KEYFRAMES => AnyCssAtRule::CssAtKeyframes(CssAtKeyframes { syntax }),
+            CSS_AT_MEDIA => AnyCssAtRule::CssAtMedia(CssAtMedia { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyCssAtRule::CssAtCharset(it) => &it.syntax,
+            AnyCssAtRule::CssAtGeneric(it) => &it.syntax,
+            AnyCssAtRule::CssAtImport(it) => &it.syntax,
+            AnyCssAtRule::CssAtKeyframes(it) => &it.syntax,
+            AnyCssAtRule::CssAtMedia(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyCssAtRule::CssAtCharset(it) => it.syntax,
+            AnyCssAtRule::CssAtGeneric(it) => it.syntax,
+            AnyCssAtRule::CssAtImport(it) => it.syntax,
+            AnyCssAtRule::CssAtKeyframes(it) => it.syntax,
+            AnyCssAtRule::CssAtMedia(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyCssAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyCssAtRule::CssAtCharset(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::CssAtGeneric(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::CssAtImport(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::CssAtKeyframes(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::CssAtMedia(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyCssAtRule> for SyntaxNode {
+    fn from(n: AnyCssAtRule) -> SyntaxNode {
+        match n {
+            AnyCssAtRule::CssAtCharset(it) => it.into(),
+            AnyCssAtRule::CssAtGeneric(it) => it.into(),
+            AnyCssAtRule::CssAtImport(it) => it.into(),
+            AnyCssAtRule::CssAtKeyframes(it) => it.into(),
+            AnyCssAtRule::CssAtMedia(it) => it.into(),
+        }
+    }
+}
+impl From<AnyCssAtRule> for SyntaxElement {
+    fn from(n: AnyCssAtRule) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssIdentifier> for AnyCssAttributeMatcherValue {
+    fn from(node: CssIdentifier) -> AnyCssAttributeMatcherValue {
+        AnyCssAttributeMatcherValue::CssIdentifier(node)
+    }
+}
+impl From<CssString> for AnyCssAttributeMatcherValue {
+    fn from(node: CssString) -> AnyCssAttributeMatcherValue {
+        AnyCssAttributeMatcherValue::CssString(node)
+    }
+}
+impl AstNode for AnyCssAttributeMatcherValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = CssIdentifier::KIND_SET.union(CssString::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, CSS_IDENTIFIER | CSS_STRING) }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_IDENTIFIER => AnyCssAttributeMatcherValue::CssIdentifier(CssIdentifier { syntax }),
+            CSS_STRING => AnyCssAttributeMatcherValue::CssString(CssString { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyCssAttributeMatcherValue::CssIdentifier(it) => &it.syntax,
+            AnyCssAttributeMatcherValue::CssString(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyCssAttributeMatcherValue::CssIdentifier(it) => it.syntax,
+            AnyCssAttributeMatcherValue::CssString(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyCssAttributeMatcherValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyCssAttributeMatcherValue::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAttributeMatcherValue::CssString(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyCssAttributeMatcherValue> for SyntaxNode {
+    fn from(n: AnyCssAttributeMatcherValue) -> SyntaxNode {
+        match n {
+            AnyCssAttributeMatcherValue::CssIdentifier(it) => it.into(),
+            AnyCssAttributeMatcherValue::CssString(it) => it.into(),
+        }
+    }
+}
+impl From<AnyCssAttributeMatcherValue> for SyntaxElement {
+    fn from(n: AnyCssAttributeMatcherValue) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssBogusRule> for AnyCssBlockItem {
+    fn from(node: CssBogusRule) -> AnyCssBlockItem { AnyCssBlockItem::CssBogusRule(node) }
+}
+impl From<CssDeclaration> for AnyCssBlockItem {
+    fn from(node: CssDeclaration) -> AnyCssBlockItem { AnyCssBlockItem::CssDeclaration(node) }
+}
+impl From<CssEmptyDeclaration> for AnyCssBlockItem {
+    fn from(node: CssEmptyDeclaration) -> AnyCssBlockItem {
+        AnyCssBlockItem::CssEmptyDeclaration(node)
+    }
+}
+impl From<CssRule> for AnyCssBlockItem {
+    fn from(node: CssRule) -> AnyCssBlockItem { AnyCssBlockItem::CssRule(node) }
+}
+impl AstNode for AnyCssBlockItem {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = AnyCssAtRule::KIND_SET
+        .union(CssBogusRule::KIND_SET)
+        .union(CssDeclaration::KIND_SET)
+        .union(CssEmptyDeclaration::KIND_SET)
+        .union(CssRule::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        match kind {
+            CSS_BOGUS_RULE | CSS_DECLARATION | CSS_EMPTY_DECLARATION | CSS_RULE => true,
+            k if AnyCssAtRule::can_cast(k) => true,
+            _ => false,
+        }
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_BOGUS_RULE => AnyCssBlockItem::CssBogusRule(CssBogusRule { syntax }),
+            CSS_DECLARATION => AnyCssBlockItem::CssDeclaration(CssDeclaration { syntax }),
+            CSS_EMPTY_DECLARATION => {
+                AnyCssBlockItem::CssEmptyDeclaration(CssEmptyDeclaration { syntax })
+            }
+            CSS_RULE => AnyCssBlockItem::CssRule(CssRule { syntax }),
+            _ => {
+                if let Some(any_css_at_rule) = AnyCssAtRule::cast(syntax) {
+                    return Some(AnyCssBlockItem::AnyCssAtRule(any_css_at_rule));
+                }
+                return None;
+            }
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyCssBlockItem::CssBogusRule(it) => &it.syntax,
+            AnyCssBlockItem::CssDeclaration(it) => &it.syntax,
+            AnyCssBlockItem::CssEmptyDeclaration(it) => &it.syntax,
+            AnyCssBlockItem::CssRule(it) => &it.syntax,
+            AnyCssBlockItem::AnyCssAtRule(it) => it.syntax(),
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyCssBlockItem::CssBogusRule(it) => it.syntax,
+            AnyCssBlockItem::CssDeclaration(it) => it.syntax,
+            AnyCssBlockItem::CssEmptyDeclaration(it) => it.syntax,
+            AnyCssBlockItem::CssRule(it) => it.syntax,
+            AnyCssBlockItem::AnyCssAtRule(it) => it.into_syntax(),
+        }
+    }
+}
+impl std::fmt::Debug for AnyCssBlockItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyCssBlockItem::AnyCssAtRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssBlockItem::CssBogusRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssBlockItem::CssDeclaration(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssBlockItem::CssEmptyDeclaration(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssBlockItem::CssRule(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyCssBlockItem> for SyntaxNode {
+    fn from(n: AnyCssBlockItem) -> SyntaxNode {
+        match n {
+            AnyCssBlockItem::AnyCssAtRule(it) => it.into(),
+            AnyCssBlockItem::CssBogusRule(it) => it.into(),
+            AnyCssBlockItem::CssDeclaration(it) => it.into(),
+            AnyCssBlockItem::CssEmptyDeclaration(it) => it.into(),
+            AnyCssBlockItem::CssRule(it) => it.into(),
+        }
+    }
+}
+impl From<AnyCssBlockItem> for SyntaxElement {
+    fn from(n: AnyCssBlockItem) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssCustomProperty> for AnyCssDeclarationName {
+    fn from(node: CssCustomProperty) -> AnyCssDeclarationName {
+        AnyCssDeclarationName::CssCustomProperty(node)
+    }
+}
+impl From<CssIdentifier> for AnyCssDeclarationName {
+    fn from(node: CssIdentifier) -> AnyCssDeclarationName {
+        AnyCssDeclarationName::CssIdentifier(node)
+    }
+}
+impl AstNode for AnyCssDeclarationName {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        CssCustomProperty::KIND_SET.union(CssIdentifier::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, CSS_CUSTOM_PROPERTY | CSS_IDENTIFIER) }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_CUSTOM_PROPERTY => {
+                AnyCssDeclarationName::CssCustomProperty(CssCustomProperty { syntax })
+            }
+            CSS_IDENTIFIER => AnyCssDeclarationName::CssIdentifier(CssIdentifier { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyCssDeclarationName::CssCustomProperty(it) => &it.syntax,
+            AnyCssDeclarationName::CssIdentifier(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyCssDeclarationName::CssCustomProperty(it) => it.syntax,
+            AnyCssDeclarationName::CssIdentifier(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyCssDeclarationName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyCssDeclarationName::CssCustomProperty(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssDeclarationName::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyCssDeclarationName> for SyntaxNode {
+    fn from(n: AnyCssDeclarationName) -> SyntaxNode {
+        match n {
+            AnyCssDeclarationName::CssCustomProperty(it) => it.into(),
+            AnyCssDeclarationName::CssIdentifier(it) => it.into(),
+        }
+    }
+}
+impl From<AnyCssDeclarationName> for SyntaxElement {
+    fn from(n: AnyCssDeclarationName) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssSimpleFunction> for AnyCssFunction {
+    fn from(node: CssSimpleFunction) -> AnyCssFunction { AnyCssFunction::CssSimpleFunction(node) }
+}
+impl From<CssUrlFunction> for AnyCssFunction {
+    fn from(node: CssUrlFunction) -> AnyCssFunction { AnyCssFunction::CssUrlFunction(node) }
+}
+impl From<CssVarFunction> for AnyCssFunction {
+    fn from(node: CssVarFunction) -> AnyCssFunction { AnyCssFunction::CssVarFunction(node) }
+}
+impl AstNode for AnyCssFunction {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = CssSimpleFunction::KIND_SET
+        .union(CssUrlFunction::KIND_SET)
+        .union(CssVarFunction::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            CSS_SIMPLE_FUNCTION | CSS_URL_FUNCTION | CSS_VAR_FUNCTION
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_SIMPLE_FUNCTION => AnyCssFunction::CssSimpleFunction(CssSimpleFunction { syntax }),
+            CSS_URL_FUNCTION => AnyCssFunction::CssUrlFunction(CssUrlFunction { syntax }),
+            CSS_VAR_FUNCTION => AnyCssFunction::CssVarFunction(CssVarFunction { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyCssFunction::CssSimpleFunction(it) => &it.syntax,
+            AnyCssFunction::CssUrlFunction(it) => &it.syntax,
+            AnyCssFunction::CssVarFunction(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyCssFunction::CssSimpleFunction(it) => it.syntax,
+            AnyCssFunction::CssUrlFunction(it) => it.syntax,
+            AnyCssFunction::CssVarFunction(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyCssFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyCssFunction::CssSimpleFunction(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssFunction::CssUrlFunction(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssFunction::CssVarFunction(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyCssFunction> for SyntaxNode {
+    fn from(n: AnyCssFunction) -> SyntaxNode {
+        match n {
+            AnyCssFunction::CssSimpleFunction(it) => it.into(),
+            AnyCssFunction::CssUrlFunction(it) => it.into(),
+            AnyCssFunction::CssVarFunction(it) => it.into(),
+        }
+    }
+}
+impl From<AnyCssFunction> for SyntaxElement {
+    fn from(n: AnyCssFunction) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssString> for AnyCssImportUrl {
+    fn from(node: CssString) -> AnyCssImportUrl { AnyCssImportUrl::CssString(node) }
+}
+impl From<CssUrlFunction> for AnyCssImportUrl {
+    fn from(node: CssUrlFunction) -> AnyCssImportUrl { AnyCssImportUrl::CssUrlFunction(node) }
+}
+impl AstNode for AnyCssImportUrl {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = CssString::KIND_SET.union(CssUrlFunction::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, CSS_STRING | CSS_URL_FUNCTION) }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_STRING => AnyCssImportUrl::CssString(CssString { syntax }),
+            CSS_URL_FUNCTION => AnyCssImportUrl::CssUrlFunction(CssUrlFunction { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyCssImportUrl::CssString(it) => &it.syntax,
+            AnyCssImportUrl::CssUrlFunction(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyCssImportUrl::CssString(it) => it.syntax,
+            AnyCssImportUrl::CssUrlFunction(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyCssImportUrl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssVarFunctionValue")
-            .field(
-                "comma_token",
-                &support::DebugSyntaxResult(self.comma_token()),
-            )
-            .field("value", &support::DebugSyntaxResult(self.value()))
-            .finish()
+        match self {
+            AnyCssImportUrl::CssString(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssImportUrl::CssUrlFunction(it) => std::fmt::Debug::fmt(it, f),
+        }
     }
 }
-impl From<CssVarFunctionValue> for SyntaxNode {
-    fn from(n: CssVarFunctionValue) -> SyntaxNode { n.syntax }
-}
-impl From<CssVarFunctionValue> for SyntaxElement {
-    fn from(n: CssVarFunctionValue) -> SyntaxElement { n.syntax.into() }
-}
-impl From<CssAtMediaQueryFeatureBoolean> for AnyCssAtMediaQueryFeatureType {
-    fn from(node: CssAtMediaQueryFeatureBoolean) -> AnyCssAtMediaQueryFeatureType {
-        AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(node)
+impl From<AnyCssImportUrl> for SyntaxNode {
+    fn from(n: AnyCssImportUrl) -> SyntaxNode {
+        match n {
+            AnyCssImportUrl::CssString(it) => it.into(),
+            AnyCssImportUrl::CssUrlFunction(it) => it.into(),
+        }
     }
 }
-impl From<CssAtMediaQueryFeatureCompare> for AnyCssAtMediaQueryFeatureType {
-    fn from(node: CssAtMediaQueryFeatureCompare) -> AnyCssAtMediaQueryFeatureType {
-        AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(node)
+impl From<AnyCssImportUrl> for SyntaxElement {
+    fn from(n: AnyCssImportUrl) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
     }
 }
-impl From<CssAtMediaQueryFeaturePlain> for AnyCssAtMediaQueryFeatureType {
-    fn from(node: CssAtMediaQueryFeaturePlain) -> AnyCssAtMediaQueryFeatureType {
-        AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(node)
-    }
+impl From<CssIdentifier> for AnyCssKeyframesName {
+    fn from(node: CssIdentifier) -> AnyCssKeyframesName { AnyCssKeyframesName::CssIdentifier(node) }
 }
-impl From<CssAtMediaQueryFeatureRange> for AnyCssAtMediaQueryFeatureType {
-    fn from(node: CssAtMediaQueryFeatureRange) -> AnyCssAtMediaQueryFeatureType {
-        AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(node)
-    }
+impl From<CssString> for AnyCssKeyframesName {
+    fn from(node: CssString) -> AnyCssKeyframesName { AnyCssKeyframesName::CssString(node) }
 }
-impl AstNode for AnyCssAtMediaQueryFeatureType {
+impl AstNode for AnyCssKeyframesName {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = CssAtMediaQueryFeatureBoolean::KIND_SET
-        .union(CssAtMediaQueryFeatureCompare::KIND_SET)
-        .union(CssAtMediaQueryFeaturePlain::KIND_SET)
-        .union(CssAtMediaQueryFeatureRange::KIND_SET);
-    fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(
-            kind,
-            CSS_AT_MEDIA_QUERY_FEATURE_BOOLEAN
-                | CSS_AT_MEDIA_QUERY_FEATURE_COMPARE
-                | CSS_AT_MEDIA_QUERY_FEATURE_PLAIN
-                | CSS_AT_MEDIA_QUERY_FEATURE_RANGE
-        )
-    }
+    const KIND_SET: SyntaxKindSet<Language> = CssIdentifier::KIND_SET.union(CssString::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, CSS_IDENTIFIER | CSS_STRING) }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            CSS_AT_MEDIA_QUERY_FEATURE_BOOLEAN => {
-                AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(
-                    CssAtMediaQueryFeatureBoolean { syntax },
-                )
-            }
-            CSS_AT_MEDIA_QUERY_FEATURE_COMPARE => {
-                AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(
-                    CssAtMediaQueryFeatureCompare { syntax },
-                )
-            }
-            CSS_AT_MEDIA_QUERY_FEATURE_PLAIN => {
-                AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(
-                    CssAtMediaQueryFeaturePlain { syntax },
-                )
-            }
-            CSS_AT_MEDIA_QUERY_FEATURE_RANGE => {
-                AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(
-                    CssAtMediaQueryFeatureRange { syntax },
-                )
-            }
+            CSS_IDENTIFIER => AnyCssKeyframesName::CssIdentifier(CssIdentifier { syntax }),
+            CSS_STRING => AnyCssKeyframesName::CssString(CssString { syntax }),
             _ => return None,
         };
         Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(it) => &it.syntax,
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(it) => &it.syntax,
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(it) => &it.syntax,
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(it) => &it.syntax,
+            AnyCssKeyframesName::CssIdentifier(it) => &it.syntax,
+            AnyCssKeyframesName::CssString(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(it) => it.syntax,
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(it) => it.syntax,
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(it) => it.syntax,
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(it) => it.syntax,
+            AnyCssKeyframesName::CssIdentifier(it) => it.syntax,
+            AnyCssKeyframesName::CssString(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for AnyCssAtMediaQueryFeatureType {
+impl std::fmt::Debug for AnyCssKeyframesName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(it) => {
-                std::fmt::Debug::fmt(it, f)
-            }
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(it) => {
-                std::fmt::Debug::fmt(it, f)
-            }
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(it) => {
-                std::fmt::Debug::fmt(it, f)
-            }
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(it) => {
-                std::fmt::Debug::fmt(it, f)
-            }
+            AnyCssKeyframesName::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssKeyframesName::CssString(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyCssAtMediaQueryFeatureType> for SyntaxNode {
-    fn from(n: AnyCssAtMediaQueryFeatureType) -> SyntaxNode {
+impl From<AnyCssKeyframesName> for SyntaxNode {
+    fn from(n: AnyCssKeyframesName) -> SyntaxNode {
         match n {
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureBoolean(it) => it.into(),
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureCompare(it) => it.into(),
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeaturePlain(it) => it.into(),
-            AnyCssAtMediaQueryFeatureType::CssAtMediaQueryFeatureRange(it) => it.into(),
+            AnyCssKeyframesName::CssIdentifier(it) => it.into(),
+            AnyCssKeyframesName::CssString(it) => it.into(),
         }
     }
 }
-impl From<AnyCssAtMediaQueryFeatureType> for SyntaxElement {
-    fn from(n: AnyCssAtMediaQueryFeatureType) -> SyntaxElement {
+impl From<AnyCssKeyframesName> for SyntaxElement {
+    fn from(n: AnyCssKeyframesName) -> SyntaxElement {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<CssAtMediaQueryFeature> for AnyCssAtMediaQueryType {
-    fn from(node: CssAtMediaQueryFeature) -> AnyCssAtMediaQueryType {
-        AnyCssAtMediaQueryType::CssAtMediaQueryFeature(node)
+impl From<CssKeyframesSelector> for AnyCssKeyframesSelector {
+    fn from(node: CssKeyframesSelector) -> AnyCssKeyframesSelector {
+        AnyCssKeyframesSelector::CssKeyframesSelector(node)
     }
 }
-impl From<CssIdentifier> for AnyCssAtMediaQueryType {
-    fn from(node: CssIdentifier) -> AnyCssAtMediaQueryType {
-        AnyCssAtMediaQueryType::CssIdentifier(node)
+impl From<CssPercentage> for AnyCssKeyframesSelector {
+    fn from(node: CssPercentage) -> AnyCssKeyframesSelector {
+        AnyCssKeyframesSelector::CssPercentage(node)
     }
 }
-impl AstNode for AnyCssAtMediaQueryType {
+impl AstNode for AnyCssKeyframesSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        CssAtMediaQueryFeature::KIND_SET.union(CssIdentifier::KIND_SET);
+        CssKeyframesSelector::KIND_SET.union(CssPercentage::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, CSS_AT_MEDIA_QUERY_FEATURE | CSS_IDENTIFIER)
+        matches!(kind, CSS_KEYFRAMES_SELECTOR | CSS_PERCENTAGE)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            CSS_AT_MEDIA_QUERY_FEATURE => {
-                AnyCssAtMediaQueryType::CssAtMediaQueryFeature(CssAtMediaQueryFeature { syntax })
+            CSS_KEYFRAMES_SELECTOR => {
+                AnyCssKeyframesSelector::CssKeyframesSelector(CssKeyframesSelector { syntax })
             }
-            CSS_IDENTIFIER => AnyCssAtMediaQueryType::CssIdentifier(CssIdentifier { syntax }),
+            CSS_PERCENTAGE => AnyCssKeyframesSelector::CssPercentage(CssPercentage { syntax }),
             _ => return None,
         };
         Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            AnyCssAtMediaQueryType::CssAtMediaQueryFeature(it) => &it.syntax,
-            AnyCssAtMediaQueryType::CssIdentifier(it) => &it.syntax,
+            AnyCssKeyframesSelector::CssKeyframesSelector(it) => &it.syntax,
+            AnyCssKeyframesSelector::CssPercentage(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            AnyCssAtMediaQueryType::CssAtMediaQueryFeature(it) => it.syntax,
-            AnyCssAtMediaQueryType::CssIdentifier(it) => it.syntax,
+            AnyCssKeyframesSelector::CssKeyframesSelector(it) => it.syntax,
+            AnyCssKeyframesSelector::CssPercentage(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for AnyCssAtMediaQueryType {
+impl std::fmt::Debug for AnyCssKeyframesSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AnyCssAtMediaQueryType::CssAtMediaQueryFeature(it) => std::fmt::Debug::fmt(it, f),
-            AnyCssAtMediaQueryType::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssKeyframesSelector::CssKeyframesSelector(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssKeyframesSelector::CssPercentage(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyCssAtMediaQueryType> for SyntaxNode {
-    fn from(n: AnyCssAtMediaQueryType) -> SyntaxNode {
+impl From<AnyCssKeyframesSelector> for SyntaxNode {
+    fn from(n: AnyCssKeyframesSelector) -> SyntaxNode {
         match n {
-            AnyCssAtMediaQueryType::CssAtMediaQueryFeature(it) => it.into(),
-            AnyCssAtMediaQueryType::CssIdentifier(it) => it.into(),
+            AnyCssKeyframesSelector::CssKeyframesSelector(it) => it.into(),
+            AnyCssKeyframesSelector::CssPercentage(it) => it.into(),
         }
     }
 }
-impl From<AnyCssAtMediaQueryType> for SyntaxElement {
-    fn from(n: AnyCssAtMediaQueryType) -> SyntaxElement {
+impl From<AnyCssKeyframesSelector> for SyntaxElement {
+    fn from(n: AnyCssKeyframesSelector) -> SyntaxElement {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<CssAtKeyframes> for AnyCssAtRule {
-    fn from(node: CssAtKeyframes) -> AnyCssAtRule { AnyCssAtRule::CssAtKeyframes(node) }
+impl From<CssPseudoSelectorParameters> for AnyCssPseudoParameters {
+    fn from(node: CssPseudoSelectorParameters) -> AnyCssPseudoParameters {
+        AnyCssPseudoParameters::CssPseudoSelectorParameters(node)
+    }
 }
-impl From<CssAtMedia> for AnyCssAtRule {
-    fn from(node: CssAtMedia) -> AnyCssAtRule { AnyCssAtRule::CssAtMedia(node) }
+impl From<CssPseudoValueParameters> for AnyCssPseudoParameters {
+    fn from(node: CssPseudoValueParameters) -> AnyCssPseudoParameters {
+        AnyCssPseudoParameters::CssPseudoValueParameters(node)
+    }
 }
-impl AstNode for AnyCssAtRule {
+impl AstNode for AnyCssPseudoParameters {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = CssAtKeyframes::KIND_SET.union(CssAtMedia::KIND_SET);
-    fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, CSS_AT_KEYFRAMES | CSS_AT_MEDIA) }
+    const KIND_SET: SyntaxKindSet<Language> =
+        CssPseudoSelectorParameters::KIND_SET.union(CssPseudoValueParameters::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            CSS_PSEUDO_SELECTOR_PARAMETERS | CSS_PSEUDO_VALUE_PARAMETERS
+        )
+    }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            CSS_AT_KEYFRAMES => AnyCssAtRule::CssAtKeyframes(CssAtKeyframes { syntax }),
-            CSS_AT_MEDIA => AnyCssAtRule::CssAtMedia(CssAtMedia { syntax }),
+            CSS_PSEUDO_SELECTOR_PARAMETERS => {
+                AnyCssPseudoParameters::CssPseudoSelectorParameters(CssPseudoSelectorParameters {
+                    syntax,
+                })
+            }
+            CSS_PSEUDO_VALUE_PARAMETERS => {
+                AnyCssPseudoParameters::CssPseudoValueParameters(CssPseudoValueParameters {
+                    syntax,
+                })
+            }
             _ => return None,
         };
         Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            AnyCssAtRule::CssAtKeyframes(it) => &it.syntax,
-            AnyCssAtRule::CssAtMedia(it) => &it.syntax,
+            AnyCssPseudoParameters::CssPseudoSelectorParameters(it) => &it.syntax,
+            AnyCssPseudoParameters::CssPseudoValueParameters(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            AnyCssAtRule::CssAtKeyframes(it) => it.syntax,
-            AnyCssAtRule::CssAtMedia(it) => it.syntax,
+            AnyCssPseudoParameters::CssPseudoSelectorParameters(it) => it.syntax,
+            AnyCssPseudoParameters::CssPseudoValueParameters(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for AnyCssAtRule {
+impl std::fmt::Debug for AnyCssPseudoParameters {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AnyCssAtRule::CssAtKeyframes(it) => std::fmt::Debug::fmt(it, f),
-            AnyCssAtRule::CssAtMedia(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssPseudoParameters::CssPseudoSelectorParameters(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssPseudoParameters::CssPseudoValueParameters(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyCssAtRule> for SyntaxNode {
-    fn from(n: AnyCssAtRule) -> SyntaxNode {
+impl From<AnyCssPseudoParameters> for SyntaxNode {
+    fn from(n: AnyCssPseudoParameters) -> SyntaxNode {
         match n {
-            AnyCssAtRule::CssAtKeyframes(it) => it.into(),
-            AnyCssAtRule::CssAtMedia(it) => it.into(),
+            AnyCssPseudoParameters::CssPseudoSelectorParameters(it) => it.into(),
+            AnyCssPseudoParameters::CssPseudoValueParameters(it) => it.into(),
         }
     }
 }
-impl From<AnyCssAtRule> for SyntaxElement {
-    fn from(n: AnyCssAtRule) -> SyntaxElement {
+impl From<AnyCssPseudoParameters> for SyntaxElement {
+    fn from(n: AnyCssPseudoParameters) -> SyntaxElement {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
+impl From<CssBogusRule> for AnyCssRule {
+    fn from(node: CssBogusRule) -> AnyCssRule { AnyCssRule::CssBogusRule(node) }
+}
 impl From<CssRule> for AnyCssRule {
     fn from(node: CssRule) -> AnyCssRule { AnyCssRule::CssRule(node) }
 }
 impl AstNode for AnyCssRule {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = AnyCssAtRule::KIND_SET.union(CssRule::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = AnyCssAtRule::KIND_SET
+        .union(CssBogusRule::KIND_SET)
+        .union(CssRule::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
-            CSS_RULE => true,
+            CSS_BOGUS_RULE | CSS_RULE => true,
             k if AnyCssAtRule::can_cast(k) => true,
             _ => false,
         }
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
+            CSS_BOGUS_RULE => AnyCssRule::CssBogusRule(CssBogusRule { syntax }),
             CSS_RULE => AnyCssRule::CssRule(CssRule { syntax }),
             _ => {
                 if let Some(any_css_at_rule) = AnyCssAtRule::cast(syntax) {
@@ -3830,12 +5329,14 @@ impl AstNode for AnyCssRule {
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
+            AnyCssRule::CssBogusRule(it) => &it.syntax,
             AnyCssRule::CssRule(it) => &it.syntax,
             AnyCssRule::AnyCssAtRule(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
+            AnyCssRule::CssBogusRule(it) => it.syntax,
             AnyCssRule::CssRule(it) => it.syntax,
             AnyCssRule::AnyCssAtRule(it) => it.into_syntax(),
         }
@@ -3845,6 +5346,7 @@ impl std::fmt::Debug for AnyCssRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             AnyCssRule::AnyCssAtRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssRule::CssBogusRule(it) => std::fmt::Debug::fmt(it, f),
             AnyCssRule::CssRule(it) => std::fmt::Debug::fmt(it, f),
         }
     }
@@ -3853,6 +5355,7 @@ impl From<AnyCssRule> for SyntaxNode {
     fn from(n: AnyCssRule) -> SyntaxNode {
         match n {
             AnyCssRule::AnyCssAtRule(it) => it.into(),
+            AnyCssRule::CssBogusRule(it) => it.into(),
             AnyCssRule::CssRule(it) => it.into(),
         }
     }
@@ -3863,6 +5366,89 @@ impl From<AnyCssRule> for SyntaxElement {
         node.into()
     }
 }
+impl From<CssBogusSelector> for AnyCssSelector {
+    fn from(node: CssBogusSelector) -> AnyCssSelector { AnyCssSelector::CssBogusSelector(node) }
+}
+impl From<CssComplexSelector> for AnyCssSelector {
+    fn from(node: CssComplexSelector) -> AnyCssSelector { AnyCssSelector::CssComplexSelector(node) }
+}
+impl From<CssRelativeSelector> for AnyCssSelector {
+    fn from(node: CssRelativeSelector) -> AnyCssSelector {
+        AnyCssSelector::CssRelativeSelector(node)
+    }
+}
+impl From<CssSelector> for AnyCssSelector {
+    fn from(node: CssSelector) -> AnyCssSelector { AnyCssSelector::CssSelector(node) }
+}
+impl AstNode for AnyCssSelector {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = CssBogusSelector::KIND_SET
+        .union(CssComplexSelector::KIND_SET)
+        .union(CssRelativeSelector::KIND_SET)
+        .union(CssSelector::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            CSS_BOGUS_SELECTOR | CSS_COMPLEX_SELECTOR | CSS_RELATIVE_SELECTOR | CSS_SELECTOR
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_BOGUS_SELECTOR => AnyCssSelector::CssBogusSelector(CssBogusSelector { syntax }),
+            CSS_COMPLEX_SELECTOR => {
+                AnyCssSelector::CssComplexSelector(CssComplexSelector { syntax })
+            }
+            CSS_RELATIVE_SELECTOR => {
+                AnyCssSelector::CssRelativeSelector(CssRelativeSelector { syntax })
+            }
+            CSS_SELECTOR => AnyCssSelector::CssSelector(CssSelector { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyCssSelector::CssBogusSelector(it) => &it.syntax,
+            AnyCssSelector::CssComplexSelector(it) => &it.syntax,
+            AnyCssSelector::CssRelativeSelector(it) => &it.syntax,
+            AnyCssSelector::CssSelector(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyCssSelector::CssBogusSelector(it) => it.syntax,
+            AnyCssSelector::CssComplexSelector(it) => it.syntax,
+            AnyCssSelector::CssRelativeSelector(it) => it.syntax,
+            AnyCssSelector::CssSelector(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyCssSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyCssSelector::CssBogusSelector(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssSelector::CssComplexSelector(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssSelector::CssRelativeSelector(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssSelector::CssSelector(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyCssSelector> for SyntaxNode {
+    fn from(n: AnyCssSelector) -> SyntaxNode {
+        match n {
+            AnyCssSelector::CssBogusSelector(it) => it.into(),
+            AnyCssSelector::CssComplexSelector(it) => it.into(),
+            AnyCssSelector::CssRelativeSelector(it) => it.into(),
+            AnyCssSelector::CssSelector(it) => it.into(),
+        }
+    }
+}
+impl From<AnyCssSelector> for SyntaxElement {
+    fn from(n: AnyCssSelector) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
 impl From<CssAttributeSelectorPattern> for AnyCssSelectorPattern {
     fn from(node: CssAttributeSelectorPattern) -> AnyCssSelectorPattern {
         AnyCssSelectorPattern::CssAttributeSelectorPattern(node)
@@ -3873,21 +5459,26 @@ impl From<CssClassSelectorPattern> for AnyCssSelectorPattern {
         AnyCssSelectorPattern::CssClassSelectorPattern(node)
     }
 }
-impl From<CssCombinatorSelectorPattern> for AnyCssSelectorPattern {
-    fn from(node: CssCombinatorSelectorPattern) -> AnyCssSelectorPattern {
-        AnyCssSelectorPattern::CssCombinatorSelectorPattern(node)
-    }
-}
 impl From<CssIdSelectorPattern> for AnyCssSelectorPattern {
     fn from(node: CssIdSelectorPattern) -> AnyCssSelectorPattern {
         AnyCssSelectorPattern::CssIdSelectorPattern(node)
     }
 }
+impl From<CssNestingSelectorPattern> for AnyCssSelectorPattern {
+    fn from(node: CssNestingSelectorPattern) -> AnyCssSelectorPattern {
+        AnyCssSelectorPattern::CssNestingSelectorPattern(node)
+    }
+}
 impl From<CssPseudoClassSelectorPattern> for AnyCssSelectorPattern {
     fn from(node: CssPseudoClassSelectorPattern) -> AnyCssSelectorPattern {
         AnyCssSelectorPattern::CssPseudoClassSelectorPattern(node)
     }
 }
+impl From<CssPseudoElementSelectorPattern> for AnyCssSelectorPattern {
+    fn from(node: CssPseudoElementSelectorPattern) -> AnyCssSelectorPattern {
+        AnyCssSelectorPattern::CssPseudoElementSelectorPattern(node)
+    }
+}
 impl From<CssTypeSelectorPattern> for AnyCssSelectorPattern {
     fn from(node: CssTypeSelectorPattern) -> AnyCssSelectorPattern {
         AnyCssSelectorPattern::CssTypeSelectorPattern(node)
@@ -3902,9 +5493,10 @@ impl AstNode for AnyCssSelectorPattern {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = CssAttributeSelectorPattern::KIND_SET
         .union(CssClassSelectorPattern::KIND_SET)
-        .union(CssCombinatorSelectorPattern::KIND_SET)
         .union(CssIdSelectorPattern::KIND_SET)
+        .union(CssNestingSelectorPattern::KIND_SET)
         .union(CssPseudoClassSelectorPattern::KIND_SET)
+        .union(CssPseudoElementSelectorPattern::KIND_SET)
         .union(CssTypeSelectorPattern::KIND_SET)
         .union(CssUniversalSelectorPattern::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
@@ -3912,9 +5504,10 @@ impl AstNode for AnyCssSelectorPattern {
             kind,
             CSS_ATTRIBUTE_SELECTOR_PATTERN
                 | CSS_CLASS_SELECTOR_PATTERN
-                | CSS_COMBINATOR_SELECTOR_PATTERN
                 | CSS_ID_SELECTOR_PATTERN
+                | CSS_NESTING_SELECTOR_PATTERN
                 | CSS_PSEUDO_CLASS_SELECTOR_PATTERN
+                | CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN
                 | CSS_TYPE_SELECTOR_PATTERN
                 | CSS_UNIVERSAL_SELECTOR_PATTERN
         )
@@ -3929,19 +5522,24 @@ impl AstNode for AnyCssSelectorPattern {
             CSS_CLASS_SELECTOR_PATTERN => {
                 AnyCssSelectorPattern::CssClassSelectorPattern(CssClassSelectorPattern { syntax })
             }
-            CSS_COMBINATOR_SELECTOR_PATTERN => {
-                AnyCssSelectorPattern::CssCombinatorSelectorPattern(CssCombinatorSelectorPattern {
-                    syntax,
-                })
-            }
             CSS_ID_SELECTOR_PATTERN => {
                 AnyCssSelectorPattern::CssIdSelectorPattern(CssIdSelectorPattern { syntax })
             }
+            CSS_NESTING_SELECTOR_PATTERN => {
+                AnyCssSelectorPattern::CssNestingSelectorPattern(CssNestingSelectorPattern {
+                    syntax,
+                })
+            }
             CSS_PSEUDO_CLASS_SELECTOR_PATTERN => {
                 AnyCssSelectorPattern::CssPseudoClassSelectorPattern(
                     CssPseudoClassSelectorPattern { syntax },
                 )
             }
+            CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN => {
+                AnyCssSelectorPattern::CssPseudoElementSelectorPattern(
+                    CssPseudoElementSelectorPattern { syntax },
+                )
+            }
             CSS_TYPE_SELECTOR_PATTERN => {
                 AnyCssSelectorPattern::CssTypeSelectorPattern(CssTypeSelectorPattern { syntax })
             }
@@ -3958,9 +5556,10 @@ impl AstNode for AnyCssSelectorPattern {
         match self {
             AnyCssSelectorPattern::CssAttributeSelectorPattern(it) => &it.syntax,
             AnyCssSelectorPattern::CssClassSelectorPattern(it) => &it.syntax,
-            AnyCssSelectorPattern::CssCombinatorSelectorPattern(it) => &it.syntax,
             AnyCssSelectorPattern::CssIdSelectorPattern(it) => &it.syntax,
+            AnyCssSelectorPattern::CssNestingSelectorPattern(it) => &it.syntax,
             AnyCssSelectorPattern::CssPseudoClassSelectorPattern(it) => &it.syntax,
+            AnyCssSelectorPattern::CssPseudoElementSelectorPattern(it) => &it.syntax,
             AnyCssSelectorPattern::CssTypeSelectorPattern(it) => &it.syntax,
             AnyCssSelectorPattern::CssUniversalSelectorPattern(it) => &it.syntax,
         }
@@ -3969,9 +5568,10 @@ impl AstNode for AnyCssSelectorPattern {
         match self {
             AnyCssSelectorPattern::CssAttributeSelectorPattern(it) => it.syntax,
             AnyCssSelectorPattern::CssClassSelectorPattern(it) => it.syntax,
-            AnyCssSelectorPattern::CssCombinatorSelectorPattern(it) => it.syntax,
             AnyCssSelectorPattern::CssIdSelectorPattern(it) => it.syntax,
+            AnyCssSelectorPattern::CssNestingSelectorPattern(it) => it.syntax,
             AnyCssSelectorPattern::CssPseudoClassSelectorPattern(it) => it.syntax,
+            AnyCssSelectorPattern::CssPseudoElementSelectorPattern(it) => it.syntax,
             AnyCssSelectorPattern::CssTypeSelectorPattern(it) => it.syntax,
             AnyCssSelectorPattern::CssUniversalSelectorPattern(it) => it.syntax,
         }
@@ -3982,9 +5582,12 @@ impl std::fmt::Debug for AnyCssSelectorPattern {
         match self {
             AnyCssSelectorPattern::CssAttributeSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
             AnyCssSelectorPattern::CssClassSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
-            AnyCssSelectorPattern::CssCombinatorSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
             AnyCssSelectorPattern::CssIdSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssSelectorPattern::CssNestingSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
             AnyCssSelectorPattern::CssPseudoClassSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssSelectorPattern::CssPseudoElementSelectorPattern(it) => {
+                std::fmt::Debug::fmt(it, f)
+            }
             AnyCssSelectorPattern::CssTypeSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
             AnyCssSelectorPattern::CssUniversalSelectorPattern(it) => std::fmt::Debug::fmt(it, f),
         }
@@ -3995,9 +5598,10 @@ impl From<AnyCssSelectorPattern> for SyntaxNode {
         match n {
             AnyCssSelectorPattern::CssAttributeSelectorPattern(it) => it.into(),
             AnyCssSelectorPattern::CssClassSelectorPattern(it) => it.into(),
-            AnyCssSelectorPattern::CssCombinatorSelectorPattern(it) => it.into(),
             AnyCssSelectorPattern::CssIdSelectorPattern(it) => it.into(),
+            AnyCssSelectorPattern::CssNestingSelectorPattern(it) => it.into(),
             AnyCssSelectorPattern::CssPseudoClassSelectorPattern(it) => it.into(),
+            AnyCssSelectorPattern::CssPseudoElementSelectorPattern(it) => it.into(),
             AnyCssSelectorPattern::CssTypeSelectorPattern(it) => it.into(),
             AnyCssSelectorPattern::CssUniversalSelectorPattern(it) => it.into(),
         }
@@ -4009,8 +5613,67 @@ impl From<AnyCssSelectorPattern> for SyntaxElement {
         node.into()
     }
 }
-impl From<CssAnyFunction> for AnyCssValue {
-    fn from(node: CssAnyFunction) -> AnyCssValue { AnyCssValue::CssAnyFunction(node) }
+impl From<CssString> for AnyCssUrlValue {
+    fn from(node: CssString) -> AnyCssUrlValue { AnyCssUrlValue::CssString(node) }
+}
+impl From<CssUrlValueRaw> for AnyCssUrlValue {
+    fn from(node: CssUrlValueRaw) -> AnyCssUrlValue { AnyCssUrlValue::CssUrlValueRaw(node) }
+}
+impl AstNode for AnyCssUrlValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = CssString::KIND_SET.union(CssUrlValueRaw::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool { matches!(kind, CSS_STRING | CSS_URL_VALUE_RAW) }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_STRING => AnyCssUrlValue::CssString(CssString { syntax }),
+            CSS_URL_VALUE_RAW => AnyCssUrlValue::CssUrlValueRaw(CssUrlValueRaw { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyCssUrlValue::CssString(it) => &it.syntax,
+            AnyCssUrlValue::CssUrlValueRaw(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyCssUrlValue::CssString(it) => it.syntax,
+            AnyCssUrlValue::CssUrlValueRaw(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyCssUrlValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyCssUrlValue::CssString(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssUrlValue::CssUrlValueRaw(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyCssUrlValue> for SyntaxNode {
+    fn from(n: AnyCssUrlValue) -> SyntaxNode {
+        match n {
+            AnyCssUrlValue::CssString(it) => it.into(),
+            AnyCssUrlValue::CssUrlValueRaw(it) => it.into(),
+        }
+    }
+}
+impl From<AnyCssUrlValue> for SyntaxElement {
+    fn from(n: AnyCssUrlValue) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<CssBogusValue> for AnyCssValue {
+    fn from(node: CssBogusValue) -> AnyCssValue { AnyCssValue::CssBogusValue(node) }
+}
+impl From<CssBracketedValue> for AnyCssValue {
+    fn from(node: CssBracketedValue) -> AnyCssValue { AnyCssValue::CssBracketedValue(node) }
+}
+impl From<CssColor> for AnyCssValue {
+    fn from(node: CssColor) -> AnyCssValue { AnyCssValue::CssColor(node) }
 }
 impl From<CssCustomProperty> for AnyCssValue {
     fn from(node: CssCustomProperty) -> AnyCssValue { AnyCssValue::CssCustomProperty(node) }
@@ -4018,12 +5681,21 @@ impl From<CssCustomProperty> for AnyCssValue {
 impl From<CssDimension> for AnyCssValue {
     fn from(node: CssDimension) -> AnyCssValue { AnyCssValue::CssDimension(node) }
 }
+impl From<CssGenericDelimiter> for AnyCssValue {
+    fn from(node: CssGenericDelimiter) -> AnyCssValue { AnyCssValue::CssGenericDelimiter(node) }
+}
 impl From<CssIdentifier> for AnyCssValue {
     fn from(node: CssIdentifier) -> AnyCssValue { AnyCssValue::CssIdentifier(node) }
 }
 impl From<CssNumber> for AnyCssValue {
     fn from(node: CssNumber) -> AnyCssValue { AnyCssValue::CssNumber(node) }
 }
+impl From<CssParenthesizedValue> for AnyCssValue {
+    fn from(node: CssParenthesizedValue) -> AnyCssValue { AnyCssValue::CssParenthesizedValue(node) }
+}
+impl From<CssPercentage> for AnyCssValue {
+    fn from(node: CssPercentage) -> AnyCssValue { AnyCssValue::CssPercentage(node) }
+}
 impl From<CssRatio> for AnyCssValue {
     fn from(node: CssRatio) -> AnyCssValue { AnyCssValue::CssRatio(node) }
 }
@@ -4032,69 +5704,113 @@ impl From<CssString> for AnyCssValue {
 }
 impl AstNode for AnyCssValue {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = CssAnyFunction::KIND_SET
+    const KIND_SET: SyntaxKindSet<Language> = AnyCssFunction::KIND_SET
+        .union(CssBogusValue::KIND_SET)
+        .union(CssBracketedValue::KIND_SET)
+        .union(CssColor::KIND_SET)
         .union(CssCustomProperty::KIND_SET)
         .union(CssDimension::KIND_SET)
+        .union(CssGenericDelimiter::KIND_SET)
         .union(CssIdentifier::KIND_SET)
         .union(CssNumber::KIND_SET)
+        .union(CssParenthesizedValue::KIND_SET)
+        .union(CssPercentage::KIND_SET)
         .union(CssRatio::KIND_SET)
         .union(CssString::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(
-            kind,
-            CSS_ANY_FUNCTION
-                | CSS_CUSTOM_PROPERTY
-                | CSS_DIMENSION
-                | CSS_IDENTIFIER
-                | CSS_NUMBER
-                | CSS_RATIO
-                | CSS_STRING
-        )
+        match kind {
+            CSS_BOGUS_VALUE
+            | CSS_BRACKETED_VALUE
+            | CSS_COLOR
+            | CSS_CUSTOM_PROPERTY
+            | CSS_DIMENSION
+            | CSS_GENERIC_DELIMITER
+            | CSS_IDENTIFIER
+            | CSS_NUMBER
+            | CSS_PARENTHESIZED_VALUE
+            | CSS_PERCENTAGE
+            | CSS_RATIO
+            | CSS_STRING => true,
+            k if AnyCssFunction::can_cast(k) => true,
+            _ => false,
+        }
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            CSS_ANY_FUNCTION => AnyCssValue::CssAnyFunction(CssAnyFunction { syntax }),
+            CSS_BOGUS_VALUE => AnyCssValue::CssBogusValue(CssBogusValue { syntax }),
+            CSS_BRACKETED_VALUE => AnyCssValue::CssBracketedValue(CssBracketedValue { syntax }),
+            CSS_COLOR => AnyCssValue::CssColor(CssColor { syntax }),
             CSS_CUSTOM_PROPERTY => AnyCssValue::CssCustomProperty(CssCustomProperty { syntax }),
             CSS_DIMENSION => AnyCssValue::CssDimension(CssDimension { syntax }),
+            CSS_GENERIC_DELIMITER => {
+                AnyCssValue::CssGenericDelimiter(CssGenericDelimiter { syntax })
+            }
             CSS_IDENTIFIER => AnyCssValue::CssIdentifier(CssIdentifier { syntax }),
             CSS_NUMBER => AnyCssValue::CssNumber(CssNumber { syntax }),
+            CSS_PARENTHESIZED_VALUE => {
+                AnyCssValue::CssParenthesizedValue(CssParenthesizedValue { syntax })
+            }
+            CSS_PERCENTAGE => AnyCssValue::CssPercentage(CssPercentage { syntax }),
             CSS_RATIO => AnyCssValue::CssRatio(CssRatio { syntax }),
             CSS_STRING => AnyCssValue::CssString(CssString { syntax }),
-            _ => return None,
+            _ => {
+                if let Some(any_css_function) = AnyCssFunction::cast(syntax) {
+                    return Some(AnyCssValue::AnyCssFunction(any_css_function));
+                }
+                return None;
+            }
         };
         Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            AnyCssValue::CssAnyFunction(it) => &it.syntax,
+            AnyCssValue::CssBogusValue(it) => &it.syntax,
+            AnyCssValue::CssBracketedValue(it) => &it.syntax,
+            AnyCssValue::CssColor(it) => &it.syntax,
             AnyCssValue::CssCustomProperty(it) => &it.syntax,
             AnyCssValue::CssDimension(it) => &it.syntax,
+            AnyCssValue::CssGenericDelimiter(it) => &it.syntax,
             AnyCssValue::CssIdentifier(it) => &it.syntax,
             AnyCssValue::CssNumber(it) => &it.syntax,
+            AnyCssValue::CssParenthesizedValue(it) => &it.syntax,
+            AnyCssValue::CssPercentage(it) => &it.syntax,
             AnyCssValue::CssRatio(it) => &it.syntax,
             AnyCssValue::CssString(it) => &it.syntax,
+            AnyCssValue::AnyCssFunction(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            AnyCssValue::CssAnyFunction(it) => it.syntax,
+            AnyCssValue::CssBogusValue(it) => it.syntax,
+            AnyCssValue::CssBracketedValue(it) => it.syntax,
+            AnyCssValue::CssColor(it) => it.syntax,
             AnyCssValue::CssCustomProperty(it) => it.syntax,
             AnyCssValue::CssDimension(it) => it.syntax,
+            AnyCssValue::CssGenericDelimiter(it) => it.syntax,
             AnyCssValue::CssIdentifier(it) => it.syntax,
             AnyCssValue::CssNumber(it) => it.syntax,
+            AnyCssValue::CssParenthesizedValue(it) => it.syntax,
+            AnyCssValue::CssPercentage(it) => it.syntax,
             AnyCssValue::CssRatio(it) => it.syntax,
             AnyCssValue::CssString(it) => it.syntax,
+            AnyCssValue::AnyCssFunction(it) => it.into_syntax(),
         }
     }
 }
 impl std::fmt::Debug for AnyCssValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AnyCssValue::CssAnyFunction(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::AnyCssFunction(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::CssBogusValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::CssBracketedValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::CssColor(it) => std::fmt::Debug::fmt(it, f),
             AnyCssValue::CssCustomProperty(it) => std::fmt::Debug::fmt(it, f),
             AnyCssValue::CssDimension(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::CssGenericDelimiter(it) => std::fmt::Debug::fmt(it, f),
             AnyCssValue::CssIdentifier(it) => std::fmt::Debug::fmt(it, f),
             AnyCssValue::CssNumber(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::CssParenthesizedValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::CssPercentage(it) => std::fmt::Debug::fmt(it, f),
             AnyCssValue::CssRatio(it) => std::fmt::Debug::fmt(it, f),
             AnyCssValue::CssString(it) => std::fmt::Debug::fmt(it, f),
         }
@@ -4103,11 +5819,17 @@ impl std::fmt::Debug for AnyCssValue {
 impl From<AnyCssValue> for SyntaxNode {
     fn from(n: AnyCssValue) -> SyntaxNode {
         match n {
-            AnyCssValue::CssAnyFunction(it) => it.into(),
+            AnyCssValue::AnyCssFunction(it) => it.into(),
+            AnyCssValue::CssBogusValue(it) => it.into(),
+            AnyCssValue::CssBracketedValue(it) => it.into(),
+            AnyCssValue::CssColor(it) => it.into(),
             AnyCssValue::CssCustomProperty(it) => it.into(),
             AnyCssValue::CssDimension(it) => it.into(),
+            AnyCssValue::CssGenericDelimiter(it) => it.into(),
             AnyCssValue::CssIdentifier(it) => it.into(),
             AnyCssValue::CssNumber(it) => it.into(),
+            AnyCssValue::CssParenthesizedValue(it) => it.into(),
+            AnyCssValue::CssPercentage(it) => it.into(),
             AnyCssValue::CssRatio(it) => it.into(),
             AnyCssValue::CssString(it) => it.into(),
         }
@@ -4134,22 +5856,82 @@ impl std::fmt::Display for AnyCssAtRule {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for AnyCssAttributeMatcherValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyCssBlockItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyCssDeclarationName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyCssFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyCssImportUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyCssKeyframesName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyCssKeyframesSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyCssPseudoParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for AnyCssRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for AnyCssSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for AnyCssSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for AnyCssUrlValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for AnyCssValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAnyFunction {
+impl std::fmt::Display for CssAtCharset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssAtGeneric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssAtImport {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -4204,12 +5986,12 @@ impl std::fmt::Display for CssAtMediaQueryFeatureRange {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAtMediaQueryRange {
+impl std::fmt::Display for CssAtMediaQueryFeatureReverseCompare {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAttribute {
+impl std::fmt::Display for CssAtMediaQueryRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -4219,37 +6001,32 @@ impl std::fmt::Display for CssAttributeMatcher {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAttributeMeta {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
-    }
-}
-impl std::fmt::Display for CssAttributeModifier {
+impl std::fmt::Display for CssAttributeSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAttributeName {
+impl std::fmt::Display for CssBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssAttributeSelectorPattern {
+impl std::fmt::Display for CssBracketedValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssBlock {
+impl std::fmt::Display for CssClassSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssClassSelectorPattern {
+impl std::fmt::Display for CssColor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssCombinatorSelectorPattern {
+impl std::fmt::Display for CssComplexSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -4274,6 +6051,16 @@ impl std::fmt::Display for CssDimension {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for CssEmptyDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssGenericDelimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for CssIdSelectorPattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -4294,6 +6081,11 @@ impl std::fmt::Display for CssKeyframesSelector {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for CssNestingSelectorPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for CssNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -4304,6 +6096,11 @@ impl std::fmt::Display for CssParameter {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for CssParenthesizedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for CssPercentage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -4314,7 +6111,17 @@ impl std::fmt::Display for CssPseudoClassSelectorPattern {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for CssPseudoClassSelectorPatternParameters {
+impl std::fmt::Display for CssPseudoElementSelectorPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssPseudoSelectorParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssPseudoValueParameters {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -4324,6 +6131,11 @@ impl std::fmt::Display for CssRatio {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for CssRelativeSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for CssRoot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -4359,6 +6171,16 @@ impl std::fmt::Display for CssUniversalSelectorPattern {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for CssUrlFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for CssUrlValueRaw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for CssVarFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -4412,6 +6234,135 @@ impl From<CssBogus> for SyntaxNode {
 impl From<CssBogus> for SyntaxElement {
     fn from(n: CssBogus) -> SyntaxElement { n.syntax.into() }
 }
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssBogusRule {
+    syntax: SyntaxNode,
+}
+impl CssBogusRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn items(&self) -> SyntaxElementChildren { support::elements(&self.syntax) }
+}
+impl AstNode for CssBogusRule {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_RULE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_BOGUS_RULE }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssBogusRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssBogusRule")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<CssBogusRule> for SyntaxNode {
+    fn from(n: CssBogusRule) -> SyntaxNode { n.syntax }
+}
+impl From<CssBogusRule> for SyntaxElement {
+    fn from(n: CssBogusRule) -> SyntaxElement { n.syntax.into() }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssBogusSelector {
+    syntax: SyntaxNode,
+}
+impl CssBogusSelector {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn items(&self) -> SyntaxElementChildren { support::elements(&self.syntax) }
+}
+impl AstNode for CssBogusSelector {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_SELECTOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_BOGUS_SELECTOR }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssBogusSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssBogusSelector")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<CssBogusSelector> for SyntaxNode {
+    fn from(n: CssBogusSelector) -> SyntaxNode { n.syntax }
+}
+impl From<CssBogusSelector> for SyntaxElement {
+    fn from(n: CssBogusSelector) -> SyntaxElement { n.syntax.into() }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct CssBogusValue {
+    syntax: SyntaxNode,
+}
+impl CssBogusValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self { Self { syntax } }
+    pub fn items(&self) -> SyntaxElementChildren { support::elements(&self.syntax) }
+}
+impl AstNode for CssBogusValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BOGUS_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_BOGUS_VALUE }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { &self.syntax }
+    fn into_syntax(self) -> SyntaxNode { self.syntax }
+}
+impl std::fmt::Debug for CssBogusValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssBogusValue")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<CssBogusValue> for SyntaxNode {
+    fn from(n: CssBogusValue) -> SyntaxNode { n.syntax }
+}
+impl From<CssBogusValue> for SyntaxElement {
+    fn from(n: CssBogusValue) -> SyntaxElement { n.syntax.into() }
+}
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub struct CssAnySelectorPatternList {
     syntax_list: SyntaxList,
@@ -4551,6 +6502,75 @@ impl IntoIterator for CssAtKeyframesItemList {
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
+pub struct CssAtMediaQueryConsequentList {
+    syntax_list: SyntaxList,
+}
+impl CssAtMediaQueryConsequentList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for CssAtMediaQueryConsequentList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST }
+    fn cast(syntax: SyntaxNode) -> Option<CssAtMediaQueryConsequentList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(CssAtMediaQueryConsequentList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode { self.syntax_list.node() }
+    fn into_syntax(self) -> SyntaxNode { self.syntax_list.into_node() }
+}
+#[cfg(feature = "serde")]
+impl Serialize for CssAtMediaQueryConsequentList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for CssAtMediaQueryConsequentList {
+    type Language = Language;
+    type Node = CssAtMediaQueryConsequent;
+    fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
+    fn into_syntax_list(self) -> SyntaxList { self.syntax_list }
+}
+impl Debug for CssAtMediaQueryConsequentList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CssAtMediaQueryConsequentList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &CssAtMediaQueryConsequentList {
+    type Item = CssAtMediaQueryConsequent;
+    type IntoIter = AstNodeListIterator<Language, CssAtMediaQueryConsequent>;
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+impl IntoIterator for CssAtMediaQueryConsequentList {
+    type Item = CssAtMediaQueryConsequent;
+    type IntoIter = AstNodeListIterator<Language, CssAtMediaQueryConsequent>;
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
 pub struct CssAtMediaQueryList {
     syntax_list: SyntaxList,
 }
@@ -4620,10 +6640,10 @@ impl IntoIterator for &CssAtMediaQueryList {
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssAttributeList {
+pub struct CssBlockItemList {
     syntax_list: SyntaxList,
 }
-impl CssAttributeList {
+impl CssBlockItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -4636,14 +6656,14 @@ impl CssAttributeList {
         }
     }
 }
-impl AstNode for CssAttributeList {
+impl AstNode for CssBlockItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_LIST as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_ATTRIBUTE_LIST }
-    fn cast(syntax: SyntaxNode) -> Option<CssAttributeList> {
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BLOCK_ITEM_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_BLOCK_ITEM_LIST }
+    fn cast(syntax: SyntaxNode) -> Option<CssBlockItemList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssAttributeList {
+            Some(CssBlockItemList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -4654,7 +6674,7 @@ impl AstNode for CssAttributeList {
     fn into_syntax(self) -> SyntaxNode { self.syntax_list.into_node() }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssAttributeList {
+impl Serialize for CssBlockItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -4666,33 +6686,33 @@ impl Serialize for CssAttributeList {
         seq.end()
     }
 }
-impl AstNodeList for CssAttributeList {
+impl AstNodeList for CssBlockItemList {
     type Language = Language;
-    type Node = CssAttribute;
+    type Node = AnyCssBlockItem;
     fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
     fn into_syntax_list(self) -> SyntaxList { self.syntax_list }
 }
-impl Debug for CssAttributeList {
+impl Debug for CssBlockItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssAttributeList ")?;
+        f.write_str("CssBlockItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssAttributeList {
-    type Item = CssAttribute;
-    type IntoIter = AstNodeListIterator<Language, CssAttribute>;
+impl IntoIterator for &CssBlockItemList {
+    type Item = AnyCssBlockItem;
+    type IntoIter = AstNodeListIterator<Language, AnyCssBlockItem>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
-impl IntoIterator for CssAttributeList {
-    type Item = CssAttribute;
-    type IntoIter = AstNodeListIterator<Language, CssAttribute>;
+impl IntoIterator for CssBlockItemList {
+    type Item = AnyCssBlockItem;
+    type IntoIter = AstNodeListIterator<Language, AnyCssBlockItem>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct CssDeclarationList {
+pub struct CssComponentValueList {
     syntax_list: SyntaxList,
 }
-impl CssDeclarationList {
+impl CssComponentValueList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -4705,14 +6725,14 @@ impl CssDeclarationList {
         }
     }
 }
-impl AstNode for CssDeclarationList {
+impl AstNode for CssComponentValueList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_LIST as u16));
-    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_DECLARATION_LIST }
-    fn cast(syntax: SyntaxNode) -> Option<CssDeclarationList> {
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPONENT_VALUE_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool { kind == CSS_COMPONENT_VALUE_LIST }
+    fn cast(syntax: SyntaxNode) -> Option<CssComponentValueList> {
         if Self::can_cast(syntax.kind()) {
-            Some(CssDeclarationList {
+            Some(CssComponentValueList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -4723,7 +6743,7 @@ impl AstNode for CssDeclarationList {
     fn into_syntax(self) -> SyntaxNode { self.syntax_list.into_node() }
 }
 #[cfg(feature = "serde")]
-impl Serialize for CssDeclarationList {
+impl Serialize for CssComponentValueList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -4735,26 +6755,26 @@ impl Serialize for CssDeclarationList {
         seq.end()
     }
 }
-impl AstNodeList for CssDeclarationList {
+impl AstNodeList for CssComponentValueList {
     type Language = Language;
-    type Node = CssDeclaration;
+    type Node = AnyCssValue;
     fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
     fn into_syntax_list(self) -> SyntaxList { self.syntax_list }
 }
-impl Debug for CssDeclarationList {
+impl Debug for CssComponentValueList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("CssDeclarationList ")?;
+        f.write_str("CssComponentValueList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &CssDeclarationList {
-    type Item = CssDeclaration;
-    type IntoIter = AstNodeListIterator<Language, CssDeclaration>;
+impl IntoIterator for &CssComponentValueList {
+    type Item = AnyCssValue;
+    type IntoIter = AstNodeListIterator<Language, AnyCssValue>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
-impl IntoIterator for CssDeclarationList {
-    type Item = CssDeclaration;
-    type IntoIter = AstNodeListIterator<Language, CssDeclaration>;
+impl IntoIterator for CssComponentValueList {
+    type Item = AnyCssValue;
+    type IntoIter = AstNodeListIterator<Language, AnyCssValue>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -4806,7 +6826,7 @@ impl Serialize for CssKeyframesSelectorList {
 }
 impl AstSeparatedList for CssKeyframesSelectorList {
     type Language = Language;
-    type Node = CssKeyframesSelector;
+    type Node = AnyCssKeyframesSelector;
     fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
     fn into_syntax_list(self) -> SyntaxList { self.syntax_list }
 }
@@ -4817,13 +6837,13 @@ impl Debug for CssKeyframesSelectorList {
     }
 }
 impl IntoIterator for CssKeyframesSelectorList {
-    type Item = SyntaxResult<CssKeyframesSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssKeyframesSelector>;
+    type Item = SyntaxResult<AnyCssKeyframesSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssKeyframesSelector>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 impl IntoIterator for &CssKeyframesSelectorList {
-    type Item = SyntaxResult<CssKeyframesSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssKeyframesSelector>;
+    type Item = SyntaxResult<AnyCssKeyframesSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssKeyframesSelector>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -4873,7 +6893,7 @@ impl Serialize for CssParameterList {
         seq.end()
     }
 }
-impl AstNodeList for CssParameterList {
+impl AstSeparatedList for CssParameterList {
     type Language = Language;
     type Node = CssParameter;
     fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
@@ -4882,17 +6902,17 @@ impl AstNodeList for CssParameterList {
 impl Debug for CssParameterList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str("CssParameterList ")?;
-        f.debug_list().entries(self.iter()).finish()
+        f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for &CssParameterList {
-    type Item = CssParameter;
-    type IntoIter = AstNodeListIterator<Language, CssParameter>;
+impl IntoIterator for CssParameterList {
+    type Item = SyntaxResult<CssParameter>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssParameter>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
-impl IntoIterator for CssParameterList {
-    type Item = CssParameter;
-    type IntoIter = AstNodeListIterator<Language, CssParameter>;
+impl IntoIterator for &CssParameterList {
+    type Item = SyntaxResult<CssParameter>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, CssParameter>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -5013,7 +7033,7 @@ impl Serialize for CssSelectorList {
 }
 impl AstSeparatedList for CssSelectorList {
     type Language = Language;
-    type Node = CssSelector;
+    type Node = AnyCssSelector;
     fn syntax_list(&self) -> &SyntaxList { &self.syntax_list }
     fn into_syntax_list(self) -> SyntaxList { self.syntax_list }
 }
@@ -5024,13 +7044,13 @@ impl Debug for CssSelectorList {
     }
 }
 impl IntoIterator for CssSelectorList {
-    type Item = SyntaxResult<CssSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssSelector>;
+    type Item = SyntaxResult<AnyCssSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssSelector>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 impl IntoIterator for &CssSelectorList {
-    type Item = SyntaxResult<CssSelector>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, CssSelector>;
+    type Item = SyntaxResult<AnyCssSelector>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyCssSelector>;
     fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 #[derive(Clone)]
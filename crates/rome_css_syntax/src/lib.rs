@@ -1,8 +1,10 @@
 #[macro_use]
 mod generated;
+mod file_source;
 mod syntax_node;
 
 pub use self::generated::*;
+pub use file_source::CssFileSource;
 pub use rome_rowan::{
     SyntaxNodeText, TextLen, TextRange, TextSize, TokenAtOffset, TriviaPieceKind, WalkEvent,
 };
@@ -28,20 +30,26 @@ impl CssSyntaxKind {
     pub fn is_trivia(self) -> bool {
         matches!(
             self,
-            CssSyntaxKind::NEWLINE | CssSyntaxKind::WHITESPACE | CssSyntaxKind::COMMENT
+            CssSyntaxKind::NEWLINE
+                | CssSyntaxKind::WHITESPACE
+                | CssSyntaxKind::COMMENT
+                | CssSyntaxKind::MULTILINE_COMMENT
         )
     }
 
     /// Returns `true` for any contextual (await) or non-contextual keyword
     #[inline]
     pub const fn is_keyword(self) -> bool {
-        true
+        (self as u16) >= (ALICEBLUE_KW as u16) && (self as u16) <= (IMPORT_KW as u16)
     }
 
     /// Returns `true` for contextual keywords (excluding strict mode contextual keywords)
+    ///
+    /// All CSS keywords are contextual: they are valid identifiers outside of the position where
+    /// they have a special meaning.
     #[inline]
     pub const fn is_contextual_keyword(self) -> bool {
-        (self as u16) >= (ALICEBLUE_KW as u16) && (self as u16) <= (CSS_SELECTOR as u16)
+        self.is_keyword()
     }
 
     /// Returns true for all non-contextual keywords (includes future reserved keywords)
@@ -56,11 +64,43 @@ impl rome_rowan::SyntaxKind for CssSyntaxKind {
     const EOF: Self = EOF;
 
     fn is_bogus(&self) -> bool {
-        matches!(self, CSS_BOGUS)
+        matches!(
+            self,
+            CSS_BOGUS | CSS_BOGUS_RULE | CSS_BOGUS_SELECTOR | CSS_BOGUS_VALUE
+        )
     }
 
     fn to_bogus(&self) -> Self {
-        todo!()
+        match self {
+            CSS_RULE
+            | CSS_DECLARATION
+            | CSS_EMPTY_DECLARATION
+            | CSS_AT_CHARSET
+            | CSS_AT_IMPORT
+            | CSS_AT_KEYFRAMES
+            | CSS_AT_MEDIA
+            | CSS_AT_GENERIC
+            | CSS_BOGUS_RULE => CSS_BOGUS_RULE,
+            CSS_SELECTOR | CSS_COMPLEX_SELECTOR | CSS_RELATIVE_SELECTOR | CSS_BOGUS_SELECTOR => {
+                CSS_BOGUS_SELECTOR
+            }
+            CSS_IDENTIFIER
+            | CSS_STRING
+            | CSS_NUMBER
+            | CSS_DIMENSION
+            | CSS_PERCENTAGE
+            | CSS_RATIO
+            | CSS_COLOR
+            | CSS_CUSTOM_PROPERTY
+            | CSS_SIMPLE_FUNCTION
+            | CSS_VAR_FUNCTION
+            | CSS_URL_FUNCTION
+            | CSS_PARENTHESIZED_VALUE
+            | CSS_BRACKETED_VALUE
+            | CSS_GENERIC_DELIMITER
+            | CSS_BOGUS_VALUE => CSS_BOGUS_VALUE,
+            _ => CSS_BOGUS,
+        }
     }
 
     #[inline]
@@ -86,16 +86,21 @@ define_categories! {
     "lint/nursery/noConfusingArrow": "https://docs.rome.tools/lint/rules/noConfusingArrow",
     "lint/nursery/noConstantCondition": "https://docs.rome.tools/lint/rules/noConstantCondition",
     "lint/nursery/noControlCharactersInRegex": "https://docs.rome.tools/lint/rules/noControlCharactersInRegex",
+    "lint/nursery/noDuplicateCssProperties": "https://docs.rome.tools/lint/rules/noDuplicateCssProperties",
     "lint/nursery/noDuplicateJsonKeys": "https://docs.rome.tools/lint/rules/noDuplicateJsonKeys",
     "lint/nursery/noExcessiveComplexity": "https://docs.rome.tools/lint/rules/noExcessiveComplexity",
     "lint/nursery/noFallthroughSwitchClause": "https://docs.rome.tools/lint/rules/noFallthroughSwitchClause",
     "lint/nursery/noGlobalIsFinite": "https://docs.rome.tools/lint/rules/noGlobalIsFinite",
     "lint/nursery/noGlobalIsNan": "https://docs.rome.tools/lint/rules/noGlobalIsNan",
+    "lint/nursery/noInvalidPackageJsonFields": "https://docs.rome.tools/lint/rules/noInvalidPackageJsonFields",
+    "lint/nursery/noJsonComments": "https://docs.rome.tools/lint/rules/noJsonComments",
     "lint/nursery/noNoninteractiveTabindex": "https://docs.rome.tools/lint/rules/noNoninteractiveTabindex",
     "lint/nursery/noNonoctalDecimalEscape": "https://docs.rome.tools/lint/rules/noNonoctalDecimalEscape",
     "lint/nursery/noRedundantRoles": "https://docs.rome.tools/lint/rules/noRedundantRoles",
     "lint/nursery/noSelfAssign": "https://docs.rome.tools/lint/rules/noSelfAssign",
     "lint/nursery/noStaticOnlyClass": "https://docs.rome.tools/lint/rules/noStaticOnlyClass",
+    "lint/nursery/noUnknownCompilerOptions": "https://docs.rome.tools/lint/rules/noUnknownCompilerOptions",
+    "lint/nursery/noUnknownCssUnits": "https://docs.rome.tools/lint/rules/noUnknownCssUnits",
     "lint/nursery/noUnsafeDeclarationMerging": "https://docs.rome.tools/lint/rules/noUnsafeDeclarationMerging",
     "lint/nursery/noUselessEmptyExport": "https://docs.rome.tools/lint/rules/noUselessEmptyExport",
     "lint/nursery/noVoid": "https://docs.rome.tools/lint/rules/noVoid",
@@ -108,6 +113,7 @@ define_categories! {
     "lint/nursery/useIsArray": "https://docs.rome.tools/lint/rules/useIsArray",
     "lint/nursery/useLiteralEnumMembers": "https://docs.rome.tools/lint/rules/useLiteralEnumMembers",
     "lint/nursery/useNamingConvention": "https://docs.rome.tools/lint/rules/useNamingConvention",
+    "lint/nursery/useSortedJsonKeys": "https://docs.rome.tools/lint/rules/useSortedJsonKeys",
     // nursery end
 
     // performance
@@ -191,6 +197,7 @@ define_categories! {
     "organizeImports",
     "migrate",
     "deserialize",
+    "jsonSchema",
     "internalError/io",
     "internalError/fs",
     "internalError/panic",
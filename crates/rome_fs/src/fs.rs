@@ -36,6 +36,11 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// Checks if the given path exists in the file system
     fn path_exists(&self, path: &Path) -> bool;
 
+    /// Creates a directory and all of its missing parents
+    ///
+    /// Equivalent to [std::fs::create_dir_all]
+    fn create_directory_all(&self, path: &Path) -> io::Result<()>;
+
     /// Method that takes a path to a folder `file_path`, and a `file_name`. It attempts to find
     /// and read the file from that folder and if not found, it reads the parent directories recursively
     /// until:
@@ -283,6 +288,10 @@ where
     fn path_exists(&self, path: &Path) -> bool {
         T::path_exists(self, path)
     }
+
+    fn create_directory_all(&self, path: &Path) -> io::Result<()> {
+        T::create_directory_all(self, path)
+    }
 }
 
 #[derive(Debug, Diagnostic, Deserialize, Serialize)]
@@ -166,6 +166,18 @@ impl FileSystem for MemoryFileSystem {
         let files = self.files.0.read();
         files.get(path).is_some()
     }
+
+    fn create_directory_all(&self, _path: &Path) -> io::Result<()> {
+        // The directories aren't stored, creating a file creates the directories of its path
+        if self.allow_write {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "cannot create a directory in read-only filesystem",
+            ))
+        }
+    }
 }
 
 struct MemoryFile {
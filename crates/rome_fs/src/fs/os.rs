@@ -47,6 +47,10 @@ impl FileSystem for OsFileSystem {
     fn path_exists(&self, path: &Path) -> bool {
         path.exists()
     }
+
+    fn create_directory_all(&self, path: &Path) -> io::Result<()> {
+        fs::create_dir_all(path)
+    }
 }
 
 struct OsFile {
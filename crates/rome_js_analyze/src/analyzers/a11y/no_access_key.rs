@@ -1,5 +1,7 @@
 use crate::JsRuleAction;
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_syntax::{jsx_ext::AnyJsxElement, JsxAttribute, JsxAttributeList};
@@ -37,6 +39,7 @@ declare_rule! {
     pub(crate) NoAccessKey {
         version: "11.0.0",
         name: "noAccessKey",
+        fix_kind: FixKind::Unsafe,
         recommended: false,
     }
 }
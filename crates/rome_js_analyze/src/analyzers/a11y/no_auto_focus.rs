@@ -1,5 +1,7 @@
 use crate::JsRuleAction;
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_syntax::{jsx_ext::AnyJsxElement, JsxAttribute};
@@ -57,6 +59,7 @@ declare_rule! {
     pub(crate) NoAutoFocus {
         version: "10.0.0",
         name: "noAutofocus",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
@@ -1,6 +1,6 @@
 use crate::JsRuleAction;
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make::{
@@ -51,6 +51,7 @@ declare_rule! {
     pub(crate) NoBlankTarget {
         version: "10.0.0",
         name: "noBlankTarget",
+        fix_kind: FixKind::Safe,
         recommended: true,
     }
 }
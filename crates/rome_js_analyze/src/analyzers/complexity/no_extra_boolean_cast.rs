@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_syntax::{
@@ -57,6 +59,7 @@ declare_rule! {
     pub(crate) NoExtraBooleanCast {
         version: "0.9.0",
         name: "noExtraBooleanCast",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_syntax::{JsRegexLiteralExpression, JsSyntaxKind, JsSyntaxToken, TextRange, TextSize};
@@ -58,6 +60,7 @@ declare_rule! {
     pub(crate) NoMultipleSpacesInRegularExpressionLiterals {
         version: "0.7.0",
         name: "noMultipleSpacesInRegularExpressionLiterals",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
@@ -1,5 +1,5 @@
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -61,6 +61,7 @@ declare_rule! {
     pub(crate) NoUselessRename {
         version: "12.0.0",
         name: "noUselessRename",
+        fix_kind: FixKind::Safe,
         recommended: true,
     }
 }
@@ -1,5 +1,5 @@
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_syntax::{JsCaseClause, JsDefaultClause};
@@ -62,6 +62,7 @@ declare_rule! {
     pub(crate) NoUselessSwitchCase {
         version: "12.0.0",
         name: "noUselessSwitchCase",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
@@ -1,5 +1,5 @@
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 
 use rome_diagnostics::Applicability;
@@ -75,6 +75,7 @@ declare_rule! {
     pub(crate) NoUselessTypeConstraint {
         version: "next",
         name: "noUselessTypeConstraint",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
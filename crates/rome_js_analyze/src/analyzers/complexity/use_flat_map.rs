@@ -1,6 +1,6 @@
 use crate::JsRuleAction;
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make::{ident, js_name};
@@ -35,6 +35,7 @@ declare_rule! {
     pub(crate) UseFlatMap {
         version: "10.0.0",
         name: "useFlatMap",
+        fix_kind: FixKind::Safe,
         recommended: true,
     }
 }
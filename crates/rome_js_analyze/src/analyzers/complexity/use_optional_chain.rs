@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -73,6 +75,7 @@ declare_rule! {
     pub(crate) UseOptionalChain {
         version: "0.10.0",
         name: "useOptionalChain",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
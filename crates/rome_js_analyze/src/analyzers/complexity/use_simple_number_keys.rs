@@ -1,5 +1,7 @@
 use crate::JsRuleAction;
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -44,6 +46,7 @@ declare_rule! {
     pub(crate) UseSimpleNumberKeys {
         version: "12.1.0",
         name: "useSimpleNumberKeys",
+        fix_kind: FixKind::Safe,
         recommended: false,
     }
 }
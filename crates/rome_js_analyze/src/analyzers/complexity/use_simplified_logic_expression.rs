@@ -1,5 +1,7 @@
 use crate::JsRuleAction;
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -49,6 +51,7 @@ declare_rule! {
     pub(crate) UseSimplifiedLogicExpression {
         version: "0.7.0",
         name: "useSimplifiedLogicExpression",
+        fix_kind: FixKind::Unsafe,
         recommended: false,
     }
 }
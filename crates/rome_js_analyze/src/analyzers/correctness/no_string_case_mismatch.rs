@@ -1,5 +1,5 @@
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -34,6 +34,7 @@ declare_rule! {
     pub(crate) NoStringCaseMismatch {
         version: "11.0.0",
         name: "noStringCaseMismatch",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
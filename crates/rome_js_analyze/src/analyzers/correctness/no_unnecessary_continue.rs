@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_syntax::{JsContinueStatement, JsLabeledStatement, JsSyntaxKind, JsSyntaxNode};
@@ -73,6 +75,7 @@ declare_rule! {
         version: "0.7.0",
         name: "noUnnecessaryContinue",
         recommended: true,
+        fix_kind: FixKind::Unsafe,
     }
 
 }
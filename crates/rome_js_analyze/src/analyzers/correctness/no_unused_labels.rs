@@ -1,7 +1,7 @@
 use rome_analyze::context::RuleContext;
 use rome_analyze::{
-    declare_rule, ActionCategory, AddVisitor, Phases, QueryMatch, Queryable, Rule, RuleDiagnostic,
-    ServiceBag, Visitor, VisitorContext,
+    declare_rule, ActionCategory, AddVisitor, FixKind, Phases, QueryMatch, Queryable, Rule,
+    RuleDiagnostic, ServiceBag, Visitor, VisitorContext,
 };
 use rome_console::markup;
 use rome_diagnostics::Applicability;
@@ -49,6 +49,7 @@ declare_rule! {
     pub(crate) NoUnusedLabels {
         version: "12.0.0",
         name: "noUnusedLabels",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
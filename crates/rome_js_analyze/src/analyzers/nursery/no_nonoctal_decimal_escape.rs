@@ -1,5 +1,7 @@
 use crate::JsRuleAction;
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -54,6 +56,7 @@ declare_rule! {
     pub(crate) NoNonoctalDecimalEscape {
         version: "next",
         name: "noNonoctalDecimalEscape",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_syntax::{AnyJsModuleItem, JsExport, JsModuleItemList, JsSyntaxToken};
@@ -44,6 +46,7 @@ declare_rule! {
     pub(crate) NoUselessEmptyExport {
         version: "next",
         name: "noUselessEmptyExport",
+        fix_kind: FixKind::Safe,
         recommended: true,
     }
 }
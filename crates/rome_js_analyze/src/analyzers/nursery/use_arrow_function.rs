@@ -1,7 +1,7 @@
 use crate::JsRuleAction;
 use rome_analyze::{
-    context::RuleContext, declare_rule, ActionCategory, AddVisitor, Phases, QueryMatch, Queryable,
-    Rule, RuleDiagnostic, ServiceBag, Visitor, VisitorContext,
+    context::RuleContext, declare_rule, ActionCategory, AddVisitor, FixKind, Phases, QueryMatch,
+    Queryable, Rule, RuleDiagnostic, ServiceBag, Visitor, VisitorContext,
 };
 use rome_console::markup;
 use rome_diagnostics::Applicability;
@@ -69,6 +69,7 @@ declare_rule! {
     pub(crate) UseArrowFunction {
         version: "next",
         name: "useArrowFunction",
+        fix_kind: FixKind::Safe,
         recommended: true,
     }
 }
@@ -1,5 +1,7 @@
 use crate::JsRuleAction;
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -61,6 +63,7 @@ declare_rule! {
     pub(crate) UseGroupedTypeImport {
         version: "12.1.0",
         name: "useGroupedTypeImport",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -59,6 +61,7 @@ declare_rule! {
     pub(crate) NoDelete {
         version: "0.7.0",
         name: "noDelete",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
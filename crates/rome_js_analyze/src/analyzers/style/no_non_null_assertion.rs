@@ -1,5 +1,7 @@
 use crate::JsRuleAction;
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -45,6 +47,7 @@ declare_rule! {
     pub(crate) NoNonNullAssertion {
         version: "11.0.0",
         name: "noNonNullAssertion",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
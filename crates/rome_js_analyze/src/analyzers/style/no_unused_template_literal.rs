@@ -1,6 +1,6 @@
 use crate::JsRuleAction;
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -41,6 +41,7 @@ declare_rule! {
     pub(crate) NoUnusedTemplateLiteral {
         version: "0.7.0",
         name: "noUnusedTemplateLiteral",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
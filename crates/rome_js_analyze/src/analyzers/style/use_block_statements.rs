@@ -1,5 +1,5 @@
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleAction, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleAction, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -65,6 +65,7 @@ declare_rule! {
     pub(crate) UseBlockStatements {
         version: "0.7.0",
         name: "useBlockStatements",
+        fix_kind: FixKind::Unsafe,
         recommended: false,
     }
 }
@@ -1,6 +1,6 @@
 use crate::JsRuleAction;
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -72,6 +72,7 @@ declare_rule! {
     pub(crate) UseEnumInitializers {
         version: "11.0.0",
         name: "useEnumInitializers",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
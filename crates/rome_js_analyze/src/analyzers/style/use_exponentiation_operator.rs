@@ -1,7 +1,7 @@
 use crate::semantic_services::Semantic;
 use crate::JsRuleAction;
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::{make, syntax::T};
@@ -54,6 +54,7 @@ declare_rule! {
     pub(crate) UseExponentiationOperator {
         version: "11.0.0",
         name: "useExponentiationOperator",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
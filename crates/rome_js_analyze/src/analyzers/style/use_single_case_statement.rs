@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -38,6 +40,7 @@ declare_rule! {
     pub(crate) UseSingleCaseStatement {
         version: "0.7.0",
         name: "useSingleCaseStatement",
+        fix_kind: FixKind::Unsafe,
         recommended: false,
     }
 }
@@ -1,5 +1,7 @@
 use rome_analyze::RuleSuppressions;
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -48,6 +50,7 @@ declare_rule! {
     pub(crate) UseTemplate {
         version: "0.7.0",
         name: "useTemplate",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
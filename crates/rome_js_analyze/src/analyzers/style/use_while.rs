@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -23,6 +25,7 @@ declare_rule! {
     pub(crate) UseWhile {
         version: "0.7.0",
         name: "useWhile",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
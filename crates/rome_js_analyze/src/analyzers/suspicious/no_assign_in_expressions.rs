@@ -1,5 +1,5 @@
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -62,6 +62,7 @@ declare_rule! {
     pub(crate) NoAssignInExpressions {
         version: "12.0.0",
         name: "noAssignInExpressions",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -34,6 +36,7 @@ declare_rule! {
     pub(crate) NoCompareNegZero {
         version: "0.7.0",
         name: "noCompareNegZero",
+        fix_kind: FixKind::Safe,
         recommended: true,
     }
 }
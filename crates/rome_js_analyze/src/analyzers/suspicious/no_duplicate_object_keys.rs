@@ -1,6 +1,6 @@
 use crate::utils::batch::JsBatchMutation;
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, Ast, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_js_syntax::{
     AnyJsObjectMember, JsGetterObjectMember, JsObjectExpression, JsSetterObjectMember,
@@ -56,6 +56,7 @@ declare_rule! {
     pub(crate) NoDuplicateObjectKeys {
         version: "11.0.0",
         name: "noDuplicateObjectKeys",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
@@ -1,6 +1,6 @@
 use crate::JsRuleAction;
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::{
@@ -44,6 +44,7 @@ declare_rule! {
     pub(crate) NoEmptyInterface {
         version: "11.0.0",
         name: "noEmptyInterface",
+        fix_kind: FixKind::Safe,
         recommended: true,
     }
 }
@@ -1,5 +1,5 @@
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_syntax::{
@@ -50,6 +50,7 @@ declare_rule! {
     pub(crate) NoExtraNonNullAssertion {
         version: "11.0.0",
         name: "noExtraNonNullAssertion",
+        fix_kind: FixKind::Safe,
         recommended: true,
     }
 }
@@ -1,5 +1,7 @@
 use crate::JsRuleAction;
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_syntax::{
@@ -68,6 +70,7 @@ declare_rule! {
  pub(crate) NoRedundantUseStrict {
      version: "11.0.0",
      name: "noRedundantUseStrict",
+     fix_kind: FixKind::Safe,
      recommended: true,
     }
 }
@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -43,6 +45,7 @@ declare_rule! {
     pub(crate) UseNamespaceKeyword {
         version: "12.0.0",
         name: "useNamespaceKeyword",
+        fix_kind: FixKind::Safe,
         recommended: true,
     }
 }
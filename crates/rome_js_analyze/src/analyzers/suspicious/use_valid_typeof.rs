@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -71,6 +73,7 @@ declare_rule! {
     pub(crate) UseValidTypeof {
         version: "0.7.0",
         name: "useValidTypeof",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
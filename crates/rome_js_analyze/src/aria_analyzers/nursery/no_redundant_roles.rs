@@ -1,5 +1,7 @@
 use crate::{aria_services::Aria, JsRuleAction};
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
 use rome_aria::{roles::AriaRoleDefinition, AriaRoles};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
@@ -46,6 +48,7 @@ declare_rule! {
     pub(crate) NoRedundantRoles {
         version: "12.1.0",
         name: "noRedundantRoles",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, RefactorKind, Rule};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, RefactorKind, Rule,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -20,6 +22,7 @@ declare_rule! {
     pub(crate) FlipBinExp {
         version: "0.7.0",
         name: "flipBinExp",
+        fix_kind: FixKind::Safe,
         recommended: false,
     }
 }
@@ -1,4 +1,6 @@
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, RefactorKind, Rule};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, RefactorKind, Rule,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_semantic::{Reference, ReferencesExtensions};
@@ -22,6 +24,7 @@ declare_rule! {
     pub(crate) InlineVariable {
         version: "0.9.0",
         name: "inlineVariable",
+        fix_kind: FixKind::Safe,
         recommended: false,
     }
 }
@@ -7,7 +7,7 @@ use std::{
 };
 
 use rome_analyze::{
-    context::RuleContext, declare_rule, ActionCategory, Ast, Rule, SourceActionKind,
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, SourceActionKind,
 };
 use rome_console::markup;
 use rome_diagnostics::Applicability;
@@ -50,6 +50,7 @@ declare_rule! {
     pub(crate) OrganizeImports {
         version: "11.0.0",
         name: "organizeImports",
+        fix_kind: FixKind::Unsafe,
         recommended: false,
     }
 }
@@ -1,12 +1,12 @@
 use std::any::TypeId;
 
-use rome_analyze::{merge_node_visitors, Visitor, VisitorContext};
+use rome_analyze::{merge_node_visitors, Visitor, VisitorContext, VisitorFinishContext};
 use rome_js_syntax::{
     AnyJsFunction, JsConstructorClassMember, JsGetterClassMember, JsGetterObjectMember, JsLanguage,
     JsMethodClassMember, JsMethodObjectMember, JsModule, JsScript, JsSetterClassMember,
-    JsSetterObjectMember, JsStaticInitializationBlockClassMember,
+    JsSetterObjectMember, JsStaticInitializationBlockClassMember, JsSyntaxNode,
 };
-use rome_rowan::{declare_node_union, AstNode, SyntaxError, SyntaxResult};
+use rome_rowan::{declare_node_union, AstNode, SyntaxError, SyntaxResult, WalkEvent};
 
 use crate::ControlFlowGraph;
 
@@ -14,7 +14,23 @@ use super::{nodes::*, FunctionBuilder};
 
 /// Return a new instance of the [ControlFlowVisitor]
 pub(crate) fn make_visitor() -> impl Visitor<Language = JsLanguage> {
-    ControlFlowVisitor::new()
+    TracedVisitor(ControlFlowVisitor::new())
+}
+
+/// Runs the [ControlFlowVisitor] in a `control_flow` tracing span, to measure the time spent
+/// building the control flow graphs
+struct TracedVisitor(ControlFlowVisitor);
+
+impl Visitor for TracedVisitor {
+    type Language = JsLanguage;
+
+    fn visit(&mut self, event: &WalkEvent<JsSyntaxNode>, ctx: VisitorContext<JsLanguage>) {
+        tracing::trace_span!("control_flow").in_scope(|| self.0.visit(event, ctx));
+    }
+
+    fn finish(self: Box<Self>, ctx: VisitorFinishContext<JsLanguage>) {
+        tracing::trace_span!("control_flow").in_scope(|| Box::new(self.0).finish(ctx));
+    }
 }
 
 /// Wrapper macro for [merge_node_visitors], implements additional control
@@ -7,14 +7,14 @@ use crate::semantic_analyzers::nursery::use_exhaustive_dependencies::{
 use crate::semantic_analyzers::nursery::use_naming_convention::{
     naming_convention_options, NamingConventionOptions,
 };
-use crate::semantic_analyzers::style::no_restricted_globals::{
-    restricted_globals_options, RestrictedGlobalsOptions,
-};
+use crate::semantic_analyzers::style::no_restricted_globals::restricted_globals_options;
+pub use crate::semantic_analyzers::style::no_restricted_globals::RestrictedGlobalsOptions;
 use bpaf::Bpaf;
 use rome_analyze::options::RuleOptions;
 use rome_analyze::RuleKey;
 use rome_deserialize::json::VisitJsonNode;
 use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_json_analyze::options::JsonFilesOptions;
 use rome_json_syntax::{AnyJsonValue, JsonLanguage, JsonMemberName, JsonObjectValue};
 use rome_rowan::AstNode;
 #[cfg(feature = "schemars")]
@@ -34,6 +34,9 @@ pub enum PossibleOptions {
     NamingConvention(#[bpaf(external(naming_convention_options), hide)] NamingConventionOptions),
     /// Options for `noRestrictedGlobals` rule
     RestrictedGlobals(#[bpaf(external(restricted_globals_options), hide)] RestrictedGlobalsOptions),
+    /// Options for `useSortedJsonKeys` and `noJsonComments` rules
+    #[bpaf(skip)]
+    JsonFiles(JsonFilesOptions),
     /// No options available
     #[default]
     NoOptions,
@@ -48,6 +51,27 @@ impl FromStr for PossibleOptions {
 }
 
 impl PossibleOptions {
+    /// Returns the default options of the rule `rule_name`, or `None` if the rule doesn't have options
+    pub fn for_rule(rule_name: &str) -> Option<Self> {
+        let options = match rule_name {
+            "noExcessiveComplexity" => PossibleOptions::Complexity(ComplexityOptions::default()),
+            "useExhaustiveDependencies" | "useHookAtTopLevel" => {
+                PossibleOptions::Hooks(HooksOptions::default())
+            }
+            "useNamingConvention" => {
+                PossibleOptions::NamingConvention(NamingConventionOptions::default())
+            }
+            "noRestrictedGlobals" => {
+                PossibleOptions::RestrictedGlobals(RestrictedGlobalsOptions::default())
+            }
+            "useSortedJsonKeys" | "noJsonComments" => {
+                PossibleOptions::JsonFiles(JsonFilesOptions::default())
+            }
+            _ => return None,
+        };
+        Some(options)
+    }
+
     pub fn extract_option(&self, rule_key: &RuleKey) -> RuleOptions {
         match rule_key.rule_name() {
             "noExcessiveComplexity" => {
@@ -78,6 +102,13 @@ impl PossibleOptions {
                 };
                 RuleOptions::new(options)
             }
+            "useSortedJsonKeys" | "noJsonComments" => {
+                let options = match self {
+                    PossibleOptions::JsonFiles(options) => options.clone(),
+                    _ => JsonFilesOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
             // TODO: review error
             _ => panic!("This rule {:?} doesn't have options", rule_key),
         }
@@ -135,6 +166,15 @@ impl PossibleOptions {
                     *self = PossibleOptions::RestrictedGlobals(options);
                 }
 
+                "files" => {
+                    let mut options = match self {
+                        PossibleOptions::JsonFiles(options) => options.clone(),
+                        _ => JsonFilesOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::JsonFiles(options);
+                }
+
                 _ => (),
             }
         }
@@ -187,6 +227,15 @@ impl PossibleOptions {
                     ));
                 }
             }
+            "useSortedJsonKeys" | "noJsonComments" => {
+                if !matches!(key_name, "files") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        &["files"],
+                    ));
+                }
+            }
             _ => {}
         }
 
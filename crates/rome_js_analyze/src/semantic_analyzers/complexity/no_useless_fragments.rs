@@ -2,7 +2,7 @@ use crate::react::{jsx_member_name_is_react_fragment, jsx_reference_identifier_i
 use crate::semantic_services::Semantic;
 use crate::JsRuleAction;
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make::{ident, js_expression_statement, jsx_string, jsx_tag_expression};
@@ -44,6 +44,7 @@ declare_rule! {
     pub(crate) NoUselessFragments {
         version: "0.10.0",
         name: "noUselessFragments",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
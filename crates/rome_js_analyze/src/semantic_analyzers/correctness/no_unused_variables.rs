@@ -1,6 +1,8 @@
 use crate::JsRuleAction;
 use crate::{semantic_services::Semantic, utils::rename::RenameSymbolExtensions};
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_semantic::{ReferencesExtensions, SemanticScopeExtensions};
@@ -101,6 +103,7 @@ declare_rule! {
     pub(crate) NoUnusedVariables {
         version: "0.9.0",
         name: "noUnusedVariables",
+        fix_kind: FixKind::Unsafe,
         recommended: false,
     }
 }
@@ -2,7 +2,7 @@ use crate::react::{ReactApiCall, ReactCreateElementCall};
 use crate::semantic_services::Semantic;
 use crate::JsRuleAction;
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic};
 use rome_console::{markup, MarkupBuf};
 use rome_diagnostics::Applicability;
 use rome_js_factory::make::{jsx_attribute_list, jsx_self_closing_element};
@@ -33,6 +33,7 @@ declare_rule! {
     pub(crate) NoVoidElementsWithChildren {
         version: "0.10.0",
         name: "noVoidElementsWithChildren",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -93,6 +93,7 @@ declare_rule! {
     pub(crate) NoBannedTypes {
         version: "10.0.0",
         name: "noBannedTypes",
+        fix_kind: FixKind::Safe,
         recommended: true,
     }
 }
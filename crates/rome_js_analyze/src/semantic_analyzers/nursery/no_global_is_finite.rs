@@ -1,5 +1,7 @@
 use crate::{semantic_services::Semantic, JsRuleAction};
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -30,6 +32,7 @@ declare_rule! {
     pub(crate) NoGlobalIsFinite {
         version: "next",
         name: "noGlobalIsFinite",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
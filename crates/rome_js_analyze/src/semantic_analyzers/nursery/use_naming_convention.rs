@@ -8,7 +8,9 @@ use crate::{
     JsRuleAction,
 };
 use bpaf::Bpaf;
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_deserialize::{
     json::{has_only_known_keys, with_only_known_variants, VisitJsonNode},
@@ -270,6 +272,7 @@ declare_rule! {
     pub(crate)  UseNamingConvention {
         version: "next",
         name: "useNamingConvention",
+        fix_kind: FixKind::Safe,
         recommended: false,
     }
 }
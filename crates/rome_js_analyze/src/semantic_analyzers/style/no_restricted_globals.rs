@@ -72,7 +72,7 @@ pub struct RestrictedGlobalsOptions {
     /// A list of names that should trigger the rule
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide, argument::<String>("NUM"), many, optional)]
-    denied_globals: Option<Vec<String>>,
+    pub denied_globals: Option<Vec<String>>,
 }
 
 impl RestrictedGlobalsOptions {
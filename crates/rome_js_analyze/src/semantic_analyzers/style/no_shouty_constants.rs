@@ -1,5 +1,7 @@
 use crate::{semantic_services::Semantic, utils::batch::JsBatchMutation, JsRuleAction};
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make::{js_literal_member_name, js_property_object_member};
@@ -45,6 +47,7 @@ declare_rule! {
     pub(crate) NoShoutyConstants {
         version: "0.7.0",
         name: "noShoutyConstants",
+        fix_kind: FixKind::Unsafe,
         recommended: false,
     }
 }
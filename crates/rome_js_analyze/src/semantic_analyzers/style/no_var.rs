@@ -1,5 +1,7 @@
 use crate::{control_flow::AnyJsControlFlowRoot, semantic_services::Semantic, JsRuleAction};
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
@@ -35,6 +37,7 @@ declare_rule! {
     pub(crate) NoVar {
         version: "11.0.0",
         name: "noVar",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
@@ -1,5 +1,7 @@
 use crate::{semantic_services::Semantic, JsRuleAction};
-use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
 use rome_console::markup;
 
 use rome_diagnostics::Applicability;
@@ -57,6 +59,7 @@ declare_rule! {
     pub(crate) UseConst {
         version: "11.0.0",
         name: "useConst",
+        fix_kind: FixKind::Unsafe,
         recommended: true,
     }
 }
@@ -108,6 +108,8 @@ impl Visitor for SemanticModelBuilderVisitor {
         event: &WalkEvent<SyntaxNode<JsLanguage>>,
         _ctx: VisitorContext<JsLanguage>,
     ) {
+        let _span = tracing::trace_span!("semantic_model").entered();
+
         match event {
             WalkEvent::Enter(node) => {
                 self.builder.push_node(node);
@@ -124,7 +126,7 @@ impl Visitor for SemanticModelBuilderVisitor {
     }
 
     fn finish(self: Box<Self>, ctx: VisitorFinishContext<JsLanguage>) {
-        let model = self.builder.build();
+        let model = tracing::trace_span!("semantic_model").in_scope(|| self.builder.build());
         ctx.services.insert_service(model);
     }
 }
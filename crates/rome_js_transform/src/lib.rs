@@ -1,6 +1,7 @@
 mod declare_transformation;
 mod registry;
 mod transformers;
+mod utils;
 
 use crate::registry::visit_transformation_registry;
 use rome_analyze::{
@@ -1,4 +1,7 @@
 use crate::transformers::ts_enum::TsEnum;
+use crate::transformers::ts_namespace::TsNamespace;
+use crate::transformers::ts_parameter_properties::TsParameterProperties;
+use crate::transformers::ts_types::TsTypes;
 use rome_analyze::{GroupCategory, RegistryVisitor, RuleCategory, RuleGroup};
 use rome_js_syntax::JsLanguage;
 
@@ -21,6 +24,9 @@ impl RuleGroup for TransformationGroup {
 
     fn record_rules<V: RegistryVisitor<Self::Language> + ?Sized>(registry: &mut V) {
         registry.record_rule::<TsEnum>();
+        registry.record_rule::<TsNamespace>();
+        registry.record_rule::<TsParameterProperties>();
+        registry.record_rule::<TsTypes>();
     }
 }
 
@@ -1 +1,4 @@
 pub(crate) mod ts_enum;
+pub(crate) mod ts_namespace;
+pub(crate) mod ts_parameter_properties;
+pub(crate) mod ts_types;
@@ -1,3 +1,4 @@
+use crate::utils::replace_with_items;
 use crate::{declare_transformation, JsBatchMutation};
 use rome_analyze::context::RuleContext;
 use rome_analyze::{Ast, Rule};
@@ -5,18 +6,20 @@ use rome_js_factory::make::{
     ident, js_assignment_expression, js_call_argument_list, js_call_arguments, js_call_expression,
     js_computed_member_assignment, js_decorator_list, js_directive_list, js_expression_statement,
     js_formal_parameter, js_function_body, js_function_expression, js_identifier_assignment,
-    js_identifier_binding, js_identifier_expression, js_logical_expression, js_module_item_list,
+    js_identifier_binding, js_identifier_expression, js_logical_expression,
     js_number_literal_expression, js_object_expression, js_object_member_list, js_parameter_list,
     js_parameters, js_parenthesized_expression, js_reference_identifier, js_statement_list,
     js_string_literal, js_string_literal_expression, js_variable_declaration,
-    js_variable_declarator, js_variable_declarator_list, js_variable_statement, token,
+    js_variable_declaration_clause, js_variable_declarator, js_variable_declarator_list,
+    js_variable_statement, token,
 };
 use rome_js_syntax::{
     AnyJsAssignment, AnyJsAssignmentPattern, AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument,
-    AnyJsExpression, AnyJsFormalParameter, AnyJsLiteralExpression, AnyJsModuleItem, AnyJsParameter,
-    AnyJsStatement, JsAssignmentExpression, JsComputedMemberAssignment, JsExpressionStatement,
-    JsFunctionExpression, JsInitializerClause, JsLogicalExpression, JsModuleItemList,
-    JsStatementList, JsVariableStatement, TsEnumDeclaration, T,
+    AnyJsDeclarationClause, AnyJsExportClause, AnyJsExpression, AnyJsFormalParameter,
+    AnyJsLiteralExpression, AnyJsModuleItem, AnyJsParameter, AnyJsStatement,
+    JsAssignmentExpression, JsComputedMemberAssignment, JsExport, JsExpressionStatement,
+    JsFunctionExpression, JsInitializerClause, JsLogicalExpression, JsStatementList,
+    JsVariableStatement, TsEnumDeclaration, T,
 };
 use rome_rowan::{AstNode, BatchMutationExt, TriviaPieceKind};
 
@@ -58,19 +61,31 @@ impl Rule for TsEnum {
     fn transform(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsBatchMutation> {
         let node = ctx.query();
         let mut mutation = node.clone().begin();
-        let parent = node.syntax().parent();
-        if let Some(parent) = parent {
-            if let Some(module_list) = JsModuleItemList::cast(parent) {
-                let variable = make_variable(state);
-                let function = make_function_caller(state);
-                let statements = vec![
-                    AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsVariableStatement(variable)),
-                    AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsExpressionStatement(
-                        function,
-                    )),
-                ];
-                let new_modules_list = js_module_item_list(statements.into_iter());
-                mutation.replace_node(module_list, new_modules_list);
+        let variable = make_variable(state);
+        let function = AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsExpressionStatement(
+            make_function_caller(state),
+        ));
+
+        match node.parent::<JsExport>() {
+            // export enum Foo {}
+            Some(export) => {
+                let variable = AnyJsModuleItem::JsExport(
+                    export
+                        .clone()
+                        .with_export_clause(AnyJsExportClause::AnyJsDeclarationClause(
+                            AnyJsDeclarationClause::JsVariableDeclarationClause(
+                                js_variable_declaration_clause(variable.declaration().ok()?)
+                                    .with_semicolon_token(token(T![;]))
+                                    .build(),
+                            ),
+                        )),
+                );
+                replace_with_items(&mut mutation, export.syntax(), vec![variable, function])?;
+            }
+            None => {
+                let variable =
+                    AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsVariableStatement(variable));
+                replace_with_items(&mut mutation, node.syntax(), vec![variable, function])?;
             }
         }
 
@@ -0,0 +1,375 @@
+use crate::utils::{indentation, replace_with_items, with_leading_trivia};
+use crate::{declare_transformation, JsBatchMutation};
+use rome_analyze::context::RuleContext;
+use rome_analyze::{Ast, Rule};
+use rome_js_factory::make::{
+    ident, js_assignment_expression, js_call_argument_list, js_call_arguments, js_call_expression,
+    js_decorator_list, js_directive_list, js_expression_statement, js_formal_parameter,
+    js_function_body, js_function_expression, js_identifier_assignment, js_identifier_binding,
+    js_identifier_expression, js_logical_expression, js_name, js_object_expression,
+    js_object_member_list, js_parameter_list, js_parameters, js_parenthesized_expression,
+    js_reference_identifier, js_statement_list, js_static_member_assignment,
+    js_variable_declaration, js_variable_declaration_clause, js_variable_declarator,
+    js_variable_declarator_list, js_variable_statement, token,
+};
+use rome_js_syntax::{
+    AnyJsAssignment, AnyJsAssignmentPattern, AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument,
+    AnyJsDeclarationClause, AnyJsExportClause, AnyJsExpression, AnyJsFormalParameter,
+    AnyJsModuleItem, AnyJsName, AnyJsParameter, AnyJsStatement, AnyTsModuleName, JsExport,
+    JsExpressionStatement, JsFunctionExpression, JsSyntaxNode, JsVariableDeclaration,
+    TsModuleDeclaration, T,
+};
+use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, TriviaPieceKind};
+
+declare_transformation! {
+    /// Transform a TypeScript namespace, or a module declared with an identifier
+    ///
+    /// ```ts
+    /// namespace Foo {
+    ///     export const bar = 1;
+    /// }
+    /// ```
+    ///
+    /// Into a function that adds the exported bindings to an object:
+    ///
+    /// ```js
+    /// var Foo;
+    /// (function (Foo) {
+    ///     const bar = 1;
+    ///     Foo.bar = bar;
+    /// })(Foo || (Foo = {}));
+    /// ```
+    ///
+    /// The exported bindings are copied to the object when they are declared, so later
+    /// assignments to them aren't visible from the outside.
+    pub(crate) TsNamespace {
+        version: "next",
+        name: "transformNamespace",
+    }
+}
+
+#[derive(Debug)]
+pub struct TsNamespaceMembers {
+    name: String,
+    /// The statements of the namespace, with the names of the bindings they export
+    statements: Vec<(AnyJsStatement, Vec<String>)>,
+}
+
+impl Rule for TsNamespace {
+    type Query = Ast<TsModuleDeclaration>;
+    type State = TsNamespaceMembers;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        // Nested names like `namespace Foo.Bar {}` aren't supported
+        let AnyTsModuleName::TsIdentifierBinding(binding) = node.name().ok()? else {
+            return None;
+        };
+        let name = binding.name_token().ok()?.text_trimmed().to_string();
+
+        let mut statements = vec![];
+        for item in node.body().ok()?.items() {
+            let statement = match item {
+                AnyJsModuleItem::AnyJsStatement(statement) => (statement, vec![]),
+                AnyJsModuleItem::JsExport(export) => exported_statement(&export)?,
+                AnyJsModuleItem::JsImport(_) => return None,
+            };
+            statements.push(statement);
+        }
+
+        Some(TsNamespaceMembers { name, statements })
+    }
+
+    fn transform(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsBatchMutation> {
+        let node = ctx.query();
+        let mut mutation = node.clone().begin();
+        let function = AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsExpressionStatement(
+            make_function_caller(state, node)?,
+        ));
+
+        match node.parent::<JsExport>() {
+            // export namespace Foo {}
+            Some(export) => {
+                let variable = AnyJsModuleItem::JsExport(
+                    export
+                        .clone()
+                        .with_export_clause(AnyJsExportClause::AnyJsDeclarationClause(
+                            AnyJsDeclarationClause::JsVariableDeclarationClause(
+                                js_variable_declaration_clause(make_variable_declaration(state))
+                                    .with_semicolon_token(token(T![;]))
+                                    .build(),
+                            ),
+                        )),
+                );
+                replace_with_items(&mut mutation, export.syntax(), vec![variable, function])?;
+            }
+            None => {
+                let mut items = vec![];
+                // The namespace can extend a class or a function with the same name,
+                // which already declare the variable
+                if !is_declared_before(node.syntax(), &state.name) {
+                    items.push(AnyJsModuleItem::AnyJsStatement(
+                        AnyJsStatement::JsVariableStatement(
+                            js_variable_statement(make_variable_declaration(state))
+                                .with_semicolon_token(token(T![;]))
+                                .build(),
+                        ),
+                    ));
+                }
+                items.push(function);
+                replace_with_items(&mut mutation, node.syntax(), items)?;
+            }
+        }
+
+        Some(mutation)
+    }
+}
+
+/// Returns the statement declared by `export`, and the names of the bindings it exports
+fn exported_statement(export: &JsExport) -> Option<(AnyJsStatement, Vec<String>)> {
+    let AnyJsExportClause::AnyJsDeclarationClause(clause) = export.export_clause().ok()? else {
+        return None;
+    };
+    let (statement, names) = match clause {
+        AnyJsDeclarationClause::JsClassDeclaration(declaration) => {
+            let name = binding_name(&declaration.id().ok()?)?;
+            (AnyJsStatement::JsClassDeclaration(declaration), vec![name])
+        }
+        AnyJsDeclarationClause::JsFunctionDeclaration(declaration) => {
+            let name = binding_name(&declaration.id().ok()?)?;
+            (
+                AnyJsStatement::JsFunctionDeclaration(declaration),
+                vec![name],
+            )
+        }
+        AnyJsDeclarationClause::TsEnumDeclaration(declaration) => {
+            let name = declaration.id().ok()?.text();
+            (AnyJsStatement::TsEnumDeclaration(declaration), vec![name])
+        }
+        AnyJsDeclarationClause::TsModuleDeclaration(declaration) => {
+            let AnyTsModuleName::TsIdentifierBinding(binding) = declaration.name().ok()? else {
+                return None;
+            };
+            let name = binding.name_token().ok()?.text_trimmed().to_string();
+            (AnyJsStatement::TsModuleDeclaration(declaration), vec![name])
+        }
+        AnyJsDeclarationClause::JsVariableDeclarationClause(clause) => {
+            let declaration = clause.declaration().ok()?;
+            let names = declaration
+                .declarators()
+                .iter()
+                .map(|declarator| match declarator.ok()?.id().ok()? {
+                    AnyJsBindingPattern::AnyJsBinding(binding) => binding_name(&binding),
+                    // Destructured bindings aren't supported
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let mut statement = js_variable_statement(declaration);
+            if let Some(semicolon) = clause.semicolon_token() {
+                statement = statement.with_semicolon_token(semicolon);
+            }
+            (
+                AnyJsStatement::JsVariableStatement(statement.build()),
+                names,
+            )
+        }
+        // Types don't export any value
+        AnyJsDeclarationClause::TsInterfaceDeclaration(declaration) => {
+            (AnyJsStatement::TsInterfaceDeclaration(declaration), vec![])
+        }
+        AnyJsDeclarationClause::TsTypeAliasDeclaration(declaration) => {
+            (AnyJsStatement::TsTypeAliasDeclaration(declaration), vec![])
+        }
+        AnyJsDeclarationClause::TsDeclareFunctionDeclaration(declaration) => (
+            AnyJsStatement::TsDeclareFunctionDeclaration(declaration),
+            vec![],
+        ),
+        AnyJsDeclarationClause::TsExternalModuleDeclaration(_)
+        | AnyJsDeclarationClause::TsGlobalDeclaration(_)
+        | AnyJsDeclarationClause::TsImportEqualsDeclaration(_) => return None,
+    };
+
+    // The declaration takes the place of the `export` keyword
+    let statement = statement
+        .with_leading_trivia_pieces(export.export_token().ok()?.leading_trivia().pieces())?;
+
+    Some((statement, names))
+}
+
+fn binding_name(binding: &AnyJsBinding) -> Option<String> {
+    let AnyJsBinding::JsIdentifierBinding(binding) = binding else {
+        return None;
+    };
+    Some(binding.name_token().ok()?.text_trimmed().to_string())
+}
+
+/// Returns `true` if a class or a function named `name` is declared before `node`,
+/// in the same list
+fn is_declared_before(node: &JsSyntaxNode, name: &str) -> bool {
+    node.siblings(rome_rowan::Direction::Prev)
+        .skip(1)
+        .filter_map(|sibling| match AnyJsModuleItem::cast(sibling)? {
+            AnyJsModuleItem::AnyJsStatement(statement) => Some(statement),
+            AnyJsModuleItem::JsExport(export) => exported_statement(&export).map(|(s, _)| s),
+            AnyJsModuleItem::JsImport(_) => None,
+        })
+        .any(|statement| {
+            let id = match statement {
+                AnyJsStatement::JsClassDeclaration(declaration) => declaration.id().ok(),
+                AnyJsStatement::JsFunctionDeclaration(declaration) => declaration.id().ok(),
+                _ => None,
+            };
+            id.and_then(|id| binding_name(&id))
+                .map_or(false, |id| id == name)
+        })
+}
+
+/// Makes
+/// ```js
+/// var Foo
+/// ```
+fn make_variable_declaration(state: &TsNamespaceMembers) -> JsVariableDeclaration {
+    let binding = js_variable_declarator(AnyJsBindingPattern::AnyJsBinding(
+        AnyJsBinding::JsIdentifierBinding(js_identifier_binding(ident(&state.name))),
+    ))
+    .build();
+
+    js_variable_declaration(
+        token(T![var]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        js_variable_declarator_list([binding], []),
+    )
+    .build()
+}
+
+/// Makes
+/// ```js
+/// (function (Foo) {})(Foo || (Foo = {}));
+/// ```
+fn make_function_caller(
+    state: &TsNamespaceMembers,
+    node: &TsModuleDeclaration,
+) -> Option<JsExpressionStatement> {
+    let callee = js_parenthesized_expression(
+        token(T!['(']),
+        AnyJsExpression::JsFunctionExpression(make_function(state, node)?),
+        token(T![')']),
+    );
+    let left = js_identifier_expression(js_reference_identifier(ident(&state.name)));
+    let assignment = js_assignment_expression(
+        AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsIdentifierAssignment(
+            js_identifier_assignment(ident(&state.name)),
+        )),
+        token(T![=]),
+        AnyJsExpression::JsObjectExpression(js_object_expression(
+            token(T!['{']),
+            js_object_member_list([], []),
+            token(T!['}']),
+        )),
+    );
+    let argument = js_logical_expression(
+        AnyJsExpression::JsIdentifierExpression(left),
+        token(T![||]),
+        AnyJsExpression::JsParenthesizedExpression(js_parenthesized_expression(
+            token(T!['(']),
+            AnyJsExpression::JsAssignmentExpression(assignment),
+            token(T![')']),
+        )),
+    );
+    let arguments = js_call_arguments(
+        token(T!['(']),
+        js_call_argument_list(
+            [AnyJsCallArgument::AnyJsExpression(
+                AnyJsExpression::JsLogicalExpression(argument),
+            )],
+            [],
+        ),
+        token(T![')']),
+    );
+    let expression = js_call_expression(
+        AnyJsExpression::JsParenthesizedExpression(callee),
+        arguments,
+    )
+    .build();
+
+    Some(
+        js_expression_statement(AnyJsExpression::JsCallExpression(expression))
+            .with_semicolon_token(token(T![;]))
+            .build(),
+    )
+}
+
+fn make_function(
+    state: &TsNamespaceMembers,
+    node: &TsModuleDeclaration,
+) -> Option<JsFunctionExpression> {
+    let parameters = js_parameters(
+        token(T!['(']),
+        js_parameter_list(
+            [AnyJsParameter::AnyJsFormalParameter(
+                AnyJsFormalParameter::JsFormalParameter(
+                    js_formal_parameter(
+                        js_decorator_list([]),
+                        AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(
+                            js_identifier_binding(ident(&state.name)),
+                        )),
+                    )
+                    .build(),
+                ),
+            )],
+            [],
+        ),
+        token(T![')']),
+    );
+
+    let mut statements = vec![];
+    for (statement, names) in &state.statements {
+        let indentation = indentation(statement.syntax().first_token()?);
+        statements.push(statement.clone());
+        for name in names {
+            statements.push(with_leading_trivia(
+                AnyJsStatement::JsExpressionStatement(make_export_assignment(&state.name, name)),
+                &indentation,
+            )?);
+        }
+    }
+
+    // The braces of the namespace keep the statements on their lines
+    let block = node.body().ok()?;
+    let body = js_function_body(
+        block.l_curly_token().ok()?,
+        js_directive_list([]),
+        js_statement_list(statements),
+        block.r_curly_token().ok()?,
+    );
+
+    Some(js_function_expression(token(T![function]), parameters, body).build())
+}
+
+/// Makes
+/// ```js
+/// Foo.bar = bar;
+/// ```
+fn make_export_assignment(namespace: &str, name: &str) -> JsExpressionStatement {
+    let left = js_static_member_assignment(
+        AnyJsExpression::JsIdentifierExpression(js_identifier_expression(js_reference_identifier(
+            ident(namespace),
+        ))),
+        token(T![.]),
+        AnyJsName::JsName(js_name(ident(name))),
+    );
+    let expression = js_assignment_expression(
+        AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsStaticMemberAssignment(left)),
+        token(T![=])
+            .with_leading_trivia([(TriviaPieceKind::Whitespace, " ")])
+            .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        AnyJsExpression::JsIdentifierExpression(js_identifier_expression(js_reference_identifier(
+            ident(name),
+        ))),
+    );
+
+    js_expression_statement(AnyJsExpression::JsAssignmentExpression(expression))
+        .with_semicolon_token(token(T![;]))
+        .build()
+}
@@ -0,0 +1,157 @@
+use crate::utils::{indentation, with_leading_trivia};
+use crate::{declare_transformation, JsBatchMutation};
+use rome_analyze::context::RuleContext;
+use rome_analyze::{Ast, Rule};
+use rome_js_factory::make::{
+    ident, js_assignment_expression, js_expression_statement, js_identifier_expression, js_name,
+    js_reference_identifier, js_statement_list, js_static_member_assignment, js_this_expression,
+    token,
+};
+use rome_js_syntax::{
+    AnyJsAssignment, AnyJsAssignmentPattern, AnyJsBinding, AnyJsBindingPattern,
+    AnyJsConstructorParameter, AnyJsExpression, AnyJsFormalParameter, AnyJsName, AnyJsStatement,
+    JsConstructorClassMember, JsExpressionStatement, TsPropertyParameter, T,
+};
+use rome_rowan::{AstNode, AstNodeList, BatchMutationExt, TriviaPieceKind};
+
+declare_transformation! {
+    /// Lower the TypeScript parameter properties of a constructor
+    ///
+    /// ```ts
+    /// class Foo {
+    ///     constructor(private bar: string) {}
+    /// }
+    /// ```
+    ///
+    /// Into parameters that are assigned to `this`, after the call to `super()` if there's one:
+    ///
+    /// ```js
+    /// class Foo {
+    ///     constructor(bar: string) {
+    ///         this.bar = bar;
+    ///     }
+    /// }
+    /// ```
+    pub(crate) TsParameterProperties {
+        version: "next",
+        name: "transformParameterProperties",
+    }
+}
+
+#[derive(Debug)]
+pub struct TsParameterPropertiesState {
+    properties: Vec<(TsPropertyParameter, AnyJsFormalParameter, String)>,
+}
+
+impl Rule for TsParameterProperties {
+    type Query = Ast<JsConstructorClassMember>;
+    type State = TsParameterPropertiesState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let mut properties = vec![];
+        for parameter in node.parameters().ok()?.parameters() {
+            if let AnyJsConstructorParameter::TsPropertyParameter(property) = parameter.ok()? {
+                // Parameter decorators don't exist in JavaScript, they can't be lowered
+                if !property.decorators().is_empty() {
+                    return None;
+                }
+                let formal_parameter = property.formal_parameter().ok()?;
+                let AnyJsFormalParameter::JsFormalParameter(parameter) = &formal_parameter else {
+                    return None;
+                };
+                let AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) =
+                    parameter.binding().ok()?
+                else {
+                    return None;
+                };
+                let name = binding.name_token().ok()?.text_trimmed().to_string();
+                properties.push((property, formal_parameter, name));
+            }
+        }
+
+        if properties.is_empty() {
+            None
+        } else {
+            Some(TsParameterPropertiesState { properties })
+        }
+    }
+
+    fn transform(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsBatchMutation> {
+        let node = ctx.query();
+        let mut mutation = node.clone().begin();
+
+        for (property, formal_parameter, _) in &state.properties {
+            mutation.replace_element(
+                property.syntax().clone().into(),
+                formal_parameter.syntax().clone().into(),
+            );
+        }
+
+        let body = node.body().ok()?;
+        let statements: Vec<_> = body.statements().iter().collect();
+        // The assignments go after `super()`, because `this` can't be accessed before it
+        let position = statements
+            .iter()
+            .position(is_super_call)
+            .map_or(0, |index| index + 1);
+        let indentation = match statements.get(position.saturating_sub(1)) {
+            Some(statement) => indentation(statement.syntax().first_token()?),
+            None => vec![(TriviaPieceKind::Whitespace, String::from(" "))],
+        };
+
+        let assignments = state
+            .properties
+            .iter()
+            .map(|(_, _, name)| {
+                with_leading_trivia(
+                    AnyJsStatement::JsExpressionStatement(make_this_assignment(name)),
+                    &indentation,
+                )
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut new_statements = statements;
+        new_statements.splice(position..position, assignments);
+        mutation.replace_node_discard_trivia(body.statements(), js_statement_list(new_statements));
+
+        Some(mutation)
+    }
+}
+
+/// Returns `true` if `statement` is the call `super()`
+fn is_super_call(statement: &AnyJsStatement) -> bool {
+    let AnyJsStatement::JsExpressionStatement(statement) = statement else {
+        return false;
+    };
+    let Ok(AnyJsExpression::JsCallExpression(call)) = statement.expression() else {
+        return false;
+    };
+    matches!(call.callee(), Ok(AnyJsExpression::JsSuperExpression(_)))
+}
+
+/// Makes
+/// ```js
+/// this.bar = bar;
+/// ```
+fn make_this_assignment(name: &str) -> JsExpressionStatement {
+    let left = js_static_member_assignment(
+        AnyJsExpression::JsThisExpression(js_this_expression(token(T![this]))),
+        token(T![.]),
+        AnyJsName::JsName(js_name(ident(name))),
+    );
+    let right = js_identifier_expression(js_reference_identifier(ident(name)));
+    let expression = js_assignment_expression(
+        AnyJsAssignmentPattern::AnyJsAssignment(AnyJsAssignment::JsStaticMemberAssignment(left)),
+        token(T![=])
+            .with_leading_trivia([(TriviaPieceKind::Whitespace, " ")])
+            .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        AnyJsExpression::JsIdentifierExpression(right),
+    );
+
+    js_expression_statement(AnyJsExpression::JsAssignmentExpression(expression))
+        .with_semicolon_token(token(T![;]))
+        .build()
+}
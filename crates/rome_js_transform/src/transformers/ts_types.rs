@@ -0,0 +1,324 @@
+use crate::utils::remove_element_keep_trivia;
+use crate::{declare_transformation, JsBatchMutation};
+use rome_analyze::context::RuleContext;
+use rome_analyze::{Ast, Rule};
+use rome_js_syntax::{
+    AnyJsAssignment, AnyJsClassMember, AnyJsDeclarationClause, AnyJsExportClause,
+    AnyJsExportDefaultDeclaration, AnyJsExpression, AnyJsImportClause, AnyTsType,
+    JsClassDeclaration, JsExport, JsFormalParameter, JsImport, JsModuleItemList, JsStatementList,
+    JsSyntaxElement, JsSyntaxKind, JsSyntaxNode, TsAccessibilityModifier, TsAsAssignment,
+    TsAsExpression, TsDeclareFunctionDeclaration, TsDeclareStatement, TsDefinitePropertyAnnotation,
+    TsDefiniteVariableAnnotation, TsImplementsClause, TsInstantiationExpression,
+    TsInterfaceDeclaration, TsNonNullAssertionAssignment, TsNonNullAssertionExpression,
+    TsOptionalPropertyAnnotation, TsOverrideModifier, TsPropertyParameterModifierList,
+    TsReadonlyModifier, TsReturnTypeAnnotation, TsSatisfiesAssignment, TsSatisfiesExpression,
+    TsThisParameter, TsTypeAliasDeclaration, TsTypeAnnotation, TsTypeArguments,
+    TsTypeAssertionAssignment, TsTypeAssertionExpression, TsTypeParameters, T,
+};
+use rome_rowan::{declare_node_union, AstNode, AstNodeList, BatchMutationExt};
+
+declare_transformation! {
+    /// Strip the TypeScript syntax that doesn't exist in JavaScript: type annotations, type
+    /// parameters and arguments, type assertions, non-null assertions, accessibility modifiers,
+    /// and the declarations that only contain types, like interfaces, type aliases, overloads
+    /// and type-only imports and exports.
+    ///
+    /// Enums, namespaces and parameter properties emit code, and they are lowered by other
+    /// transformations.
+    pub(crate) TsTypes {
+        version: "next",
+        name: "stripTypes",
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyTsStrippableNode =
+        TsTypeAnnotation
+        | TsReturnTypeAnnotation
+        | TsTypeParameters
+        | TsTypeArguments
+        | TsDefiniteVariableAnnotation
+        | TsDefinitePropertyAnnotation
+        | TsOptionalPropertyAnnotation
+        | TsImplementsClause
+        | TsThisParameter
+        | TsAccessibilityModifier
+        | TsReadonlyModifier
+        | TsOverrideModifier
+        | TsAsExpression
+        | TsSatisfiesExpression
+        | TsNonNullAssertionExpression
+        | TsTypeAssertionExpression
+        | TsInstantiationExpression
+        | TsAsAssignment
+        | TsSatisfiesAssignment
+        | TsNonNullAssertionAssignment
+        | TsTypeAssertionAssignment
+        | TsInterfaceDeclaration
+        | TsTypeAliasDeclaration
+        | TsDeclareStatement
+        | TsDeclareFunctionDeclaration
+        | AnyJsClassMember
+        | JsImport
+        | JsExport
+        | JsClassDeclaration
+        | JsFormalParameter
+}
+
+/// How a TypeScript node is stripped
+#[derive(Debug)]
+pub enum StripTypes {
+    /// Removes an optional element or a modifier, keeping the trivia around it
+    Remove(JsSyntaxElement),
+    /// Removes an item from a list of statements or class members
+    RemoveItem(JsSyntaxNode),
+    /// Removes the `this` parameter and its trailing comma
+    RemoveThisParameter(TsThisParameter),
+    /// Replaces an expression with the expression it asserts the type of
+    ReplaceExpression(AnyJsExpression, AnyJsExpression),
+    /// Replaces an assignment with the assignment it asserts the type of
+    ReplaceAssignment(AnyJsAssignment, AnyJsAssignment),
+}
+
+impl Rule for TsTypes {
+    type Query = Ast<AnyTsStrippableNode>;
+    type State = StripTypes;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+
+        // The node is removed with the enclosing type or declaration
+        if is_inside_type(node.syntax()) {
+            return None;
+        }
+
+        let state = match node {
+            AnyTsStrippableNode::TsTypeAnnotation(_)
+            | AnyTsStrippableNode::TsReturnTypeAnnotation(_)
+            | AnyTsStrippableNode::TsTypeParameters(_)
+            | AnyTsStrippableNode::TsTypeArguments(_)
+            | AnyTsStrippableNode::TsDefiniteVariableAnnotation(_)
+            | AnyTsStrippableNode::TsDefinitePropertyAnnotation(_)
+            | AnyTsStrippableNode::TsOptionalPropertyAnnotation(_)
+            | AnyTsStrippableNode::TsImplementsClause(_) => {
+                StripTypes::Remove(node.syntax().clone().into())
+            }
+            AnyTsStrippableNode::TsAccessibilityModifier(_)
+            | AnyTsStrippableNode::TsReadonlyModifier(_)
+            | AnyTsStrippableNode::TsOverrideModifier(_) => {
+                // The modifiers of parameter properties are lowered by `transformParameterProperties`
+                if node.parent::<TsPropertyParameterModifierList>().is_some() {
+                    return None;
+                }
+                StripTypes::Remove(node.syntax().clone().into())
+            }
+            AnyTsStrippableNode::TsThisParameter(parameter) => {
+                StripTypes::RemoveThisParameter(parameter.clone())
+            }
+            AnyTsStrippableNode::TsAsExpression(expression) => StripTypes::ReplaceExpression(
+                expression.clone().into(),
+                expression.expression().ok()?,
+            ),
+            AnyTsStrippableNode::TsSatisfiesExpression(expression) => {
+                StripTypes::ReplaceExpression(
+                    expression.clone().into(),
+                    expression.expression().ok()?,
+                )
+            }
+            AnyTsStrippableNode::TsNonNullAssertionExpression(expression) => {
+                StripTypes::ReplaceExpression(
+                    expression.clone().into(),
+                    expression.expression().ok()?,
+                )
+            }
+            AnyTsStrippableNode::TsTypeAssertionExpression(expression) => {
+                StripTypes::ReplaceExpression(
+                    expression.clone().into(),
+                    expression.expression().ok()?,
+                )
+            }
+            AnyTsStrippableNode::TsInstantiationExpression(expression) => {
+                StripTypes::ReplaceExpression(
+                    expression.clone().into(),
+                    expression.expression().ok()?,
+                )
+            }
+            AnyTsStrippableNode::TsAsAssignment(assignment) => StripTypes::ReplaceAssignment(
+                assignment.clone().into(),
+                assignment.assignment().ok()?,
+            ),
+            AnyTsStrippableNode::TsSatisfiesAssignment(assignment) => {
+                StripTypes::ReplaceAssignment(
+                    assignment.clone().into(),
+                    assignment.assignment().ok()?,
+                )
+            }
+            AnyTsStrippableNode::TsNonNullAssertionAssignment(assignment) => {
+                StripTypes::ReplaceAssignment(
+                    assignment.clone().into(),
+                    assignment.assignment().ok()?,
+                )
+            }
+            AnyTsStrippableNode::TsTypeAssertionAssignment(assignment) => {
+                StripTypes::ReplaceAssignment(
+                    assignment.clone().into(),
+                    assignment.assignment().ok()?,
+                )
+            }
+            AnyTsStrippableNode::TsInterfaceDeclaration(_)
+            | AnyTsStrippableNode::TsTypeAliasDeclaration(_)
+            | AnyTsStrippableNode::TsDeclareStatement(_)
+            | AnyTsStrippableNode::TsDeclareFunctionDeclaration(_) => {
+                // Exported declarations are removed with their export
+                let parent = node.syntax().parent()?;
+                if !JsModuleItemList::can_cast(parent.kind())
+                    && !JsStatementList::can_cast(parent.kind())
+                {
+                    return None;
+                }
+                StripTypes::RemoveItem(node.syntax().clone())
+            }
+            AnyTsStrippableNode::AnyJsClassMember(member) => {
+                if !is_signature_member(member) {
+                    return None;
+                }
+                StripTypes::RemoveItem(member.syntax().clone())
+            }
+            AnyTsStrippableNode::JsImport(import) => {
+                let type_token = match import.import_clause().ok()? {
+                    AnyJsImportClause::JsImportNamedClause(clause) => clause.type_token(),
+                    AnyJsImportClause::JsImportDefaultClause(clause) => clause.type_token(),
+                    AnyJsImportClause::JsImportNamespaceClause(clause) => clause.type_token(),
+                    AnyJsImportClause::JsImportBareClause(_) => None,
+                };
+                type_token?;
+                StripTypes::RemoveItem(import.syntax().clone())
+            }
+            AnyTsStrippableNode::JsExport(export) => {
+                if !is_type_only_export(export)? {
+                    return None;
+                }
+                StripTypes::RemoveItem(export.syntax().clone())
+            }
+            AnyTsStrippableNode::JsClassDeclaration(declaration) => {
+                // The class stays abstract until its abstract members are removed
+                if declaration
+                    .members()
+                    .iter()
+                    .any(|member| is_signature_member(&member))
+                {
+                    return None;
+                }
+                StripTypes::Remove(declaration.abstract_token()?.into())
+            }
+            AnyTsStrippableNode::JsFormalParameter(parameter) => {
+                StripTypes::Remove(parameter.question_mark_token()?.into())
+            }
+        };
+
+        Some(state)
+    }
+
+    fn transform(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsBatchMutation> {
+        let node = ctx.query();
+        let mut mutation = node.clone().begin();
+
+        match state {
+            StripTypes::Remove(element) => {
+                remove_element_keep_trivia(&mut mutation, element.clone())?;
+            }
+            StripTypes::RemoveItem(node) => {
+                mutation.remove_element(node.clone().into());
+            }
+            StripTypes::RemoveThisParameter(parameter) => {
+                if let Some(comma) = parameter
+                    .syntax()
+                    .next_sibling_or_token()
+                    .filter(|element| element.kind() == T![,])
+                {
+                    mutation.remove_element(comma);
+                }
+                mutation.remove_node(parameter.clone());
+            }
+            StripTypes::ReplaceExpression(expression, inner) => {
+                mutation.replace_node(expression.clone(), inner.clone());
+            }
+            StripTypes::ReplaceAssignment(assignment, inner) => {
+                mutation.replace_node(assignment.clone(), inner.clone());
+            }
+        }
+
+        Some(mutation)
+    }
+}
+
+/// Returns `true` if `member` is a signature, an index signature, or an abstract member,
+/// which don't have a body
+fn is_signature_member(member: &AnyJsClassMember) -> bool {
+    matches!(
+        member,
+        AnyJsClassMember::TsIndexSignatureClassMember(_)
+            | AnyJsClassMember::TsPropertySignatureClassMember(_)
+            | AnyJsClassMember::TsInitializedPropertySignatureClassMember(_)
+            | AnyJsClassMember::TsMethodSignatureClassMember(_)
+            | AnyJsClassMember::TsConstructorSignatureClassMember(_)
+            | AnyJsClassMember::TsGetterSignatureClassMember(_)
+            | AnyJsClassMember::TsSetterSignatureClassMember(_)
+    )
+}
+
+/// Returns `true` if `node` is part of a type, or of a node that is removed with its types
+fn is_inside_type(node: &JsSyntaxNode) -> bool {
+    node.ancestors().skip(1).any(|ancestor| {
+        AnyTsType::can_cast(ancestor.kind())
+            || matches!(
+                ancestor.kind(),
+                JsSyntaxKind::TS_DEFINITE_VARIABLE_ANNOTATION
+                    | JsSyntaxKind::TS_DEFINITE_PROPERTY_ANNOTATION
+                    | JsSyntaxKind::TS_OPTIONAL_PROPERTY_ANNOTATION
+                    | JsSyntaxKind::TS_THIS_PARAMETER
+                    | JsSyntaxKind::TS_INTERFACE_DECLARATION
+                    | JsSyntaxKind::TS_TYPE_ALIAS_DECLARATION
+                    | JsSyntaxKind::TS_DECLARE_STATEMENT
+                    | JsSyntaxKind::TS_DECLARE_FUNCTION_DECLARATION
+                    | JsSyntaxKind::TS_DECLARE_FUNCTION_EXPORT_DEFAULT_DECLARATION
+                    | JsSyntaxKind::TS_EXPORT_DECLARE_CLAUSE
+                    | JsSyntaxKind::TS_INDEX_SIGNATURE_CLASS_MEMBER
+                    | JsSyntaxKind::TS_PROPERTY_SIGNATURE_CLASS_MEMBER
+                    | JsSyntaxKind::TS_INITIALIZED_PROPERTY_SIGNATURE_CLASS_MEMBER
+                    | JsSyntaxKind::TS_METHOD_SIGNATURE_CLASS_MEMBER
+                    | JsSyntaxKind::TS_CONSTRUCTOR_SIGNATURE_CLASS_MEMBER
+                    | JsSyntaxKind::TS_GETTER_SIGNATURE_CLASS_MEMBER
+                    | JsSyntaxKind::TS_SETTER_SIGNATURE_CLASS_MEMBER
+            )
+    })
+}
+
+/// Returns `true` if `export` only exports types, so it doesn't exist in JavaScript
+fn is_type_only_export(export: &JsExport) -> Option<bool> {
+    let is_type_only = match export.export_clause().ok()? {
+        AnyJsExportClause::JsExportNamedClause(clause) => clause.type_token().is_some(),
+        AnyJsExportClause::JsExportFromClause(clause) => clause.type_token().is_some(),
+        AnyJsExportClause::JsExportNamedFromClause(clause) => clause.type_token().is_some(),
+        AnyJsExportClause::AnyJsDeclarationClause(clause) => matches!(
+            clause,
+            AnyJsDeclarationClause::TsInterfaceDeclaration(_)
+                | AnyJsDeclarationClause::TsTypeAliasDeclaration(_)
+                | AnyJsDeclarationClause::TsDeclareFunctionDeclaration(_)
+                | AnyJsDeclarationClause::TsGlobalDeclaration(_)
+        ),
+        AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => matches!(
+            clause.declaration().ok()?,
+            AnyJsExportDefaultDeclaration::TsInterfaceDeclaration(_)
+                | AnyJsExportDefaultDeclaration::TsDeclareFunctionExportDefaultDeclaration(_)
+        ),
+        AnyJsExportClause::TsExportDeclareClause(_)
+        | AnyJsExportClause::TsExportAsNamespaceClause(_) => true,
+        AnyJsExportClause::JsExportDefaultExpressionClause(_)
+        | AnyJsExportClause::TsExportAssignmentClause(_) => false,
+    };
+
+    Some(is_type_only)
+}
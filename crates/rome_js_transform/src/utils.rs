@@ -0,0 +1,146 @@
+use crate::JsBatchMutation;
+use rome_js_factory::make::{js_module_item_list, js_statement_list};
+use rome_js_syntax::{
+    AnyJsModuleItem, AnyJsStatement, JsLanguage, JsModuleItemList, JsStatementList,
+    JsSyntaxElement, JsSyntaxNode, JsSyntaxToken,
+};
+use rome_rowan::{AstNode, AstNodeList, TriviaPieceKind};
+
+/// Replaces `node`, an item of a [JsModuleItemList] or a statement of a [JsStatementList],
+/// with `items`.
+///
+/// The leading trivia of `node` is moved to the first new item, the following items are
+/// indented like `node`. It returns [None] when `node` isn't inside a list, or when `items`
+/// contain module items that can't be placed inside a [JsStatementList].
+pub(crate) fn replace_with_items(
+    mutation: &mut JsBatchMutation,
+    node: &JsSyntaxNode,
+    items: Vec<AnyJsModuleItem>,
+) -> Option<()> {
+    let parent = node.parent()?;
+    let leading_trivia = node.first_token()?.leading_trivia();
+    let indentation = indentation(node.first_token()?);
+
+    let items = items.into_iter().enumerate().map(|(index, item)| {
+        if index == 0 {
+            item.with_leading_trivia_pieces(leading_trivia.pieces())
+        } else {
+            with_leading_trivia(item, &indentation)
+        }
+    });
+
+    if let Some(list) = JsModuleItemList::cast_ref(&parent) {
+        let items: Option<Vec<_>> = items.collect();
+        let new_list = js_module_item_list(splice(list.iter(), node, items?, |item| {
+            item.syntax().clone()
+        }));
+        mutation.replace_node_discard_trivia(list, new_list);
+    } else if let Some(list) = JsStatementList::cast_ref(&parent) {
+        let statements = items
+            .map(|item| match item? {
+                AnyJsModuleItem::AnyJsStatement(statement) => Some(statement),
+                _ => None,
+            })
+            .collect::<Option<Vec<AnyJsStatement>>>()?;
+        let new_list = js_statement_list(splice(list.iter(), node, statements, |statement| {
+            statement.syntax().clone()
+        }));
+        mutation.replace_node_discard_trivia(list, new_list);
+    } else {
+        return None;
+    }
+
+    Some(())
+}
+
+fn splice<T>(
+    items: impl Iterator<Item = T>,
+    node: &JsSyntaxNode,
+    replacements: Vec<T>,
+    syntax: impl Fn(&T) -> JsSyntaxNode,
+) -> Vec<T> {
+    let mut result = Vec::new();
+    let mut replacements = Some(replacements);
+    for item in items {
+        if &syntax(&item) == node {
+            result.extend(replacements.take().into_iter().flatten());
+        } else {
+            result.push(item);
+        }
+    }
+    result
+}
+
+/// Returns the new line and the whitespace that precede `token`, or a single space
+/// when `token` isn't at the start of a line
+pub(crate) fn indentation(token: JsSyntaxToken) -> Vec<(TriviaPieceKind, String)> {
+    let pieces: Vec<_> = token.leading_trivia().pieces().collect();
+    let newline = pieces.iter().rposition(|piece| piece.is_newline());
+
+    match newline {
+        Some(position) => pieces[position..]
+            .iter()
+            .take_while(|piece| piece.is_newline() || piece.is_whitespace())
+            .map(|piece| (piece.kind(), piece.text().to_string()))
+            .collect(),
+        None => vec![(TriviaPieceKind::Whitespace, String::from(" "))],
+    }
+}
+
+/// Returns `node` with the leading trivia of its first token replaced with `trivia`
+pub(crate) fn with_leading_trivia<N>(node: N, trivia: &[(TriviaPieceKind, String)]) -> Option<N>
+where
+    N: AstNode<Language = JsLanguage>,
+{
+    let token = node.syntax().first_token()?;
+    let new_token =
+        token.with_leading_trivia(trivia.iter().map(|(kind, text)| (*kind, text.as_str())));
+    N::cast(
+        node.into_syntax()
+            .replace_child(token.into(), new_token.into())?,
+    )
+}
+
+/// Removes `element`, which occupies an optional slot of its parent.
+///
+/// When `element` starts a line, its leading trivia is moved to the next token, so the
+/// next token keeps the same position. When the removal would join two tokens, the trailing
+/// trivia of `element` is moved to the previous token.
+pub(crate) fn remove_element_keep_trivia(
+    mutation: &mut JsBatchMutation,
+    element: JsSyntaxElement,
+) -> Option<()> {
+    let (first_token, last_token) = match &element {
+        JsSyntaxElement::Node(node) => (node.first_token()?, node.last_token()?),
+        JsSyntaxElement::Token(token) => (token.clone(), token.clone()),
+    };
+    let next_token = last_token.next_token();
+
+    if first_token.leading_trivia().pieces().len() > 0 {
+        if let Some(next_token) = next_token {
+            if next_token.leading_trivia().pieces().len() == 0 {
+                mutation.replace_token_discard_trivia(
+                    next_token.clone(),
+                    next_token.with_leading_trivia_pieces(first_token.leading_trivia().pieces()),
+                );
+            }
+        }
+    } else if last_token.trailing_trivia().pieces().len() > 0 {
+        let next_has_leading_trivia = next_token.map_or(true, |next_token| {
+            next_token.leading_trivia().pieces().len() > 0
+        });
+        if let Some(previous_token) = first_token.prev_token() {
+            if previous_token.trailing_trivia().pieces().len() == 0 && !next_has_leading_trivia {
+                mutation.replace_token_discard_trivia(
+                    previous_token.clone(),
+                    previous_token
+                        .with_trailing_trivia_pieces(last_token.trailing_trivia().pieces()),
+                );
+            }
+        }
+    }
+
+    mutation.remove_element(element);
+
+    Some(())
+}
@@ -0,0 +1,5 @@
+let a: number = 1;
+let b!: string;
+function c<T>(this: Window, d: T, e?: string): void {}
+const f = <T,>(g: T): T => g;
+call<string>(a);
@@ -0,0 +1,7 @@
+abstract class A<T> extends B<T> implements C {
+	private readonly d: string;
+	e?: number;
+	[key: string]: unknown;
+	abstract f(): void;
+	public override g() {}
+}
@@ -0,0 +1,11 @@
+import type { A } from "a";
+import { b } from "b";
+interface C {
+	d: string;
+}
+type E = C | string;
+export type { E };
+export interface F {}
+declare const g: number;
+function h(a: string): void;
+function h(a: any) {}
@@ -0,0 +1,5 @@
+const a = b as string;
+const c = d satisfies Foo;
+const e = f!.g;
+const h = <number>i;
+(j as any) = 1;
@@ -0,0 +1,10 @@
+namespace A {
+	export const b = 1, c = 2;
+	export function d() {}
+	function e() {}
+	export namespace F {}
+}
+
+export namespace G {
+	export class H {}
+}
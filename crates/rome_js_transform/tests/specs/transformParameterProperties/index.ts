@@ -0,0 +1,12 @@
+class A {
+	constructor(private a: string, readonly b = 1, c: number) {
+		console.log(a);
+	}
+}
+
+class B extends A {
+	constructor(public d: string) {
+		super("a");
+		console.log(d);
+	}
+}
@@ -3,12 +3,20 @@
 use rome_analyze::declare_group;
 
 pub(crate) mod no_duplicate_json_keys;
+pub(crate) mod no_invalid_package_json_fields;
+pub(crate) mod no_json_comments;
+pub(crate) mod no_unknown_compiler_options;
+pub(crate) mod use_sorted_json_keys;
 
 declare_group! {
     pub (crate) Nursery {
         name : "nursery" ,
         rules : [
             self :: no_duplicate_json_keys :: NoDuplicateJsonKeys ,
+            self :: no_invalid_package_json_fields :: NoInvalidPackageJsonFields ,
+            self :: no_json_comments :: NoJsonComments ,
+            self :: no_unknown_compiler_options :: NoUnknownCompilerOptions ,
+            self :: use_sorted_json_keys :: UseSortedJsonKeys ,
         ]
      }
 }
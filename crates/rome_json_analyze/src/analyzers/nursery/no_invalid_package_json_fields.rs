@@ -0,0 +1,244 @@
+use crate::utils::is_root_object;
+use rome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_json_syntax::{AnyJsonValue, JsonObjectValue, TextRange};
+use rome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Disallow fields of a `package.json` file that don't have the type expected by npm.
+    ///
+    /// The rule only runs on files called `package.json`. It checks the type of the well-known fields,
+    /// and that the `name` of the package follows the
+    /// [naming rules of npm](https://docs.npmjs.com/cli/configuring-npm/package-json#name).
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// The following fields are reported inside a `package.json` file:
+    ///
+    /// ```json
+    /// {
+    ///   "name": "My Package",
+    ///   "private": "true",
+    ///   "keywords": "lint",
+    ///   "dependencies": {
+    ///     "rome": 12
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json
+    /// {
+    ///   "name": "my-package",
+    ///   "private": true,
+    ///   "keywords": ["lint"],
+    ///   "dependencies": {
+    ///     "rome": "^12.0.0"
+    ///   }
+    /// }
+    /// ```
+    pub(crate) NoInvalidPackageJsonFields {
+        version: "next",
+        name: "noInvalidPackageJsonFields",
+        recommended: true,
+    }
+}
+
+/// The reason why a field is invalid
+pub(crate) enum InvalidField {
+    /// The value of the field doesn't have the expected type
+    Type {
+        name: String,
+        range: TextRange,
+        expected: ExpectedType,
+    },
+    /// The value of the `name` field isn't a valid package name
+    PackageName {
+        range: TextRange,
+        reason: &'static str,
+    },
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum ExpectedType {
+    String,
+    Boolean,
+    ArrayOfStrings,
+    ObjectOfStrings,
+    StringOrObjectOfStrings,
+    BooleanOrArrayOfStrings,
+}
+
+impl ExpectedType {
+    /// Returns the type expected by npm for the field `name`, if it's a known field
+    fn from_field(name: &str) -> Option<Self> {
+        let expected = match name {
+            "name" | "version" | "description" | "homepage" | "license" | "main" | "module"
+            | "types" | "typings" | "type" | "packageManager" => Self::String,
+            "private" | "preferGlobal" => Self::Boolean,
+            "keywords" | "files" | "os" | "cpu" => Self::ArrayOfStrings,
+            "scripts"
+            | "dependencies"
+            | "devDependencies"
+            | "peerDependencies"
+            | "optionalDependencies"
+            | "engines"
+            | "directories" => Self::ObjectOfStrings,
+            "bin" => Self::StringOrObjectOfStrings,
+            "bundleDependencies" | "bundledDependencies" => Self::BooleanOrArrayOfStrings,
+            _ => return None,
+        };
+
+        Some(expected)
+    }
+
+    fn matches(&self, value: &AnyJsonValue) -> bool {
+        match self {
+            Self::String => is_string(value),
+            Self::Boolean => is_boolean(value),
+            Self::ArrayOfStrings => is_array_of_strings(value),
+            Self::ObjectOfStrings => is_object_of_strings(value),
+            Self::StringOrObjectOfStrings => is_string(value) || is_object_of_strings(value),
+            Self::BooleanOrArrayOfStrings => is_boolean(value) || is_array_of_strings(value),
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            Self::String => "a string",
+            Self::Boolean => "a boolean",
+            Self::ArrayOfStrings => "an array of strings",
+            Self::ObjectOfStrings => "an object whose values are strings",
+            Self::StringOrObjectOfStrings => "a string or an object whose values are strings",
+            Self::BooleanOrArrayOfStrings => "a boolean or an array of strings",
+        }
+    }
+}
+
+impl Rule for NoInvalidPackageJsonFields {
+    type Query = Ast<JsonObjectValue>;
+    type State = InvalidField;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let is_package_json = ctx
+            .file_path()
+            .file_name()
+            .map_or(false, |file_name| file_name == "package.json");
+
+        if !is_package_json || !is_root_object(node) {
+            return Vec::new();
+        }
+
+        let mut signals = Vec::new();
+        for member in node.json_member_list().iter().flatten() {
+            let (Ok(name), Ok(value)) = (member.name(), member.value()) else {
+                continue;
+            };
+            let Ok(text) = name.inner_string_text() else {
+                continue;
+            };
+            let Some(expected) = ExpectedType::from_field(text.text()) else {
+                continue;
+            };
+
+            if !expected.matches(&value) {
+                signals.push(InvalidField::Type {
+                    name: text.text().to_string(),
+                    range: value.range(),
+                    expected,
+                });
+            } else if text.text() == "name" {
+                let reason = value
+                    .as_json_string_value()
+                    .and_then(|value| value.inner_string_text().ok())
+                    .and_then(|package_name| invalid_package_name_reason(package_name.text()));
+
+                if let Some(reason) = reason {
+                    signals.push(InvalidField::PackageName {
+                        range: value.range(),
+                        reason,
+                    });
+                }
+            }
+        }
+
+        signals
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidField::Type {
+                name,
+                range,
+                expected,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The field "<Emphasis>{name}</Emphasis>" should be "{expected.description()}"."
+                },
+            ),
+            InvalidField::PackageName { range, reason } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This package name isn't valid: "{reason}"."
+                },
+            ),
+        };
+
+        Some(diagnostic.note(markup! {
+            "npm ignores or rejects a "<Emphasis>"package.json"</Emphasis>" file with invalid fields."
+        }))
+    }
+}
+
+/// Returns the reason why `name` can't be used as the name of an npm package
+fn invalid_package_name_reason(name: &str) -> Option<&'static str> {
+    if name.is_empty() {
+        Some("the name can't be empty")
+    } else if name.len() > 214 {
+        Some("the name can't be longer than 214 characters")
+    } else if name.starts_with('.') || name.starts_with('_') {
+        Some("the name can't start with a dot or an underscore")
+    } else if name.chars().any(|c| c.is_ascii_uppercase()) {
+        Some("the name can't contain uppercase letters")
+    } else if name.chars().any(char::is_whitespace) {
+        Some("the name can't contain spaces")
+    } else {
+        None
+    }
+}
+
+fn is_string(value: &AnyJsonValue) -> bool {
+    matches!(value, AnyJsonValue::JsonStringValue(_))
+}
+
+fn is_boolean(value: &AnyJsonValue) -> bool {
+    matches!(value, AnyJsonValue::JsonBooleanValue(_))
+}
+
+fn is_array_of_strings(value: &AnyJsonValue) -> bool {
+    value.as_json_array_value().map_or(false, |array| {
+        array
+            .elements()
+            .iter()
+            .all(|element| element.map_or(true, |element| is_string(&element)))
+    })
+}
+
+fn is_object_of_strings(value: &AnyJsonValue) -> bool {
+    value.as_json_object_value().map_or(false, |object| {
+        object.json_member_list().iter().all(|member| {
+            member
+                .and_then(|member| member.value())
+                .map_or(true, |value| is_string(&value))
+        })
+    })
+}
@@ -0,0 +1,111 @@
+use crate::options::JsonFilesOptions;
+use rome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_json_syntax::{AnyJsonValue, JsonRoot, TextRange};
+use rome_rowan::{AstNode, Direction};
+use std::path::Path;
+
+declare_rule! {
+    /// Disallow comments in JSON files whose format doesn't allow them.
+    ///
+    /// Rome accepts comments in any JSON file when `json.parser.allowComments` is enabled,
+    /// but some tools fail to read their configuration file when it contains comments.
+    ///
+    /// The rule checks `package.json`, `package-lock.json` and `composer.json` files,
+    /// and the files listed by the `files` option.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// The following comment is reported inside a `package.json` file:
+    ///
+    /// ```json
+    /// {
+    ///   // The name of the package
+    ///   "name": "my-package"
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json
+    /// {
+    ///   "name": "my-package"
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `files` to list the names of other files that can't contain comments:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "files": ["manifest.json"]
+    ///     }
+    /// }
+    /// ```
+    pub(crate) NoJsonComments {
+        version: "next",
+        name: "noJsonComments",
+        recommended: true,
+    }
+}
+
+/// The files whose format forbids comments
+const FILES_WITHOUT_COMMENTS: [&str; 3] = ["composer.json", "package-lock.json", "package.json"];
+
+impl Rule for NoJsonComments {
+    type Query = Ast<AnyJsonValue>;
+    type State = TextRange;
+    type Signals = Vec<Self::State>;
+    type Options = JsonFilesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+
+        // Only inspect the document once, from its top-level value
+        let Some(root) = node.syntax().parent().and_then(JsonRoot::cast) else {
+            return Vec::new();
+        };
+
+        if !forbids_comments(ctx.file_path()) && !ctx.options().contains(ctx.file_path()) {
+            return Vec::new();
+        }
+
+        root.syntax()
+            .descendants_tokens(Direction::Next)
+            .flat_map(|token| {
+                token
+                    .leading_trivia()
+                    .pieces()
+                    .chain(token.trailing_trivia().pieces())
+            })
+            .filter(|piece| piece.is_comments())
+            .map(|piece| piece.text_range())
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Comments aren't allowed in this file."
+                },
+            )
+            .note(markup! {
+                "The tools that read this file expect standard JSON, which doesn't support comments."
+            }),
+        )
+    }
+}
+
+fn forbids_comments(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map_or(false, |name| FILES_WITHOUT_COMMENTS.contains(&name))
+}
@@ -0,0 +1,225 @@
+use crate::utils::is_root_object;
+use rome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_json_syntax::{JsonMemberName, JsonObjectValue};
+use rome_rowan::{AstNode, AstSeparatedList};
+use std::path::Path;
+
+declare_rule! {
+    /// Disallow unknown options inside the `compilerOptions` of a `tsconfig.json` file.
+    ///
+    /// The rule runs on `tsconfig.json` and `jsconfig.json` files, and on files named after
+    /// the pattern `tsconfig.*.json`, such as `tsconfig.build.json`.
+    /// TypeScript reports an error when it finds an unknown compiler option.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// The following option is reported inside a `tsconfig.json` file:
+    ///
+    /// ```json
+    /// {
+    ///   "compilerOptions": {
+    ///     "strictNullCheck": true
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json
+    /// {
+    ///   "compilerOptions": {
+    ///     "strictNullChecks": true
+    ///   }
+    /// }
+    /// ```
+    pub(crate) NoUnknownCompilerOptions {
+        version: "next",
+        name: "noUnknownCompilerOptions",
+        recommended: true,
+    }
+}
+
+/// The compiler options supported by TypeScript, sorted alphabetically.
+const KNOWN_COMPILER_OPTIONS: [&str; 109] = [
+    "allowArbitraryExtensions",
+    "allowImportingTsExtensions",
+    "allowJs",
+    "allowSyntheticDefaultImports",
+    "allowUmdGlobalAccess",
+    "allowUnreachableCode",
+    "allowUnusedLabels",
+    "alwaysStrict",
+    "assumeChangesOnlyAffectDirectDependencies",
+    "baseUrl",
+    "charset",
+    "checkJs",
+    "composite",
+    "customConditions",
+    "declaration",
+    "declarationDir",
+    "declarationMap",
+    "diagnostics",
+    "disableReferencedProjectLoad",
+    "disableSizeLimit",
+    "disableSolutionSearching",
+    "disableSourceOfProjectReferenceRedirect",
+    "downlevelIteration",
+    "emitBOM",
+    "emitDeclarationOnly",
+    "emitDecoratorMetadata",
+    "esModuleInterop",
+    "exactOptionalPropertyTypes",
+    "experimentalDecorators",
+    "explainFiles",
+    "extendedDiagnostics",
+    "forceConsistentCasingInFileNames",
+    "generateCpuProfile",
+    "ignoreDeprecations",
+    "importHelpers",
+    "importsNotUsedAsValues",
+    "incremental",
+    "inlineSourceMap",
+    "inlineSources",
+    "isolatedModules",
+    "jsx",
+    "jsxFactory",
+    "jsxFragmentFactory",
+    "jsxImportSource",
+    "keyofStringsOnly",
+    "lib",
+    "listEmittedFiles",
+    "listFiles",
+    "mapRoot",
+    "maxNodeModuleJsDepth",
+    "module",
+    "moduleDetection",
+    "moduleResolution",
+    "moduleSuffixes",
+    "newLine",
+    "noEmit",
+    "noEmitHelpers",
+    "noEmitOnError",
+    "noErrorTruncation",
+    "noFallthroughCasesInSwitch",
+    "noImplicitAny",
+    "noImplicitOverride",
+    "noImplicitReturns",
+    "noImplicitThis",
+    "noImplicitUseStrict",
+    "noLib",
+    "noPropertyAccessFromIndexSignature",
+    "noResolve",
+    "noStrictGenericChecks",
+    "noUncheckedIndexedAccess",
+    "noUnusedLocals",
+    "noUnusedParameters",
+    "out",
+    "outDir",
+    "outFile",
+    "paths",
+    "plugins",
+    "preserveConstEnums",
+    "preserveSymlinks",
+    "preserveValueImports",
+    "preserveWatchOutput",
+    "pretty",
+    "reactNamespace",
+    "removeComments",
+    "resolveJsonModule",
+    "resolvePackageJsonExports",
+    "resolvePackageJsonImports",
+    "rootDir",
+    "rootDirs",
+    "skipDefaultLibCheck",
+    "skipLibCheck",
+    "sourceMap",
+    "sourceRoot",
+    "strict",
+    "strictBindCallApply",
+    "strictFunctionTypes",
+    "strictNullChecks",
+    "strictPropertyInitialization",
+    "stripInternal",
+    "suppressExcessPropertyErrors",
+    "suppressImplicitAnyIndexErrors",
+    "target",
+    "traceResolution",
+    "tsBuildInfoFile",
+    "typeRoots",
+    "types",
+    "useDefineForClassFields",
+    "useUnknownInCatchVariables",
+    "verbatimModuleSyntax",
+];
+
+impl Rule for NoUnknownCompilerOptions {
+    type Query = Ast<JsonObjectValue>;
+    type State = JsonMemberName;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+
+        if !is_tsconfig(ctx.file_path()) || !is_root_object(node) {
+            return Vec::new();
+        }
+
+        let compiler_options = node.json_member_list().iter().flatten().find_map(|member| {
+            let name = member.name().ok()?;
+            if name.inner_string_text().ok()?.text() == "compilerOptions" {
+                member.value().ok()?.as_json_object_value().cloned()
+            } else {
+                None
+            }
+        });
+
+        let Some(compiler_options) = compiler_options else {
+            return Vec::new();
+        };
+
+        compiler_options
+            .json_member_list()
+            .iter()
+            .flatten()
+            .filter_map(|member| {
+                let name = member.name().ok()?;
+                let text = name.inner_string_text().ok()?;
+                // TypeScript matches the names of the options case-insensitively
+                let is_known = KNOWN_COMPILER_OPTIONS
+                    .iter()
+                    .any(|option| option.eq_ignore_ascii_case(text.text()));
+
+                (!is_known).then_some(name)
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.range(),
+                markup! {
+                    "Unknown compiler option "<Emphasis>{{name.inner_string_text().ok()?.text()}}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "See the "<Hyperlink href="https://www.typescriptlang.org/tsconfig">"TSConfig reference"</Hyperlink>" for the list of valid options."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `path` points to a TypeScript or JavaScript project configuration file
+fn is_tsconfig(path: &Path) -> bool {
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+
+    matches!(file_name, "tsconfig.json" | "jsconfig.json")
+        || (file_name.starts_with("tsconfig.") && file_name.ends_with(".json"))
+}
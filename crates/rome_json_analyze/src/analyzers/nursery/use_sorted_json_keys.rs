@@ -0,0 +1,113 @@
+use crate::options::JsonFilesOptions;
+use rome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_json_syntax::{JsonMemberName, JsonObjectValue};
+use rome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Enforce that the keys of the objects are sorted in the configured files.
+    ///
+    /// Keeping the keys sorted makes large configuration files easier to browse and
+    /// reduces the conflicts when several people edit them.
+    /// Keys are compared by their code points.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// When `"files": ["settings.json"]` is configured, the following object is reported
+    /// inside a `settings.json` file:
+    ///
+    /// ```json
+    /// {
+    ///   "name": "rome",
+    ///   "description": "Toolchain of the web"
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json
+    /// {
+    ///   "description": "Toolchain of the web",
+    ///   "name": "rome"
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule doesn't check any file by default. Use the option `files` to list the names
+    /// of the files whose keys should be sorted:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "files": ["settings.json", "translations.json"]
+    ///     }
+    /// }
+    /// ```
+    pub(crate) UseSortedJsonKeys {
+        version: "next",
+        name: "useSortedJsonKeys",
+        recommended: false,
+    }
+}
+
+pub(crate) struct UnsortedKey {
+    /// The key that should be placed after `key`
+    previous_key: JsonMemberName,
+    /// The first key that isn't in order
+    key: JsonMemberName,
+}
+
+impl Rule for UseSortedJsonKeys {
+    type Query = Ast<JsonObjectValue>;
+    type State = UnsortedKey;
+    type Signals = Option<Self::State>;
+    type Options = JsonFilesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.options().contains(ctx.file_path()) {
+            return None;
+        }
+
+        let node = ctx.query();
+        let mut previous: Option<(JsonMemberName, String)> = None;
+
+        for member in node.json_member_list().iter().flatten() {
+            let key = member.name().ok()?;
+            let text = key.inner_string_text().ok()?.text().to_string();
+
+            if let Some((previous_key, previous_text)) = previous {
+                if text < previous_text {
+                    return Some(UnsortedKey { previous_key, key });
+                }
+            }
+
+            previous = Some((key, text));
+        }
+
+        None
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnsortedKey { previous_key, key } = state;
+
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                key.range(),
+                markup! {
+                    "The keys of this object aren't sorted."
+                },
+            )
+            .detail(
+                previous_key.range(),
+                markup! {
+                    "The key "<Emphasis>{{key.inner_string_text().ok()?.text()}}</Emphasis>" should be placed before this key."
+                },
+            ),
+        )
+    }
+}
@@ -0,0 +1,14 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use rome_analyze::declare_group;
+
+pub(crate) mod sort_keys;
+
+declare_group! {
+    pub (crate) Correctness {
+        name : "correctness" ,
+        rules : [
+            self :: sort_keys :: SortKeys ,
+        ]
+     }
+}
@@ -0,0 +1,259 @@
+use crate::options::SortKeysOptions;
+use crate::utils::is_root_object;
+use crate::JsonRuleAction;
+use rome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, SourceActionKind,
+};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_json_factory::make;
+use rome_json_syntax::{
+    AnyJsonValue, JsonArrayElementList, JsonLanguage, JsonMember, JsonMemberList, JsonObjectValue,
+};
+use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxTriviaPiece};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+declare_rule! {
+    /// Provides a whole-source code action to sort the keys of the objects in the file.
+    ///
+    /// The keys of the nested objects are sorted too. Keys are compared by their code points,
+    /// and the comments attached to a member are moved with it.
+    ///
+    /// ## Examples
+    ///
+    /// ```json
+    /// {
+    ///   "version": "1.0.0",
+    ///   // The name of the package
+    ///   "name": "rome",
+    ///   "dependencies": {
+    ///     "tslib": "^2.5.0",
+    ///     "lodash": "^4.17.21"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The keys listed in the option `order` are placed first, in the given order.
+    /// The option is set in the `json.assists.sortKeys` section of the configuration:
+    ///
+    /// ```json
+    /// {
+    ///     "json": {
+    ///         "assists": {
+    ///             "sortKeys": {
+    ///                 "order": ["name", "version"]
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub(crate) SortKeys {
+        version: "next",
+        name: "sortKeys",
+        fix_kind: FixKind::Unsafe,
+        recommended: false,
+    }
+}
+
+impl Rule for SortKeys {
+    type Query = Ast<JsonObjectValue>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = SortKeysOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+
+        // The action sorts the whole document at once
+        if !is_root_object(node) {
+            return None;
+        }
+
+        let is_sorted = is_sorted(&AnyJsonValue::from(node.clone()), ctx.options())?;
+        (!is_sorted).then_some(())
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsonRuleAction> {
+        let old_list = ctx.query().json_member_list();
+        let new_list = sort_member_list(&old_list, ctx.options())?;
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(old_list, new_list);
+
+        Some(JsonRuleAction {
+            category: ActionCategory::Source(SourceActionKind::Other(Cow::Borrowed("sortKeys"))),
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Sort the keys (Rome)" }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Compares two keys, placing the keys of the configured order first
+fn compare_keys(left: &str, right: &str, options: &SortKeysOptions) -> Ordering {
+    match (options.position(left), options.position(right)) {
+        (Some(left), Some(right)) => left.cmp(&right),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => left.cmp(right),
+    }
+}
+
+fn member_key(member: &JsonMember) -> Option<String> {
+    Some(
+        member
+            .name()
+            .ok()?
+            .inner_string_text()
+            .ok()?
+            .text()
+            .to_string(),
+    )
+}
+
+/// Returns `true` if the keys of `value` and of all its nested objects are sorted,
+/// or [None] if the value contains syntax errors
+fn is_sorted(value: &AnyJsonValue, options: &SortKeysOptions) -> Option<bool> {
+    match value {
+        AnyJsonValue::JsonObjectValue(object) => {
+            let mut previous_key: Option<String> = None;
+            for member in object.json_member_list().iter() {
+                let member = member.ok()?;
+                let key = member_key(&member)?;
+                if let Some(previous_key) = previous_key {
+                    if compare_keys(&previous_key, &key, options) == Ordering::Greater {
+                        return Some(false);
+                    }
+                }
+
+                if !is_sorted(&member.value().ok()?, options)? {
+                    return Some(false);
+                }
+                previous_key = Some(key);
+            }
+
+            Some(true)
+        }
+        AnyJsonValue::JsonArrayValue(array) => {
+            for element in array.elements().iter() {
+                if !is_sorted(&element.ok()?, options)? {
+                    return Some(false);
+                }
+            }
+
+            Some(true)
+        }
+        _ => Some(true),
+    }
+}
+
+/// Returns a copy of `value` where the keys of all the objects are sorted
+fn sort_value(value: &AnyJsonValue, options: &SortKeysOptions) -> Option<AnyJsonValue> {
+    let value = match value {
+        AnyJsonValue::JsonObjectValue(object) => {
+            let list = sort_member_list(&object.json_member_list(), options)?;
+            AnyJsonValue::from(object.clone().with_json_member_list(Some(list)))
+        }
+        AnyJsonValue::JsonArrayValue(array) => {
+            let list = sort_element_list(&array.elements(), options)?;
+            AnyJsonValue::from(array.clone().with_elements(Some(list)))
+        }
+        _ => value.clone(),
+    };
+
+    Some(value)
+}
+
+fn sort_element_list(
+    list: &JsonArrayElementList,
+    options: &SortKeysOptions,
+) -> Option<JsonArrayElementList> {
+    let mut elements = Vec::new();
+    let mut separators = Vec::new();
+    for element in list.elements() {
+        elements.push(sort_value(&element.node.ok()?, options)?);
+        separators.extend(element.trailing_separator.ok()?);
+    }
+
+    Some(make::json_array_element_list(elements, separators))
+}
+
+/// A member of an object and the trivia that follows it
+struct SortableMember {
+    key: String,
+    member: JsonMember,
+    /// The trivia that follows the member until the end of the line, if it contains comments.
+    /// It's moved with the member, whereas the whitespace stays in place.
+    trailing_comments: Option<Vec<SyntaxTriviaPiece<JsonLanguage>>>,
+}
+
+fn sort_member_list(list: &JsonMemberList, options: &SortKeysOptions) -> Option<JsonMemberList> {
+    let mut members = Vec::new();
+    let mut separators = Vec::new();
+    // The trivia that follows each position of the list, when it doesn't contain comments
+    let mut trailing_whitespaces = Vec::new();
+
+    for element in list.elements() {
+        let member = element.node.ok()?;
+        let key = member_key(&member)?;
+        let value = sort_value(&member.value().ok()?, options)?;
+        let mut member = member.with_value(value);
+
+        let trailing_trivia: Vec<_> = match element.trailing_separator.ok()? {
+            Some(separator) => {
+                let trivia = separator.trailing_trivia().pieces().collect();
+                separators.push(separator.clone());
+                trivia
+            }
+            None => {
+                // The last member doesn't have a separator: its trailing trivia is
+                // detached, so it doesn't end up before a separator once moved
+                let trivia = member
+                    .syntax()
+                    .last_token()?
+                    .trailing_trivia()
+                    .pieces()
+                    .collect();
+                member = member.with_trailing_trivia_pieces([])?;
+                trivia
+            }
+        };
+
+        let has_comments = trailing_trivia.iter().any(|piece| piece.is_comments());
+        if has_comments {
+            trailing_whitespaces.push(Vec::new());
+        } else {
+            trailing_whitespaces.push(trailing_trivia.clone());
+        }
+
+        members.push(SortableMember {
+            key,
+            member,
+            trailing_comments: has_comments.then_some(trailing_trivia),
+        });
+    }
+
+    members.sort_by(|left, right| compare_keys(&left.key, &right.key, options));
+
+    let mut separators = separators.into_iter();
+    let mut new_separators = Vec::new();
+    let mut new_members = Vec::new();
+
+    for (member, trailing_whitespace) in members.into_iter().zip(trailing_whitespaces) {
+        let trailing_trivia = member.trailing_comments.unwrap_or(trailing_whitespace);
+        match separators.next() {
+            Some(separator) => {
+                new_separators.push(separator.with_trailing_trivia_pieces(trailing_trivia));
+                new_members.push(member.member);
+            }
+            None => {
+                new_members.push(member.member.with_trailing_trivia_pieces(trailing_trivia)?);
+            }
+        }
+    }
+
+    Some(make::json_member_list(new_members, new_separators))
+}
@@ -1,16 +1,21 @@
 mod analyzers;
+mod assists;
 mod diagnostics;
+pub mod options;
 mod registry;
+mod utils;
 
 use crate::diagnostics::SuppressionDiagnostic;
 pub use crate::registry::visit_registry;
 use rome_analyze::{
     AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleRegistry, SuppressionKind,
+    MetadataRegistry, RuleAction, RuleRegistry, SuppressionKind,
 };
 use rome_diagnostics::Error;
 use rome_json_syntax::JsonLanguage;
 
+pub(crate) type JsonRuleAction = RuleAction<JsonLanguage>;
+
 /// Return the static [MetadataRegistry] for the JSON analyzer rules
 pub fn metadata() -> &'static MetadataRegistry {
     lazy_static::lazy_static! {
@@ -0,0 +1,107 @@
+//! This module contains the options shared by the JSON rules
+
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::SyntaxNode;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Options for the rules that only run on some files, `useSortedJsonKeys` and `noJsonComments`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct JsonFilesOptions {
+    /// A list of file names the rule applies to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<String>>,
+}
+
+impl JsonFilesOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["files"];
+
+    /// Returns `true` if the name of the file at `path` is one of the configured files
+    pub(crate) fn contains(&self, path: &Path) -> bool {
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            return false;
+        };
+
+        self.files
+            .as_ref()
+            .map_or(false, |files| files.iter().any(|file| file == file_name))
+    }
+}
+
+impl VisitJsonNode for JsonFilesOptions {}
+impl VisitNode<JsonLanguage> for JsonFilesOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "files" {
+            self.files = self.map_to_array_of_strings(&value, name_text, diagnostics);
+        }
+
+        Some(())
+    }
+}
+
+/// Options for the `sortKeys` assist
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SortKeysOptions {
+    /// A list of keys that are placed first, in the given order. The other keys are sorted
+    /// by their code points.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<Vec<String>>,
+}
+
+impl SortKeysOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["order"];
+
+    /// Returns the position of `key` in the configured order, if the key is part of it
+    pub(crate) fn position(&self, key: &str) -> Option<usize> {
+        self.order
+            .as_ref()
+            .and_then(|order| order.iter().position(|item| item == key))
+    }
+}
+
+impl VisitJsonNode for SortKeysOptions {}
+impl VisitNode<JsonLanguage> for SortKeysOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "order" {
+            self.order = self.map_to_array_of_strings(&value, name_text, diagnostics);
+        }
+
+        Some(())
+    }
+}
@@ -4,4 +4,5 @@ use rome_analyze::RegistryVisitor;
 use rome_json_syntax::JsonLanguage;
 pub fn visit_registry<V: RegistryVisitor<JsonLanguage>>(registry: &mut V) {
     registry.record_category::<crate::analyzers::Analyzers>();
+    registry.record_category::<crate::assists::Assists>();
 }
@@ -0,0 +1,10 @@
+use rome_json_syntax::{JsonObjectValue, JsonRoot};
+use rome_rowan::AstNode;
+
+/// Returns `true` if `object` is the top-level value of the document
+pub(crate) fn is_root_object(object: &JsonObjectValue) -> bool {
+    object
+        .syntax()
+        .parent()
+        .map_or(false, |parent| JsonRoot::can_cast(parent.kind()))
+}
@@ -9,7 +9,12 @@ use rome_test_utils::{
     has_bogus_nodes_or_empty_slots, parse_test_path, register_leak_checker,
     write_analyzer_snapshot,
 };
-use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
+use std::{
+    ffi::OsStr,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    slice,
+};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{json}", crate::run_test, "module"}
 
@@ -19,6 +24,11 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     let input_file = Path::new(input);
     let file_name = input_file.file_name().and_then(OsStr::to_str).unwrap();
 
+    // Files ending with `.options.json` configure the rule of the test with the same name
+    if file_name.ends_with(".options.json") {
+        return;
+    }
+
     let (group, rule) = parse_test_path(input_file);
     if rule == "specs" || rule == "suppression" {
         panic!("the test file must be placed in the {rule}/<group-name>/<rule-name>/ directory");
@@ -67,12 +77,18 @@ pub(crate) fn analyze_and_snap(
     file_name: &str,
     input_file: &Path,
 ) -> usize {
-    let parsed = parse_json(input_code, JsonParserOptions::default());
+    // Some rules inspect comments, which aren't part of the standard JSON syntax
+    let parsed = parse_json(
+        input_code,
+        JsonParserOptions::default().with_allow_comments(),
+    );
     let root = parsed.tree();
 
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
-    let options = create_analyzer_options(input_file, &mut diagnostics);
+    let mut options = create_analyzer_options(input_file, &mut diagnostics);
+    // Some rules only run on files with a specific name
+    options.file_path = PathBuf::from(file_name);
 
     let (_, errors) =
         rome_json_analyze::analyze(&root.value().unwrap(), filter, &options, |event| {
@@ -136,6 +152,6 @@ fn check_code_action(path: &Path, source: &str, action: &AnalyzerAction<JsonLang
     }
 
     // Re-parse the modified code and panic if the resulting tree has syntax errors
-    let re_parse = parse_json(&output, JsonParserOptions::default());
+    let re_parse = parse_json(&output, JsonParserOptions::default().with_allow_comments());
     assert_errors_are_absent(re_parse.tree().syntax(), re_parse.diagnostics(), path);
 }
@@ -5,9 +5,14 @@ use rome_formatter::{
 };
 
 use crate::comments::{FormatJsonLeadingComment, JsonComments};
+use rome_deserialize::json::with_only_known_variants;
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_formatter::separated::TrailingSeparator;
 use rome_json_syntax::JsonLanguage;
+use rome_rowan::SyntaxNode;
 use std::fmt;
 use std::rc::Rc;
+use std::str::FromStr;
 
 #[derive(Debug)]
 pub struct JsonFormatContext {
@@ -54,10 +59,25 @@ impl CstFormatContext for JsonFormatContext {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct JsonFormatOptions {
     indent_style: IndentStyle,
     line_width: LineWidth,
+    trailing_commas: TrailingCommas,
+    normalize_quotes: bool,
+    object_wrap: ObjectWrap,
+}
+
+impl Default for JsonFormatOptions {
+    fn default() -> Self {
+        Self {
+            indent_style: IndentStyle::default(),
+            line_width: LineWidth::default(),
+            trailing_commas: TrailingCommas::default(),
+            normalize_quotes: true,
+            object_wrap: ObjectWrap::default(),
+        }
+    }
 }
 
 impl JsonFormatOptions {
@@ -70,6 +90,33 @@ impl JsonFormatOptions {
         self.line_width = line_width;
         self
     }
+
+    pub fn with_trailing_commas(mut self, trailing_commas: TrailingCommas) -> Self {
+        self.trailing_commas = trailing_commas;
+        self
+    }
+
+    pub fn with_normalize_quotes(mut self, normalize_quotes: bool) -> Self {
+        self.normalize_quotes = normalize_quotes;
+        self
+    }
+
+    pub fn with_object_wrap(mut self, object_wrap: ObjectWrap) -> Self {
+        self.object_wrap = object_wrap;
+        self
+    }
+
+    pub fn trailing_commas(&self) -> TrailingCommas {
+        self.trailing_commas
+    }
+
+    pub fn normalize_quotes(&self) -> bool {
+        self.normalize_quotes
+    }
+
+    pub fn object_wrap(&self) -> ObjectWrap {
+        self.object_wrap
+    }
 }
 
 impl FormatOptions for JsonFormatOptions {
@@ -89,6 +136,142 @@ impl FormatOptions for JsonFormatOptions {
 impl fmt::Display for JsonFormatOptions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "Indent style: {}", self.indent_style)?;
-        writeln!(f, "Line width: {}", self.line_width.value())
+        writeln!(f, "Line width: {}", self.line_width.value())?;
+        writeln!(f, "Trailing commas: {}", self.trailing_commas)?;
+        writeln!(f, "Normalize quotes: {}", self.normalize_quotes)?;
+        writeln!(f, "Object wrap: {}", self.object_wrap)
+    }
+}
+
+/// Print trailing commas in multi-line objects and arrays.
+///
+/// Trailing commas aren't valid in JSON: Rome only prints them in the JSONC files.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum TrailingCommas {
+    /// No trailing commas.
+    #[default]
+    None,
+    /// Trailing commas wherever possible.
+    All,
+}
+
+impl TrailingCommas {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["none", "all"];
+
+    pub const fn is_all(&self) -> bool {
+        matches!(self, TrailingCommas::All)
+    }
+
+    /// Returns the [TrailingSeparator] used to format the lists of objects and arrays
+    pub(crate) const fn trailing_separator(&self) -> TrailingSeparator {
+        match self {
+            TrailingCommas::None => TrailingSeparator::Omit,
+            TrailingCommas::All => TrailingSeparator::Allowed,
+        }
+    }
+}
+
+impl FromStr for TrailingCommas {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" | "None" => Ok(Self::None),
+            "all" | "All" => Ok(Self::All),
+            // TODO: replace this error with a diagnostic
+            _ => Err("Value not supported for TrailingCommas"),
+        }
+    }
+}
+
+impl fmt::Display for TrailingCommas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrailingCommas::None => std::write!(f, "None"),
+            TrailingCommas::All => std::write!(f, "All"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for TrailingCommas {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, TrailingCommas::KNOWN_VALUES, diagnostics)?;
+        if node.inner_string_text().ok()?.text() == "all" {
+            *self = TrailingCommas::All;
+        } else {
+            *self = TrailingCommas::None;
+        }
+        Some(())
+    }
+}
+
+/// How the formatter wraps the objects that fit on a single line.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum ObjectWrap {
+    /// Objects are printed on multiple lines if there's a newline between
+    /// the `{` and the first member in the original source.
+    #[default]
+    Preserve,
+    /// Objects are printed on a single line if they fit.
+    Collapse,
+}
+
+impl ObjectWrap {
+    pub(crate) const KNOWN_VALUES: &'static [&'static str] = &["preserve", "collapse"];
+
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, ObjectWrap::Preserve)
+    }
+}
+
+impl FromStr for ObjectWrap {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "collapse" | "Collapse" => Ok(Self::Collapse),
+            // TODO: replace this error with a diagnostic
+            _ => Err("Value not supported for ObjectWrap"),
+        }
+    }
+}
+
+impl fmt::Display for ObjectWrap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObjectWrap::Preserve => std::write!(f, "Preserve"),
+            ObjectWrap::Collapse => std::write!(f, "Collapse"),
+        }
+    }
+}
+
+impl VisitNode<JsonLanguage> for ObjectWrap {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, ObjectWrap::KNOWN_VALUES, diagnostics)?;
+        if node.inner_string_text().ok()?.text() == "collapse" {
+            *self = ObjectWrap::Collapse;
+        } else {
+            *self = ObjectWrap::Preserve;
+        }
+        Some(())
     }
 }
@@ -13,6 +13,10 @@ pub(crate) struct CleanedStringLiteralText<'token> {
 
 impl Format<JsonFormatContext> for CleanedStringLiteralText<'_> {
     fn fmt(&self, f: &mut Formatter<JsonFormatContext>) -> FormatResult<()> {
+        if !f.options().normalize_quotes() {
+            return self.token.format().fmt(f);
+        }
+
         let content = self.token.text_trimmed();
         let raw_content = &content[1..content.len() - 1];
 
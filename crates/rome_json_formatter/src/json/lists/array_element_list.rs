@@ -10,6 +10,7 @@ pub(crate) struct FormatJsonArrayElementList;
 impl FormatRule<JsonArrayElementList> for FormatJsonArrayElementList {
     type Context = JsonFormatContext;
     fn fmt(&self, node: &JsonArrayElementList, f: &mut JsonFormatter) -> FormatResult<()> {
+        let trailing_separator = f.options().trailing_commas().trailing_separator();
         let layout = if can_concisely_print_array_list(node) {
             ArrayLayout::Fill
         } else {
@@ -20,7 +21,10 @@ impl FormatRule<JsonArrayElementList> for FormatJsonArrayElementList {
             ArrayLayout::Fill => {
                 let mut filler = f.fill();
 
-                for (element, formatted) in node.iter().zip(node.format_separated(",")) {
+                for (element, formatted) in node
+                    .iter()
+                    .zip(node.format_separated(",", trailing_separator))
+                {
                     filler.entry(
                         &format_once(|f| {
                             if get_lines_before(element?.syntax()) > 1 {
@@ -39,7 +43,10 @@ impl FormatRule<JsonArrayElementList> for FormatJsonArrayElementList {
             ArrayLayout::OnePerLine => {
                 let mut join = f.join_nodes_with_soft_line();
 
-                for (element, formatted) in node.elements().zip(node.format_separated(",")) {
+                for (element, formatted) in node
+                    .elements()
+                    .zip(node.format_separated(",", trailing_separator))
+                {
                     join.entry(element.node()?.syntax(), &formatted);
                 }
 
@@ -8,7 +8,8 @@ pub(crate) struct FormatJsonObjectValue;
 
 impl FormatNodeRule<JsonObjectValue> for FormatJsonObjectValue {
     fn fmt_fields(&self, node: &JsonObjectValue, f: &mut JsonFormatter) -> FormatResult<()> {
-        let should_expand = node.json_member_list().syntax().has_leading_newline();
+        let should_expand = f.options().object_wrap().is_preserve()
+            && node.json_member_list().syntax().has_leading_newline();
 
         let list = format_with(|f| {
             write!(
@@ -1,6 +1,8 @@
 use rome_formatter::{FormatContext, FormatResult, Formatted, IndentStyle, LineWidth, Printed};
 use rome_formatter_test::TestFormatLanguage;
-use rome_json_formatter::context::{JsonFormatContext, JsonFormatOptions};
+use rome_json_formatter::context::{
+    JsonFormatContext, JsonFormatOptions, ObjectWrap, TrailingCommas,
+};
 use rome_json_formatter::{format_node, format_range, JsonFormatLanguage};
 use rome_json_parser::{parse_json, JsonParserOptions};
 use rome_json_syntax::{JsonFileSource, JsonLanguage};
@@ -20,7 +22,12 @@ impl TestFormatLanguage for JsonTestFormatLanguage {
     type FormatLanguage = JsonFormatLanguage;
 
     fn parse(&self, text: &str) -> AnyParse {
-        let parse = parse_json(text, JsonParserOptions::default().with_allow_comments());
+        let parse = parse_json(
+            text,
+            JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas(),
+        );
 
         AnyParse::new(
             parse.syntax().as_send().unwrap(),
@@ -77,6 +84,36 @@ impl From<JsonSerializableIndentStyle> for IndentStyle {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsonSerializableTrailingCommas {
+    None,
+    All,
+}
+
+impl From<JsonSerializableTrailingCommas> for TrailingCommas {
+    fn from(test: JsonSerializableTrailingCommas) -> Self {
+        match test {
+            JsonSerializableTrailingCommas::None => TrailingCommas::None,
+            JsonSerializableTrailingCommas::All => TrailingCommas::All,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum JsonSerializableObjectWrap {
+    Preserve,
+    Collapse,
+}
+
+impl From<JsonSerializableObjectWrap> for ObjectWrap {
+    fn from(test: JsonSerializableObjectWrap) -> Self {
+        match test {
+            JsonSerializableObjectWrap::Preserve => ObjectWrap::Preserve,
+            JsonSerializableObjectWrap::Collapse => ObjectWrap::Collapse,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy)]
 pub struct JsonSerializableFormatOptions {
     /// The indent style.
@@ -84,6 +121,15 @@ pub struct JsonSerializableFormatOptions {
 
     /// What's the max width of a line. Defaults to 80.
     pub line_width: Option<u16>,
+
+    /// Print trailing commas in multi-line objects and arrays. Defaults to "none".
+    pub trailing_commas: Option<JsonSerializableTrailingCommas>,
+
+    /// Whether the formatter removes the unnecessary escapes of the strings. Defaults to true.
+    pub normalize_quotes: Option<bool>,
+
+    /// How the objects that fit on a single line are wrapped. Defaults to "preserve".
+    pub object_wrap: Option<JsonSerializableObjectWrap>,
 }
 
 impl From<JsonSerializableFormatOptions> for JsonFormatOptions {
@@ -98,6 +144,15 @@ impl From<JsonSerializableFormatOptions> for JsonFormatOptions {
                     .and_then(|width| LineWidth::try_from(width).ok())
                     .unwrap_or_default(),
             )
+            .with_trailing_commas(
+                test.trailing_commas
+                    .map_or_else(|| TrailingCommas::None, |value| value.into()),
+            )
+            .with_normalize_quotes(test.normalize_quotes.unwrap_or(true))
+            .with_object_wrap(
+                test.object_wrap
+                    .map_or_else(|| ObjectWrap::Preserve, |value| value.into()),
+            )
     }
 }
 
@@ -9,11 +9,13 @@ use rome_parser::ParserContext;
 pub(crate) struct JsonParser<'source> {
     context: ParserContext<JsonSyntaxKind>,
     source: JsonTokenSource<'source>,
+    options: JsonParserOptions,
 }
 
 #[derive(Default, Debug, Clone, Copy)]
 pub struct JsonParserOptions {
     pub allow_comments: bool,
+    pub allow_trailing_commas: bool,
 }
 
 impl JsonParserOptions {
@@ -21,6 +23,11 @@ impl JsonParserOptions {
         self.allow_comments = true;
         self
     }
+
+    pub fn with_allow_trailing_commas(mut self) -> Self {
+        self.allow_trailing_commas = true;
+        self
+    }
 }
 
 impl<'source> JsonParser<'source> {
@@ -28,9 +35,14 @@ impl<'source> JsonParser<'source> {
         Self {
             context: ParserContext::default(),
             source: JsonTokenSource::from_str(source, config),
+            options: config,
         }
     }
 
+    pub fn options(&self) -> &JsonParserOptions {
+        &self.options
+    }
+
     pub fn finish(
         self,
     ) -> (
@@ -175,6 +175,10 @@ fn parse_sequence(p: &mut JsonParser, root_kind: SequenceKind) -> ParsedSyntax {
                 first = false;
             } else {
                 p.expect(T![,]);
+
+                if p.options().allow_trailing_commas && p.at(current.kind.close_paren()) {
+                    break;
+                }
             }
 
             progress.assert_progressing(p);
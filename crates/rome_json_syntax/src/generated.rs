@@ -1,6 +1,8 @@
 #[rustfmt::skip]
 pub(super) mod nodes;
 #[rustfmt::skip]
+pub(super) mod nodes_mut;
+#[rustfmt::skip]
 pub mod macros;
 #[macro_use]
 pub mod kind;
@@ -173,6 +173,8 @@ fn fix_all(
         path: rome_path,
         fix_file_mode: FixFileMode::SafeFixes,
         should_format,
+        only: vec![],
+        skip: vec![],
     })?;
 
     if fixed.actions.is_empty() {
@@ -15,6 +15,7 @@ use rome_service::workspace::{RageEntry, RageParams, RageResult};
 use rome_service::{workspace, Workspace};
 use serde_json::json;
 use std::collections::HashMap;
+use std::ffi::OsStr;
 use std::panic::RefUnwindSafe;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
@@ -134,7 +135,7 @@ impl LSPServer {
                 CapabilityStatus::Enable(Some(json!(DidChangeWatchedFilesRegistrationOptions {
                     watchers: vec![FileSystemWatcher {
                         glob_pattern: GlobPattern::String(format!(
-                            "{}/rome.json",
+                            "{}/**/rome.json",
                             base_path.display()
                         )),
                         kind: Some(WatchKind::all()),
@@ -308,7 +309,9 @@ impl LanguageServer for LSPServer {
                     if let Some(base_path) = base_path {
                         let possible_rome_json = file_path.strip_prefix(&base_path);
                         if let Ok(possible_rome_json) = possible_rome_json {
-                            if possible_rome_json.display().to_string() == CONFIG_NAME {
+                            // The nested configuration files are merged onto the root one,
+                            // all the settings are loaded again when one of them changes
+                            if possible_rome_json.file_name() == Some(OsStr::new(CONFIG_NAME)) {
                                 self.session.load_workspace_settings().await;
                                 self.setup_capabilities().await;
                                 self.session.update_all_diagnostics().await;
@@ -550,6 +553,9 @@ impl ServerFactory {
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
         workspace_method!(builder, organize_imports);
+        workspace_method!(builder, transform_file);
+        workspace_method!(builder, resolve_json_schema);
+        workspace_method!(builder, register_json_schema);
 
         let (service, socket) = builder.finish();
         ServerConnection { socket, service }
@@ -8,16 +8,19 @@ use futures::stream::futures_unordered::FuturesUnordered;
 use futures::StreamExt;
 use rome_analyze::RuleCategories;
 use rome_console::markup;
-use rome_fs::{FileSystem, OsFileSystem, RomePath};
+use rome_diagnostics::PrintDescription;
+use rome_fs::{FileSystem, FileSystemExt, OsFileSystem, RomePath};
+use rome_service::configuration::nested::NestedConfigurations;
 use rome_service::workspace::{
-    FeatureName, FeaturesBuilder, PullDiagnosticsParams, SupportsFeatureParams,
+    FeatureName, FeaturesBuilder, PullDiagnosticsParams, RegisterJsonSchemaParams,
+    ResolveJsonSchemaParams, SupportsFeatureParams,
 };
 use rome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
 use rome_service::{load_config, ConfigurationBasePath, Workspace};
 use rome_service::{DynRef, WorkspaceError};
 use serde_json::Value;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicU8;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -64,6 +67,10 @@ pub(crate) struct Session {
 
     documents: RwLock<HashMap<lsp_types::Url, Document>>,
 
+    /// The configuration files of the subdirectories of the workspace, they're read when a
+    /// document of their directory is opened. It's `None` until the configuration is loaded
+    nested_configurations: RwLock<Option<NestedConfigurations>>,
+
     pub(crate) cancellation: Arc<Notify>,
 }
 
@@ -144,6 +151,7 @@ impl Session {
             documents,
             extension_settings: config,
             fs: DynRef::Owned(Box::new(OsFileSystem)),
+            nested_configurations: RwLock::default(),
             cancellation,
         }
     }
@@ -271,6 +279,7 @@ impl Session {
     pub(crate) async fn update_diagnostics(&self, url: lsp_types::Url) -> Result<()> {
         let rome_path = self.file_path(&url)?;
         let doc = self.document(&url)?;
+        self.register_nested_configurations(&rome_path);
         let file_features = self.workspace.file_features(SupportsFeatureParams {
             feature: FeaturesBuilder::new()
                 .with_linter()
@@ -291,7 +300,8 @@ impl Session {
         } else {
             let mut categories = RuleCategories::SYNTAX;
             if file_features.supports_for(&FeatureName::Lint) {
-                categories |= RuleCategories::LINT
+                categories |= RuleCategories::LINT;
+                self.register_json_schema(&rome_path);
             }
             if file_features.supports_for(&FeatureName::OrganizeImports) {
                 categories |= RuleCategories::ACTION
@@ -300,6 +310,8 @@ impl Session {
                 path: rome_path,
                 categories,
                 max_diagnostics: u64::MAX,
+                only: vec![],
+                skip: vec![],
             })?;
 
             tracing::trace!("rome diagnostics: {:#?}", result.diagnostics);
@@ -337,6 +349,68 @@ impl Session {
         Ok(())
     }
 
+    /// Reads the JSON Schema used to validate the document from the file system and
+    /// registers it in the workspace, if it isn't registered yet
+    fn register_json_schema(&self, rome_path: &RomePath) {
+        let result = match self.workspace.resolve_json_schema(ResolveJsonSchemaParams {
+            path: rome_path.clone(),
+        }) {
+            Ok(result) => result,
+            Err(err) => {
+                error!("Couldn't resolve the JSON Schema of the document: {}", err);
+                return;
+            }
+        };
+        let Some(schema_path) = result.schema_path.filter(|_| !result.is_registered) else {
+            return;
+        };
+
+        let full_path = match self.base_path() {
+            Some(base_path) => base_path.join(schema_path.as_path()),
+            None => schema_path.to_path_buf(),
+        };
+        let content = self.fs.open(&full_path).and_then(|mut file| {
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+            Ok(content)
+        });
+        let result = match content {
+            Ok(content) => self
+                .workspace
+                .register_json_schema(RegisterJsonSchemaParams {
+                    path: schema_path,
+                    content,
+                })
+                .map_err(|err| err.to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+
+        if let Err(err) = result {
+            error!(
+                "Couldn't load the JSON Schema {}: {}",
+                full_path.display(),
+                err
+            );
+        }
+    }
+
+    /// Reads the configuration files of the directory of the document and of its ancestors, and
+    /// registers their settings in the workspace
+    fn register_nested_configurations(&self, rome_path: &RomePath) {
+        let nested_configurations = self.nested_configurations.read().unwrap();
+        let Some(nested_configurations) = nested_configurations.as_ref() else {
+            return;
+        };
+
+        let directory = rome_path.parent().unwrap_or(Path::new(""));
+        for diagnostic in nested_configurations.register(&*self.fs, &*self.workspace, directory) {
+            error!(
+                "Couldn't load a nested configuration file: {}",
+                PrintDescription(&diagnostic)
+            );
+        }
+    }
+
     /// Updates diagnostics for every [`Document`] in this [`Session`]
     pub(crate) async fn update_all_diagnostics(&self) {
         let mut futures: FuturesUnordered<_> = self
@@ -403,14 +477,21 @@ impl Session {
 
                 info!("Loaded workspace settings: {configuration:#?}");
 
-                let result = self
-                    .workspace
-                    .update_settings(UpdateSettingsParams { configuration });
+                let result = self.workspace.update_settings(UpdateSettingsParams {
+                    configuration: configuration.clone(),
+                    directory: None,
+                });
 
                 if let Err(error) = result {
                     error!("Failed to set workspace settings: {}", error);
                     ConfigurationStatus::Error
                 } else {
+                    // The nested configuration files are read again, since the workspace
+                    // discarded the settings resolved from the previous configuration
+                    *self.nested_configurations.write().unwrap() = Some(NestedConfigurations::new(
+                        configuration,
+                        self.base_path().unwrap_or_default(),
+                    ));
                     ConfigurationStatus::Loaded
                 }
             }
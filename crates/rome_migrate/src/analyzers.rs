@@ -1,7 +1,11 @@
+use crate::analyzers::nursery_promotions::NurseryPromotions;
+use crate::analyzers::renamed_rules::RenamedRules;
 use crate::analyzers::rule_set::RuleSet;
 use rome_analyze::{GroupCategory, RegistryVisitor, RuleCategory, RuleGroup};
 use rome_json_syntax::JsonLanguage;
 
+mod nursery_promotions;
+mod renamed_rules;
 mod rule_set;
 
 pub(crate) struct MigrationGroup;
@@ -14,8 +18,10 @@ impl RuleGroup for MigrationGroup {
 
     fn record_rules<V: RegistryVisitor<Self::Language> + ?Sized>(registry: &mut V) {
         // Order here is important, rules should be added from the most old, to the most recent
-        // v13.0.0
+        // v11.0.0
         registry.record_rule::<RuleSet>();
+        registry.record_rule::<RenamedRules>();
+        registry.record_rule::<NurseryPromotions>();
     }
 }
 
@@ -0,0 +1,116 @@
+use crate::declare_migration;
+use crate::utils::{move_rule_action, RuleEntry, RuleMove};
+use crate::MigrationAction;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{Ast, Rule};
+use rome_json_syntax::JsonMember;
+
+declare_migration! {
+    /// Moves the configuration of the rules promoted from the `nursery` group to their new group.
+    pub(crate) NurseryPromotions {
+        version: "11.0.0",
+        name: "nurseryPromotions",
+    }
+}
+
+/// The rules promoted from the `nursery` group: the version of the promotion,
+/// the new group and the name of the rule
+const PROMOTED_RULES: &[(&str, &str, &str)] = &[
+    ("11.0.0", "complexity", "useFlatMap"),
+    ("11.0.0", "correctness", "noConstAssign"),
+    ("11.0.0", "correctness", "useValidForDirection"),
+    ("11.0.0", "suspicious", "noExplicitAny"),
+    ("12.0.0", "a11y", "noAccessKey"),
+    ("12.0.0", "a11y", "noDistractingElements"),
+    ("12.0.0", "a11y", "noHeaderScope"),
+    ("12.0.0", "a11y", "useHtmlLang"),
+    ("12.0.0", "correctness", "noConstructorReturn"),
+    ("12.0.0", "correctness", "noPrecisionLoss"),
+    ("12.0.0", "correctness", "noSetterReturn"),
+    ("12.0.0", "correctness", "noStringCaseMismatch"),
+    ("12.0.0", "correctness", "noUnsafeFinally"),
+    ("12.0.0", "correctness", "noVoidTypeReturn"),
+    ("12.0.0", "style", "noNonNullAssertion"),
+    ("12.0.0", "style", "noVar"),
+    ("12.0.0", "style", "useConst"),
+    ("12.0.0", "style", "useDefaultParameterLast"),
+    ("12.0.0", "style", "useEnumInitializers"),
+    ("12.0.0", "style", "useExponentiationOperator"),
+    ("12.0.0", "style", "useNumericLiterals"),
+    ("12.0.0", "suspicious", "noConstEnum"),
+    ("12.0.0", "suspicious", "noDuplicateObjectKeys"),
+    ("12.0.0", "suspicious", "noEmptyInterface"),
+    ("12.0.0", "suspicious", "noExtraNonNullAssertion"),
+    ("12.0.0", "suspicious", "noRedundantUseStrict"),
+    ("12.0.0", "suspicious", "useDefaultSwitchClauseLast"),
+    ("12.1.0", "a11y", "noNoninteractiveElementToInteractiveRole"),
+    ("12.1.0", "a11y", "noRedundantAlt"),
+    ("12.1.0", "a11y", "noSvgWithoutTitle"),
+    ("12.1.0", "a11y", "useAriaPropsForRole"),
+    ("12.1.0", "a11y", "useIframeTitle"),
+    ("12.1.0", "a11y", "useMediaCaption"),
+    ("12.1.0", "a11y", "useValidAriaProps"),
+    ("12.1.0", "a11y", "useValidLang"),
+    ("12.1.0", "complexity", "noUselessCatch"),
+    ("12.1.0", "complexity", "noUselessConstructor"),
+    ("12.1.0", "complexity", "noUselessRename"),
+    ("12.1.0", "complexity", "noUselessSwitchCase"),
+    ("12.1.0", "complexity", "noWith"),
+    ("12.1.0", "correctness", "noGlobalObjectCalls"),
+    ("12.1.0", "correctness", "noInnerDeclarations"),
+    ("12.1.0", "correctness", "noInvalidConstructorSuper"),
+    ("12.1.0", "correctness", "noSwitchDeclarations"),
+    ("12.1.0", "correctness", "noUnreachableSuper"),
+    ("12.1.0", "correctness", "noUnsafeOptionalChaining"),
+    ("12.1.0", "correctness", "noUnusedLabels"),
+    ("12.1.0", "correctness", "useYield"),
+    ("12.1.0", "style", "noCommaOperator"),
+    ("12.1.0", "style", "noInferrableTypes"),
+    ("12.1.0", "style", "noNamespace"),
+    ("12.1.0", "style", "noParameterAssign"),
+    ("12.1.0", "style", "noParameterProperties"),
+    ("12.1.0", "style", "noRestrictedGlobals"),
+    ("12.1.0", "suspicious", "noAssignInExpressions"),
+    ("12.1.0", "suspicious", "noClassAssign"),
+    ("12.1.0", "suspicious", "noConfusingLabels"),
+    ("12.1.0", "suspicious", "noDuplicateCase"),
+    ("12.1.0", "suspicious", "noDuplicateClassMembers"),
+    ("12.1.0", "suspicious", "noPrototypeBuiltins"),
+    ("12.1.0", "suspicious", "noRedeclare"),
+    ("12.1.0", "suspicious", "noSelfCompare"),
+    ("12.1.0", "suspicious", "useNamespaceKeyword"),
+    ("next", "a11y", "useHeadingContent"),
+    ("next", "complexity", "noForEach"),
+    ("next", "complexity", "useLiteralKeys"),
+    ("next", "complexity", "useSimpleNumberKeys"),
+    ("next", "correctness", "useIsNan"),
+    ("next", "suspicious", "noConsoleLog"),
+    ("next", "suspicious", "noDuplicateJsxProps"),
+];
+
+impl Rule for NurseryPromotions {
+    type Query = Ast<JsonMember>;
+    type State = RuleMove;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let entry = RuleEntry::from_member(ctx.query())?;
+        if entry.group_name != "nursery" {
+            return None;
+        }
+
+        PROMOTED_RULES
+            .iter()
+            .find(|(_, _, rule)| *rule == entry.rule_name)
+            .map(|(version, group, rule)| RuleMove {
+                version,
+                from: ("nursery", rule),
+                to: (group, rule),
+            })
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<MigrationAction> {
+        move_rule_action(ctx.query(), ctx.root(), state)
+    }
+}
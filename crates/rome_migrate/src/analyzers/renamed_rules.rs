@@ -0,0 +1,165 @@
+use crate::declare_migration;
+use crate::utils::{move_rule_action, RuleEntry, RuleMove};
+use crate::MigrationAction;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{Ast, Rule};
+use rome_json_syntax::JsonMember;
+
+declare_migration! {
+    /// Updates the configuration of the rules that were renamed, or moved to another group.
+    pub(crate) RenamedRules {
+        version: "11.0.0",
+        name: "renamedRules",
+    }
+}
+
+const RENAMED_RULES: &[RuleMove] = &[
+    RuleMove::new(
+        "11.0.0",
+        ("a11y", "useBlankTarget"),
+        ("a11y", "noBlankTarget"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noMultipleSpacesInRegularExpressionLiterals"),
+        ("complexity", "noMultipleSpacesInRegularExpressionLiterals"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("style", "useOptionalChain"),
+        ("complexity", "useOptionalChain"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noUselessFragments"),
+        ("complexity", "noUselessFragments"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noDelete"),
+        ("performance", "noDelete"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "useSingleCaseStatement"),
+        ("style", "useSingleCaseStatement"),
+    ),
+    RuleMove::new("11.0.0", ("correctness", "useWhile"), ("style", "useWhile")),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noArguments"),
+        ("style", "noArguments"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noAsyncPromiseExecutor"),
+        ("suspicious", "noAsyncPromiseExecutor"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noCommentText"),
+        ("suspicious", "noCommentText"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noCompareNegZero"),
+        ("suspicious", "noCompareNegZero"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noDebugger"),
+        ("suspicious", "noDebugger"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noDoubleEquals"),
+        ("suspicious", "noDoubleEquals"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noShadowRestrictedNames"),
+        ("suspicious", "noShadowRestrictedNames"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noSparseArray"),
+        ("suspicious", "noSparseArray"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noUnsafeNegation"),
+        ("suspicious", "noUnsafeNegation"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "useValidTypeof"),
+        ("suspicious", "useValidTypeof"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noArrayIndexKey"),
+        ("suspicious", "noArrayIndexKey"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noCatchAssign"),
+        ("suspicious", "noCatchAssign"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noDupeArgs"),
+        ("suspicious", "noDuplicateParameters"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noFunctionAssign"),
+        ("suspicious", "noFunctionAssign"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noImportAssign"),
+        ("suspicious", "noImportAssign"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noLabelVar"),
+        ("suspicious", "noLabelVar"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("correctness", "noRestrictedGlobals"),
+        ("nursery", "noRestrictedGlobals"),
+    ),
+    RuleMove::new(
+        "11.0.0",
+        ("nursery", "noDupeKeys"),
+        ("nursery", "noDuplicateObjectKeys"),
+    ),
+    RuleMove::new(
+        "12.1.0",
+        ("nursery", "noExtraLabels"),
+        ("complexity", "noUselessLabel"),
+    ),
+];
+
+impl Rule for RenamedRules {
+    type Query = Ast<JsonMember>;
+    type State = RuleMove;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let entry = RuleEntry::from_member(ctx.query())?;
+
+        RENAMED_RULES
+            .iter()
+            .find(|rule_move| {
+                rule_move.from == (entry.group_name.as_str(), entry.rule_name.as_str())
+            })
+            .copied()
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<MigrationAction> {
+        move_rule_action(ctx.query(), ctx.root(), state)
+    }
+}
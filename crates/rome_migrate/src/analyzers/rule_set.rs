@@ -1,25 +1,120 @@
-use crate::declare_migration;
+use crate::utils::{is_linter_rules, member_list, member_name, remove_member, string_literal};
+use crate::{declare_migration, MigrationAction};
 use rome_analyze::context::RuleContext;
-use rome_analyze::{Ast, Rule};
-use rome_json_syntax::JsonObjectValue;
+use rome_analyze::{ActionCategory, Ast, Rule};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_json_factory::make;
+use rome_json_syntax::{AnyJsonValue, JsonMember, JsonObjectValue, JsonSyntaxToken, T};
+use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
 
 declare_migration! {
+    /// Replaces the `ruleSet` preset of `linter.rules` with the options `recommended` and `all`.
+    ///
+    /// - `"ruleSet": "recommended"` becomes `"recommended": true`
+    /// - `"ruleSet": "all"` becomes `"all": true`
+    /// - `"ruleSet": "none"` becomes `"recommended": false`
+    ///
+    /// The `ruleSet` member is removed if the new option is already set.
     pub(crate) RuleSet {
         version: "11.0.0",
         name: "ruleSet",
     }
 }
 
+#[derive(Clone, Copy)]
+pub(crate) enum Preset {
+    Recommended,
+    All,
+    None,
+}
+
+impl Preset {
+    /// The option that replaces the preset, and its value
+    fn option(&self) -> (&'static str, bool) {
+        match self {
+            Preset::Recommended => ("recommended", true),
+            Preset::All => ("all", true),
+            Preset::None => ("recommended", false),
+        }
+    }
+}
+
 impl Rule for RuleSet {
-    type Query = Ast<JsonObjectValue>;
-    type State = ();
+    type Query = Ast<JsonMember>;
+    type State = Preset;
     type Signals = Option<Self::State>;
     type Options = ();
 
-    fn run(_: &RuleContext<Self>) -> Self::Signals {
-        // TODO: write rule to create a "ruleSet" config
-        // ruleSet -> "recommended", "all", "none" as a starter
-        // It should merge "recommended" and "all"
-        None
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if member_name(node)? != "ruleSet" {
+            return None;
+        }
+
+        let rules = node
+            .syntax()
+            .parent()?
+            .parent()
+            .and_then(JsonObjectValue::cast)?;
+        if !is_linter_rules(&rules) {
+            return None;
+        }
+
+        let value = node.value().ok()?;
+        let preset = value.as_json_string_value()?.inner_string_text().ok()?;
+        match preset.text() {
+            "recommended" => Some(Preset::Recommended),
+            "all" => Some(Preset::All),
+            "none" => Some(Preset::None),
+            _ => None,
+        }
+    }
+
+    fn action(ctx: &RuleContext<Self>, preset: &Self::State) -> Option<MigrationAction> {
+        let node = ctx.query();
+        let (option, enabled) = preset.option();
+        let list = node
+            .syntax()
+            .parent()
+            .and_then(rome_json_syntax::JsonMemberList::cast)?;
+
+        let mut mutation = ctx.root().begin();
+        let is_option_set = list
+            .iter()
+            .flatten()
+            .any(|member| member_name(&member).as_deref() == Some(option));
+
+        if is_option_set {
+            let members = remove_member(&list, node)?;
+            mutation.replace_node_discard_trivia(list.clone(), member_list(members, &list));
+        } else {
+            let name = node.name().ok()?.value_token().ok()?;
+            let value = node.value().ok()?;
+            let value_token = value.syntax().first_token()?;
+            let boolean = if enabled {
+                JsonSyntaxToken::new_detached(T![true], "true", [], [])
+            } else {
+                JsonSyntaxToken::new_detached(T![false], "false", [], [])
+            }
+            .with_leading_trivia_pieces(value_token.leading_trivia().pieces())
+            .with_trailing_trivia_pieces(value_token.trailing_trivia().pieces());
+
+            let new_node = node
+                .clone()
+                .with_name(make::json_member_name(string_literal(option, &name)))
+                .with_value(AnyJsonValue::from(make::json_boolean_value(boolean)));
+            mutation.replace_node_discard_trivia(node.clone(), new_node);
+        }
+
+        Some(MigrationAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! {
+                "Replace the preset "<Emphasis>"ruleSet"</Emphasis>" with the option "<Emphasis>{option}</Emphasis>"."
+            }
+            .to_owned(),
+            mutation,
+        })
     }
 }
@@ -1,18 +1,21 @@
 mod analyzers;
 mod macros;
 mod registry;
+mod utils;
 
 use crate::registry::visit_migration_registry;
 pub use rome_analyze::ControlFlow;
 use rome_analyze::{
     AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, InspectMatcher,
-    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleRegistry,
+    LanguageRoot, MatchQueryParams, MetadataRegistry, RuleAction, RuleRegistry,
 };
 use rome_diagnostics::Error;
 use rome_json_syntax::JsonLanguage;
 use std::convert::Infallible;
 use std::path::{Path, PathBuf};
 
+pub(crate) type MigrationAction = RuleAction<JsonLanguage>;
+
 /// Return the static [MetadataRegistry] for the JS analyzer rules
 pub fn metadata() -> &'static MetadataRegistry {
     lazy_static::lazy_static! {
@@ -98,8 +101,126 @@ mod test {
     use crate::migrate_configuration;
     use rome_analyze::{ControlFlow, Never};
     use rome_json_parser::{parse_json, JsonParserOptions};
+    use rome_json_syntax::JsonRoot;
+    use rome_rowan::AstNode;
     use std::path::Path;
 
+    /// Applies the first action of the migrations until the configuration is up to date
+    fn migrate(source: &str) -> String {
+        let mut tree = parse_json(source, JsonParserOptions::default()).tree();
+        loop {
+            let (action, _) =
+                migrate_configuration(&tree.value().unwrap(), Path::new("rome.json"), |signal| {
+                    match signal.actions().next() {
+                        Some(action) => ControlFlow::Break(action),
+                        None => ControlFlow::Continue(()),
+                    }
+                });
+
+            match action {
+                Some(action) => tree = JsonRoot::cast(action.mutation.commit()).unwrap(),
+                None => return tree.to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn rule_set_presets() {
+        assert_eq!(
+            migrate(r#"{ "linter": { "rules": { "ruleSet": "recommended" } } }"#),
+            r#"{ "linter": { "rules": { "recommended": true } } }"#
+        );
+        assert_eq!(
+            migrate(r#"{ "linter": { "rules": { "ruleSet": "all" } } }"#),
+            r#"{ "linter": { "rules": { "all": true } } }"#
+        );
+        assert_eq!(
+            migrate(r#"{ "linter": { "rules": { "ruleSet": "none" } } }"#),
+            r#"{ "linter": { "rules": { "recommended": false } } }"#
+        );
+        assert_eq!(
+            migrate(r#"{ "linter": { "rules": { "all": false, "ruleSet": "all" } } }"#),
+            r#"{ "linter": { "rules": { "all": false } } }"#
+        );
+    }
+
+    #[test]
+    fn rule_set_outside_of_rules() {
+        let source = r#"{ "formatter": { "ruleSet": "all" } }"#;
+        assert_eq!(migrate(source), source);
+    }
+
+    #[test]
+    fn renamed_rules() {
+        assert_eq!(
+            migrate(r#"{ "linter": { "rules": { "a11y": { "useBlankTarget": "error" } } } }"#),
+            r#"{ "linter": { "rules": { "a11y": { "noBlankTarget": "error" } } } }"#
+        );
+        assert_eq!(
+            migrate(
+                r#"{ "linter": { "rules": { "correctness": { "noDebugger": "off", "noDupeArgs": "warn" }, "style": {} } } }"#
+            ),
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off", "noDuplicateParameters": "warn" }, "style": {} } } }"#
+        );
+    }
+
+    #[test]
+    fn renamed_rule_already_configured() {
+        assert_eq!(
+            migrate(
+                r#"{ "linter": { "rules": { "correctness": { "noDebugger": "off" }, "suspicious": { "noDebugger": "error" } } } }"#
+            ),
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#
+        );
+    }
+
+    #[test]
+    fn nursery_promotions() {
+        let source = r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noConstAssign": "error",
+        "useFlatMap": "warn"
+      },
+      "style": {
+        "noArguments": "off"
+      }
+    }
+  }
+}"#;
+        let expected = r#"{
+  "linter": {
+    "rules": {
+      "complexity": {
+        "useFlatMap": "warn"
+      },
+      "correctness": {
+        "noConstAssign": "error"
+      },
+      "style": {
+        "noArguments": "off"
+      }
+    }
+  }
+}"#;
+        assert_eq!(migrate(source), expected);
+    }
+
+    #[test]
+    fn renamed_then_promoted_rule() {
+        assert_eq!(
+            migrate(r#"{ "linter": { "rules": { "nursery": { "noDupeKeys": "error" } } } }"#),
+            r#"{ "linter": { "rules": { "suspicious": { "noDuplicateObjectKeys": "error" } } } }"#
+        );
+    }
+
+    #[test]
+    fn rules_outside_of_linter() {
+        let source = r#"{ "rules": { "nursery": { "noConstAssign": "error" } } }"#;
+        assert_eq!(migrate(source), source);
+    }
+
     #[test]
     #[ignore]
     fn smoke() {
@@ -1,16 +1,17 @@
 #[macro_export]
 macro_rules! declare_migration {
-    (  $vis:vis $id:ident {
+    ( $( #[doc = $doc:literal] )* $vis:vis $id:ident {
         version: $version:literal,
         name: $name:tt,
         $( $key:ident: $value:expr, )*
     } ) => {
+        $( #[doc = $doc] )*
         $vis enum $id {}
 
         impl rome_analyze::RuleMeta for $id {
             type Group = $crate::analyzers::MigrationGroup;
             const METADATA: rome_analyze::RuleMetadata =
-                rome_analyze::RuleMetadata::new($version, $name, "") $( .$key($value) )*;
+                rome_analyze::RuleMetadata::new($version, $name, concat!( $( $doc, "\n", )* )) $( .$key($value) )*;
         }
 
         // Declare a new `rule_category!` macro in the module context that
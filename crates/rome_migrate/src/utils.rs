@@ -0,0 +1,332 @@
+use crate::MigrationAction;
+use rome_analyze::ActionCategory;
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_json_factory::make;
+use rome_json_syntax::{
+    AnyJsonValue, JsonMember, JsonMemberList, JsonObjectValue, JsonRoot, JsonSyntaxKind,
+    JsonSyntaxToken, T,
+};
+use rome_rowan::{
+    AstNode, AstSeparatedList, BatchMutation, BatchMutationExt, TriviaPiece, TriviaPieceKind,
+};
+
+/// A rule that was renamed, or moved to another group, in the given version of Rome
+#[derive(Clone, Copy)]
+pub(crate) struct RuleMove {
+    pub(crate) version: &'static str,
+    /// The old group and name of the rule
+    pub(crate) from: (&'static str, &'static str),
+    /// The new group and name of the rule
+    pub(crate) to: (&'static str, &'static str),
+}
+
+impl RuleMove {
+    pub(crate) const fn new(
+        version: &'static str,
+        from: (&'static str, &'static str),
+        to: (&'static str, &'static str),
+    ) -> Self {
+        Self { version, from, to }
+    }
+}
+
+/// Returns the action that moves the configuration of the rule `member` as described by `rule_move`
+pub(crate) fn move_rule_action(
+    member: &JsonMember,
+    root: AnyJsonValue,
+    rule_move: &RuleMove,
+) -> Option<MigrationAction> {
+    let entry = RuleEntry::from_member(member)?;
+    let mut mutation = root.begin();
+    entry.move_to(&mut mutation, rule_move.to)?;
+
+    let (from_group, from_rule) = rule_move.from;
+    let (to_group, to_rule) = rule_move.to;
+    let version = rule_move.version;
+    let message = if version == "next" {
+        markup! {
+            "Move the rule "<Emphasis>{from_group}"/"{from_rule}</Emphasis>" to "<Emphasis>{to_group}"/"{to_rule}</Emphasis>"."
+        }
+        .to_owned()
+    } else {
+        markup! {
+            "Move the rule "<Emphasis>{from_group}"/"{from_rule}</Emphasis>" to "<Emphasis>{to_group}"/"{to_rule}</Emphasis>", it changed in Rome "{version}"."
+        }
+        .to_owned()
+    };
+
+    Some(MigrationAction {
+        category: ActionCategory::QuickFix,
+        applicability: Applicability::Always,
+        message,
+        mutation,
+    })
+}
+
+/// Returns the name of a member, without quotes
+pub(crate) fn member_name(member: &JsonMember) -> Option<String> {
+    Some(
+        member
+            .name()
+            .ok()?
+            .inner_string_text()
+            .ok()?
+            .text()
+            .to_string(),
+    )
+}
+
+/// Returns the object that contains `member`
+fn enclosing_object(member: &JsonMember) -> Option<JsonObjectValue> {
+    member
+        .syntax()
+        .parent()?
+        .parent()
+        .and_then(JsonObjectValue::cast)
+}
+
+/// Returns the member whose value is `object`
+fn enclosing_member(object: &JsonObjectValue) -> Option<JsonMember> {
+    object.syntax().parent().and_then(JsonMember::cast)
+}
+
+/// Returns `true` if `object` is the value of `linter.rules`
+pub(crate) fn is_linter_rules(object: &JsonObjectValue) -> bool {
+    let is_linter_rules = || -> Option<bool> {
+        let rules = enclosing_member(object)?;
+        let linter = enclosing_member(&enclosing_object(&rules)?)?;
+        let root = enclosing_object(&linter)?;
+
+        Some(
+            member_name(&rules)? == "rules"
+                && member_name(&linter)? == "linter"
+                && root
+                    .syntax()
+                    .parent()
+                    .map_or(false, |root| JsonRoot::can_cast(root.kind())),
+        )
+    };
+
+    is_linter_rules().unwrap_or(false)
+}
+
+/// A rule configured in `linter.rules`, like `"noDebugger": "error"`
+pub(crate) struct RuleEntry {
+    pub(crate) group_name: String,
+    pub(crate) rule_name: String,
+    /// The member of the rule
+    pub(crate) rule: JsonMember,
+    /// The member of the group that contains the rule
+    pub(crate) group: JsonMember,
+    /// The value of `linter.rules`
+    pub(crate) rules: JsonObjectValue,
+}
+
+impl RuleEntry {
+    pub(crate) fn from_member(rule: &JsonMember) -> Option<Self> {
+        let group = enclosing_member(&enclosing_object(rule)?)?;
+        let rules = enclosing_object(&group)?;
+        if !is_linter_rules(&rules) {
+            return None;
+        }
+
+        Some(Self {
+            group_name: member_name(&group)?,
+            rule_name: member_name(rule)?,
+            rule: rule.clone(),
+            group,
+            rules,
+        })
+    }
+
+    /// Moves the rule to the group and the name of `to`.
+    ///
+    /// The group is created if it doesn't exist yet, and the old group is removed if
+    /// it doesn't contain any other rule. If the new rule is already configured, the old
+    /// rule is removed.
+    pub(crate) fn move_to(
+        &self,
+        mutation: &mut BatchMutation<rome_json_syntax::JsonLanguage>,
+        to: (&str, &str),
+    ) -> Option<()> {
+        let (to_group, to_rule) = to;
+        let moved_rule = if self.rule_name == to_rule {
+            self.rule.clone()
+        } else {
+            let name = self.rule.name().ok()?;
+            let token = string_literal(to_rule, &name.value_token().ok()?);
+            self.rule.clone().with_name(make::json_member_name(token))
+        };
+
+        let mut groups = Vec::new();
+        // The position where the new group is inserted, if it doesn't exist yet
+        let mut new_group_position = None;
+        let mut has_target_group = false;
+
+        for group in self.rules.json_member_list().iter() {
+            let group = group.ok()?;
+            let group_name = member_name(&group)?;
+
+            if group == self.group {
+                let rules = if group_name == to_group && !has_rule(&group, to_rule)? {
+                    object_members(&group)?
+                        .into_iter()
+                        .map(|rule| {
+                            if rule == self.rule {
+                                moved_rule.clone()
+                            } else {
+                                rule
+                            }
+                        })
+                        .collect()
+                } else {
+                    let object = group.value().ok()?;
+                    remove_member(
+                        &object.as_json_object_value()?.json_member_list(),
+                        &self.rule,
+                    )?
+                };
+
+                if group_name != to_group {
+                    new_group_position = Some(groups.len() + usize::from(!rules.is_empty()));
+                }
+                if !rules.is_empty() {
+                    groups.push(with_object_members(&group, rules)?);
+                }
+            } else if group_name == to_group {
+                has_target_group = true;
+                let mut rules = object_members(&group)?;
+                if !has_rule(&group, to_rule)? {
+                    rules.push(moved_rule.clone());
+                }
+                groups.push(with_object_members(&group, rules)?);
+            } else {
+                groups.push(group);
+            }
+        }
+
+        if let (false, Some(position)) = (has_target_group, new_group_position) {
+            // The new group looks like the old group
+            let old_name = self.group.name().ok()?.value_token().ok()?;
+            let old_object = self.group.value().ok()?;
+            let old_object = old_object.as_json_object_value()?;
+
+            let new_group = make::json_member(
+                make::json_member_name(string_literal(to_group, &old_name)),
+                self.group.colon_token().ok()?,
+                AnyJsonValue::from(
+                    make::json_object_value(
+                        old_object.l_curly_token().ok()?,
+                        old_object.r_curly_token().ok()?,
+                    )
+                    .with_json_member_list(member_list(
+                        vec![moved_rule],
+                        &old_object.json_member_list(),
+                    ))
+                    .build(),
+                ),
+            );
+            groups.insert(position, new_group);
+        }
+
+        let old_list = self.rules.json_member_list();
+        let new_list = member_list(groups, &old_list);
+        mutation.replace_node_discard_trivia(old_list, new_list);
+
+        Some(())
+    }
+}
+
+/// Returns the members of the object that is the value of `member`
+fn object_members(member: &JsonMember) -> Option<Vec<JsonMember>> {
+    member
+        .value()
+        .ok()?
+        .as_json_object_value()?
+        .json_member_list()
+        .iter()
+        .collect::<Result<_, _>>()
+        .ok()
+}
+
+/// Returns `true` if the object that is the value of `member` has a member called `name`
+fn has_rule(member: &JsonMember, name: &str) -> Option<bool> {
+    Some(
+        object_members(member)?
+            .iter()
+            .any(|rule| member_name(rule).as_deref() == Some(name)),
+    )
+}
+
+/// Returns the members of `list` without `removed`. When `removed` is the last member,
+/// its trailing trivia is moved to the new last member.
+pub(crate) fn remove_member(
+    list: &JsonMemberList,
+    removed: &JsonMember,
+) -> Option<Vec<JsonMember>> {
+    let mut members: Vec<_> = list.iter().collect::<Result<Vec<_>, _>>().ok()?;
+    let is_last = members.last() == Some(removed);
+    members.retain(|member| member != removed);
+
+    if is_last {
+        if let Some(last) = members.pop() {
+            let trailing_trivia = removed.syntax().last_trailing_trivia()?;
+            members.push(last.with_trailing_trivia_pieces(trailing_trivia.pieces())?);
+        }
+    }
+
+    Some(members)
+}
+
+/// Returns a copy of `member` whose value is an object with the given members
+fn with_object_members(member: &JsonMember, members: Vec<JsonMember>) -> Option<JsonMember> {
+    let object = member.value().ok()?;
+    let object = object.as_json_object_value()?;
+    let list = member_list(members, &object.json_member_list());
+    Some(member.clone().with_value(AnyJsonValue::from(
+        object.clone().with_json_member_list(Some(list)),
+    )))
+}
+
+/// Creates a list of members separated by commas. The commas have the trivia of
+/// the first comma of `like`, so the list keeps the layout of the original list.
+///
+/// When `like` doesn't have any comma, the members that don't start on a new line
+/// are separated by a space.
+pub(crate) fn member_list(members: Vec<JsonMember>, like: &JsonMemberList) -> JsonMemberList {
+    let separator = like
+        .separators()
+        .next()
+        .and_then(|separator| separator.ok())
+        .unwrap_or_else(|| {
+            let is_multiline = members
+                .iter()
+                .skip(1)
+                .all(|member| member.syntax().has_leading_newline());
+            if is_multiline {
+                JsonSyntaxToken::new_detached(T![,], ",", [], [])
+            } else {
+                JsonSyntaxToken::new_detached(
+                    T![,],
+                    ", ",
+                    [],
+                    [TriviaPiece::new(TriviaPieceKind::Whitespace, 1)],
+                )
+            }
+        });
+    let separators = (1..members.len()).map(|_| separator.clone());
+    make::json_member_list(members, separators)
+}
+
+/// Creates the string literal `"text"`, with the trivia of `like`
+pub(crate) fn string_literal(text: &str, like: &JsonSyntaxToken) -> JsonSyntaxToken {
+    JsonSyntaxToken::new_detached(
+        JsonSyntaxKind::JSON_STRING_LITERAL,
+        &format!("\"{text}\""),
+        [],
+        [],
+    )
+    .with_leading_trivia_pieces(like.leading_trivia().pieces())
+    .with_trailing_trivia_pieces(like.trailing_trivia().pieces())
+}
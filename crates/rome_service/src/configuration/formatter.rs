@@ -136,7 +136,7 @@ impl TryFrom<FormatterConfiguration> for FormatSettings {
     }
 }
 
-fn deserialize_line_width<'de, D>(deserializer: D) -> Result<Option<LineWidth>, D::Error>
+pub(crate) fn deserialize_line_width<'de, D>(deserializer: D) -> Result<Option<LineWidth>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
@@ -1,5 +1,8 @@
-use crate::configuration::merge::MergeWith;
+use crate::configuration::merge::{unset, MergeWith};
 use bpaf::Bpaf;
+use rome_deserialize::StringSet;
+use rome_json_analyze::options::SortKeysOptions;
+use rome_json_formatter::context::{ObjectWrap, TrailingCommas};
 use serde::{Deserialize, Serialize};
 
 #[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Bpaf)]
@@ -9,10 +12,26 @@ pub struct JsonConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(external(json_parser), optional)]
     pub parser: Option<JsonParser>,
+
+    /// Formatting options specific to the JSON files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(pure(Default::default()))]
+    pub formatter: Option<JsonFormatter>,
+
+    /// A list of JSON Schemas used to validate the JSON files matching their patterns.
+    /// A file that declares its own `$schema` is validated against that schema instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(external(unset), hide)]
+    pub schemas: Option<Vec<JsonSchemaMapping>>,
+
+    /// Options of the code actions provided for the JSON files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(pure(Default::default()))]
+    pub assists: Option<JsonAssists>,
 }
 
 impl JsonConfiguration {
-    pub const KNOWN_KEYS: &'static [&'static str] = &["parser"];
+    pub const KNOWN_KEYS: &'static [&'static str] = &["parser", "formatter", "schemas", "assists"];
 }
 
 impl MergeWith<JsonConfiguration> for JsonConfiguration {
@@ -21,6 +40,55 @@ impl MergeWith<JsonConfiguration> for JsonConfiguration {
             let parser = self.parser.get_or_insert_with(JsonParser::default);
             parser.merge_with(other_parser);
         }
+        if let Some(other_formatter) = other.formatter {
+            let formatter = self.formatter.get_or_insert_with(JsonFormatter::default);
+            formatter.merge_with(other_formatter);
+        }
+        if let Some(schemas) = other.schemas {
+            self.schemas = Some(schemas);
+        }
+        if let Some(other_assists) = other.assists {
+            let assists = self.assists.get_or_insert_with(JsonAssists::default);
+            assists.merge_with(other_assists);
+        }
+    }
+}
+
+/// Associates a JSON Schema to the JSON files that match a list of patterns
+#[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct JsonSchemaMapping {
+    /// A list of Unix shell style patterns. The files that match these patterns are
+    /// validated against the schema.
+    pub include: StringSet,
+
+    /// The path to the JSON Schema file, relative to the working directory
+    pub schema: String,
+}
+
+impl JsonSchemaMapping {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["include", "schema"];
+}
+
+#[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsonAssists {
+    /// Options of the code action that sorts the keys of the objects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_keys: Option<SortKeysOptions>,
+}
+
+impl JsonAssists {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["sortKeys"];
+}
+
+impl MergeWith<JsonAssists> for JsonAssists {
+    fn merge_with(&mut self, other: JsonAssists) {
+        if let Some(sort_keys) = other.sort_keys {
+            self.sort_keys = Some(sort_keys);
+        }
     }
 }
 
@@ -37,10 +105,16 @@ pub struct JsonParser {
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Allow parsing comments in `.json` files
     pub allow_comments: Option<bool>,
+
+    #[bpaf(hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Allow parsing trailing commas in `.json` files
+    pub allow_trailing_commas: Option<bool>,
 }
 
 impl JsonParser {
-    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["allowComments"];
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] =
+        &["allowComments", "allowTrailingCommas"];
 }
 
 impl MergeWith<JsonParser> for JsonParser {
@@ -48,5 +122,47 @@ impl MergeWith<JsonParser> for JsonParser {
         if let Some(allow_comments) = other.allow_comments {
             self.allow_comments = Some(allow_comments);
         }
+        if let Some(allow_trailing_commas) = other.allow_trailing_commas {
+            self.allow_trailing_commas = Some(allow_trailing_commas);
+        }
+    }
+}
+
+#[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsonFormatter {
+    /// Print trailing commas in multi-line objects and arrays. They are only printed in
+    /// the JSONC files, like `.jsonc` files and `tsconfig.json`. Defaults to "none".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_commas: Option<TrailingCommas>,
+
+    /// Whether the formatter removes the unnecessary escapes of the strings,
+    /// for example `"\/"` becomes `"/"`. Defaults to true.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalize_quotes: Option<bool>,
+
+    /// Whether an object that fits on a single line is collapsed, or kept expanded when
+    /// the original source has a line break after its `{`. Defaults to "preserve".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_wrap: Option<ObjectWrap>,
+}
+
+impl JsonFormatter {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] =
+        &["trailingCommas", "normalizeQuotes", "objectWrap"];
+}
+
+impl MergeWith<JsonFormatter> for JsonFormatter {
+    fn merge_with(&mut self, other: JsonFormatter) {
+        if let Some(trailing_commas) = other.trailing_commas {
+            self.trailing_commas = Some(trailing_commas);
+        }
+        if let Some(normalize_quotes) = other.normalize_quotes {
+            self.normalize_quotes = Some(normalize_quotes);
+        }
+        if let Some(object_wrap) = other.object_wrap {
+            self.object_wrap = Some(object_wrap);
+        }
     }
 }
@@ -2,7 +2,7 @@
 mod rules;
 
 pub use crate::configuration::linter::rules::{rules, Rules};
-use crate::configuration::merge::MergeWith;
+use crate::configuration::merge::{unset, MergeWith};
 use crate::settings::LinterSettings;
 use crate::{ConfigurationDiagnostic, MatchOptions, Matcher, WorkspaceError};
 use bpaf::Bpaf;
@@ -26,7 +26,7 @@ pub struct LinterConfiguration {
 
     /// List of rules
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[bpaf(external, optional, hide)]
+    #[bpaf(external(unset), hide)]
     pub rules: Option<Rules>,
 
     /// A list of Unix shell style patterns. The formatter will ignore files/folders that will
@@ -41,6 +41,13 @@ impl MergeWith<LinterConfiguration> for LinterConfiguration {
         if let Some(enabled) = other.enabled {
             self.enabled = Some(enabled);
         }
+        if let Some(other_rules) = other.rules {
+            let rules = self.rules.get_or_insert_with(Rules::default);
+            rules.merge_with(other_rules);
+        }
+        if let Some(ignore) = other.ignore {
+            self.ignore = Some(ignore);
+        }
     }
 }
 
@@ -1,5 +1,6 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
+use crate::configuration::MergeWith;
 use crate::RuleConfiguration;
 use bpaf::Bpaf;
 use indexmap::IndexSet;
@@ -199,6 +200,83 @@ impl Rules {
             None
         }
     }
+    #[doc = r" Sets the configuration of the rule `rule_name` of the group `group`."]
+    #[doc = r""]
+    #[doc = r" It returns `false` if the rule doesn't exist."]
+    pub fn set_rule_configuration(
+        &mut self,
+        group: &str,
+        rule_name: &str,
+        configuration: RuleConfiguration,
+    ) -> bool {
+        match group {
+            "a11y" => {
+                if !A11y::has_rule(rule_name) {
+                    return false;
+                }
+                self.a11y
+                    .get_or_insert_with(A11y::default)
+                    .set_rule_configuration(rule_name, configuration)
+            }
+            "complexity" => {
+                if !Complexity::has_rule(rule_name) {
+                    return false;
+                }
+                self.complexity
+                    .get_or_insert_with(Complexity::default)
+                    .set_rule_configuration(rule_name, configuration)
+            }
+            "correctness" => {
+                if !Correctness::has_rule(rule_name) {
+                    return false;
+                }
+                self.correctness
+                    .get_or_insert_with(Correctness::default)
+                    .set_rule_configuration(rule_name, configuration)
+            }
+            "nursery" => {
+                if !Nursery::has_rule(rule_name) {
+                    return false;
+                }
+                self.nursery
+                    .get_or_insert_with(Nursery::default)
+                    .set_rule_configuration(rule_name, configuration)
+            }
+            "performance" => {
+                if !Performance::has_rule(rule_name) {
+                    return false;
+                }
+                self.performance
+                    .get_or_insert_with(Performance::default)
+                    .set_rule_configuration(rule_name, configuration)
+            }
+            "security" => {
+                if !Security::has_rule(rule_name) {
+                    return false;
+                }
+                self.security
+                    .get_or_insert_with(Security::default)
+                    .set_rule_configuration(rule_name, configuration)
+            }
+            "style" => {
+                if !Style::has_rule(rule_name) {
+                    return false;
+                }
+                self.style
+                    .get_or_insert_with(Style::default)
+                    .set_rule_configuration(rule_name, configuration)
+            }
+            "suspicious" => {
+                if !Suspicious::has_rule(rule_name) {
+                    return false;
+                }
+                self.suspicious
+                    .get_or_insert_with(Suspicious::default)
+                    .set_rule_configuration(rule_name, configuration)
+            }
+            _ => false,
+        }
+    }
     pub(crate) const fn is_recommended(&self) -> bool { !matches!(self.recommended, Some(false)) }
     pub(crate) const fn is_all(&self) -> bool { matches!(self.all, Some(true)) }
     pub(crate) const fn is_not_all(&self) -> bool { matches!(self.all, Some(false)) }
@@ -334,6 +412,57 @@ impl Rules {
         enabled_rules.difference(&disabled_rules).cloned().collect()
     }
 }
+impl MergeWith<Rules> for Rules {
+    #[doc = r" The groups and the rules configured in `other` replace the ones of this configuration"]
+    fn merge_with(&mut self, other: Rules) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(other_group) = other.a11y {
+            self.a11y
+                .get_or_insert_with(A11y::default)
+                .merge_with(other_group);
+        }
+        if let Some(other_group) = other.complexity {
+            self.complexity
+                .get_or_insert_with(Complexity::default)
+                .merge_with(other_group);
+        }
+        if let Some(other_group) = other.correctness {
+            self.correctness
+                .get_or_insert_with(Correctness::default)
+                .merge_with(other_group);
+        }
+        if let Some(other_group) = other.nursery {
+            self.nursery
+                .get_or_insert_with(Nursery::default)
+                .merge_with(other_group);
+        }
+        if let Some(other_group) = other.performance {
+            self.performance
+                .get_or_insert_with(Performance::default)
+                .merge_with(other_group);
+        }
+        if let Some(other_group) = other.security {
+            self.security
+                .get_or_insert_with(Security::default)
+                .merge_with(other_group);
+        }
+        if let Some(other_group) = other.style {
+            self.style
+                .get_or_insert_with(Style::default)
+                .merge_with(other_group);
+        }
+        if let Some(other_group) = other.suspicious {
+            self.suspicious
+                .get_or_insert_with(Suspicious::default)
+                .merge_with(other_group);
+        }
+    }
+}
 #[derive(Deserialize, Default, Serialize, Debug, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "camelCase", default)]
@@ -847,6 +976,118 @@ impl A11y {
             _ => None,
         }
     }
+    #[doc = r" Sets the configuration of the rule `rule_name`. It returns `false` if the rule"]
+    #[doc = r" doesn't belong to this group."]
+    pub(crate) fn set_rule_configuration(
+        &mut self,
+        rule_name: &str,
+        configuration: RuleConfiguration,
+    ) -> bool {
+        match rule_name {
+            "noAccessKey" => self.no_access_key = Some(configuration),
+            "noAutofocus" => self.no_autofocus = Some(configuration),
+            "noBlankTarget" => self.no_blank_target = Some(configuration),
+            "noDistractingElements" => self.no_distracting_elements = Some(configuration),
+            "noHeaderScope" => self.no_header_scope = Some(configuration),
+            "noNoninteractiveElementToInteractiveRole" => {
+                self.no_noninteractive_element_to_interactive_role = Some(configuration)
+            }
+            "noPositiveTabindex" => self.no_positive_tabindex = Some(configuration),
+            "noRedundantAlt" => self.no_redundant_alt = Some(configuration),
+            "noSvgWithoutTitle" => self.no_svg_without_title = Some(configuration),
+            "useAltText" => self.use_alt_text = Some(configuration),
+            "useAnchorContent" => self.use_anchor_content = Some(configuration),
+            "useAriaPropsForRole" => self.use_aria_props_for_role = Some(configuration),
+            "useButtonType" => self.use_button_type = Some(configuration),
+            "useHeadingContent" => self.use_heading_content = Some(configuration),
+            "useHtmlLang" => self.use_html_lang = Some(configuration),
+            "useIframeTitle" => self.use_iframe_title = Some(configuration),
+            "useKeyWithClickEvents" => self.use_key_with_click_events = Some(configuration),
+            "useKeyWithMouseEvents" => self.use_key_with_mouse_events = Some(configuration),
+            "useMediaCaption" => self.use_media_caption = Some(configuration),
+            "useValidAnchor" => self.use_valid_anchor = Some(configuration),
+            "useValidAriaProps" => self.use_valid_aria_props = Some(configuration),
+            "useValidLang" => self.use_valid_lang = Some(configuration),
+            _ => return false,
+        }
+        true
+    }
+}
+impl MergeWith<A11y> for A11y {
+    fn merge_with(&mut self, other: A11y) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(rule) = other.no_access_key {
+            self.no_access_key = Some(rule);
+        }
+        if let Some(rule) = other.no_autofocus {
+            self.no_autofocus = Some(rule);
+        }
+        if let Some(rule) = other.no_blank_target {
+            self.no_blank_target = Some(rule);
+        }
+        if let Some(rule) = other.no_distracting_elements {
+            self.no_distracting_elements = Some(rule);
+        }
+        if let Some(rule) = other.no_header_scope {
+            self.no_header_scope = Some(rule);
+        }
+        if let Some(rule) = other.no_noninteractive_element_to_interactive_role {
+            self.no_noninteractive_element_to_interactive_role = Some(rule);
+        }
+        if let Some(rule) = other.no_positive_tabindex {
+            self.no_positive_tabindex = Some(rule);
+        }
+        if let Some(rule) = other.no_redundant_alt {
+            self.no_redundant_alt = Some(rule);
+        }
+        if let Some(rule) = other.no_svg_without_title {
+            self.no_svg_without_title = Some(rule);
+        }
+        if let Some(rule) = other.use_alt_text {
+            self.use_alt_text = Some(rule);
+        }
+        if let Some(rule) = other.use_anchor_content {
+            self.use_anchor_content = Some(rule);
+        }
+        if let Some(rule) = other.use_aria_props_for_role {
+            self.use_aria_props_for_role = Some(rule);
+        }
+        if let Some(rule) = other.use_button_type {
+            self.use_button_type = Some(rule);
+        }
+        if let Some(rule) = other.use_heading_content {
+            self.use_heading_content = Some(rule);
+        }
+        if let Some(rule) = other.use_html_lang {
+            self.use_html_lang = Some(rule);
+        }
+        if let Some(rule) = other.use_iframe_title {
+            self.use_iframe_title = Some(rule);
+        }
+        if let Some(rule) = other.use_key_with_click_events {
+            self.use_key_with_click_events = Some(rule);
+        }
+        if let Some(rule) = other.use_key_with_mouse_events {
+            self.use_key_with_mouse_events = Some(rule);
+        }
+        if let Some(rule) = other.use_media_caption {
+            self.use_media_caption = Some(rule);
+        }
+        if let Some(rule) = other.use_valid_anchor {
+            self.use_valid_anchor = Some(rule);
+        }
+        if let Some(rule) = other.use_valid_aria_props {
+            self.use_valid_aria_props = Some(rule);
+        }
+        if let Some(rule) = other.use_valid_lang {
+            self.use_valid_lang = Some(rule);
+        }
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -1256,6 +1497,96 @@ impl Complexity {
             _ => None,
         }
     }
+    #[doc = r" Sets the configuration of the rule `rule_name`. It returns `false` if the rule"]
+    #[doc = r" doesn't belong to this group."]
+    pub(crate) fn set_rule_configuration(
+        &mut self,
+        rule_name: &str,
+        configuration: RuleConfiguration,
+    ) -> bool {
+        match rule_name {
+            "noExtraBooleanCast" => self.no_extra_boolean_cast = Some(configuration),
+            "noForEach" => self.no_for_each = Some(configuration),
+            "noMultipleSpacesInRegularExpressionLiterals" => {
+                self.no_multiple_spaces_in_regular_expression_literals = Some(configuration)
+            }
+            "noUselessCatch" => self.no_useless_catch = Some(configuration),
+            "noUselessConstructor" => self.no_useless_constructor = Some(configuration),
+            "noUselessFragments" => self.no_useless_fragments = Some(configuration),
+            "noUselessLabel" => self.no_useless_label = Some(configuration),
+            "noUselessRename" => self.no_useless_rename = Some(configuration),
+            "noUselessSwitchCase" => self.no_useless_switch_case = Some(configuration),
+            "noUselessTypeConstraint" => self.no_useless_type_constraint = Some(configuration),
+            "noWith" => self.no_with = Some(configuration),
+            "useFlatMap" => self.use_flat_map = Some(configuration),
+            "useLiteralKeys" => self.use_literal_keys = Some(configuration),
+            "useOptionalChain" => self.use_optional_chain = Some(configuration),
+            "useSimpleNumberKeys" => self.use_simple_number_keys = Some(configuration),
+            "useSimplifiedLogicExpression" => {
+                self.use_simplified_logic_expression = Some(configuration)
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+impl MergeWith<Complexity> for Complexity {
+    fn merge_with(&mut self, other: Complexity) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(rule) = other.no_extra_boolean_cast {
+            self.no_extra_boolean_cast = Some(rule);
+        }
+        if let Some(rule) = other.no_for_each {
+            self.no_for_each = Some(rule);
+        }
+        if let Some(rule) = other.no_multiple_spaces_in_regular_expression_literals {
+            self.no_multiple_spaces_in_regular_expression_literals = Some(rule);
+        }
+        if let Some(rule) = other.no_useless_catch {
+            self.no_useless_catch = Some(rule);
+        }
+        if let Some(rule) = other.no_useless_constructor {
+            self.no_useless_constructor = Some(rule);
+        }
+        if let Some(rule) = other.no_useless_fragments {
+            self.no_useless_fragments = Some(rule);
+        }
+        if let Some(rule) = other.no_useless_label {
+            self.no_useless_label = Some(rule);
+        }
+        if let Some(rule) = other.no_useless_rename {
+            self.no_useless_rename = Some(rule);
+        }
+        if let Some(rule) = other.no_useless_switch_case {
+            self.no_useless_switch_case = Some(rule);
+        }
+        if let Some(rule) = other.no_useless_type_constraint {
+            self.no_useless_type_constraint = Some(rule);
+        }
+        if let Some(rule) = other.no_with {
+            self.no_with = Some(rule);
+        }
+        if let Some(rule) = other.use_flat_map {
+            self.use_flat_map = Some(rule);
+        }
+        if let Some(rule) = other.use_literal_keys {
+            self.use_literal_keys = Some(rule);
+        }
+        if let Some(rule) = other.use_optional_chain {
+            self.use_optional_chain = Some(rule);
+        }
+        if let Some(rule) = other.use_simple_number_keys {
+            self.use_simple_number_keys = Some(rule);
+        }
+        if let Some(rule) = other.use_simplified_logic_expression {
+            self.use_simplified_logic_expression = Some(rule);
+        }
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -1869,6 +2200,134 @@ impl Correctness {
             _ => None,
         }
     }
+    #[doc = r" Sets the configuration of the rule `rule_name`. It returns `false` if the rule"]
+    #[doc = r" doesn't belong to this group."]
+    pub(crate) fn set_rule_configuration(
+        &mut self,
+        rule_name: &str,
+        configuration: RuleConfiguration,
+    ) -> bool {
+        match rule_name {
+            "noChildrenProp" => self.no_children_prop = Some(configuration),
+            "noConstAssign" => self.no_const_assign = Some(configuration),
+            "noConstructorReturn" => self.no_constructor_return = Some(configuration),
+            "noEmptyPattern" => self.no_empty_pattern = Some(configuration),
+            "noGlobalObjectCalls" => self.no_global_object_calls = Some(configuration),
+            "noInnerDeclarations" => self.no_inner_declarations = Some(configuration),
+            "noInvalidConstructorSuper" => self.no_invalid_constructor_super = Some(configuration),
+            "noNewSymbol" => self.no_new_symbol = Some(configuration),
+            "noPrecisionLoss" => self.no_precision_loss = Some(configuration),
+            "noRenderReturnValue" => self.no_render_return_value = Some(configuration),
+            "noSetterReturn" => self.no_setter_return = Some(configuration),
+            "noStringCaseMismatch" => self.no_string_case_mismatch = Some(configuration),
+            "noSwitchDeclarations" => self.no_switch_declarations = Some(configuration),
+            "noUndeclaredVariables" => self.no_undeclared_variables = Some(configuration),
+            "noUnnecessaryContinue" => self.no_unnecessary_continue = Some(configuration),
+            "noUnreachable" => self.no_unreachable = Some(configuration),
+            "noUnreachableSuper" => self.no_unreachable_super = Some(configuration),
+            "noUnsafeFinally" => self.no_unsafe_finally = Some(configuration),
+            "noUnsafeOptionalChaining" => self.no_unsafe_optional_chaining = Some(configuration),
+            "noUnusedLabels" => self.no_unused_labels = Some(configuration),
+            "noUnusedVariables" => self.no_unused_variables = Some(configuration),
+            "noVoidElementsWithChildren" => {
+                self.no_void_elements_with_children = Some(configuration)
+            }
+            "noVoidTypeReturn" => self.no_void_type_return = Some(configuration),
+            "useIsNan" => self.use_is_nan = Some(configuration),
+            "useValidForDirection" => self.use_valid_for_direction = Some(configuration),
+            "useYield" => self.use_yield = Some(configuration),
+            _ => return false,
+        }
+        true
+    }
+}
+impl MergeWith<Correctness> for Correctness {
+    fn merge_with(&mut self, other: Correctness) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(rule) = other.no_children_prop {
+            self.no_children_prop = Some(rule);
+        }
+        if let Some(rule) = other.no_const_assign {
+            self.no_const_assign = Some(rule);
+        }
+        if let Some(rule) = other.no_constructor_return {
+            self.no_constructor_return = Some(rule);
+        }
+        if let Some(rule) = other.no_empty_pattern {
+            self.no_empty_pattern = Some(rule);
+        }
+        if let Some(rule) = other.no_global_object_calls {
+            self.no_global_object_calls = Some(rule);
+        }
+        if let Some(rule) = other.no_inner_declarations {
+            self.no_inner_declarations = Some(rule);
+        }
+        if let Some(rule) = other.no_invalid_constructor_super {
+            self.no_invalid_constructor_super = Some(rule);
+        }
+        if let Some(rule) = other.no_new_symbol {
+            self.no_new_symbol = Some(rule);
+        }
+        if let Some(rule) = other.no_precision_loss {
+            self.no_precision_loss = Some(rule);
+        }
+        if let Some(rule) = other.no_render_return_value {
+            self.no_render_return_value = Some(rule);
+        }
+        if let Some(rule) = other.no_setter_return {
+            self.no_setter_return = Some(rule);
+        }
+        if let Some(rule) = other.no_string_case_mismatch {
+            self.no_string_case_mismatch = Some(rule);
+        }
+        if let Some(rule) = other.no_switch_declarations {
+            self.no_switch_declarations = Some(rule);
+        }
+        if let Some(rule) = other.no_undeclared_variables {
+            self.no_undeclared_variables = Some(rule);
+        }
+        if let Some(rule) = other.no_unnecessary_continue {
+            self.no_unnecessary_continue = Some(rule);
+        }
+        if let Some(rule) = other.no_unreachable {
+            self.no_unreachable = Some(rule);
+        }
+        if let Some(rule) = other.no_unreachable_super {
+            self.no_unreachable_super = Some(rule);
+        }
+        if let Some(rule) = other.no_unsafe_finally {
+            self.no_unsafe_finally = Some(rule);
+        }
+        if let Some(rule) = other.no_unsafe_optional_chaining {
+            self.no_unsafe_optional_chaining = Some(rule);
+        }
+        if let Some(rule) = other.no_unused_labels {
+            self.no_unused_labels = Some(rule);
+        }
+        if let Some(rule) = other.no_unused_variables {
+            self.no_unused_variables = Some(rule);
+        }
+        if let Some(rule) = other.no_void_elements_with_children {
+            self.no_void_elements_with_children = Some(rule);
+        }
+        if let Some(rule) = other.no_void_type_return {
+            self.no_void_type_return = Some(rule);
+        }
+        if let Some(rule) = other.use_is_nan {
+            self.use_is_nan = Some(rule);
+        }
+        if let Some(rule) = other.use_valid_for_direction {
+            self.use_valid_for_direction = Some(rule);
+        }
+        if let Some(rule) = other.use_yield {
+            self.use_yield = Some(rule);
+        }
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -1922,6 +2381,15 @@ pub struct Nursery {
     )]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_control_characters_in_regex: Option<RuleConfiguration>,
+    #[doc = "Disallow duplicate properties inside a declaration block."]
+    #[bpaf(
+        long("no-duplicate-css-properties"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_css_properties: Option<RuleConfiguration>,
     #[doc = "Disallow two keys with the same name inside a JSON object."]
     #[bpaf(
         long("no-duplicate-json-keys"),
@@ -1957,6 +2425,19 @@ pub struct Nursery {
     #[bpaf(long("no-global-is-nan"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_global_is_nan: Option<RuleConfiguration>,
+    #[doc = "Disallow fields of a package.json file that don't have the type expected by npm."]
+    #[bpaf(
+        long("no-invalid-package-json-fields"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_package_json_fields: Option<RuleConfiguration>,
+    #[doc = "Disallow comments in JSON files whose format doesn't allow them."]
+    #[bpaf(long("no-json-comments"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_json_comments: Option<RuleConfiguration>,
     #[doc = "Enforce that tabIndex is not assigned to non-interactive HTML elements."]
     #[bpaf(
         long("no-noninteractive-tabindex"),
@@ -1987,6 +2468,19 @@ pub struct Nursery {
     #[bpaf(long("no-static-only-class"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_static_only_class: Option<RuleConfiguration>,
+    #[doc = "Disallow unknown options inside the compilerOptions of a tsconfig.json file."]
+    #[bpaf(
+        long("no-unknown-compiler-options"),
+        argument("on|off|warn"),
+        optional,
+        hide
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_compiler_options: Option<RuleConfiguration>,
+    #[doc = "Disallow units that aren't defined by the CSS specifications."]
+    #[bpaf(long("no-unknown-css-units"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_css_units: Option<RuleConfiguration>,
     #[doc = "Disallow unsafe declaration merging between interfaces and classes."]
     #[bpaf(
         long("no-unsafe-declaration-merging"),
@@ -2065,26 +2559,35 @@ pub struct Nursery {
     #[bpaf(long("use-naming-convention"), argument("on|off|warn"), optional, hide)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_naming_convention: Option<RuleConfiguration>,
+    #[doc = "Enforce that the keys of the objects are sorted in the configured files."]
+    #[bpaf(long("use-sorted-json-keys"), argument("on|off|warn"), optional, hide)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_json_keys: Option<RuleConfiguration>,
 }
 impl Nursery {
     const GROUP_NAME: &'static str = "nursery";
-    pub(crate) const GROUP_RULES: [&'static str; 28] = [
+    pub(crate) const GROUP_RULES: [&'static str; 34] = [
         "noAccumulatingSpread",
         "noAriaUnsupportedElements",
         "noBannedTypes",
         "noConfusingArrow",
         "noConstantCondition",
         "noControlCharactersInRegex",
+        "noDuplicateCssProperties",
         "noDuplicateJsonKeys",
         "noExcessiveComplexity",
         "noFallthroughSwitchClause",
         "noGlobalIsFinite",
         "noGlobalIsNan",
+        "noInvalidPackageJsonFields",
+        "noJsonComments",
         "noNoninteractiveTabindex",
         "noNonoctalDecimalEscape",
         "noRedundantRoles",
         "noSelfAssign",
         "noStaticOnlyClass",
+        "noUnknownCompilerOptions",
+        "noUnknownCssUnits",
         "noUnsafeDeclarationMerging",
         "noUselessEmptyExport",
         "noVoid",
@@ -2097,19 +2600,25 @@ impl Nursery {
         "useIsArray",
         "useLiteralEnumMembers",
         "useNamingConvention",
+        "useSortedJsonKeys",
     ];
-    const RECOMMENDED_RULES: [&'static str; 18] = [
+    const RECOMMENDED_RULES: [&'static str; 23] = [
         "noAriaUnsupportedElements",
         "noBannedTypes",
         "noConstantCondition",
         "noControlCharactersInRegex",
+        "noDuplicateCssProperties",
         "noDuplicateJsonKeys",
         "noGlobalIsFinite",
         "noGlobalIsNan",
+        "noInvalidPackageJsonFields",
+        "noJsonComments",
         "noNonoctalDecimalEscape",
         "noRedundantRoles",
         "noSelfAssign",
         "noStaticOnlyClass",
+        "noUnknownCompilerOptions",
+        "noUnknownCssUnits",
         "noUnsafeDeclarationMerging",
         "noUselessEmptyExport",
         "useArrowFunction",
@@ -2118,27 +2627,32 @@ impl Nursery {
         "useIsArray",
         "useLiteralEnumMembers",
     ];
-    const RECOMMENDED_RULES_AS_FILTERS: [RuleFilter<'static>; 18] = [
+    const RECOMMENDED_RULES_AS_FILTERS: [RuleFilter<'static>; 23] = [
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]),
     ];
-    const ALL_RULES_AS_FILTERS: [RuleFilter<'static>; 28] = [
+    const ALL_RULES_AS_FILTERS: [RuleFilter<'static>; 34] = [
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
@@ -2167,6 +2681,12 @@ impl Nursery {
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]),
     ];
     #[doc = r" Retrieves the recommended rules"]
     pub(crate) fn is_recommended(&self) -> bool { matches!(self.recommended, Some(true)) }
@@ -2207,116 +2727,146 @@ impl Nursery {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
+        if let Some(rule) = self.no_duplicate_css_properties.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_excessive_complexity.as_ref() {
+        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.no_fallthrough_switch_clause.as_ref() {
+        if let Some(rule) = self.no_excessive_complexity.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.no_global_is_finite.as_ref() {
+        if let Some(rule) = self.no_fallthrough_switch_clause.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.no_global_is_nan.as_ref() {
+        if let Some(rule) = self.no_global_is_finite.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.no_noninteractive_tabindex.as_ref() {
+        if let Some(rule) = self.no_global_is_nan.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.no_nonoctal_decimal_escape.as_ref() {
+        if let Some(rule) = self.no_invalid_package_json_fields.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_redundant_roles.as_ref() {
+        if let Some(rule) = self.no_json_comments.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.no_self_assign.as_ref() {
+        if let Some(rule) = self.no_noninteractive_tabindex.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_static_only_class.as_ref() {
+        if let Some(rule) = self.no_nonoctal_decimal_escape.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_unsafe_declaration_merging.as_ref() {
+        if let Some(rule) = self.no_redundant_roles.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
-        if let Some(rule) = self.no_useless_empty_export.as_ref() {
+        if let Some(rule) = self.no_self_assign.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
             }
         }
-        if let Some(rule) = self.no_void.as_ref() {
+        if let Some(rule) = self.no_static_only_class.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
             }
         }
-        if let Some(rule) = self.use_aria_prop_types.as_ref() {
+        if let Some(rule) = self.no_unknown_compiler_options.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
             }
         }
-        if let Some(rule) = self.use_arrow_function.as_ref() {
+        if let Some(rule) = self.no_unknown_css_units.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
             }
         }
-        if let Some(rule) = self.use_exhaustive_dependencies.as_ref() {
+        if let Some(rule) = self.no_unsafe_declaration_merging.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
             }
         }
-        if let Some(rule) = self.use_grouped_type_import.as_ref() {
+        if let Some(rule) = self.no_useless_empty_export.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
             }
         }
-        if let Some(rule) = self.use_hook_at_top_level.as_ref() {
+        if let Some(rule) = self.no_void.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.use_aria_prop_types.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
             }
         }
-        if let Some(rule) = self.use_is_array.as_ref() {
+        if let Some(rule) = self.use_arrow_function.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
             }
         }
-        if let Some(rule) = self.use_literal_enum_members.as_ref() {
+        if let Some(rule) = self.use_exhaustive_dependencies.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
             }
         }
-        if let Some(rule) = self.use_naming_convention.as_ref() {
+        if let Some(rule) = self.use_grouped_type_import.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
             }
         }
+        if let Some(rule) = self.use_hook_at_top_level.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
+            }
+        }
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
+            }
+        }
+        if let Some(rule) = self.use_is_array.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
+            }
+        }
+        if let Some(rule) = self.use_literal_enum_members.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
+            }
+        }
+        if let Some(rule) = self.use_naming_convention.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_json_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
+            }
+        }
         index_set
     }
     pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
@@ -2351,116 +2901,146 @@ impl Nursery {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
+        if let Some(rule) = self.no_duplicate_css_properties.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_excessive_complexity.as_ref() {
+        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.no_fallthrough_switch_clause.as_ref() {
+        if let Some(rule) = self.no_excessive_complexity.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.no_global_is_finite.as_ref() {
+        if let Some(rule) = self.no_fallthrough_switch_clause.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.no_global_is_nan.as_ref() {
+        if let Some(rule) = self.no_global_is_finite.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.no_noninteractive_tabindex.as_ref() {
+        if let Some(rule) = self.no_global_is_nan.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.no_nonoctal_decimal_escape.as_ref() {
+        if let Some(rule) = self.no_invalid_package_json_fields.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_redundant_roles.as_ref() {
+        if let Some(rule) = self.no_json_comments.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.no_self_assign.as_ref() {
+        if let Some(rule) = self.no_noninteractive_tabindex.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_static_only_class.as_ref() {
+        if let Some(rule) = self.no_nonoctal_decimal_escape.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_unsafe_declaration_merging.as_ref() {
+        if let Some(rule) = self.no_redundant_roles.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
-        if let Some(rule) = self.no_useless_empty_export.as_ref() {
+        if let Some(rule) = self.no_self_assign.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
             }
         }
-        if let Some(rule) = self.no_void.as_ref() {
+        if let Some(rule) = self.no_static_only_class.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
             }
         }
-        if let Some(rule) = self.use_aria_prop_types.as_ref() {
+        if let Some(rule) = self.no_unknown_compiler_options.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
             }
         }
-        if let Some(rule) = self.use_arrow_function.as_ref() {
+        if let Some(rule) = self.no_unknown_css_units.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
             }
         }
-        if let Some(rule) = self.use_exhaustive_dependencies.as_ref() {
+        if let Some(rule) = self.no_unsafe_declaration_merging.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
             }
         }
-        if let Some(rule) = self.use_grouped_type_import.as_ref() {
+        if let Some(rule) = self.no_useless_empty_export.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
             }
         }
-        if let Some(rule) = self.use_hook_at_top_level.as_ref() {
+        if let Some(rule) = self.no_void.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.use_aria_prop_types.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
             }
         }
-        if let Some(rule) = self.use_is_array.as_ref() {
+        if let Some(rule) = self.use_arrow_function.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
             }
         }
-        if let Some(rule) = self.use_literal_enum_members.as_ref() {
+        if let Some(rule) = self.use_exhaustive_dependencies.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
             }
         }
-        if let Some(rule) = self.use_naming_convention.as_ref() {
+        if let Some(rule) = self.use_grouped_type_import.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
             }
         }
+        if let Some(rule) = self.use_hook_at_top_level.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
+            }
+        }
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
+            }
+        }
+        if let Some(rule) = self.use_is_array.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
+            }
+        }
+        if let Some(rule) = self.use_literal_enum_members.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
+            }
+        }
+        if let Some(rule) = self.use_naming_convention.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_json_keys.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
+            }
+        }
         index_set
     }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
@@ -2469,10 +3049,10 @@ impl Nursery {
     pub(crate) fn is_recommended_rule(rule_name: &str) -> bool {
         Self::RECOMMENDED_RULES.contains(&rule_name)
     }
-    pub(crate) fn recommended_rules_as_filters() -> [RuleFilter<'static>; 18] {
+    pub(crate) fn recommended_rules_as_filters() -> [RuleFilter<'static>; 23] {
         Self::RECOMMENDED_RULES_AS_FILTERS
     }
-    pub(crate) fn all_rules_as_filters() -> [RuleFilter<'static>; 28] { Self::ALL_RULES_AS_FILTERS }
+    pub(crate) fn all_rules_as_filters() -> [RuleFilter<'static>; 34] { Self::ALL_RULES_AS_FILTERS }
     #[doc = r" Select preset rules"]
     pub(crate) fn collect_preset_rules(
         &self,
@@ -2499,16 +3079,21 @@ impl Nursery {
             "noConfusingArrow" => self.no_confusing_arrow.as_ref(),
             "noConstantCondition" => self.no_constant_condition.as_ref(),
             "noControlCharactersInRegex" => self.no_control_characters_in_regex.as_ref(),
+            "noDuplicateCssProperties" => self.no_duplicate_css_properties.as_ref(),
             "noDuplicateJsonKeys" => self.no_duplicate_json_keys.as_ref(),
             "noExcessiveComplexity" => self.no_excessive_complexity.as_ref(),
             "noFallthroughSwitchClause" => self.no_fallthrough_switch_clause.as_ref(),
             "noGlobalIsFinite" => self.no_global_is_finite.as_ref(),
             "noGlobalIsNan" => self.no_global_is_nan.as_ref(),
+            "noInvalidPackageJsonFields" => self.no_invalid_package_json_fields.as_ref(),
+            "noJsonComments" => self.no_json_comments.as_ref(),
             "noNoninteractiveTabindex" => self.no_noninteractive_tabindex.as_ref(),
             "noNonoctalDecimalEscape" => self.no_nonoctal_decimal_escape.as_ref(),
             "noRedundantRoles" => self.no_redundant_roles.as_ref(),
             "noSelfAssign" => self.no_self_assign.as_ref(),
             "noStaticOnlyClass" => self.no_static_only_class.as_ref(),
+            "noUnknownCompilerOptions" => self.no_unknown_compiler_options.as_ref(),
+            "noUnknownCssUnits" => self.no_unknown_css_units.as_ref(),
             "noUnsafeDeclarationMerging" => self.no_unsafe_declaration_merging.as_ref(),
             "noUselessEmptyExport" => self.no_useless_empty_export.as_ref(),
             "noVoid" => self.no_void.as_ref(),
@@ -2521,9 +3106,174 @@ impl Nursery {
             "useIsArray" => self.use_is_array.as_ref(),
             "useLiteralEnumMembers" => self.use_literal_enum_members.as_ref(),
             "useNamingConvention" => self.use_naming_convention.as_ref(),
+            "useSortedJsonKeys" => self.use_sorted_json_keys.as_ref(),
             _ => None,
         }
     }
+    #[doc = r" Sets the configuration of the rule `rule_name`. It returns `false` if the rule"]
+    #[doc = r" doesn't belong to this group."]
+    pub(crate) fn set_rule_configuration(
+        &mut self,
+        rule_name: &str,
+        configuration: RuleConfiguration,
+    ) -> bool {
+        match rule_name {
+            "noAccumulatingSpread" => self.no_accumulating_spread = Some(configuration),
+            "noAriaUnsupportedElements" => self.no_aria_unsupported_elements = Some(configuration),
+            "noBannedTypes" => self.no_banned_types = Some(configuration),
+            "noConfusingArrow" => self.no_confusing_arrow = Some(configuration),
+            "noConstantCondition" => self.no_constant_condition = Some(configuration),
+            "noControlCharactersInRegex" => {
+                self.no_control_characters_in_regex = Some(configuration)
+            }
+            "noDuplicateCssProperties" => self.no_duplicate_css_properties = Some(configuration),
+            "noDuplicateJsonKeys" => self.no_duplicate_json_keys = Some(configuration),
+            "noExcessiveComplexity" => self.no_excessive_complexity = Some(configuration),
+            "noFallthroughSwitchClause" => self.no_fallthrough_switch_clause = Some(configuration),
+            "noGlobalIsFinite" => self.no_global_is_finite = Some(configuration),
+            "noGlobalIsNan" => self.no_global_is_nan = Some(configuration),
+            "noInvalidPackageJsonFields" => {
+                self.no_invalid_package_json_fields = Some(configuration)
+            }
+            "noJsonComments" => self.no_json_comments = Some(configuration),
+            "noNoninteractiveTabindex" => self.no_noninteractive_tabindex = Some(configuration),
+            "noNonoctalDecimalEscape" => self.no_nonoctal_decimal_escape = Some(configuration),
+            "noRedundantRoles" => self.no_redundant_roles = Some(configuration),
+            "noSelfAssign" => self.no_self_assign = Some(configuration),
+            "noStaticOnlyClass" => self.no_static_only_class = Some(configuration),
+            "noUnknownCompilerOptions" => self.no_unknown_compiler_options = Some(configuration),
+            "noUnknownCssUnits" => self.no_unknown_css_units = Some(configuration),
+            "noUnsafeDeclarationMerging" => {
+                self.no_unsafe_declaration_merging = Some(configuration)
+            }
+            "noUselessEmptyExport" => self.no_useless_empty_export = Some(configuration),
+            "noVoid" => self.no_void = Some(configuration),
+            "useAriaPropTypes" => self.use_aria_prop_types = Some(configuration),
+            "useArrowFunction" => self.use_arrow_function = Some(configuration),
+            "useExhaustiveDependencies" => self.use_exhaustive_dependencies = Some(configuration),
+            "useGroupedTypeImport" => self.use_grouped_type_import = Some(configuration),
+            "useHookAtTopLevel" => self.use_hook_at_top_level = Some(configuration),
+            "useImportRestrictions" => self.use_import_restrictions = Some(configuration),
+            "useIsArray" => self.use_is_array = Some(configuration),
+            "useLiteralEnumMembers" => self.use_literal_enum_members = Some(configuration),
+            "useNamingConvention" => self.use_naming_convention = Some(configuration),
+            "useSortedJsonKeys" => self.use_sorted_json_keys = Some(configuration),
+            _ => return false,
+        }
+        true
+    }
+}
+impl MergeWith<Nursery> for Nursery {
+    fn merge_with(&mut self, other: Nursery) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(rule) = other.no_accumulating_spread {
+            self.no_accumulating_spread = Some(rule);
+        }
+        if let Some(rule) = other.no_aria_unsupported_elements {
+            self.no_aria_unsupported_elements = Some(rule);
+        }
+        if let Some(rule) = other.no_banned_types {
+            self.no_banned_types = Some(rule);
+        }
+        if let Some(rule) = other.no_confusing_arrow {
+            self.no_confusing_arrow = Some(rule);
+        }
+        if let Some(rule) = other.no_constant_condition {
+            self.no_constant_condition = Some(rule);
+        }
+        if let Some(rule) = other.no_control_characters_in_regex {
+            self.no_control_characters_in_regex = Some(rule);
+        }
+        if let Some(rule) = other.no_duplicate_css_properties {
+            self.no_duplicate_css_properties = Some(rule);
+        }
+        if let Some(rule) = other.no_duplicate_json_keys {
+            self.no_duplicate_json_keys = Some(rule);
+        }
+        if let Some(rule) = other.no_excessive_complexity {
+            self.no_excessive_complexity = Some(rule);
+        }
+        if let Some(rule) = other.no_fallthrough_switch_clause {
+            self.no_fallthrough_switch_clause = Some(rule);
+        }
+        if let Some(rule) = other.no_global_is_finite {
+            self.no_global_is_finite = Some(rule);
+        }
+        if let Some(rule) = other.no_global_is_nan {
+            self.no_global_is_nan = Some(rule);
+        }
+        if let Some(rule) = other.no_invalid_package_json_fields {
+            self.no_invalid_package_json_fields = Some(rule);
+        }
+        if let Some(rule) = other.no_json_comments {
+            self.no_json_comments = Some(rule);
+        }
+        if let Some(rule) = other.no_noninteractive_tabindex {
+            self.no_noninteractive_tabindex = Some(rule);
+        }
+        if let Some(rule) = other.no_nonoctal_decimal_escape {
+            self.no_nonoctal_decimal_escape = Some(rule);
+        }
+        if let Some(rule) = other.no_redundant_roles {
+            self.no_redundant_roles = Some(rule);
+        }
+        if let Some(rule) = other.no_self_assign {
+            self.no_self_assign = Some(rule);
+        }
+        if let Some(rule) = other.no_static_only_class {
+            self.no_static_only_class = Some(rule);
+        }
+        if let Some(rule) = other.no_unknown_compiler_options {
+            self.no_unknown_compiler_options = Some(rule);
+        }
+        if let Some(rule) = other.no_unknown_css_units {
+            self.no_unknown_css_units = Some(rule);
+        }
+        if let Some(rule) = other.no_unsafe_declaration_merging {
+            self.no_unsafe_declaration_merging = Some(rule);
+        }
+        if let Some(rule) = other.no_useless_empty_export {
+            self.no_useless_empty_export = Some(rule);
+        }
+        if let Some(rule) = other.no_void {
+            self.no_void = Some(rule);
+        }
+        if let Some(rule) = other.use_aria_prop_types {
+            self.use_aria_prop_types = Some(rule);
+        }
+        if let Some(rule) = other.use_arrow_function {
+            self.use_arrow_function = Some(rule);
+        }
+        if let Some(rule) = other.use_exhaustive_dependencies {
+            self.use_exhaustive_dependencies = Some(rule);
+        }
+        if let Some(rule) = other.use_grouped_type_import {
+            self.use_grouped_type_import = Some(rule);
+        }
+        if let Some(rule) = other.use_hook_at_top_level {
+            self.use_hook_at_top_level = Some(rule);
+        }
+        if let Some(rule) = other.use_import_restrictions {
+            self.use_import_restrictions = Some(rule);
+        }
+        if let Some(rule) = other.use_is_array {
+            self.use_is_array = Some(rule);
+        }
+        if let Some(rule) = other.use_literal_enum_members {
+            self.use_literal_enum_members = Some(rule);
+        }
+        if let Some(rule) = other.use_naming_convention {
+            self.use_naming_convention = Some(rule);
+        }
+        if let Some(rule) = other.use_sorted_json_keys {
+            self.use_sorted_json_keys = Some(rule);
+        }
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -2610,6 +3360,32 @@ impl Performance {
             _ => None,
         }
     }
+    #[doc = r" Sets the configuration of the rule `rule_name`. It returns `false` if the rule"]
+    #[doc = r" doesn't belong to this group."]
+    pub(crate) fn set_rule_configuration(
+        &mut self,
+        rule_name: &str,
+        configuration: RuleConfiguration,
+    ) -> bool {
+        match rule_name {
+            "noDelete" => self.no_delete = Some(configuration),
+            _ => return false,
+        }
+        true
+    }
+}
+impl MergeWith<Performance> for Performance {
+    fn merge_with(&mut self, other: Performance) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(rule) = other.no_delete {
+            self.no_delete = Some(rule);
+        }
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -2733,6 +3509,38 @@ impl Security {
             _ => None,
         }
     }
+    #[doc = r" Sets the configuration of the rule `rule_name`. It returns `false` if the rule"]
+    #[doc = r" doesn't belong to this group."]
+    pub(crate) fn set_rule_configuration(
+        &mut self,
+        rule_name: &str,
+        configuration: RuleConfiguration,
+    ) -> bool {
+        match rule_name {
+            "noDangerouslySetInnerHtml" => self.no_dangerously_set_inner_html = Some(configuration),
+            "noDangerouslySetInnerHtmlWithChildren" => {
+                self.no_dangerously_set_inner_html_with_children = Some(configuration)
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+impl MergeWith<Security> for Security {
+    fn merge_with(&mut self, other: Security) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(rule) = other.no_dangerously_set_inner_html {
+            self.no_dangerously_set_inner_html = Some(rule);
+        }
+        if let Some(rule) = other.no_dangerously_set_inner_html_with_children {
+            self.no_dangerously_set_inner_html_with_children = Some(rule);
+        }
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -3320,6 +4128,132 @@ impl Style {
             _ => None,
         }
     }
+    #[doc = r" Sets the configuration of the rule `rule_name`. It returns `false` if the rule"]
+    #[doc = r" doesn't belong to this group."]
+    pub(crate) fn set_rule_configuration(
+        &mut self,
+        rule_name: &str,
+        configuration: RuleConfiguration,
+    ) -> bool {
+        match rule_name {
+            "noArguments" => self.no_arguments = Some(configuration),
+            "noCommaOperator" => self.no_comma_operator = Some(configuration),
+            "noImplicitBoolean" => self.no_implicit_boolean = Some(configuration),
+            "noInferrableTypes" => self.no_inferrable_types = Some(configuration),
+            "noNamespace" => self.no_namespace = Some(configuration),
+            "noNegationElse" => self.no_negation_else = Some(configuration),
+            "noNonNullAssertion" => self.no_non_null_assertion = Some(configuration),
+            "noParameterAssign" => self.no_parameter_assign = Some(configuration),
+            "noParameterProperties" => self.no_parameter_properties = Some(configuration),
+            "noRestrictedGlobals" => self.no_restricted_globals = Some(configuration),
+            "noShoutyConstants" => self.no_shouty_constants = Some(configuration),
+            "noUnusedTemplateLiteral" => self.no_unused_template_literal = Some(configuration),
+            "noVar" => self.no_var = Some(configuration),
+            "useBlockStatements" => self.use_block_statements = Some(configuration),
+            "useConst" => self.use_const = Some(configuration),
+            "useDefaultParameterLast" => self.use_default_parameter_last = Some(configuration),
+            "useEnumInitializers" => self.use_enum_initializers = Some(configuration),
+            "useExponentiationOperator" => self.use_exponentiation_operator = Some(configuration),
+            "useFragmentSyntax" => self.use_fragment_syntax = Some(configuration),
+            "useNumericLiterals" => self.use_numeric_literals = Some(configuration),
+            "useSelfClosingElements" => self.use_self_closing_elements = Some(configuration),
+            "useShorthandArrayType" => self.use_shorthand_array_type = Some(configuration),
+            "useSingleCaseStatement" => self.use_single_case_statement = Some(configuration),
+            "useSingleVarDeclarator" => self.use_single_var_declarator = Some(configuration),
+            "useTemplate" => self.use_template = Some(configuration),
+            "useWhile" => self.use_while = Some(configuration),
+            _ => return false,
+        }
+        true
+    }
+}
+impl MergeWith<Style> for Style {
+    fn merge_with(&mut self, other: Style) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(rule) = other.no_arguments {
+            self.no_arguments = Some(rule);
+        }
+        if let Some(rule) = other.no_comma_operator {
+            self.no_comma_operator = Some(rule);
+        }
+        if let Some(rule) = other.no_implicit_boolean {
+            self.no_implicit_boolean = Some(rule);
+        }
+        if let Some(rule) = other.no_inferrable_types {
+            self.no_inferrable_types = Some(rule);
+        }
+        if let Some(rule) = other.no_namespace {
+            self.no_namespace = Some(rule);
+        }
+        if let Some(rule) = other.no_negation_else {
+            self.no_negation_else = Some(rule);
+        }
+        if let Some(rule) = other.no_non_null_assertion {
+            self.no_non_null_assertion = Some(rule);
+        }
+        if let Some(rule) = other.no_parameter_assign {
+            self.no_parameter_assign = Some(rule);
+        }
+        if let Some(rule) = other.no_parameter_properties {
+            self.no_parameter_properties = Some(rule);
+        }
+        if let Some(rule) = other.no_restricted_globals {
+            self.no_restricted_globals = Some(rule);
+        }
+        if let Some(rule) = other.no_shouty_constants {
+            self.no_shouty_constants = Some(rule);
+        }
+        if let Some(rule) = other.no_unused_template_literal {
+            self.no_unused_template_literal = Some(rule);
+        }
+        if let Some(rule) = other.no_var {
+            self.no_var = Some(rule);
+        }
+        if let Some(rule) = other.use_block_statements {
+            self.use_block_statements = Some(rule);
+        }
+        if let Some(rule) = other.use_const {
+            self.use_const = Some(rule);
+        }
+        if let Some(rule) = other.use_default_parameter_last {
+            self.use_default_parameter_last = Some(rule);
+        }
+        if let Some(rule) = other.use_enum_initializers {
+            self.use_enum_initializers = Some(rule);
+        }
+        if let Some(rule) = other.use_exponentiation_operator {
+            self.use_exponentiation_operator = Some(rule);
+        }
+        if let Some(rule) = other.use_fragment_syntax {
+            self.use_fragment_syntax = Some(rule);
+        }
+        if let Some(rule) = other.use_numeric_literals {
+            self.use_numeric_literals = Some(rule);
+        }
+        if let Some(rule) = other.use_self_closing_elements {
+            self.use_self_closing_elements = Some(rule);
+        }
+        if let Some(rule) = other.use_shorthand_array_type {
+            self.use_shorthand_array_type = Some(rule);
+        }
+        if let Some(rule) = other.use_single_case_statement {
+            self.use_single_case_statement = Some(rule);
+        }
+        if let Some(rule) = other.use_single_var_declarator {
+            self.use_single_var_declarator = Some(rule);
+        }
+        if let Some(rule) = other.use_template {
+            self.use_template = Some(rule);
+        }
+        if let Some(rule) = other.use_while {
+            self.use_while = Some(rule);
+        }
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Clone, Bpaf)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
@@ -4068,4 +5002,160 @@ impl Suspicious {
             _ => None,
         }
     }
+    #[doc = r" Sets the configuration of the rule `rule_name`. It returns `false` if the rule"]
+    #[doc = r" doesn't belong to this group."]
+    pub(crate) fn set_rule_configuration(
+        &mut self,
+        rule_name: &str,
+        configuration: RuleConfiguration,
+    ) -> bool {
+        match rule_name {
+            "noArrayIndexKey" => self.no_array_index_key = Some(configuration),
+            "noAssignInExpressions" => self.no_assign_in_expressions = Some(configuration),
+            "noAsyncPromiseExecutor" => self.no_async_promise_executor = Some(configuration),
+            "noCatchAssign" => self.no_catch_assign = Some(configuration),
+            "noClassAssign" => self.no_class_assign = Some(configuration),
+            "noCommentText" => self.no_comment_text = Some(configuration),
+            "noCompareNegZero" => self.no_compare_neg_zero = Some(configuration),
+            "noConfusingLabels" => self.no_confusing_labels = Some(configuration),
+            "noConsoleLog" => self.no_console_log = Some(configuration),
+            "noConstEnum" => self.no_const_enum = Some(configuration),
+            "noDebugger" => self.no_debugger = Some(configuration),
+            "noDoubleEquals" => self.no_double_equals = Some(configuration),
+            "noDuplicateCase" => self.no_duplicate_case = Some(configuration),
+            "noDuplicateClassMembers" => self.no_duplicate_class_members = Some(configuration),
+            "noDuplicateJsxProps" => self.no_duplicate_jsx_props = Some(configuration),
+            "noDuplicateObjectKeys" => self.no_duplicate_object_keys = Some(configuration),
+            "noDuplicateParameters" => self.no_duplicate_parameters = Some(configuration),
+            "noEmptyInterface" => self.no_empty_interface = Some(configuration),
+            "noExplicitAny" => self.no_explicit_any = Some(configuration),
+            "noExtraNonNullAssertion" => self.no_extra_non_null_assertion = Some(configuration),
+            "noFunctionAssign" => self.no_function_assign = Some(configuration),
+            "noImportAssign" => self.no_import_assign = Some(configuration),
+            "noLabelVar" => self.no_label_var = Some(configuration),
+            "noPrototypeBuiltins" => self.no_prototype_builtins = Some(configuration),
+            "noRedeclare" => self.no_redeclare = Some(configuration),
+            "noRedundantUseStrict" => self.no_redundant_use_strict = Some(configuration),
+            "noSelfCompare" => self.no_self_compare = Some(configuration),
+            "noShadowRestrictedNames" => self.no_shadow_restricted_names = Some(configuration),
+            "noSparseArray" => self.no_sparse_array = Some(configuration),
+            "noUnsafeNegation" => self.no_unsafe_negation = Some(configuration),
+            "useDefaultSwitchClauseLast" => {
+                self.use_default_switch_clause_last = Some(configuration)
+            }
+            "useNamespaceKeyword" => self.use_namespace_keyword = Some(configuration),
+            "useValidTypeof" => self.use_valid_typeof = Some(configuration),
+            _ => return false,
+        }
+        true
+    }
+}
+impl MergeWith<Suspicious> for Suspicious {
+    fn merge_with(&mut self, other: Suspicious) {
+        if let Some(recommended) = other.recommended {
+            self.recommended = Some(recommended);
+        }
+        if let Some(all) = other.all {
+            self.all = Some(all);
+        }
+        if let Some(rule) = other.no_array_index_key {
+            self.no_array_index_key = Some(rule);
+        }
+        if let Some(rule) = other.no_assign_in_expressions {
+            self.no_assign_in_expressions = Some(rule);
+        }
+        if let Some(rule) = other.no_async_promise_executor {
+            self.no_async_promise_executor = Some(rule);
+        }
+        if let Some(rule) = other.no_catch_assign {
+            self.no_catch_assign = Some(rule);
+        }
+        if let Some(rule) = other.no_class_assign {
+            self.no_class_assign = Some(rule);
+        }
+        if let Some(rule) = other.no_comment_text {
+            self.no_comment_text = Some(rule);
+        }
+        if let Some(rule) = other.no_compare_neg_zero {
+            self.no_compare_neg_zero = Some(rule);
+        }
+        if let Some(rule) = other.no_confusing_labels {
+            self.no_confusing_labels = Some(rule);
+        }
+        if let Some(rule) = other.no_console_log {
+            self.no_console_log = Some(rule);
+        }
+        if let Some(rule) = other.no_const_enum {
+            self.no_const_enum = Some(rule);
+        }
+        if let Some(rule) = other.no_debugger {
+            self.no_debugger = Some(rule);
+        }
+        if let Some(rule) = other.no_double_equals {
+            self.no_double_equals = Some(rule);
+        }
+        if let Some(rule) = other.no_duplicate_case {
+            self.no_duplicate_case = Some(rule);
+        }
+        if let Some(rule) = other.no_duplicate_class_members {
+            self.no_duplicate_class_members = Some(rule);
+        }
+        if let Some(rule) = other.no_duplicate_jsx_props {
+            self.no_duplicate_jsx_props = Some(rule);
+        }
+        if let Some(rule) = other.no_duplicate_object_keys {
+            self.no_duplicate_object_keys = Some(rule);
+        }
+        if let Some(rule) = other.no_duplicate_parameters {
+            self.no_duplicate_parameters = Some(rule);
+        }
+        if let Some(rule) = other.no_empty_interface {
+            self.no_empty_interface = Some(rule);
+        }
+        if let Some(rule) = other.no_explicit_any {
+            self.no_explicit_any = Some(rule);
+        }
+        if let Some(rule) = other.no_extra_non_null_assertion {
+            self.no_extra_non_null_assertion = Some(rule);
+        }
+        if let Some(rule) = other.no_function_assign {
+            self.no_function_assign = Some(rule);
+        }
+        if let Some(rule) = other.no_import_assign {
+            self.no_import_assign = Some(rule);
+        }
+        if let Some(rule) = other.no_label_var {
+            self.no_label_var = Some(rule);
+        }
+        if let Some(rule) = other.no_prototype_builtins {
+            self.no_prototype_builtins = Some(rule);
+        }
+        if let Some(rule) = other.no_redeclare {
+            self.no_redeclare = Some(rule);
+        }
+        if let Some(rule) = other.no_redundant_use_strict {
+            self.no_redundant_use_strict = Some(rule);
+        }
+        if let Some(rule) = other.no_self_compare {
+            self.no_self_compare = Some(rule);
+        }
+        if let Some(rule) = other.no_shadow_restricted_names {
+            self.no_shadow_restricted_names = Some(rule);
+        }
+        if let Some(rule) = other.no_sparse_array {
+            self.no_sparse_array = Some(rule);
+        }
+        if let Some(rule) = other.no_unsafe_negation {
+            self.no_unsafe_negation = Some(rule);
+        }
+        if let Some(rule) = other.use_default_switch_clause_last {
+            self.use_default_switch_clause_last = Some(rule);
+        }
+        if let Some(rule) = other.use_namespace_keyword {
+            self.use_namespace_keyword = Some(rule);
+        }
+        if let Some(rule) = other.use_valid_typeof {
+            self.use_valid_typeof = Some(rule);
+        }
+    }
 }
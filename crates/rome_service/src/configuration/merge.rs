@@ -10,3 +10,12 @@ pub trait MergeWith<T> {
         }
     }
 }
+
+/// Parser of the options that can't be passed from the command line
+///
+/// The parser always returns `None`, so that the configuration of the command line doesn't replace
+/// these options once merged with the configuration file. A parser derived for a list or for the
+/// rules would return an empty value instead.
+pub(crate) fn unset<T: Clone + 'static>() -> impl bpaf::Parser<Option<T>> {
+    bpaf::pure(None)
+}
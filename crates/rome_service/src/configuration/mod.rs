@@ -9,7 +9,9 @@ pub mod javascript;
 pub mod json;
 pub mod linter;
 mod merge;
+pub mod nested;
 pub mod organize_imports;
+pub mod overrides;
 mod parse;
 pub mod vcs;
 
@@ -17,19 +19,20 @@ pub use crate::configuration::diagnostics::ConfigurationDiagnostic;
 use crate::configuration::generated::push_to_analyzer_rules;
 pub use crate::configuration::merge::MergeWith;
 use crate::configuration::organize_imports::{organize_imports, OrganizeImports};
+use crate::configuration::overrides::OverridePattern;
 use crate::configuration::vcs::{vcs_configuration, VcsConfiguration};
-use crate::settings::{LanguagesSettings, LinterSettings};
+use crate::settings::LanguagesSettings;
 use crate::{DynRef, WorkspaceError, VERSION};
 use bpaf::Bpaf;
 pub use formatter::{formatter_configuration, FormatterConfiguration, PlainIndentStyle};
 pub use javascript::{javascript_configuration, JavascriptConfiguration, JavascriptFormatter};
 pub use json::{json_configuration, JsonConfiguration};
 pub use linter::{linter_configuration, LinterConfiguration, RuleConfiguration, Rules};
-use rome_analyze::{AnalyzerConfiguration, AnalyzerRules};
+use rome_analyze::options::RuleOptions;
+use rome_analyze::{AnalyzerConfiguration, AnalyzerRules, RuleKey};
 use rome_deserialize::json::deserialize_from_json_str;
 use rome_deserialize::{Deserialized, StringSet};
 use rome_fs::{AutoSearchResult, FileSystem, OpenOptions};
-use rome_js_analyze::metadata;
 use rome_json_formatter::context::JsonFormatOptions;
 use rome_json_parser::{parse_json, JsonParserOptions};
 use serde::{Deserialize, Serialize};
@@ -88,6 +91,12 @@ pub struct Configuration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(hide)]
     pub extends: Option<StringSet>,
+
+    /// A list of configurations applied to the files that match their patterns, merged in order
+    /// onto the rest of the configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(pure(Default::default()))]
+    pub overrides: Option<Vec<OverridePattern>>,
 }
 
 impl Default for Configuration {
@@ -105,6 +114,7 @@ impl Default for Configuration {
             vcs: None,
             extends: None,
             json: None,
+            overrides: None,
         }
     }
 }
@@ -120,6 +130,7 @@ impl Configuration {
         "$schema",
         "organizeImports",
         "extends",
+        "overrides",
     ];
     pub fn is_formatter_disabled(&self) -> bool {
         self.formatter
@@ -158,12 +169,16 @@ impl MergeWith<Configuration> for Configuration {
         self.merge_with(other_configuration.formatter);
         // javascript
         self.merge_with(other_configuration.javascript);
+        // json
+        self.merge_with(other_configuration.json);
         // linter
         self.merge_with(other_configuration.linter);
         // organize imports
         self.merge_with(other_configuration.organize_imports);
         // VCS
         self.merge_with(other_configuration.vcs);
+        // overrides
+        self.merge_with(other_configuration.overrides);
     }
 }
 
@@ -175,6 +190,16 @@ impl MergeWith<Option<Configuration>> for Configuration {
     }
 }
 
+impl MergeWith<Option<Vec<OverridePattern>>> for Configuration {
+    /// The patterns of `other` are applied after the ones of this configuration
+    fn merge_with(&mut self, other: Option<Vec<OverridePattern>>) {
+        if let Some(other_overrides) = other {
+            let overrides = self.overrides.get_or_insert_with(Vec::new);
+            overrides.extend(other_overrides);
+        }
+    }
+}
+
 impl MergeWith<Option<VcsConfiguration>> for Configuration {
     fn merge_with(&mut self, other: Option<VcsConfiguration>) {
         if let Some(other_vcs) = other {
@@ -195,6 +220,15 @@ impl MergeWith<Option<OrganizeImports>> for Configuration {
     }
 }
 
+impl MergeWith<Option<JsonConfiguration>> for Configuration {
+    fn merge_with(&mut self, other: Option<JsonConfiguration>) {
+        if let Some(other_json) = other {
+            let json = self.json.get_or_insert_with(JsonConfiguration::default);
+            json.merge_with(other_json);
+        }
+    }
+}
+
 impl MergeWith<Option<LinterConfiguration>> for Configuration {
     fn merge_with(&mut self, other: Option<LinterConfiguration>) {
         if let Some(other_linter) = other {
@@ -419,7 +453,10 @@ pub fn create_config(
 /// The function needs access to a filter, in order to have an easy access to the [metadata] of the
 /// rules.
 ///
-/// The third argument is a closure that accepts a reference to `linter_settings`.
+/// The first argument is the list of rules applied to the file, usually the result of
+/// [WorkspaceSettings::as_rules].
+///
+/// The third argument is a closure that accepts a reference to `language_settings`.
 ///
 /// The closure is responsible to map the globals from the correct
 /// location of the settings.
@@ -433,7 +470,7 @@ pub fn create_config(
 /// settings.languages.javascript.globals = Some(["jQuery".to_string(), "React".to_string()].into());
 /// // map globals from JS language
 /// let analyzer_configuration =
-///     to_analyzer_configuration(&settings.linter, &settings.languages, |settings| {
+///     to_analyzer_configuration(settings.linter.rules.as_ref(), &settings.languages, |settings| {
 ///         if let Some(globals) = settings.javascript.globals.as_ref() {
 ///             globals
 ///                 .iter()
@@ -451,10 +488,11 @@ pub fn create_config(
 /// ```
 ///
 /// [WorkspaceSettings]: crate::settings::WorkspaceSettings
+/// [WorkspaceSettings::as_rules]: crate::settings::WorkspaceSettings::as_rules
 /// [metadata]: rome_analyze::RegistryRuleMetadata
 /// [configuration for the analyzer]: AnalyzerConfiguration
 pub fn to_analyzer_configuration<ToGlobals>(
-    linter_settings: &LinterSettings,
+    rules: Option<&Rules>,
     language_settings: &LanguagesSettings,
     to_globals: ToGlobals,
 ) -> AnalyzerConfiguration
@@ -465,10 +503,17 @@ where
 
     let mut analyzer_rules = AnalyzerRules::default();
 
-    if let Some(rules) = linter_settings.rules.as_ref() {
-        push_to_analyzer_rules(rules, metadata(), &mut analyzer_rules);
+    if let Some(rules) = rules {
+        push_to_analyzer_rules(rules, rome_js_analyze::metadata(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, rome_json_analyze::metadata(), &mut analyzer_rules);
     }
 
+    // The assists don't have an entry in the rules, their options come from the language settings
+    analyzer_rules.push_rule(
+        RuleKey::new("correctness", "sortKeys"),
+        RuleOptions::new(language_settings.json.linter.sort_keys.clone()),
+    );
+
     AnalyzerConfiguration {
         globals,
         rules: analyzer_rules,
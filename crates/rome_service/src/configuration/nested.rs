@@ -0,0 +1,187 @@
+use crate::workspace::UpdateSettingsParams;
+use crate::{Configuration, MergeWith, Workspace, WorkspaceError};
+use rome_deserialize::json::deserialize_from_json_str;
+use rome_diagnostics::{DiagnosticExt, Error};
+use rome_fs::{FileSystem, FileSystemExt};
+use rome_json_parser::JsonParserOptions;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// The content of a nested configuration file. The options that aren't written in the file are
+/// left unset instead of taking their default value, so that they keep the value of the parent
+/// directory once merged
+pub(crate) struct NestedConfiguration(pub(crate) Configuration);
+
+impl Default for NestedConfiguration {
+    fn default() -> Self {
+        Self(Configuration {
+            linter: None,
+            organize_imports: None,
+            ..Configuration::default()
+        })
+    }
+}
+
+/// Discovers the configuration files of the subdirectories of a project, and registers the
+/// settings of their directories in the workspace.
+///
+/// The configuration of a directory is the configuration of the root, merged with the
+/// configuration files found in its ancestors and then with its own configuration file. The
+/// files of a directory use the settings of the nearest directory that has a configuration file.
+#[derive(Debug)]
+pub struct NestedConfigurations {
+    /// The configuration of the root of the project, the nested configurations are merged onto it
+    root: Configuration,
+    /// The directory the relative paths are resolved from. The discovery stops at this
+    /// directory, that contains (or is below) the configuration file of the root
+    base_path: PathBuf,
+    /// The resolved configuration of each directory that was already visited, it's `None` when
+    /// neither the directory nor its ancestors have a configuration file
+    directories: Mutex<HashMap<PathBuf, Option<Arc<Configuration>>>>,
+}
+
+impl NestedConfigurations {
+    pub fn new(root: Configuration, base_path: PathBuf) -> Self {
+        Self {
+            root,
+            base_path,
+            directories: Mutex::default(),
+        }
+    }
+
+    /// Reads the configuration files of `directory` and of its ancestors that weren't visited
+    /// yet, and registers the settings of their directories in the workspace.
+    ///
+    /// Returns the diagnostics of the configuration files that couldn't be loaded, these files
+    /// are then ignored.
+    pub fn register(
+        &self,
+        fs: &dyn FileSystem,
+        workspace: &dyn Workspace,
+        directory: &Path,
+    ) -> Vec<Error> {
+        let mut diagnostics = Vec::new();
+        let mut directories = self.directories.lock().unwrap();
+        self.resolve(fs, workspace, directory, &mut directories, &mut diagnostics);
+        diagnostics
+    }
+
+    /// Returns the resolved configuration of the nearest configuration file of `directory`, or
+    /// `None` when the directory uses the configuration of the root
+    ///
+    /// The directory must have been [registered](NestedConfigurations::register) first.
+    pub fn get(&self, directory: &Path) -> Option<Arc<Configuration>> {
+        self.directories
+            .lock()
+            .unwrap()
+            .get(directory)
+            .cloned()
+            .flatten()
+    }
+
+    /// Removes the configurations resolved so far, the configuration files are read again the
+    /// next time their directory is registered
+    pub fn clear(&self) {
+        self.directories.lock().unwrap().clear();
+    }
+
+    fn resolve(
+        &self,
+        fs: &dyn FileSystem,
+        workspace: &dyn Workspace,
+        directory: &Path,
+        directories: &mut HashMap<PathBuf, Option<Arc<Configuration>>>,
+        diagnostics: &mut Vec<Error>,
+    ) -> Option<Arc<Configuration>> {
+        if self.is_root(directory) {
+            return None;
+        }
+        if let Some(configuration) = directories.get(directory) {
+            return configuration.clone();
+        }
+
+        let parent = match directory.parent() {
+            Some(parent) => self.resolve(fs, workspace, parent, directories, diagnostics),
+            None => None,
+        };
+
+        let configuration = match self.load(fs, directory) {
+            Ok(Some(nested)) => {
+                let mut configuration = parent.as_deref().unwrap_or(&self.root).clone();
+                configuration.merge_with(nested);
+
+                let result = workspace.update_settings(UpdateSettingsParams {
+                    configuration: configuration.clone(),
+                    directory: Some(directory.to_path_buf()),
+                });
+                match result {
+                    Ok(()) => Some(Arc::new(configuration)),
+                    Err(error) => {
+                        diagnostics.push(Error::from(error).with_file_path(
+                            self.configuration_path(fs, directory).display().to_string(),
+                        ));
+                        parent
+                    }
+                }
+            }
+            Ok(None) => parent,
+            Err(errors) => {
+                diagnostics.extend(errors);
+                parent
+            }
+        };
+
+        directories.insert(directory.to_path_buf(), configuration.clone());
+        configuration
+    }
+
+    /// The discovery stops at the base path, and at the root of the relative paths
+    fn is_root(&self, directory: &Path) -> bool {
+        if directory.is_absolute() {
+            directory == self.base_path || !directory.starts_with(&self.base_path)
+        } else {
+            directory.as_os_str().is_empty() || directory == Path::new(".")
+        }
+    }
+
+    fn configuration_path(&self, fs: &dyn FileSystem, directory: &Path) -> PathBuf {
+        self.base_path.join(directory).join(fs.config_name())
+    }
+
+    /// Reads the configuration file of `directory`, if it has one
+    fn load(
+        &self,
+        fs: &dyn FileSystem,
+        directory: &Path,
+    ) -> Result<Option<Configuration>, Vec<Error>> {
+        let path = self.configuration_path(fs, directory);
+        if !fs.path_exists(&path) {
+            return Ok(None);
+        }
+
+        let content = fs.open(&path).and_then(|mut file| {
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+            Ok(content)
+        });
+        let file_path = path.display().to_string();
+        let Ok(content) = content else {
+            return Err(vec![Error::from(WorkspaceError::cant_read_file(file_path))]);
+        };
+
+        let (configuration, errors) = deserialize_from_json_str::<NestedConfiguration>(
+            &content,
+            JsonParserOptions::default(),
+        )
+        .consume();
+        if errors.is_empty() {
+            Ok(Some(configuration.0))
+        } else {
+            Err(errors
+                .into_iter()
+                .map(|error| error.with_file_path(file_path.clone()))
+                .collect())
+        }
+    }
+}
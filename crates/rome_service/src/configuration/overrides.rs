@@ -0,0 +1,113 @@
+use crate::configuration::formatter::{deserialize_line_width, serialize_line_width};
+use crate::configuration::{JavascriptConfiguration, JsonConfiguration, PlainIndentStyle};
+use crate::Rules;
+use rome_deserialize::StringSet;
+use rome_formatter::LineWidth;
+use serde::{Deserialize, Serialize};
+
+/// A configuration applied to the files that match the patterns of `include`, and that don't
+/// match the patterns of `ignore`
+#[derive(Default, Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverridePattern {
+    /// A list of Unix shell style patterns. The configuration is applied to the files that match
+    /// these patterns, or to all the files when the list is omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<StringSet>,
+
+    /// A list of Unix shell style patterns. The configuration isn't applied to the files that
+    /// match these patterns.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore: Option<StringSet>,
+
+    /// The options of the formatter that change for these files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<OverrideFormatterConfiguration>,
+
+    /// The options of the linter that change for these files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linter: Option<OverrideLinterConfiguration>,
+
+    /// The options of the JavaScript language that change for these files. The options of the
+    /// formatter and of the parser are applied, and the globals are added to the top-level ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub javascript: Option<JavascriptConfiguration>,
+
+    /// The options of the JSON language that change for these files. The options of the
+    /// formatter and of the parser are applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json: Option<JsonConfiguration>,
+}
+
+impl OverridePattern {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &[
+        "include",
+        "ignore",
+        "formatter",
+        "linter",
+        "javascript",
+        "json",
+    ];
+}
+
+/// The options of the formatter that can be changed by an override. The options that are
+/// omitted keep the value of the top-level `formatter` configuration.
+#[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideFormatterConfiguration {
+    /// if `false`, the files aren't formatted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Stores whether formatting should be allowed to proceed if a given file
+    /// has syntax errors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format_with_errors: Option<bool>,
+
+    /// The indent style.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_style: Option<PlainIndentStyle>,
+
+    /// The size of the indentation, 2 by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_size: Option<u8>,
+
+    /// What's the max width of a line.
+    #[serde(
+        deserialize_with = "deserialize_line_width",
+        serialize_with = "serialize_line_width",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub line_width: Option<LineWidth>,
+}
+
+impl OverrideFormatterConfiguration {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &[
+        "enabled",
+        "formatWithErrors",
+        "indentStyle",
+        "indentSize",
+        "lineWidth",
+    ];
+}
+
+/// The options of the linter that can be changed by an override. The rules are merged with the
+/// rules of the top-level `linter` configuration.
+#[derive(Default, Debug, Deserialize, Serialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideLinterConfiguration {
+    /// if `false`, the files aren't linted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// List of rules
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Rules>,
+}
+
+impl OverrideLinterConfiguration {
+    pub(crate) const KNOWN_KEYS: &'static [&'static str] = &["enabled", "rules"];
+}
@@ -1,9 +1,13 @@
-use crate::configuration::json::{JsonConfiguration, JsonParser};
-
+use crate::configuration::json::{
+    JsonAssists, JsonConfiguration, JsonFormatter, JsonParser, JsonSchemaMapping,
+};
+use rome_console::markup;
 use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
-use rome_deserialize::{DeserializationDiagnostic, VisitNode};
-use rome_json_syntax::{JsonLanguage, JsonSyntaxNode};
-use rome_rowan::SyntaxNode;
+use rome_deserialize::{DeserializationDiagnostic, StringSet, VisitNode};
+use rome_json_analyze::options::SortKeysOptions;
+use rome_json_formatter::context::{ObjectWrap, TrailingCommas};
+use rome_json_syntax::{AnyJsonValue, JsonLanguage, JsonSyntaxNode};
+use rome_rowan::{AstNode, SyntaxNode};
 
 impl VisitJsonNode for JsonConfiguration {}
 
@@ -25,10 +29,117 @@ impl VisitNode<JsonLanguage> for JsonConfiguration {
         let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
         let name_text = name.text();
 
-        if let "parser" = name_text {
-            let mut parser = JsonParser::default();
-            self.map_to_object(&value, name_text, &mut parser, diagnostics)?;
-            self.parser = Some(parser);
+        match name_text {
+            "parser" => {
+                let mut parser = JsonParser::default();
+                self.map_to_object(&value, name_text, &mut parser, diagnostics)?;
+                self.parser = Some(parser);
+            }
+            "formatter" => {
+                let mut formatter = JsonFormatter::default();
+                self.map_to_object(&value, name_text, &mut formatter, diagnostics)?;
+                self.formatter = Some(formatter);
+            }
+            "schemas" => {
+                let mut schemas = JsonSchemaMappings::default();
+                self.map_to_array(&value, name_text, &mut schemas, diagnostics)?;
+                self.schemas = Some(schemas.0);
+            }
+            "assists" => {
+                let mut assists = JsonAssists::default();
+                self.map_to_object(&value, name_text, &mut assists, diagnostics)?;
+                self.assists = Some(assists);
+            }
+            _ => {}
+        }
+
+        Some(())
+    }
+}
+
+/// Collects the elements of the `schemas` array
+#[derive(Default)]
+struct JsonSchemaMappings(Vec<JsonSchemaMapping>);
+
+impl VisitJsonNode for JsonSchemaMappings {}
+impl VisitNode<JsonLanguage> for JsonSchemaMappings {
+    fn visit_array_member(
+        &mut self,
+        element: &JsonSyntaxNode,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let element = AnyJsonValue::cast_ref(element)?;
+        let mut mapping = JsonSchemaMapping::default();
+        self.map_to_object(&element, "schemas", &mut mapping, diagnostics)?;
+        if mapping.schema.is_empty() {
+            diagnostics.push(
+                DeserializationDiagnostic::new(markup!(
+                    "The field "<Emphasis>"schema"</Emphasis>" is mandatory"
+                ))
+                .with_range(element.range()),
+            )
+        } else {
+            self.0.push(mapping);
+        }
+        Some(())
+    }
+}
+
+impl VisitJsonNode for JsonSchemaMapping {}
+impl VisitNode<JsonLanguage> for JsonSchemaMapping {
+    fn visit_member_name(
+        &mut self,
+        node: &JsonSyntaxNode,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, JsonSchemaMapping::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        match name_text {
+            "include" => {
+                self.include = self
+                    .map_to_index_set_string(&value, name_text, diagnostics)
+                    .map(StringSet::new)?;
+            }
+            "schema" => {
+                self.schema = self.map_to_string(&value, name_text, diagnostics)?;
+            }
+            _ => {}
+        }
+        Some(())
+    }
+}
+
+impl VisitJsonNode for JsonAssists {}
+impl VisitNode<JsonLanguage> for JsonAssists {
+    fn visit_member_name(
+        &mut self,
+        node: &JsonSyntaxNode,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, JsonAssists::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "sortKeys" {
+            let mut sort_keys = SortKeysOptions::default();
+            self.map_to_object(&value, name_text, &mut sort_keys, diagnostics)?;
+            self.sort_keys = Some(sort_keys);
         }
 
         Some(())
@@ -53,8 +164,53 @@ impl VisitNode<JsonLanguage> for JsonParser {
     ) -> Option<()> {
         let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
         let name_text = name.text();
-        if name_text == "allowComments" {
-            self.allow_comments = self.map_to_boolean(&value, name_text, diagnostics);
+        match name_text {
+            "allowComments" => {
+                self.allow_comments = self.map_to_boolean(&value, name_text, diagnostics);
+            }
+            "allowTrailingCommas" => {
+                self.allow_trailing_commas = self.map_to_boolean(&value, name_text, diagnostics);
+            }
+            _ => {}
+        }
+
+        Some(())
+    }
+}
+
+impl VisitJsonNode for JsonFormatter {}
+impl VisitNode<JsonLanguage> for JsonFormatter {
+    fn visit_member_name(
+        &mut self,
+        node: &JsonSyntaxNode,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, JsonFormatter::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        match name_text {
+            "trailingCommas" => {
+                let mut trailing_commas = TrailingCommas::default();
+                self.map_to_known_string(&value, name_text, &mut trailing_commas, diagnostics)?;
+                self.trailing_commas = Some(trailing_commas);
+            }
+            "normalizeQuotes" => {
+                self.normalize_quotes = self.map_to_boolean(&value, name_text, diagnostics);
+            }
+            "objectWrap" => {
+                let mut object_wrap = ObjectWrap::default();
+                self.map_to_known_string(&value, name_text, &mut object_wrap, diagnostics)?;
+                self.object_wrap = Some(object_wrap);
+            }
+            _ => {}
         }
 
         Some(())
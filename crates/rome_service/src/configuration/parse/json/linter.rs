@@ -37,7 +37,12 @@ impl VisitNode<JsonLanguage> for LinterConfiguration {
                 self.enabled = self.map_to_boolean(&value, name_text, diagnostics);
             }
             "rules" => {
-                let mut rules = Rules::default();
+                // `recommended` is left unset, so that the rules of a nested configuration file
+                // don't enable the recommended rules disabled by its ancestors
+                let mut rules = Rules {
+                    recommended: None,
+                    ..Rules::default()
+                };
                 if are_recommended_and_all_correct(&value, name_text, diagnostics)? {
                     self.map_to_object(&value, name_text, &mut rules, diagnostics)?;
                     self.rules = Some(rules);
@@ -7,7 +7,9 @@ mod formatter;
 mod javascript;
 mod json_configuration;
 mod linter;
+mod nested;
 mod organize_imports;
+mod overrides;
 mod rules;
 mod vcs;
 
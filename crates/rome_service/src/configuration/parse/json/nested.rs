@@ -0,0 +1,80 @@
+use crate::configuration::nested::NestedConfiguration;
+use crate::configuration::organize_imports::OrganizeImports;
+use crate::configuration::{FormatterConfiguration, LinterConfiguration};
+use crate::Configuration;
+use rome_deserialize::json::{JsonDeserialize, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_json_syntax::{JsonLanguage, JsonMemberName, JsonRoot, JsonSyntaxNode};
+use rome_rowan::{AstNode, SyntaxNode};
+
+impl JsonDeserialize for NestedConfiguration {
+    fn deserialize_from_ast(
+        root: &JsonRoot,
+        visitor: &mut impl VisitJsonNode,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        Configuration::deserialize_from_ast(root, visitor, diagnostics)
+    }
+}
+
+impl VisitJsonNode for NestedConfiguration {}
+
+impl VisitNode<JsonLanguage> for NestedConfiguration {
+    fn visit_member_name(
+        &mut self,
+        node: &JsonSyntaxNode,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        self.0.visit_member_name(node, diagnostics)
+    }
+
+    /// The sections that have default values start empty, the other ones are read like the
+    /// sections of the root configuration
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let member_name = JsonMemberName::cast_ref(key)?.inner_string_text().ok()?;
+        match member_name.text() {
+            "formatter" => {
+                let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+                let mut formatter = FormatterConfiguration {
+                    enabled: None,
+                    format_with_errors: None,
+                    indent_style: None,
+                    indent_size: None,
+                    line_width: None,
+                    ignore: None,
+                };
+                self.map_to_object(&value, name.text(), &mut formatter, diagnostics)?;
+                self.0.formatter = Some(formatter);
+            }
+            "linter" => {
+                let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+                let mut linter = LinterConfiguration {
+                    enabled: None,
+                    rules: None,
+                    ignore: None,
+                };
+                self.map_to_object(&value, name.text(), &mut linter, diagnostics)?;
+                self.0.linter = Some(linter);
+            }
+            "organizeImports" => {
+                let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+                let mut organize_imports = OrganizeImports {
+                    enabled: None,
+                    ignore: None,
+                };
+                self.map_to_object(&value, name.text(), &mut organize_imports, diagnostics)?;
+                self.0.organize_imports = Some(organize_imports);
+            }
+            _ => {
+                self.0.visit_map(key, value, diagnostics)?;
+            }
+        }
+
+        Some(())
+    }
+}
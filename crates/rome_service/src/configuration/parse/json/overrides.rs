@@ -0,0 +1,189 @@
+use crate::configuration::overrides::{
+    OverrideFormatterConfiguration, OverrideLinterConfiguration, OverridePattern,
+};
+use crate::configuration::parse::json::linter::are_recommended_and_all_correct;
+use crate::configuration::{JavascriptConfiguration, JsonConfiguration, PlainIndentStyle};
+use crate::Rules;
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, StringSet, VisitNode};
+use rome_formatter::LineWidth;
+use rome_json_syntax::{AnyJsonValue, JsonLanguage, JsonSyntaxNode};
+use rome_rowan::{AstNode, SyntaxNode};
+
+/// Collects the elements of the `overrides` array
+#[derive(Default)]
+pub(crate) struct Overrides(pub(crate) Vec<OverridePattern>);
+
+impl VisitJsonNode for Overrides {}
+impl VisitNode<JsonLanguage> for Overrides {
+    fn visit_array_member(
+        &mut self,
+        element: &JsonSyntaxNode,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let element = AnyJsonValue::cast_ref(element)?;
+        let mut pattern = OverridePattern::default();
+        self.map_to_object(&element, "overrides", &mut pattern, diagnostics)?;
+        self.0.push(pattern);
+        Some(())
+    }
+}
+
+impl VisitJsonNode for OverridePattern {}
+impl VisitNode<JsonLanguage> for OverridePattern {
+    fn visit_member_name(
+        &mut self,
+        node: &JsonSyntaxNode,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, OverridePattern::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        match name_text {
+            "include" => {
+                self.include = self
+                    .map_to_index_set_string(&value, name_text, diagnostics)
+                    .map(StringSet::new);
+            }
+            "ignore" => {
+                self.ignore = self
+                    .map_to_index_set_string(&value, name_text, diagnostics)
+                    .map(StringSet::new);
+            }
+            "formatter" => {
+                let mut formatter = OverrideFormatterConfiguration::default();
+                self.map_to_object(&value, name_text, &mut formatter, diagnostics)?;
+                self.formatter = Some(formatter);
+            }
+            "linter" => {
+                let mut linter = OverrideLinterConfiguration::default();
+                self.map_to_object(&value, name_text, &mut linter, diagnostics)?;
+                self.linter = Some(linter);
+            }
+            "javascript" => {
+                let mut javascript = JavascriptConfiguration::default();
+                self.map_to_object(&value, name_text, &mut javascript, diagnostics)?;
+                self.javascript = Some(javascript);
+            }
+            "json" => {
+                let mut json = JsonConfiguration::default();
+                self.map_to_object(&value, name_text, &mut json, diagnostics)?;
+                self.json = Some(json);
+            }
+            _ => {}
+        }
+
+        Some(())
+    }
+}
+
+impl VisitJsonNode for OverrideFormatterConfiguration {}
+impl VisitNode<JsonLanguage> for OverrideFormatterConfiguration {
+    fn visit_member_name(
+        &mut self,
+        node: &JsonSyntaxNode,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(
+            node,
+            OverrideFormatterConfiguration::KNOWN_KEYS,
+            diagnostics,
+        )
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        match name_text {
+            "formatWithErrors" => {
+                self.format_with_errors = self.map_to_boolean(&value, name_text, diagnostics);
+            }
+            "enabled" => {
+                self.enabled = self.map_to_boolean(&value, name_text, diagnostics);
+            }
+            "indentStyle" => {
+                let mut indent_style = PlainIndentStyle::default();
+                self.map_to_known_string(&value, name_text, &mut indent_style, diagnostics)?;
+                self.indent_style = Some(indent_style);
+            }
+            "indentSize" => {
+                self.indent_size = self.map_to_u8(&value, name_text, u8::MAX, diagnostics);
+            }
+            "lineWidth" => {
+                let line_width = self.map_to_u16(&value, name_text, LineWidth::MAX, diagnostics)?;
+
+                self.line_width = Some(match LineWidth::try_from(line_width) {
+                    Ok(result) => result,
+                    Err(err) => {
+                        diagnostics.push(
+                            DeserializationDiagnostic::new(err.to_string())
+                                .with_range(value.range())
+                                .with_note(
+                                    markup! {"Maximum value accepted is "{{LineWidth::MAX}}},
+                                ),
+                        );
+                        LineWidth::default()
+                    }
+                });
+            }
+            _ => {}
+        }
+
+        Some(())
+    }
+}
+
+impl VisitJsonNode for OverrideLinterConfiguration {}
+impl VisitNode<JsonLanguage> for OverrideLinterConfiguration {
+    fn visit_member_name(
+        &mut self,
+        node: &JsonSyntaxNode,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, OverrideLinterConfiguration::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        match name_text {
+            "enabled" => {
+                self.enabled = self.map_to_boolean(&value, name_text, diagnostics);
+            }
+            "rules" => {
+                // The rules that aren't configured keep the configuration of the top-level linter,
+                // including the recommended ones
+                let mut rules = Rules {
+                    recommended: None,
+                    ..Rules::default()
+                };
+                if are_recommended_and_all_correct(&value, name_text, diagnostics)? {
+                    self.map_to_object(&value, name_text, &mut rules, diagnostics)?;
+                    self.rules = Some(rules);
+                }
+            }
+            _ => {}
+        }
+
+        Some(())
+    }
+}
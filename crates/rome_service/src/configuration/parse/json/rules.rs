@@ -1770,16 +1770,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                 "noConfusingArrow",
                 "noConstantCondition",
                 "noControlCharactersInRegex",
+                "noDuplicateCssProperties",
                 "noDuplicateJsonKeys",
                 "noExcessiveComplexity",
                 "noFallthroughSwitchClause",
                 "noGlobalIsFinite",
                 "noGlobalIsNan",
+                "noInvalidPackageJsonFields",
+                "noJsonComments",
                 "noNoninteractiveTabindex",
                 "noNonoctalDecimalEscape",
                 "noRedundantRoles",
                 "noSelfAssign",
                 "noStaticOnlyClass",
+                "noUnknownCompilerOptions",
+                "noUnknownCssUnits",
                 "noUnsafeDeclarationMerging",
                 "noUselessEmptyExport",
                 "noVoid",
@@ -1792,6 +1797,7 @@ impl VisitNode<JsonLanguage> for Nursery {
                 "useIsArray",
                 "useLiteralEnumMembers",
                 "useNamingConvention",
+                "useSortedJsonKeys",
             ],
             diagnostics,
         )
@@ -1949,6 +1955,29 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
+            "noDuplicateCssProperties" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_duplicate_css_properties = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noDuplicateCssProperties",
+                        diagnostics,
+                    )?;
+                    self.no_duplicate_css_properties = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
             "noDuplicateJsonKeys" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
@@ -2064,6 +2093,52 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
+            "noInvalidPackageJsonFields" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_invalid_package_json_fields = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noInvalidPackageJsonFields",
+                        diagnostics,
+                    )?;
+                    self.no_invalid_package_json_fields = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noJsonComments" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_json_comments = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noJsonComments",
+                        diagnostics,
+                    )?;
+                    self.no_json_comments = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
             "noNoninteractiveTabindex" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
@@ -2179,6 +2254,52 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
+            "noUnknownCompilerOptions" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_unknown_compiler_options = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUnknownCompilerOptions",
+                        diagnostics,
+                    )?;
+                    self.no_unknown_compiler_options = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUnknownCssUnits" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_unknown_css_units = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUnknownCssUnits",
+                        diagnostics,
+                    )?;
+                    self.no_unknown_css_units = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
             "noUnsafeDeclarationMerging" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
@@ -2455,6 +2576,29 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
+            "useSortedJsonKeys" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_sorted_json_keys = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useSortedJsonKeys",
+                        diagnostics,
+                    )?;
+                    self.use_sorted_json_keys = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
             _ => {}
         }
         Some(())
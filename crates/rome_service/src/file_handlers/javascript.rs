@@ -3,8 +3,10 @@ use super::{
     LintResults, Mime, ParserCapabilities,
 };
 use crate::configuration::to_analyzer_configuration;
-use crate::file_handlers::{is_diagnostic_error, Features, FixAllParams, Language as LanguageId};
-use crate::workspace::OrganizeImportsResult;
+use crate::file_handlers::{
+    is_diagnostic_error, ActionsVisitor, Features, FixAllParams, Language as LanguageId,
+};
+use crate::workspace::{OrganizeImportsResult, TransformFileResult};
 use crate::{
     settings::{FormatSettings, Language, LanguageSettings, LanguagesSettings, SettingsHandle},
     workspace::{
@@ -13,10 +15,8 @@ use crate::{
     },
     Rules, WorkspaceError,
 };
-use indexmap::IndexSet;
 use rome_analyze::{
-    AnalysisFilter, AnalyzerOptions, ControlFlow, GroupCategory, Never, QueryMatch,
-    RegistryVisitor, RuleCategories, RuleCategory, RuleFilter, RuleGroup,
+    AnalysisFilter, AnalyzerOptions, ControlFlow, Never, QueryMatch, RuleCategories, RuleFilter,
 };
 use rome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
 use rome_formatter::{FormatError, Printed};
@@ -63,6 +63,7 @@ pub struct JsParserSettings {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JsonParserSettings {
     pub allow_comments: bool,
+    pub allow_trailing_commas: bool,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -86,6 +87,27 @@ impl Language for JsLanguage {
         &languages.javascript
     }
 
+    fn merge_format_settings(settings: &mut JsFormatterSettings, other: &JsFormatterSettings) {
+        if let Some(quote_style) = other.quote_style {
+            settings.quote_style = Some(quote_style);
+        }
+        if let Some(jsx_quote_style) = other.jsx_quote_style {
+            settings.jsx_quote_style = Some(jsx_quote_style);
+        }
+        if let Some(quote_properties) = other.quote_properties {
+            settings.quote_properties = Some(quote_properties);
+        }
+        if let Some(trailing_comma) = other.trailing_comma {
+            settings.trailing_comma = Some(trailing_comma);
+        }
+        if let Some(semicolons) = other.semicolons {
+            settings.semicolons = Some(semicolons);
+        }
+        if let Some(arrow_parentheses) = other.arrow_parentheses {
+            settings.arrow_parentheses = Some(arrow_parentheses);
+        }
+    }
+
     fn resolve_format_options(
         global: &FormatSettings,
         language: &JsFormatterSettings,
@@ -133,6 +155,7 @@ impl ExtensionHandler for JsFileHandler {
                 fix_all: Some(fix_all),
                 rename: Some(rename),
                 organize_imports: Some(organize_imports),
+                transform: Some(transform),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
@@ -169,9 +192,25 @@ fn parse(
             LanguageId::TypeScriptReact => JsFileSource::tsx(),
             _ => JsFileSource::js_module(),
         });
-    let settings = &settings.as_ref().languages.javascript.parser;
+    let settings = settings.as_ref();
+    // The overrides that match the file can only enable the decorators
+    let parse_class_parameter_decorators = settings
+        .languages
+        .javascript
+        .parser
+        .parse_class_parameter_decorators
+        || settings
+            .override_settings
+            .matching_patterns(rome_path.as_path())
+            .any(|pattern| {
+                pattern
+                    .languages
+                    .javascript
+                    .parser
+                    .parse_class_parameter_decorators
+            });
     let options = JsParserOptions {
-        parse_class_parameter_decorators: settings.parse_class_parameter_decorators,
+        parse_class_parameter_decorators,
     };
     let parse = rome_js_parser::parse_js_with_cache(text, source_type, options, cache);
     let root = parse.syntax();
@@ -337,34 +376,6 @@ fn lint(params: LintParams) -> LintResults {
     }
 }
 
-struct ActionsVisitor<'a> {
-    enabled_rules: Vec<RuleFilter<'a>>,
-}
-
-impl RegistryVisitor<JsLanguage> for ActionsVisitor<'_> {
-    fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
-        if matches!(C::CATEGORY, RuleCategory::Action) {
-            C::record_groups(self);
-        }
-    }
-
-    fn record_group<G: RuleGroup<Language = JsLanguage>>(&mut self) {
-        G::record_rules(self)
-    }
-
-    fn record_rule<R>(&mut self)
-    where
-        R: rome_analyze::Rule + 'static,
-        R::Query: rome_analyze::Queryable<Language = JsLanguage>,
-        <R::Query as rome_analyze::Queryable>::Output: Clone,
-    {
-        self.enabled_rules.push(RuleFilter::Rule(
-            <R::Group as RuleGroup>::NAME,
-            R::METADATA.name,
-        ));
-    }
-}
-
 #[tracing::instrument(level = "debug", skip(parse))]
 fn code_actions(
     parse: AnyParse,
@@ -438,6 +449,7 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     let FixAllParams {
         parse,
         rules,
+        mut filter,
         fix_file_mode,
         settings,
         should_format,
@@ -450,18 +462,6 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
     let mut tree: AnyJsRoot = parse.tree();
     let mut actions = Vec::new();
 
-    let enabled_rules: Option<Vec<RuleFilter>> = if let Some(rules) = rules {
-        let enabled: IndexSet<RuleFilter> = rules.as_enabled_rules();
-        Some(enabled.into_iter().collect())
-    } else {
-        None
-    };
-
-    let mut filter = match &enabled_rules {
-        Some(rules) => AnalysisFilter::from_enabled_rules(Some(rules.as_slice())),
-        _ => AnalysisFilter::default(),
-    };
-
     filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT;
 
     let mut skipped_suggested_fixes = 0;
@@ -472,7 +472,7 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
             let current_diagnostic = signal.diagnostic();
 
             if let Some(diagnostic) = current_diagnostic.as_ref() {
-                if is_diagnostic_error(diagnostic, params.rules) {
+                if is_diagnostic_error(diagnostic, rules) {
                     errors += 1;
                 }
             }
@@ -712,21 +712,64 @@ fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceE
     }
 }
 
-fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> AnalyzerOptions {
-    let configuration = to_analyzer_configuration(
-        settings.as_ref().linter(),
-        &settings.as_ref().languages,
-        |settings| {
-            if let Some(globals) = settings.javascript.globals.as_ref() {
-                globals
-                    .iter()
-                    .map(|global| global.to_string())
-                    .collect::<Vec<_>>()
-            } else {
-                vec![]
+/// Applies the transformations one at a time, until the file doesn't emit any
+fn transform(rome_path: &RomePath, parse: AnyParse) -> Result<TransformFileResult, WorkspaceError> {
+    let file_source = parse
+        .file_source(rome_path)
+        .map_err(|_| extension_error(rome_path))?;
+    let mut tree: AnyJsRoot = parse.tree();
+    let filter = AnalysisFilter {
+        categories: RuleCategories::TRANSFORMATION,
+        ..AnalysisFilter::default()
+    };
+    let options = AnalyzerOptions::default();
+
+    loop {
+        let (transformation, _) = rome_js_transform::transform(
+            &tree,
+            filter,
+            &options,
+            file_source,
+            |signal| match signal.transformations().next() {
+                Some(transformation) => ControlFlow::Break(transformation),
+                None => ControlFlow::Continue(()),
+            },
+        );
+
+        match transformation {
+            Some(transformation) => {
+                tree = AnyJsRoot::cast(transformation.mutation.commit()).ok_or(
+                    WorkspaceError::RuleError(RuleError::ReplacedRootWithNonRootError {
+                        rule_name: None,
+                    }),
+                )?;
             }
-        },
-    );
+            None => {
+                return Ok(TransformFileResult {
+                    code: tree.syntax().to_string(),
+                })
+            }
+        }
+    }
+}
+
+fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> AnalyzerOptions {
+    let settings = settings.as_ref();
+    let rules = settings.as_rules(file_path.as_path());
+    let configuration =
+        to_analyzer_configuration(rules.as_deref(), &settings.languages, |languages| {
+            // The globals of the overrides that match the file are added to the ones of the language
+            let overrides = settings
+                .override_settings
+                .matching_patterns(file_path.as_path())
+                .map(|pattern| &pattern.languages);
+            std::iter::once(languages)
+                .chain(overrides)
+                .filter_map(|languages| languages.javascript.globals.as_ref())
+                .flatten()
+                .map(|global| global.to_string())
+                .collect::<Vec<_>>()
+        });
     AnalyzerOptions {
         configuration,
         file_path,
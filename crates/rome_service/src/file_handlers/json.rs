@@ -1,36 +1,89 @@
 use super::{ExtensionHandler, Mime};
+use crate::configuration::json::JsonSchemaMapping;
 use crate::configuration::to_analyzer_configuration;
 use crate::file_handlers::javascript::JsonParserSettings;
 use crate::file_handlers::{
-    AnalyzerCapabilities, Capabilities, FixAllParams, FormatterCapabilities, LintParams,
-    LintResults, ParserCapabilities,
+    ActionsVisitor, AnalyzerCapabilities, Capabilities, FixAllParams, FormatterCapabilities,
+    LintParams, LintResults, ParserCapabilities,
 };
 use crate::file_handlers::{DebugCapabilities, Language as LanguageId};
+use crate::json_schema::resolve_schema_path;
 use crate::settings::{
     FormatSettings, Language, LanguageSettings, LanguagesSettings, SettingsHandle,
 };
 use crate::workspace::{
-    FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
+    CodeAction, FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
 };
-use crate::{Configuration, Rules, WorkspaceError};
-use rome_analyze::{AnalyzerOptions, ControlFlow, Never, RuleCategories};
+use crate::{Configuration, ConfigurationDiagnostic, MatchOptions, Matcher, Rules, WorkspaceError};
+use rome_analyze::{AnalysisFilter, AnalyzerOptions, ControlFlow, Never, RuleCategories};
 use rome_deserialize::json::deserialize_from_json_ast;
 use rome_diagnostics::{category, Diagnostic, DiagnosticExt, Severity};
 use rome_formatter::{FormatError, Printed};
 use rome_fs::{RomePath, CONFIG_NAME};
-use rome_json_analyze::analyze;
-use rome_json_formatter::context::JsonFormatOptions;
+use rome_json_analyze::options::SortKeysOptions;
+use rome_json_analyze::{analyze, visit_registry};
+use rome_json_formatter::context::{JsonFormatOptions, ObjectWrap, TrailingCommas};
 use rome_json_formatter::format_node;
 use rome_json_parser::JsonParserOptions;
 use rome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
 use rome_parser::AnyParse;
 use rome_rowan::{AstNode, FileSource, NodeCache};
 use rome_rowan::{TextRange, TextSize, TokenAtOffset};
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
+use tracing::trace;
+
+#[derive(Debug, Clone, Default)]
+pub struct JsonFormatterSettings {
+    pub trailing_commas: Option<TrailingCommas>,
+    pub normalize_quotes: Option<bool>,
+    pub object_wrap: Option<ObjectWrap>,
+}
+
+#[derive(Debug, Default)]
+pub struct JsonLinterSettings {
+    /// The JSON Schemas associated to the files matching their patterns
+    pub schemas: Vec<JsonSchemaSettings>,
+    /// The options of the assist that sorts the keys of the objects
+    pub sort_keys: SortKeysOptions,
+}
+
+#[derive(Debug)]
+pub struct JsonSchemaSettings {
+    /// The files validated against the schema
+    pub included_files: Matcher,
+    /// The path to the JSON Schema file
+    pub schema: PathBuf,
+}
+
+impl TryFrom<JsonSchemaMapping> for JsonSchemaSettings {
+    type Error = WorkspaceError;
+
+    fn try_from(mapping: JsonSchemaMapping) -> Result<Self, Self::Error> {
+        let mut included_files = Matcher::new(MatchOptions {
+            case_sensitive: true,
+            require_literal_leading_dot: false,
+            require_literal_separator: false,
+        });
+        for pattern in mapping.include.index_set() {
+            included_files.add_pattern(pattern).map_err(|err| {
+                WorkspaceError::Configuration(ConfigurationDiagnostic::new_invalid_ignore_pattern(
+                    pattern.to_string(),
+                    err.msg.to_string(),
+                ))
+            })?;
+        }
+
+        Ok(Self {
+            included_files,
+            schema: PathBuf::from(mapping.schema),
+        })
+    }
+}
 
 impl Language for JsonLanguage {
-    type FormatterSettings = ();
-    type LinterSettings = ();
+    type FormatterSettings = JsonFormatterSettings;
+    type LinterSettings = JsonLinterSettings;
     type OrganizeImportsSettings = ();
     type FormatOptions = JsonFormatOptions;
     type ParserSettings = JsonParserSettings;
@@ -38,14 +91,42 @@ impl Language for JsonLanguage {
         &language.json
     }
 
+    fn merge_format_settings(
+        settings: &mut Self::FormatterSettings,
+        other: &Self::FormatterSettings,
+    ) {
+        if let Some(trailing_commas) = other.trailing_commas {
+            settings.trailing_commas = Some(trailing_commas);
+        }
+        if let Some(normalize_quotes) = other.normalize_quotes {
+            settings.normalize_quotes = Some(normalize_quotes);
+        }
+        if let Some(object_wrap) = other.object_wrap {
+            settings.object_wrap = Some(object_wrap);
+        }
+    }
+
     fn resolve_format_options(
         global: &FormatSettings,
-        _language: &Self::FormatterSettings,
-        _path: &RomePath,
+        language: &Self::FormatterSettings,
+        path: &RomePath,
     ) -> Self::FormatOptions {
+        // Trailing commas are only printed in the files where they don't break the parsing
+        let is_jsonc = JsonFileSource::try_from(path.as_path())
+            .map_or(false, |source_type| source_type.is_jsonc())
+            || is_file_allowed_as_jsonc(path);
+        let trailing_commas = if is_jsonc {
+            language.trailing_commas.unwrap_or_default()
+        } else {
+            TrailingCommas::None
+        };
+
         JsonFormatOptions::default()
             .with_indent_style(global.indent_style.unwrap_or_default())
             .with_line_width(global.line_width.unwrap_or_default())
+            .with_trailing_commas(trailing_commas)
+            .with_normalize_quotes(language.normalize_quotes.unwrap_or(true))
+            .with_object_wrap(language.object_wrap.unwrap_or_default())
     }
 }
 
@@ -79,6 +160,7 @@ impl ExtensionHandler for JsonFileHandler {
                 rename: None,
                 fix_all: Some(fix_all),
                 organize_imports: Some(organize_imports),
+                transform: None,
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
@@ -104,17 +186,28 @@ fn parse(
     settings: SettingsHandle,
     cache: &mut NodeCache,
 ) -> AnyParse {
-    let parser = &settings.as_ref().languages.json.parser;
+    let settings = settings.as_ref();
+    let parser = &settings.languages.json.parser;
+    // The overrides that match the file can only allow the comments and the trailing commas
+    let overrides: Vec<_> = settings
+        .override_settings
+        .matching_patterns(rome_path.as_path())
+        .map(|pattern| &pattern.languages.json.parser)
+        .collect();
+    let allow_comments =
+        parser.allow_comments || overrides.iter().any(|parser| parser.allow_comments);
+    let allow_trailing_commas =
+        parser.allow_trailing_commas || overrides.iter().any(|parser| parser.allow_trailing_commas);
     let source_type =
         JsonFileSource::try_from(rome_path.as_path()).unwrap_or_else(|_| match language_hint {
             LanguageId::Json => JsonFileSource::json(),
             LanguageId::Jsonc => JsonFileSource::jsonc(),
             _ => JsonFileSource::json(),
         });
+    let is_jsonc = source_type.is_jsonc() || is_file_allowed_as_jsonc(rome_path);
     let options: JsonParserOptions = JsonParserOptions {
-        allow_comments: parser.allow_comments
-            || source_type.is_jsonc()
-            || is_file_allowed_as_jsonc(rome_path),
+        allow_comments: allow_comments || is_jsonc,
+        allow_trailing_commas: allow_trailing_commas || is_jsonc,
     };
     let parse = rome_json_parser::parse_json_with_cache(text, cache, options);
     let root = parse.syntax();
@@ -244,6 +337,26 @@ fn lint(params: LintParams) -> LintResults {
         let skipped_diagnostics = diagnostic_count - diagnostics.len() as u64;
 
         let has_lint = params.filter.categories.contains(RuleCategories::LINT);
+
+        if has_lint {
+            let schema = resolve_schema_path(
+                params.path,
+                &root,
+                &params.settings.as_ref().languages.json.linter,
+            )
+            .and_then(|schema_path| params.json_schemas.get(&schema_path));
+
+            if let Some(schema) = schema {
+                for diagnostic in schema.validate(&root) {
+                    diagnostic_count += 1;
+                    errors += 1;
+                    if diagnostic_count <= params.max_diagnostics {
+                        diagnostics.push(rome_diagnostics::serde::Diagnostic::new(diagnostic));
+                    }
+                }
+            }
+        }
+
         let analyzer_options =
             compute_analyzer_options(&params.settings, PathBuf::from(params.path.as_path()));
 
@@ -309,16 +422,55 @@ fn lint(params: LintParams) -> LintResults {
         }
     })
 }
+#[tracing::instrument(level = "debug", skip(parse))]
 fn code_actions(
-    _parse: AnyParse,
-    _range: TextRange,
-    _rules: Option<&Rules>,
-    _settings: SettingsHandle,
-    _path: &RomePath,
+    parse: AnyParse,
+    range: TextRange,
+    rules: Option<&Rules>,
+    settings: SettingsHandle,
+    path: &RomePath,
 ) -> PullActionsResult {
-    PullActionsResult {
-        actions: Vec::new(),
-    }
+    let tree: JsonRoot = parse.tree();
+    let Ok(value) = tree.value() else {
+        return PullActionsResult {
+            actions: Vec::new(),
+        };
+    };
+
+    let mut actions = Vec::new();
+
+    // The rules in the assist category do not have configuration entries,
+    // always add them all to the enabled rules list
+    let mut visitor = ActionsVisitor {
+        enabled_rules: rules
+            .map(|rules| rules.as_enabled_rules().into_iter().collect())
+            .unwrap_or_default(),
+    };
+    visit_registry(&mut visitor);
+    let enabled_rules = visitor.enabled_rules;
+
+    let mut filter = AnalysisFilter::from_enabled_rules(Some(enabled_rules.as_slice()));
+    filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT | RuleCategories::ACTION;
+    filter.range = Some(range);
+
+    trace!("Filter applied for code actions: {:?}", &filter);
+    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(path.as_path()));
+
+    analyze(&value, filter, &analyzer_options, |signal| {
+        actions.extend(signal.actions().into_code_action_iter().map(|item| {
+            CodeAction {
+                category: item.category.clone(),
+                rule_name: item
+                    .rule_name
+                    .map(|(group, name)| (Cow::Borrowed(group), Cow::Borrowed(name))),
+                suggestion: item.suggestion,
+            }
+        }));
+
+        ControlFlow::<Never>::Continue(())
+    });
+
+    PullActionsResult { actions }
 }
 
 fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
@@ -338,11 +490,9 @@ fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceE
 }
 
 fn compute_analyzer_options(settings: &SettingsHandle, file_path: PathBuf) -> AnalyzerOptions {
-    let configuration = to_analyzer_configuration(
-        settings.as_ref().linter(),
-        &settings.as_ref().languages,
-        |_| vec![],
-    );
+    let rules = settings.as_ref().as_rules(file_path.as_path());
+    let configuration =
+        to_analyzer_configuration(rules.as_deref(), &settings.as_ref().languages, |_| vec![]);
     AnalyzerOptions {
         configuration,
         file_path,
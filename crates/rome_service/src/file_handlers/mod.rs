@@ -1,12 +1,20 @@
-use self::{javascript::JsFileHandler, json::JsonFileHandler, unknown::UnknownFileHandler};
-use crate::workspace::{FixFileMode, OrganizeImportsResult};
+use self::{
+    css::CssFileHandler, javascript::JsFileHandler, json::JsonFileHandler,
+    unknown::UnknownFileHandler,
+};
+use crate::json_schema::JsonSchemaStore;
+use crate::workspace::{FixFileMode, OrganizeImportsResult, TransformFileResult};
 use crate::{
     settings::SettingsHandle,
     workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
     Rules, WorkspaceError,
 };
 pub use javascript::JsFormatterSettings;
-use rome_analyze::{AnalysisFilter, AnalyzerDiagnostic};
+pub use json::{JsonLinterSettings, JsonSchemaSettings};
+use rome_analyze::{
+    AnalysisFilter, AnalyzerDiagnostic, GroupCategory, RegistryVisitor, RuleCategory, RuleFilter,
+    RuleGroup,
+};
 use rome_console::fmt::Formatter;
 use rome_console::markup;
 use rome_diagnostics::{Diagnostic, Severity};
@@ -14,10 +22,11 @@ use rome_formatter::Printed;
 use rome_fs::RomePath;
 use rome_js_syntax::{TextRange, TextSize};
 use rome_parser::AnyParse;
-use rome_rowan::NodeCache;
+use rome_rowan::{Language as RowanLanguage, NodeCache};
 use std::ffi::OsStr;
 use std::path::Path;
 
+mod css;
 mod javascript;
 mod json;
 mod unknown;
@@ -38,6 +47,8 @@ pub enum Language {
     Json,
     /// JSONC
     Jsonc,
+    /// CSS
+    Css,
     /// Any language that is not supported
     #[default]
     Unknown,
@@ -45,8 +56,10 @@ pub enum Language {
 
 impl Language {
     /// Files that can be bypassed, because correctly handled by the JSON parser
-    pub(crate) const ALLOWED_FILES: &'static [&'static str; 13] = &[
+    pub(crate) const ALLOWED_FILES: &'static [&'static str; 15] = &[
         "typescript.json",
+        "tsconfig.json",
+        "jsconfig.json",
         "tslint.json",
         "babel.config.json",
         ".babelrc.json",
@@ -70,6 +83,7 @@ impl Language {
             "tsx" => Language::TypeScriptReact,
             "json" => Language::Json,
             "jsonc" => Language::Jsonc,
+            "css" => Language::Css,
             _ => Language::Unknown,
         }
     }
@@ -104,6 +118,7 @@ impl Language {
             "typescriptreact" => Language::TypeScriptReact,
             "json" => Language::Json,
             "jsonc" => Language::Jsonc,
+            "css" => Language::Css,
             _ => Language::Unknown,
         }
     }
@@ -148,13 +163,12 @@ impl rome_console::fmt::Display for Language {
             Language::TypeScriptReact => fmt.write_markup(markup! { "TSX" }),
             Language::Json => fmt.write_markup(markup! { "JSON" }),
             Language::Jsonc => fmt.write_markup(markup! { "JSONC" }),
+            Language::Css => fmt.write_markup(markup! { "CSS" }),
             Language::Unknown => fmt.write_markup(markup! { "Unknown" }),
         }
     }
 }
 
-// TODO: The Css variant is unused at the moment
-#[allow(dead_code)]
 pub(crate) enum Mime {
     Javascript,
     Json,
@@ -182,6 +196,8 @@ impl rome_console::fmt::Display for Mime {
 pub struct FixAllParams<'a> {
     pub(crate) parse: AnyParse,
     pub(crate) rules: Option<&'a Rules>,
+    /// The rules whose fixes are applied
+    pub(crate) filter: AnalysisFilter<'a>,
     pub(crate) fix_file_mode: FixFileMode,
     pub(crate) settings: SettingsHandle<'a>,
     /// Whether it should format the code action
@@ -227,6 +243,8 @@ pub(crate) struct LintParams<'a> {
     pub(crate) settings: SettingsHandle<'a>,
     pub(crate) max_diagnostics: u64,
     pub(crate) path: &'a RomePath,
+    /// The JSON Schemas registered in the workspace
+    pub(crate) json_schemas: &'a JsonSchemaStore,
 }
 
 pub(crate) struct LintResults {
@@ -241,6 +259,7 @@ type CodeActions =
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
 type Rename = fn(&RomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
 type OrganizeImports = fn(AnyParse) -> Result<OrganizeImportsResult, WorkspaceError>;
+type Transform = fn(&RomePath, AnyParse) -> Result<TransformFileResult, WorkspaceError>;
 
 #[derive(Default)]
 pub struct AnalyzerCapabilities {
@@ -254,6 +273,8 @@ pub struct AnalyzerCapabilities {
     pub(crate) rename: Option<Rename>,
     /// It organize imports
     pub(crate) organize_imports: Option<OrganizeImports>,
+    /// It applies the transformations that turn a file into JavaScript
+    pub(crate) transform: Option<Transform>,
 }
 
 type Format = fn(&RomePath, AnyParse, SettingsHandle) -> Result<Printed, WorkspaceError>;
@@ -303,6 +324,7 @@ pub(crate) trait ExtensionHandler {
 pub(crate) struct Features {
     js: JsFileHandler,
     json: JsonFileHandler,
+    css: CssFileHandler,
     unknown: UnknownFileHandler,
 }
 
@@ -311,6 +333,7 @@ impl Features {
         Features {
             js: JsFileHandler {},
             json: JsonFileHandler {},
+            css: CssFileHandler {},
             unknown: UnknownFileHandler::default(),
         }
     }
@@ -340,11 +363,42 @@ impl Features {
             | Language::TypeScript
             | Language::TypeScriptReact => self.js.capabilities(),
             Language::Json | Language::Jsonc => self.json.capabilities(),
+            Language::Css => self.css.capabilities(),
             Language::Unknown => self.unknown.capabilities(),
         }
     }
 }
 
+/// Collects the rules of the assist category of an analyzer, which don't
+/// have a configuration entry
+pub(crate) struct ActionsVisitor<'a> {
+    pub(crate) enabled_rules: Vec<RuleFilter<'a>>,
+}
+
+impl<L: RowanLanguage> RegistryVisitor<L> for ActionsVisitor<'_> {
+    fn record_category<C: GroupCategory<Language = L>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Action) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = L>>(&mut self) {
+        G::record_rules(self)
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: rome_analyze::Rule + 'static,
+        R::Query: rome_analyze::Queryable<Language = L>,
+        <R::Query as rome_analyze::Queryable>::Output: Clone,
+    {
+        self.enabled_rules.push(RuleFilter::Rule(
+            <R::Group as RuleGroup>::NAME,
+            R::METADATA.name,
+        ));
+    }
+}
+
 /// Checks whether a diagnostic coming from the analyzer is an [error](Severity::Error)
 ///
 /// The function checks the diagnostic against the current configured rules.
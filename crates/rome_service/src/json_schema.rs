@@ -0,0 +1,544 @@
+//! Validation of JSON documents against a [JSON Schema](https://json-schema.org/).
+//!
+//! The workspace doesn't have access to the file system, so the clients read the schemas
+//! and register them with [crate::Workspace::register_json_schema]. A document is validated
+//! against the schema referenced by its `$schema` field or, when it doesn't have one, against
+//! the first schema of the `json.schemas` configuration whose patterns match its path.
+//!
+//! The validator supports the keywords used by most schemas: `type`, `enum`, `const`,
+//! `properties`, `required`, `additionalProperties`, `items`, the length and range
+//! constraints, `allOf`, `anyOf`, `oneOf` and local `$ref`s.
+
+use crate::file_handlers::JsonLinterSettings;
+use crate::{ConfigurationDiagnostic, WorkspaceError};
+use dashmap::DashMap;
+use rome_console::markup;
+use rome_diagnostics::{Diagnostic, MessageAndDescription};
+use rome_fs::CONFIG_NAME;
+use rome_json_syntax::{
+    AnyJsonValue, JsonArrayValue, JsonMemberName, JsonObjectValue, JsonRoot, TextRange,
+};
+use rome_rowan::{AstNode, AstSeparatedList};
+use serde_json::{Map, Value};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+/// Maximum number of `$ref` that are followed before giving up, so that cyclic references
+/// don't loop forever
+const MAX_REFERENCE_DEPTH: usize = 32;
+
+/// The JSON Schemas registered in the workspace, indexed by path
+#[derive(Debug, Default)]
+pub(crate) struct JsonSchemaStore {
+    schemas: DashMap<PathBuf, Arc<JsonSchema>>,
+}
+
+impl JsonSchemaStore {
+    /// Parses `content` and registers it as the schema found at `path`
+    pub(crate) fn register(&self, path: &Path, content: &str) -> Result<(), WorkspaceError> {
+        let schema = JsonSchema::parse(content).map_err(|error| {
+            WorkspaceError::Configuration(ConfigurationDiagnostic::invalid_configuration(format!(
+                "The JSON Schema {} couldn't be parsed, reason: {error}",
+                path.display()
+            )))
+        })?;
+        self.schemas.insert(normalize_path(path), Arc::new(schema));
+        Ok(())
+    }
+
+    pub(crate) fn get(&self, path: &Path) -> Option<Arc<JsonSchema>> {
+        self.schemas
+            .get(&normalize_path(path))
+            .map(|schema| Arc::clone(schema.value()))
+    }
+
+    pub(crate) fn contains(&self, path: &Path) -> bool {
+        self.schemas.contains_key(&normalize_path(path))
+    }
+}
+
+/// Returns the path of the schema that applies to the document at `path`, if any.
+///
+/// A `$schema` with a relative path is resolved against the directory of the document.
+/// Remote schemas aren't supported. The `rome.json` file is already validated when it's
+/// deserialized, so it's never associated to a schema.
+pub(crate) fn resolve_schema_path(
+    path: &Path,
+    root: &JsonRoot,
+    settings: &JsonLinterSettings,
+) -> Option<PathBuf> {
+    if path.ends_with(CONFIG_NAME) {
+        return None;
+    }
+
+    let reference = schema_reference(root).filter(|reference| !reference.contains("://"));
+    if let Some(reference) = reference {
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+        return Some(normalize_path(&directory.join(reference)));
+    }
+
+    settings
+        .schemas
+        .iter()
+        .find(|schema| schema.included_files.matches_path(path))
+        .map(|schema| normalize_path(&schema.schema))
+}
+
+/// Returns the value of the `$schema` field of the root object
+fn schema_reference(root: &JsonRoot) -> Option<String> {
+    let object = root.value().ok()?;
+    let object = object.as_json_object_value()?;
+    object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .find_map(|member| {
+            let name = member.name().ok()?;
+            if name.inner_string_text().ok()?.text() != "$schema" {
+                return None;
+            }
+            let value = member.value().ok()?;
+            let text = value.as_json_string_value()?.inner_string_text().ok()?;
+            Some(text.text().to_string())
+        })
+}
+
+/// Removes the `.` components of a path and resolves its `..` components lexically
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) {
+                    normalized.pop();
+                } else {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[derive(Debug)]
+pub(crate) struct JsonSchema {
+    root: Value,
+}
+
+impl JsonSchema {
+    fn parse(content: &str) -> Result<Self, serde_json::Error> {
+        let root = serde_json::from_str(content)?;
+        Ok(Self { root })
+    }
+
+    /// Validates a document against this schema
+    pub(crate) fn validate(&self, root: &JsonRoot) -> Vec<JsonSchemaDiagnostic> {
+        let mut validator = Validator {
+            root: &self.root,
+            diagnostics: Vec::new(),
+        };
+        if let Ok(value) = root.value() {
+            validator.validate(&self.root, &value);
+        }
+        validator.diagnostics
+    }
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "jsonSchema", severity = Error)]
+pub(crate) struct JsonSchemaDiagnostic {
+    #[location(span)]
+    range: TextRange,
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+}
+
+impl JsonSchemaDiagnostic {
+    fn new(range: TextRange, message: impl rome_console::fmt::Display) -> Self {
+        Self {
+            range,
+            message: MessageAndDescription::from(markup! {{message}}.to_owned()),
+        }
+    }
+}
+
+struct Validator<'schema> {
+    /// The root of the schema, used to resolve the `$ref`s
+    root: &'schema Value,
+    diagnostics: Vec<JsonSchemaDiagnostic>,
+}
+
+impl<'schema> Validator<'schema> {
+    fn validate(&mut self, schema: &'schema Value, value: &AnyJsonValue) {
+        let Some(schema) = self.resolve_reference(schema) else {
+            return;
+        };
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => {
+                self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    value.range(),
+                    markup! { "This value isn't allowed by the schema." },
+                ));
+                return;
+            }
+            Value::Object(schema) => schema,
+            _ => return,
+        };
+
+        if let Some(Value::Array(schemas)) = schema.get("allOf") {
+            for schema in schemas {
+                self.validate(schema, value);
+            }
+        }
+        if let Some(Value::Array(schemas)) = schema.get("anyOf") {
+            if self.count_matching_schemas(schemas, value) == 0 {
+                self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    value.range(),
+                    markup! { "This value doesn't match any of the schemas allowed here." },
+                ));
+            }
+        }
+        if let Some(Value::Array(schemas)) = schema.get("oneOf") {
+            match self.count_matching_schemas(schemas, value) {
+                0 => self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    value.range(),
+                    markup! { "This value doesn't match any of the schemas allowed here." },
+                )),
+                1 => {}
+                _ => self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    value.range(),
+                    markup! { "This value matches more than one of the schemas allowed here." },
+                )),
+            }
+        }
+
+        if let Some(expected) = schema.get("type") {
+            if !matches_type(expected, value) {
+                self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    value.range(),
+                    markup! {
+                        "Expected "<Emphasis>{type_description(expected)}</Emphasis>" but found "<Emphasis>{value_type(value)}</Emphasis>"."
+                    },
+                ));
+                // The other keywords would only report noise on a value of the wrong type
+                return;
+            }
+        }
+
+        if let Some(Value::Array(allowed)) = schema.get("enum") {
+            if let Some(actual) = to_value(value) {
+                if !allowed.contains(&actual) {
+                    let allowed = allowed
+                        .iter()
+                        .map(Value::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    self.diagnostics.push(JsonSchemaDiagnostic::new(
+                        value.range(),
+                        markup! {
+                            "This value isn't one of the allowed values: "<Emphasis>{allowed}</Emphasis>"."
+                        },
+                    ));
+                }
+            }
+        }
+        if let Some(expected) = schema.get("const") {
+            if to_value(value).map_or(false, |actual| &actual != expected) {
+                self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    value.range(),
+                    markup! {
+                        "Expected the value "<Emphasis>{expected.to_string()}</Emphasis>"."
+                    },
+                ));
+            }
+        }
+
+        match value {
+            AnyJsonValue::JsonObjectValue(object) => self.validate_object(schema, object),
+            AnyJsonValue::JsonArrayValue(array) => self.validate_array(schema, array),
+            AnyJsonValue::JsonStringValue(string) => {
+                if let Ok(text) = string.inner_string_text() {
+                    let length = text.text().chars().count() as u64;
+                    self.validate_bounds(
+                        schema,
+                        ("minLength", "maxLength"),
+                        length,
+                        "characters",
+                        value.range(),
+                    );
+                }
+            }
+            AnyJsonValue::JsonNumberValue(number) => {
+                if let Some(number) = number
+                    .value_token()
+                    .ok()
+                    .and_then(|token| token.text_trimmed().parse::<f64>().ok())
+                {
+                    self.validate_range(schema, number, value.range());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn validate_object(&mut self, schema: &'schema Map<String, Value>, object: &JsonObjectValue) {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        let additional_properties = schema.get("additionalProperties");
+        let mut names = Vec::new();
+
+        for member in object.json_member_list().iter().flatten() {
+            let (Ok(name), Ok(value)) = (member.name(), member.value()) else {
+                continue;
+            };
+            let Some(text) = member_name(&name) else {
+                continue;
+            };
+
+            if let Some(property) = properties.and_then(|properties| properties.get(&text)) {
+                self.validate(property, &value);
+            } else if let Some(additional_properties) = additional_properties {
+                if matches!(additional_properties, Value::Bool(false)) {
+                    // A document is allowed to declare its schema even if the schema doesn't
+                    if text != "$schema" {
+                        self.diagnostics.push(JsonSchemaDiagnostic::new(
+                            name.range(),
+                            markup! {
+                                "The property "<Emphasis>{text}</Emphasis>" isn't allowed here."
+                            },
+                        ));
+                    }
+                } else {
+                    self.validate(additional_properties, &value);
+                }
+            }
+            names.push(text);
+        }
+
+        if let Some(Value::Array(required)) = schema.get("required") {
+            let range = object
+                .l_curly_token()
+                .map_or_else(|_| object.range(), |token| token.text_trimmed_range());
+            for required in required.iter().filter_map(Value::as_str) {
+                if !names.iter().any(|name| name == required) {
+                    self.diagnostics.push(JsonSchemaDiagnostic::new(
+                        range,
+                        markup! {
+                            "The required property "<Emphasis>{required}</Emphasis>" is missing."
+                        },
+                    ));
+                }
+            }
+        }
+
+        self.validate_bounds(
+            schema,
+            ("minProperties", "maxProperties"),
+            names.len() as u64,
+            "properties",
+            object.range(),
+        );
+    }
+
+    fn validate_array(&mut self, schema: &'schema Map<String, Value>, array: &JsonArrayValue) {
+        let elements = array.elements().iter().flatten().collect::<Vec<_>>();
+        match schema.get("items") {
+            // The array form of `items` validates every element against the schema at the same index
+            Some(Value::Array(items)) => {
+                for (item, element) in items.iter().zip(&elements) {
+                    self.validate(item, element);
+                }
+            }
+            Some(items) => {
+                for element in &elements {
+                    self.validate(items, element);
+                }
+            }
+            None => {}
+        }
+
+        self.validate_bounds(
+            schema,
+            ("minItems", "maxItems"),
+            elements.len() as u64,
+            "items",
+            array.range(),
+        );
+    }
+
+    /// Checks the size of a value against a pair of `min*`/`max*` keywords
+    fn validate_bounds(
+        &mut self,
+        schema: &Map<String, Value>,
+        (min_keyword, max_keyword): (&str, &str),
+        size: u64,
+        unit: &str,
+        range: TextRange,
+    ) {
+        if let Some(min) = schema.get(min_keyword).and_then(Value::as_u64) {
+            if size < min {
+                self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    range,
+                    markup! { "Expected at least "{min}" "{unit}" but found "{size}"." },
+                ));
+            }
+        }
+        if let Some(max) = schema.get(max_keyword).and_then(Value::as_u64) {
+            if size > max {
+                self.diagnostics.push(JsonSchemaDiagnostic::new(
+                    range,
+                    markup! { "Expected at most "{max}" "{unit}" but found "{size}"." },
+                ));
+            }
+        }
+    }
+
+    fn validate_range(&mut self, schema: &Map<String, Value>, number: f64, range: TextRange) {
+        let bound = |keyword: &str| schema.get(keyword).and_then(Value::as_f64);
+        let message = if let Some(minimum) = bound("minimum").filter(|minimum| number < *minimum) {
+            format!("The value should be greater than or equal to {minimum}.")
+        } else if let Some(maximum) = bound("maximum").filter(|maximum| number > *maximum) {
+            format!("The value should be less than or equal to {maximum}.")
+        } else if let Some(minimum) = bound("exclusiveMinimum").filter(|minimum| number <= *minimum)
+        {
+            format!("The value should be greater than {minimum}.")
+        } else if let Some(maximum) = bound("exclusiveMaximum").filter(|maximum| number >= *maximum)
+        {
+            format!("The value should be less than {maximum}.")
+        } else {
+            return;
+        };
+        self.diagnostics
+            .push(JsonSchemaDiagnostic::new(range, markup! {{message}}));
+    }
+
+    /// Returns the number of `schemas` that validate `value` without errors
+    fn count_matching_schemas(&self, schemas: &'schema [Value], value: &AnyJsonValue) -> usize {
+        schemas
+            .iter()
+            .filter(|schema| {
+                let mut validator = Validator {
+                    root: self.root,
+                    diagnostics: Vec::new(),
+                };
+                validator.validate(schema, value);
+                validator.diagnostics.is_empty()
+            })
+            .count()
+    }
+
+    /// Follows the `$ref` of a schema. Only references to the same document are supported,
+    /// any other reference is resolved to `None` and its value isn't validated.
+    fn resolve_reference(&self, mut schema: &'schema Value) -> Option<&'schema Value> {
+        for _ in 0..MAX_REFERENCE_DEPTH {
+            let Some(reference) = schema.get("$ref").and_then(Value::as_str) else {
+                return Some(schema);
+            };
+            let pointer = reference.strip_prefix('#')?;
+            schema = self.root.pointer(pointer)?;
+        }
+        None
+    }
+}
+
+/// Returns the text of a member name, with its escape sequences resolved
+fn member_name(name: &JsonMemberName) -> Option<String> {
+    let token = name.value_token().ok()?;
+    serde_json::from_str(token.text_trimmed()).ok()
+}
+
+/// Converts a syntax node into a [Value], so that it can be compared with the
+/// values of the schema
+fn to_value(value: &AnyJsonValue) -> Option<Value> {
+    match value {
+        AnyJsonValue::JsonBogusValue(_) => None,
+        AnyJsonValue::JsonArrayValue(array) => array
+            .elements()
+            .iter()
+            .map(|element| to_value(&element.ok()?))
+            .collect::<Option<_>>()
+            .map(Value::Array),
+        AnyJsonValue::JsonObjectValue(object) => object
+            .json_member_list()
+            .iter()
+            .map(|member| {
+                let member = member.ok()?;
+                Some((
+                    member_name(&member.name().ok()?)?,
+                    to_value(&member.value().ok()?)?,
+                ))
+            })
+            .collect::<Option<_>>()
+            .map(Value::Object),
+        _ => serde_json::from_str(&value.syntax().text_trimmed().to_string()).ok(),
+    }
+}
+
+fn matches_type(expected: &Value, value: &AnyJsonValue) -> bool {
+    match expected {
+        Value::String(expected) => is_of_type(expected, value),
+        Value::Array(expected) => expected
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|expected| is_of_type(expected, value)),
+        _ => true,
+    }
+}
+
+fn is_of_type(expected: &str, value: &AnyJsonValue) -> bool {
+    match (expected, value) {
+        ("object", AnyJsonValue::JsonObjectValue(_))
+        | ("array", AnyJsonValue::JsonArrayValue(_))
+        | ("string", AnyJsonValue::JsonStringValue(_))
+        | ("number", AnyJsonValue::JsonNumberValue(_))
+        | ("boolean", AnyJsonValue::JsonBooleanValue(_))
+        | ("null", AnyJsonValue::JsonNullValue(_))
+        // Don't report the values that couldn't be parsed, the parser already did
+        | (_, AnyJsonValue::JsonBogusValue(_)) => true,
+        ("integer", AnyJsonValue::JsonNumberValue(number)) => {
+            number.value_token().map_or(true, |token| {
+                !token.text_trimmed().contains(['.', 'e', 'E'])
+            })
+        }
+        _ => false,
+    }
+}
+
+fn type_description(expected: &Value) -> String {
+    match expected {
+        Value::String(expected) => article(expected),
+        Value::Array(expected) => expected
+            .iter()
+            .filter_map(Value::as_str)
+            .map(article)
+            .collect::<Vec<_>>()
+            .join(" or "),
+        _ => String::new(),
+    }
+}
+
+fn article(type_name: &str) -> String {
+    match type_name {
+        "object" | "array" | "integer" => format!("an {type_name}"),
+        "null" => type_name.to_string(),
+        _ => format!("a {type_name}"),
+    }
+}
+
+fn value_type(value: &AnyJsonValue) -> &'static str {
+    match value {
+        AnyJsonValue::JsonArrayValue(_) => "an array",
+        AnyJsonValue::JsonBogusValue(_) => "an invalid value",
+        AnyJsonValue::JsonBooleanValue(_) => "a boolean",
+        AnyJsonValue::JsonNullValue(_) => "null",
+        AnyJsonValue::JsonNumberValue(_) => "a number",
+        AnyJsonValue::JsonObjectValue(_) => "an object",
+        AnyJsonValue::JsonStringValue(_) => "a string",
+    }
+}
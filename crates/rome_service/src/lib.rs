@@ -10,6 +10,7 @@ pub mod settings;
 pub mod workspace;
 
 mod diagnostics;
+mod json_schema;
 #[cfg(feature = "schema")]
 pub mod workspace_types;
 
@@ -1,15 +1,24 @@
+use crate::configuration::overrides::{
+    OverrideFormatterConfiguration, OverrideLinterConfiguration, OverridePattern,
+};
+use crate::configuration::{JavascriptConfiguration, JsonConfiguration, PlainIndentStyle};
+use crate::file_handlers::JsonSchemaSettings;
 use crate::{
     configuration::FilesConfiguration, Configuration, ConfigurationDiagnostic, MatchOptions,
-    Matcher, Rules, WorkspaceError,
+    Matcher, MergeWith, Rules, WorkspaceError,
 };
 use indexmap::IndexSet;
+use rome_css_syntax::CssLanguage;
 use rome_diagnostics::Category;
 use rome_formatter::{IndentStyle, LineWidth};
 use rome_fs::RomePath;
 use rome_js_syntax::JsLanguage;
 use rome_json_syntax::JsonLanguage;
 use std::{
+    borrow::Cow,
+    collections::BTreeMap,
     num::NonZeroU64,
+    path::{Path, PathBuf},
     sync::{RwLock, RwLockReadGuard},
 };
 
@@ -26,6 +35,8 @@ pub struct WorkspaceSettings {
     pub files: FilesSettings,
     /// Analyzer settings
     pub organize_imports: OrganizeImportsSettings,
+    /// The settings applied to the files that match the patterns of `overrides`
+    pub override_settings: OverrideSettings,
 }
 
 impl WorkspaceSettings {
@@ -70,39 +81,17 @@ impl WorkspaceSettings {
         }
 
         // javascript settings
-        let javascript = configuration.javascript;
-        if let Some(javascript) = javascript {
-            self.languages.javascript.globals = javascript.globals.map(|g| g.into_index_set());
-            let formatter = javascript.formatter;
-            if let Some(formatter) = formatter {
-                self.languages.javascript.formatter.quote_style = formatter.quote_style;
-                self.languages.javascript.formatter.jsx_quote_style = formatter.jsx_quote_style;
-                self.languages.javascript.formatter.quote_properties = formatter.quote_properties;
-                self.languages.javascript.formatter.trailing_comma = formatter.trailing_comma;
-                self.languages.javascript.formatter.semicolons = formatter.semicolons;
-                self.languages.javascript.formatter.arrow_parentheses = formatter.arrow_parentheses;
-            }
-
-            if let Some(parser) = javascript.parser {
-                self.languages
-                    .javascript
-                    .parser
-                    .parse_class_parameter_decorators = parser
-                    .unsafe_parameter_decorators_enabled
-                    .unwrap_or_default();
-            }
-
-            let organize_imports = javascript.organize_imports;
-            if let Some(_organize_imports) = organize_imports {}
+        if let Some(javascript) = configuration.javascript {
+            self.languages.merge_with_javascript(javascript);
         }
 
         // json settings
-        let json = configuration.json;
-        if let Some(json) = json {
-            if let Some(parser) = json.parser {
-                self.languages.json.parser.allow_comments =
-                    parser.allow_comments.unwrap_or_default();
-            }
+        if let Some(json) = configuration.json {
+            self.languages.merge_with_json(json)?;
+        }
+
+        if let Some(overrides) = configuration.overrides {
+            self.override_settings = OverrideSettings::try_from(overrides)?;
         }
 
         Ok(())
@@ -124,6 +113,43 @@ impl WorkspaceSettings {
             None
         }
     }
+
+    /// Returns whether the formatter is enabled for the file at `path`
+    pub fn is_formatter_enabled(&self, path: &Path) -> bool {
+        self.override_settings
+            .resolve(path, self.formatter.enabled, |pattern| {
+                pattern.formatter.enabled
+            })
+    }
+
+    /// Returns whether the file at `path` is formatted when it has syntax errors
+    pub fn format_with_errors(&self, path: &Path) -> bool {
+        self.override_settings
+            .resolve(path, self.formatter.format_with_errors, |pattern| {
+                pattern.formatter.format_with_errors
+            })
+    }
+
+    /// Returns whether the linter is enabled for the file at `path`
+    pub fn is_linter_enabled(&self, path: &Path) -> bool {
+        self.override_settings
+            .resolve(path, self.linter.enabled, |pattern| pattern.linter.enabled)
+    }
+
+    /// Returns the rules applied to the file at `path`: the rules of the overrides that match
+    /// the file are merged, in order, onto the rules of the linter
+    pub fn as_rules(&self, path: &Path) -> Option<Cow<Rules>> {
+        let mut rules = self.linter.rules.as_ref().map(Cow::Borrowed);
+        for pattern in self.override_settings.matching_patterns(path) {
+            if let Some(pattern_rules) = pattern.linter.rules.as_ref() {
+                rules
+                    .get_or_insert_with(|| Cow::Owned(Rules::default()))
+                    .to_mut()
+                    .merge_with(pattern_rules.clone());
+            }
+        }
+        rules
+    }
 }
 
 /// Formatter settings for the entire workspace
@@ -212,11 +238,62 @@ impl Default for OrganizeImportsSettings {
 pub struct LanguagesSettings {
     pub javascript: LanguageSettings<JsLanguage>,
     pub json: LanguageSettings<JsonLanguage>,
+    pub css: LanguageSettings<CssLanguage>,
+}
+
+impl LanguagesSettings {
+    /// Applies the options of the `javascript` section of the configuration
+    fn merge_with_javascript(&mut self, javascript: JavascriptConfiguration) {
+        self.javascript.globals = javascript.globals.map(|g| g.into_index_set());
+        let formatter = javascript.formatter;
+        if let Some(formatter) = formatter {
+            self.javascript.formatter.quote_style = formatter.quote_style;
+            self.javascript.formatter.jsx_quote_style = formatter.jsx_quote_style;
+            self.javascript.formatter.quote_properties = formatter.quote_properties;
+            self.javascript.formatter.trailing_comma = formatter.trailing_comma;
+            self.javascript.formatter.semicolons = formatter.semicolons;
+            self.javascript.formatter.arrow_parentheses = formatter.arrow_parentheses;
+        }
+
+        if let Some(parser) = javascript.parser {
+            self.javascript.parser.parse_class_parameter_decorators = parser
+                .unsafe_parameter_decorators_enabled
+                .unwrap_or_default();
+        }
+
+        let organize_imports = javascript.organize_imports;
+        if let Some(_organize_imports) = organize_imports {}
+    }
+
+    /// Applies the options of the `json` section of the configuration
+    fn merge_with_json(&mut self, json: JsonConfiguration) -> Result<(), WorkspaceError> {
+        if let Some(parser) = json.parser {
+            self.json.parser.allow_comments = parser.allow_comments.unwrap_or_default();
+            self.json.parser.allow_trailing_commas =
+                parser.allow_trailing_commas.unwrap_or_default();
+        }
+        if let Some(formatter) = json.formatter {
+            self.json.formatter.trailing_commas = formatter.trailing_commas;
+            self.json.formatter.normalize_quotes = formatter.normalize_quotes;
+            self.json.formatter.object_wrap = formatter.object_wrap;
+        }
+        if let Some(schemas) = json.schemas {
+            self.json.linter.schemas = schemas
+                .into_iter()
+                .map(JsonSchemaSettings::try_from)
+                .collect::<Result<_, _>>()?;
+        }
+        if let Some(sort_keys) = json.assists.and_then(|assists| assists.sort_keys) {
+            self.json.linter.sort_keys = sort_keys;
+        }
+
+        Ok(())
+    }
 }
 
 pub trait Language: rome_rowan::Language {
     /// Formatter settings type for this language
-    type FormatterSettings: Default;
+    type FormatterSettings: Default + Clone;
 
     type LinterSettings: Default;
 
@@ -232,6 +309,13 @@ pub trait Language: rome_rowan::Language {
     /// Read the settings type for this language from the [LanguagesSettings] map
     fn lookup_settings(languages: &LanguagesSettings) -> &LanguageSettings<Self>;
 
+    /// Merges the formatter settings of an override onto the formatter settings of this language.
+    /// The options that aren't set in `other` are left unchanged
+    fn merge_format_settings(
+        settings: &mut Self::FormatterSettings,
+        other: &Self::FormatterSettings,
+    );
+
     /// Resolve the formatter options from the global (workspace level),
     /// per-language and editor provided formatter settings
     fn resolve_format_options(
@@ -320,24 +404,238 @@ impl TryFrom<FilesConfiguration> for FilesSettings {
     }
 }
 
+/// The settings of the `overrides` of the configuration
+#[derive(Debug, Default)]
+pub struct OverrideSettings {
+    /// The patterns, in the order of the configuration
+    pub patterns: Vec<OverrideSettingPattern>,
+}
+
+impl OverrideSettings {
+    /// Returns the patterns that apply to the file at `path`, in the order of the configuration
+    pub fn matching_patterns<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> impl Iterator<Item = &'a OverrideSettingPattern> + 'a {
+        self.patterns
+            .iter()
+            .filter(move |pattern| pattern.matches_path(path))
+    }
+
+    /// Returns the value of an option for the file at `path`: the value set by the last pattern
+    /// that matches the file, or `value` if none of them sets the option
+    fn resolve<T>(
+        &self,
+        path: &Path,
+        value: T,
+        option: impl Fn(&OverrideSettingPattern) -> Option<T>,
+    ) -> T {
+        self.matching_patterns(path)
+            .filter_map(option)
+            .last()
+            .unwrap_or(value)
+    }
+}
+
+impl TryFrom<Vec<OverridePattern>> for OverrideSettings {
+    type Error = WorkspaceError;
+
+    fn try_from(overrides: Vec<OverridePattern>) -> Result<Self, Self::Error> {
+        let patterns = overrides
+            .into_iter()
+            .map(OverrideSettingPattern::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { patterns })
+    }
+}
+
+/// The settings of a single pattern of `overrides`
+#[derive(Debug)]
+pub struct OverrideSettingPattern {
+    /// The files the pattern applies to, all the files if [None]
+    pub included_files: Option<Matcher>,
+    /// The files the pattern doesn't apply to
+    pub ignored_files: Matcher,
+    /// The formatter settings changed by this pattern
+    pub formatter: OverrideFormatSettings,
+    /// The linter settings changed by this pattern
+    pub linter: OverrideLinterSettings,
+    /// The language settings changed by this pattern. The options of the formatter are merged
+    /// onto the ones of the language, the globals are added to the ones of the language, and the
+    /// options of the parser can only enable a syntax
+    pub languages: LanguagesSettings,
+}
+
+impl OverrideSettingPattern {
+    fn matches_path(&self, path: &Path) -> bool {
+        let is_included = self
+            .included_files
+            .as_ref()
+            .map_or(true, |included_files| included_files.matches_path(path));
+        is_included && !self.ignored_files.matches_path(path)
+    }
+}
+
+impl TryFrom<OverridePattern> for OverrideSettingPattern {
+    type Error = WorkspaceError;
+
+    fn try_from(pattern: OverridePattern) -> Result<Self, Self::Error> {
+        let included_files = pattern
+            .include
+            .map(|include| to_matcher(include.index_set()))
+            .transpose()?;
+        let ignored_files = to_matcher(
+            pattern
+                .ignore
+                .as_ref()
+                .map(|ignore| ignore.index_set())
+                .unwrap_or(&IndexSet::new()),
+        )?;
+
+        let mut languages = LanguagesSettings::default();
+        if let Some(javascript) = pattern.javascript {
+            languages.merge_with_javascript(javascript);
+        }
+        if let Some(json) = pattern.json {
+            languages.merge_with_json(json)?;
+        }
+
+        Ok(Self {
+            included_files,
+            ignored_files,
+            formatter: pattern.formatter.map(Into::into).unwrap_or_default(),
+            linter: pattern.linter.map(Into::into).unwrap_or_default(),
+            languages,
+        })
+    }
+}
+
+/// Creates a [Matcher] from a list of Unix shell style patterns
+fn to_matcher(patterns: &IndexSet<String>) -> Result<Matcher, WorkspaceError> {
+    let mut matcher = Matcher::new(MatchOptions {
+        case_sensitive: true,
+        require_literal_leading_dot: false,
+        require_literal_separator: false,
+    });
+    for pattern in patterns {
+        matcher.add_pattern(pattern).map_err(|err| {
+            WorkspaceError::Configuration(ConfigurationDiagnostic::new_invalid_ignore_pattern(
+                pattern.to_string(),
+                err.msg.to_string(),
+            ))
+        })?;
+    }
+    Ok(matcher)
+}
+
+/// The formatter settings changed by a pattern of `overrides`, [None] when they don't change
+#[derive(Debug, Default)]
+pub struct OverrideFormatSettings {
+    pub enabled: Option<bool>,
+    pub format_with_errors: Option<bool>,
+    pub indent_style: Option<PlainIndentStyle>,
+    pub indent_size: Option<u8>,
+    pub line_width: Option<LineWidth>,
+}
+
+impl OverrideFormatSettings {
+    /// Applies the indentation and the line width of this pattern to `settings`
+    fn apply_to(&self, settings: &mut FormatSettings) {
+        let indent_size = self.indent_size.or(match settings.indent_style {
+            Some(IndentStyle::Space(size)) => Some(size),
+            _ => None,
+        });
+        match self.indent_style {
+            Some(PlainIndentStyle::Tab) => settings.indent_style = Some(IndentStyle::Tab),
+            Some(PlainIndentStyle::Space) => {
+                settings.indent_style = Some(IndentStyle::Space(indent_size.unwrap_or(2)))
+            }
+            // Only the size of the spaces changes, the tabs don't have a size
+            None => {
+                if let (Some(IndentStyle::Space(_)), Some(indent_size)) =
+                    (settings.indent_style, self.indent_size)
+                {
+                    settings.indent_style = Some(IndentStyle::Space(indent_size));
+                }
+            }
+        }
+        if let Some(line_width) = self.line_width {
+            settings.line_width = Some(line_width);
+        }
+    }
+}
+
+impl From<OverrideFormatterConfiguration> for OverrideFormatSettings {
+    fn from(configuration: OverrideFormatterConfiguration) -> Self {
+        Self {
+            enabled: configuration.enabled,
+            format_with_errors: configuration.format_with_errors,
+            indent_style: configuration.indent_style,
+            indent_size: configuration.indent_size,
+            line_width: configuration.line_width,
+        }
+    }
+}
+
+/// The linter settings changed by a pattern of `overrides`, [None] when they don't change
+#[derive(Debug, Default)]
+pub struct OverrideLinterSettings {
+    pub enabled: Option<bool>,
+    /// The rules merged onto the rules of the linter
+    pub rules: Option<Rules>,
+}
+
+impl From<OverrideLinterConfiguration> for OverrideLinterSettings {
+    fn from(configuration: OverrideLinterConfiguration) -> Self {
+        Self {
+            enabled: configuration.enabled,
+            rules: configuration.rules,
+        }
+    }
+}
+
+/// The settings of the directories that contain a nested configuration file
+pub type NestedSettings = BTreeMap<PathBuf, WorkspaceSettings>;
+
 /// Handle object holding a temporary lock on the workspace settings until
 /// the deferred language-specific options resolution is called
 #[derive(Debug)]
 pub struct SettingsHandle<'a> {
     inner: RwLockReadGuard<'a, WorkspaceSettings>,
+    nested: RwLockReadGuard<'a, NestedSettings>,
+    /// The directory whose settings apply to the file, the settings of the root apply when it's
+    /// `None`
+    directory: Option<PathBuf>,
 }
 
 impl<'a> SettingsHandle<'a> {
-    pub(crate) fn new(settings: &'a RwLock<WorkspaceSettings>) -> Self {
+    /// Locks the settings that apply to the file at `path`: the settings of its nearest
+    /// directory that has a nested configuration file, or the settings of the root
+    pub(crate) fn new(
+        settings: &'a RwLock<WorkspaceSettings>,
+        nested_settings: &'a RwLock<NestedSettings>,
+        path: &Path,
+    ) -> Self {
+        let nested = nested_settings.read().unwrap();
+        let directory = path
+            .ancestors()
+            .find(|ancestor| nested.contains_key(*ancestor))
+            .map(Path::to_path_buf);
+
         Self {
             inner: settings.read().unwrap(),
+            nested,
+            directory,
         }
     }
 }
 
 impl<'a> AsRef<WorkspaceSettings> for SettingsHandle<'a> {
     fn as_ref(&self) -> &WorkspaceSettings {
-        &self.inner
+        match &self.directory {
+            Some(directory) => &self.nested[directory],
+            None => &self.inner,
+        }
     }
 }
 
@@ -347,10 +645,21 @@ impl<'a> SettingsHandle<'a> {
     where
         L: Language,
     {
-        L::resolve_format_options(
-            &self.inner.formatter,
-            &L::lookup_settings(&self.inner.languages).formatter,
-            path,
-        )
+        let settings = self.as_ref();
+        let mut formatter = FormatSettings {
+            indent_style: settings.formatter.indent_style,
+            line_width: settings.formatter.line_width,
+            ..FormatSettings::default()
+        };
+        let mut language = L::lookup_settings(&settings.languages).formatter.clone();
+        for pattern in settings.override_settings.matching_patterns(path) {
+            pattern.formatter.apply_to(&mut formatter);
+            L::merge_format_settings(
+                &mut language,
+                &L::lookup_settings(&pattern.languages).formatter,
+            );
+        }
+
+        L::resolve_format_options(&formatter, &language, path)
     }
 }
@@ -53,8 +53,8 @@
 
 use crate::file_handlers::Capabilities;
 use crate::{Configuration, Deserialize, Serialize, WorkspaceError};
-use rome_analyze::ActionCategory;
 pub use rome_analyze::RuleCategories;
+use rome_analyze::{ActionCategory, RuleFilter};
 use rome_console::{markup, Markup, MarkupBuf};
 use rome_diagnostics::CodeSuggestion;
 use rome_formatter::Printed;
@@ -62,7 +62,14 @@ use rome_fs::RomePath;
 use rome_js_syntax::{TextRange, TextSize};
 use rome_text_edit::TextEdit;
 use std::collections::HashMap;
-use std::{borrow::Cow, panic::RefUnwindSafe, sync::Arc};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::{
+    borrow::Cow,
+    panic::RefUnwindSafe,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 pub use self::client::{TransportRequest, WorkspaceClient, WorkspaceTransport};
 pub use crate::file_handlers::Language;
@@ -121,12 +128,12 @@ impl FileFeaturesResult {
         self
     }
 
-    pub fn with_settings(mut self, settings: &WorkspaceSettings) -> Self {
-        if !settings.formatter().enabled {
+    pub fn with_settings(mut self, settings: &WorkspaceSettings, path: &Path) -> Self {
+        if !settings.is_formatter_enabled(path) {
             self.features_supported
                 .insert(FeatureName::Format, SupportKind::FeatureNotEnabled);
         }
-        if !settings.linter().enabled {
+        if !settings.is_linter_enabled(path) {
             self.features_supported
                 .insert(FeatureName::Lint, SupportKind::FeatureNotEnabled);
         }
@@ -243,6 +250,10 @@ impl FeaturesBuilder {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateSettingsParams {
     pub configuration: Configuration,
+    /// The directory of a nested configuration file. The settings then only apply to the files
+    /// of this directory, the settings of the root are used when it's omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directory: Option<PathBuf>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -307,6 +318,73 @@ pub struct PullDiagnosticsParams {
     pub path: RomePath,
     pub categories: RuleCategories,
     pub max_diagnostics: u64,
+    /// Runs only these rules, in place of the rules enabled by the configuration
+    #[serde(default)]
+    pub only: Vec<RuleSelector>,
+    /// Doesn't run these rules, even when they're enabled
+    #[serde(default)]
+    pub skip: Vec<RuleSelector>,
+}
+
+/// A rule, or all the rules of a group, as written in `group/rule` or `group`
+#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RuleSelector {
+    pub group: String,
+    /// The name of the rule, it's [None] when the whole group is selected
+    pub rule: Option<String>,
+}
+
+impl RuleSelector {
+    pub fn as_rule_filter(&self) -> RuleFilter {
+        match &self.rule {
+            Some(rule) => RuleFilter::Rule(&self.group, rule),
+            None => RuleFilter::Group(&self.group),
+        }
+    }
+}
+
+impl FromStr for RuleSelector {
+    type Err = String;
+
+    /// Parses `group/rule` or `group`, it fails when the rule or the group doesn't exist
+    fn from_str(selector: &str) -> Result<Self, Self::Err> {
+        let registries = [rome_js_analyze::metadata(), rome_json_analyze::metadata()];
+        match selector.split_once('/') {
+            Some((group, rule)) => {
+                if registries
+                    .iter()
+                    .any(|registry| registry.find_rule(group, rule).is_some())
+                {
+                    Ok(Self {
+                        group: group.to_string(),
+                        rule: Some(rule.to_string()),
+                    })
+                } else {
+                    Err(format!("the rule {selector} doesn't exist"))
+                }
+            }
+            None => {
+                if registries
+                    .iter()
+                    .any(|registry| registry.find_group(selector).is_some())
+                {
+                    Ok(Self {
+                        group: selector.to_string(),
+                        rule: None,
+                    })
+                } else {
+                    Err(format!("the group {selector} doesn't exist"))
+                }
+            }
+        }
+    }
+}
+
+impl Display for RuleSelector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.as_rule_filter(), f)
+    }
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -374,6 +452,12 @@ pub struct FixFileParams {
     pub path: RomePath,
     pub fix_file_mode: FixFileMode,
     pub should_format: bool,
+    /// Applies only the fixes of these rules, in place of the rules enabled by the configuration
+    #[serde(default)]
+    pub only: Vec<RuleSelector>,
+    /// Doesn't apply the fixes of these rules
+    #[serde(default)]
+    pub skip: Vec<RuleSelector>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -479,6 +563,43 @@ impl RageEntry {
     }
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TransformFileParams {
+    pub path: RomePath,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TransformFileResult {
+    /// New source code for the file with all the transformations applied
+    pub code: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ResolveJsonSchemaParams {
+    pub path: RomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ResolveJsonSchemaResult {
+    /// The path of the JSON Schema used to validate the file, if any
+    pub schema_path: Option<RomePath>,
+    /// Whether the schema was already registered in the workspace
+    pub is_registered: bool,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RegisterJsonSchemaParams {
+    /// The path of the schema, as returned by [Workspace::resolve_json_schema]
+    pub path: RomePath,
+    /// The content of the schema
+    pub content: String,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct IsPathIgnoredParams {
@@ -504,7 +625,8 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// If the file path matches, than `true` is returned and it should be considered ignored.
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError>;
 
-    /// Update the global settings for this workspace
+    /// Update the global settings for this workspace, or the settings of the directory of a
+    /// nested configuration file
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError>;
 
     /// Add a new file to the workspace
@@ -572,6 +694,25 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         &self,
         params: OrganizeImportsParams,
     ) -> Result<OrganizeImportsResult, WorkspaceError>;
+
+    /// Applies the transformations that turn a TypeScript file into JavaScript: the types are
+    /// stripped, and enums, namespaces and parameter properties are lowered
+    fn transform_file(
+        &self,
+        params: TransformFileParams,
+    ) -> Result<TransformFileResult, WorkspaceError>;
+
+    /// Returns the path of the JSON Schema used to validate a JSON file.
+    ///
+    /// The workspace can't read the schema from the file system, so the client has to
+    /// register it with [Workspace::register_json_schema] when it isn't registered yet.
+    fn resolve_json_schema(
+        &self,
+        params: ResolveJsonSchemaParams,
+    ) -> Result<ResolveJsonSchemaResult, WorkspaceError>;
+
+    /// Registers the content of a JSON Schema, used to validate the JSON files that refer to it
+    fn register_json_schema(&self, params: RegisterJsonSchemaParams) -> Result<(), WorkspaceError>;
 }
 
 /// Convenience function for constructing a server instance of [Workspace]
@@ -639,11 +780,15 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         &self,
         categories: RuleCategories,
         max_diagnostics: u64,
+        only: Vec<RuleSelector>,
+        skip: Vec<RuleSelector>,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         self.workspace.pull_diagnostics(PullDiagnosticsParams {
             path: self.path.clone(),
             categories,
             max_diagnostics,
+            only,
+            skip,
         })
     }
 
@@ -678,11 +823,21 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         &self,
         fix_file_mode: FixFileMode,
         should_format: bool,
+        only: Vec<RuleSelector>,
+        skip: Vec<RuleSelector>,
     ) -> Result<FixFileResult, WorkspaceError> {
         self.workspace.fix_file(FixFileParams {
             path: self.path.clone(),
             fix_file_mode,
             should_format,
+            only,
+            skip,
+        })
+    }
+
+    pub fn resolve_json_schema(&self) -> Result<ResolveJsonSchemaResult, WorkspaceError> {
+        self.workspace.resolve_json_schema(ResolveJsonSchemaParams {
+            path: self.path.clone(),
         })
     }
 
@@ -691,6 +846,12 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             path: self.path.clone(),
         })
     }
+
+    pub fn transform_file(&self) -> Result<TransformFileResult, WorkspaceError> {
+        self.workspace.transform_file(TransformFileParams {
+            path: self.path.clone(),
+        })
+    }
 }
 
 impl<'app, W: Workspace + ?Sized> Drop for FileGuard<'app, W> {
@@ -15,8 +15,9 @@ use super::{
     ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
     GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult,
-    SupportsFeatureParams, UpdateSettingsParams,
+    PullDiagnosticsParams, PullDiagnosticsResult, RegisterJsonSchemaParams, RenameParams,
+    RenameResult, ResolveJsonSchemaParams, ResolveJsonSchemaResult, SupportsFeatureParams,
+    TransformFileParams, TransformFileResult, UpdateSettingsParams,
 };
 
 pub struct WorkspaceClient<T> {
@@ -194,4 +195,22 @@ where
     ) -> Result<OrganizeImportsResult, WorkspaceError> {
         self.request("rome/organize_imports", params)
     }
+
+    fn transform_file(
+        &self,
+        params: TransformFileParams,
+    ) -> Result<TransformFileResult, WorkspaceError> {
+        self.request("rome/transform_file", params)
+    }
+
+    fn resolve_json_schema(
+        &self,
+        params: ResolveJsonSchemaParams,
+    ) -> Result<ResolveJsonSchemaResult, WorkspaceError> {
+        self.request("rome/resolve_json_schema", params)
+    }
+
+    fn register_json_schema(&self, params: RegisterJsonSchemaParams) -> Result<(), WorkspaceError> {
+        self.request("rome/register_json_schema", params)
+    }
 }
@@ -2,17 +2,19 @@ use super::{
     ChangeFileParams, CloseFileParams, FeatureName, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
     GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameResult, SupportsFeatureParams,
-    UpdateSettingsParams,
+    PullDiagnosticsParams, PullDiagnosticsResult, RegisterJsonSchemaParams, RenameResult,
+    ResolveJsonSchemaParams, ResolveJsonSchemaResult, SupportsFeatureParams, TransformFileParams,
+    TransformFileResult, UpdateSettingsParams,
 };
 use crate::file_handlers::{Capabilities, FixAllParams, Language, LintParams};
+use crate::json_schema::{resolve_schema_path, JsonSchemaStore};
 use crate::workspace::{
     FileFeaturesResult, GetFileContentParams, IsPathIgnoredParams, OrganizeImportsParams,
-    OrganizeImportsResult, RageEntry, RageParams, RageResult, ServerInfo,
+    OrganizeImportsResult, RageEntry, RageParams, RageResult, RuleSelector, ServerInfo,
 };
 use crate::{
     file_handlers::Features,
-    settings::{SettingsHandle, WorkspaceSettings},
+    settings::{NestedSettings, SettingsHandle, WorkspaceSettings},
     Rules, Workspace, WorkspaceError,
 };
 use dashmap::{mapref::entry::Entry, DashMap};
@@ -24,6 +26,7 @@ use rome_fs::RomePath;
 use rome_parser::AnyParse;
 use rome_rowan::NodeCache;
 use std::ffi::OsStr;
+use std::path::Path;
 use std::{panic::RefUnwindSafe, sync::RwLock};
 use tracing::trace;
 
@@ -32,10 +35,15 @@ pub(super) struct WorkspaceServer {
     features: Features,
     /// global settings object for this workspace
     settings: RwLock<WorkspaceSettings>,
+    /// The settings of the directories that contain a nested configuration file, resolved by
+    /// merging their configuration onto the configuration of their ancestors
+    nested_settings: RwLock<NestedSettings>,
     /// Stores the document (text content + version number) associated with a URL
     documents: DashMap<RomePath, Document>,
     /// Stores the result of the parser (syntax tree + diagnostics) for a given URL
     syntax: DashMap<RomePath, AnyParse>,
+    /// Stores the JSON Schemas registered by the client
+    json_schemas: JsonSchemaStore,
 }
 
 /// The `Workspace` object is long lived, so we want it to be able to cross
@@ -64,13 +72,16 @@ impl WorkspaceServer {
         Self {
             features: Features::new(),
             settings: RwLock::default(),
+            nested_settings: RwLock::default(),
             documents: DashMap::default(),
             syntax: DashMap::default(),
+            json_schemas: JsonSchemaStore::default(),
         }
     }
 
-    fn settings(&self) -> SettingsHandle {
-        SettingsHandle::new(&self.settings)
+    /// Returns the settings that apply to the file at `path`
+    fn settings(&self, path: &Path) -> SettingsHandle {
+        SettingsHandle::new(&self.settings, &self.nested_settings, path)
     }
 
     /// Get the supported capabilities for a given file path
@@ -164,7 +175,7 @@ impl WorkspaceServer {
                     .ok_or_else(self.build_capability_error(rome_path))?;
 
                 let size_limit = {
-                    let settings = self.settings();
+                    let settings = self.settings(rome_path);
                     let settings = settings.as_ref();
                     let limit = settings.files.max_size.get();
                     usize::try_from(limit).unwrap_or(usize::MAX)
@@ -180,7 +191,7 @@ impl WorkspaceServer {
                     ));
                 }
 
-                let settings = self.settings();
+                let settings = self.settings(rome_path);
                 let parsed = parse(
                     rome_path,
                     document.language_hint,
@@ -201,10 +212,11 @@ impl Workspace for WorkspaceServer {
         params: SupportsFeatureParams,
     ) -> Result<FileFeaturesResult, WorkspaceError> {
         let capabilities = self.get_capabilities(&params.path);
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
+        let settings = settings.as_ref();
         let mut file_features = FileFeaturesResult::new()
             .with_capabilities(&capabilities)
-            .with_settings(&settings);
+            .with_settings(settings, params.path.as_path());
 
         if settings.files.ignore_unknown {
             let language = self.get_language(&params.path);
@@ -227,7 +239,7 @@ impl Workspace for WorkspaceServer {
     }
 
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError> {
-        let settings = self.settings();
+        let settings = self.settings(&params.rome_path);
         let is_ignored_by_file_config = settings
             .as_ref()
             .files
@@ -262,15 +274,32 @@ impl Workspace for WorkspaceServer {
         })
     }
 
-    /// Update the global settings for this workspace
+    /// Update the global settings for this workspace, or the settings of the directory of a
+    /// nested configuration file
+    ///
+    /// The settings of the directories are cleared when the global settings change, since
+    /// they were resolved from the previous configuration
     ///
     /// ## Panics
     /// This function may panic if the internal settings mutex has been poisoned
     /// by another thread having previously panicked while holding the lock
     #[tracing::instrument(level = "debug", skip(self))]
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), WorkspaceError> {
-        let mut settings = self.settings.write().unwrap();
-        settings.merge_with_configuration(params.configuration)?;
+        match params.directory {
+            Some(directory) => {
+                let mut settings = WorkspaceSettings::default();
+                settings.merge_with_configuration(params.configuration)?;
+                self.nested_settings
+                    .write()
+                    .unwrap()
+                    .insert(directory, settings);
+            }
+            None => {
+                let mut settings = self.settings.write().unwrap();
+                settings.merge_with_configuration(params.configuration)?;
+                self.nested_settings.write().unwrap().clear();
+            }
+        }
         Ok(())
     }
 
@@ -336,10 +365,10 @@ impl Workspace for WorkspaceServer {
             .debug
             .debug_formatter_ir
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !settings.as_ref().format_with_errors(params.path.as_path()) && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
@@ -383,17 +412,26 @@ impl Workspace for WorkspaceServer {
         };
 
         let parse = self.get_parse(params.path.clone(), Some(feature))?;
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
+        let settings = settings.as_ref();
 
         let (diagnostics, errors, skipped_diagnostics) = if let Some(lint) =
             self.get_capabilities(&params.path).analyzer.lint
         {
-            let rules = settings.linter().rules.as_ref();
-            let mut rule_filter_list = self.build_rule_filter_list(rules);
-            if settings.organize_imports.enabled {
-                rule_filter_list.push(RuleFilter::Rule("correctness", "organizeImports"));
-            }
+            let rules = settings.as_rules(params.path.as_path());
+            let rules = rules.as_deref();
+            let rule_filter_list = if params.only.is_empty() {
+                let mut rule_filter_list = self.build_rule_filter_list(rules);
+                if settings.organize_imports.enabled {
+                    rule_filter_list.push(RuleFilter::Rule("correctness", "organizeImports"));
+                }
+                rule_filter_list
+            } else {
+                as_rule_filters(&params.only)
+            };
+            let disabled_rules = as_rule_filters(&params.skip);
             let mut filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
+            filter.disabled_rules = Some(disabled_rules.as_slice());
             filter.categories = params.categories;
 
             trace!("Analyzer filter to apply to lint: {:?}", &filter);
@@ -402,9 +440,10 @@ impl Workspace for WorkspaceServer {
                 parse,
                 filter,
                 rules,
-                settings: self.settings(),
+                settings: self.settings(&params.path),
                 max_diagnostics: params.max_diagnostics,
                 path: &params.path,
+                json_schemas: &self.json_schemas,
             });
 
             (
@@ -445,13 +484,15 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Lint))?;
-        let settings = self.settings.read().unwrap();
-        let rules = settings.linter().rules.as_ref();
+        let settings = self.settings(&params.path);
+        let settings = settings.as_ref();
+        let rules = settings.as_rules(params.path.as_path());
+        let rules = rules.as_deref();
         Ok(code_actions(
             parse,
             params.range,
             rules,
-            self.settings(),
+            self.settings(&params.path),
             &params.path,
         ))
     }
@@ -464,10 +505,10 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !settings.as_ref().format_with_errors(params.path.as_path()) && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
@@ -480,10 +521,10 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format_range
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !settings.as_ref().format_with_errors(params.path.as_path()) && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
@@ -497,9 +538,9 @@ impl Workspace for WorkspaceServer {
             .format_on_type
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !settings.as_ref().format_with_errors(params.path.as_path()) && parse.has_errors() {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
 
@@ -512,15 +553,29 @@ impl Workspace for WorkspaceServer {
             .analyzer
             .fix_all
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
+        let settings = settings.as_ref();
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Lint))?;
 
-        let rules = settings.linter().rules.as_ref();
+        let rules = settings.as_rules(params.path.as_path());
+        let rules = rules.as_deref();
+        let enabled_rules = if params.only.is_empty() {
+            rules.map(|rules| rules.as_enabled_rules().into_iter().collect())
+        } else {
+            Some(as_rule_filters(&params.only))
+        };
+        let disabled_rules = as_rule_filters(&params.skip);
+        let filter = AnalysisFilter {
+            enabled_rules: enabled_rules.as_deref(),
+            disabled_rules: Some(disabled_rules.as_slice()),
+            ..AnalysisFilter::default()
+        };
         fix_all(FixAllParams {
             parse,
             rules,
+            filter,
             fix_file_mode: params.fix_file_mode,
-            settings: self.settings(),
+            settings: self.settings(&params.path),
             should_format: params.should_format,
             rome_path: &params.path,
         })
@@ -567,4 +622,53 @@ impl Workspace for WorkspaceServer {
 
         Ok(result)
     }
+
+    fn transform_file(
+        &self,
+        params: TransformFileParams,
+    ) -> Result<TransformFileResult, WorkspaceError> {
+        let capabilities = self.get_capabilities(&params.path);
+        let transform = capabilities
+            .analyzer
+            .transform
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(params.path.clone(), None)?;
+        transform(&params.path, parse)
+    }
+
+    fn resolve_json_schema(
+        &self,
+        params: ResolveJsonSchemaParams,
+    ) -> Result<ResolveJsonSchemaResult, WorkspaceError> {
+        let language = Features::get_language(&params.path).or(self.get_language(&params.path));
+        if !matches!(language, Language::Json | Language::Jsonc) {
+            return Ok(ResolveJsonSchemaResult::default());
+        }
+
+        let parse = self.get_parse(params.path.clone(), None)?;
+        let settings = self.settings(&params.path);
+        let schema_path = resolve_schema_path(
+            &params.path,
+            &parse.tree(),
+            &settings.as_ref().languages.json.linter,
+        );
+        let is_registered = schema_path
+            .as_ref()
+            .map_or(false, |schema_path| self.json_schemas.contains(schema_path));
+
+        Ok(ResolveJsonSchemaResult {
+            schema_path: schema_path.map(RomePath::new),
+            is_registered,
+        })
+    }
+
+    fn register_json_schema(&self, params: RegisterJsonSchemaParams) -> Result<(), WorkspaceError> {
+        self.json_schemas.register(&params.path, &params.content)
+    }
+}
+
+/// Returns the filters of the rules selected by `selectors`
+fn as_rule_filters(selectors: &[RuleSelector]) -> Vec<RuleFilter> {
+    selectors.iter().map(RuleSelector::as_rule_filter).collect()
 }
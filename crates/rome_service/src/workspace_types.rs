@@ -451,7 +451,7 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 17] {
+pub fn methods() -> [WorkspaceMethod; 20] {
     [
         WorkspaceMethod::of::<SupportsFeatureParams, SupportsFeatureResult>("file_features"),
         workspace_method!(update_settings),
@@ -460,6 +460,7 @@ pub fn methods() -> [WorkspaceMethod; 17] {
         workspace_method!(close_file),
         workspace_method!(get_syntax_tree),
         workspace_method!(organize_imports),
+        workspace_method!(transform_file),
         workspace_method!(get_file_content),
         workspace_method!(get_control_flow_graph),
         workspace_method!(get_formatter_ir),
@@ -470,5 +471,7 @@ pub fn methods() -> [WorkspaceMethod; 17] {
         workspace_method!(format_on_type),
         workspace_method!(fix_file),
         workspace_method!(rename),
+        workspace_method!(resolve_json_schema),
+        workspace_method!(register_json_schema),
     ]
 }
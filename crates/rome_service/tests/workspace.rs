@@ -1,6 +1,14 @@
+use rome_deserialize::json::deserialize_from_json_str;
+use rome_diagnostics::{Diagnostic, PrintDescription};
 use rome_fs::RomePath;
-use rome_js_syntax::TextSize;
-use rome_service::workspace::{server, FileGuard, Language, OpenFileParams};
+use rome_js_syntax::{TextRange, TextSize};
+use rome_json_parser::JsonParserOptions;
+use rome_service::workspace::{
+    server, FileGuard, Language, OpenFileParams, RegisterJsonSchemaParams, RuleCategories,
+    UpdateSettingsParams,
+};
+use rome_service::{Configuration, Workspace};
+use std::path::Path;
 
 #[test]
 fn debug_control_flow() {
@@ -27,3 +35,291 @@ fn debug_control_flow() {
 
     assert_eq!(cfg, GRAPH);
 }
+
+#[test]
+fn transform_typescript_file() {
+    const SOURCE: &str = r#"import type { Options } from "./options";
+
+interface Point {
+	x: number;
+}
+
+enum Direction {
+	Up,
+}
+
+namespace Geometry {
+	export const origin: Point = { x: 0 };
+}
+
+class Shape {
+	private readonly name: string;
+	constructor(public size: number, name?: string) {
+		this.name = name as string;
+	}
+}
+
+export function area<T>(shape: Shape, options?: Options): number {
+	return shape!.size;
+}
+"#;
+
+    let workspace = server();
+
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("file.ts"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::TypeScript,
+        },
+    )
+    .unwrap();
+
+    let result = file.transform_file().unwrap();
+
+    assert_eq!(
+        result.code,
+        r#"
+
+var Direction;
+(function(Direction){Direction[Direction["Up"]=0]="Up";})(Direction||(Direction={}));
+
+var Geometry;
+(function(Geometry){
+	const origin = { x: 0 };
+	Geometry.origin = origin;
+})(Geometry||(Geometry={}));
+
+class Shape {
+	name;
+	constructor(size, name) {
+		this.size = size;
+		this.name = name;
+	}
+}
+
+export function area(shape, options) {
+	return shape.size;
+}
+"#
+    );
+}
+
+const SCHEMA: &str = r##"{
+    "type": "object",
+    "properties": {
+        "name": { "type": "string" },
+        "port": { "$ref": "#/definitions/port" }
+    },
+    "required": ["name"],
+    "additionalProperties": false,
+    "definitions": {
+        "port": { "type": "integer", "minimum": 1 }
+    }
+}"##;
+
+fn pull_lint_diagnostics(file: &FileGuard<dyn Workspace>) -> Vec<(String, Option<TextRange>)> {
+    file.pull_diagnostics(RuleCategories::LINT, u32::MAX.into(), vec![], vec![])
+        .unwrap()
+        .diagnostics
+        .iter()
+        .map(|diagnostic| {
+            (
+                PrintDescription(diagnostic).to_string(),
+                diagnostic.location().span,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn validate_json_with_schema_reference() {
+    const SOURCE: &str = r#"{
+    "$schema": "../schemas/server.schema.json",
+    "port": "80",
+    "host": "localhost"
+}"#;
+
+    let workspace = server();
+
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("config/server.json"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::Json,
+        },
+    )
+    .unwrap();
+
+    let resolved = file.resolve_json_schema().unwrap();
+    let schema_path = resolved.schema_path.unwrap();
+    assert_eq!(
+        schema_path.as_path(),
+        Path::new("schemas/server.schema.json")
+    );
+    assert!(!resolved.is_registered);
+
+    workspace
+        .register_json_schema(RegisterJsonSchemaParams {
+            path: schema_path,
+            content: SCHEMA.into(),
+        })
+        .unwrap();
+    assert!(file.resolve_json_schema().unwrap().is_registered);
+
+    assert_eq!(
+        pull_lint_diagnostics(&file),
+        vec![
+            (
+                "Expected an integer but found a string.".to_string(),
+                Some(TextRange::new(TextSize::from(62), TextSize::from(66)))
+            ),
+            (
+                "The property host isn't allowed here.".to_string(),
+                Some(TextRange::new(TextSize::from(72), TextSize::from(78)))
+            ),
+            (
+                "The required property name is missing.".to_string(),
+                Some(TextRange::new(TextSize::from(0), TextSize::from(1)))
+            ),
+        ]
+    );
+}
+
+#[test]
+fn validate_json_with_configured_schema() {
+    const CONFIGURATION: &str = r#"{
+    "json": {
+        "schemas": [
+            { "include": ["*.server.json"], "schema": "./schemas/server.schema.json" }
+        ]
+    }
+}"#;
+
+    let workspace = server();
+    let configuration =
+        deserialize_from_json_str::<Configuration>(CONFIGURATION, JsonParserOptions::default())
+            .into_deserialized();
+    workspace
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            directory: None,
+        })
+        .unwrap();
+
+    workspace
+        .register_json_schema(RegisterJsonSchemaParams {
+            path: RomePath::new("schemas/server.schema.json"),
+            content: SCHEMA.into(),
+        })
+        .unwrap();
+
+    let valid = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("prod.server.json"),
+            content: r#"{ "name": "prod", "port": 443 }"#.into(),
+            version: 0,
+            language_hint: Language::Json,
+        },
+    )
+    .unwrap();
+    assert!(valid.resolve_json_schema().unwrap().is_registered);
+    assert_eq!(pull_lint_diagnostics(&valid), vec![]);
+
+    let invalid = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("dev.server.json"),
+            content: r#"{ "name": "dev", "port": 0 }"#.into(),
+            version: 0,
+            language_hint: Language::Json,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        pull_lint_diagnostics(&invalid),
+        vec![(
+            "The value should be greater than or equal to 1.".to_string(),
+            Some(TextRange::new(TextSize::from(25), TextSize::from(26)))
+        )]
+    );
+
+    let unrelated = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("package.json"),
+            content: r#"{ "name": "unrelated" }"#.into(),
+            version: 0,
+            language_hint: Language::Json,
+        },
+    )
+    .unwrap();
+    assert!(unrelated
+        .resolve_json_schema()
+        .unwrap()
+        .schema_path
+        .is_none());
+}
+
+#[test]
+fn register_invalid_json_schema() {
+    let workspace = server();
+    let result = workspace.register_json_schema(RegisterJsonSchemaParams {
+        path: RomePath::new("schema.json"),
+        content: "{ \"type\": ".into(),
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+fn pull_json_sort_keys_action() {
+    const CONFIGURATION: &str = r#"{
+    "json": {
+        "assists": {
+            "sortKeys": { "order": ["name"] }
+        }
+    }
+}"#;
+
+    let workspace = server();
+    let configuration =
+        deserialize_from_json_str::<Configuration>(CONFIGURATION, JsonParserOptions::default())
+            .into_deserialized();
+    workspace
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            directory: None,
+        })
+        .unwrap();
+
+    const SOURCE: &str = r#"{ "version": "1.0.0", "author": "Rome", "name": "rome" }"#;
+    let file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            path: RomePath::new("package.json"),
+            content: SOURCE.into(),
+            version: 0,
+            language_hint: Language::Json,
+        },
+    )
+    .unwrap();
+
+    let result = file
+        .pull_actions(TextRange::new(TextSize::from(2), TextSize::from(2)))
+        .unwrap();
+    let sort_keys = result
+        .actions
+        .into_iter()
+        .find(|action| action.category.matches("source.sortKeys.rome"))
+        .expect("the sort keys action isn't available");
+
+    assert_eq!(
+        sort_keys.suggestion.suggestion.new_string(SOURCE),
+        r#"{ "name": "rome", "author": "Rome", "version": "1.0.0" }"#
+    );
+}
@@ -58,8 +58,11 @@ pub fn create_analyzer_options(
             let configuration = deserialized.into_deserialized();
             let mut settings = WorkspaceSettings::default();
             settings.merge_with_configuration(configuration).unwrap();
-            let configuration =
-                to_analyzer_configuration(&settings.linter, &settings.languages, |_| vec![]);
+            let configuration = to_analyzer_configuration(
+                settings.linter.rules.as_ref(),
+                &settings.languages,
+                |_| vec![],
+            );
             options = AnalyzerOptions {
                 configuration,
                 ..AnalyzerOptions::default()
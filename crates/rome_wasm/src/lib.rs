@@ -5,7 +5,7 @@ use rome_service::workspace::{
     self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
     FormatRangeParams, GetControlFlowGraphParams, GetFileContentParams, GetFormatterIRParams,
     GetSyntaxTreeParams, OrganizeImportsParams, PullActionsParams, PullDiagnosticsParams,
-    RenameParams, UpdateSettingsParams,
+    RenameParams, TransformFileParams, UpdateSettingsParams,
 };
 use rome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 
@@ -186,6 +186,19 @@ impl Workspace {
             .map_err(into_error)
     }
 
+    #[wasm_bindgen(js_name = transformFile)]
+    pub fn transform_file(
+        &self,
+        params: ITransformFileParams,
+    ) -> Result<ITransformFileResult, Error> {
+        let params: TransformFileParams =
+            serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
+        let result = self.inner.transform_file(params).map_err(into_error)?;
+        to_value(&result)
+            .map(ITransformFileResult::from)
+            .map_err(into_error)
+    }
+
     pub fn rename(&self, params: IRenameParams) -> Result<IRenameResult, Error> {
         let params: RenameParams =
             serde_wasm_bindgen::from_value(params.into()).map_err(into_error)?;
@@ -18,6 +18,10 @@ export type SupportKind =
 	| "FileNotSupported";
 export interface UpdateSettingsParams {
 	configuration: Configuration;
+	/**
+	 * The directory of a nested configuration file. The settings then only apply to the files of this directory, the settings of the root are used when it's omitted
+	 */
+	directory?: string;
 }
 /**
  * The configuration that is contained inside the file `rome.json`
@@ -55,6 +59,10 @@ export interface Configuration {
 	 * The configuration of the import sorting
 	 */
 	organizeImports?: OrganizeImports;
+	/**
+	 * A list of configurations applied to the files that match their patterns, merged in order onto the rest of the configuration.
+	 */
+	overrides?: OverridePattern[];
 	/**
 	 * The configuration of the VCS integration
 	 */
@@ -116,7 +124,19 @@ If defined here, they should not emit diagnostics.
 	parser?: JavascriptParser;
 }
 export interface JsonConfiguration {
+	/**
+	 * Options of the code actions provided for the JSON files
+	 */
+	assists?: JsonAssists;
+	/**
+	 * Formatting options specific to the JSON files
+	 */
+	formatter?: JsonFormatter;
 	parser?: JsonParser;
+	/**
+	 * A list of JSON Schemas used to validate the JSON files matching their patterns. A file that declares its own `$schema` is validated against that schema instead.
+	 */
+	schemas?: JsonSchemaMapping[];
 }
 export interface LinterConfiguration {
 	/**
@@ -142,6 +162,35 @@ export interface OrganizeImports {
 	 */
 	ignore?: StringSet;
 }
+/**
+ * A configuration applied to the files that match the patterns of `include`, and that don't match the patterns of `ignore`
+ */
+export interface OverridePattern {
+	/**
+	 * The options of the formatter that change for these files
+	 */
+	formatter?: OverrideFormatterConfiguration;
+	/**
+	 * A list of Unix shell style patterns. The configuration isn't applied to the files that match these patterns.
+	 */
+	ignore?: StringSet;
+	/**
+	 * A list of Unix shell style patterns. The configuration is applied to the files that match these patterns, or to all the files when the list is omitted.
+	 */
+	include?: StringSet;
+	/**
+	 * The options of the JavaScript language that change for these files. The options of the formatter and of the parser are applied, and the globals are added to the top-level ones.
+	 */
+	javascript?: JavascriptConfiguration;
+	/**
+	 * The options of the JSON language that change for these files. The options of the formatter and of the parser are applied.
+	 */
+	json?: JsonConfiguration;
+	/**
+	 * The options of the linter that change for these files
+	 */
+	linter?: OverrideLinterConfiguration;
+}
 /**
  * Set of properties to integrate Rome with a VCS software.
  */
@@ -207,11 +256,48 @@ These decorators belong to an old proposal, and they are subject to change.
 	 */
 	unsafeParameterDecoratorsEnabled?: boolean;
 }
+export interface JsonAssists {
+	/**
+	 * Options of the code action that sorts the keys of the objects
+	 */
+	sortKeys?: SortKeysOptions;
+}
+export interface JsonFormatter {
+	/**
+	 * Whether the formatter removes the unnecessary escapes of the strings, for example `"\/"` becomes `"/"`. Defaults to true.
+	 */
+	normalizeQuotes?: boolean;
+	/**
+	 * Whether an object that fits on a single line is collapsed, or kept expanded when the original source has a line break after its `{`. Defaults to "preserve".
+	 */
+	objectWrap?: ObjectWrap;
+	/**
+	 * Print trailing commas in multi-line objects and arrays. They are only printed in the JSONC files, like `.jsonc` files and `tsconfig.json`. Defaults to "none".
+	 */
+	trailingCommas?: TrailingCommas;
+}
 export interface JsonParser {
 	/**
 	 * Allow parsing comments in `.json` files
 	 */
 	allowComments?: boolean;
+	/**
+	 * Allow parsing trailing commas in `.json` files
+	 */
+	allowTrailingCommas?: boolean;
+}
+/**
+ * Associates a JSON Schema to the JSON files that match a list of patterns
+ */
+export interface JsonSchemaMapping {
+	/**
+	 * A list of Unix shell style patterns. The files that match these patterns are validated against the schema.
+	 */
+	include?: StringSet;
+	/**
+	 * The path to the JSON Schema file, relative to the working directory
+	 */
+	schema?: string;
 }
 export interface Rules {
 	a11y?: A11y;
@@ -231,6 +317,44 @@ export interface Rules {
 	style?: Style;
 	suspicious?: Suspicious;
 }
+/**
+ * The options of the formatter that can be changed by an override. The options that are omitted keep the value of the top-level `formatter` configuration.
+ */
+export interface OverrideFormatterConfiguration {
+	/**
+	 * if `false`, the files aren't formatted
+	 */
+	enabled?: boolean;
+	/**
+	 * Stores whether formatting should be allowed to proceed if a given file has syntax errors
+	 */
+	formatWithErrors?: boolean;
+	/**
+	 * The size of the indentation, 2 by default
+	 */
+	indentSize?: number;
+	/**
+	 * The indent style.
+	 */
+	indentStyle?: PlainIndentStyle;
+	/**
+	 * What's the max width of a line.
+	 */
+	lineWidth?: LineWidth;
+}
+/**
+ * The options of the linter that can be changed by an override. The rules are merged with the rules of the top-level `linter` configuration.
+ */
+export interface OverrideLinterConfiguration {
+	/**
+	 * if `false`, the files aren't linted
+	 */
+	enabled?: boolean;
+	/**
+	 * List of rules
+	 */
+	rules?: Rules;
+}
 export type VcsClientKind = "git";
 export type ArrowParentheses = "always" | "asNeeded";
 export type QuoteStyle = "double" | "single";
@@ -240,6 +364,25 @@ export type Semicolons = "always" | "asNeeded";
  * Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
  */
 export type TrailingComma = "all" | "es5" | "none";
+/**
+ * Options for the `sortKeys` assist
+ */
+export interface SortKeysOptions {
+	/**
+	 * A list of keys that are placed first, in the given order. The other keys are sorted by their code points.
+	 */
+	order?: string[];
+}
+/**
+ * How the formatter wraps the objects that fit on a single line.
+ */
+export type ObjectWrap = "preserve" | "collapse";
+/**
+	* Print trailing commas in multi-line objects and arrays.
+
+Trailing commas aren't valid in JSON: Rome only prints them in the JSONC files. 
+	 */
+export type TrailingCommas = "none" | "all";
 /**
  * A list of rules that belong to this group
  */
@@ -567,6 +710,10 @@ export interface Nursery {
 	 * Prevents from having control characters and some escape sequences that match control characters in regular expressions.
 	 */
 	noControlCharactersInRegex?: RuleConfiguration;
+	/**
+	 * Disallow duplicate properties inside a declaration block.
+	 */
+	noDuplicateCssProperties?: RuleConfiguration;
 	/**
 	 * Disallow two keys with the same name inside a JSON object.
 	 */
@@ -587,6 +734,14 @@ export interface Nursery {
 	 * Use Number.isNaN instead of global isNaN.
 	 */
 	noGlobalIsNan?: RuleConfiguration;
+	/**
+	 * Disallow fields of a package.json file that don't have the type expected by npm.
+	 */
+	noInvalidPackageJsonFields?: RuleConfiguration;
+	/**
+	 * Disallow comments in JSON files whose format doesn't allow them.
+	 */
+	noJsonComments?: RuleConfiguration;
 	/**
 	 * Enforce that tabIndex is not assigned to non-interactive HTML elements.
 	 */
@@ -607,6 +762,14 @@ export interface Nursery {
 	 * This rule reports when a class has no non-static members, such as for a class used exclusively as a static namespace.
 	 */
 	noStaticOnlyClass?: RuleConfiguration;
+	/**
+	 * Disallow unknown options inside the compilerOptions of a tsconfig.json file.
+	 */
+	noUnknownCompilerOptions?: RuleConfiguration;
+	/**
+	 * Disallow units that aren't defined by the CSS specifications.
+	 */
+	noUnknownCssUnits?: RuleConfiguration;
 	/**
 	 * Disallow unsafe declaration merging between interfaces and classes.
 	 */
@@ -659,6 +822,10 @@ export interface Nursery {
 	 * Enforce naming conventions for everything across a codebase.
 	 */
 	useNamingConvention?: RuleConfiguration;
+	/**
+	 * Enforce that the keys of the objects are sorted in the configured files.
+	 */
+	useSortedJsonKeys?: RuleConfiguration;
 }
 /**
  * A list of rules that belong to this group
@@ -971,6 +1138,7 @@ export type PossibleOptions =
 	| HooksOptions
 	| NamingConventionOptions
 	| RestrictedGlobalsOptions
+	| JsonFilesOptions
 	| null;
 /**
  * Options for the rule `noNestedModuleImports`.
@@ -1012,6 +1180,15 @@ export interface RestrictedGlobalsOptions {
 	 */
 	deniedGlobals?: string[];
 }
+/**
+ * Options for the rules that only run on some files, `useSortedJsonKeys` and `noJsonComments`.
+ */
+export interface JsonFilesOptions {
+	/**
+	 * A list of file names the rule applies to
+	 */
+	files?: string[];
+}
 export interface Hooks {
 	/**
 	* The "position" of the closure function, starting from zero.
@@ -1048,6 +1225,7 @@ export type Language =
 	| "TypeScriptReact"
 	| "Json"
 	| "Jsonc"
+	| "Css"
 	| "Unknown";
 export interface ChangeFileParams {
 	content: string;
@@ -1070,6 +1248,15 @@ export interface OrganizeImportsParams {
 export interface OrganizeImportsResult {
 	code: string;
 }
+export interface TransformFileParams {
+	path: RomePath;
+}
+export interface TransformFileResult {
+	/**
+	 * New source code for the file with all the transformations applied
+	 */
+	code: string;
+}
 export interface GetFileContentParams {
 	path: RomePath;
 }
@@ -1084,9 +1271,27 @@ export interface GetFormatterIRParams {
 export interface PullDiagnosticsParams {
 	categories: RuleCategories;
 	max_diagnostics: number;
+	/**
+	 * Runs only these rules, in place of the rules enabled by the configuration
+	 */
+	only?: RuleSelector[];
 	path: RomePath;
+	/**
+	 * Doesn't run these rules, even when they're enabled
+	 */
+	skip?: RuleSelector[];
 }
 export type RuleCategories = RuleCategory[];
+/**
+ * A rule, or all the rules of a group, as written in `group/rule` or `group`
+ */
+export interface RuleSelector {
+	group: string;
+	/**
+	 * The name of the rule, it's [None] when the whole group is selected
+	 */
+	rule?: string;
+}
 export type RuleCategory = "Syntax" | "Lint" | "Action" | "Transformation";
 export interface PullDiagnosticsResult {
 	diagnostics: Diagnostic[];
@@ -1184,16 +1389,21 @@ export type Category =
 	| "lint/nursery/noConfusingArrow"
 	| "lint/nursery/noConstantCondition"
 	| "lint/nursery/noControlCharactersInRegex"
+	| "lint/nursery/noDuplicateCssProperties"
 	| "lint/nursery/noDuplicateJsonKeys"
 	| "lint/nursery/noExcessiveComplexity"
 	| "lint/nursery/noFallthroughSwitchClause"
 	| "lint/nursery/noGlobalIsFinite"
 	| "lint/nursery/noGlobalIsNan"
+	| "lint/nursery/noInvalidPackageJsonFields"
+	| "lint/nursery/noJsonComments"
 	| "lint/nursery/noNoninteractiveTabindex"
 	| "lint/nursery/noNonoctalDecimalEscape"
 	| "lint/nursery/noRedundantRoles"
 	| "lint/nursery/noSelfAssign"
 	| "lint/nursery/noStaticOnlyClass"
+	| "lint/nursery/noUnknownCompilerOptions"
+	| "lint/nursery/noUnknownCssUnits"
 	| "lint/nursery/noUnsafeDeclarationMerging"
 	| "lint/nursery/noUselessEmptyExport"
 	| "lint/nursery/noVoid"
@@ -1206,6 +1416,7 @@ export type Category =
 	| "lint/nursery/useIsArray"
 	| "lint/nursery/useLiteralEnumMembers"
 	| "lint/nursery/useNamingConvention"
+	| "lint/nursery/useSortedJsonKeys"
 	| "lint/performance/noDelete"
 	| "lint/security/noDangerouslySetInnerHtml"
 	| "lint/security/noDangerouslySetInnerHtmlWithChildren"
@@ -1276,6 +1487,7 @@ export type Category =
 	| "organizeImports"
 	| "migrate"
 	| "deserialize"
+	| "jsonSchema"
 	| "internalError/io"
 	| "internalError/fs"
 	| "internalError/panic"
@@ -1471,8 +1683,16 @@ export interface FormatOnTypeParams {
 }
 export interface FixFileParams {
 	fix_file_mode: FixFileMode;
+	/**
+	 * Applies only the fixes of these rules, in place of the rules enabled by the configuration
+	 */
+	only?: RuleSelector[];
 	path: RomePath;
 	should_format: boolean;
+	/**
+	 * Doesn't apply the fixes of these rules
+	 */
+	skip?: RuleSelector[];
 }
 /**
  * Which fixes should be applied during the analyzing phase
@@ -1521,6 +1741,29 @@ export interface RenameResult {
 	 */
 	range: TextRange;
 }
+export interface ResolveJsonSchemaParams {
+	path: RomePath;
+}
+export interface ResolveJsonSchemaResult {
+	/**
+	 * Whether the schema was already registered in the workspace
+	 */
+	is_registered: boolean;
+	/**
+	 * The path of the JSON Schema used to validate the file, if any
+	 */
+	schema_path?: RomePath;
+}
+export interface RegisterJsonSchemaParams {
+	/**
+	 * The content of the schema
+	 */
+	content: string;
+	/**
+	 * The path of the schema, as returned by [Workspace::resolve_json_schema]
+	 */
+	path: RomePath;
+}
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<SupportsFeatureResult>;
 	updateSettings(params: UpdateSettingsParams): Promise<void>;
@@ -1531,6 +1774,7 @@ export interface Workspace {
 	organizeImports(
 		params: OrganizeImportsParams,
 	): Promise<OrganizeImportsResult>;
+	transformFile(params: TransformFileParams): Promise<TransformFileResult>;
 	getFileContent(params: GetFileContentParams): Promise<string>;
 	getControlFlowGraph(params: GetControlFlowGraphParams): Promise<string>;
 	getFormatterIr(params: GetFormatterIRParams): Promise<string>;
@@ -1543,6 +1787,10 @@ export interface Workspace {
 	formatOnType(params: FormatOnTypeParams): Promise<Printed>;
 	fixFile(params: FixFileParams): Promise<FixFileResult>;
 	rename(params: RenameParams): Promise<RenameResult>;
+	resolveJsonSchema(
+		params: ResolveJsonSchemaParams,
+	): Promise<ResolveJsonSchemaResult>;
+	registerJsonSchema(params: RegisterJsonSchemaParams): Promise<void>;
 	destroy(): void;
 }
 export function createWorkspace(transport: Transport): Workspace {
@@ -1568,6 +1816,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		organizeImports(params) {
 			return transport.request("rome/organize_imports", params);
 		},
+		transformFile(params) {
+			return transport.request("rome/transform_file", params);
+		},
 		getFileContent(params) {
 			return transport.request("rome/get_file_content", params);
 		},
@@ -1598,6 +1849,12 @@ export function createWorkspace(transport: Transport): Workspace {
 		rename(params) {
 			return transport.request("rome/rename", params);
 		},
+		resolveJsonSchema(params) {
+			return transport.request("rome/resolve_json_schema", params);
+		},
+		registerJsonSchema(params) {
+			return transport.request("rome/register_json_schema", params);
+		},
 		destroy() {
 			transport.destroy();
 		},
@@ -23,6 +23,7 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         ("%", "PERCENT"),
         (".", "DOT"),
         (":", "COLON"),
+        ("::", "COLON2"),
         ("=", "EQ"),
         ("!", "BANG"),
         ("!=", "NEQ"),
@@ -195,12 +196,16 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "from",
         "to",
         "var",
+        "url",
+        "charset",
+        "import",
     ],
     literals: &[
         "CSS_STRING_LITERAL",
         "CSS_NUMBER_LITERAL",
-        "CSS_CUSTOM_PROPERTY",
-        "CSS_SPACE_LITERAL",
+        "CSS_CUSTOM_PROPERTY_LITERAL",
+        "CSS_COLOR_LITERAL",
+        "CSS_URL_VALUE_RAW_LITERAL",
     ],
     tokens: &[
         "ERROR_TOKEN",
@@ -213,57 +218,72 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
     nodes: &[
         "CSS_ROOT",
         "CSS_RULE_LIST",
-        "CSS_ID_SELECTOR_PATTERN",
         "CSS_RULE",
         "CSS_SELECTOR_LIST",
         "CSS_SELECTOR",
-        "CSS_ANY_FUNCTION",
+        "CSS_ANY_SELECTOR_PATTERN_LIST",
+        "CSS_COMPLEX_SELECTOR",
+        "CSS_RELATIVE_SELECTOR",
+        "CSS_ID_SELECTOR_PATTERN",
+        "CSS_CLASS_SELECTOR_PATTERN",
+        "CSS_UNIVERSAL_SELECTOR_PATTERN",
+        "CSS_NESTING_SELECTOR_PATTERN",
+        "CSS_ATTRIBUTE_SELECTOR_PATTERN",
+        "CSS_ATTRIBUTE_MATCHER",
+        "CSS_PSEUDO_CLASS_SELECTOR_PATTERN",
+        "CSS_PSEUDO_ELEMENT_SELECTOR_PATTERN",
+        "CSS_PSEUDO_SELECTOR_PARAMETERS",
+        "CSS_PSEUDO_VALUE_PARAMETERS",
+        "CSS_TYPE_SELECTOR_PATTERN",
+        "CSS_BLOCK",
+        "CSS_BLOCK_ITEM_LIST",
+        "CSS_DECLARATION",
+        "CSS_DECLARATION_IMPORTANT",
+        "CSS_EMPTY_DECLARATION",
+        "CSS_AT_CHARSET",
+        "CSS_AT_IMPORT",
         "CSS_AT_KEYFRAMES",
         "CSS_AT_KEYFRAMES_BODY",
+        "CSS_AT_KEYFRAMES_ITEM_LIST",
+        "CSS_KEYFRAMES_BLOCK",
+        "CSS_KEYFRAMES_SELECTOR_LIST",
+        "CSS_KEYFRAMES_SELECTOR",
         "CSS_AT_MEDIA",
+        "CSS_AT_MEDIA_QUERY_LIST",
         "CSS_AT_MEDIA_QUERY",
+        "CSS_AT_MEDIA_QUERY_CONSEQUENT_LIST",
         "CSS_AT_MEDIA_QUERY_CONSEQUENT",
         "CSS_AT_MEDIA_QUERY_FEATURE",
+        "CSS_AT_MEDIA_QUERY_FEATURE_PLAIN",
         "CSS_AT_MEDIA_QUERY_FEATURE_BOOLEAN",
         "CSS_AT_MEDIA_QUERY_FEATURE_COMPARE",
-        "CSS_AT_MEDIA_QUERY_FEATURE_PLAIN",
+        "CSS_AT_MEDIA_QUERY_FEATURE_REVERSE_COMPARE",
         "CSS_AT_MEDIA_QUERY_FEATURE_RANGE",
         "CSS_AT_MEDIA_QUERY_RANGE",
-        "CSS_ATTRIBUTE",
-        "CSS_ATTRIBUTE_MATCHER",
-        "CSS_ATTRIBUTE_META",
-        "CSS_ATTRIBUTE_MODIFIER",
-        "CSS_ATTRIBUTE_NAME",
-        "CSS_ATTRIBUTE_SELECTOR_PATTERN",
-        "CSS_BLOCK",
-        "CSS_CLASS_SELECTOR_PATTERN",
-        "CSS_COMBINATOR_SELECTOR_PATTERN",
-        "CSS_DECLARATION",
+        "CSS_AT_GENERIC",
+        "CSS_COMPONENT_VALUE_LIST",
         "CSS_DIMENSION",
-        "CSS_IDENTIFIER",
-        "CSS_KEYFRAMES_BLOCK",
-        "CSS_KEYFRAMES_SELECTOR",
-        "CSS_NUMBER",
-        "CSS_PARAMETER",
-        "CSS_PERCENTAGE",
-        "CSS_PSEUDO_CLASS_SELECTOR_PATTERN",
-        "CSS_PSEUDO_CLASS_SELECTOR_PATTERN_PARAMETERS",
         "CSS_RATIO",
+        "CSS_PERCENTAGE",
+        "CSS_COLOR",
+        "CSS_PARENTHESIZED_VALUE",
+        "CSS_BRACKETED_VALUE",
+        "CSS_GENERIC_DELIMITER",
         "CSS_SIMPLE_FUNCTION",
-        "CSS_STRING",
-        "CSS_TYPE_SELECTOR_PATTERN",
-        "CSS_UNIVERSAL_SELECTOR_PATTERN",
+        "CSS_PARAMETER_LIST",
+        "CSS_PARAMETER",
         "CSS_VAR_FUNCTION",
         "CSS_VAR_FUNCTION_VALUE",
-        "CSS_ANY_SELECTOR_PATTERN_LIST",
-        "CSS_AT_KEYFRAMES_ITEM_LIST",
-        "CSS_AT_MEDIA_QUERY_LIST",
-        "CSS_ATTRIBUTE_LIST",
-        "CSS_DECLARATION_LIST",
-        "CSS_KEYFRAMES_SELECTOR_LIST",
-        "CSS_PARAMETER_LIST",
-        "CSS_DECLARATION_IMPORTANT",
-        // Bogs nodes
+        "CSS_URL_FUNCTION",
+        "CSS_URL_VALUE_RAW",
+        "CSS_CUSTOM_PROPERTY",
+        "CSS_IDENTIFIER",
+        "CSS_STRING",
+        "CSS_NUMBER",
+        // Bogus nodes
         "CSS_BOGUS",
+        "CSS_BOGUS_RULE",
+        "CSS_BOGUS_SELECTOR",
+        "CSS_BOGUS_VALUE",
     ],
 };
@@ -524,6 +524,7 @@ enum NodeDialect {
     Ts,
     Jsx,
     Json,
+    Css,
 }
 
 impl NodeDialect {
@@ -533,6 +534,7 @@ impl NodeDialect {
             NodeDialect::Ts,
             NodeDialect::Jsx,
             NodeDialect::Json,
+            NodeDialect::Css,
         ]
     }
 
@@ -546,6 +548,7 @@ impl NodeDialect {
             NodeDialect::Ts => "ts",
             NodeDialect::Jsx => "jsx",
             NodeDialect::Json => "json",
+            NodeDialect::Css => "css",
         }
     }
 
@@ -555,6 +558,7 @@ impl NodeDialect {
             "Js" => NodeDialect::Js,
             "Ts" => NodeDialect::Ts,
             "Json" => NodeDialect::Json,
+            "Css" => NodeDialect::Css,
             _ => {
                 eprintln!("missing prefix {}", name);
                 NodeDialect::Js
@@ -585,6 +589,10 @@ enum NodeConcept {
 
     // JSON
     Value,
+
+    // CSS
+    Selector,
+    AtRule,
 }
 
 impl NodeConcept {
@@ -606,6 +614,8 @@ impl NodeConcept {
             NodeConcept::Attribute => "attribute",
             NodeConcept::Auxiliary => "auxiliary",
             NodeConcept::Value => "value",
+            NodeConcept::Selector => "selectors",
+            NodeConcept::AtRule => "at_rule",
         }
     }
 }
@@ -726,7 +736,21 @@ fn name_to_module(kind: &NodeKind, in_name: &str, language: LanguageKind) -> Nod
                 _ if name.ends_with("Value") => NodeConcept::Value,
                 _ => NodeConcept::Auxiliary,
             },
-            LanguageKind::Css => NodeConcept::Auxiliary,
+            LanguageKind::Css => match name {
+                _ if name.starts_with("Keyframes") => NodeConcept::AtRule,
+                _ if name.contains("Selector") || name.starts_with("Pseudo") => {
+                    NodeConcept::Selector
+                }
+                "AttributeMatcher" => NodeConcept::Selector,
+                _ if name.starts_with("At") => NodeConcept::AtRule,
+                "Root"
+                | "Rule"
+                | "Block"
+                | "Declaration"
+                | "DeclarationImportant"
+                | "EmptyDeclaration" => NodeConcept::Auxiliary,
+                _ => NodeConcept::Value,
+            },
         }
     };
 
@@ -10,6 +10,7 @@ use xtask::{glue::fs2, project_root};
 pub fn generate_analyzer() -> Result<()> {
     generate_js_analyzer()?;
     generate_json_analyzer()?;
+    generate_css_analyzer()?;
     Ok(())
 }
 
@@ -44,14 +45,25 @@ fn generate_js_analyzer() -> Result<()> {
 }
 
 fn generate_json_analyzer() -> Result<()> {
+    let base_path = project_root().join("crates/rome_json_analyze/src");
+    let mut analyzers = BTreeMap::new();
+    generate_category("analyzers", &mut analyzers, base_path.clone())?;
+
+    let mut assists = BTreeMap::new();
+    generate_category("assists", &mut assists, base_path)?;
+
+    update_json_registry_builder(analyzers, assists)
+}
+
+fn generate_css_analyzer() -> Result<()> {
     let mut analyzers = BTreeMap::new();
     generate_category(
         "analyzers",
         &mut analyzers,
-        project_root().join("crates/rome_json_analyze/src"),
+        project_root().join("crates/rome_css_analyze/src"),
     )?;
 
-    update_json_registry_builder(analyzers)
+    update_css_registry_builder(analyzers)
 }
 
 fn generate_category(
@@ -240,10 +252,16 @@ fn update_js_registry_builder(
     Ok(())
 }
 
-fn update_json_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+fn update_json_registry_builder(
+    analyzers: BTreeMap<&'static str, TokenStream>,
+    assists: BTreeMap<&'static str, TokenStream>,
+) -> Result<()> {
     let path = project_root().join("crates/rome_json_analyze/src/registry.rs");
 
-    let categories = analyzers.into_values();
+    let categories = analyzers
+        .into_iter()
+        .chain(assists)
+        .map(|(_, tokens)| tokens);
 
     let tokens = xtask::reformat(quote! {
         use rome_analyze::RegistryVisitor;
@@ -258,3 +276,22 @@ fn update_json_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>)
 
     Ok(())
 }
+
+fn update_css_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+    let path = project_root().join("crates/rome_css_analyze/src/registry.rs");
+
+    let categories = analyzers.into_values();
+
+    let tokens = xtask::reformat(quote! {
+        use rome_analyze::RegistryVisitor;
+        use rome_css_syntax::CssLanguage;
+
+        pub fn visit_registry<V: RegistryVisitor<CssLanguage>>(registry: &mut V) {
+            #( #categories )*
+        }
+    })?;
+
+    fs2::write(path, tokens)?;
+
+    Ok(())
+}
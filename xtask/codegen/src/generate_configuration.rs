@@ -5,6 +5,7 @@ use quote::quote;
 use rome_analyze::{
     GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup, RuleMetadata,
 };
+use rome_css_syntax::CssLanguage;
 use rome_js_syntax::JsLanguage;
 use rome_json_syntax::JsonLanguage;
 use std::collections::BTreeMap;
@@ -62,9 +63,30 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
         }
     }
 
+    impl RegistryVisitor<CssLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = CssLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule + 'static,
+            R::Query: Queryable<Language = CssLanguage>,
+            <R::Query as Queryable>::Output: Clone,
+        {
+            self.groups
+                .entry(<R::Group as RuleGroup>::NAME)
+                .or_insert_with(BTreeMap::new)
+                .insert(R::METADATA.name, R::METADATA);
+        }
+    }
+
     let mut visitor = LintRulesVisitor::default();
     rome_js_analyze::visit_registry(&mut visitor);
     rome_json_analyze::visit_registry(&mut visitor);
+    rome_css_analyze::visit_registry(&mut visitor);
 
     let LintRulesVisitor { groups } = visitor;
 
@@ -74,6 +96,8 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
     let mut group_rules_union = Vec::new();
     let mut group_match_code = Vec::new();
     let mut group_get_severity = Vec::new();
+    let mut group_set_rule_configuration = Vec::new();
+    let mut group_merge = Vec::new();
     let mut group_name_list = vec!["recommended", "all"];
     let mut rule_visitor_call = Vec::new();
     let mut visitor_rule_list = Vec::new();
@@ -130,6 +154,23 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
                     }
                 })
         });
+        group_set_rule_configuration.push(quote! {
+            #group => {
+                if !#group_struct_name::has_rule(rule_name) {
+                    return false;
+                }
+                self.#property_group_name
+                    .get_or_insert_with(#group_struct_name::default)
+                    .set_rule_configuration(rule_name, configuration)
+            }
+        });
+        group_merge.push(quote! {
+            if let Some(other_group) = other.#property_group_name {
+                self.#property_group_name
+                    .get_or_insert_with(#group_struct_name::default)
+                    .merge_with(other_group);
+            }
+        });
         group_match_code.push(quote! {
            #group => #group_struct_name::has_rule(rule_name).then_some((category, rule_name))
         });
@@ -154,6 +195,7 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
         #[cfg(feature = "schema")]
         use schemars::JsonSchema;
         use crate::RuleConfiguration;
+        use crate::configuration::MergeWith;
         use rome_analyze::RuleFilter;
         use indexmap::IndexSet;
         use bpaf::Bpaf;
@@ -229,6 +271,22 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
                 }
             }
 
+            /// Sets the configuration of the rule `rule_name` of the group `group`.
+            ///
+            /// It returns `false` if the rule doesn't exist.
+            pub fn set_rule_configuration(
+                &mut self,
+                group: &str,
+                rule_name: &str,
+                configuration: RuleConfiguration,
+            ) -> bool {
+                match group {
+                    #( #group_set_rule_configuration ),*,
+
+                    _ => false
+                }
+            }
+
             pub(crate) const fn is_recommended(&self) -> bool {
                 // It is only considered _not_ recommended when
                 // the configuration is `"recommended": false`.
@@ -259,6 +317,19 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
             }
         }
 
+        impl MergeWith<Rules> for Rules {
+            /// The groups and the rules configured in `other` replace the ones of this configuration
+            fn merge_with(&mut self, other: Rules) {
+                if let Some(recommended) = other.recommended {
+                    self.recommended = Some(recommended);
+                }
+                if let Some(all) = other.all {
+                    self.all = Some(all);
+                }
+                #( #group_merge )*
+            }
+        }
+
         #( #struct_groups )*
     };
 
@@ -361,6 +432,8 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
     let mut rule_enabled_check_line = Vec::new();
     let mut rule_disabled_check_line = Vec::new();
     let mut get_rule_configuration_line = Vec::new();
+    let mut set_rule_configuration_line = Vec::new();
+    let mut merge_rule_line = Vec::new();
 
     let mut number_of_recommended_rules: u8 = 0;
     let number_of_rules = Literal::u8_unsuffixed(rules.len() as u8);
@@ -463,6 +536,14 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
         get_rule_configuration_line.push(quote! {
             #rule => self.#rule_identifier.as_ref()
         });
+        set_rule_configuration_line.push(quote! {
+            #rule => self.#rule_identifier = Some(configuration)
+        });
+        merge_rule_line.push(quote! {
+            if let Some(rule) = other.#rule_identifier {
+                self.#rule_identifier = Some(rule);
+            }
+        });
     }
 
     let group_struct_name = Ident::new(&group.to_capitalized(), Span::call_site());
@@ -590,6 +671,33 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
                     _ => None
                 }
             }
+
+            /// Sets the configuration of the rule `rule_name`. It returns `false` if the rule
+            /// doesn't belong to this group.
+            pub(crate) fn set_rule_configuration(
+                &mut self,
+                rule_name: &str,
+                configuration: RuleConfiguration,
+            ) -> bool {
+                match rule_name {
+                    #( #set_rule_configuration_line ),*,
+                    _ => return false
+                }
+
+                true
+            }
+        }
+
+        impl MergeWith<#group_struct_name> for #group_struct_name {
+            fn merge_with(&mut self, other: #group_struct_name) {
+                if let Some(recommended) = other.recommended {
+                    self.recommended = Some(recommended);
+                }
+                if let Some(all) = other.all {
+                    self.all = Some(all);
+                }
+                #( #merge_rule_line )*
+            }
         }
     }
 }
@@ -8,6 +8,8 @@ use rome_console::{
     fmt::{Formatter, HTML},
     markup, Console, Markup, MarkupBuf,
 };
+use rome_css_parser::CssParserOptions;
+use rome_css_syntax::CssLanguage;
 use rome_diagnostics::termcolor::NoColor;
 use rome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic};
 use rome_js_parser::JsParserOptions;
@@ -112,9 +114,31 @@ fn main() -> Result<()> {
         }
     }
 
+    impl RegistryVisitor<CssLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = CssLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule + 'static,
+            R::Query: Queryable<Language = CssLanguage>,
+            <R::Query as Queryable>::Output: Clone,
+        {
+            self.number_or_rules += 1;
+            self.groups
+                .entry(<R::Group as RuleGroup>::NAME)
+                .or_insert_with(BTreeMap::new)
+                .insert(R::METADATA.name, R::METADATA);
+        }
+    }
+
     let mut visitor = LintRulesVisitor::default();
     rome_js_analyze::visit_registry(&mut visitor);
     rome_json_analyze::visit_registry(&mut visitor);
+    rome_css_analyze::visit_registry(&mut visitor);
 
     let LintRulesVisitor {
         mut groups,
@@ -439,6 +463,7 @@ fn parse_documentation(
 enum BlockType {
     Js(JsFileSource),
     Json,
+    Css,
 }
 
 struct CodeBlockTest {
@@ -495,6 +520,10 @@ impl FromStr for CodeBlockTest {
                     test.block_type = BlockType::Json;
                 }
 
+                "css" => {
+                    test.block_type = BlockType::Css;
+                }
+
                 _ => {
                     bail!("unknown code block attribute {token:?}")
                 }
@@ -647,7 +676,11 @@ fn assert_lint(
             }
         }
         BlockType::Json => {
-            let parse = rome_json_parser::parse_json(code, JsonParserOptions::default());
+            let parse = rome_json_parser::parse_json(
+                code,
+                // Some rules inspect comments, which aren't part of the standard JSON syntax
+                JsonParserOptions::default().with_allow_comments(),
+            );
 
             if parse.has_errors() {
                 for diag in parse.into_diagnostics() {
@@ -701,6 +734,67 @@ fn assert_lint(
                     },
                 );
 
+                // Result is Some(_) if analysis aborted with an error
+                for diagnostic in diagnostics {
+                    write_diagnostic(code, diagnostic)?;
+                }
+            }
+        }
+        BlockType::Css => {
+            let parse = rome_css_parser::parse_css(code, CssParserOptions::default());
+
+            if parse.has_errors() {
+                for diag in parse.into_diagnostics() {
+                    let error = diag
+                        .with_file_path(file.clone())
+                        .with_file_source_code(code);
+                    write_diagnostic(code, error)?;
+                }
+            } else {
+                let root = parse.tree();
+
+                let settings = WorkspaceSettings::default();
+
+                let rule_filter = RuleFilter::Rule(group, rule);
+                let filter = AnalysisFilter {
+                    enabled_rules: Some(slice::from_ref(&rule_filter)),
+                    ..AnalysisFilter::default()
+                };
+
+                let options = AnalyzerOptions::default();
+                let (_, diagnostics) = rome_css_analyze::analyze(
+                    &root,
+                    filter,
+                    &options,
+                    |signal| {
+                        if let Some(mut diag) = signal.diagnostic() {
+                            let category = diag.category().expect("linter diagnostic has no code");
+                            let severity = settings.get_severity_from_rule_code(category).expect(
+                                "If you see this error, it means you need to run cargo codegen-configuration",
+                            );
+
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diag = diag.add_code_suggestion(action.into());
+                                }
+                            }
+
+                            let error = diag
+                                .with_severity(severity)
+                                .with_file_path(file.clone())
+                                .with_file_source_code(code);
+                            let res = write_diagnostic(code, error);
+
+                            // Abort the analysis on error
+                            if let Err(err) = res {
+                                return ControlFlow::Break(err);
+                            }
+                        }
+
+                        ControlFlow::Continue(())
+                    },
+                );
+
                 // Result is Some(_) if analysis aborted with an error
                 for diagnostic in diagnostics {
                     write_diagnostic(code, diagnostic)?;